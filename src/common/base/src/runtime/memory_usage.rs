@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory usage breakdown by component.
+//!
+//! The `MemStat` tree tracks the memory of the queries, but not what the memory is used for.
+//! The major memory consumers report the bytes they hold to the `MemoryUsageRegistry`, either
+//! directly with `MemoryUsageRegistry::record` if they can tell the bytes released, or through
+//! a `MemoryUsageGuard` that releases the bytes it reported when it is dropped.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
+
+static MEMORY_USAGE_REGISTRY: MemoryUsageRegistry = MemoryUsageRegistry::create();
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryComponent {
+    /// The data blocks pushed to the ports of the processors and not pulled yet.
+    PipelineBuffers,
+    /// The buckets of the hash tables of the aggregations and the joins.
+    HashTables,
+    /// The data blocks buffered by the sorts.
+    SortBuffers,
+    /// The in-memory cache of the segments.
+    SegmentCache,
+    /// The data blocks buffered by the writers of the query result cache.
+    ResultCache,
+    /// The in-memory caches of the table meta that are metered by bytes.
+    MetaCache,
+}
+
+impl MemoryComponent {
+    pub const ALL: [MemoryComponent; 6] = [
+        MemoryComponent::PipelineBuffers,
+        MemoryComponent::HashTables,
+        MemoryComponent::SortBuffers,
+        MemoryComponent::SegmentCache,
+        MemoryComponent::ResultCache,
+        MemoryComponent::MetaCache,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MemoryComponent::PipelineBuffers => "pipeline_buffers",
+            MemoryComponent::HashTables => "hash_tables",
+            MemoryComponent::SortBuffers => "sort_buffers",
+            MemoryComponent::SegmentCache => "segment_cache",
+            MemoryComponent::ResultCache => "result_cache",
+            MemoryComponent::MetaCache => "meta_cache",
+        }
+    }
+}
+
+impl Display for MemoryComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The memory usage of a component.
+///
+/// `allocated` is the bytes reserved by the component and `used` is the bytes holding data,
+/// it is less than `allocated` if the component reserves memory ahead of use.
+pub struct ComponentMemoryStat {
+    allocated: AtomicI64,
+    used: AtomicI64,
+    peak: AtomicI64,
+}
+
+impl ComponentMemoryStat {
+    const fn create() -> Self {
+        Self {
+            allocated: AtomicI64::new(0),
+            used: AtomicI64::new(0),
+            peak: AtomicI64::new(0),
+        }
+    }
+
+    #[inline]
+    fn record(&self, allocated: i64, used: i64) {
+        let current = self.allocated.fetch_add(allocated, Ordering::Relaxed) + allocated;
+        self.used.fetch_add(used, Ordering::Relaxed);
+        if allocated > 0 {
+            self.peak.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_allocated_bytes(&self) -> i64 {
+        self.allocated.load(Ordering::Relaxed)
+    }
+
+    pub fn get_used_bytes(&self) -> i64 {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// The max allocated bytes since the process started or the peak was reset.
+    pub fn get_peak_bytes(&self) -> i64 {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    fn reset_peak(&self) {
+        self.peak
+            .store(self.allocated.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+}
+
+pub struct MemoryUsageRegistry {
+    stats: [ComponentMemoryStat; MemoryComponent::ALL.len()],
+}
+
+impl MemoryUsageRegistry {
+    const fn create() -> Self {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const EMPTY: ComponentMemoryStat = ComponentMemoryStat::create();
+        Self {
+            stats: [EMPTY; MemoryComponent::ALL.len()],
+        }
+    }
+
+    pub fn instance() -> &'static MemoryUsageRegistry {
+        &MEMORY_USAGE_REGISTRY
+    }
+
+    pub fn get(&self, component: MemoryComponent) -> &ComponentMemoryStat {
+        &self.stats[component as usize]
+    }
+
+    /// Adds the bytes to the usage of the component, the bytes released are negative.
+    #[inline]
+    pub fn record(&self, component: MemoryComponent, allocated: i64, used: i64) {
+        self.get(component).record(allocated, used)
+    }
+
+    /// Resets the peak of every component to its current allocated bytes.
+    pub fn reset_peak(&self) {
+        for stat in self.stats.iter() {
+            stat.reset_peak();
+        }
+    }
+}
+
+/// Reports the memory usage of one instance of a component, which is released on drop.
+pub struct MemoryUsageGuard {
+    component: MemoryComponent,
+    allocated: AtomicI64,
+    used: AtomicI64,
+}
+
+impl MemoryUsageGuard {
+    pub fn create(component: MemoryComponent) -> Self {
+        Self {
+            component,
+            allocated: AtomicI64::new(0),
+            used: AtomicI64::new(0),
+        }
+    }
+
+    /// Replaces the bytes reported by the guard.
+    pub fn update(&self, allocated: usize, used: usize) {
+        let allocated = allocated as i64;
+        let used = used as i64;
+        let old_allocated = self.allocated.swap(allocated, Ordering::Relaxed);
+        let old_used = self.used.swap(used, Ordering::Relaxed);
+        MemoryUsageRegistry::instance().record(
+            self.component,
+            allocated - old_allocated,
+            used - old_used,
+        );
+    }
+}
+
+impl Drop for MemoryUsageGuard {
+    fn drop(&mut self) {
+        self.update(0, 0);
+    }
+}
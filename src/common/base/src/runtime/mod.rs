@@ -15,6 +15,7 @@
 mod backtrace;
 mod catch_unwind;
 mod global_runtime;
+mod memory_usage;
 #[allow(clippy::module_inception)]
 mod runtime;
 mod runtime_tracker;
@@ -27,6 +28,10 @@ pub use catch_unwind::catch_unwind;
 pub use catch_unwind::CatchUnwindFuture;
 pub use global_runtime::GlobalIORuntime;
 pub use global_runtime::GlobalQueryRuntime;
+pub use memory_usage::ComponentMemoryStat;
+pub use memory_usage::MemoryComponent;
+pub use memory_usage::MemoryUsageGuard;
+pub use memory_usage::MemoryUsageRegistry;
 pub use runtime::block_on;
 pub use runtime::execute_futures_in_parallel;
 pub use runtime::match_join_handle;
@@ -165,15 +165,23 @@ impl Runtime {
         Self::create(None, mem_stat, &mut runtime_builder)
     }
 
-    #[allow(unused_mut)]
-    pub fn with_worker_threads(workers: usize, mut thread_name: Option<String>) -> Result<Self> {
+    pub fn with_worker_threads(workers: usize, thread_name: Option<String>) -> Result<Self> {
         let mut mem_stat_name = String::from("UnnamedRuntime");
 
         if let Some(thread_name) = thread_name.as_ref() {
             mem_stat_name = format!("{}Runtime", thread_name);
         }
 
-        let mem_stat = MemStat::create(mem_stat_name);
+        Self::with_worker_threads_and_mem_stat(workers, thread_name, MemStat::create(mem_stat_name))
+    }
+
+    /// Spawns a new tokio runtime whose memory is tracked by the `mem_stat`.
+    #[allow(unused_mut)]
+    pub fn with_worker_threads_and_mem_stat(
+        workers: usize,
+        mut thread_name: Option<String>,
+        mem_stat: Arc<MemStat>,
+    ) -> Result<Self> {
         let mut runtime_builder = Self::tracker_builder(mem_stat.clone());
 
         #[cfg(debug_assertions)]
@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageGuard;
+use databend_common_base::runtime::MemoryUsageRegistry;
+
+#[test]
+fn test_memory_usage_guard() {
+    let registry = MemoryUsageRegistry::instance();
+    let stat = registry.get(MemoryComponent::SortBuffers);
+    let allocated = stat.get_allocated_bytes();
+    let used = stat.get_used_bytes();
+
+    let guard = MemoryUsageGuard::create(MemoryComponent::SortBuffers);
+    guard.update(100, 60);
+    assert_eq!(stat.get_allocated_bytes() - allocated, 100);
+    assert_eq!(stat.get_used_bytes() - used, 60);
+    assert!(stat.get_peak_bytes() >= allocated + 100);
+
+    guard.update(40, 40);
+    assert_eq!(stat.get_allocated_bytes() - allocated, 40);
+    assert_eq!(stat.get_used_bytes() - used, 40);
+
+    drop(guard);
+    assert_eq!(stat.get_allocated_bytes(), allocated);
+    assert_eq!(stat.get_used_bytes(), used);
+}
+
+#[test]
+fn test_reset_memory_peak() {
+    let registry = MemoryUsageRegistry::instance();
+    let stat = registry.get(MemoryComponent::ResultCache);
+
+    let guard = MemoryUsageGuard::create(MemoryComponent::ResultCache);
+    guard.update(1024, 1024);
+    drop(guard);
+    assert!(stat.get_peak_bytes() >= 1024);
+
+    registry.reset_peak();
+    assert_eq!(stat.get_peak_bytes(), stat.get_allocated_bytes());
+}
@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Result;
+use std::io::Write;
+
+use async_compression::codec::Decode;
+use async_compression::codec::Encode;
+use async_compression::util::PartialBuffer;
+
+/// BufferedCodec supports the formats which async-compression doesn't support.
+///
+/// The whole input is buffered, and (de)compressed in one shot while finishing.
+#[derive(Debug)]
+pub struct BufferedCodec {
+    codec: fn(&[u8]) -> Result<Vec<u8>>,
+    input: Vec<u8>,
+    output: Option<Vec<u8>>,
+    written: usize,
+}
+
+impl BufferedCodec {
+    pub fn lz4_decoder() -> Self {
+        Self::new(lz4_decompress)
+    }
+
+    pub fn lz4_encoder() -> Self {
+        Self::new(lz4_compress)
+    }
+
+    pub fn snappy_decoder() -> Self {
+        Self::new(snappy_decompress)
+    }
+
+    pub fn snappy_encoder() -> Self {
+        Self::new(snappy_compress)
+    }
+
+    fn new(codec: fn(&[u8]) -> Result<Vec<u8>>) -> Self {
+        Self {
+            codec,
+            input: vec![],
+            output: None,
+            written: 0,
+        }
+    }
+
+    fn consume(&mut self, input: &mut PartialBuffer<impl AsRef<[u8]>>) {
+        let len = input.unwritten().len();
+        self.input.extend_from_slice(input.unwritten());
+        input.advance(len);
+    }
+
+    /// Returns true if all the output has been written.
+    fn drain(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        if self.output.is_none() {
+            self.output = Some((self.codec)(&self.input)?);
+            self.input = vec![];
+        }
+
+        let data = &self.output.as_ref().unwrap()[self.written..];
+        let len = data.len().min(output.unwritten().len());
+        output.unwritten_mut()[..len].copy_from_slice(&data[..len]);
+        output.advance(len);
+        self.written += len;
+        Ok(len == data.len())
+    }
+}
+
+impl Decode for BufferedCodec {
+    fn reinit(&mut self) -> Result<()> {
+        self.input = vec![];
+        self.output = None;
+        self.written = 0;
+        Ok(())
+    }
+
+    fn decode(
+        &mut self,
+        input: &mut PartialBuffer<impl AsRef<[u8]>>,
+        _output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        self.consume(input);
+        Ok(false)
+    }
+
+    fn flush(
+        &mut self,
+        _output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn finish(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        self.drain(output)
+    }
+}
+
+impl Encode for BufferedCodec {
+    fn encode(
+        &mut self,
+        input: &mut PartialBuffer<impl AsRef<[u8]>>,
+        _output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<()> {
+        self.consume(input);
+        Ok(())
+    }
+
+    fn flush(
+        &mut self,
+        _output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn finish(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        self.drain(output)
+    }
+}
+
+fn lz4_decompress(input: &[u8]) -> Result<Vec<u8>> {
+    let mut decoder = lz4::Decoder::new(Cursor::new(input))?;
+    let mut output = vec![];
+    decoder.read_to_end(&mut output)?;
+    // Returns error if the frame is not complete.
+    decoder.finish().1?;
+    Ok(output)
+}
+
+fn lz4_compress(input: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = lz4::EncoderBuilder::new().build(vec![])?;
+    encoder.write_all(input)?;
+    let (output, res) = encoder.finish();
+    res?;
+    Ok(output)
+}
+
+fn snappy_decompress(input: &[u8]) -> Result<Vec<u8>> {
+    let mut output = vec![];
+    snap::read::FrameDecoder::new(input).read_to_end(&mut output)?;
+    Ok(output)
+}
+
+fn snappy_compress(input: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = snap::write::FrameEncoder::new(vec![]);
+    encoder.write_all(input)?;
+    encoder
+        .into_inner()
+        .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
+}
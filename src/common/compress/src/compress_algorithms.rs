@@ -32,8 +32,16 @@ pub enum CompressAlgorithm {
     ///
     /// Similar to [`CompressAlgorithm::Deflate`] and [`CompressAlgorithm::Zlib`]
     Gzip,
+    /// [LZ4](https://github.com/lz4/lz4/blob/dev/doc/lz4_Frame_format.md) frame format.
+    ///
+    /// Not supported by async-compression, the whole file is buffered while (de)compressing.
+    Lz4,
     /// [LZMA](https://www.7-zip.org/sdk.html) compress format.
     Lzma,
+    /// [Snappy](https://github.com/google/snappy/blob/main/framing_format.txt) framing format.
+    ///
+    /// Not supported by async-compression, the whole file is buffered while (de)compressing.
+    Snappy,
     /// [Xz](https://tukaani.org/xz/) compress format, the successor of [`CompressAlgorithm::Lzma`].
     Xz,
     /// [Zlib](https://datatracker.ietf.org/doc/html/rfc1950) compress format.
@@ -52,7 +60,9 @@ impl CompressAlgorithm {
             CompressAlgorithm::Bz2 => "bz2",
             CompressAlgorithm::Deflate => "deflate",
             CompressAlgorithm::Gzip => "gz",
+            CompressAlgorithm::Lz4 => "lz4",
             CompressAlgorithm::Lzma => "lzma",
+            CompressAlgorithm::Snappy => "snappy",
             CompressAlgorithm::Xz => "xz",
             CompressAlgorithm::Zlib => "zl",
             CompressAlgorithm::Zstd => "zstd",
@@ -68,7 +78,9 @@ impl CompressAlgorithm {
             "bz2" => Some(CompressAlgorithm::Bz2),
             "deflate" => Some(CompressAlgorithm::Deflate),
             "gz" => Some(CompressAlgorithm::Gzip),
+            "lz4" => Some(CompressAlgorithm::Lz4),
             "lzma" => Some(CompressAlgorithm::Lzma),
+            "snappy" | "sz" => Some(CompressAlgorithm::Snappy),
             "xz" => Some(CompressAlgorithm::Xz),
             "zl" => Some(CompressAlgorithm::Zlib),
             "zstd" | "zst" => Some(CompressAlgorithm::Zstd),
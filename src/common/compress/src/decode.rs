@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Error;
+use std::io::ErrorKind;
 use std::io::Result;
 use std::pin::Pin;
 use std::task::Context;
@@ -37,6 +39,7 @@ use futures::AsyncRead;
 use log::trace;
 use pin_project::pin_project;
 
+use crate::buffered::BufferedCodec;
 use crate::CompressAlgorithm;
 
 #[derive(Debug)]
@@ -52,8 +55,12 @@ pub enum DecompressCodec {
     Deflate(DeflateDecoder),
     /// Decoder for [`CompressAlgorithm::Gzip`]
     Gzip(GzipDecoder),
+    /// Decoder for [`CompressAlgorithm::Lz4`]
+    Lz4(BufferedCodec),
     /// Decoder for [`CompressAlgorithm::Lzma`]
     Lzma(LzmaDecoder),
+    /// Decoder for [`CompressAlgorithm::Snappy`]
+    Snappy(BufferedCodec),
     /// Decoder for [`CompressAlgorithm::Xz`]
     Xz(XzDecoder),
     /// Decoder for [`CompressAlgorithm::Zlib`]
@@ -69,7 +76,9 @@ impl From<CompressAlgorithm> for DecompressCodec {
             CompressAlgorithm::Bz2 => DecompressCodec::Bz2(BzDecoder::new()),
             CompressAlgorithm::Deflate => DecompressCodec::Deflate(DeflateDecoder::new()),
             CompressAlgorithm::Gzip => DecompressCodec::Gzip(GzipDecoder::new()),
+            CompressAlgorithm::Lz4 => DecompressCodec::Lz4(BufferedCodec::lz4_decoder()),
             CompressAlgorithm::Lzma => DecompressCodec::Lzma(LzmaDecoder::new()),
+            CompressAlgorithm::Snappy => DecompressCodec::Snappy(BufferedCodec::snappy_decoder()),
             CompressAlgorithm::Xz => DecompressCodec::Xz(XzDecoder::new()),
             CompressAlgorithm::Zlib => DecompressCodec::Zlib(ZlibDecoder::new()),
             CompressAlgorithm::Zstd => DecompressCodec::Zstd(ZstdDecoder::new()),
@@ -77,6 +86,13 @@ impl From<CompressAlgorithm> for DecompressCodec {
     }
 }
 
+impl DecompressCodec {
+    /// Buffered codecs only find out the end of the stream while finishing.
+    fn is_buffered(&self) -> bool {
+        matches!(self, DecompressCodec::Lz4(_) | DecompressCodec::Snappy(_))
+    }
+}
+
 impl Decode for DecompressCodec {
     fn reinit(&mut self) -> Result<()> {
         match self {
@@ -84,7 +100,9 @@ impl Decode for DecompressCodec {
             DecompressCodec::Bz2(v) => v.reinit(),
             DecompressCodec::Deflate(v) => v.reinit(),
             DecompressCodec::Gzip(v) => v.reinit(),
+            DecompressCodec::Lz4(v) => v.reinit(),
             DecompressCodec::Lzma(v) => v.reinit(),
+            DecompressCodec::Snappy(v) => v.reinit(),
             DecompressCodec::Xz(v) => v.reinit(),
             DecompressCodec::Zlib(v) => v.reinit(),
             DecompressCodec::Zstd(v) => v.reinit(),
@@ -101,7 +119,9 @@ impl Decode for DecompressCodec {
             DecompressCodec::Bz2(v) => v.decode(input, output),
             DecompressCodec::Deflate(v) => v.decode(input, output),
             DecompressCodec::Gzip(v) => v.decode(input, output),
+            DecompressCodec::Lz4(v) => v.decode(input, output),
             DecompressCodec::Lzma(v) => v.decode(input, output),
+            DecompressCodec::Snappy(v) => v.decode(input, output),
             DecompressCodec::Xz(v) => v.decode(input, output),
             DecompressCodec::Zlib(v) => v.decode(input, output),
             DecompressCodec::Zstd(v) => v.decode(input, output),
@@ -117,7 +137,9 @@ impl Decode for DecompressCodec {
             DecompressCodec::Bz2(v) => v.flush(output),
             DecompressCodec::Deflate(v) => v.flush(output),
             DecompressCodec::Gzip(v) => v.flush(output),
+            DecompressCodec::Lz4(v) => v.flush(output),
             DecompressCodec::Lzma(v) => v.flush(output),
+            DecompressCodec::Snappy(v) => v.flush(output),
             DecompressCodec::Xz(v) => v.flush(output),
             DecompressCodec::Zlib(v) => v.flush(output),
             DecompressCodec::Zstd(v) => v.flush(output),
@@ -133,7 +155,9 @@ impl Decode for DecompressCodec {
             DecompressCodec::Bz2(v) => v.finish(output),
             DecompressCodec::Deflate(v) => v.finish(output),
             DecompressCodec::Gzip(v) => v.finish(output),
+            DecompressCodec::Lz4(v) => v.finish(output),
             DecompressCodec::Lzma(v) => v.finish(output),
+            DecompressCodec::Snappy(v) => v.finish(output),
             DecompressCodec::Xz(v) => v.finish(output),
             DecompressCodec::Zlib(v) => v.finish(output),
             DecompressCodec::Zstd(v) => v.finish(output),
@@ -173,6 +197,10 @@ pub struct DecompressDecoder {
     decoder: DecompressCodec,
     state: DecompressState,
     multiple_members: bool,
+    /// Total bytes consumed from the input.
+    consumed: usize,
+    /// The decoder has reached the end of the compressed stream.
+    stream_end: bool,
 }
 
 impl DecompressDecoder {
@@ -183,6 +211,8 @@ impl DecompressDecoder {
             decoder: algo.into(),
             state: DecompressState::Reading,
             multiple_members: false,
+            consumed: 0,
+            stream_end: false,
         }
     }
 
@@ -221,6 +251,13 @@ impl DecompressDecoder {
         // If input is empty, inner reader must reach EOF, return directly.
         if self.buf.is_empty() {
             trace!("input is empty, return directly");
+            // The input of a truncated file reaches EOF before the end of the stream.
+            if self.consumed > 0 && !self.stream_end && !self.decoder.is_buffered() {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "compressed data is truncated",
+                ));
+            }
             // Avoid attempting to reinitialise the decoder if the reader
             // has returned EOF.
             self.multiple_members = false;
@@ -234,8 +271,10 @@ impl DecompressDecoder {
         let read_len = input.written().len();
         let written_len = output.written().len();
         self.buf.advance(read_len);
+        self.consumed += read_len;
 
         if done {
+            self.stream_end = true;
             self.state = DecompressState::Flushing;
         } else if self.buf.is_empty() {
             self.state = DecompressState::Reading;
@@ -262,6 +301,7 @@ impl DecompressDecoder {
         if done {
             if self.multiple_members {
                 self.decoder.reinit()?;
+                self.stream_end = false;
                 self.state = DecompressState::Reading;
             } else {
                 self.state = DecompressState::Done;
@@ -28,6 +28,7 @@ use async_compression::Level;
 use brotli::enc::backward_references::BrotliEncoderParams;
 use databend_common_exception::ErrorCode;
 
+use crate::buffered::BufferedCodec;
 use crate::CompressAlgorithm;
 
 #[derive(Debug)]
@@ -43,8 +44,12 @@ pub enum CompressCodec {
     Deflate(DeflateEncoder),
     /// Encoder for [`CompressAlgorithm::Gzip`]
     Gzip(GzipEncoder),
+    /// Encoder for [`CompressAlgorithm::Lz4`]
+    Lz4(BufferedCodec),
     /// Encoder for [`CompressAlgorithm::Lzma`]
     Lzma(LzmaEncoder),
+    /// Encoder for [`CompressAlgorithm::Snappy`]
+    Snappy(BufferedCodec),
     /// Encoder for [`CompressAlgorithm::Xz`]
     Xz(XzEncoder),
     /// Encoder for [`CompressAlgorithm::Zlib`]
@@ -68,9 +73,11 @@ impl From<CompressAlgorithm> for CompressCodec {
             CompressAlgorithm::Gzip => {
                 CompressCodec::Gzip(GzipEncoder::new(Level::Default.into_flate2()))
             }
+            CompressAlgorithm::Lz4 => CompressCodec::Lz4(BufferedCodec::lz4_encoder()),
             CompressAlgorithm::Lzma => {
                 CompressCodec::Lzma(LzmaEncoder::new(Level::Default.into_xz2()))
             }
+            CompressAlgorithm::Snappy => CompressCodec::Snappy(BufferedCodec::snappy_encoder()),
             CompressAlgorithm::Xz => CompressCodec::Xz(XzEncoder::new(Level::Default.into_xz2())),
             CompressAlgorithm::Zlib => {
                 CompressCodec::Zlib(ZlibEncoder::new(Level::Default.into_flate2()))
@@ -93,7 +100,9 @@ impl Encode for CompressCodec {
             CompressCodec::Bz2(v) => v.encode(input, output),
             CompressCodec::Deflate(v) => v.encode(input, output),
             CompressCodec::Gzip(v) => v.encode(input, output),
+            CompressCodec::Lz4(v) => v.encode(input, output),
             CompressCodec::Lzma(v) => v.encode(input, output),
+            CompressCodec::Snappy(v) => v.encode(input, output),
             CompressCodec::Xz(v) => v.encode(input, output),
             CompressCodec::Zlib(v) => v.encode(input, output),
             CompressCodec::Zstd(v) => v.encode(input, output),
@@ -109,7 +118,9 @@ impl Encode for CompressCodec {
             CompressCodec::Bz2(v) => v.flush(output),
             CompressCodec::Deflate(v) => v.flush(output),
             CompressCodec::Gzip(v) => v.flush(output),
+            CompressCodec::Lz4(v) => v.flush(output),
             CompressCodec::Lzma(v) => v.flush(output),
+            CompressCodec::Snappy(v) => v.flush(output),
             CompressCodec::Xz(v) => v.flush(output),
             CompressCodec::Zlib(v) => v.flush(output),
             CompressCodec::Zstd(v) => v.flush(output),
@@ -125,7 +136,9 @@ impl Encode for CompressCodec {
             CompressCodec::Bz2(v) => v.finish(output),
             CompressCodec::Deflate(v) => v.finish(output),
             CompressCodec::Gzip(v) => v.finish(output),
+            CompressCodec::Lz4(v) => v.finish(output),
             CompressCodec::Lzma(v) => v.finish(output),
+            CompressCodec::Snappy(v) => v.finish(output),
             CompressCodec::Xz(v) => v.finish(output),
             CompressCodec::Zlib(v) => v.finish(output),
             CompressCodec::Zstd(v) => v.finish(output),
@@ -200,6 +213,8 @@ mod tests {
             CompressAlgorithm::Deflate,
             CompressAlgorithm::Xz,
             CompressAlgorithm::Lzma,
+            CompressAlgorithm::Lz4,
+            CompressAlgorithm::Snappy,
         ] {
             let mut encoder = CompressCodec::from(algo);
             let compressed = encoder.compress_all(&content)?;
@@ -217,4 +232,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_decompress_truncated() -> databend_common_exception::Result<()> {
+        let mut rng = ThreadRng::default();
+        let mut content = vec![0; 16 * 1024];
+        rng.fill_bytes(&mut content);
+        for algo in [
+            CompressAlgorithm::Zlib,
+            CompressAlgorithm::Gzip,
+            CompressAlgorithm::Bz2,
+            CompressAlgorithm::Zstd,
+            CompressAlgorithm::Deflate,
+            CompressAlgorithm::Xz,
+            CompressAlgorithm::Lzma,
+            CompressAlgorithm::Lz4,
+            CompressAlgorithm::Snappy,
+        ] {
+            let compressed = CompressCodec::from(algo).compress_all(&content)?;
+            let truncated = &compressed[..compressed.len() / 2];
+            let mut decoder = DecompressDecoder::new(algo);
+            assert!(
+                decoder.decompress_all(truncated).is_err(),
+                "truncated {algo:?} should not be decompressed"
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -14,6 +14,7 @@
 
 //! This mod provides compress support for BytesWrite and decompress support for BytesRead.
 
+mod buffered;
 mod compress_algorithms;
 mod decode;
 mod encode;
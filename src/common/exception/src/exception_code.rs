@@ -100,6 +100,7 @@ build_exceptions! {
     TooManyUserConnections(1041),
     AbortedSession(1042),
     AbortedQuery(1043),
+    QueryQueueTimeout(1044),
     CannotListenerPort(1045),
     BadBytes(1046),
     InitPrometheusFailure(1047),
@@ -127,6 +128,7 @@ build_exceptions! {
     InvalidTimestamp(1080),
     InvalidClusterKeys(1081),
     UnknownFragmentExchange(1082),
+    CorruptedCompressedFile(1083),
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
     LayoutError(1103),
@@ -237,6 +239,14 @@ build_exceptions! {
     InvalidPassword(2215),
     RoleAlreadyExists(2216),
     IllegalRole(2217),
+    UnknownNotificationIntegration(2218),
+    NotificationIntegrationAlreadyExists(2219),
+    IllegalNotificationIntegration(2220),
+    UnknownTask(2221),
+    TaskAlreadyExists(2222),
+    IllegalTask(2223),
+    UnknownMigration(2224),
+    IllegalMigration(2225),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -22,6 +22,7 @@ use databend_common_base::mem_allocator::MmapAllocator;
 
 use super::traits::HashJoinHashtableLike;
 use super::traits::Keyable;
+use crate::table0::record_hashtable_bytes;
 
 #[derive(Clone, Copy, Debug)]
 pub struct RowPtr {
@@ -112,6 +113,12 @@ unsafe impl<K: Keyable + Send, A: Allocator + Clone + Send> Send for HashJoinHas
 
 unsafe impl<K: Keyable + Sync, A: Allocator + Clone + Sync> Sync for HashJoinHashTable<K, A> {}
 
+impl<K: Keyable, A: Allocator + Clone> Drop for HashJoinHashTable<K, A> {
+    fn drop(&mut self) {
+        record_hashtable_bytes(-(std::mem::size_of_val(self.pointers.as_ref()) as i64));
+    }
+}
+
 impl<K: Keyable, A: Allocator + Clone + Default> HashJoinHashTable<K, A> {
     pub fn with_build_row_num(row_num: usize) -> Self {
         let capacity = std::cmp::max((row_num * 2).next_power_of_two(), 1 << 10);
@@ -126,6 +133,7 @@ impl<K: Keyable, A: Allocator + Clone + Default> HashJoinHashTable<K, A> {
         hashtable.atomic_pointers = unsafe {
             std::mem::transmute::<*mut u64, *mut AtomicU64>(hashtable.pointers.as_mut_ptr())
         };
+        record_hashtable_bytes(std::mem::size_of_val(hashtable.pointers.as_ref()) as i64);
         hashtable
     }
 
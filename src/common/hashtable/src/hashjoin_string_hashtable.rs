@@ -25,6 +25,7 @@ use crate::hashjoin_hashtable::early_filtering;
 use crate::hashjoin_hashtable::hash_bits;
 use crate::hashjoin_hashtable::new_header;
 use crate::hashjoin_hashtable::remove_header_tag;
+use crate::table0::record_hashtable_bytes;
 use crate::traits::hash_join_fast_string_hash;
 use crate::RowPtr;
 
@@ -47,6 +48,12 @@ unsafe impl<A: Allocator + Clone + Send> Send for HashJoinStringHashTable<A> {}
 
 unsafe impl<A: Allocator + Clone + Sync> Sync for HashJoinStringHashTable<A> {}
 
+impl<A: Allocator + Clone> Drop for HashJoinStringHashTable<A> {
+    fn drop(&mut self) {
+        record_hashtable_bytes(-(std::mem::size_of_val(self.pointers.as_ref()) as i64));
+    }
+}
+
 impl<A: Allocator + Clone + Default> HashJoinStringHashTable<A> {
     pub fn with_build_row_num(row_num: usize) -> Self {
         let capacity = std::cmp::max((row_num * 2).next_power_of_two(), 1 << 10);
@@ -60,6 +67,7 @@ impl<A: Allocator + Clone + Default> HashJoinStringHashTable<A> {
         hashtable.atomic_pointers = unsafe {
             std::mem::transmute::<*mut u64, *mut AtomicU64>(hashtable.pointers.as_mut_ptr())
         };
+        record_hashtable_bytes(std::mem::size_of_val(hashtable.pointers.as_ref()) as i64);
         hashtable
     }
 
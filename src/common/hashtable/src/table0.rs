@@ -16,6 +16,9 @@ use std::alloc::Allocator;
 use std::intrinsics::assume;
 use std::mem::MaybeUninit;
 
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageRegistry;
+
 use super::container::Container;
 use super::traits::EntryMutRefLike;
 use super::traits::EntryRefLike;
@@ -62,6 +65,13 @@ impl<K: Keyable, V> Entry<K, V> {
     }
 }
 
+/// Reports the bytes of the buckets of a hash table, which are all used since they are zeroed
+/// when allocated.
+#[inline]
+pub(crate) fn record_hashtable_bytes(bytes: i64) {
+    MemoryUsageRegistry::instance().record(MemoryComponent::HashTables, bytes, bytes);
+}
+
 pub struct Table0<K, V, C, A>
 where
     K: Keyable,
@@ -82,7 +92,7 @@ where
     A: Allocator + Clone,
 {
     pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
-        Self {
+        let table = Self {
             entries: unsafe {
                 C::new_zeroed(
                     std::cmp::max(8, capacity.next_power_of_two()),
@@ -92,7 +102,9 @@ where
             len: 0,
             allocator,
             dropped: false,
-        }
+        };
+        record_hashtable_bytes(table.heap_bytes() as i64);
+        table
     }
 
     #[inline(always)]
@@ -239,7 +251,9 @@ where
                 }
             }
 
+            let heap_bytes = self.heap_bytes();
             self.entries = C::new_zeroed(0, self.allocator.clone());
+            record_hashtable_bytes(self.heap_bytes() as i64 - heap_bytes as i64);
         }
     }
 
@@ -257,9 +271,11 @@ where
     pub fn grow(&mut self, shift: u8) {
         let old_capacity = self.entries.len();
         let new_capacity = self.entries.len() << shift;
+        let heap_bytes = self.heap_bytes();
         unsafe {
             self.entries.grow_zeroed(new_capacity);
         }
+        record_hashtable_bytes(self.heap_bytes() as i64 - heap_bytes as i64);
         for i in 0..old_capacity {
             unsafe {
                 assume(i < self.entries.len());
@@ -343,6 +359,7 @@ where
     A: Allocator + Clone,
 {
     fn drop(&mut self) {
+        record_hashtable_bytes(-(self.heap_bytes() as i64));
         if std::mem::needs_drop::<V>() && !self.dropped {
             unsafe {
                 for entry in self.entries.as_mut() {
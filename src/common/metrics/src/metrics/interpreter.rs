@@ -39,6 +39,7 @@ const METRIC_QUERY_SCAN_PARTITIONS: &str = "query_scan_partitions";
 const METRIC_QUERY_TOTAL_PARTITIONS: &str = "query_total_partitions";
 const METRIC_QUERY_RESULT_ROWS: &str = "query_result_rows";
 const METRIC_QUERY_RESULT_BYTES: &str = "query_result_bytes";
+const METRIC_QUERY_RECEIVED_BYTES: &str = "query_received_bytes";
 
 pub static QUERY_START: LazyLock<Family<VecLabels, Counter>> =
     LazyLock::new(|| register_counter_family(METRIC_QUERY_START));
@@ -76,3 +77,5 @@ pub static QUERY_RESULT_ROWS: LazyLock<Family<VecLabels, Counter>> =
     LazyLock::new(|| register_counter_family(METRIC_QUERY_RESULT_ROWS));
 pub static QUERY_RESULT_BYTES: LazyLock<Family<VecLabels, Counter>> =
     LazyLock::new(|| register_counter_family(METRIC_QUERY_RESULT_BYTES));
+pub static QUERY_RECEIVED_BYTES: LazyLock<Family<VecLabels, Counter>> =
+    LazyLock::new(|| register_counter_family(METRIC_QUERY_RECEIVED_BYTES));
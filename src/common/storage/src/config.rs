@@ -24,6 +24,8 @@ use databend_common_meta_app::storage::StorageParams;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::S3ReplicaConfig;
+
 /// Config for storage backend.
 ///
 /// # TODO(xuanwo)
@@ -39,13 +41,47 @@ use serde::Serialize;
 /// [storage.temporary]
 /// type = "s3"
 /// ```
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
+    /// Allow the external stages to load the credentials from the environment of the node.
+    pub allow_credential_chain: bool,
+    /// Local directory to cache the objects read from remote storage, cleared on startup.
+    pub cache_dir: String,
+    /// Max size in GB of the local storage cache, 0 means disabled.
+    pub cache_max_size_gb: u64,
+    /// Max retry times of the requests to S3 compatible storages.
+    pub s3_max_retries: u64,
+    /// The backoff in milliseconds of the first retry.
+    pub s3_retry_initial_delay_ms: u64,
+    /// Total duration in seconds of the retries of a request.
+    pub s3_request_timeout_secs: u64,
+    /// The region of this node, the S3 buckets in the other regions are read from their replicas.
+    pub s3_local_region: String,
+    /// Replicas of the S3 buckets in the other regions.
+    pub s3_replicas: Vec<S3ReplicaConfig>,
     pub params: StorageParams,
 }
 
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig {
+            num_cpus: 0,
+            allow_insecure: false,
+            allow_credential_chain: false,
+            cache_dir: "".to_string(),
+            cache_max_size_gb: 0,
+            s3_max_retries: 3,
+            s3_retry_initial_delay_ms: 1000,
+            s3_request_timeout_secs: 60,
+            s3_local_region: "".to_string(),
+            s3_replicas: vec![],
+            params: Default::default(),
+        }
+    }
+}
+
 // TODO: This config should be moved out of common-storage crate.
 #[derive(Clone)]
 pub struct ShareTableConfig {
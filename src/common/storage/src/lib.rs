@@ -36,8 +36,11 @@ pub use config::ShareTableConfig;
 pub use config::StorageConfig;
 
 mod operator;
+mod placement;
 pub use operator::init_operator;
 pub use operator::DataOperator;
+pub use placement::DataPlacementPolicy;
+pub use placement::S3ReplicaConfig;
 
 pub mod metrics;
 pub use crate::metrics::StorageMetrics;
@@ -45,6 +48,13 @@ pub use crate::metrics::StorageMetricsLayer;
 
 mod runtime_layer;
 
+mod retry_layer;
+pub use retry_layer::S3RetryConfig;
+pub use retry_layer::S3RetryLayer;
+
+mod local_cache_layer;
+pub use local_cache_layer::LocalCacheLayer;
+
 mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
@@ -59,7 +69,9 @@ pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
 mod stage;
+pub use stage::file_format_type_of_extension;
 pub use stage::init_stage_operator;
+pub use stage::init_stage_read_operator;
 pub use stage::StageFileInfo;
 pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
@@ -0,0 +1,477 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fs::File;
+use std::io;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use databend_common_cache::Cache;
+use databend_common_cache::DefaultHashBuilder;
+use databend_common_cache::LruCache;
+use databend_common_cache::Meter;
+use databend_common_metrics::cache::metrics_inc_cache_access_count;
+use databend_common_metrics::cache::metrics_inc_cache_hit_count;
+use databend_common_metrics::cache::metrics_inc_cache_miss_count;
+use log::warn;
+use opendal::raw::oio;
+use opendal::raw::Accessor;
+use opendal::raw::BytesRange;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpWrite;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpWrite;
+use opendal::Result;
+
+const LOCAL_CACHE_NAME: &str = "storage_local_cache";
+
+/// The directories of the objects that are never changed once written, i.e., the snapshots,
+/// segments, blocks and indexes of the fuse tables, which are named by uuid.
+const IMMUTABLE_DIRS: &[&str] = &["_ss", "_sg", "_b", "_ts", "_vb", "_i_b_v2", "_i_a"];
+
+/// LocalCacheLayer caches the objects read from remote storage in a local directory.
+///
+/// The first read of an object range streams it from the remote storage and writes it
+/// to the local directory at the same time, following reads of the same range are served
+/// from the local file. The cached files are evicted in LRU order once their total size
+/// exceeds the capacity.
+///
+/// Only the immutable objects written by databend are cached, since the cache can not see
+/// the changes made by other nodes. The others, such as the last snapshot hint of a table
+/// and the files of stages, are always read from the remote storage. All of the cached
+/// ranges of an object are evicted when it is overwritten or deleted by this node.
+#[derive(Clone)]
+pub struct LocalCacheLayer {
+    cache: Arc<Mutex<DiskCache>>,
+}
+
+impl Debug for LocalCacheLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalCacheLayer")
+            .field("path", &self.cache.lock().unwrap().dir)
+            .finish()
+    }
+}
+
+impl LocalCacheLayer {
+    pub fn try_create(path: &str, capacity_bytes: u64) -> databend_common_exception::Result<Self> {
+        let cache = DiskCache::try_create(PathBuf::from(path), capacity_bytes)?;
+        Ok(LocalCacheLayer {
+            cache: Arc::new(Mutex::new(cache)),
+        })
+    }
+}
+
+impl<A: Accessor> Layer<A> for LocalCacheLayer {
+    type LayeredAccessor = LocalCacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        LocalCacheAccessor {
+            inner: Arc::new(inner),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LocalCacheAccessor<A> {
+    inner: Arc<A>,
+    cache: Arc<Mutex<DiskCache>>,
+}
+
+impl<A: Debug> Debug for LocalCacheAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalCacheAccessor")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<A> LocalCacheAccessor<A> {
+    /// The ranges of the same object are cached separately, since the column chunks of
+    /// a block are always read with the same ranges.
+    fn cache_key(path: &str, range: BytesRange) -> String {
+        match (range.offset(), range.size()) {
+            (None, None) => path.to_string(),
+            (offset, size) => format!(
+                "{path}-{}-{}",
+                offset.map_or_else(String::new, |v| v.to_string()),
+                size.map_or_else(String::new, |v| v.to_string())
+            ),
+        }
+    }
+
+    fn is_immutable(path: &str) -> bool {
+        let mut parts = path.trim_end_matches('/').rsplit('/');
+        // The last part is the name of the object.
+        parts.next();
+        parts
+            .next()
+            .is_some_and(|dir| IMMUTABLE_DIRS.contains(&dir))
+    }
+
+    fn evict(&self, path: &str) {
+        self.cache.lock().unwrap().evict(path);
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for LocalCacheAccessor<A> {
+    type Inner = A;
+    type Reader = LocalCacheReader<A::Reader>;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        // Conditional reads must be checked by the remote storage.
+        if !Self::is_immutable(path) || args.if_match().is_some() || args.if_none_match().is_some()
+        {
+            let (rp, r) = self.inner.read(path, args).await?;
+            return Ok((rp, LocalCacheReader::Inner(r)));
+        }
+
+        let key = Self::cache_key(path, args.range());
+        metrics_inc_cache_access_count(1, LOCAL_CACHE_NAME);
+
+        let cached_file = self.cache.lock().unwrap().get(&key);
+        // The file may be evicted after it is got, it is read again from the remote storage.
+        if let Some(Ok(data)) = cached_file.map(std::fs::read) {
+            metrics_inc_cache_hit_count(1, LOCAL_CACHE_NAME);
+            let size = data.len() as u64;
+            return Ok((
+                RpRead::new().with_size(Some(size)),
+                LocalCacheReader::Cached(oio::Cursor::from(Bytes::from(data))),
+            ));
+        }
+
+        metrics_inc_cache_miss_count(1, LOCAL_CACHE_NAME);
+        let (rp, r) = self.inner.read(path, args).await?;
+        let (temp_file, capacity) = {
+            let mut cache = self.cache.lock().unwrap();
+            // Objects larger than the capacity are passed through.
+            let file = match rp.size() {
+                Some(size) if size > cache.capacity => None,
+                _ => cache.create_file(),
+            };
+            (file, cache.capacity)
+        };
+        let reader = match temp_file {
+            Some(file) => LocalCacheReader::Filling(FillingReader {
+                inner: r,
+                cache: self.cache.clone(),
+                path: path.to_string(),
+                key,
+                file: Some(file),
+                size: 0,
+                expected_size: rp.size(),
+                capacity,
+            }),
+            None => LocalCacheReader::Inner(r),
+        };
+        Ok((rp, reader))
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.evict(path);
+        self.inner.write(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.evict(path);
+        self.inner.delete(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.evict(path);
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub enum LocalCacheReader<R> {
+    Cached(oio::Cursor),
+    Inner(R),
+    Filling(FillingReader<R>),
+}
+
+impl<R: oio::Read> oio::Read for LocalCacheReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        match self {
+            LocalCacheReader::Cached(r) => r.poll_read(cx, buf),
+            LocalCacheReader::Inner(r) => r.poll_read(cx, buf),
+            LocalCacheReader::Filling(r) => r.poll_read(cx, buf),
+        }
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        match self {
+            LocalCacheReader::Cached(r) => r.poll_seek(cx, pos),
+            LocalCacheReader::Inner(r) => r.poll_seek(cx, pos),
+            LocalCacheReader::Filling(r) => r.poll_seek(cx, pos),
+        }
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match self {
+            LocalCacheReader::Cached(r) => r.poll_next(cx),
+            LocalCacheReader::Inner(r) => r.poll_next(cx),
+            LocalCacheReader::Filling(r) => r.poll_next(cx),
+        }
+    }
+}
+
+/// Passes the data read from the remote storage through, and writes it to a file of the
+/// cache at the same time. The file is added to the cache once the range is read to the end,
+/// without waiting for the end of the stream if the size of the range is known.
+///
+/// The file is dropped if the reader seeks, or is dropped before the end, or if the range
+/// turns out to be larger than the capacity.
+pub struct FillingReader<R> {
+    inner: R,
+    cache: Arc<Mutex<DiskCache>>,
+    path: String,
+    key: String,
+    file: Option<(PathBuf, File)>,
+    size: u64,
+    /// The size of the range if it is known, the file is added once it is read.
+    expected_size: Option<u64>,
+    capacity: u64,
+}
+
+impl<R> FillingReader<R> {
+    fn fill(&mut self, data: &[u8]) {
+        let Some((_, file)) = &mut self.file else {
+            return;
+        };
+
+        self.size += data.len() as u64;
+        let res = if self.size > self.capacity {
+            Ok(false)
+        } else {
+            file.write_all(data).map(|_| true)
+        };
+        match res {
+            Ok(true) if Some(self.size) == self.expected_size => self.finish(),
+            Ok(true) => {}
+            Ok(false) => self.give_up(),
+            Err(e) => {
+                warn!("fail to write local cache of {}: {}", self.path, e);
+                self.give_up();
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Some((file_path, file)) = self.file.take() {
+            drop(file);
+            self.cache
+                .lock()
+                .unwrap()
+                .insert(&self.path, &self.key, file_path, self.size);
+        }
+    }
+
+    fn give_up(&mut self) {
+        if let Some((file_path, _)) = self.file.take() {
+            let _ = std::fs::remove_file(file_path);
+        }
+    }
+}
+
+impl<R> Drop for FillingReader<R> {
+    fn drop(&mut self) {
+        self.give_up();
+    }
+}
+
+impl<R: oio::Read> oio::Read for FillingReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_read(cx, buf))?;
+        if n == 0 && !buf.is_empty() {
+            self.finish();
+        } else {
+            self.fill(&buf[..n]);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        self.give_up();
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match ready!(self.inner.poll_next(cx)) {
+            Some(Ok(bs)) => {
+                self.fill(&bs);
+                Poll::Ready(Some(Ok(bs)))
+            }
+            Some(Err(e)) => Poll::Ready(Some(Err(e))),
+            None => {
+                self.finish();
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+struct CachedRange {
+    path: String,
+    file: PathBuf,
+    size: u64,
+}
+
+struct CachedRangeSize;
+
+impl Meter<String, CachedRange> for CachedRangeSize {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &CachedRange) -> usize
+    where String: std::borrow::Borrow<Q> {
+        v.size as usize
+    }
+}
+
+/// The cached ranges in the local directory, which is cleared when it is created.
+struct DiskCache {
+    dir: PathBuf,
+    capacity: u64,
+    next_file_id: u64,
+    ranges: LruCache<String, CachedRange, DefaultHashBuilder, CachedRangeSize>,
+    /// The cache keys of the ranges of each object.
+    objects: HashMap<String, HashSet<String>>,
+}
+
+impl DiskCache {
+    fn try_create(dir: PathBuf, capacity: u64) -> io::Result<Self> {
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+        std::fs::create_dir_all(&dir)?;
+
+        Ok(DiskCache {
+            dir,
+            capacity,
+            next_file_id: 0,
+            ranges: LruCache::with_meter(capacity, CachedRangeSize),
+            objects: HashMap::new(),
+        })
+    }
+
+    fn get(&mut self, key: &str) -> Option<PathBuf> {
+        self.ranges.get(key).map(|range| range.file.clone())
+    }
+
+    fn create_file(&mut self) -> Option<(PathBuf, File)> {
+        self.next_file_id += 1;
+        let file_path = self.dir.join(self.next_file_id.to_string());
+        match File::create(&file_path) {
+            Ok(file) => Some((file_path, file)),
+            Err(e) => {
+                warn!("fail to create local cache file {:?}: {}", file_path, e);
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, path: &str, key: &str, file: PathBuf, size: u64) {
+        if size > self.capacity {
+            remove_file(&file);
+            return;
+        }
+        while self.ranges.size() + size > self.capacity {
+            match self.ranges.pop_by_policy() {
+                Some((key, range)) => self.remove(&key, range),
+                None => break,
+            }
+        }
+
+        let range = CachedRange {
+            path: path.to_string(),
+            file,
+            size,
+        };
+        if let Some(old) = self.ranges.put(key.to_string(), range) {
+            remove_file(&old.file);
+        }
+        self.objects
+            .entry(path.to_string())
+            .or_default()
+            .insert(key.to_string());
+    }
+
+    fn evict(&mut self, path: &str) {
+        for key in self.objects.remove(path).unwrap_or_default() {
+            if let Some(range) = self.ranges.pop(&key) {
+                remove_file(&range.file);
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &str, range: CachedRange) {
+        remove_file(&range.file);
+        if let Some(keys) = self.objects.get_mut(&range.path) {
+            keys.remove(key);
+            if keys.is_empty() {
+                self.objects.remove(&range.path);
+            }
+        }
+    }
+}
+
+fn remove_file(file: &Path) {
+    if let Err(e) = std::fs::remove_file(file) {
+        warn!("fail to remove local cache file {:?}: {}", file, e);
+    }
+}
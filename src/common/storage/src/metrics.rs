@@ -35,6 +35,25 @@ use opendal::raw::RpRead;
 use opendal::raw::RpWrite;
 use opendal::Result;
 
+/// The upper bounds(in ms) of the buckets of the read latency histogram, the last bucket
+/// holds the rest.
+const READ_LATENCY_BUCKETS_MS: [u64; 14] = [
+    1,
+    2,
+    5,
+    10,
+    20,
+    50,
+    100,
+    200,
+    500,
+    1000,
+    2000,
+    5000,
+    10000,
+    u64::MAX,
+];
+
 /// StorageMetrics represents the metrics of storage (all bytes metrics are compressed size).
 #[derive(Debug, Default)]
 pub struct StorageMetrics {
@@ -46,6 +65,12 @@ pub struct StorageMetrics {
     write_bytes: AtomicUsize,
     /// Cost(in ms) of write bytes.
     write_bytes_cost_ms: AtomicU64,
+    /// Number of read requests.
+    read_requests: AtomicU64,
+    /// Number of write requests.
+    write_requests: AtomicU64,
+    /// Number of read requests in each bucket of `READ_LATENCY_BUCKETS_MS`.
+    read_latency_buckets: [AtomicU64; READ_LATENCY_BUCKETS_MS.len()],
     /// Number of partitions scanned, after pruning
     partitions_scanned: AtomicU64,
     /// Number of partitions, before pruning
@@ -70,6 +95,17 @@ impl StorageMetrics {
             partitions_total: AtomicU64::new(
                 vs.iter().map(|v| v.as_ref().get_partitions_total()).sum(),
             ),
+            read_requests: AtomicU64::new(vs.iter().map(|v| v.as_ref().get_read_requests()).sum()),
+            write_requests: AtomicU64::new(
+                vs.iter().map(|v| v.as_ref().get_write_requests()).sum(),
+            ),
+            read_latency_buckets: std::array::from_fn(|i| {
+                AtomicU64::new(
+                    vs.iter()
+                        .map(|v| v.as_ref().read_latency_buckets[i].load(Ordering::Relaxed))
+                        .sum(),
+                )
+            }),
         }
     }
 
@@ -132,6 +168,47 @@ impl StorageMetrics {
     pub fn get_partitions_total(&self) -> u64 {
         self.partitions_total.load(Ordering::Relaxed)
     }
+
+    pub fn inc_read_requests(&self, latency_ms: u64) {
+        self.read_requests.fetch_add(1, Ordering::Relaxed);
+        let bucket = READ_LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| latency_ms <= *bound)
+            .unwrap_or(READ_LATENCY_BUCKETS_MS.len() - 1);
+        self.read_latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn get_read_requests(&self) -> u64 {
+        self.read_requests.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_write_requests(&self) {
+        self.write_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn get_write_requests(&self) -> u64 {
+        self.write_requests.load(Ordering::Relaxed)
+    }
+
+    /// The 99th percentile of the read latency(in ms), rounded up to the bound of its bucket.
+    ///
+    /// Returns the largest latency recorded if it is beyond the last bound.
+    pub fn get_read_latency_p99_ms(&self) -> u64 {
+        let total = self.get_read_requests();
+        if total == 0 {
+            return 0;
+        }
+
+        let rank = (total * 99).div_ceil(100);
+        let mut count = 0;
+        for (bucket, bound) in READ_LATENCY_BUCKETS_MS.iter().enumerate() {
+            count += self.read_latency_buckets[bucket].load(Ordering::Relaxed);
+            if count >= rank {
+                return *bound;
+            }
+        }
+        READ_LATENCY_BUCKETS_MS[READ_LATENCY_BUCKETS_MS.len() - 1]
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -179,14 +256,16 @@ impl<A: Accessor> LayeredAccessor for StorageMetricsAccessor<A> {
 
     #[async_backtrace::framed]
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-        self.inner
-            .read(path, args)
-            .await
-            .map(|(rp, r)| (rp, StorageMetricsWrapper::new(r, self.metrics.clone())))
+        let start = Instant::now();
+        let result = self.inner.read(path, args).await;
+        self.metrics
+            .inc_read_requests(start.elapsed().as_millis() as u64);
+        result.map(|(rp, r)| (rp, StorageMetricsWrapper::new(r, self.metrics.clone())))
     }
 
     #[async_backtrace::framed]
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.metrics.inc_write_requests();
         self.inner
             .write(path, args)
             .await
@@ -199,12 +278,15 @@ impl<A: Accessor> LayeredAccessor for StorageMetricsAccessor<A> {
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        self.inner
-            .blocking_read(path, args)
-            .map(|(rp, r)| (rp, StorageMetricsWrapper::new(r, self.metrics.clone())))
+        let start = Instant::now();
+        let result = self.inner.blocking_read(path, args);
+        self.metrics
+            .inc_read_requests(start.elapsed().as_millis() as u64);
+        result.map(|(rp, r)| (rp, StorageMetricsWrapper::new(r, self.metrics.clone())))
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.metrics.inc_write_requests();
         self.inner
             .blocking_write(path, args)
             .map(|(rp, r)| (rp, StorageMetricsWrapper::new(r, self.metrics.clone())))
@@ -266,7 +348,15 @@ impl<R: oio::Read> oio::Read for StorageMetricsWrapper<R> {
 
 impl<R: oio::BlockingRead> oio::BlockingRead for StorageMetricsWrapper<R> {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
-        self.inner.read(buf)
+        let start = Instant::now();
+
+        let result = self.inner.read(buf);
+        if let Ok(size) = result {
+            self.metrics.inc_read_bytes(size);
+            self.metrics
+                .inc_read_bytes_cost(start.elapsed().as_millis() as u64);
+        }
+        result
     }
 
     fn seek(&mut self, pos: io::SeekFrom) -> Result<u64> {
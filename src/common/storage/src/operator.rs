@@ -53,12 +53,18 @@ use opendal::layers::MinitraceLayer;
 use opendal::layers::PrometheusClientLayer;
 use opendal::layers::RetryLayer;
 use opendal::layers::TimeoutLayer;
+use opendal::raw::FusedAccessor;
 use opendal::raw::HttpClient;
+use opendal::raw::Layer;
 use opendal::services;
 use opendal::Builder;
 use opendal::Operator;
 use reqwest_hickory_resolver::HickoryResolver;
 
+use crate::local_cache_layer::LocalCacheLayer;
+use crate::placement::DataPlacementPolicy;
+use crate::retry_layer::S3RetryConfig;
+use crate::retry_layer::S3RetryLayer;
 use crate::runtime_layer::RuntimeLayer;
 use crate::StorageConfig;
 
@@ -84,7 +90,10 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
         StorageParams::Memory => build_operator(init_memory_operator()?)?,
         StorageParams::Moka(cfg) => build_operator(init_moka_operator(cfg)?)?,
         StorageParams::Obs(cfg) => build_operator(init_obs_operator(cfg)?)?,
-        StorageParams::S3(cfg) => build_operator(init_s3_operator(cfg)?)?,
+        StorageParams::S3(cfg) => build_operator_with_retry(
+            init_s3_operator(cfg)?,
+            S3RetryLayer::new(S3RetryConfig::instance()),
+        )?,
         StorageParams::Oss(cfg) => build_operator(init_oss_operator(cfg)?)?,
         StorageParams::Webhdfs(cfg) => build_operator(init_webhdfs_operator(cfg)?)?,
         StorageParams::Cos(cfg) => build_operator(init_cos_operator(cfg)?)?,
@@ -101,6 +110,14 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
 }
 
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
+    build_operator_with_retry(builder, RetryLayer::new().with_jitter())
+}
+
+/// build_operator_with_retry() builds the operator with the given retry layer instead of the default one.
+fn build_operator_with_retry<B: Builder, R: Layer<FusedAccessor>>(
+    builder: B,
+    retry: R,
+) -> Result<Operator> {
     let ob = Operator::new(builder)?;
 
     let op = ob
@@ -135,8 +152,9 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
 
             timeout_layer
         })
+        .finish()
         // Add retry
-        .layer(RetryLayer::new().with_jitter())
+        .layer(retry)
         // Add async backtrace
         .layer(AsyncBacktraceLayer)
         // Add logging
@@ -148,11 +166,11 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
 
     if let Ok(permits) = env::var("_DATABEND_INTERNAL_MAX_CONCURRENT_IO_REQUEST") {
         if let Ok(permits) = permits.parse::<usize>() {
-            return Ok(op.layer(ConcurrentLimitLayer::new(permits)).finish());
+            return Ok(op.layer(ConcurrentLimitLayer::new(permits)));
         }
     }
 
-    Ok(op.finish())
+    Ok(op)
 }
 
 /// build_operator() can be called multiple times, it would be dangerous to register the opendal metrics
@@ -452,7 +470,21 @@ impl DataOperator {
 
     #[async_backtrace::framed]
     pub async fn init(conf: &StorageConfig) -> databend_common_exception::Result<()> {
-        GlobalInstance::set(Self::try_create(&conf.params).await?);
+        S3RetryConfig::init(conf);
+        DataPlacementPolicy::init(conf);
+        let mut data_operator = Self::try_create(&conf.params).await?;
+
+        // Local disk is fast enough, only the remote storage needs a local cache.
+        let is_local = matches!(conf.params, StorageParams::Fs(_) | StorageParams::Memory);
+        if conf.cache_max_size_gb > 0 && !conf.cache_dir.is_empty() && !is_local {
+            let layer = LocalCacheLayer::try_create(
+                &conf.cache_dir,
+                conf.cache_max_size_gb * 1024 * 1024 * 1024,
+            )?;
+            data_operator.operator = data_operator.operator.layer(layer);
+        }
+
+        GlobalInstance::set(data_operator);
 
         Ok(())
     }
@@ -28,6 +28,9 @@ use parquet::file::metadata::FileMetaData;
 use parquet::file::metadata::ParquetMetaData;
 
 const FOOTER_SIZE: u64 = 8;
+/// The magic number ending the parquet files whose footer is encrypted by
+/// Parquet Modular Encryption.
+const ENCRYPTED_FOOTER_MAGIC: &[u8; 4] = b"PARE";
 /// The number of bytes read at the end of the parquet file on first read
 const DEFAULT_FOOTER_READ_SIZE: u64 = 64 * 1024;
 
@@ -102,6 +105,7 @@ pub async fn read_metadata_async(
         .range((file_size - default_end_len)..file_size)
         .await?;
     let buffer_len = buffer.len();
+    check_not_encrypted(path, &buffer)?;
     let metadata_len = decode_footer(
         &buffer[(buffer_len - FOOTER_SIZE as usize)..]
             .try_into()
@@ -139,6 +143,19 @@ fn check_footer_size(file_size: u64) -> Result<()> {
     }
 }
 
+/// The modular encryption of parquet is not supported, the parquet crate can't decrypt the
+/// footer or the column chunks.
+fn check_not_encrypted(path: &str, buffer: &[u8]) -> Result<()> {
+    if buffer.ends_with(ENCRYPTED_FOOTER_MAGIC) {
+        Err(ErrorCode::Unimplemented(format!(
+            "Parquet file '{}' is encrypted by Parquet Modular Encryption, which is not supported yet",
+            path
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 /// check file is large enough to hold metadata
 fn check_meta_size(file_size: u64, metadata_len: u64) -> Result<()> {
     if metadata_len + FOOTER_SIZE > file_size {
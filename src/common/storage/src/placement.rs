@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
+use log::info;
+use log::warn;
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::StorageConfig;
+
+static DATA_PLACEMENT_POLICY: OnceCell<DataPlacementPolicy> = OnceCell::new();
+
+/// A replica of an S3 bucket in another region, such as the destination of S3 replication.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct S3ReplicaConfig {
+    /// The bucket to be replicated.
+    pub bucket: String,
+    /// The region of the replica.
+    pub region: String,
+    /// The bucket of the replica.
+    pub replica_bucket: String,
+    /// The endpoint of the replica, the endpoint of the replicated bucket is used if empty.
+    pub replica_endpoint_url: String,
+}
+
+/// DataPlacementPolicy decides the region to read an S3 bucket from.
+///
+/// The bucket is read from the preferred region of the stage, or the local region of
+/// this node if the stage doesn't prefer any. If the bucket is in another region,
+/// the read is routed to the replica of the bucket in the preferred region, or a
+/// warning is emitted if there is no such replica.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataPlacementPolicy {
+    local_region: String,
+    replicas: Vec<S3ReplicaConfig>,
+}
+
+impl DataPlacementPolicy {
+    pub fn create(local_region: String, replicas: Vec<S3ReplicaConfig>) -> Self {
+        DataPlacementPolicy {
+            local_region,
+            replicas,
+        }
+    }
+
+    /// Set the policy used by the stage read operators, only the first call takes effect.
+    pub fn init(conf: &StorageConfig) {
+        let _ = DATA_PLACEMENT_POLICY.set(DataPlacementPolicy::create(
+            conf.s3_local_region.clone(),
+            conf.s3_replicas.clone(),
+        ));
+    }
+
+    pub fn instance() -> DataPlacementPolicy {
+        DATA_PLACEMENT_POLICY.get().cloned().unwrap_or_default()
+    }
+
+    /// Returns the storage params to read the data from.
+    pub fn select(&self, params: &StorageParams) -> StorageParams {
+        match params {
+            StorageParams::S3(cfg) => StorageParams::S3(self.select_s3(cfg)),
+            _ => params.clone(),
+        }
+    }
+
+    fn select_s3(&self, cfg: &StorageS3Config) -> StorageS3Config {
+        let preferred_region = if cfg.preferred_region.is_empty() {
+            &self.local_region
+        } else {
+            &cfg.preferred_region
+        };
+        // The region of the bucket is unknown if it's not specified.
+        if preferred_region.is_empty() || cfg.region.is_empty() || &cfg.region == preferred_region {
+            return cfg.clone();
+        }
+
+        let replica = self
+            .replicas
+            .iter()
+            .find(|r| r.bucket == cfg.bucket && &r.region == preferred_region);
+        match replica {
+            Some(replica) => {
+                info!(
+                    "read bucket {} in region {} from replica {} in region {}",
+                    cfg.bucket, cfg.region, replica.replica_bucket, replica.region
+                );
+                let mut cfg = cfg.clone();
+                cfg.bucket = replica.replica_bucket.clone();
+                cfg.region = replica.region.clone();
+                if !replica.replica_endpoint_url.is_empty() {
+                    cfg.endpoint_url = replica.replica_endpoint_url.clone();
+                }
+                cfg
+            }
+            None => {
+                warn!(
+                    "bucket {} in region {} is read from region {}, no replica is configured, it may incur high latency",
+                    cfg.bucket, cfg.region, preferred_region
+                );
+                cfg.clone()
+            }
+        }
+    }
+}
@@ -0,0 +1,351 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::tokio::time::Sleep;
+use futures::ready;
+use log::debug;
+use once_cell::sync::OnceCell;
+use opendal::raw::oio;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Error;
+use opendal::Result;
+use rand::Rng;
+
+use crate::StorageConfig;
+
+static S3_RETRY_CONFIG: OnceCell<S3RetryConfig> = OnceCell::new();
+
+/// Config of the retries on the transient errors of S3 compatible storages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct S3RetryConfig {
+    /// Max retry times of a request, 0 means never retry.
+    pub max_retries: u64,
+    /// The backoff of the first retry, it doubles for each following retry.
+    pub initial_delay: Duration,
+    /// Total duration of the retries of a request.
+    pub timeout: Duration,
+}
+
+impl Default for S3RetryConfig {
+    fn default() -> Self {
+        S3RetryConfig {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(1000),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl S3RetryConfig {
+    /// Set the retry config used by all the S3 operators built later, only the first call takes effect.
+    pub fn init(conf: &StorageConfig) {
+        let _ = S3_RETRY_CONFIG.set(S3RetryConfig {
+            max_retries: conf.s3_max_retries,
+            initial_delay: Duration::from_millis(conf.s3_retry_initial_delay_ms),
+            timeout: Duration::from_secs(conf.s3_request_timeout_secs),
+        });
+    }
+
+    pub fn instance() -> S3RetryConfig {
+        S3_RETRY_CONFIG.get().cloned().unwrap_or_default()
+    }
+}
+
+/// S3RetryLayer retries the requests failed with transient errors.
+///
+/// OpenDAL marks throttling (429), service unavailable (503) and the broken
+/// connections (such as connection reset) as temporary errors, which will
+/// be retried with exponential backoff and full jitter:
+///
+/// ```text
+/// delay = random_between(0, initial_delay * 2 ^ attempt)
+/// ```
+///
+/// A request gives up once it reaches the max retry times or the total
+/// duration of the retries exceeds the timeout.
+///
+/// The layer works with any storage, the parquet tables also wrap their
+/// operators with it to retry the reads of the files.
+#[derive(Clone, Debug)]
+pub struct S3RetryLayer {
+    config: Arc<S3RetryConfig>,
+}
+
+impl S3RetryLayer {
+    pub fn new(config: S3RetryConfig) -> Self {
+        S3RetryLayer {
+            config: Arc::new(config),
+        }
+    }
+}
+
+impl<A: Accessor> Layer<A> for S3RetryLayer {
+    type LayeredAccessor = S3RetryAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        S3RetryAccessor {
+            inner,
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// Backoff tracks the retries of one request.
+struct Backoff {
+    config: Arc<S3RetryConfig>,
+    attempts: u64,
+    started: Option<Instant>,
+}
+
+impl Backoff {
+    fn new(config: Arc<S3RetryConfig>) -> Self {
+        Backoff {
+            config,
+            attempts: 0,
+            started: None,
+        }
+    }
+
+    /// Returns the delay before the next retry, or `None` if the error should be returned.
+    fn next_delay(&mut self, err: &Error, path: &str) -> Option<Duration> {
+        if !err.is_temporary() || self.attempts >= self.config.max_retries {
+            return None;
+        }
+
+        let elapsed = self.started.get_or_insert_with(Instant::now).elapsed();
+        let remaining = self.config.timeout.checked_sub(elapsed)?;
+
+        let max_delay = self
+            .config
+            .initial_delay
+            .saturating_mul(2u32.saturating_pow(self.attempts.min(31) as u32))
+            .min(remaining);
+        let delay = rand::thread_rng().gen_range(Duration::ZERO..=max_delay);
+
+        self.attempts += 1;
+        debug!(
+            "retry request of {path} after {delay:?}, attempt: {}, cause: {err}",
+            self.attempts
+        );
+        Some(delay)
+    }
+
+    /// Reset the backoff after a success, the reader and writer share it across their requests.
+    fn reset(&mut self) {
+        self.attempts = 0;
+        self.started = None;
+    }
+}
+
+#[derive(Debug)]
+pub struct S3RetryAccessor<A: Accessor> {
+    inner: A,
+    config: Arc<S3RetryConfig>,
+}
+
+impl<A: Accessor> S3RetryAccessor<A> {
+    async fn retry<T, F, Fut>(&self, path: &str, f: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut backoff = Backoff::new(self.config.clone());
+        loop {
+            match f().await {
+                Ok(v) => return Ok(v),
+                Err(err) => match backoff.next_delay(&err, path) {
+                    Some(delay) => sleep(delay).await,
+                    None => return Err(err),
+                },
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for S3RetryAccessor<A> {
+    type Inner = A;
+    type Reader = S3RetryWrapper<A::Reader>;
+    type BlockingReader = A::BlockingReader;
+    type Writer = S3RetryWrapper<A::Writer>;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = S3RetryWrapper<A::Lister>;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.retry(path, || self.inner.create_dir(path, args.clone()))
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.retry(path, || self.inner.read(path, args.clone()))
+            .await
+            .map(|(rp, r)| (rp, S3RetryWrapper::new(r, path, self.config.clone())))
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.retry(path, || self.inner.write(path, args.clone()))
+            .await
+            .map(|(rp, r)| (rp, S3RetryWrapper::new(r, path, self.config.clone())))
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.retry(path, || self.inner.stat(path, args.clone()))
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.retry(path, || self.inner.delete(path, args.clone()))
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.retry(path, || self.inner.list(path, args.clone()))
+            .await
+            .map(|(rp, r)| (rp, S3RetryWrapper::new(r, path, self.config.clone())))
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// S3RetryWrapper retries the failed IO of the readers, writers and listers.
+pub struct S3RetryWrapper<R> {
+    inner: R,
+    path: String,
+    backoff: Backoff,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<R> S3RetryWrapper<R> {
+    fn new(inner: R, path: &str, config: Arc<S3RetryConfig>) -> Self {
+        S3RetryWrapper {
+            inner,
+            path: path.to_string(),
+            backoff: Backoff::new(config),
+            sleep: None,
+        }
+    }
+
+    fn poll_retry<T>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut f: impl FnMut(&mut R, &mut Context<'_>) -> Poll<Result<T>>,
+    ) -> Poll<Result<T>> {
+        loop {
+            if let Some(sleep) = self.sleep.as_mut() {
+                ready!(sleep.as_mut().poll(cx));
+                self.sleep = None;
+            }
+
+            match ready!(f(&mut self.inner, cx)) {
+                Ok(v) => {
+                    self.backoff.reset();
+                    return Poll::Ready(Ok(v));
+                }
+                Err(err) => match self.backoff.next_delay(&err, &self.path) {
+                    Some(delay) => self.sleep = Some(Box::pin(sleep(delay))),
+                    None => {
+                        self.backoff.reset();
+                        return Poll::Ready(Err(err));
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for S3RetryWrapper<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        self.poll_retry(cx, |r, cx| r.poll_read(cx, buf))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        self.poll_retry(cx, |r, cx| r.poll_seek(cx, pos))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        self.poll_retry(cx, |r, cx| match ready!(r.poll_next(cx)) {
+            Some(Ok(bs)) => Poll::Ready(Ok(Some(bs))),
+            Some(Err(err)) => Poll::Ready(Err(err)),
+            None => Poll::Ready(Ok(None)),
+        })
+        .map(Result::transpose)
+    }
+}
+
+impl<R: oio::Write> oio::Write for S3RetryWrapper<R> {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn oio::WriteBuf) -> Poll<Result<usize>> {
+        self.poll_retry(cx, |w, cx| w.poll_write(cx, bs))
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.poll_retry(cx, |w, cx| w.poll_close(cx))
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.poll_retry(cx, |w, cx| w.poll_abort(cx))
+    }
+}
+
+impl<R: oio::List> oio::List for S3RetryWrapper<R> {
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<oio::Entry>>> {
+        self.poll_retry(cx, |l, cx| l.poll_next(cx))
+    }
+}
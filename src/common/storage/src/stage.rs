@@ -18,6 +18,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserIdentity;
@@ -30,6 +31,7 @@ use regex::Regex;
 
 use crate::init_operator;
 use crate::DataOperator;
+use crate::DataPlacementPolicy;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum StageFileStatus {
@@ -79,6 +81,21 @@ pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
         Ok(init_operator(&param)?)
     }
 }
+
+/// Init the operator to read the files of the stage, which may read an external stage from
+/// a replica in another region, see `DataPlacementPolicy`.
+///
+/// The files are always written to and removed from the stage itself, use
+/// `init_stage_operator` for that.
+pub fn init_stage_read_operator(stage_info: &StageInfo) -> Result<Operator> {
+    if stage_info.stage_type == StageType::External {
+        let param = DataPlacementPolicy::instance().select(&stage_info.stage_params.storage);
+        Ok(init_operator(&param)?)
+    } else {
+        init_stage_operator(stage_info)
+    }
+}
+
 /// select * from @s1/<path> (FILES => <files> PATTERN => <pattern>)
 /// copy from @s1/<path> FILES = <files> PATTERN => <pattern>
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug)]
@@ -89,6 +106,46 @@ pub struct StageFilesInfo {
 }
 
 impl StageFilesInfo {
+    /// If the path is a glob like `data/year=*/*.parquet` and neither `FILES` nor `PATTERN`
+    /// is given, list the directory before the first wildcard, `data/`, and keep the files
+    /// matching the rest of the glob.
+    ///
+    /// `*` and `?` match within a directory, `**` matches any number of directories.
+    /// The files are listed lazily, when the files of the stage are listed.
+    pub fn resolve_glob(self) -> StageFilesInfo {
+        if self.files.is_some() || self.pattern.is_some() || !is_glob(&self.path) {
+            return self;
+        }
+
+        let wildcard = self.path.find(['*', '?']).unwrap();
+        let (path, glob) = match self.path[..wildcard].rfind('/') {
+            Some(pos) => self.path.split_at(pos + 1),
+            None => ("/", self.path.as_str()),
+        };
+        StageFilesInfo {
+            path: path.to_string(),
+            files: None,
+            pattern: Some(glob_to_pattern(glob)),
+        }
+    }
+
+    /// The extension of the files to read, e.g. `csv` for `data/*.csv`, or for
+    /// `FILES => ('a.csv', 'b.csv')`.
+    ///
+    /// `None` if the path is a directory, or the files have different extensions.
+    pub fn file_extension(&self) -> Option<String> {
+        match &self.files {
+            Some(files) => {
+                let mut extensions = files.iter().map(|file| path_extension(file));
+                let first = extensions.next()??;
+                extensions
+                    .all(|extension| extension.as_ref() == Some(&first))
+                    .then_some(first)
+            }
+            None => path_extension(&self.path),
+        }
+    }
+
     fn get_pattern(&self) -> Result<Option<Regex>> {
         match &self.pattern {
             Some(pattern) => match Regex::new(&format!("^{pattern}$")) {
@@ -247,6 +304,58 @@ impl StageFilesInfo {
     }
 }
 
+fn path_extension(path: &str) -> Option<String> {
+    let name = path.rsplit('/').next()?;
+    match name.rsplit_once('.') {
+        Some((stem, extension))
+            if !stem.is_empty() && !extension.is_empty() && !is_glob(extension) =>
+        {
+            Some(extension.to_lowercase())
+        }
+        _ => None,
+    }
+}
+
+/// The format of the files with the extension, `None` if the extension is unknown.
+///
+/// The `.json` files are read as NDJSON, the only JSON format supported by the stage tables.
+pub fn file_format_type_of_extension(extension: &str) -> Option<StageFileFormatType> {
+    match extension {
+        "parquet" => Some(StageFileFormatType::Parquet),
+        "csv" => Some(StageFileFormatType::Csv),
+        "tsv" => Some(StageFileFormatType::Tsv),
+        "ndjson" | "jsonl" | "json" => Some(StageFileFormatType::NdJson),
+        _ => None,
+    }
+}
+
+fn is_glob(path: &str) -> bool {
+    path != STDIN_FD && path.contains(['*', '?'])
+}
+
+/// Translate a glob to a regex matching the same paths.
+fn glob_to_pattern(glob: &str) -> String {
+    let mut pattern = String::with_capacity(glob.len() * 2);
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    pattern.push_str("(.*/)?");
+                } else {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern
+}
+
 fn check_file(path: &str, mode: EntryMode, pattern: &Option<Regex>) -> bool {
     if !path.is_empty() && mode.is_file() {
         pattern.as_ref().map_or(true, |p| p.is_match(path))
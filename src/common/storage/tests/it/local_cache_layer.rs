@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_storage::LocalCacheLayer;
+use databend_common_storage::StorageMetrics;
+use databend_common_storage::StorageMetricsLayer;
+use opendal::services::Memory;
+use opendal::Operator;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_local_cache_layer() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let layer = LocalCacheLayer::try_create(dir.path().to_str().unwrap(), 1024 * 1024)?;
+
+    // The remote operator shares the same memory backend with the cached one.
+    let metrics = Arc::new(StorageMetrics::default());
+    let remote = Operator::new(Memory::default())?
+        .finish()
+        .layer(StorageMetricsLayer::new(metrics.clone()));
+    let cached = remote.clone().layer(layer);
+
+    let block = "1/2/_b/block.parquet";
+    remote.write(block, "hello").await?;
+    assert_eq!(cached.read(block).await?, b"hello");
+
+    // Served from the local cache, nothing is read from the remote storage.
+    let requests = metrics.get_read_requests();
+    let bytes = metrics.get_read_bytes();
+    assert_eq!(cached.read(block).await?, b"hello");
+    assert_eq!(metrics.get_read_requests(), requests);
+    assert_eq!(metrics.get_read_bytes(), bytes);
+
+    // Ranges are cached separately.
+    assert_eq!(cached.read_with(block).range(1..3).await?, b"el");
+    let requests = metrics.get_read_requests();
+    assert_eq!(cached.read_with(block).range(1..3).await?, b"el");
+    assert_eq!(metrics.get_read_requests(), requests);
+
+    // Deleting through the layer evicts all of the cached ranges of the object.
+    cached.delete(block).await?;
+    remote.write(block, "world").await?;
+    assert_eq!(cached.read(block).await?, b"world");
+    assert_eq!(cached.read_with(block).range(1..3).await?, b"or");
+
+    // The objects that may be changed by other nodes are not cached.
+    let hint = "1/2/last_snapshot_location_hint";
+    remote.write(hint, "1/2/_ss/a.json").await?;
+    assert_eq!(cached.read(hint).await?, b"1/2/_ss/a.json");
+    remote.write(hint, "1/2/_ss/b.json").await?;
+    assert_eq!(cached.read(hint).await?, b"1/2/_ss/b.json");
+
+    // Objects larger than the capacity are not cached.
+    let large = vec![1u8; 2 * 1024 * 1024];
+    remote.write("1/2/_b/large.parquet", large.clone()).await?;
+    assert_eq!(cached.read("1/2/_b/large.parquet").await?, large);
+    let requests = metrics.get_read_requests();
+    assert_eq!(cached.read("1/2/_b/large.parquet").await?, large);
+    assert_eq!(metrics.get_read_requests(), requests + 1);
+
+    Ok(())
+}
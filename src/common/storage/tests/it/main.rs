@@ -13,3 +13,8 @@
 // limitations under the License.
 
 mod column_node;
+mod local_cache_layer;
+mod parquet_rs;
+mod placement;
+mod retry_layer;
+mod stage;
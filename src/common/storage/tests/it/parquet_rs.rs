@@ -0,0 +1,38 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storage::parquet_rs::read_metadata_async;
+use opendal::services::Memory;
+use opendal::Operator;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_metadata_of_encrypted_file() -> Result<()> {
+    let operator = Operator::new(Memory::default())?.finish();
+    // The footer of a file encrypted by Parquet Modular Encryption ends with `PARE`.
+    let mut data = b"PARE".to_vec();
+    data.extend_from_slice(&[0u8; 16]);
+    data.extend_from_slice(&16u32.to_le_bytes());
+    data.extend_from_slice(b"PARE");
+    operator.write("encrypted.parquet", data).await?;
+
+    let err = read_metadata_async("encrypted.parquet", &operator, None)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNIMPLEMENTED);
+    assert!(err.message().contains("'encrypted.parquet'"), "{err}");
+
+    Ok(())
+}
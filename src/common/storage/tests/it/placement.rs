@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::runtime::spawn;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
+use databend_common_storage::DataPlacementPolicy;
+use databend_common_storage::S3ReplicaConfig;
+use databend_common_storage::StorageConfig;
+use opendal::services::S3;
+use opendal::Operator;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+
+/// Start a mock S3 endpoint which returns `body` for all the requests.
+async fn start_mock_s3(body: &'static str) -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            spawn(async move {
+                let mut buf = vec![0; 4096];
+                let _ = stream.read(&mut buf).await;
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(resp.as_bytes()).await;
+            });
+        }
+    });
+
+    Ok(format!("http://{addr}"))
+}
+
+async fn read_from(params: &StorageParams) -> Result<Vec<u8>> {
+    let StorageParams::S3(cfg) = params else {
+        unreachable!("only s3 is used in this test");
+    };
+    let mut builder = S3::default();
+    builder.endpoint(&cfg.endpoint_url);
+    builder.bucket(&cfg.bucket);
+    builder.region(&cfg.region);
+    builder.access_key_id("access_key_id");
+    builder.secret_access_key("secret_access_key");
+    builder.disable_config_load();
+    builder.disable_ec2_metadata();
+    let op = Operator::new(builder)?.finish();
+
+    Ok(op.read("data").await?)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_data_placement_policy() -> Result<()> {
+    let remote_endpoint = start_mock_s3("remote").await?;
+    let local_endpoint = start_mock_s3("local").await?;
+
+    let policy = DataPlacementPolicy::create("us-west-2".to_string(), vec![S3ReplicaConfig {
+        bucket: "test".to_string(),
+        region: "us-west-2".to_string(),
+        replica_bucket: "test-replica".to_string(),
+        replica_endpoint_url: local_endpoint.clone(),
+    }]);
+    let remote = StorageS3Config {
+        endpoint_url: remote_endpoint.clone(),
+        region: "us-east-1".to_string(),
+        bucket: "test".to_string(),
+        ..Default::default()
+    };
+
+    // The bucket in the remote region is read from the replica in the local region.
+    let selected = policy.select(&StorageParams::S3(remote.clone()));
+    assert_eq!(
+        selected,
+        StorageParams::S3(StorageS3Config {
+            endpoint_url: local_endpoint,
+            region: "us-west-2".to_string(),
+            bucket: "test-replica".to_string(),
+            ..Default::default()
+        })
+    );
+    assert_eq!(read_from(&selected).await?, b"local");
+
+    // The preferred region of the stage overrides the local region.
+    let preferred = StorageParams::S3(StorageS3Config {
+        preferred_region: "us-east-1".to_string(),
+        ..remote.clone()
+    });
+    let selected = policy.select(&preferred);
+    assert_eq!(selected, preferred);
+    assert_eq!(read_from(&selected).await?, b"remote");
+
+    // The bucket without replica is read from its own region.
+    let no_replica = StorageParams::S3(StorageS3Config {
+        bucket: "other".to_string(),
+        ..remote
+    });
+    assert_eq!(policy.select(&no_replica), no_replica);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stage_operator_placement() -> Result<()> {
+    let remote_endpoint = start_mock_s3("remote").await?;
+    let local_endpoint = start_mock_s3("local").await?;
+
+    DataPlacementPolicy::init(&StorageConfig {
+        s3_local_region: "us-west-2".to_string(),
+        s3_replicas: vec![S3ReplicaConfig {
+            bucket: "test".to_string(),
+            region: "us-west-2".to_string(),
+            replica_bucket: "test-replica".to_string(),
+            replica_endpoint_url: local_endpoint,
+        }],
+        ..Default::default()
+    });
+    let stage = StageInfo::new_external_stage(
+        StorageParams::S3(StorageS3Config {
+            endpoint_url: remote_endpoint,
+            region: "us-east-1".to_string(),
+            bucket: "test".to_string(),
+            access_key_id: "access_key_id".to_string(),
+            secret_access_key: "secret_access_key".to_string(),
+            disable_credential_loader: true,
+            ..Default::default()
+        }),
+        false,
+    );
+
+    // Only the reads go to the replica, the files are written to the stage itself.
+    let op = init_stage_read_operator(&stage)?;
+    assert_eq!(op.read("data").await?, b"local");
+    let op = init_stage_operator(&stage)?;
+    assert_eq!(op.read("data").await?, b"remote");
+
+    Ok(())
+}
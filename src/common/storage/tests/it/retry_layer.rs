@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::runtime::spawn;
+use databend_common_exception::Result;
+use databend_common_storage::S3RetryConfig;
+use databend_common_storage::S3RetryLayer;
+use opendal::services::S3;
+use opendal::Operator;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+
+/// Start a mock S3 server which returns `status` for the first `failures` requests.
+async fn start_mock_s3(
+    failures: usize,
+    status: &'static str,
+) -> Result<(SocketAddr, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let counter = requests.clone();
+    spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            spawn(async move {
+                let mut buf = vec![0; 4096];
+                let _ = stream.read(&mut buf).await;
+                let resp = if n < failures {
+                    format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                } else {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello"
+                        .to_string()
+                };
+                let _ = stream.write_all(resp.as_bytes()).await;
+            });
+        }
+    });
+
+    Ok((addr, requests))
+}
+
+fn mock_s3_operator(addr: SocketAddr, max_retries: u64) -> Result<Operator> {
+    let mut builder = S3::default();
+    builder.endpoint(&format!("http://{addr}"));
+    builder.bucket("test");
+    builder.region("us-east-1");
+    builder.access_key_id("access_key_id");
+    builder.secret_access_key("secret_access_key");
+    builder.disable_config_load();
+    builder.disable_ec2_metadata();
+
+    let config = S3RetryConfig {
+        max_retries,
+        initial_delay: Duration::from_millis(10),
+        timeout: Duration::from_secs(10),
+    };
+    Ok(Operator::new(builder)?
+        .layer(S3RetryLayer::new(config))
+        .finish())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_s3_retry_layer() -> Result<()> {
+    // Succeed after 3 retries.
+    let (addr, requests) = start_mock_s3(3, "503 Service Unavailable").await?;
+    let op = mock_s3_operator(addr, 3)?;
+    assert_eq!(op.read("data").await?, b"hello");
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+    // Give up when the retries are exhausted.
+    let (addr, requests) = start_mock_s3(3, "503 Service Unavailable").await?;
+    let op = mock_s3_operator(addr, 2)?;
+    assert!(op.read("data").await.is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    // Fail fast on the errors which are not temporary.
+    for status in ["404 Not Found", "403 Forbidden"] {
+        let (addr, requests) = start_mock_s3(1, status).await?;
+        let op = mock_s3_operator(addr, 3)?;
+        assert!(op.read("data").await.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    Ok(())
+}
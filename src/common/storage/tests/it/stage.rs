@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_storage::file_format_type_of_extension;
+use databend_common_storage::StageFilesInfo;
+use opendal::services::Memory;
+use opendal::Operator;
+
+fn files_info(path: &str) -> StageFilesInfo {
+    StageFilesInfo {
+        path: path.to_string(),
+        files: None,
+        pattern: None,
+    }
+}
+
+#[test]
+fn test_resolve_glob() {
+    let resolved = files_info("data/year=*/*.parquet").resolve_glob();
+    assert_eq!(resolved.path, "data/");
+    assert_eq!(
+        resolved.pattern.as_deref(),
+        Some(r"year=[^/]*/[^/]*\.parquet")
+    );
+
+    let resolved = files_info("**/f?.parquet").resolve_glob();
+    assert_eq!(resolved.path, "/");
+    assert_eq!(resolved.pattern.as_deref(), Some(r"(.*/)?f[^/]\.parquet"));
+
+    // Not a glob, or the files to read are given.
+    assert_eq!(files_info("data/").resolve_glob(), files_info("data/"));
+    let info = StageFilesInfo {
+        pattern: Some(".*".to_string()),
+        ..files_info("data/*")
+    };
+    assert_eq!(info.clone().resolve_glob(), info);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_glob() -> Result<()> {
+    let operator = Operator::new(Memory::default())?.finish();
+    for path in [
+        "data/year=2023/a.parquet",
+        "data/year=2024/b.parquet",
+        "data/year=2024/month=1/c.parquet",
+        "data/year=2024/d.csv",
+    ] {
+        operator.write(path, vec![1u8]).await?;
+    }
+
+    let list = |glob: &str| {
+        let operator = operator.clone();
+        let info = files_info(glob).resolve_glob();
+        async move {
+            let mut files = info
+                .list(&operator, false, None)
+                .await?
+                .into_iter()
+                .map(|file| file.path)
+                .collect::<Vec<_>>();
+            files.sort();
+            Result::Ok(files)
+        }
+    };
+
+    assert_eq!(list("data/year=*/*.parquet").await?, vec![
+        "data/year=2023/a.parquet",
+        "data/year=2024/b.parquet",
+    ]);
+    assert_eq!(list("data/**/*.parquet").await?, vec![
+        "data/year=2023/a.parquet",
+        "data/year=2024/b.parquet",
+        "data/year=2024/month=1/c.parquet",
+    ]);
+    assert_eq!(list("data/year=2024/**").await?, vec![
+        "data/year=2024/b.parquet",
+        "data/year=2024/d.csv",
+        "data/year=2024/month=1/c.parquet",
+    ]);
+
+    // Matching no file is not an error.
+    assert!(list("data/*.parquet").await?.is_empty());
+    assert!(list("missing/*.parquet").await?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_file_extension() {
+    assert_eq!(
+        files_info("data/*.CSV").file_extension().as_deref(),
+        Some("csv")
+    );
+    assert_eq!(
+        files_info("data/a.parquet").file_extension().as_deref(),
+        Some("parquet")
+    );
+    assert_eq!(files_info("data/").file_extension(), None);
+    assert_eq!(files_info("data/.hidden").file_extension(), None);
+    assert_eq!(files_info("data/a.*").file_extension(), None);
+
+    let files = |files: &[&str]| StageFilesInfo {
+        files: Some(files.iter().map(|file| file.to_string()).collect()),
+        ..files_info("data/")
+    };
+    assert_eq!(
+        files(&["a.ndjson", "b.ndjson"]).file_extension().as_deref(),
+        Some("ndjson")
+    );
+    assert_eq!(files(&["a.ndjson", "b.csv"]).file_extension(), None);
+    assert_eq!(files(&["a.ndjson", "b"]).file_extension(), None);
+
+    assert_eq!(
+        file_format_type_of_extension("jsonl"),
+        Some(StageFileFormatType::NdJson)
+    );
+    assert_eq!(file_format_type_of_extension("txt"), None);
+}
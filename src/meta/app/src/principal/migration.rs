@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A migration script applied by `APPLY MIGRATION`, which is listed in
+/// `system.databend_migrations`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Migration {
+    /// The file name of the script, e.g. `v1_to_v2.sql`.
+    pub name: String,
+    /// The SHA-256 of the script, a script can not be applied again once it is changed.
+    pub checksum: String,
+    /// The statements run by `ROLLBACK MIGRATION`, which follow the `--/+` line in the script.
+    pub rollback_script: Option<String>,
+    pub applied_at: DateTime<Utc>,
+}
@@ -16,11 +16,14 @@
 
 mod connection;
 mod file_format;
+mod migration;
 mod network_policy;
+mod notification_integration;
 mod ownership_info;
 mod password_policy;
 mod principal_identity;
 mod role_info;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -34,12 +37,21 @@ mod user_stage;
 
 pub use connection::*;
 pub use file_format::*;
+pub use migration::Migration;
 pub use network_policy::NetworkPolicy;
+pub use notification_integration::NotificationEvent;
+pub use notification_integration::NotificationIntegration;
+pub use notification_integration::NotificationType;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use task::Task;
+pub use task::TaskRun;
+pub use task::TaskRunState;
+pub use task::TaskSchedule;
+pub use task::TaskState;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -48,6 +60,7 @@ pub use user_defined_function::LambdaUDF;
 pub use user_defined_function::UDFDefinition;
 pub use user_defined_function::UDFServer;
 pub use user_defined_function::UdfName;
+pub use user_defined_function::UdfVersionIdent;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
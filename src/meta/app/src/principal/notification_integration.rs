@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub enum NotificationType {
+    /// POST the events as JSON to a URL.
+    #[default]
+    Webhook,
+}
+
+impl FromStr for NotificationType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "webhook" => Ok(NotificationType::Webhook),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid notification type: {s}. The valid values are WEBHOOK."
+            ))),
+        }
+    }
+}
+
+impl Display for NotificationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationType::Webhook => write!(f, "WEBHOOK"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NotificationEvent {
+    /// A `COPY INTO <table>` has committed the files it loaded.
+    CopyIntoComplete,
+    /// A query failed.
+    QueryError,
+    /// A pipe failed to load the files.
+    PipeError,
+}
+
+impl FromStr for NotificationEvent {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "COPY_INTO_COMPLETE" => Ok(NotificationEvent::CopyIntoComplete),
+            "QUERY_ERROR" => Ok(NotificationEvent::QueryError),
+            "PIPE_ERROR" => Ok(NotificationEvent::PipeError),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid notification event: {s}. The valid values are COPY_INTO_COMPLETE | QUERY_ERROR | PIPE_ERROR."
+            ))),
+        }
+    }
+}
+
+impl Display for NotificationEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationEvent::CopyIntoComplete => write!(f, "COPY_INTO_COMPLETE"),
+            NotificationEvent::QueryError => write!(f, "QUERY_ERROR"),
+            NotificationEvent::PipeError => write!(f, "PIPE_ERROR"),
+        }
+    }
+}
+
+/// Delivers the events of a tenant to an external service.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct NotificationIntegration {
+    pub name: String,
+    pub notification_type: NotificationType,
+    pub url: String,
+    /// The events delivered by the integration.
+    pub events: Vec<NotificationEvent>,
+    pub enabled: bool,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
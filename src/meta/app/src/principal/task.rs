@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cron::Schedule;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub enum TaskState {
+    /// The task is not scheduled, it can still be executed by `EXECUTE TASK`.
+    #[default]
+    Suspended,
+    Started,
+}
+
+impl Display for TaskState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskState::Suspended => write!(f, "Suspended"),
+            TaskState::Started => write!(f, "Started"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TaskSchedule {
+    IntervalSecs(u64),
+    /// A cron expression and the time zone it is evaluated in, which is UTC if not given.
+    Cron {
+        expr: String,
+        time_zone: Option<String>,
+    },
+}
+
+impl TaskSchedule {
+    /// Parses a cron expression, the seconds field can be omitted like `0 * * * *`.
+    pub fn parse_cron(expr: &str) -> Result<Schedule> {
+        let expr = expr.trim();
+        let normalized = if expr.split_whitespace().count() == 5 {
+            format!("0 {expr}")
+        } else {
+            expr.to_string()
+        };
+        Schedule::from_str(&normalized)
+            .map_err(|e| ErrorCode::IllegalTask(format!("invalid cron expression {expr}: {e}")))
+    }
+
+    pub fn parse_time_zone(time_zone: &str) -> Result<chrono_tz::Tz> {
+        chrono_tz::Tz::from_str(time_zone)
+            .map_err(|e| ErrorCode::IllegalTask(format!("invalid time zone {time_zone}: {e}")))
+    }
+
+    /// The first time the task should run after `after`.
+    pub fn next_scheduled_at(&self, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        match self {
+            TaskSchedule::IntervalSecs(secs) => {
+                Ok(Some(after + chrono::Duration::seconds(*secs as i64)))
+            }
+            TaskSchedule::Cron { expr, time_zone } => {
+                let schedule = Self::parse_cron(expr)?;
+                let time_zone = match time_zone {
+                    Some(time_zone) if !time_zone.is_empty() => Self::parse_time_zone(time_zone)?,
+                    _ => chrono_tz::UTC,
+                };
+                Ok(schedule
+                    .after(&after.with_timezone(&time_zone))
+                    .next()
+                    .map(|t| t.with_timezone(&Utc)))
+            }
+        }
+    }
+}
+
+impl Display for TaskSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskSchedule::IntervalSecs(secs) => write!(f, "INTERVAL {} SECOND", secs),
+            TaskSchedule::Cron { expr, time_zone } => {
+                write!(f, "CRON {}", expr)?;
+                if let Some(time_zone) = time_zone {
+                    write!(f, " TIMEZONE {}", time_zone)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A SQL statement run by the `TaskScheduler` of the query nodes on a schedule.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Task {
+    pub id: u64,
+    pub name: String,
+    /// The role that created the task, the SQL runs with the privileges of the role.
+    pub owner: String,
+    pub query_text: String,
+    pub schedule: Option<TaskSchedule>,
+    pub warehouse: Option<String>,
+    pub suspend_task_after_num_failures: Option<u64>,
+    pub session_parameters: BTreeMap<String, String>,
+    pub comment: String,
+    pub state: TaskState,
+    /// The number of the runs that failed since the last run that succeeded.
+    pub failures: u64,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
+
+impl Task {
+    /// Updates `next_scheduled_at` to the first time the task should run after `after`, the
+    /// task is not scheduled if it is suspended.
+    pub fn reschedule(&mut self, after: DateTime<Utc>) -> Result<()> {
+        self.next_scheduled_at = match (&self.state, &self.schedule) {
+            (TaskState::Started, Some(schedule)) => schedule.next_scheduled_at(after)?,
+            _ => None,
+        };
+        Ok(())
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub enum TaskRunState {
+    #[default]
+    Succeeded,
+    Failed,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskRunState::Succeeded => write!(f, "SUCCEEDED"),
+            TaskRunState::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// A finished run of a task, which is kept in the history of the task.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TaskRun {
+    pub task_id: u64,
+    pub task_name: String,
+    pub owner: String,
+    pub query_text: String,
+    pub schedule: Option<TaskSchedule>,
+    pub warehouse: Option<String>,
+    pub comment: String,
+    pub run_id: String,
+    pub query_id: String,
+    pub state: TaskRunState,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    pub attempt_number: i32,
+    /// The time the run was scheduled at, which is the time it is executed by `EXECUTE TASK`.
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    pub session_parameters: BTreeMap<String, String>,
+}
@@ -39,6 +39,24 @@ impl UdfName {
     }
 }
 
+/// The key of a previous version of a UDF, `seq` is the seq of the UDF when it was replaced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UdfVersionIdent {
+    pub tenant: String,
+    pub name: String,
+    pub seq: u64,
+}
+
+impl UdfVersionIdent {
+    pub fn new(tenant: impl ToString, name: impl ToString, seq: u64) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+            seq,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LambdaUDF {
     pub parameters: Vec<String>,
@@ -153,6 +171,7 @@ mod kv_api_impl {
     use databend_common_meta_kvapi::kvapi;
 
     use super::UdfName;
+    use super::UdfVersionIdent;
     use crate::principal::UserDefinedFunction;
     use crate::tenant::Tenant;
 
@@ -184,5 +203,35 @@ mod kv_api_impl {
         }
     }
 
+    impl kvapi::Key for UdfVersionIdent {
+        const PREFIX: &'static str = "__fd_udf_versions";
+
+        type ValueType = UserDefinedFunction;
+
+        /// It belongs to a tenant
+        fn parent(&self) -> Option<String> {
+            Some(Tenant::new(&self.tenant).to_string_key())
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.name)
+                .push_u64(self.seq)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let name = p.next_str()?;
+            let seq = p.next_u64()?;
+            p.done()?;
+
+            Ok(UdfVersionIdent { tenant, name, seq })
+        }
+    }
+
     impl kvapi::Value for UserDefinedFunction {}
 }
@@ -57,6 +57,8 @@ pub enum GrantObject {
     TableById(String, u64, u64),
     UDF(String),
     Stage(String),
+    /// A column of the table, only SELECT could be granted on it.
+    Column(String, u64, u64, String),
 }
 
 impl GrantObject {
@@ -75,6 +77,9 @@ impl GrantObject {
             (GrantObject::DatabaseById(lcat, ldb), GrantObject::TableById(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (GrantObject::DatabaseById(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
+                lcat == rcat && ldb == rdb
+            }
             (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
@@ -86,6 +91,19 @@ impl GrantObject {
                 GrantObject::TableById(lcat, lhs_db, lhs_table),
                 GrantObject::TableById(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::TableById(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::Column(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
             (GrantObject::Table(_, _, _), _) => false,
             (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
             (GrantObject::UDF(udf), GrantObject::UDF(rudf)) => udf == rudf,
@@ -109,6 +127,7 @@ impl GrantObject {
             GrantObject::Stage(_) => {
                 UserPrivilegeSet::available_privileges_on_stage(available_ownership)
             }
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
         }
     }
 
@@ -116,7 +135,9 @@ impl GrantObject {
         match self {
             GrantObject::Global | GrantObject::Stage(_) | GrantObject::UDF(_) => None,
             GrantObject::Database(cat, _) | GrantObject::DatabaseById(cat, _) => Some(cat.clone()),
-            GrantObject::Table(cat, _, _) | GrantObject::TableById(cat, _, _) => Some(cat.clone()),
+            GrantObject::Table(cat, _, _)
+            | GrantObject::TableById(cat, _, _)
+            | GrantObject::Column(cat, _, _, _) => Some(cat.clone()),
         }
     }
 }
@@ -135,6 +156,9 @@ impl fmt::Display for GrantObject {
             }
             GrantObject::UDF(udf) => write!(f, "UDF {udf}"),
             GrantObject::Stage(stage) => write!(f, "STAGE {stage}"),
+            GrantObject::Column(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'.'{}'", cat, db, table, column)
+            }
         }
     }
 }
@@ -189,6 +213,13 @@ impl GrantEntry {
 impl fmt::Display for GrantEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
         let privileges: UserPrivilegeSet = self.privileges.into();
+        if let GrantObject::Column(cat, db, table, column) = &self.object {
+            return write!(
+                f,
+                "GRANT {} ({}) ON '{}'.'{}'.'{}'",
+                privileges, column, cat, db, table
+            );
+        }
         let privileges_str = if self.has_all_available_privileges() {
             "ALL".to_string()
         } else {
@@ -185,6 +185,11 @@ impl UserPrivilegeSet {
         }
     }
 
+    /// Only SELECT could be granted on a column of the table.
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select }).into()
+    }
+
     pub fn available_privileges_on_stage(available_ownership: bool) -> Self {
         if available_ownership {
             make_bitflags!(UserPrivilegeType::{  Read | Write | Ownership }).into()
@@ -110,6 +110,7 @@ pub enum StageFileCompression {
     Lzo,
     Snappy,
     Xz,
+    Lz4,
     None,
 }
 
@@ -139,6 +140,7 @@ impl FromStr for StageFileCompression {
             "lzo" => Ok(StageFileCompression::Lzo),
             "snappy" => Ok(StageFileCompression::Snappy),
             "xz" => Ok(StageFileCompression::Xz),
+            "lz4" => Ok(StageFileCompression::Lz4),
             "none" => Ok(StageFileCompression::None),
             _ => Err("Unknown file compression type, must one of { auto | gzip | bz2 | brotli | zstd | deflate | raw_deflate | lzo | snappy | xz | none }"
                 .to_string()),
@@ -159,6 +161,7 @@ impl ToString for StageFileCompression {
             StageFileCompression::Lzo => "lzo".to_string(),
             StageFileCompression::Snappy => "snappy".to_string(),
             StageFileCompression::Xz => "xz".to_string(),
+            StageFileCompression::Lz4 => "lz4".to_string(),
             StageFileCompression::None => "none".to_string(),
         }
     }
@@ -376,6 +376,9 @@ pub struct StorageS3Config {
     pub role_arn: String,
     /// The ExternalId that used for AssumeRole.
     pub external_id: String,
+    /// The region preferred to read the bucket from, the bucket is read from its replica
+    /// in this region if there is one.
+    pub preferred_region: String,
 }
 
 impl Default for StorageS3Config {
@@ -393,6 +396,7 @@ impl Default for StorageS3Config {
             enable_virtual_host_style: false,
             role_arn: "".to_string(),
             external_id: "".to_string(),
+            preferred_region: "".to_string(),
         }
     }
 }
@@ -408,6 +412,7 @@ impl Debug for StorageS3Config {
             .field("enable_virtual_host_style", &self.enable_virtual_host_style)
             .field("role_arn", &self.role_arn)
             .field("external_id", &self.external_id)
+            .field("preferred_region", &self.preferred_region)
             .field("access_key_id", &mask_string(&self.access_key_id, 3))
             .field(
                 "secret_access_key",
@@ -107,6 +107,31 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::TableById("default".into(), 1, 10),
+            rhs: GrantObject::Column("default".into(), 1, 10, "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::DatabaseById("default".into(), 1),
+            rhs: GrantObject::Column("default".into(), 1, 10, "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), 1, 10, "a".into()),
+            rhs: GrantObject::Column("default".into(), 1, 10, "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), 1, 10, "a".into()),
+            rhs: GrantObject::Column("default".into(), 1, 10, "b".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), 1, 10, "a".into()),
+            rhs: GrantObject::TableById("default".into(), 1, 10),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -178,6 +203,25 @@ fn test_user_grant_entry() -> Result<()> {
         vec![UserPrivilegeType::Create]
     ));
 
+    let grant = GrantEntry::new(
+        GrantObject::Column("default".into(), 1, 10, "a".into()),
+        make_bitflags!(UserPrivilegeType::{Select}),
+    );
+    assert!(grant.verify_privilege(
+        &GrantObject::Column("default".into(), 1, 10, "a".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(!grant.verify_privilege(
+        &GrantObject::Column("default".into(), 1, 10, "b".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(
+        !grant.verify_privilege(&GrantObject::TableById("default".into(), 1, 10), vec![
+            UserPrivilegeType::Select
+        ])
+    );
+    assert_eq!(grant.to_string(), "GRANT SELECT (a) ON 'default'.'1'.'10'");
+
     Ok(())
 }
 
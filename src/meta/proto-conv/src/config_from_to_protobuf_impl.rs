@@ -132,6 +132,7 @@ impl FromToProto for StorageS3Config {
             enable_virtual_host_style: p.enable_virtual_host_style,
             role_arn: p.role_arn,
             external_id: p.external_id,
+            preferred_region: p.preferred_region,
         })
     }
 
@@ -151,6 +152,7 @@ impl FromToProto for StorageS3Config {
             enable_virtual_host_style: self.enable_virtual_host_style,
             role_arn: self.role_arn.clone(),
             external_id: self.external_id.clone(),
+            preferred_region: self.preferred_region.clone(),
         })
     }
 }
@@ -82,6 +82,7 @@ impl FromToProtoEnum for mt::principal::StageFileCompression {
             pb::StageFileCompression::Snappy => Ok(mt::principal::StageFileCompression::Snappy),
             pb::StageFileCompression::None => Ok(mt::principal::StageFileCompression::None),
             pb::StageFileCompression::Xz => Ok(mt::principal::StageFileCompression::Xz),
+            pb::StageFileCompression::Lz4 => Ok(mt::principal::StageFileCompression::Lz4),
         }
     }
 
@@ -100,6 +101,7 @@ impl FromToProtoEnum for mt::principal::StageFileCompression {
             mt::principal::StageFileCompression::Snappy => Ok(pb::StageFileCompression::Snappy),
             mt::principal::StageFileCompression::None => Ok(pb::StageFileCompression::None),
             mt::principal::StageFileCompression::Xz => Ok(pb::StageFileCompression::Xz),
+            mt::principal::StageFileCompression::Lz4 => Ok(pb::StageFileCompression::Lz4),
         }
     }
 }
@@ -0,0 +1,227 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::TaskSchedule {
+    type PB = pb::TaskSchedule;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        match p.schedule {
+            Some(pb::task_schedule::Schedule::IntervalSecs(secs)) => {
+                Ok(mt::TaskSchedule::IntervalSecs(secs))
+            }
+            Some(pb::task_schedule::Schedule::Cron(expr)) => Ok(mt::TaskSchedule::Cron {
+                expr,
+                time_zone: p.time_zone,
+            }),
+            None => Err(Incompatible {
+                reason: "TaskSchedule.schedule cannot be None".to_string(),
+            }),
+        }
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let (schedule, time_zone) = match self {
+            mt::TaskSchedule::IntervalSecs(secs) => {
+                (pb::task_schedule::Schedule::IntervalSecs(*secs), None)
+            }
+            mt::TaskSchedule::Cron { expr, time_zone } => (
+                pb::task_schedule::Schedule::Cron(expr.clone()),
+                time_zone.clone(),
+            ),
+        };
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            schedule: Some(schedule),
+            time_zone,
+        })
+    }
+}
+
+impl FromToProto for mt::Task {
+    type PB = pb::Task;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let state = match p.state {
+            0 => mt::TaskState::Suspended,
+            1 => mt::TaskState::Started,
+            s => {
+                return Err(Incompatible {
+                    reason: format!("Task.state can not be {}", s),
+                });
+            }
+        };
+
+        Ok(Self {
+            id: p.id,
+            name: p.name,
+            owner: p.owner,
+            query_text: p.query_text,
+            schedule: match p.schedule {
+                Some(s) => Some(mt::TaskSchedule::from_pb(s)?),
+                None => None,
+            },
+            warehouse: p.warehouse,
+            suspend_task_after_num_failures: p.suspend_task_after_num_failures,
+            session_parameters: p.session_parameters,
+            comment: p.comment,
+            state,
+            failures: p.failures,
+            next_scheduled_at: match p.next_scheduled_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            last_suspended_at: match p.last_suspended_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+            updated_on: DateTime::<Utc>::from_pb(p.updated_on)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let state = match self.state {
+            mt::TaskState::Suspended => pb::task::State::Suspended,
+            mt::TaskState::Started => pb::task::State::Started,
+        };
+
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            id: self.id,
+            name: self.name.clone(),
+            owner: self.owner.clone(),
+            query_text: self.query_text.clone(),
+            schedule: match &self.schedule {
+                Some(s) => Some(s.to_pb()?),
+                None => None,
+            },
+            warehouse: self.warehouse.clone(),
+            suspend_task_after_num_failures: self.suspend_task_after_num_failures,
+            session_parameters: self.session_parameters.clone(),
+            comment: self.comment.clone(),
+            state: state as i32,
+            failures: self.failures,
+            next_scheduled_at: match &self.next_scheduled_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            last_suspended_at: match &self.last_suspended_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            created_on: self.created_on.to_pb()?,
+            updated_on: self.updated_on.to_pb()?,
+        })
+    }
+}
+
+impl FromToProto for mt::TaskRun {
+    type PB = pb::TaskRun;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let state = match p.state {
+            0 => mt::TaskRunState::Succeeded,
+            1 => mt::TaskRunState::Failed,
+            s => {
+                return Err(Incompatible {
+                    reason: format!("TaskRun.state can not be {}", s),
+                });
+            }
+        };
+
+        Ok(Self {
+            task_id: p.task_id,
+            task_name: p.task_name,
+            owner: p.owner,
+            query_text: p.query_text,
+            schedule: match p.schedule {
+                Some(s) => Some(mt::TaskSchedule::from_pb(s)?),
+                None => None,
+            },
+            warehouse: p.warehouse,
+            comment: p.comment,
+            run_id: p.run_id,
+            query_id: p.query_id,
+            state,
+            error_code: p.error_code,
+            error_message: p.error_message,
+            attempt_number: p.attempt_number,
+            scheduled_at: DateTime::<Utc>::from_pb(p.scheduled_at)?,
+            completed_at: DateTime::<Utc>::from_pb(p.completed_at)?,
+            session_parameters: p.session_parameters,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let state = match self.state {
+            mt::TaskRunState::Succeeded => pb::task_run::State::Succeeded,
+            mt::TaskRunState::Failed => pb::task_run::State::Failed,
+        };
+
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task_id: self.task_id,
+            task_name: self.task_name.clone(),
+            owner: self.owner.clone(),
+            query_text: self.query_text.clone(),
+            schedule: match &self.schedule {
+                Some(s) => Some(s.to_pb()?),
+                None => None,
+            },
+            warehouse: self.warehouse.clone(),
+            comment: self.comment.clone(),
+            run_id: self.run_id.clone(),
+            query_id: self.query_id.clone(),
+            state: state as i32,
+            error_code: self.error_code,
+            error_message: self.error_message.clone(),
+            attempt_number: self.attempt_number,
+            scheduled_at: self.scheduled_at.to_pb()?,
+            completed_at: self.completed_at.to_pb()?,
+            session_parameters: self.session_parameters.clone(),
+        })
+    }
+}
@@ -178,6 +178,14 @@ impl FromToProto for mt::principal::GrantObject {
             Some(pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage })) => {
                 Ok(mt::principal::GrantObject::Stage(stage))
             }
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                column,
+            })) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column,
+            )),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -223,6 +231,14 @@ impl FromToProto for mt::principal::GrantObject {
                     stage: stage.clone(),
                 },
             )),
+            mt::principal::GrantObject::Column(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: *db,
+                    table: *table,
+                    column: column.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -109,7 +109,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (77, "2024-01-22: Remove: allow_anonymous in S3 Config", ),
     (78, "2024-01-29: Refactor: GrantEntry::UserPrivilegeType and ShareGrantEntry::ShareGrantObjectPrivilege use from_bits_truncate deserialize", ),
     (79, "2024-01-31: Add: udf.proto/UserDefinedFunction add created_on field", ),
-    (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type")
+    (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type"),
+    (81, "2024-02-05: Add: config.proto/S3StorageConfig add preferred_region", ),
+    (82, "2024-02-07: Add: file_format.proto/StageFileCompression add Lz4", ),
+    (83, "2024-02-08: Add: user.proto/GrantObject add GrantColumnObject", ),
+    (84, "2024-02-09: Add: notification.proto/NotificationIntegration", ),
+    (85, "2024-02-12: Add: task.proto/Task and TaskRun", ),
+    (86, "2024-02-14: Add: migration.proto/Migration", ),
+    (87, "2024-02-15: Add: metadata.proto/DataField add declared_length", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -84,3 +84,10 @@ mod v077_s3_remove_allow_anonymous;
 mod v078_grantentry;
 mod v079_udf_created_on;
 mod v080_geometry_datatype;
+mod v081_s3_preferred_region;
+mod v082_lz4_compression;
+mod v083_user_grant_column;
+mod v084_notification_integration;
+mod v085_task;
+mod v086_migration;
+mod v087_data_field_declared_length;
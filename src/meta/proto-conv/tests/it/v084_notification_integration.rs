@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_meta_app::principal::NotificationType;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v84_notification_integration() -> anyhow::Result<()> {
+    let notification_integration_v84 = vec![
+        10, 2, 110, 49, 18, 7, 87, 69, 66, 72, 79, 79, 75, 26, 24, 104, 116, 116, 112, 115, 58, 47,
+        47, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 47, 104, 111, 111, 107, 34, 18, 67,
+        79, 80, 89, 95, 73, 78, 84, 79, 95, 67, 79, 77, 80, 76, 69, 84, 69, 34, 11, 81, 85, 69, 82,
+        89, 95, 69, 82, 82, 79, 82, 40, 1, 50, 7, 99, 111, 109, 109, 101, 110, 116, 58, 23, 50, 48,
+        50, 52, 45, 48, 50, 45, 48, 57, 32, 48, 55, 58, 48, 56, 58, 48, 57, 32, 85, 84, 67, 160, 6,
+        84, 168, 6, 24,
+    ];
+
+    let want = || NotificationIntegration {
+        name: "n1".to_string(),
+        notification_type: NotificationType::Webhook,
+        url: "https://example.com/hook".to_string(),
+        events: vec![
+            NotificationEvent::CopyIntoComplete,
+            NotificationEvent::QueryError,
+        ],
+        enabled: true,
+        comment: "comment".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 2, 9, 7, 8, 9).unwrap(),
+        update_on: None,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        notification_integration_v84.as_slice(),
+        84,
+        want(),
+    )?;
+
+    Ok(())
+}
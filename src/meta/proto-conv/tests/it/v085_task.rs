@@ -0,0 +1,118 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskSchedule;
+use databend_common_meta_app::principal::TaskState;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v85_task() -> anyhow::Result<()> {
+    let task_v85 = vec![
+        8, 7, 18, 2, 116, 49, 26, 13, 97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 109, 105, 110,
+        34, 22, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32, 116, 32, 83, 69, 76, 69, 67, 84,
+        32, 49, 42, 32, 18, 9, 48, 32, 42, 32, 42, 32, 42, 32, 42, 26, 13, 65, 115, 105, 97, 47,
+        83, 104, 97, 110, 103, 104, 97, 105, 160, 6, 85, 168, 6, 24, 56, 3, 66, 15, 10, 8, 116,
+        105, 109, 101, 122, 111, 110, 101, 18, 3, 85, 84, 67, 74, 7, 99, 111, 109, 109, 101, 110,
+        116, 80, 1, 88, 1, 98, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 48, 56, 58, 48, 48,
+        58, 48, 48, 32, 85, 84, 67, 114, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 48, 55,
+        58, 48, 56, 58, 48, 57, 32, 85, 84, 67, 122, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50,
+        32, 48, 55, 58, 48, 56, 58, 48, 57, 32, 85, 84, 67, 160, 6, 85, 168, 6, 24,
+    ];
+
+    let want = || Task {
+        id: 7,
+        name: "t1".to_string(),
+        owner: "account_admin".to_string(),
+        query_text: "INSERT INTO t SELECT 1".to_string(),
+        schedule: Some(TaskSchedule::Cron {
+            expr: "0 * * * *".to_string(),
+            time_zone: Some("Asia/Shanghai".to_string()),
+        }),
+        warehouse: None,
+        suspend_task_after_num_failures: Some(3),
+        session_parameters: BTreeMap::from([("timezone".to_string(), "UTC".to_string())]),
+        comment: "comment".to_string(),
+        state: TaskState::Started,
+        failures: 1,
+        next_scheduled_at: Some(Utc.with_ymd_and_hms(2024, 2, 12, 8, 0, 0).unwrap()),
+        last_suspended_at: None,
+        created_on: Utc.with_ymd_and_hms(2024, 2, 12, 7, 8, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2024, 2, 12, 7, 8, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_v85.as_slice(), 85, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v85_task_run() -> anyhow::Result<()> {
+    let task_run_v85 = vec![
+        8, 7, 18, 2, 116, 49, 26, 13, 97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 109, 105, 110,
+        34, 22, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32, 116, 32, 83, 69, 76, 69, 67, 84,
+        32, 49, 42, 32, 18, 9, 48, 32, 42, 32, 42, 32, 42, 32, 42, 26, 13, 65, 115, 105, 97, 47,
+        83, 104, 97, 110, 103, 104, 97, 105, 160, 6, 85, 168, 6, 24, 58, 7, 99, 111, 109, 109, 101,
+        110, 116, 66, 5, 114, 117, 110, 45, 49, 74, 7, 113, 117, 101, 114, 121, 45, 49, 80, 1, 88,
+        129, 8, 98, 4, 98, 111, 111, 109, 104, 1, 114, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50,
+        32, 48, 56, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 122, 23, 50, 48, 50, 52, 45, 48, 50,
+        45, 49, 50, 32, 48, 56, 58, 48, 48, 58, 48, 49, 32, 85, 84, 67, 160, 6, 85, 168, 6, 24,
+    ];
+
+    let want = || TaskRun {
+        task_id: 7,
+        task_name: "t1".to_string(),
+        owner: "account_admin".to_string(),
+        query_text: "INSERT INTO t SELECT 1".to_string(),
+        schedule: Some(TaskSchedule::Cron {
+            expr: "0 * * * *".to_string(),
+            time_zone: Some("Asia/Shanghai".to_string()),
+        }),
+        warehouse: None,
+        comment: "comment".to_string(),
+        run_id: "run-1".to_string(),
+        query_id: "query-1".to_string(),
+        state: TaskRunState::Failed,
+        error_code: 1025,
+        error_message: Some("boom".to_string()),
+        attempt_number: 1,
+        scheduled_at: Utc.with_ymd_and_hms(2024, 2, 12, 8, 0, 0).unwrap(),
+        completed_at: Utc.with_ymd_and_hms(2024, 2, 12, 8, 0, 1).unwrap(),
+        session_parameters: BTreeMap::new(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_run_v85.as_slice(), 85, want())?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::Migration;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v86_migration() -> anyhow::Result<()> {
+    let migration_v86 = vec![
+        10, 12, 118, 49, 95, 116, 111, 95, 118, 50, 46, 115, 113, 108, 18, 64, 54, 98, 56, 54, 98,
+        50, 55, 51, 102, 102, 51, 52, 102, 99, 101, 49, 57, 100, 54, 98, 56, 48, 52, 101, 102, 102,
+        53, 97, 51, 102, 53, 55, 52, 55, 97, 100, 97, 52, 101, 97, 97, 50, 50, 102, 49, 100, 52,
+        57, 99, 48, 49, 101, 53, 50, 100, 100, 98, 55, 56, 55, 53, 98, 52, 98, 26, 14, 68, 82, 79,
+        80, 32, 84, 65, 66, 76, 69, 32, 116, 50, 59, 34, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49,
+        52, 32, 48, 56, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 86, 168, 6, 24,
+    ];
+
+    let want = || Migration {
+        name: "v1_to_v2.sql".to_string(),
+        checksum: "6b86b273ff34fce19d6b804eff5a3f5747ada4eaa22f1d49c01e52ddb7875b4b".to_string(),
+        rollback_script: Some("DROP TABLE t2;".to_string()),
+        applied_at: Utc.with_ymd_and_hms(2024, 2, 14, 8, 0, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), migration_v86.as_slice(), 86, want())?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v87_data_field_declared_length() -> anyhow::Result<()> {
+    let data_field_v87 = vec![
+        10, 1, 99, 26, 9, 146, 2, 0, 160, 6, 87, 168, 6, 24, 48, 255, 1, 160, 6, 87, 168, 6, 24,
+    ];
+
+    let want = || TableField::new("c", TableDataType::String).with_declared_length(Some(255));
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), data_field_v87.as_slice(), 87, want())?;
+
+    Ok(())
+}
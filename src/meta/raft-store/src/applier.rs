@@ -117,6 +117,7 @@ impl<'a> Applier<'a> {
 
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.sm.subscriber {
+            subscriber.log_applied(log_id.index, log_time_ms, &self.changes);
             for event in self.changes.drain(..) {
                 subscriber.kv_changed(event);
             }
@@ -116,6 +116,20 @@ pub struct RaftConfig {
 
     /// Max timeout(in milli seconds) when waiting a cluster leader.
     pub wait_leader_timeout: u64,
+
+    /// The location to export the changelog of the KV changes to, for point-in-time recovery.
+    ///
+    /// It is `s3://<bucket>/<path>` or `fs://<path>`, the credentials of S3 are loaded from the
+    /// environment. The changelog is not exported if it is empty.
+    pub changelog_export_uri: String,
+
+    /// The endpoint of the S3 service to export the changelog to.
+    pub changelog_export_endpoint: String,
+
+    /// The region of the S3 service to export the changelog to.
+    ///
+    /// It is loaded from the environment if it is empty.
+    pub changelog_export_region: String,
 }
 
 pub fn get_default_raft_advertise_host() -> String {
@@ -150,6 +164,9 @@ impl Default for RaftConfig {
             sled_tree_prefix: "".to_string(),
             cluster_name: "foo_cluster".to_string(),
             wait_leader_timeout: 70000,
+            changelog_export_uri: "".to_string(),
+            changelog_export_endpoint: "".to_string(),
+            changelog_export_region: "".to_string(),
         }
     }
 }
@@ -97,6 +97,18 @@ const TREE_STATE_MACHINE: &str = "state_machine";
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
     fn kv_changed(&self, change: Change<Vec<u8>, String>);
+
+    /// Called once for every applied raft log, before the changes are sent to `kv_changed`.
+    ///
+    /// `log_time_ms` is the proposing time of the log, 0 if the log has no time embedded.
+    /// `changes` are all of the KV changes made by the log, it is empty if there is none.
+    fn log_applied(
+        &self,
+        _log_index: u64,
+        _log_time_ms: u64,
+        _changes: &[Change<Vec<u8>, String>],
+    ) {
+    }
 }
 
 /// The state machine of the `MemStore`.
@@ -315,6 +327,7 @@ impl StateMachine {
 
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.subscriber {
+            subscriber.log_applied(log_id.index, log_time_ms, &changes);
             for event in changes {
                 subscriber.kv_changed(event);
             }
@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export the raft logs applied to the state machine to object storage,
+//! so that the data can be recovered to a point in time by replaying the KV changes of them.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_meta_raft_store::state_machine::StateMachineSubscriber;
+use databend_common_meta_types::changelog_file_name;
+use databend_common_meta_types::Change;
+use databend_common_meta_types::ChangelogEntry;
+use databend_common_meta_types::MetaStartupError;
+use databend_common_meta_types::NodeId;
+use log::error;
+use log::info;
+use opendal::services;
+use opendal::Operator;
+
+/// The max number of entries in one changelog file.
+const MAX_ENTRIES_PER_FILE: usize = 1024;
+
+/// The max time an applied change waits before it is exported.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Build the operator of the location to export the changelog to.
+///
+/// `uri` is `s3://<bucket>/<path>` or `fs://<path>`. The region of S3 is loaded from the
+/// environment if `region` is empty.
+pub fn changelog_operator(
+    uri: &str,
+    endpoint: &str,
+    region: &str,
+) -> Result<Operator, MetaStartupError> {
+    let invalid = |msg: String| MetaStartupError::InvalidConfig(msg);
+
+    let (scheme, path) = uri
+        .split_once("://")
+        .ok_or_else(|| invalid(format!("invalid changelog_export_uri: {}", uri)))?;
+
+    let op = match scheme {
+        "s3" => {
+            let (bucket, root) = path.split_once('/').unwrap_or((path, ""));
+            let mut builder = services::S3::default();
+            builder.bucket(bucket);
+            builder.root(&format!("/{}", root));
+            if !endpoint.is_empty() {
+                builder.endpoint(endpoint);
+            }
+            if !region.is_empty() {
+                builder.region(region);
+            }
+            Operator::new(builder).map(|b| b.finish())
+        }
+        "fs" => {
+            let mut builder = services::Fs::default();
+            builder.root(path);
+            Operator::new(builder).map(|b| b.finish())
+        }
+        _ => {
+            return Err(invalid(format!(
+                "unsupported scheme of changelog_export_uri: {}",
+                uri
+            )));
+        }
+    };
+
+    op.map_err(|e| invalid(format!("fail to build changelog operator: {}", e)))
+}
+
+/// A state machine subscriber that sends the applied logs to [`ChangelogExporter`].
+///
+/// The entries are stamped with the log index and the proposing time of the log, which are the
+/// same on every node and every time the log is applied.
+#[derive(Clone, Debug)]
+pub(crate) struct ChangelogSender(mpsc::UnboundedSender<ChangelogEntry>);
+
+impl StateMachineSubscriber for ChangelogSender {
+    fn kv_changed(&self, _change: Change<Vec<u8>, String>) {}
+
+    fn log_applied(&self, log_index: u64, log_time_ms: u64, changes: &[Change<Vec<u8>, String>]) {
+        let _ = self
+            .0
+            .send(ChangelogEntry::new(log_index, log_time_ms, changes));
+    }
+}
+
+/// Send a change to every subscriber.
+#[derive(Debug)]
+pub(crate) struct Subscribers(pub(crate) Vec<Box<dyn StateMachineSubscriber>>);
+
+impl StateMachineSubscriber for Subscribers {
+    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
+        for subscriber in self.0.iter() {
+            subscriber.kv_changed(change.clone());
+        }
+    }
+
+    fn log_applied(&self, log_index: u64, log_time_ms: u64, changes: &[Change<Vec<u8>, String>]) {
+        for subscriber in self.0.iter() {
+            subscriber.log_applied(log_index, log_time_ms, changes);
+        }
+    }
+}
+
+/// Exports the received logs to `<node_id>/<changelog_file_name>` of the operator.
+///
+/// The logs are buffered and written as one file every [`FLUSH_INTERVAL`], or when there
+/// are [`MAX_ENTRIES_PER_FILE`] logs, since the objects can not be appended to.
+///
+/// A file may overlap with the former ones if the logs are re-applied after a restart, the
+/// duplicated entries are removed by [`databend_common_meta_types::merge_changelog`] when the
+/// changelog is replayed.
+pub(crate) struct ChangelogExporter {
+    operator: Operator,
+    node_id: NodeId,
+    rx: mpsc::UnboundedReceiver<ChangelogEntry>,
+    buffer: Vec<ChangelogEntry>,
+}
+
+impl ChangelogExporter {
+    pub(crate) fn spawn(operator: Operator, node_id: NodeId) -> ChangelogSender {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let exporter = ChangelogExporter {
+            operator,
+            node_id,
+            rx,
+            buffer: vec![],
+        };
+
+        let _h = databend_common_base::runtime::spawn(exporter.main());
+
+        ChangelogSender(tx)
+    }
+
+    async fn main(mut self) {
+        info!("changelog exporter of node {} started", self.node_id);
+
+        loop {
+            let res = if self.buffer.is_empty() {
+                Ok(self.rx.recv().await)
+            } else {
+                timeout(FLUSH_INTERVAL, self.rx.recv()).await
+            };
+
+            match res {
+                Ok(Some(entry)) => {
+                    self.buffer.push(entry);
+                    if self.buffer.len() >= MAX_ENTRIES_PER_FILE {
+                        self.flush().await;
+                    }
+                }
+                Ok(None) => {
+                    self.flush().await;
+                    break;
+                }
+                Err(_elapsed) => self.flush().await,
+            }
+        }
+
+        info!("changelog exporter of node {} quit", self.node_id);
+    }
+
+    /// Write the buffered logs to a file.
+    ///
+    /// The logs are kept in the buffer if it fails, and are written with the next flush.
+    async fn flush(&mut self) {
+        let (Some(first), Some(last)) = (self.buffer.first(), self.buffer.last()) else {
+            return;
+        };
+
+        let path = format!(
+            "{}/{}",
+            self.node_id,
+            changelog_file_name(first.log_index, last.log_index)
+        );
+
+        let mut data = vec![];
+        for entry in self.buffer.iter() {
+            // Serializing a plain struct does not fail.
+            serde_json::to_writer(&mut data, entry).unwrap();
+            data.push(b'\n');
+        }
+
+        match self.operator.write(&path, data).await {
+            Ok(_) => {
+                info!(
+                    "exported {} logs to changelog file {}",
+                    self.buffer.len(),
+                    path
+                );
+                self.buffer.clear();
+            }
+            Err(e) => {
+                error!("fail to export changelog to {}: {}", path, e);
+            }
+        }
+    }
+}
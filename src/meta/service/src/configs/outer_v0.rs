@@ -296,6 +296,9 @@ pub struct ConfigViaEnv {
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
+    pub metasrv_changelog_export_uri: String,
+    pub metasrv_changelog_export_endpoint: String,
+    pub metasrv_changelog_export_region: String,
 }
 
 impl Default for ConfigViaEnv {
@@ -342,6 +345,9 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
+            metasrv_changelog_export_uri: cfg.raft_config.changelog_export_uri,
+            metasrv_changelog_export_endpoint: cfg.raft_config.changelog_export_endpoint,
+            metasrv_changelog_export_region: cfg.raft_config.changelog_export_region,
         }
     }
 }
@@ -372,6 +378,9 @@ impl Into<Config> for ConfigViaEnv {
             id: self.kvsrv_id,
             sled_tree_prefix: self.sled_tree_prefix,
             cluster_name: self.cluster_name,
+            changelog_export_uri: self.metasrv_changelog_export_uri,
+            changelog_export_endpoint: self.metasrv_changelog_export_endpoint,
+            changelog_export_region: self.metasrv_changelog_export_region,
         };
         let log_config = LogConfig {
             file: FileLogConfig {
@@ -514,6 +523,23 @@ pub struct RaftConfig {
     /// Max timeout(in milli seconds) when waiting a cluster leader.
     #[clap(long, default_value = "180000")]
     pub wait_leader_timeout: u64,
+
+    /// The location to export the changelog of the KV changes to, for point-in-time recovery.
+    ///
+    /// It is `s3://<bucket>/<path>` or `fs://<path>`, the credentials of S3 are loaded from the
+    /// environment. The changelog is not exported if it is empty.
+    #[clap(long, default_value = "")]
+    pub changelog_export_uri: String,
+
+    /// The endpoint of the S3 service to export the changelog to.
+    #[clap(long, default_value = "")]
+    pub changelog_export_endpoint: String,
+
+    /// The region of the S3 service to export the changelog to.
+    ///
+    /// It is loaded from the environment if it is empty.
+    #[clap(long, default_value = "")]
+    pub changelog_export_region: String,
 }
 
 impl Default for RaftConfig {
@@ -544,6 +570,9 @@ impl From<RaftConfig> for InnerRaftConfig {
             sled_tree_prefix: x.sled_tree_prefix,
             cluster_name: x.cluster_name,
             wait_leader_timeout: x.wait_leader_timeout,
+            changelog_export_uri: x.changelog_export_uri,
+            changelog_export_endpoint: x.changelog_export_endpoint,
+            changelog_export_region: x.changelog_export_region,
         }
     }
 }
@@ -570,6 +599,9 @@ impl From<InnerRaftConfig> for RaftConfig {
             sled_tree_prefix: inner.sled_tree_prefix,
             cluster_name: inner.cluster_name,
             wait_leader_timeout: inner.wait_leader_timeout,
+            changelog_export_uri: inner.changelog_export_uri,
+            changelog_export_endpoint: inner.changelog_export_endpoint,
+            changelog_export_region: inner.changelog_export_region,
         }
     }
 }
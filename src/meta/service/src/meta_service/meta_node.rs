@@ -78,6 +78,9 @@ use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
 
+use crate::changelog::changelog_operator;
+use crate::changelog::ChangelogExporter;
+use crate::changelog::Subscribers;
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
@@ -185,6 +188,9 @@ pub struct MetaNodeBuilder {
     node_id: Option<NodeId>,
     raft_config: Option<Config>,
     sto: Option<RaftStore>,
+    changelog_export_uri: String,
+    changelog_export_endpoint: String,
+    changelog_export_region: String,
     monitor_metrics: bool,
     endpoint: Option<Endpoint>,
 }
@@ -218,9 +224,25 @@ impl MetaNodeBuilder {
 
         let dispatcher_tx = EventDispatcher::spawn();
 
-        sto.get_state_machine()
-            .await
-            .set_subscriber(Box::new(DispatcherSender(dispatcher_tx.clone())));
+        let dispatcher_sender = Box::new(DispatcherSender(dispatcher_tx.clone()));
+        if self.changelog_export_uri.is_empty() {
+            sto.get_state_machine()
+                .await
+                .set_subscriber(dispatcher_sender);
+        } else {
+            let operator = changelog_operator(
+                &self.changelog_export_uri,
+                &self.changelog_export_endpoint,
+                &self.changelog_export_region,
+            )?;
+            let changelog_sender = Box::new(ChangelogExporter::spawn(operator, node_id));
+            sto.get_state_machine()
+                .await
+                .set_subscriber(Box::new(Subscribers(vec![
+                    dispatcher_sender,
+                    changelog_sender,
+                ])));
+        }
 
         let mn = Arc::new(MetaNode {
             sto: sto.clone(),
@@ -288,6 +310,9 @@ impl MetaNode {
             node_id: None,
             raft_config: Some(raft_config),
             sto: None,
+            changelog_export_uri: config.changelog_export_uri.clone(),
+            changelog_export_endpoint: config.changelog_export_endpoint.clone(),
+            changelog_export_region: config.changelog_export_region.clone(),
             monitor_metrics: true,
             endpoint: None,
         }
@@ -65,6 +65,9 @@ join = ["j1", "j2"]
 id = 20
 sled_tree_prefix = "sled_foo"
 cluster_name = "foo_cluster"
+changelog_export_uri = "s3://foo/changelog"
+changelog_export_endpoint = "http://127.0.0.1:9900"
+changelog_export_region = "us-west-2"
              "#
     )?;
 
@@ -92,6 +95,12 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.raft_config.id, 20);
         assert_eq!(cfg.raft_config.sled_tree_prefix, "sled_foo");
         assert_eq!(cfg.raft_config.cluster_name, "foo_cluster");
+        assert_eq!(cfg.raft_config.changelog_export_uri, "s3://foo/changelog");
+        assert_eq!(
+            cfg.raft_config.changelog_export_endpoint,
+            "http://127.0.0.1:9900"
+        );
+        assert_eq!(cfg.raft_config.changelog_export_region, "us-west-2");
     });
 
     temp_env::with_vars(
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::merge_changelog;
+use databend_common_meta_types::parse_changelog_file_name;
+use databend_common_meta_types::ChangelogEntry;
+use log::info;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::service::start_metasrv_with_context;
+use crate::tests::service::MetaSrvTestContext;
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+/// Read the changelog files of a node, in the order of the log index.
+fn read_changelog(dir: &std::path::Path) -> anyhow::Result<Vec<ChangelogEntry>> {
+    let mut names = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name().into_string().unwrap();
+        assert!(parse_changelog_file_name(&name).is_some(), "{}", name);
+        names.push(name);
+    }
+    names.sort();
+
+    let mut entries = vec![];
+    for name in names {
+        let data = std::fs::read_to_string(dir.join(name))?;
+        for line in data.lines() {
+            entries.push(serde_json::from_str(line)?);
+        }
+    }
+    Ok(entries)
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_changelog_point_in_time_recovery() -> anyhow::Result<()> {
+    // - Start a metasrv that exports the changelog.
+    // - Write some data, and lose the metasrv.
+    // - Replay the changelog up to a point in time onto a new metasrv and check the data.
+
+    let changelog_dir = tempfile::tempdir()?;
+
+    let mut tc = MetaSrvTestContext::new(0);
+    tc.config.raft_config.changelog_export_uri =
+        format!("fs://{}", changelog_dir.path().to_str().unwrap());
+    start_metasrv_with_context(&mut tc).await?;
+    let client = tc.grpc_client().await?;
+
+    info!("--- write data");
+    client.upsert_kv(UpsertKVReq::update("foo", b"foo")).await?;
+    client.upsert_kv(UpsertKVReq::update("bar", b"bar")).await?;
+    client.upsert_kv(UpsertKVReq::update("wow", b"wow")).await?;
+    client.upsert_kv(UpsertKVReq::delete("bar")).await?;
+
+    sleep(Duration::from_millis(100)).await;
+    let point_in_time = now_ms();
+    sleep(Duration::from_millis(100)).await;
+
+    client
+        .upsert_kv(UpsertKVReq::update("foo", b"foo2"))
+        .await?;
+    client.upsert_kv(UpsertKVReq::update("new", b"new")).await?;
+
+    // Wait for the changelog to be flushed.
+    sleep(Duration::from_secs(3)).await;
+
+    info!("--- lose the metasrv");
+    drop(client);
+    drop(tc);
+
+    // Every applied log is exported, including the ones without KV change.
+    let entries = merge_changelog(read_changelog(&changelog_dir.path().join("0"))?)?;
+    assert_eq!(entries.last().unwrap().log_index + 1, entries.len() as u64);
+    assert!(entries.iter().any(|e| e.changes.is_empty()));
+
+    let keys = entries
+        .iter()
+        .flat_map(|e| e.changes.iter().map(|c| c.key.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["foo", "bar", "wow", "bar", "foo", "new"], keys);
+
+    info!("--- replay the changelog onto a new metasrv");
+    let mut tc2 = MetaSrvTestContext::new(0);
+    start_metasrv_with_context(&mut tc2).await?;
+    let client2 = tc2.grpc_client().await?;
+
+    for entry in entries.iter().take_while(|e| e.time_ms <= point_in_time) {
+        for change in entry.changes.iter() {
+            client2.upsert_kv(change.to_upsert_kv()).await?;
+        }
+    }
+
+    let get = |key: &'static str| {
+        let client2 = client2.clone();
+        async move {
+            let res = client2.get_kv(key).await?;
+            Ok::<_, anyhow::Error>(res.map(|seqv| seqv.data))
+        }
+    };
+    assert_eq!(Some(b"foo".to_vec()), get("foo").await?);
+    assert_eq!(None, get("bar").await?);
+    assert_eq!(Some(b"wow".to_vec()), get("wow").await?);
+    assert_eq!(None, get("new").await?);
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Change;
+use crate::UpsertKV;
+
+/// A raft log applied to the state machine, as it is exported to the changelog.
+///
+/// The changelog consists of files named by [`changelog_file_name`], each of which contains
+/// the entries in JSON, one entry per line, in the order they are applied.
+///
+/// Every applied log has an entry, even if it makes no KV change, so that the missing logs can be
+/// detected when the changelog is replayed. An entry may be exported more than once, e.g., when
+/// the logs are re-applied after a restart, or by every node of a cluster.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogEntry {
+    /// The index of the raft log.
+    pub log_index: u64,
+    /// The time in milliseconds since 1970 when the log is proposed, 0 if the log has no time.
+    pub time_ms: u64,
+    /// The KV changes made by the log.
+    pub changes: Vec<ChangelogChange>,
+}
+
+/// A KV change made by a raft log.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogChange {
+    pub key: String,
+    /// The value after the change, `None` if the key is deleted.
+    pub value: Option<Vec<u8>>,
+    /// The expiration time in second since 1970.
+    pub expire_at: Option<u64>,
+}
+
+impl ChangelogEntry {
+    pub fn new(log_index: u64, time_ms: u64, changes: &[Change<Vec<u8>, String>]) -> Self {
+        Self {
+            log_index,
+            time_ms,
+            changes: changes.iter().filter_map(ChangelogChange::new).collect(),
+        }
+    }
+}
+
+impl ChangelogChange {
+    /// Build a change from a KV change, `None` if the change has no key.
+    pub fn new(change: &Change<Vec<u8>, String>) -> Option<Self> {
+        let key = change.ident.clone()?;
+        let (value, expire_at) = match &change.result {
+            Some(seqv) => (
+                Some(seqv.data.clone()),
+                seqv.meta.as_ref().and_then(|m| m.expire_at),
+            ),
+            None => (None, None),
+        };
+        Some(Self {
+            key,
+            value,
+            expire_at,
+        })
+    }
+
+    /// Returns the upsert that makes the same change when the changelog is replayed.
+    pub fn to_upsert_kv(&self) -> UpsertKV {
+        match (&self.value, self.expire_at) {
+            (Some(value), Some(expire_at)) => {
+                UpsertKV::update(&self.key, value).with_expire_sec(expire_at)
+            }
+            (Some(value), None) => UpsertKV::update(&self.key, value),
+            (None, _) => UpsertKV::delete(&self.key),
+        }
+    }
+}
+
+/// The logs that are missing from a changelog.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("the changelog of raft log [{first_index}, {last_index}] is missing")]
+pub struct ChangelogGap {
+    pub first_index: u64,
+    pub last_index: u64,
+}
+
+/// Merge the changelog entries exported by any nodes into the entries of every log in the order
+/// of the log index, without duplicates.
+///
+/// It returns an error if a log is missing, since a replay without it does not rebuild the data.
+/// The log index starts from 0.
+pub fn merge_changelog(
+    entries: impl IntoIterator<Item = ChangelogEntry>,
+) -> Result<Vec<ChangelogEntry>, ChangelogGap> {
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+    // Stable, the first exported one of the duplicated entries is kept.
+    entries.sort_by_key(|e| e.log_index);
+    entries.dedup_by_key(|e| e.log_index);
+
+    let mut next_index = 0;
+    for entry in entries.iter() {
+        if entry.log_index != next_index {
+            return Err(ChangelogGap {
+                first_index: next_index,
+                last_index: entry.log_index - 1,
+            });
+        }
+        next_index += 1;
+    }
+
+    Ok(entries)
+}
+
+/// The name of a changelog file that contains the entries of the logs in
+/// `[first_index, last_index]`.
+pub fn changelog_file_name(first_index: u64, last_index: u64) -> String {
+    // Zero padded so that the files are listed in the order of the log index.
+    format!("{:020}-{:020}.json", first_index, last_index)
+}
+
+/// Parse the name of a changelog file into the range of the log index of the entries in it.
+pub fn parse_changelog_file_name(name: &str) -> Option<(u64, u64)> {
+    let (first, last) = name.strip_suffix(".json")?.split_once('-')?;
+    Some((first.parse().ok()?, last.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_changelog;
+    use super::ChangelogEntry;
+    use super::ChangelogGap;
+
+    fn entry(log_index: u64, time_ms: u64) -> ChangelogEntry {
+        ChangelogEntry {
+            log_index,
+            time_ms,
+            changes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_merge_changelog() {
+        // Re-applied after a restart, and exported by two nodes.
+        let node1 = vec![entry(0, 0), entry(1, 10), entry(2, 20), entry(1, 10)];
+        let node2 = vec![entry(2, 21), entry(3, 30)];
+
+        let merged = merge_changelog(node1.into_iter().chain(node2)).unwrap();
+        assert_eq!(merged, vec![
+            entry(0, 0),
+            entry(1, 10),
+            entry(2, 20),
+            entry(3, 30)
+        ]);
+
+        // The node installed a snapshot of log [0, 2].
+        let res = merge_changelog(vec![entry(0, 0), entry(3, 30), entry(4, 40)]);
+        assert_eq!(
+            res,
+            Err(ChangelogGap {
+                first_index: 1,
+                last_index: 2
+            })
+        );
+
+        let res = merge_changelog(vec![entry(1, 10)]);
+        assert_eq!(
+            res,
+            Err(ChangelogGap {
+                first_index: 0,
+                last_index: 0
+            })
+        );
+    }
+}
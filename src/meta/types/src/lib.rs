@@ -20,6 +20,7 @@
 
 mod applied_state;
 mod change;
+mod changelog;
 mod cluster;
 mod endpoint;
 mod eval_expire_time;
@@ -59,6 +60,12 @@ pub mod protobuf {
 }
 pub use applied_state::AppliedState;
 pub use change::Change;
+pub use changelog::changelog_file_name;
+pub use changelog::merge_changelog;
+pub use changelog::parse_changelog_file_name;
+pub use changelog::ChangelogChange;
+pub use changelog::ChangelogEntry;
+pub use changelog::ChangelogGap;
 pub use cluster::Node;
 pub use cluster::NodeInfo;
 pub use endpoint::Endpoint;
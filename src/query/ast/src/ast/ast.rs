@@ -113,6 +113,20 @@ pub(crate) fn write_comma_separated_list(
     Ok(())
 }
 
+/// Write input items into `a b c`
+pub(crate) fn write_space_separated_list(
+    f: &mut Formatter<'_>,
+    items: impl IntoIterator<Item = impl Display>,
+) -> std::fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
 /// Write input items into `'a', 'b', 'c'`
 pub(crate) fn write_comma_separated_quoted_list(
     f: &mut Formatter<'_>,
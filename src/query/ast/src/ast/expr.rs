@@ -312,6 +312,8 @@ pub enum TypeName {
     Timestamp,
     Binary,
     String,
+    /// A string with a declared length, e.g. `VARCHAR(255)`, the length is not checked.
+    Varchar(u64),
     Array(Box<TypeName>),
     Map {
         key_type: Box<TypeName>,
@@ -438,6 +440,8 @@ pub enum BinaryOperator {
     Xor,
     Like,
     NotLike,
+    ILike,
+    NotILike,
     Regexp,
     RLike,
     NotRegexp,
@@ -730,6 +734,12 @@ impl Display for BinaryOperator {
             BinaryOperator::NotLike => {
                 write!(f, "NOT LIKE")
             }
+            BinaryOperator::ILike => {
+                write!(f, "ILIKE")
+            }
+            BinaryOperator::NotILike => {
+                write!(f, "NOT ILIKE")
+            }
             BinaryOperator::Regexp => {
                 write!(f, "REGEXP")
             }
@@ -861,6 +871,9 @@ impl Display for TypeName {
             TypeName::String => {
                 write!(f, "STRING")?;
             }
+            TypeName::Varchar(length) => {
+                write!(f, "VARCHAR({length})")?;
+            }
             TypeName::Array(ty) => {
                 write!(f, "ARRAY({})", ty)?;
             }
@@ -710,12 +710,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Graph => "Graph",
             ExplainKind::Pipeline => "Pipeline",
             ExplainKind::Fragments => "Fragments",
+            ExplainKind::Distributed { .. } => "Distributed",
             ExplainKind::Raw => "Raw",
             ExplainKind::Optimized => "Optimized",
             ExplainKind::Plan => "Plan",
             ExplainKind::Memo(_) => "Memo",
             ExplainKind::JOIN => "JOIN",
             ExplainKind::AnalyzePlan => "Analyze",
+            ExplainKind::Memory => "Memory",
+            ExplainKind::Cost { .. } => "Cost",
+            ExplainKind::DescribePipeline { .. } => "DescribePipeline",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
         let node = FormatTreeNode::with_children(format_ctx, vec![child]);
@@ -925,6 +929,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowMetrics".to_string());
     }
 
+    fn visit_show_status(&mut self, global: bool, show_options: &'ast Option<ShowOptions>) {
+        let name = if global {
+            "ShowGlobalStatus"
+        } else {
+            "ShowSessionStatus"
+        };
+        self.visit_show_options(show_options, name.to_string());
+    }
+
     fn visit_show_engines(&mut self, show_options: &'ast Option<ShowOptions>) {
         self.visit_show_options(show_options, "ShowEngines".to_string());
     }
@@ -1679,6 +1692,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_view_owner(&mut self, stmt: &'ast AlterViewOwnerStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+        let role_format_ctx = AstFormatContext::new(format!("Role {}", stmt.role));
+        let role_child = FormatTreeNode::new(role_format_ctx);
+
+        let name = "AlterViewOwner".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child, role_child]);
+        self.children.push(node);
+    }
+
     fn visit_drop_view(&mut self, stmt: &'ast DropViewStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
         let child = self.children.pop().unwrap();
@@ -1745,6 +1770,41 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_rollup_table(&mut self, stmt: &'ast CreateRollupTableStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.source_database, &stmt.source_table);
+        let source_child = self.children.pop().unwrap();
+
+        let mut aggregates_children = Vec::with_capacity(stmt.aggregates.len());
+        for column in stmt.aggregates.iter() {
+            self.visit_expr(&column.expr);
+            aggregates_children.push(self.children.pop().unwrap());
+        }
+        let aggregates_ctx =
+            AstFormatContext::with_children("Aggregate".to_string(), aggregates_children.len());
+        let aggregates_child = FormatTreeNode::with_children(aggregates_ctx, aggregates_children);
+
+        let mut group_by_children = Vec::with_capacity(stmt.group_by.len());
+        for column in stmt.group_by.iter() {
+            self.visit_expr(&column.expr);
+            group_by_children.push(self.children.pop().unwrap());
+        }
+        let group_by_ctx =
+            AstFormatContext::with_children("GroupingBy".to_string(), group_by_children.len());
+        let group_by_child = FormatTreeNode::with_children(group_by_ctx, group_by_children);
+
+        let name = "CreateRollupTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 4);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            table_child,
+            source_child,
+            aggregates_child,
+            group_by_child,
+        ]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -2324,6 +2384,49 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_stage(&mut self, stmt: &'ast AlterStageStmt) {
+        let mut children = Vec::new();
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stmt.stage_name));
+        children.push(FormatTreeNode::new(stage_name_format_ctx));
+        match &stmt.action {
+            AlterStageAction::Set {
+                location,
+                file_format_options,
+                comments,
+            } => {
+                if let Some(location) = location {
+                    let location_format_ctx =
+                        AstFormatContext::new(format!("Location {}", location));
+                    children.push(FormatTreeNode::new(location_format_ctx));
+                }
+                for (k, v) in file_format_options.iter() {
+                    let file_format_format_ctx =
+                        AstFormatContext::new(format!("FileFormat {} = {:?}", k, v));
+                    children.push(FormatTreeNode::new(file_format_format_ctx));
+                }
+                if let Some(comments) = comments {
+                    let comments_format_ctx =
+                        AstFormatContext::new(format!("Comments {}", comments));
+                    children.push(FormatTreeNode::new(comments_format_ctx));
+                }
+            }
+            AlterStageAction::RenameTo { new_stage_name } => {
+                let new_name_format_ctx =
+                    AstFormatContext::new(format!("NewStageName {}", new_stage_name));
+                children.push(FormatTreeNode::new(new_name_format_ctx));
+            }
+            AlterStageAction::RefreshCredentials => {
+                let action_format_ctx = AstFormatContext::new("RefreshCredentials".to_string());
+                children.push(FormatTreeNode::new(action_format_ctx));
+            }
+        }
+
+        let name = "AlterStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_show_stages(&mut self) {
         let name = "ShowStages".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -2611,6 +2714,48 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_notification_integration(
+        &mut self,
+        stmt: &'ast CreateNotificationIntegrationStmt,
+    ) {
+        let ctx = AstFormatContext::new(format!("NotificationIntegrationName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateNotificationIntegration".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_notification_integration(
+        &mut self,
+        stmt: &'ast AlterNotificationIntegrationStmt,
+    ) {
+        let ctx = AstFormatContext::new(format!("NotificationIntegrationName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterNotificationIntegration".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_notification_integration(&mut self, stmt: &'ast DropNotificationIntegrationStmt) {
+        let ctx = AstFormatContext::new(format!("NotificationIntegrationName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropNotificationIntegration".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_notification_integrations(&mut self) {
+        let ctx = AstFormatContext::new("ShowNotificationIntegrations".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
     fn visit_create_password_policy(&mut self, stmt: &'ast CreatePasswordPolicyStmt) {
         let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -201,6 +201,13 @@ pub(crate) fn pretty_copy_into_table(copy_stmt: CopyIntoTableStmt) -> RcDoc<'sta
                 .append(pretty_query(*query))
                 .append(RcDoc::text(")")),
         })
+        .append(if let Some(cols) = &copy_stmt.src_columns {
+            RcDoc::space().append(parenthesized(
+                interweave_comma(cols.iter().map(|col| RcDoc::text(col.to_string()))).group(),
+            ))
+        } else {
+            RcDoc::nil()
+        })
         .append(pretty_file_format(&copy_stmt.file_format))
         .append(if let Some(pattern) = &copy_stmt.pattern {
             RcDoc::line()
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_dot_separated_list;
+use crate::ast::Identifier;
+use crate::ast::UriLocation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub uri_location: UriLocation,
+    /// The id of the backup to take the incremental backup on.
+    pub since: Option<String>,
+}
+
+impl Display for BackupDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "BACKUP DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO {}", self.uri_location)?;
+        if let Some(since) = &self.since {
+            write!(f, " INCREMENTAL SINCE '{since}'")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestoreDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub uri_location: UriLocation,
+    pub new_database: Identifier,
+}
+
+impl Display for RestoreDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "RESTORE DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM {} AS {}", self.uri_location, self.new_database)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowBackupsStmt {
+    pub uri_location: UriLocation,
+}
+
+impl Display for ShowBackupsStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW BACKUPS FROM {}", self.uri_location)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestoreMetasrvStmt {
+    pub timestamp: String,
+    pub uri_location: UriLocation,
+}
+
+impl Display for RestoreMetasrvStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "RESTORE METASRV TO TIMESTAMP '{}' FROM {}",
+            self.timestamp, self.uri_location
+        )
+    }
+}
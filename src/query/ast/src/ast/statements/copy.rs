@@ -83,6 +83,8 @@ pub struct CopyIntoTableStmt {
     pub src: CopyIntoTableSource,
     pub dst: TableIdentifier,
     pub dst_columns: Option<Vec<Identifier>>,
+    /// The columns of the files loaded into `dst_columns`, if the files are loaded from a location.
+    pub src_columns: Option<Vec<CopySourceColumn>>,
 
     pub hints: Option<Hint>,
 
@@ -103,6 +105,8 @@ pub struct CopyIntoTableStmt {
     pub disable_variant_check: bool,
     pub return_failed_only: bool,
     pub on_error: String,
+    /// `SCHEMA = INFER`: create the table with the schema inferred from the files if it doesn't exist.
+    pub infer_schema: bool,
 }
 
 impl CopyIntoTableStmt {
@@ -120,6 +124,7 @@ impl CopyIntoTableStmt {
             CopyIntoTableOption::DisableVariantCheck(v) => self.disable_variant_check = v,
             CopyIntoTableOption::ReturnFailedOnly(v) => self.return_failed_only = v,
             CopyIntoTableOption::OnError(v) => self.on_error = v,
+            CopyIntoTableOption::InferSchema => self.infer_schema = true,
         }
     }
 
@@ -165,6 +170,9 @@ impl Display for CopyIntoTableStmt {
             write!(f, "({})", columns.iter().map(|c| c.to_string()).join(","))?;
         }
         write!(f, " FROM {}", self.src)?;
+        if let Some(columns) = &self.src_columns {
+            write!(f, " ({})", columns.iter().map(|c| c.to_string()).join(", "))?;
+        }
 
         if let Some(files) = &self.files {
             write!(f, " FILES = (")?;
@@ -203,6 +211,10 @@ impl Display for CopyIntoTableStmt {
         write!(f, " DISABLE_VARIANT_CHECK = {}", self.disable_variant_check)?;
         write!(f, " ON_ERROR = {}", self.on_error)?;
 
+        if self.infer_schema {
+            write!(f, " SCHEMA = INFER")?;
+        }
+
         Ok(())
     }
 }
@@ -252,6 +264,27 @@ impl CopyIntoLocationStmt {
     }
 }
 
+/// `IMPORT INTO <table> FROM '<path>'` loads the files in the filesystem of the query node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportStmt {
+    pub dst: TableIdentifier,
+    /// The absolute path of the files, whose last component may be a glob pattern.
+    pub path: String,
+    pub file_format: BTreeMap<String, String>,
+}
+
+impl Display for ImportStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IMPORT INTO {} FROM '{}'", self.dst, self.path)?;
+        if !self.file_format.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            write_comma_separated_map(f, &self.file_format)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CopyIntoTableSource {
     Location(FileLocation),
@@ -271,6 +304,24 @@ impl Display for CopyIntoTableSource {
     }
 }
 
+/// A column of the files loaded by `COPY INTO <table> FROM <location> (<columns>)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopySourceColumn {
+    /// A field of the files.
+    Field(Identifier),
+    /// A metadata column of the files like `$FILENAME`, the name is without the `$`.
+    Metadata(Identifier),
+}
+
+impl Display for CopySourceColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CopySourceColumn::Field(field) => write!(f, "{field}"),
+            CopySourceColumn::Metadata(name) => write!(f, "${}", name.name),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CopyIntoLocationSource {
     Query(Box<Query>),
@@ -494,6 +545,7 @@ pub enum CopyIntoTableOption {
     DisableVariantCheck(bool),
     ReturnFailedOnly(bool),
     OnError(String),
+    InferSchema,
 }
 
 pub enum CopyIntoLocationOption {
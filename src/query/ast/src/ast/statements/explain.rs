@@ -22,6 +22,8 @@ pub enum ExplainKind {
     Graph,
     Pipeline,
     Fragments,
+    // `json` is set by `EXPLAIN (FORMAT JSON) DISTRIBUTED`.
+    Distributed { json: bool },
     Raw,
     Optimized,
     Plan,
@@ -30,4 +32,15 @@ pub enum ExplainKind {
 
     // Explain analyze plan
     AnalyzePlan,
+
+    // Estimate the peak memory usage of the physical plan
+    Memory,
+
+    // The values of the cost model for each operator of the plan.
+    // `json` is set by `EXPLAIN (FORMAT JSON) COST`.
+    Cost { json: bool },
+
+    // The processors of the pipeline rendered by `DESCRIBE PIPELINE FOR <query>`.
+    // `dot` is set by `FORMAT = DOT`.
+    DescribePipeline { dot: bool },
 }
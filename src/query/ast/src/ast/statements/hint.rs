@@ -15,12 +15,14 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use crate::ast::write_space_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Hint {
     pub hints_list: Vec<HintItem>,
+    pub optimizer_hints: Vec<OptimizerHint>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +31,58 @@ pub struct HintItem {
     pub expr: Expr,
 }
 
+/// Hints that override the decisions of the optimizer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptimizerHint {
+    /// `HASH_JOIN(t1 t2)`
+    HashJoin(Vec<Identifier>),
+    /// `SORT_MERGE_JOIN(t1 t2)`
+    SortMergeJoin(Vec<Identifier>),
+    /// `BROADCAST(t1)`
+    Broadcast(Vec<Identifier>),
+    /// `NO_INDEX(t col)`
+    NoIndex {
+        table: Identifier,
+        columns: Vec<Identifier>,
+    },
+    /// `USE_CLUSTERING_KEY(t)`
+    UseClusteringKey(Identifier),
+    /// `PARALLEL(8)`
+    Parallel(u64),
+}
+
+impl Display for OptimizerHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimizerHint::HashJoin(tables) => {
+                write!(f, "HASH_JOIN(")?;
+                write_space_separated_list(f, tables)?;
+            }
+            OptimizerHint::SortMergeJoin(tables) => {
+                write!(f, "SORT_MERGE_JOIN(")?;
+                write_space_separated_list(f, tables)?;
+            }
+            OptimizerHint::Broadcast(tables) => {
+                write!(f, "BROADCAST(")?;
+                write_space_separated_list(f, tables)?;
+            }
+            OptimizerHint::NoIndex { table, columns } => {
+                write!(f, "NO_INDEX({table}")?;
+                for column in columns {
+                    write!(f, " {column}")?;
+                }
+            }
+            OptimizerHint::UseClusteringKey(table) => {
+                write!(f, "USE_CLUSTERING_KEY({table}")?;
+            }
+            OptimizerHint::Parallel(num) => {
+                write!(f, "PARALLEL({num}")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
 impl Display for Hint {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "/*+ ")?;
@@ -39,6 +93,9 @@ impl Display for Hint {
             write!(f, "{}", hint.expr)?;
             write!(f, ") ")?;
         }
+        for hint in &self.optimizer_hints {
+            write!(f, "{hint} ")?;
+        }
         write!(f, "*/")
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod backup;
 mod call;
 mod catalog;
 mod columns;
@@ -28,10 +29,13 @@ mod kill;
 mod lock;
 mod merge_into;
 mod network_policy;
+mod notification;
 mod password_policy;
 mod pipe;
 mod presign;
+mod query_cache;
 mod replace;
+mod rollup;
 mod share;
 mod show;
 mod stage;
@@ -39,6 +43,7 @@ mod statement;
 mod stream;
 mod table;
 mod task;
+mod transaction;
 mod udf;
 mod unset;
 mod update;
@@ -46,6 +51,7 @@ mod user;
 mod view;
 mod virtual_column;
 
+pub use backup::*;
 pub use call::*;
 pub use catalog::*;
 pub use columns::*;
@@ -62,10 +68,13 @@ pub use kill::*;
 pub use lock::*;
 pub use merge_into::*;
 pub use network_policy::*;
+pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
+pub use query_cache::*;
 pub use replace::*;
+pub use rollup::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
@@ -73,6 +82,7 @@ pub use statement::*;
 pub use stream::*;
 pub use table::*;
 pub use task::*;
+pub use transaction::*;
 pub use udf::*;
 pub use unset::*;
 pub use update::*;
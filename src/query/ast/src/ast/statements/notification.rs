@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_meta_app::schema::CreateOption;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateNotificationIntegrationStmt {
+    pub create_option: CreateOption,
+    pub name: String,
+    pub notification_type: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateNotificationIntegrationStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "NOTIFICATION INTEGRATION ")?;
+        if let CreateOption::CreateIfNotExists(if_not_exists) = self.create_option {
+            if if_not_exists {
+                write!(f, "IF NOT EXISTS ")?;
+            }
+        }
+        write!(f, "{}", self.name)?;
+        write!(f, " TYPE = {}", self.notification_type)?;
+        write!(f, " URL = '{}'", self.url)?;
+        write!(f, " EVENTS = ({})", self.events.join(", "))?;
+        if let Some(enabled) = self.enabled {
+            write!(f, " ENABLED = {}", enabled)?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterNotificationIntegrationStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+impl Display for AlterNotificationIntegrationStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER NOTIFICATION INTEGRATION ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET", self.name)?;
+
+        if let Some(url) = &self.url {
+            write!(f, " URL = '{}'", url)?;
+        }
+        if let Some(events) = &self.events {
+            write!(f, " EVENTS = ({})", events.join(", "))?;
+        }
+        if let Some(enabled) = self.enabled {
+            write!(f, " ENABLED = {}", enabled)?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropNotificationIntegrationStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropNotificationIntegrationStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP NOTIFICATION INTEGRATION ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// The result caches removed by `PURGE QUERY CACHE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PurgeQueryCacheTarget {
+    All,
+    /// The result caches of the queries reading the table `[<database>.]<table>`.
+    Table(String),
+    /// The result caches older than the interval, e.g. `'1 hour'`.
+    OlderThan(String),
+}
+
+impl Display for PurgeQueryCacheTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PurgeQueryCacheTarget::All => write!(f, "ALL"),
+            PurgeQueryCacheTarget::Table(table) => write!(f, "WHERE TABLE = '{table}'"),
+            PurgeQueryCacheTarget::OlderThan(interval) => {
+                write!(f, "OLDER THAN INTERVAL '{interval}'")
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_dot_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+
+/// An expression of a rollup table, optionally named by `AS <alias>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollupColumn {
+    pub expr: Expr,
+    pub alias: Option<Identifier>,
+}
+
+impl Display for RollupColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expr)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {alias}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateRollupTableStmt {
+    pub if_not_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub source_database: Option<Identifier>,
+    pub source_table: Identifier,
+    pub aggregates: Vec<RollupColumn>,
+    pub group_by: Vec<RollupColumn>,
+}
+
+impl Display for CreateRollupTableStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE ROLLUP TABLE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(self.database.iter())
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " ON ")?;
+        write_dot_separated_list(
+            f,
+            self.source_database.iter().chain(Some(&self.source_table)),
+        )?;
+        write!(f, " AGGREGATE (")?;
+        write_comma_separated_list(f, &self.aggregates)?;
+        write!(f, ") GROUPING BY ")?;
+        write_comma_separated_list(f, &self.group_by)
+    }
+}
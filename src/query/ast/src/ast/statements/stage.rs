@@ -81,6 +81,66 @@ impl Display for CreateStageStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterStageStmt {
+    pub if_exists: bool,
+    pub stage_name: String,
+    pub action: AlterStageAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterStageAction {
+    Set {
+        location: Option<UriLocation>,
+        file_format_options: BTreeMap<String, String>,
+        comments: Option<String>,
+    },
+    RenameTo {
+        new_stage_name: String,
+    },
+    /// Rebuild the operator of the stage, to check the credentials loaded from the environment.
+    RefreshCredentials,
+}
+
+impl Display for AlterStageStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER STAGE")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.stage_name)?;
+
+        match &self.action {
+            AlterStageAction::Set {
+                location,
+                file_format_options,
+                comments,
+            } => {
+                write!(f, " SET")?;
+                if let Some(location) = location {
+                    write!(f, " URL = {location}")?;
+                }
+                if !file_format_options.is_empty() {
+                    write!(f, " FILE_FORMAT = (")?;
+                    write_comma_separated_map(f, file_format_options)?;
+                    write!(f, " )")?;
+                }
+                if let Some(comments) = comments {
+                    write!(f, " COMMENTS = '{comments}'")?;
+                }
+            }
+            AlterStageAction::RenameTo { new_stage_name } => {
+                write!(f, " RENAME TO {new_stage_name}")?;
+            }
+            AlterStageAction::RefreshCredentials => {
+                write!(f, " REFRESH CREDENTIALS")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SelectStageOption {
     Files(Vec<String>),
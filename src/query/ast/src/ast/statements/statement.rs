@@ -14,7 +14,9 @@
 
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::time::Duration;
 
+use databend_common_io::escape_string_with_quote;
 use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::PrincipalIdentity;
 use databend_common_meta_app::principal::UserIdentity;
@@ -41,9 +43,28 @@ pub enum Statement {
     ExplainAnalyze {
         query: Box<Statement>,
     },
+    RecommendIndex {
+        query: Box<Statement>,
+    },
 
     CopyIntoTable(CopyIntoTableStmt),
     CopyIntoLocation(CopyIntoLocationStmt),
+    Import(ImportStmt),
+
+    // Migrations
+    ApplyMigration {
+        location: String,
+    },
+    ShowMigrations,
+    RollbackMigration {
+        name: String,
+    },
+
+    // Query result cache
+    ShowQueryCache,
+    PurgeQueryCache {
+        target: PurgeQueryCacheTarget,
+    },
 
     Call(CallStmt),
 
@@ -56,6 +77,16 @@ pub enum Statement {
     ShowMetrics {
         show_options: Option<ShowOptions>,
     },
+    ShowIoStats {
+        query_id: String,
+    },
+    ShowIncompatibilities {
+        query: String,
+    },
+    ShowStatus {
+        global: bool,
+        show_options: Option<ShowOptions>,
+    },
     ShowEngines {
         show_options: Option<ShowOptions>,
     },
@@ -78,6 +109,8 @@ pub enum Statement {
         object_id: String,
     },
 
+    ResetMemoryPeak,
+
     SetVariable {
         is_global: bool,
         variable: Identifier,
@@ -86,6 +119,16 @@ pub enum Statement {
 
     UnSetVariable(UnSetStmt),
 
+    // Transaction
+    SetTransactionIsolation {
+        level: TransactionIsolationLevel,
+    },
+    /// Starts a transaction that only scopes the reads of the following statements, the writes
+    /// are committed by each statement and can not be rolled back.
+    Begin,
+    /// Ends the transaction started by `BEGIN`.
+    Commit,
+
     SetRole {
         is_default: bool,
         role_name: String,
@@ -118,14 +161,20 @@ pub enum Statement {
     UseDatabase {
         database: Identifier,
     },
+    BackupDatabase(BackupDatabaseStmt),
+    RestoreDatabase(RestoreDatabaseStmt),
+    ShowBackups(ShowBackupsStmt),
+    RestoreMetasrv(RestoreMetasrvStmt),
 
     // Tables
     ShowTables(ShowTablesStmt),
     ShowCreateTable(ShowCreateTableStmt),
+    ShowSegments(ShowSegmentsStmt),
     DescribeTable(DescribeTableStmt),
     ShowTablesStatus(ShowTablesStatusStmt),
     ShowDropTables(ShowDropTablesStmt),
     AttachTable(AttachTableStmt),
+    CloneTable(CloneTableStmt),
     CreateTable(CreateTableStmt),
     DropTable(DropTableStmt),
     UndropTable(UndropTableStmt),
@@ -144,6 +193,7 @@ pub enum Statement {
     // Views
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
+    AlterViewOwner(AlterViewOwnerStmt),
     DropView(DropViewStmt),
 
     // Streams
@@ -152,6 +202,9 @@ pub enum Statement {
     ShowStreams(ShowStreamsStmt),
     DescribeStream(DescribeStreamStmt),
 
+    // Rollups
+    CreateRollupTable(CreateRollupTableStmt),
+
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
@@ -205,6 +258,7 @@ pub enum Statement {
     DescribeStage {
         stage_name: String,
     },
+    AlterStage(AlterStageStmt),
     RemoveStage {
         location: String,
         pattern: String,
@@ -212,6 +266,9 @@ pub enum Statement {
     ListStage {
         location: String,
         pattern: Option<String>,
+        /// Only list the files modified within the duration.
+        staged_since: Option<Duration>,
+        limit: Option<u64>,
     },
     // Connection
     CreateConnection(CreateConnectionStmt),
@@ -225,6 +282,11 @@ pub enum Statement {
         name: String,
         file_format_options: FileFormatOptionsAst,
     },
+    AlterFileFormat {
+        if_exists: bool,
+        name: String,
+        file_format_options: FileFormatOptionsAst,
+    },
     DropFileFormat {
         if_exists: bool,
         name: String,
@@ -258,6 +320,12 @@ pub enum Statement {
     DescNetworkPolicy(DescNetworkPolicyStmt),
     ShowNetworkPolicies,
 
+    // notification integration
+    CreateNotificationIntegration(CreateNotificationIntegrationStmt),
+    AlterNotificationIntegration(AlterNotificationIntegrationStmt),
+    DropNotificationIntegration(DropNotificationIntegrationStmt),
+    ShowNotificationIntegrations,
+
     // password policy
     CreatePasswordPolicy(CreatePasswordPolicyStmt),
     AlterPasswordPolicy(AlterPasswordPolicyStmt),
@@ -316,6 +384,17 @@ impl Statement {
                 }
                 format!("{}", Statement::CreateStage(stage_clone))
             }
+            Statement::AlterStage(stage) => {
+                let mut stage_clone = stage.clone();
+                if let AlterStageAction::Set {
+                    location: Some(location),
+                    ..
+                } = &mut stage_clone.action
+                {
+                    location.connection = location.connection.mask()
+                }
+                format!("{}", Statement::AlterStage(stage_clone))
+            }
             Statement::AttachTable(attach) => {
                 let mut attach_clone = attach.clone();
                 attach_clone.uri_location.connection = attach_clone.uri_location.connection.mask();
@@ -329,6 +408,15 @@ impl Statement {
 impl Display for Statement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Statement::Explain {
+                kind: ExplainKind::DescribePipeline { dot },
+                query,
+            } => {
+                write!(f, "DESCRIBE PIPELINE FOR {query}")?;
+                if *dot {
+                    write!(f, " FORMAT = DOT")?;
+                }
+            }
             Statement::Explain { kind, query } => {
                 write!(f, "EXPLAIN")?;
                 match *kind {
@@ -337,18 +425,29 @@ impl Display for Statement {
                     ExplainKind::Graph => write!(f, " GRAPH")?,
                     ExplainKind::Pipeline => write!(f, " PIPELINE")?,
                     ExplainKind::Fragments => write!(f, " FRAGMENTS")?,
+                    ExplainKind::Distributed { json: false } => write!(f, " DISTRIBUTED")?,
+                    ExplainKind::Distributed { json: true } => {
+                        write!(f, " (FORMAT JSON) DISTRIBUTED")?
+                    }
                     ExplainKind::Raw => write!(f, " RAW")?,
                     ExplainKind::Optimized => write!(f, " Optimized")?,
                     ExplainKind::Plan => (),
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::JOIN => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::Memory => write!(f, " MEMORY")?,
+                    ExplainKind::Cost { json: false } => write!(f, " COST")?,
+                    ExplainKind::Cost { json: true } => write!(f, " (FORMAT JSON) COST")?,
+                    ExplainKind::DescribePipeline { .. } => unreachable!(),
                 }
                 write!(f, " {query}")?;
             }
             Statement::ExplainAnalyze { query } => {
                 write!(f, "EXPLAIN ANALYZE {query}")?;
             }
+            Statement::RecommendIndex { query } => {
+                write!(f, "RECOMMEND INDEX FOR {query}")?;
+            }
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
@@ -357,6 +456,12 @@ impl Display for Statement {
             Statement::Update(update) => write!(f, "{update}")?,
             Statement::CopyIntoTable(stmt) => write!(f, "{stmt}")?,
             Statement::CopyIntoLocation(stmt) => write!(f, "{stmt}")?,
+            Statement::Import(stmt) => write!(f, "{stmt}")?,
+            Statement::ApplyMigration { location } => write!(f, "APPLY MIGRATION '{location}'")?,
+            Statement::ShowMigrations => write!(f, "SHOW MIGRATIONS")?,
+            Statement::RollbackMigration { name } => write!(f, "ROLLBACK MIGRATION '{name}'")?,
+            Statement::ShowQueryCache => write!(f, "SHOW QUERY CACHE")?,
+            Statement::PurgeQueryCache { target } => write!(f, "PURGE QUERY CACHE {target}")?,
             Statement::ShowSettings { show_options } => {
                 write!(f, "SHOW SETTINGS")?;
                 if let Some(show_options) = show_options {
@@ -369,12 +474,33 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::ShowIoStats { query_id } => {
+                write!(f, "SHOW IO STATS FOR QUERY '{query_id}'")?
+            }
+            Statement::ShowIncompatibilities { query } => write!(
+                f,
+                "SHOW INCOMPATIBILITIES FOR QUERY '{}'",
+                escape_string_with_quote(query, Some('\''))
+            )?,
             Statement::ShowMetrics { show_options } => {
                 write!(f, "SHOW METRICS")?;
                 if let Some(show_options) = show_options {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::ShowStatus {
+                global,
+                show_options,
+            } => {
+                write!(f, "SHOW")?;
+                if *global {
+                    write!(f, " GLOBAL")?;
+                }
+                write!(f, " STATUS")?;
+                if let Some(show_options) = show_options {
+                    write!(f, " {show_options}")?;
+                }
+            }
             Statement::ShowEngines { show_options } => {
                 write!(f, "SHOW ENGINES")?;
                 if let Some(show_options) = show_options {
@@ -417,6 +543,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{object_id}'")?;
             }
+            Statement::ResetMemoryPeak => write!(f, "RESET MEMORY PEAK")?,
             Statement::SetVariable {
                 is_global,
                 variable,
@@ -429,6 +556,11 @@ impl Display for Statement {
                 write!(f, "{variable} = {value}")?;
             }
             Statement::UnSetVariable(unset) => write!(f, "{unset}")?,
+            Statement::SetTransactionIsolation { level } => {
+                write!(f, "SET TRANSACTION ISOLATION LEVEL {level}")?
+            }
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
             Statement::SetRole {
                 is_default,
                 role_name,
@@ -458,13 +590,19 @@ impl Display for Statement {
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
+            Statement::BackupDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowBackups(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreMetasrv(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateTable(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSegments(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
             Statement::ShowDropTables(stmt) => write!(f, "{stmt}")?,
             Statement::AttachTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CloneTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateTable(stmt) => write!(f, "{stmt}")?,
             Statement::DropTable(stmt) => write!(f, "{stmt}")?,
             Statement::UndropTable(stmt) => write!(f, "{stmt}")?,
@@ -479,11 +617,13 @@ impl Display for Statement {
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterViewOwner(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
             Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowStreams(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRollupTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
@@ -544,11 +684,22 @@ impl Display for Statement {
                 write!(f, " {udf_name}")?;
             }
             Statement::AlterUDF(stmt) => write!(f, "{stmt}")?,
-            Statement::ListStage { location, pattern } => {
+            Statement::ListStage {
+                location,
+                pattern,
+                staged_since,
+                limit,
+            } => {
                 write!(f, "LIST @{location}")?;
                 if let Some(pattern) = pattern {
                     write!(f, " PATTERN = '{pattern}'")?;
                 }
+                if let Some(staged_since) = staged_since {
+                    write!(f, " STAGED_SINCE = {} SECONDS", staged_since.as_secs())?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, " LIMIT {limit}")?;
+                }
             }
             Statement::ShowStages => write!(f, "SHOW STAGES")?,
             Statement::DropStage {
@@ -562,6 +713,7 @@ impl Display for Statement {
                 write!(f, " {stage_name}")?;
             }
             Statement::CreateStage(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterStage(stmt) => write!(f, "{stmt}")?,
             Statement::RemoveStage { location, pattern } => {
                 write!(f, "REMOVE STAGE @{location}")?;
                 if !pattern.is_empty() {
@@ -587,6 +739,18 @@ impl Display for Statement {
                 write!(f, " {name}")?;
                 write!(f, " {file_format_options}")?;
             }
+            Statement::AlterFileFormat {
+                if_exists,
+                name,
+                file_format_options,
+            } => {
+                write!(f, "ALTER FILE FORMAT")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {name}")?;
+                write!(f, " {file_format_options}")?;
+            }
             Statement::DropFileFormat { if_exists, name } => {
                 write!(f, "DROP FILE_FORMAT")?;
                 if *if_exists {
@@ -617,6 +781,10 @@ impl Display for Statement {
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowNetworkPolicies => write!(f, "SHOW NETWORK POLICIES")?,
+            Statement::CreateNotificationIntegration(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterNotificationIntegration(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNotificationIntegration(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowNotificationIntegrations => write!(f, "SHOW NOTIFICATION INTEGRATIONS")?,
             Statement::CreatePasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropPasswordPolicy(stmt) => write!(f, "{stmt}")?,
@@ -86,6 +86,31 @@ impl Display for ShowCreateTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowSegmentsStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub snapshot_id: Option<String>,
+}
+
+impl Display for ShowSegmentsStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW SEGMENTS FOR TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(snapshot_id) = &self.snapshot_id {
+            write!(f, " AT SNAPSHOT '{snapshot_id}'")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShowTablesStatusStmt {
     pub database: Option<Identifier>,
@@ -222,6 +247,36 @@ impl Display for AttachTableStmt {
     }
 }
 
+/// Creates a table which refers to the snapshot of the source table, without copying the data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloneTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub new_database: Option<Identifier>,
+    pub new_table: Identifier,
+    pub snapshot_id: Option<String>,
+}
+
+impl Display for CloneTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CLONE TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " INTO ")?;
+        write_dot_separated_list(f, self.new_database.iter().chain(Some(&self.new_table)))?;
+        if let Some(snapshot_id) = &self.snapshot_id {
+            write!(f, " AT SNAPSHOT '{snapshot_id}'")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
     Columns(Vec<ColumnDefinition>),
@@ -611,6 +666,8 @@ pub struct AnalyzeTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// `None` if the statistics are estimated from the block statistics.
+    pub update_statistics: Option<UpdateStatisticsOption>,
 }
 
 impl Display for AnalyzeTableStmt {
@@ -623,11 +680,58 @@ impl Display for AnalyzeTableStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if let Some(update_statistics) = &self.update_statistics {
+            write!(f, " UPDATE STATISTICS{update_statistics}")?;
+        }
 
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateStatisticsOption {
+    /// Chooses the method by the `statistics_auto_sample_threshold` setting.
+    Auto,
+    All(StatisticsMethod),
+    Columns(Vec<(Identifier, StatisticsMethod)>),
+}
+
+impl Display for UpdateStatisticsOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UpdateStatisticsOption::Auto => Ok(()),
+            UpdateStatisticsOption::All(method) => write!(f, " {method}"),
+            UpdateStatisticsOption::Columns(columns) => {
+                write!(f, " (")?;
+                for (i, (column, method)) in columns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{column} {method}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatisticsMethod {
+    /// Reads all the data.
+    Full,
+    /// Reads a random fraction of the rows.
+    Sample { percent: u64 },
+}
+
+impl Display for StatisticsMethod {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            StatisticsMethod::Full => write!(f, "FULL"),
+            StatisticsMethod::Sample { percent } => write!(f, "SAMPLE {percent} PERCENT"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExistsTableStmt {
     pub catalog: Option<Identifier>,
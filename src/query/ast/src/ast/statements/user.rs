@@ -161,6 +161,8 @@ pub enum AccountMgrLevel {
     Table(Option<String>, String),
     UDF(String),
     Stage(String),
+    /// Columns of a table, e.g. `SELECT (c1, c2) ON db.t`.
+    Column(Option<String>, String, Vec<String>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -201,6 +203,11 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
+                if let AccountMgrLevel::Column(_, _, columns) = level {
+                    write!(f, " (")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
+                }
                 write!(f, " ON")?;
                 match level {
                     AccountMgrLevel::Global => write!(f, " *.*")?,
@@ -211,7 +218,8 @@ impl Display for AccountMgrSource {
                             write!(f, " *")?;
                         }
                     }
-                    AccountMgrLevel::Table(database_name, table_name) => {
+                    AccountMgrLevel::Table(database_name, table_name)
+                    | AccountMgrLevel::Column(database_name, table_name, _) => {
                         if let Some(database_name) = database_name {
                             write!(f, " {database_name}.{table_name}")?;
                         } else {
@@ -234,7 +242,8 @@ impl Display for AccountMgrSource {
                             write!(f, " *")?;
                         }
                     }
-                    AccountMgrLevel::Table(database_name, table_name) => {
+                    AccountMgrLevel::Table(database_name, table_name)
+                    | AccountMgrLevel::Column(database_name, table_name, _) => {
                         if let Some(database_name) = database_name {
                             write!(f, " {database_name}.{table_name}")?;
                         } else {
@@ -88,6 +88,28 @@ impl Display for AlterViewStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterViewOwnerStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub view: Identifier,
+    pub role: String,
+}
+
+impl Display for AlterViewOwnerStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER VIEW ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.view)),
+        )?;
+        write!(f, " OWNER TO ROLE {}", self.role)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DropViewStmt {
     pub if_exists: bool,
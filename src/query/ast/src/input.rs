@@ -86,6 +86,8 @@ pub enum Dialect {
     PostgreSQL,
     MySQL,
     Hive,
+    Snowflake,
+    Trino,
     Experimental,
 }
 
@@ -94,6 +96,7 @@ impl Dialect {
         match self {
             Dialect::MySQL => c == '`',
             Dialect::Hive => c == '`',
+            Dialect::Snowflake | Dialect::Trino => c == '"',
             // TODO: remove '`' quote support once mysql handler correctly set mysql dialect.
             Dialect::Experimental | Dialect::PostgreSQL => c == '"' || c == '`',
         }
@@ -103,6 +106,7 @@ impl Dialect {
         match self {
             Dialect::MySQL => c == '\'' || c == '"',
             Dialect::Hive => c == '\'' || c == '"',
+            Dialect::Snowflake | Dialect::Trino => c == '\'',
             Dialect::Experimental | Dialect::PostgreSQL => c == '\'',
         }
     }
@@ -111,6 +115,7 @@ impl Dialect {
         match self {
             Dialect::MySQL => false,
             Dialect::Hive => false,
+            Dialect::Snowflake | Dialect::Trino => true,
             Dialect::Experimental | Dialect::PostgreSQL => true,
         }
     }
@@ -119,6 +124,8 @@ impl Dialect {
         match self {
             Dialect::MySQL => false,
             Dialect::Hive => true,
+            Dialect::Snowflake => true,
+            Dialect::Trino => false,
             Dialect::Experimental | Dialect::PostgreSQL => false,
         }
     }
@@ -29,6 +29,7 @@ pub use visitors::walk_query;
 pub use visitors::walk_query_mut;
 pub use visitors::walk_select_target;
 pub use visitors::walk_select_target_mut;
+pub use visitors::walk_set_expr_mut;
 pub use visitors::walk_statement_mut;
 pub use visitors::walk_table_reference_mut;
 pub use visitors::Visitor;
@@ -21,6 +21,8 @@ use crate::ast::CopyIntoLocationStmt;
 use crate::ast::CopyIntoTableOption;
 use crate::ast::CopyIntoTableSource;
 use crate::ast::CopyIntoTableStmt;
+use crate::ast::CopySourceColumn;
+use crate::ast::Identifier;
 use crate::ast::Statement;
 use crate::ast::Statement::CopyIntoLocation;
 use crate::ast::TableIdentifier;
@@ -59,14 +61,16 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
             ~ #hint?
             ~ INTO ~ #table_triple ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ ^FROM ~ ^#copy_into_table_source
+            ~ ( "(" ~ #comma_separated_list1(copy_source_column) ~ ")" )?
             ~ #copy_into_table_option*
         },
-        |(_copy, opt_hints, _into, dst, dst_columns, _from, src, opts)| {
+        |(_copy, opt_hints, _into, dst, dst_columns, _from, src, src_columns, opts)| {
             let mut copy_stmt = CopyIntoTableStmt {
                 hints: opt_hints,
                 src,
                 dst,
                 dst_columns: dst_columns.map(|(_, columns, _)| columns),
+                src_columns: src_columns.map(|(_, columns, _)| columns),
                 files: Default::default(),
                 pattern: Default::default(),
                 file_format: Default::default(),
@@ -79,6 +83,7 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
                 disable_variant_check: Default::default(),
                 on_error: "abort".to_string(),
                 return_failed_only: Default::default(),
+                infer_schema: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -88,6 +93,19 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
     )(i)
 }
 
+fn copy_source_column(i: Input) -> IResult<CopySourceColumn> {
+    alt((
+        map(ident, CopySourceColumn::Field),
+        map(rule! { MetadataColumn }, |token| {
+            CopySourceColumn::Metadata(Identifier {
+                name: token.text()[1..].to_string(),
+                quote: None,
+                span: Some(token.span),
+            })
+        }),
+    ))(i)
+}
+
 fn copy_into_location(i: Input) -> IResult<Statement> {
     let copy_into_location_source = alt((
         map(table_triple, CopyIntoLocationSource::Table),
@@ -131,10 +149,12 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
          | #copy_into_table: "`COPY
                 INTO { [<database_name>.]<table_name> { ( <columns> ) } }
                 FROM { internalStage | externalStage | externalLocation | ( <query> ) }
+                [ ( { <file_column> | $FILENAME | $FILE_ROW_NUMBER | $FILE_CONTENT_KEY } [ , ... ] ) ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] ) ]
                 [ PATTERN = '<regex_pattern>' ]
                 [ VALIDATION_MODE = RETURN_ROWS ]
+                [ SCHEMA = INFER ]
                 [ copyOptions ]`"
     )(i)
 }
@@ -187,6 +207,9 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
             rule! { RETURN_FAILED_ONLY ~ "=" ~ #literal_bool },
             |(_, _, return_failed_only)| CopyIntoTableOption::ReturnFailedOnly(return_failed_only),
         ),
+        map(rule! { SCHEMA ~ "=" ~ INFER }, |_| {
+            CopyIntoTableOption::InferSchema
+        }),
     ))(i)
 }
 
@@ -25,6 +25,7 @@ use pratt::PrattParser;
 use pratt::Precedence;
 
 use crate::ast::*;
+use crate::input::Dialect;
 use crate::input::Input;
 use crate::input::WithSpan;
 use crate::parser::query::*;
@@ -380,6 +381,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 BinaryOperator::NotRegexp => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::RLike => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::NotRLike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::ILike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::NotILike => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::SoundsLike => Affix::Infix(Precedence(20), Associativity::Left),
 
                 BinaryOperator::BitwiseOr => Affix::Infix(Precedence(22), Associativity::Left),
@@ -1318,6 +1321,7 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::RLike, rule! { RLIKE }),
             value(BinaryOperator::NotRLike, rule! { NOT ~ RLIKE }),
             value(BinaryOperator::SoundsLike, rule! { SOUNDS ~ LIKE }),
+            snowflake_binary_op,
             value(BinaryOperator::BitwiseOr, rule! { BitWiseOr }),
             value(BinaryOperator::BitwiseAnd, rule! { BitWiseAnd }),
             value(BinaryOperator::BitwiseXor, rule! { BitWiseXor }),
@@ -1327,6 +1331,20 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
     ))(i)
 }
 
+/// Parses the binary operators only available in the Snowflake dialect.
+fn snowflake_binary_op(i: Input) -> IResult<BinaryOperator> {
+    if i.1 != Dialect::Snowflake {
+        return Err(nom::Err::Error(nom::error::ParseError::from_error_kind(
+            i,
+            nom::error::ErrorKind::Tag,
+        )));
+    }
+    alt((
+        value(BinaryOperator::ILike, rule! { ILIKE }),
+        value(BinaryOperator::NotILike, rule! { NOT ~ ILIKE }),
+    ))(i)
+}
+
 pub fn json_op(i: Input) -> IResult<JsonOperator> {
     alt((
         value(JsonOperator::Arrow, rule! { "->" }),
@@ -1581,9 +1599,12 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         TypeName::Binary,
         rule! { ( BINARY | VARBINARY | LONGBLOB | MEDIUMBLOB |  TINYBLOB| BLOB ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
-    let ty_string = value(
-        TypeName::String,
+    let ty_string = map(
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
+        |(_, length)| match length {
+            Some((_, length, _)) => TypeName::Varchar(length),
+            None => TypeName::String,
+        },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
@@ -147,9 +147,15 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
 }
 
 pub fn file_format_clause(i: Input) -> IResult<BTreeMap<String, String>> {
+    let inline_format = map(rule! { "(" ~ ^#format_options ~ ^")" }, |(_, opts, _)| opts);
+    // `FILE_FORMAT = <name>` is short for `FILE_FORMAT = (FORMAT_NAME = '<name>')`.
+    let named_format = map(ident, |name| {
+        BTreeMap::from([("format_name".to_string(), name.to_string())])
+    });
+
     map(
-        rule! { FILE_FORMAT ~ ^"=" ~ ^"(" ~ ^#format_options ~ ^")" },
-        |(_, _, _, opts, _)| opts,
+        rule! { FILE_FORMAT ~ ^"=" ~ ^( #inline_format | #named_format ) },
+        |(_, _, opts)| opts,
     )(i)
 }
 
@@ -199,13 +205,24 @@ pub fn string_location(i: Input) -> IResult<FileLocation> {
             #literal_string
             ~ (CONNECTION ~ ^"=" ~ ^#connection_options ~ ","?)?
             ~ (CREDENTIALS ~ ^"=" ~ ^#connection_options ~ ","?)?
+            ~ (CREDENTIALS_FROM ~ ^"=" ~ ^#literal_string ~ ","?)?
+            ~ (ROLE_ARN ~ ^"=" ~ ^#literal_string ~ ","?)?
             ~ (LOCATION_PREFIX ~ ^"=" ~ ^#literal_string ~ ","?)?
         },
-        |(location, connection_opts, credentials_opts, location_prefix)| {
+        |(
+            location,
+            connection_opts,
+            credentials_opts,
+            credentials_from,
+            role_arn,
+            location_prefix,
+        )| {
             if let Some(stripped) = location.strip_prefix('@') {
                 if location_prefix.is_none()
                     && connection_opts.is_none()
                     && credentials_opts.is_none()
+                    && credentials_from.is_none()
+                    && role_arn.is_none()
                 {
                     Ok(FileLocation::Stage(stripped.to_string()))
                 } else {
@@ -224,6 +241,12 @@ pub fn string_location(i: Input) -> IResult<FileLocation> {
                 // TODO: We will use `CONNECTION` to replace `CREDENTIALS`.
                 let mut conns = connection_opts.map(|v| v.2).unwrap_or_default();
                 conns.extend(credentials_opts.map(|v| v.2).unwrap_or_default());
+                if let Some((_, _, credentials_from, _)) = credentials_from {
+                    conns.insert("credentials_from".to_string(), credentials_from);
+                }
+                if let Some((_, _, role_arn, _)) = role_arn {
+                    conns.insert("role_arn".to_string(), role_arn);
+                }
 
                 let uri = UriLocation::from_uri(location, part_prefix, conns)
                     .map_err(|_| nom::Err::Failure(ErrorKind::Other("invalid uri")))?;
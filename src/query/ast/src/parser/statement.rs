@@ -63,7 +63,7 @@ pub enum CreateDatabaseOption {
 pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
+            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | DISTRIBUTED | RAW | OPTIMIZED | MEMO | MEMORY | COST )? ~ #statement
         },
         |(_, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -86,9 +86,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                     Some(TokenKind::JOIN) => ExplainKind::JOIN,
                     Some(TokenKind::GRAPH) => ExplainKind::Graph,
                     Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
+                    Some(TokenKind::DISTRIBUTED) => ExplainKind::Distributed { json: false },
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::OPTIMIZED) => ExplainKind::Optimized,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::MEMORY) => ExplainKind::Memory,
+                    Some(TokenKind::COST) => ExplainKind::Cost { json: false },
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -96,6 +99,30 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             })
         },
     );
+    let explain_json = map(
+        rule! {
+            EXPLAIN ~ "(" ~ FORMAT ~ ^JSON ~ ^")" ~ ^( DISTRIBUTED | COST ) ~ #statement
+        },
+        |(_, _, _, _, _, kind, statement)| Statement::Explain {
+            kind: match kind.kind {
+                TokenKind::DISTRIBUTED => ExplainKind::Distributed { json: true },
+                TokenKind::COST => ExplainKind::Cost { json: true },
+                _ => unreachable!(),
+            },
+            query: Box::new(statement.stmt),
+        },
+    );
+    let describe_pipeline = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ PIPELINE ~ ^FOR ~ #query ~ ( FORMAT ~ "=" ~ ^DOT )?
+        },
+        |(_, _, _, query, opt_format)| Statement::Explain {
+            kind: ExplainKind::DescribePipeline {
+                dot: opt_format.is_some(),
+            },
+            query: Box::new(Statement::Query(Box::new(query))),
+        },
+    );
     let explain_analyze = map(
         rule! {
             EXPLAIN ~ ANALYZE ~ #statement
@@ -104,6 +131,14 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             query: Box::new(statement.stmt),
         },
     );
+    let recommend_index = map(
+        rule! {
+            RECOMMEND ~ ^INDEX ~ ^FOR ~ #statement
+        },
+        |(_, _, _, statement)| Statement::RecommendIndex {
+            query: Box::new(statement.stmt),
+        },
+    );
 
     let create_task = map(
         rule! {
@@ -343,6 +378,27 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
         |(_, _, show_options)| Statement::ShowMetrics { show_options },
     );
+    let show_io_stats = map(
+        rule! {
+            SHOW ~ IO ~ STATS ~ FOR ~ QUERY ~ #literal_string
+        },
+        |(_, _, _, _, _, query_id)| Statement::ShowIoStats { query_id },
+    );
+    let show_incompatibilities = map(
+        rule! {
+            SHOW ~ INCOMPATIBILITIES ~ ^FOR ~ ^QUERY ~ ^#literal_string
+        },
+        |(_, _, _, _, query)| Statement::ShowIncompatibilities { query },
+    );
+    let show_status = map(
+        rule! {
+            SHOW ~ ( GLOBAL | SESSION )? ~ STATUS ~ #show_options?
+        },
+        |(_, opt_scope, _, show_options)| Statement::ShowStatus {
+            global: matches!(opt_scope, Some(scope) if scope.kind == GLOBAL),
+            show_options,
+        },
+    );
     let show_engines = map(
         rule! {
             SHOW ~ ENGINES ~ #show_options?
@@ -396,6 +452,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    // reset memory peak;
+    let reset_memory_peak = value(Statement::ResetMemoryPeak, rule! { RESET ~ MEMORY ~ PEAK });
+
     let set_variable = map(
         rule! {
             SET ~ GLOBAL? ~ #ident ~ "=" ~ #subexpr(0)
@@ -418,6 +477,16 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let set_transaction_isolation = map(
+        rule! {
+            SET ~ TRANSACTION ~ ^ISOLATION ~ ^LEVEL ~ ^#transaction_isolation_level
+        },
+        |(_, _, _, _, level)| Statement::SetTransactionIsolation { level },
+    );
+    // The transaction only scopes the reads, there is no `ROLLBACK`.
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+
     let set_role = map(
         rule! {
             SET ~ DEFAULT? ~ ROLE ~ #role_name
@@ -584,6 +653,50 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             })
         },
     );
+    let backup_database = map(
+        rule! {
+            BACKUP ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ TO ~ #uri_location
+            ~ ( INCREMENTAL ~ ^SINCE ~ ^#literal_string )?
+        },
+        |(_, _, (catalog, database), _, uri_location, opt_since)| {
+            Statement::BackupDatabase(BackupDatabaseStmt {
+                catalog,
+                database,
+                uri_location,
+                since: opt_since.map(|(_, _, since)| since),
+            })
+        },
+    );
+    let restore_database = map(
+        rule! {
+            RESTORE ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ FROM ~ #uri_location ~ AS ~ ^#ident
+        },
+        |(_, _, (catalog, database), _, uri_location, _, new_database)| {
+            Statement::RestoreDatabase(RestoreDatabaseStmt {
+                catalog,
+                database,
+                uri_location,
+                new_database,
+            })
+        },
+    );
+    let show_backups = map(
+        rule! {
+            SHOW ~ BACKUPS ~ FROM ~ #uri_location
+        },
+        |(_, _, _, uri_location)| Statement::ShowBackups(ShowBackupsStmt { uri_location }),
+    );
+    let restore_metasrv = map(
+        rule! {
+            RESTORE ~ METASRV ~ TO ~ ^TIMESTAMP ~ ^#literal_string ~ FROM ~ #uri_location
+        },
+        |(_, _, _, _, timestamp, _, uri_location)| {
+            Statement::RestoreMetasrv(RestoreMetasrvStmt {
+                timestamp,
+                uri_location,
+            })
+        },
+    );
     let use_database = map(
         rule! {
             USE ~ #ident
@@ -640,6 +753,20 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             })
         },
     );
+    let show_segments = map(
+        rule! {
+            SHOW ~ SEGMENTS ~ FOR ~ TABLE ~ #dot_separated_idents_1_to_3
+            ~ ( AT ~ ^SNAPSHOT ~ ^#literal_string )?
+        },
+        |(_, _, _, _, (catalog, database, table), opt_snapshot)| {
+            Statement::ShowSegments(ShowSegmentsStmt {
+                catalog,
+                database,
+                table,
+                snapshot_id: opt_snapshot.map(|(_, _, snapshot_id)| snapshot_id),
+            })
+        },
+    );
     let describe_table = map(
         rule! {
             ( DESC | DESCRIBE ) ~ #dot_separated_idents_1_to_3
@@ -704,6 +831,22 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             })
         },
     );
+    let clone_table = map(
+        rule! {
+            CLONE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ INTO ~ ^#dot_separated_idents_1_to_2
+            ~ ( AT ~ ^SNAPSHOT ~ ^#literal_string )?
+        },
+        |(_, _, (catalog, database, table), _, (new_database, new_table), opt_snapshot)| {
+            Statement::CloneTable(CloneTableStmt {
+                catalog,
+                database,
+                table,
+                new_database,
+                new_table,
+                snapshot_id: opt_snapshot.map(|(_, _, snapshot_id)| snapshot_id),
+            })
+        },
+    );
     let create_table = map_res(
         rule! {
             CREATE ~ (OR ~ REPLACE)? ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -748,6 +891,42 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             }))
         },
     );
+    let create_rollup_table = map(
+        rule! {
+            CREATE ~ ROLLUP ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ON ~ #dot_separated_idents_1_to_2
+            ~ AGGREGATE ~ ^"(" ~ ^#comma_separated_list1(rollup_column) ~ ^")"
+            ~ GROUPING ~ ^BY ~ ^#comma_separated_list1(rollup_column)
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, table),
+            _,
+            (source_database, source_table),
+            _,
+            _,
+            aggregates,
+            _,
+            _,
+            _,
+            group_by,
+        )| {
+            Statement::CreateRollupTable(CreateRollupTableStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                table,
+                source_database,
+                source_table,
+                aggregates,
+                group_by,
+            })
+        },
+    );
     let drop_table = map(
         rule! {
             DROP ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ ALL?
@@ -877,13 +1056,15 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     );
     let analyze_table = map(
         rule! {
-            ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3
+            ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ ( UPDATE ~ ^STATISTICS ~ #update_statistics_option? )?
         },
-        |(_, _, (catalog, database, table))| {
+        |(_, _, (catalog, database, table), opt_update_statistics)| {
             Statement::AnalyzeTable(AnalyzeTableStmt {
                 catalog,
                 database,
                 table,
+                update_statistics: opt_update_statistics
+                    .map(|(_, _, option)| option.unwrap_or(UpdateStatisticsOption::Auto)),
             })
         },
     );
@@ -964,6 +1145,22 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let alter_view_owner = map(
+        rule! {
+            ALTER ~ VIEW
+            ~ #dot_separated_idents_1_to_3
+            ~ OWNER ~ TO ~ ROLE ~ #role_name
+        },
+        |(_, _, (catalog, database, view), _, _, _, role)| {
+            Statement::AlterViewOwner(AlterViewOwnerStmt {
+                catalog,
+                database,
+                view,
+                role,
+            })
+        },
+    );
+
     let create_index = map_res(
         rule! {
             CREATE ~ (OR ~ REPLACE)? ~ ASYNC? ~ AGGREGATING ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1317,10 +1514,13 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     let list_stage = map(
         rule! {
             LIST ~ #at_string ~ (PATTERN ~ "=" ~ #literal_string)?
+            ~ (STAGED_SINCE ~ ^"=" ~ ^#literal_duration)? ~ (LIMIT ~ ^#literal_u64)?
         },
-        |(_, location, opt_pattern)| Statement::ListStage {
+        |(_, location, opt_pattern, opt_staged_since, opt_limit)| Statement::ListStage {
             location,
             pattern: opt_pattern.map(|v| v.2),
+            staged_since: opt_staged_since.map(|v| v.2),
+            limit: opt_limit.map(|v| v.1),
         },
     );
 
@@ -1344,6 +1544,19 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let alter_stage = map(
+        rule! {
+            ALTER ~ STAGE ~ ( IF ~ ^EXISTS )? ~ #stage_name ~ #alter_stage_action
+        },
+        |(_, _, opt_if_exists, stage_name, action)| {
+            Statement::AlterStage(AlterStageStmt {
+                if_exists: opt_if_exists.is_some(),
+                stage_name: stage_name.to_string(),
+                action,
+            })
+        },
+    );
+
     let desc_stage = map(
         rule! {
             (DESC | DESCRIBE) ~ STAGE ~ #ident
@@ -1597,6 +1810,17 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let alter_file_format = map(
+        rule! {
+            ALTER ~ FILE ~ FORMAT ~ ( IF ~ ^EXISTS )? ~ #ident ~ SET? ~ #format_options
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| Statement::AlterFileFormat {
+            if_exists: opt_if_exists.is_some(),
+            name: name.to_string(),
+            file_format_options: FileFormatOptionsAst { options },
+        },
+    );
+
     let drop_file_format = map(
         rule! {
             DROP ~ FILE ~ FORMAT ~ ( IF ~  EXISTS )? ~ #ident
@@ -1752,6 +1976,87 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! { SHOW ~ NETWORK ~ ^POLICIES },
     );
 
+    let create_notification_integration = map_res(
+        rule! {
+            CREATE ~ (OR ~ REPLACE)? ~ NOTIFICATION ~ ^INTEGRATION ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ TYPE ~ ^Eq ~ ^#ident
+             ~ URL ~ ^Eq ~ ^#literal_string
+             ~ EVENTS ~ ^Eq ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+             ~ ( ENABLED ~ ^Eq ~ ^#literal_bool )?
+             ~ ( COMMENT ~ ^Eq ~ ^#literal_string )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            notification_type,
+            _,
+            _,
+            url,
+            _,
+            _,
+            _,
+            events,
+            _,
+            opt_enabled,
+            opt_comment,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateNotificationIntegrationStmt {
+                create_option,
+                name: name.to_string(),
+                notification_type: notification_type.to_string(),
+                url,
+                events: events.iter().map(|event| event.to_string()).collect(),
+                enabled: opt_enabled.map(|opt| opt.2),
+                comment: opt_comment.map(|opt| opt.2),
+            };
+            Ok(Statement::CreateNotificationIntegration(stmt))
+        },
+    );
+    let alter_notification_integration = map(
+        rule! {
+            ALTER ~ NOTIFICATION ~ ^INTEGRATION ~ ( IF ~ ^EXISTS )? ~ ^#ident ~ SET
+             ~ ( URL ~ ^Eq ~ ^#literal_string )?
+             ~ ( EVENTS ~ ^Eq ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+             ~ ( ENABLED ~ ^Eq ~ ^#literal_bool )?
+             ~ ( COMMENT ~ ^Eq ~ ^#literal_string )?
+        },
+        |(_, _, _, opt_if_exists, name, _, opt_url, opt_events, opt_enabled, opt_comment)| {
+            let stmt = AlterNotificationIntegrationStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                url: opt_url.map(|opt| opt.2),
+                events: opt_events.map(|opt| opt.3.iter().map(|event| event.to_string()).collect()),
+                enabled: opt_enabled.map(|opt| opt.2),
+                comment: opt_comment.map(|opt| opt.2),
+            };
+            Statement::AlterNotificationIntegration(stmt)
+        },
+    );
+    let drop_notification_integration = map(
+        rule! {
+            DROP ~ NOTIFICATION ~ ^INTEGRATION ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropNotificationIntegrationStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropNotificationIntegration(stmt)
+        },
+    );
+    let show_notification_integrations = value(
+        Statement::ShowNotificationIntegrations,
+        rule! { SHOW ~ NOTIFICATION ~ ^INTEGRATIONS },
+    );
+
     let create_password_policy = map_res(
         rule! {
             CREATE ~ (OR ~ REPLACE)? ~ PASSWORD ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -1811,6 +2116,54 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let import = map(
+        rule! {
+            IMPORT ~ ^INTO ~ ^#dot_separated_idents_1_to_3
+            ~ ^FROM ~ ^#literal_string
+            ~ ( #file_format_clause )?
+        },
+        |(_, _, dst, _, path, file_format)| {
+            Statement::Import(ImportStmt {
+                dst: TableIdentifier::from_tuple(dst),
+                path,
+                file_format: file_format.unwrap_or_default(),
+            })
+        },
+    );
+
+    let apply_migration = map(
+        rule! {
+            APPLY ~ MIGRATION ~ ^#literal_string
+        },
+        |(_, _, location)| Statement::ApplyMigration { location },
+    );
+    let show_migrations = value(Statement::ShowMigrations, rule! { SHOW ~ MIGRATIONS });
+    let rollback_migration = map(
+        rule! {
+            ROLLBACK ~ ^MIGRATION ~ ^#literal_string
+        },
+        |(_, _, name)| Statement::RollbackMigration { name },
+    );
+
+    let show_query_cache = value(Statement::ShowQueryCache, rule! { SHOW ~ QUERY ~ CACHE });
+    let purge_query_cache_target = alt((
+        value(PurgeQueryCacheTarget::All, rule! { ALL }),
+        map(
+            rule! { WHERE ~ ^TABLE ~ ^"=" ~ ^#literal_string },
+            |(_, _, _, table)| PurgeQueryCacheTarget::Table(table),
+        ),
+        map(
+            rule! { OLDER ~ ^THAN ~ ^INTERVAL ~ ^#literal_string },
+            |(_, _, _, interval)| PurgeQueryCacheTarget::OlderThan(interval),
+        ),
+    ));
+    let purge_query_cache = map(
+        rule! {
+            PURGE ~ QUERY ~ ^CACHE ~ ^#purge_query_cache_target
+        },
+        |(_, _, _, target)| Statement::PurgeQueryCache { target },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1878,17 +2231,24 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         // query, explain,show
         rule!(
         #map(query, |query| Statement::Query(Box::new(query)))
-            | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
+            | #explain_json : "`EXPLAIN (FORMAT JSON) { DISTRIBUTED | COST } <statement>`"
+            | #explain : "`EXPLAIN [PIPELINE | GRAPH | MEMORY] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #describe_pipeline : "`DESCRIBE PIPELINE FOR <query> [FORMAT = DOT]`"
+            | #recommend_index : "`RECOMMEND INDEX FOR <statement>`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
             | #show_process_list : "`SHOW PROCESSLIST`"
             | #show_metrics : "`SHOW METRICS`"
+            | #show_io_stats : "`SHOW IO STATS FOR QUERY '<query_id>'`"
+            | #show_incompatibilities : "`SHOW INCOMPATIBILITIES FOR QUERY '<sql>'`"
+            | #show_status : "`SHOW [GLOBAL | SESSION] STATUS [<show_limit>]`"
             | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
             | #show_indexes : "`SHOW INDEXES`"
             | #show_locks : "`SHOW LOCKS [IN ACCOUNT] [WHERE ...]`"
             | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
+            | #reset_memory_peak : "`RESET MEMORY PEAK`"
             | #vacuum_temp_files : "VACUUM TEMPORARY FILES [RETAIN number SECONDS|DAYS] [LIMIT number]"
         ),
         // database
@@ -1900,14 +2260,22 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
+            | #backup_database : "`BACKUP DATABASE <database> TO <uri> [INCREMENTAL SINCE '<backup_id>']`"
+            | #restore_database : "`RESTORE DATABASE <database> FROM <uri> AS <new_database>`"
+            | #show_backups : "`SHOW BACKUPS FROM <uri>`"
+            | #restore_metasrv : "`RESTORE METASRV TO TIMESTAMP '<timestamp>' FROM <uri>`"
         ),
-        // network policy / password policy
+        // network policy / notification integration / password policy
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #drop_network_policy: "`DROP NETWORK POLICY [IF EXISTS] name`"
             | #describe_network_policy: "`DESC NETWORK POLICY name`"
             | #show_network_policies: "`SHOW NETWORK POLICIES`"
+            | #create_notification_integration: "`CREATE [OR REPLACE] NOTIFICATION INTEGRATION [IF NOT EXISTS] name TYPE = WEBHOOK URL = '<url>' EVENTS = (<event> [, <event>]) [ENABLED = <bool>] [COMMENT = '<string_literal>']`"
+            | #alter_notification_integration: "`ALTER NOTIFICATION INTEGRATION [IF EXISTS] name SET [URL = '<url>'] [EVENTS = (<event> [, <event>])] [ENABLED = <bool>] [COMMENT = '<string_literal>']`"
+            | #drop_notification_integration: "`DROP NOTIFICATION INTEGRATION [IF EXISTS] name`"
+            | #show_notification_integrations: "`SHOW NOTIFICATION INTEGRATIONS`"
             | #create_password_policy: "`CREATE PASSWORD POLICY [IF NOT EXISTS] name [PASSWORD_MIN_LENGTH = <u64_literal>] ... [COMMENT = '<string_literal>']`"
             | #alter_password_policy: "`ALTER PASSWORD POLICY [IF EXISTS] name SET [PASSWORD_MIN_LENGTH = <u64_literal>] ... [COMMENT = '<string_literal>']`"
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
@@ -1922,18 +2290,23 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
         ),
         rule!(
-            #set_variable : "`SET <variable> = <value>`"
+            #set_transaction_isolation : "`SET TRANSACTION ISOLATION LEVEL { READ COMMITTED | SNAPSHOT ISOLATION }`"
+            | #set_variable : "`SET <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
+            | #show_segments : "`SHOW SEGMENTS FOR TABLE [<database>.]<table> [AT SNAPSHOT '<snapshot_id>']`"
             | #describe_table : "`DESCRIBE [<database>.]<table>`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
             | #show_drop_tables_status : "`SHOW DROP TABLES [FROM <database>]`"
             | #attach_table : "`ATTACH TABLE [<database>.]<table> <uri>`"
+            | #clone_table : "`CLONE TABLE [<database>.]<table> INTO [<database>.]<new_table> [AT SNAPSHOT '<snapshot_id>']`"
             | #create_table : "`CREATE [OR REPLACE] TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
             | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
@@ -1943,7 +2316,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN]`"
-            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table> [UPDATE STATISTICS [FULL | SAMPLE <n> PERCENT | (<column> {FULL | SAMPLE <n> PERCENT}, ...)]]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
@@ -1952,7 +2325,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #create_view : "`CREATE [OR REPLACE] VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #alter_view_owner : "`ALTER VIEW [<database>.]<view> OWNER TO ROLE <role_name>`"
             | #stream_table
+            | #create_rollup_table: "`CREATE ROLLUP TABLE [IF NOT EXISTS] [<database>.]<table> ON [<database>.]<table> AGGREGATE (<expr> [AS <alias>], ...) GROUPING BY <expr> [AS <alias>], ...`"
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
@@ -1985,16 +2360,26 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 [ COPY_OPTIONS = ( copyOptions ) ]
                 [ COMMENT = '<string_literal>' ]`"
             | #desc_stage: "`DESC STAGE <stage_name>`"
-            | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
+            | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>'] [STAGED_SINCE = <duration>] [LIMIT <n>]`"
             | #remove_stage: "`REMOVE @<stage_name> [pattern = '<pattern>']`"
+            | #alter_stage: "`ALTER STAGE [IF EXISTS] <stage_name> { SET [URL = '<url>' [CONNECTION = (...)]] [FILE_FORMAT = (...)] [COMMENT = '<string_literal>'] | RENAME TO <new_stage_name> | REFRESH CREDENTIALS }`"
             | #drop_stage: "`DROP STAGE <stage_name>`"
         ),
         rule!(
             #create_file_format: "`CREATE FILE FORMAT [ IF NOT EXISTS ] <format_name> formatTypeOptions`"
+            | #alter_file_format: "`ALTER FILE FORMAT [ IF EXISTS ] <format_name> [ SET ] formatTypeOptions`"
             | #show_file_formats: "`SHOW FILE FORMATS`"
             | #drop_file_format: "`DROP FILE FORMAT  [ IF EXISTS ] <format_name>`"
         ),
-        rule!( #copy_into ),
+        rule!(
+            #copy_into
+            | #import : "`IMPORT INTO [<database_name>.]<table_name> FROM '<path>' [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]`"
+            | #apply_migration : "`APPLY MIGRATION '@<stage_name>/<path>'`"
+            | #show_migrations : "`SHOW MIGRATIONS`"
+            | #rollback_migration : "`ROLLBACK MIGRATION '<name>'`"
+            | #show_query_cache : "`SHOW QUERY CACHE`"
+            | #purge_query_cache : "`PURGE QUERY CACHE { ALL | WHERE TABLE = '[<database>.]<table>' | OLDER THAN INTERVAL '<interval>' }`"
+        ),
         rule!(
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
         ),
@@ -2005,7 +2390,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #grant_ownership : "GRANT OWNERSHIP ON <privileges_level> TO ROLE <role_name>"
         ),
         rule!(
-            #presign: "`PRESIGN [{DOWNLOAD | UPLOAD}] <location> [EXPIRE = 3600]`"
+            #presign: "`PRESIGN [{DOWNLOAD | UPLOAD}] <location> [{EXPIRE | EXPIRATION} = 3600]`"
         ),
         // data mask
         rule!(
@@ -2079,6 +2464,18 @@ AS
     )(i)
 }
 
+pub fn rollup_column(i: Input) -> IResult<RollupColumn> {
+    map(
+        rule! {
+            #expr ~ ( AS ~ ^#ident )?
+        },
+        |(expr, opt_alias)| RollupColumn {
+            expr,
+            alias: opt_alias.map(|(_, alias)| alias),
+        },
+    )(i)
+}
+
 pub fn parse_create_option(
     opt_or_replace: bool,
     opt_if_not_exists: bool,
@@ -2205,18 +2602,92 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn optimizer_hint(i: Input) -> IResult<OptimizerHint> {
+    let hash_join = map(
+        rule! {
+            HASH_JOIN ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| OptimizerHint::HashJoin(tables),
+    );
+    let sort_merge_join = map(
+        rule! {
+            SORT_MERGE_JOIN ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| OptimizerHint::SortMergeJoin(tables),
+    );
+    let broadcast = map(
+        rule! {
+            BROADCAST ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| OptimizerHint::Broadcast(tables),
+    );
+    let no_index = map(
+        rule! {
+            NO_INDEX ~ ^"(" ~ ^#ident ~ #ident* ~ ^")"
+        },
+        |(_, _, table, columns, _)| OptimizerHint::NoIndex { table, columns },
+    );
+    let use_clustering_key = map(
+        rule! {
+            USE_CLUSTERING_KEY ~ ^"(" ~ ^#ident ~ ^")"
+        },
+        |(_, _, table, _)| OptimizerHint::UseClusteringKey(table),
+    );
+    let parallel = map(
+        rule! {
+            PARALLEL ~ ^"(" ~ ^#literal_u64 ~ ^")"
+        },
+        |(_, _, num, _)| OptimizerHint::Parallel(num),
+    );
+
+    rule!(
+        #hash_join
+        | #sort_merge_join
+        | #broadcast
+        | #no_index
+        | #use_clustering_key
+        | #parallel
+    )(i)
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
+    #[derive(Clone)]
+    enum HintElement {
+        SetVar(HintItem),
+        Optimizer(OptimizerHint),
+    }
+
+    let hint_element = alt((
+        map(set_var_hints, HintElement::SetVar),
+        map(optimizer_hint, HintElement::Optimizer),
+    ));
     let hint = map(
         rule! {
-            "/*+" ~ #set_var_hints+ ~ "*/"
+            "/*+" ~ #hint_element+ ~ "*/"
+        },
+        |(_, elements, _)| {
+            let mut hints_list = vec![];
+            let mut optimizer_hints = vec![];
+            for element in elements {
+                match element {
+                    HintElement::SetVar(item) => hints_list.push(item),
+                    HintElement::Optimizer(hint) => optimizer_hints.push(hint),
+                }
+            }
+            Hint {
+                hints_list,
+                optimizer_hints,
+            }
         },
-        |(_, hints_list, _)| Hint { hints_list },
     );
     let invalid_hint = map(
         rule! {
             "/*+" ~ (!"*/" ~ #any_token)* ~ "*/"
         },
-        |_| Hint { hints_list: vec![] },
+        |_| Hint {
+            hints_list: vec![],
+            optimizer_hints: vec![],
+        },
     );
     rule!(#hint|#invalid_hint)(i)
 }
@@ -2387,6 +2858,20 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    let column_privs = map(
+        rule! {
+            SELECT ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ TABLE? ~ ( #ident ~ "." )? ~ #ident
+        },
+        |(_, _, columns, _, _, _, database, table)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Select],
+            level: AccountMgrLevel::Column(
+                database.map(|(database, _)| database.name),
+                table.name,
+                columns.into_iter().map(|column| column.name).collect(),
+            ),
+        },
+    );
+
     let stage_privs = map(
         rule! {
             #comma_separated_list1(stage_priv_type) ~ ON ~ STAGE ~ #ident
@@ -2400,6 +2885,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     rule!(
         #role : "ROLE <role_name>"
         | #udf_privs: "USAGE ON UDF <udf_name>"
+        | #column_privs : "SELECT (<column>, ...) ON [TABLE] <database>.<table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
@@ -2998,6 +3484,26 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     ))(i)
 }
 
+pub fn update_statistics_option(i: Input) -> IResult<UpdateStatisticsOption> {
+    let columns = map(
+        rule! {
+            "(" ~ ^#comma_separated_list1(rule! { #ident ~ #statistics_method }) ~ ^")"
+        },
+        |(_, columns, _)| UpdateStatisticsOption::Columns(columns),
+    );
+    alt((map(statistics_method, UpdateStatisticsOption::All), columns))(i)
+}
+
+pub fn statistics_method(i: Input) -> IResult<StatisticsMethod> {
+    alt((
+        value(StatisticsMethod::Full, rule! { FULL }),
+        map(
+            rule! { SAMPLE ~ ^#literal_u64 ~ ^PERCENT },
+            |(_, percent, _)| StatisticsMethod::Sample { percent },
+        ),
+    ))(i)
+}
+
 pub fn literal_duration(i: Input) -> IResult<Duration> {
     let seconds = map(
         rule! {
@@ -3019,6 +3525,38 @@ pub fn literal_duration(i: Input) -> IResult<Duration> {
     )(i)
 }
 
+pub fn alter_stage_action(i: Input) -> IResult<AlterStageAction> {
+    let set = map(
+        rule! {
+            SET ~ ( URL ~ ^"=" ~ ^#uri_location )?
+            ~ ( #file_format_clause )?
+            ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, url_opt, file_format_opt, comment_opt)| AlterStageAction::Set {
+            location: url_opt.map(|(_, _, location)| location),
+            file_format_options: file_format_opt.unwrap_or_default(),
+            comments: comment_opt.map(|v| v.2),
+        },
+    );
+    let rename = map(
+        rule! {
+            RENAME ~ TO ~ #stage_name
+        },
+        |(_, _, new_stage_name)| AlterStageAction::RenameTo {
+            new_stage_name: new_stage_name.to_string(),
+        },
+    );
+    let refresh_credentials = value(AlterStageAction::RefreshCredentials, rule! {
+        REFRESH ~ CREDENTIALS
+    });
+
+    rule!(
+        #set
+        | #rename
+        | #refresh_credentials
+    )(i)
+}
+
 pub fn vacuum_drop_table_option(i: Input) -> IResult<VacuumDropTableOption> {
     alt((map(
         rule! {
@@ -3198,13 +3736,68 @@ pub fn task_schedule_option(i: Input) -> IResult<ScheduleOptions> {
         },
         |(secs, _)| ScheduleOptions::IntervalSecs(secs),
     );
+    // `SCHEDULE = 'USING CRON 0 * * * *'`
+    let quoted = map_res(rule! { #literal_string }, |s| {
+        parse_quoted_schedule(&s).ok_or(nom::Err::Failure(ErrorKind::Other(
+            "invalid schedule, expecting 'USING CRON <expr> [<time zone>]', '<num> MINUTE' or '<num> SECOND'",
+        )))
+    });
     rule!(
         #interval
         | #cron_expr
         | #interval_sec
+        | #quoted
     )(i)
 }
 
+/// Parses the schedule given in a string. The time zone is the last word after the cron
+/// expression if it contains a `/` or is `UTC`, e.g. `'USING CRON 0 9 * * * America/Los_Angeles'`.
+fn parse_quoted_schedule(s: &str) -> Option<ScheduleOptions> {
+    let words = s.split_whitespace().collect::<Vec<_>>();
+    match words.as_slice() {
+        [using, cron, rest @ ..]
+            if using.eq_ignore_ascii_case("USING")
+                && cron.eq_ignore_ascii_case("CRON")
+                && !rest.is_empty() =>
+        {
+            let (expr, time_zone) = match rest.split_last() {
+                Some((last, expr))
+                    if !expr.is_empty()
+                        && (last.contains('/') || last.eq_ignore_ascii_case("UTC")) =>
+                {
+                    (expr, Some(last.to_string()))
+                }
+                _ => (rest, None),
+            };
+            Some(ScheduleOptions::CronExpression(expr.join(" "), time_zone))
+        }
+        [num, unit] => {
+            let num = num.parse::<u64>().ok()?;
+            if unit.eq_ignore_ascii_case("MINUTE") {
+                Some(ScheduleOptions::IntervalSecs(num * 60))
+            } else if unit.eq_ignore_ascii_case("SECOND") {
+                Some(ScheduleOptions::IntervalSecs(num))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn transaction_isolation_level(i: Input) -> IResult<TransactionIsolationLevel> {
+    alt((
+        value(
+            TransactionIsolationLevel::ReadCommitted,
+            rule! { READ ~ ^COMMITTED },
+        ),
+        value(
+            TransactionIsolationLevel::SnapshotIsolation,
+            rule! { SNAPSHOT ~ ^ISOLATION },
+        ),
+    ))(i)
+}
+
 pub fn kill_target(i: Input) -> IResult<KillTarget> {
     alt((
         value(KillTarget::Query, rule! { QUERY }),
@@ -3434,9 +4027,10 @@ pub fn presign_location(i: Input) -> IResult<PresignLocation> {
 
 pub fn presign_option(i: Input) -> IResult<PresignOption> {
     alt((
-        map(rule! { EXPIRE ~ ^"=" ~ ^#literal_u64 }, |(_, _, v)| {
-            PresignOption::Expire(v)
-        }),
+        map(
+            rule! { ( EXPIRE | EXPIRATION ) ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PresignOption::Expire(v),
+        ),
         map(
             rule! { CONTENT_TYPE ~ ^"=" ~ ^#literal_string },
             |(_, _, v)| PresignOption::ContentType(v),
@@ -146,6 +146,9 @@ pub enum TokenKind {
     #[regex(r#"\$[0-9]+"#)]
     ColumnPosition,
 
+    #[regex(r#"\$[_a-zA-Z][_a-zA-Z0-9]*"#)]
+    MetadataColumn,
+
     #[regex(r#"`[^`]*`"#)]
     #[regex(r#""([^"\\]|\\.|"")*""#)]
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
@@ -323,10 +326,14 @@ pub enum TokenKind {
     ADD,
     #[token("AFTER", ignore(ascii_case))]
     AFTER,
+    #[token("AGGREGATE", ignore(ascii_case))]
+    AGGREGATE,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
+    #[token("APPLY", ignore(ascii_case))]
+    APPLY,
     #[token("APPEND_ONLY", ignore(ascii_case))]
     APPEND_ONLY,
     #[token("ARGS", ignore(ascii_case))]
@@ -359,8 +366,14 @@ pub enum TokenKind {
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
     ATTACH,
+    #[token("BACKUP", ignore(ascii_case))]
+    BACKUP,
+    #[token("BACKUPS", ignore(ascii_case))]
+    BACKUPS,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -389,10 +402,14 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BROADCAST", ignore(ascii_case))]
+    BROADCAST,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -405,12 +422,18 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
+    #[token("COMMITTED", ignore(ascii_case))]
+    COMMITTED,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -435,12 +458,16 @@ pub enum TokenKind {
     COPY_OPTIONS,
     #[token("COPY", ignore(ascii_case))]
     COPY,
+    #[token("COST", ignore(ascii_case))]
+    COST,
     #[token("COUNT", ignore(ascii_case))]
     COUNT,
     #[token("CREATE", ignore(ascii_case))]
     CREATE,
     #[token("CREDENTIALS", ignore(ascii_case))]
     CREDENTIALS,
+    #[token("CREDENTIALS_FROM", ignore(ascii_case))]
+    CREDENTIALS_FROM,
     #[token("CROSS", ignore(ascii_case))]
     CROSS,
     #[token("CSV", ignore(ascii_case))]
@@ -489,12 +516,16 @@ pub enum TokenKind {
     DISABLE_VARIANT_CHECK,
     #[token("DISTINCT", ignore(ascii_case))]
     DISTINCT,
+    #[token("DISTRIBUTED", ignore(ascii_case))]
+    DISTRIBUTED,
     #[token("DIV", ignore(ascii_case))]
     DIV,
     #[token("DOUBLE_SHA1_PASSWORD", ignore(ascii_case))]
     DOUBLE_SHA1_PASSWORD,
     #[token("DOUBLE", ignore(ascii_case))]
     DOUBLE,
+    #[token("DOT", ignore(ascii_case))]
+    DOT,
     #[token("DOW", ignore(ascii_case))]
     DOW,
     #[token("WEEK", ignore(ascii_case))]
@@ -519,6 +550,8 @@ pub enum TokenKind {
     EMPTY_FIELD_AS,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
+    #[token("ENABLED", ignore(ascii_case))]
+    ENABLED,
     #[token("END", ignore(ascii_case))]
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
@@ -533,12 +566,16 @@ pub enum TokenKind {
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
+    #[token("EVENTS", ignore(ascii_case))]
+    EVENTS,
     #[token("EXISTS", ignore(ascii_case))]
     EXISTS,
     #[token("EXPLAIN", ignore(ascii_case))]
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXPIRATION", ignore(ascii_case))]
+    EXPIRATION,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
@@ -607,6 +644,8 @@ pub enum TokenKind {
     GROUP,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
+    #[token("HASH_JOIN", ignore(ascii_case))]
+    HASH_JOIN,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
     #[token("HISTORY", ignore(ascii_case))]
@@ -625,10 +664,20 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("ILIKE", ignore(ascii_case))]
+    ILIKE,
+    #[token("IMPORT", ignore(ascii_case))]
+    IMPORT,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCOMPATIBILITIES", ignore(ascii_case))]
+    INCOMPATIBILITIES,
+    #[token("INCREMENTAL", ignore(ascii_case))]
+    INCREMENTAL,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INFER", ignore(ascii_case))]
+    INFER,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -645,14 +694,22 @@ pub enum TokenKind {
     INT8,
     #[token("INTEGER", ignore(ascii_case))]
     INTEGER,
+    #[token("INTEGRATION", ignore(ascii_case))]
+    INTEGRATION,
+    #[token("INTEGRATIONS", ignore(ascii_case))]
+    INTEGRATIONS,
     #[token("INTERVAL", ignore(ascii_case))]
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("IO", ignore(ascii_case))]
+    IO,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
     ISODOW,
+    #[token("ISOLATION", ignore(ascii_case))]
+    ISOLATION,
     #[token("ISOYEAR", ignore(ascii_case))]
     ISOYEAR,
     #[token("JOIN", ignore(ascii_case))]
@@ -679,6 +736,8 @@ pub enum TokenKind {
     SECONDARY,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("ROLE_ARN", ignore(ascii_case))]
+    ROLE_ARN,
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
     #[token("<->")]
     L2DISTANCE,
@@ -686,6 +745,8 @@ pub enum TokenKind {
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
     LEFT,
+    #[token("LEVEL", ignore(ascii_case))]
+    LEVEL,
     #[token("LIKE", ignore(ascii_case))]
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
@@ -706,10 +767,16 @@ pub enum TokenKind {
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("METASRV", ignore(ascii_case))]
+    METASRV,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
     MICROSECONDS,
+    #[token("MIGRATION", ignore(ascii_case))]
+    MIGRATION,
+    #[token("MIGRATIONS", ignore(ascii_case))]
+    MIGRATIONS,
     #[token("MILLENNIUM", ignore(ascii_case))]
     MILLENNIUM,
     #[token("MILLISECONDS", ignore(ascii_case))]
@@ -730,6 +797,8 @@ pub enum TokenKind {
     NETWORK,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NO_INDEX", ignore(ascii_case))]
+    NO_INDEX,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -738,6 +807,8 @@ pub enum TokenKind {
     NOT,
     #[token("NOTENANTSETTING", ignore(ascii_case))]
     NOTENANTSETTING,
+    #[token("NOTIFICATION", ignore(ascii_case))]
+    NOTIFICATION,
     #[token("NULL", ignore(ascii_case))]
     NULL,
     #[token("NULLABLE", ignore(ascii_case))]
@@ -748,6 +819,8 @@ pub enum TokenKind {
     OF,
     #[token("OFFSET", ignore(ascii_case))]
     OFFSET,
+    #[token("OLDER", ignore(ascii_case))]
+    OLDER,
     #[token("ON", ignore(ascii_case))]
     ON,
     #[token("OPTIMIZE", ignore(ascii_case))]
@@ -768,6 +841,10 @@ pub enum TokenKind {
     OVER,
     #[token("OVERWRITE", ignore(ascii_case))]
     OVERWRITE,
+    #[token("OWNER", ignore(ascii_case))]
+    OWNER,
+    #[token("PARALLEL", ignore(ascii_case))]
+    PARALLEL,
     #[token("PARTITION", ignore(ascii_case))]
     PARTITION,
     #[token("PARQUET", ignore(ascii_case))]
@@ -798,6 +875,10 @@ pub enum TokenKind {
     PASSWORD_HISTORY,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PEAK", ignore(ascii_case))]
+    PEAK,
+    #[token("PERCENT", ignore(ascii_case))]
+    PERCENT,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -826,6 +907,8 @@ pub enum TokenKind {
     READ_ONLY,
     #[token("RECLUSTER", ignore(ascii_case))]
     RECLUSTER,
+    #[token("RECOMMEND", ignore(ascii_case))]
+    RECOMMEND,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
@@ -860,6 +943,8 @@ pub enum TokenKind {
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("PRECEDING", ignore(ascii_case))]
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
@@ -872,6 +957,10 @@ pub enum TokenKind {
     QUALIFY,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESET", ignore(ascii_case))]
+    RESET,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
@@ -894,6 +983,8 @@ pub enum TokenKind {
     RAW,
     #[token("OPTIMIZED", ignore(ascii_case))]
     OPTIMIZED,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -908,6 +999,10 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEGMENTS", ignore(ascii_case))]
+    SEGMENTS,
+    #[token("SESSION", ignore(ascii_case))]
+    SESSION,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -916,6 +1011,8 @@ pub enum TokenKind {
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
     STAGES,
+    #[token("STAGED_SINCE", ignore(ascii_case))]
+    STAGED_SINCE,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
@@ -924,6 +1021,8 @@ pub enum TokenKind {
     SHOW,
     #[token("SIGNED", ignore(ascii_case))]
     SIGNED,
+    #[token("SINCE", ignore(ascii_case))]
+    SINCE,
     #[token("SINGLE", ignore(ascii_case))]
     SINGLE,
     #[token("SIZE_LIMIT", ignore(ascii_case))]
@@ -956,6 +1055,10 @@ pub enum TokenKind {
     SHARES,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
+    #[token("STATISTICS", ignore(ascii_case))]
+    STATISTICS,
+    #[token("STATS", ignore(ascii_case))]
+    STATS,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
     #[token("STORED", ignore(ascii_case))]
@@ -972,6 +1075,8 @@ pub enum TokenKind {
     SUBSTR,
     #[token("SEMI", ignore(ascii_case))]
     SEMI,
+    #[token("SORT_MERGE_JOIN", ignore(ascii_case))]
+    SORT_MERGE_JOIN,
     #[token("SOUNDS", ignore(ascii_case))]
     SOUNDS,
     #[token("SYNC", ignore(ascii_case))]
@@ -996,6 +1101,8 @@ pub enum TokenKind {
     TENANTS,
     #[token("TENANT", ignore(ascii_case))]
     TENANT,
+    #[token("THAN", ignore(ascii_case))]
+    THAN,
     #[token("THEN", ignore(ascii_case))]
     THEN,
     #[token("TIMESTAMP", ignore(ascii_case))]
@@ -1014,6 +1121,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -1050,6 +1159,8 @@ pub enum TokenKind {
     URL,
     #[token("USE", ignore(ascii_case))]
     USE,
+    #[token("USE_CLUSTERING_KEY", ignore(ascii_case))]
+    USE_CLUSTERING_KEY,
     #[token("USER", ignore(ascii_case))]
     USER,
     #[token("USERS", ignore(ascii_case))]
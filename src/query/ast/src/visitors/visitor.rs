@@ -403,6 +403,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_metrics(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_show_status(&mut self, _global: bool, _show_options: &'ast Option<ShowOptions>) {}
+
     fn visit_show_engines(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
     fn visit_show_functions(&mut self, _show_options: &'ast Option<ShowOptions>) {}
@@ -563,6 +565,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_view(&mut self, _stmt: &'ast AlterViewStmt) {}
 
+    fn visit_alter_view_owner(&mut self, _stmt: &'ast AlterViewOwnerStmt) {}
+
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
@@ -573,6 +577,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_describe_stream(&mut self, _stmt: &'ast DescribeStreamStmt) {}
 
+    fn visit_create_rollup_table(&mut self, _stmt: &'ast CreateRollupTableStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
@@ -616,6 +622,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_create_stage(&mut self, _stmt: &'ast CreateStageStmt) {}
 
+    fn visit_alter_stage(&mut self, _stmt: &'ast AlterStageStmt) {}
+
     fn visit_show_stages(&mut self) {}
 
     fn visit_drop_stage(&mut self, _if_exists: bool, _stage_name: &'ast str) {}
@@ -634,6 +642,14 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_alter_file_format(
+        &mut self,
+        _if_exists: bool,
+        _name: &'ast str,
+        _file_format_options: &'ast FileFormatOptionsAst,
+    ) {
+    }
+
     fn visit_drop_file_format(&mut self, _if_exists: bool, _name: &'ast str) {}
 
     fn visit_show_file_formats(&mut self) {}
@@ -680,6 +696,26 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_network_policies(&mut self) {}
 
+    fn visit_create_notification_integration(
+        &mut self,
+        _stmt: &'ast CreateNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_alter_notification_integration(
+        &mut self,
+        _stmt: &'ast AlterNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_drop_notification_integration(
+        &mut self,
+        _stmt: &'ast DropNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_show_notification_integrations(&mut self) {}
+
     fn visit_create_password_policy(&mut self, _stmt: &'ast CreatePasswordPolicyStmt) {}
 
     fn visit_alter_password_policy(&mut self, _stmt: &'ast AlterPasswordPolicyStmt) {}
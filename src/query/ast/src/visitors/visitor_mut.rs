@@ -417,6 +417,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_metrics(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_show_status(&mut self, _global: bool, _show_options: &mut Option<ShowOptions>) {}
+
     fn visit_show_engines(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_show_functions(&mut self, _show_options: &mut Option<ShowOptions>) {}
@@ -577,6 +579,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_view(&mut self, _stmt: &mut AlterViewStmt) {}
 
+    fn visit_alter_view_owner(&mut self, _stmt: &mut AlterViewOwnerStmt) {}
+
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
@@ -587,6 +591,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_describe_stream(&mut self, _stmt: &mut DescribeStreamStmt) {}
 
+    fn visit_create_rollup_table(&mut self, _stmt: &mut CreateRollupTableStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
@@ -630,6 +636,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_create_stage(&mut self, _stmt: &mut CreateStageStmt) {}
 
+    fn visit_alter_stage(&mut self, _stmt: &mut AlterStageStmt) {}
+
     fn visit_show_stages(&mut self) {}
 
     fn visit_drop_stage(&mut self, _if_exists: bool, _stage_name: &mut String) {}
@@ -648,6 +656,14 @@ pub trait VisitorMut: Sized {
     ) {
     }
 
+    fn visit_alter_file_format(
+        &mut self,
+        _if_exists: bool,
+        _name: &mut String,
+        _file_format_options: &mut FileFormatOptionsAst,
+    ) {
+    }
+
     fn visit_drop_file_format(&mut self, _if_exists: bool, _name: &mut String) {}
 
     fn visit_show_file_formats(&mut self) {}
@@ -694,6 +710,23 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_network_policies(&mut self) {}
 
+    fn visit_create_notification_integration(
+        &mut self,
+        _stmt: &mut CreateNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_alter_notification_integration(
+        &mut self,
+        _stmt: &mut AlterNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_drop_notification_integration(&mut self, _stmt: &mut DropNotificationIntegrationStmt) {
+    }
+
+    fn visit_show_notification_integrations(&mut self) {}
+
     fn visit_create_password_policy(&mut self, _stmt: &mut CreatePasswordPolicyStmt) {}
 
     fn visit_alter_password_policy(&mut self, _stmt: &mut AlterPasswordPolicyStmt) {}
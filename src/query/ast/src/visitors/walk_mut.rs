@@ -364,6 +364,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
     match statement {
         Statement::Explain { kind, query } => visitor.visit_explain(kind, &mut *query),
         Statement::ExplainAnalyze { query } => visitor.visit_statement(&mut *query),
+        Statement::RecommendIndex { query } => visitor.visit_statement(&mut *query),
         Statement::Query(query) => visitor.visit_query(&mut *query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
@@ -377,6 +378,21 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             visitor.visit_show_process_list(show_options)
         }
         Statement::ShowMetrics { show_options } => visitor.visit_show_metrics(show_options),
+        Statement::ShowIoStats { .. } => {}
+        Statement::ShowIncompatibilities { .. } => {}
+        Statement::Import(_) => {}
+        Statement::ApplyMigration { .. } => {}
+        Statement::ShowMigrations => {}
+        Statement::RollbackMigration { .. } => {}
+        Statement::ShowQueryCache => {}
+        Statement::PurgeQueryCache { .. } => {}
+        Statement::SetTransactionIsolation { .. } => {}
+        Statement::Begin => {}
+        Statement::Commit => {}
+        Statement::ShowStatus {
+            global,
+            show_options,
+        } => visitor.visit_show_status(*global, show_options),
         Statement::ShowEngines { show_options } => visitor.visit_show_engines(show_options),
         Statement::ShowFunctions { show_options } => visitor.visit_show_functions(show_options),
         Statement::ShowUserFunctions { show_options } => {
@@ -391,6 +407,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             kill_target,
             object_id,
         } => visitor.visit_kill(kill_target, object_id),
+        Statement::ResetMemoryPeak => {}
         Statement::SetVariable {
             is_global,
             variable,
@@ -416,6 +433,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
         Statement::ShowColumns(stmt) => visitor.visit_show_columns(stmt),
         Statement::ShowCreateTable(stmt) => visitor.visit_show_create_table(stmt),
+        Statement::ShowSegments(_) => {}
         Statement::DescribeTable(stmt) => visitor.visit_describe_table(stmt),
         Statement::ShowTablesStatus(stmt) => visitor.visit_show_tables_status(stmt),
         Statement::ShowDropTables(stmt) => visitor.visit_show_drop_tables(stmt),
@@ -433,11 +451,13 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::ExistsTable(stmt) => visitor.visit_exists_table(stmt),
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
+        Statement::AlterViewOwner(stmt) => visitor.visit_alter_view_owner(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
         Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
         Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::ShowStreams(stmt) => visitor.visit_show_streams(stmt),
         Statement::DescribeStream(stmt) => visitor.visit_describe_stream(stmt),
+        Statement::CreateRollupTable(stmt) => visitor.visit_create_rollup_table(stmt),
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
@@ -468,13 +488,16 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             udf_name,
         } => visitor.visit_drop_udf(*if_exists, udf_name),
         Statement::AlterUDF(stmt) => visitor.visit_alter_udf(stmt),
-        Statement::ListStage { location, pattern } => visitor.visit_list_stage(location, pattern),
+        Statement::ListStage {
+            location, pattern, ..
+        } => visitor.visit_list_stage(location, pattern),
         Statement::ShowStages => visitor.visit_show_stages(),
         Statement::DropStage {
             if_exists,
             stage_name,
         } => visitor.visit_drop_stage(*if_exists, stage_name),
         Statement::CreateStage(stmt) => visitor.visit_create_stage(stmt),
+        Statement::AlterStage(stmt) => visitor.visit_alter_stage(stmt),
         Statement::RemoveStage { location, pattern } => {
             visitor.visit_remove_stage(location, pattern)
         }
@@ -484,6 +507,11 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             name,
             file_format_options,
         } => visitor.visit_create_file_format(create_option, name, file_format_options),
+        Statement::AlterFileFormat {
+            if_exists,
+            name,
+            file_format_options,
+        } => visitor.visit_alter_file_format(*if_exists, name, file_format_options),
         Statement::DropFileFormat { if_exists, name } => {
             visitor.visit_drop_file_format(*if_exists, name)
         }
@@ -508,11 +536,26 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
         Statement::AttachTable(_) => {}
+        Statement::CloneTable(_) => {}
+        Statement::BackupDatabase(_) => {}
+        Statement::RestoreDatabase(_) => {}
+        Statement::ShowBackups(_) => {}
+        Statement::RestoreMetasrv(_) => {}
         Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
         Statement::AlterNetworkPolicy(stmt) => visitor.visit_alter_network_policy(stmt),
         Statement::DropNetworkPolicy(stmt) => visitor.visit_drop_network_policy(stmt),
         Statement::DescNetworkPolicy(stmt) => visitor.visit_desc_network_policy(stmt),
         Statement::ShowNetworkPolicies => visitor.visit_show_network_policies(),
+        Statement::CreateNotificationIntegration(stmt) => {
+            visitor.visit_create_notification_integration(stmt)
+        }
+        Statement::AlterNotificationIntegration(stmt) => {
+            visitor.visit_alter_notification_integration(stmt)
+        }
+        Statement::DropNotificationIntegration(stmt) => {
+            visitor.visit_drop_notification_integration(stmt)
+        }
+        Statement::ShowNotificationIntegrations => visitor.visit_show_notification_integrations(),
         Statement::CreatePasswordPolicy(stmt) => visitor.visit_create_password_policy(stmt),
         Statement::AlterPasswordPolicy(stmt) => visitor.visit_alter_password_policy(stmt),
         Statement::DropPasswordPolicy(stmt) => visitor.visit_drop_password_policy(stmt),
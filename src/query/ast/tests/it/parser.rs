@@ -95,8 +95,15 @@ fn test_statement() {
         r#"show processlist where database='default' limit 2;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
+        r#"show segments for table a.b;"#,
+        r#"show segments for table b at snapshot 'abc';"#,
         r#"explain pipeline select a from b;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
+        r#"explain memory select a from b;"#,
+        r#"explain (format json) cost select a from b;"#,
+        r#"describe pipeline for select a from b format = dot;"#,
+        r#"recommend index for select a from b;"#,
+        r#"reset memory peak;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
@@ -118,6 +125,8 @@ fn test_statement() {
              location_prefix = 'db';"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
+        r#"analyze table a update statistics full;"#,
+        r#"analyze table a update statistics (b full, c sample 10 percent);"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
         r#"use "a";"#,
@@ -136,6 +145,11 @@ fn test_statement() {
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"create or replace view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"alter view db01.v1 owner to role role1;"#,
+        r#"backup database db1 to 's3://backup-bucket/prefix/' incremental since 'a1b2';"#,
+        r#"restore database db1 from 's3://backup-bucket/prefix/' as db2;"#,
+        r#"show backups from 's3://backup-bucket/prefix/';"#,
+        r#"restore metasrv to timestamp '2024-01-01 00:00:00' from 's3://backup-bucket/changelog/';"#,
         r#"create stream test2.s1 on table test.t append_only = false;"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
         r#"create or replace stream test2.s1 on table test.t append_only = false;"#,
@@ -143,6 +157,8 @@ fn test_statement() {
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
         r#"rename table d.t to e.s;"#,
+        r#"clone table d.t into e.s;"#,
+        r#"CLONE TABLE t INTO t_new AT SNAPSHOT '7a2b1c3d';"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
         r#"DROP table table1;"#,
@@ -199,11 +215,16 @@ fn test_statement() {
         r#"CREATE STAGE IF NOT EXISTS test_stage 's3://load/files/' credentials=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
+        r#"CREATE STAGE s3_stage url='s3://bucket/prefix/' credentials_from = 'AWS_ROLE' role_arn = 'arn:aws:iam::123456789012:role/databend'"#,
+        r#"ALTER STAGE s1 SET URL = 's3://bucket/new/' CONNECTION = (role_arn = 'arn:aws:iam::123456789012:role/databend') FILE_FORMAT = (type = CSV) COMMENT = 'moved'"#,
+        r#"ALTER STAGE IF EXISTS s1 RENAME TO s2"#,
+        r#"ALTER STAGE s1 REFRESH CREDENTIALS"#,
         r#"CREATE OR REPLACE STAGE test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
         r#"DROP STAGE abc"#,
         r#"DROP STAGE ~"#,
         r#"list @stage_a;"#,
         r#"list @~;"#,
+        r#"list @stage_a pattern = '.*[.]csv' staged_since = 1 days limit 10;"#,
         r#"create user 'test-e' identified by 'password';"#,
         r#"drop user if exists 'test-j';"#,
         r#"alter user 'test-e' identified by 'new-password';"#,
@@ -262,6 +283,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO USER 'test-grant';"#,
         r#"GRANT SELECT ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT SELECT ON tb1 TO ROLE role1;"#,
+        r#"GRANT SELECT (c1, c2) ON TABLE db01.tb1 TO ROLE role1;"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant';"#,
@@ -270,6 +292,7 @@ fn test_statement() {
         r#"SHOW GRANTS FOR ROLE 'role1';"#,
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE role1;"#,
+        r#"REVOKE SELECT (c1) ON tb1 FROM ROLE role1;"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"drop role 'role1';"#,
         r#"GRANT ROLE test TO ROLE 'test-user';"#,
@@ -281,6 +304,9 @@ fn test_statement() {
         r#"COPY INTO mytable
                 FROM '@~/mybucket/my data.csv'
                 size_limit=10;"#,
+        r#"COPY INTO mytable FROM @my_stage SCHEMA = INFER;"#,
+        r#"COPY INTO mytable (a, b, source_file, row_number) FROM @my_stage (a, b, $FILENAME, $file_row_number);"#,
+        r#"COPY INTO mytable FROM @my_stage FILE_FORMAT = my_csv;"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -444,6 +470,23 @@ fn test_statement() {
         r#"show engines where engine='MEMORY' limit 1"#,
         r#"show metrics like '%parse%' limit 1"#,
         r#"show metrics where metric='session_connect_numbers' limit 1"#,
+        r#"show io stats for query 'abc'"#,
+        r#"show incompatibilities for query 'select $1 from t where a ilike ''x%'''"#,
+        r#"import into t from '/tmp/data/*.csv' file_format = (type = CSV field_delimiter = ',');"#,
+        r#"apply migration '@s1/v1_to_v2.sql';"#,
+        r#"show migrations;"#,
+        r#"rollback migration 'v1_to_v2.sql';"#,
+        r#"show query cache;"#,
+        r#"purge query cache all;"#,
+        r#"purge query cache where table = 'db1.t1';"#,
+        r#"purge query cache older than interval '1 hour';"#,
+        r#"set transaction isolation level read committed;"#,
+        r#"set transaction isolation level snapshot isolation;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"commit;"#,
+        r#"show status"#,
+        r#"show global status like 'Uptime'"#,
         r#"show table_functions like 'fuse%' limit 1"#,
         r#"show table_functions where name='fuse_snapshot' limit 1"#,
         r#"show indexes like 'test%' limit 1"#,
@@ -459,6 +502,7 @@ fn test_statement() {
         r#"PRESIGN UPLOAD @my_stage/path/to/file EXPIRE=7200"#,
         r#"PRESIGN UPLOAD @my_stage/path/to/file EXPIRE=7200 CONTENT_TYPE='application/octet-stream'"#,
         r#"PRESIGN UPLOAD @my_stage/path/to/file CONTENT_TYPE='application/octet-stream' EXPIRE=7200"#,
+        r#"PRESIGN DOWNLOAD @my_stage/path/file.parquet EXPIRATION = 3600"#,
         r#"CREATE SHARE ENDPOINT IF NOT EXISTS t URL='http://127.0.0.1' TENANT=x ARGS=(jwks_key_file="https://eks.public/keys" ssl_cert="cert.pem") COMMENT='share endpoint comment';"#,
         r#"CREATE OR REPLACE SHARE ENDPOINT t URL='http://127.0.0.1' TENANT=x ARGS=(jwks_key_file="https://eks.public/keys" ssl_cert="cert.pem") COMMENT='share endpoint comment';"#,
         r#"CREATE SHARE t COMMENT='share comment';"#,
@@ -503,6 +547,7 @@ fn test_statement() {
         r#"CREATE OR REPLACE FILE FORMAT my_csv
             type = CSV field_delimiter = ',' record_delimiter = '\n' skip_header = 1;"#,
         r#"SHOW FILE FORMATS"#,
+        r#"ALTER FILE FORMAT IF EXISTS my_csv SET type = CSV field_delimiter = '|'"#,
         r#"DROP FILE FORMAT my_csv"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS (a, b, c, d)"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS (a, b, (c, d))"#,
@@ -522,6 +567,9 @@ fn test_statement() {
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"CREATE OR REPLACE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE NOTIFICATION INTEGRATION IF NOT EXISTS n1 TYPE=WEBHOOK URL='https://example.com/hook' EVENTS=(COPY_INTO_COMPLETE, QUERY_ERROR) COMMENT='hook'"#,
+        r#"ALTER NOTIFICATION INTEGRATION n1 SET EVENTS=(PIPE_ERROR) ENABLED=false"#,
+        r#"SHOW NOTIFICATION INTEGRATIONS"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' DATABASE = 'target', TIMEZONE = 'America/Los Angeles' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
@@ -529,6 +577,7 @@ fn test_statement() {
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 6 * * *' 'America/Los_Angeles' COMMENT = 'serverless + cron' AS insert into t (c1, c2) values (1, 2), (3, 4)"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 12 * * *' AS copy into streams_test.paper_table from @stream_stage FILE_FORMAT = (TYPE = PARQUET) PURGE=true"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 13 * * *' AS COPY INTO @my_internal_stage FROM canadian_city_population FILE_FORMAT = (TYPE = PARQUET)"#,
+        r#"CREATE TASK MyTask1 SCHEDULE = 'USING CRON 0 * * * * Asia/Shanghai' AS VACUUM TABLE t"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 AFTER 'task2', 'task3' WHEN SYSTEM$GET_PREDECESSOR_RETURN_VALUE('task_name') != 'VALIDATION' AS VACUUM TABLE t"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 DATABASE = 'target', TIMEZONE = 'America/Los Angeles'  AS VACUUM TABLE t"#,
         r#"ALTER TASK MyTask1 RESUME"#,
@@ -884,6 +933,18 @@ fn test_experimental_expr() {
     }
 }
 
+#[test]
+fn test_snowflake_expr() {
+    let mut mint = Mint::new("tests/it/testdata");
+    let file = &mut mint.new_goldenfile("snowflake-expr.txt").unwrap();
+
+    let cases = &[r#"a ilike 'x%'"#, r#"a not ilike 'x%'"#];
+
+    for case in cases {
+        run_parser_with_dialect(file, expr, Dialect::Snowflake, case);
+    }
+}
+
 #[test]
 fn test_expr_error() {
     let mut mint = Mint::new("tests/it/testdata");
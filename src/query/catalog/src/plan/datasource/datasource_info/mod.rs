@@ -25,5 +25,7 @@ pub use parquet::ParquetTableInfo;
 pub use parquet::ParquetTableInfo as ParquetTableInfoV2;
 pub use parquet2::Parquet2TableInfo;
 pub use parquet_read_options::ParquetReadOptions;
+pub use parquet_read_options::SnapshotConsistency;
 pub use result_scan::ResultScanTableInfo;
+pub use stage::StageMetadataColumn;
 pub use stage::StageTableInfo;
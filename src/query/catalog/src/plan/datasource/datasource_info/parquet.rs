@@ -71,6 +71,9 @@ pub struct ParquetTableInfo {
     pub files_to_read: Option<Vec<StageFileInfo>>,
     pub schema_from: String,
     pub compression_ratio: f64,
+    /// The columns parsed from the Hive-style directories of the files, they are the last
+    /// fields of the table schema.
+    pub hive_partition_columns: Vec<String>,
 
     // These fields are only used in coordinator node of the cluster,
     // so we don't need to serialize them.
@@ -215,6 +218,7 @@ mod tests {
             files_to_read: None,
             schema_from: "".to_string(),
             compression_ratio: 0.0,
+            hive_partition_columns: vec![],
             parquet_metas: Arc::new(Mutex::new(vec![])),
             need_stats_provider: false,
             max_threads: 1,
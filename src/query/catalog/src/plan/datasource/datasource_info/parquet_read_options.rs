@@ -12,6 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+
+/// How the files changed after planning are handled when reading them.
+///
+/// The etags of the files are captured when planning and compared with the etags
+/// of the files before reading them.
+#[derive(serde::Serialize, serde::Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotConsistency {
+    /// Read the files as they are, the etags are not captured.
+    Ignore,
+    /// Fail the query if a file has been changed.
+    Error,
+    /// Skip the files which have been changed.
+    Skip,
+}
+
+impl FromStr for SnapshotConsistency {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ignore" => Ok(SnapshotConsistency::Ignore),
+            "error" => Ok(SnapshotConsistency::Error),
+            "skip" => Ok(SnapshotConsistency::Skip),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "invalid snapshot consistency {s:?}, expect one of ignore, error and skip"
+            ))),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ParquetReadOptions {
     /// Prune row groups before reading. Require Chunk level statistics.
@@ -31,6 +64,25 @@ pub struct ParquetReadOptions {
     /// - `push_down_bitmap` and  `prune_pages` are exclusive. (`push_down_bitmap && prune_pages == false`)
     /// - If `push_down_bitmap` is true, `do_prewhere` should be true, too.
     push_down_bitmap: bool,
+    /// If keep the case of the column names in the files, instead of lowering them.
+    case_sensitive: bool,
+    /// If merge the schemas of the files, instead of requiring all of them to have the
+    /// schema of the first file.
+    schema_merge: bool,
+    /// If prune row groups by the bloom filters of the columns compared to constants with `=`.
+    use_bloom_filter: bool,
+    /// Max retries of the reads of the files failed with transient errors, 0 means never retry.
+    max_read_retries: u64,
+    /// The backoff in milliseconds of the first retry, it doubles for each following retry.
+    read_retry_initial_delay_ms: u64,
+    /// How the files changed between planning and reading are handled.
+    snapshot_consistency: SnapshotConsistency,
+    /// If read the legacy INT96 timestamps as the wall clock times of the session timezone,
+    /// like Hive and Impala write them, instead of the UTC times.
+    int96_as_timestamp: bool,
+    /// If parse the Hive-style directories of the files, such as `year=2024/`,
+    /// into the partition columns of the table.
+    hive_partitioning: bool,
     // /// If refresh the file meta data cache.
     // refresh_meta_cache: bool,
 }
@@ -63,6 +115,49 @@ impl ParquetReadOptions {
         self
     }
 
+    #[inline]
+    pub fn with_case_sensitive(mut self, v: bool) -> Self {
+        self.case_sensitive = v;
+        self
+    }
+
+    #[inline]
+    pub fn with_schema_merge(mut self, v: bool) -> Self {
+        self.schema_merge = v;
+        self
+    }
+
+    #[inline]
+    pub fn with_use_bloom_filter(mut self, v: bool) -> Self {
+        self.use_bloom_filter = v;
+        self
+    }
+
+    #[inline]
+    pub fn with_read_retries(mut self, max_retries: u64, initial_delay_ms: u64) -> Self {
+        self.max_read_retries = max_retries;
+        self.read_retry_initial_delay_ms = initial_delay_ms;
+        self
+    }
+
+    #[inline]
+    pub fn with_snapshot_consistency(mut self, v: SnapshotConsistency) -> Self {
+        self.snapshot_consistency = v;
+        self
+    }
+
+    #[inline]
+    pub fn with_int96_as_timestamp(mut self, v: bool) -> Self {
+        self.int96_as_timestamp = v;
+        self
+    }
+
+    #[inline]
+    pub fn with_hive_partitioning(mut self, v: bool) -> Self {
+        self.hive_partitioning = v;
+        self
+    }
+
     // #[inline]
     // pub fn with_refresh_meta_cache(mut self, v: bool) -> Self {
     //     self.refresh_meta_cache = v;
@@ -89,6 +184,46 @@ impl ParquetReadOptions {
         self.do_prewhere
     }
 
+    #[inline]
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    #[inline]
+    pub fn schema_merge(&self) -> bool {
+        self.schema_merge
+    }
+
+    #[inline]
+    pub fn use_bloom_filter(&self) -> bool {
+        self.use_bloom_filter
+    }
+
+    #[inline]
+    pub fn max_read_retries(&self) -> u64 {
+        self.max_read_retries
+    }
+
+    #[inline]
+    pub fn read_retry_initial_delay_ms(&self) -> u64 {
+        self.read_retry_initial_delay_ms
+    }
+
+    #[inline]
+    pub fn snapshot_consistency(&self) -> SnapshotConsistency {
+        self.snapshot_consistency
+    }
+
+    #[inline]
+    pub fn int96_as_timestamp(&self) -> bool {
+        self.int96_as_timestamp
+    }
+
+    #[inline]
+    pub fn hive_partitioning(&self) -> bool {
+        self.hive_partitioning
+    }
+
     // #[inline]
     // pub fn refresh_meta_cache(&self) -> bool {
     //     self.refresh_meta_cache
@@ -102,6 +237,14 @@ impl Default for ParquetReadOptions {
             prune_row_groups: true,
             prune_pages: true,
             push_down_bitmap: false,
+            case_sensitive: false,
+            schema_merge: false,
+            use_bloom_filter: false,
+            max_read_retries: 3,
+            read_retry_initial_delay_ms: 1000,
+            snapshot_consistency: SnapshotConsistency::Ignore,
+            int96_as_timestamp: true,
+            hive_partitioning: false,
             // refresh_meta_cache: false,
         }
     }
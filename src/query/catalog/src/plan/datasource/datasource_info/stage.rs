@@ -16,12 +16,15 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::StageInfo;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
 
@@ -33,6 +36,52 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// The metadata columns appended to the columns of the files, in the order of the columns.
+    #[serde(default)]
+    pub metadata_columns: Vec<StageMetadataColumn>,
+}
+
+/// A column of the rows loaded from a stage that is not read from the files.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StageMetadataColumn {
+    /// The path of the file in the stage.
+    FileName,
+    /// The number of the row in the file, starting from 1 and not counting the headers.
+    FileRowNumber,
+    /// The ETag of the file, or its MD5 if there is no ETag.
+    FileContentKey,
+}
+
+impl StageMetadataColumn {
+    /// Parses the name of a metadata column without the `$`, case-insensitively.
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_uppercase().as_str() {
+            "FILENAME" => Ok(StageMetadataColumn::FileName),
+            "FILE_ROW_NUMBER" => Ok(StageMetadataColumn::FileRowNumber),
+            "FILE_CONTENT_KEY" => Ok(StageMetadataColumn::FileContentKey),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "unknown metadata column ${name}, expect $FILENAME, $FILE_ROW_NUMBER or $FILE_CONTENT_KEY"
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StageMetadataColumn::FileName => "FILENAME",
+            StageMetadataColumn::FileRowNumber => "FILE_ROW_NUMBER",
+            StageMetadataColumn::FileContentKey => "FILE_CONTENT_KEY",
+        }
+    }
+
+    pub fn data_type(&self) -> TableDataType {
+        match self {
+            StageMetadataColumn::FileName => TableDataType::String,
+            StageMetadataColumn::FileRowNumber => TableDataType::Number(NumberDataType::UInt64),
+            StageMetadataColumn::FileContentKey => {
+                TableDataType::Nullable(Box::new(TableDataType::String))
+            }
+        }
+    }
 }
 
 impl StageTableInfo {
@@ -46,7 +95,7 @@ impl StageTableInfo {
 
     #[async_backtrace::framed]
     pub async fn list_files(&self, max_files: Option<usize>) -> Result<Vec<StageFileInfo>> {
-        let op = init_stage_operator(&self.stage_info)?;
+        let op = init_stage_read_operator(&self.stage_info)?;
         let infos = self
             .files_info
             .list(&op, false, max_files)
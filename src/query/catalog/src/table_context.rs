@@ -35,6 +35,7 @@ use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::processors::profile::PlanProfile;
 use databend_common_pipeline_core::processors::profile::Profile;
 use databend_common_pipeline_core::InputError;
@@ -74,12 +75,33 @@ pub struct ProcessInfo {
     pub client_address: Option<SocketAddr>,
     pub session_extra_info: Option<String>,
     pub memory_usage: i64,
+    /// The memory allocated by the hash tables of the aggregations of the running query.
+    pub aggregate_memory_usage: i64,
     /// storage metrics for persisted data reading.
     pub data_metrics: Option<StorageMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
+    /// The SQL running in the session, `None` if the session is idle.
+    pub current_query: Option<String>,
+}
+
+/// The resources used by the queries of a tenant.
+#[derive(Debug, Clone)]
+pub struct TenantResourceUsage {
+    pub tenant: String,
+    pub running_queries: u64,
+    /// The queries waiting for the running queries to finish.
+    pub queued_queries: u64,
+    pub memory_usage: i64,
+    pub peak_memory_usage: i64,
+    /// 0 means unlimited.
+    pub max_memory_bytes: u64,
+    /// 0 means the queries don't share a runtime.
+    pub max_threads: u64,
+    /// 0 means unlimited.
+    pub max_concurrent_queries: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -165,6 +187,10 @@ pub trait TableContext: Send + Sync {
     fn get_current_session_id(&self) -> String {
         unimplemented!()
     }
+    /// The time when the query node was started.
+    fn get_instance_started_at(&self) -> SystemTime {
+        unimplemented!()
+    }
     async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>>;
     async fn get_available_roles(&self) -> Result<Vec<RoleInfo>>;
     async fn get_visibility_checker(&self) -> Result<GrantObjectVisibilityChecker>;
@@ -179,6 +205,7 @@ pub trait TableContext: Send + Sync {
     fn get_shared_settings(&self) -> Arc<Settings>;
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    fn get_tenant_resource_usage(&self) -> Vec<TenantResourceUsage>;
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
@@ -201,6 +228,27 @@ pub trait TableContext: Send + Sync {
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
+    /// Within a `SNAPSHOT ISOLATION` transaction, get the table with the snapshot read when the
+    /// transaction accessed it first, the snapshot is pinned for the following statements of
+    /// the transaction.
+    async fn pin_table_snapshot(&self, table: Arc<dyn Table>) -> Result<Arc<dyn Table>> {
+        Ok(table)
+    }
+
+    /// Called before a statement commits a new snapshot of the table, `table_info` is the
+    /// version to be replaced. Within a `SNAPSHOT ISOLATION` transaction, it fails if the table
+    /// has been changed since the snapshot read by the transaction.
+    async fn check_table_commit(&self, _table_info: &TableInfo) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called when a statement commits a new snapshot of the table, `table_info` is the
+    /// committed version. The snapshot is pinned for the following statements of the
+    /// transaction, so that they see their own writes.
+    fn on_table_committed(&self, _table_info: &TableInfo) -> Result<()> {
+        Ok(())
+    }
+
     async fn filter_out_copied_files(
         &self,
         catalog_name: &str,
@@ -43,6 +43,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use databend_common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use databend_common_meta_app::tenant::TenantQuota;
+use databend_common_storage::S3ReplicaConfig;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
@@ -268,6 +269,59 @@ pub struct StorageConfig {
     #[clap(long = "storage-allow-insecure")]
     pub allow_insecure: bool,
 
+    /// Allow the external stages created with `CREDENTIALS_FROM = 'ENV'` to load the
+    /// credentials from the environment of the node.
+    #[clap(long = "storage-allow-credential-chain")]
+    pub allow_credential_chain: bool,
+
+    /// Local directory to cache the objects read from remote storage, cleared on startup.
+    #[clap(long = "storage-cache-dir", value_name = "VALUE", default_value_t)]
+    pub cache_dir: String,
+
+    /// Max size in GB of the local storage cache, 0 means disabled.
+    #[clap(
+        long = "storage-cache-max-size-gb",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub cache_max_size_gb: u64,
+
+    /// Max retry times of the requests to S3 compatible storages on transient errors.
+    #[clap(
+        long = "storage-s3-max-retries",
+        value_name = "VALUE",
+        default_value = "3"
+    )]
+    pub s3_max_retries: u64,
+
+    /// The backoff in milliseconds of the first retry, it doubles for each following retry.
+    #[clap(
+        long = "storage-s3-retry-initial-delay-ms",
+        value_name = "VALUE",
+        default_value = "1000"
+    )]
+    pub s3_retry_initial_delay_ms: u64,
+
+    /// Total duration in seconds of the retries of a request to S3 compatible storages.
+    #[clap(
+        long = "storage-s3-request-timeout-secs",
+        value_name = "VALUE",
+        default_value = "60"
+    )]
+    pub s3_request_timeout_secs: u64,
+
+    /// The region of this node, the S3 buckets in the other regions are read from their replicas.
+    #[clap(
+        long = "storage-s3-local-region",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub s3_local_region: String,
+
+    /// Replicas of the S3 buckets in the other regions.
+    #[clap(skip)]
+    pub s3_replicas: Vec<S3ReplicaConfig>,
+
     // Fs storage backend config.
     #[clap(flatten)]
     pub fs: FsStorageConfig,
@@ -317,6 +371,14 @@ impl From<InnerStorageConfig> for StorageConfig {
             storage_num_cpus: inner.num_cpus,
             typ: "".to_string(),
             allow_insecure: inner.allow_insecure,
+            allow_credential_chain: inner.allow_credential_chain,
+            cache_dir: inner.cache_dir,
+            cache_max_size_gb: inner.cache_max_size_gb,
+            s3_max_retries: inner.s3_max_retries,
+            s3_retry_initial_delay_ms: inner.s3_retry_initial_delay_ms,
+            s3_request_timeout_secs: inner.s3_request_timeout_secs,
+            s3_local_region: inner.s3_local_region,
+            s3_replicas: inner.s3_replicas,
             // use default for each config instead of using `..Default::default`
             // using `..Default::default` is calling `Self::default`
             // and `Self::default` relies on `InnerStorage::into()`
@@ -402,6 +464,14 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
         Ok(InnerStorageConfig {
             num_cpus: self.storage_num_cpus,
             allow_insecure: self.allow_insecure,
+            allow_credential_chain: self.allow_credential_chain,
+            cache_dir: self.cache_dir,
+            cache_max_size_gb: self.cache_max_size_gb,
+            s3_max_retries: self.s3_max_retries,
+            s3_retry_initial_delay_ms: self.s3_retry_initial_delay_ms,
+            s3_request_timeout_secs: self.s3_request_timeout_secs,
+            s3_local_region: self.s3_local_region,
+            s3_replicas: self.s3_replicas,
             params: {
                 match self.typ.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
@@ -780,6 +850,7 @@ impl TryInto<InnerStorageS3Config> for S3StorageConfig {
             enable_virtual_host_style: self.enable_virtual_host_style,
             role_arn: self.s3_role_arn,
             external_id: self.s3_external_id,
+            preferred_region: "".to_string(),
         })
     }
 }
@@ -1485,6 +1556,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub rpc_client_timeout_secs: u64,
 
+    /// The lease of the node in the cluster registry, renewed by the heartbeat of the node
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub cluster_heartbeat_timeout_secs: u64,
+
     /// Table engine memory enabled
     #[clap(long,  value_name = "VALUE",value_parser = clap::value_parser!(bool), default_value = "true")]
     pub table_engine_memory_enabled: bool,
@@ -1495,6 +1570,25 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// The max memory in bytes used by the queries of a tenant, 0 means unlimited
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub tenant_max_memory_bytes: u64,
+
+    /// The worker threads of the runtime shared by the queries of a tenant,
+    /// 0 means each query runs in a runtime of its own
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub tenant_max_threads: u64,
+
+    /// The max queries of a tenant running at the same time, the others wait for them to finish.
+    /// 0 means unlimited
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub tenant_max_concurrent_queries: u64,
+
+    /// The max seconds a query waits for the running queries of its tenant to finish,
+    /// 0 means waiting without a timeout
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub tenant_query_queue_timeout_secs: u64,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1646,6 +1740,20 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE")]
     pub udf_server_allow_list: Vec<String>,
 
+    /// The number of previous versions kept for each UDF, 0 disables keeping them.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub max_udf_versions: usize,
+
+    /// Allow `IMPORT` to load the files in the filesystem of the query node,
+    /// which should be disabled in production.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub enable_local_import: bool,
+
+    /// The directory of the query node which `IMPORT` can load the files in, required by
+    /// `enable_local_import`.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub local_import_root: String,
+
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
 
@@ -1702,9 +1810,14 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             rpc_tls_query_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert,
             rpc_tls_query_service_domain_name: self.rpc_tls_query_service_domain_name,
             rpc_client_timeout_secs: self.rpc_client_timeout_secs,
+            cluster_heartbeat_timeout_secs: self.cluster_heartbeat_timeout_secs,
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            tenant_max_memory_bytes: self.tenant_max_memory_bytes,
+            tenant_max_threads: self.tenant_max_threads,
+            tenant_max_concurrent_queries: self.tenant_max_concurrent_queries,
+            tenant_query_queue_timeout_secs: self.tenant_query_queue_timeout_secs,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1731,6 +1844,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_version: self.openai_api_version,
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
+            max_udf_versions: self.max_udf_versions,
+            enable_local_import: self.enable_local_import,
+            local_import_root: self.local_import_root,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
             settings: self
@@ -1786,9 +1902,14 @@ impl From<InnerQueryConfig> for QueryConfig {
             rpc_tls_query_server_root_ca_cert: inner.rpc_tls_query_server_root_ca_cert,
             rpc_tls_query_service_domain_name: inner.rpc_tls_query_service_domain_name,
             rpc_client_timeout_secs: inner.rpc_client_timeout_secs,
+            cluster_heartbeat_timeout_secs: inner.cluster_heartbeat_timeout_secs,
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            tenant_max_memory_bytes: inner.tenant_max_memory_bytes,
+            tenant_max_threads: inner.tenant_max_threads,
+            tenant_max_concurrent_queries: inner.tenant_max_concurrent_queries,
+            tenant_query_queue_timeout_secs: inner.tenant_query_queue_timeout_secs,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
@@ -1827,6 +1948,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_embedding_model: inner.openai_api_embedding_model,
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
+            max_udf_versions: inner.max_udf_versions,
+            enable_local_import: inner.enable_local_import,
+            local_import_root: inner.local_import_root,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
             settings: HashMap::new(),
@@ -2766,6 +2890,14 @@ pub struct CacheConfig {
     )]
     pub table_prune_partitions_count: u64,
 
+    /// Max number of cached meta data of the files of parquet tables. Set it to 0 to disable it.
+    #[clap(
+        long = "cache-parquet-file-meta-count",
+        value_name = "VALUE",
+        default_value = "3000"
+    )]
+    pub parquet_file_meta_count: u64,
+
     /// Type of data cache storage
     #[clap(
         long = "cache-data-cache-storage",
@@ -2940,6 +3072,7 @@ mod cache_config_converters {
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                parquet_file_meta_count: value.parquet_file_meta_count,
                 data_cache_storage: value.data_cache_storage.try_into()?,
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
@@ -2961,6 +3094,7 @@ mod cache_config_converters {
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                parquet_file_meta_count: value.parquet_file_meta_count,
                 data_cache_storage: value.data_cache_storage.into(),
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
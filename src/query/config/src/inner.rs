@@ -188,11 +188,21 @@ pub struct QueryConfig {
     pub rpc_tls_query_server_root_ca_cert: String,
     pub rpc_tls_query_service_domain_name: String,
     pub rpc_client_timeout_secs: u64,
+    /// The lease of the node in the cluster registry, renewed by the heartbeat of the node
+    pub cluster_heartbeat_timeout_secs: u64,
     /// Table engine memory enabled
     pub table_engine_memory_enabled: bool,
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    /// The max memory in bytes used by the queries of a tenant, 0 means unlimited
+    pub tenant_max_memory_bytes: u64,
+    /// The worker threads of the runtime shared by the queries of a tenant, 0 means not shared
+    pub tenant_max_threads: u64,
+    /// The max queries of a tenant running at the same time, 0 means unlimited
+    pub tenant_max_concurrent_queries: u64,
+    /// The max seconds a query waits for the running queries of its tenant, 0 means no timeout
+    pub tenant_query_queue_timeout_secs: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -226,6 +236,11 @@ pub struct QueryConfig {
 
     pub enable_udf_server: bool,
     pub udf_server_allow_list: Vec<String>,
+    /// The number of previous versions kept for each UDF, 0 disables keeping them.
+    pub max_udf_versions: usize,
+    pub enable_local_import: bool,
+    /// The directory which `IMPORT` can load the files in.
+    pub local_import_root: String,
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
@@ -269,9 +284,14 @@ impl Default for QueryConfig {
             rpc_tls_query_server_root_ca_cert: "".to_string(),
             rpc_tls_query_service_domain_name: "localhost".to_string(),
             rpc_client_timeout_secs: 0,
+            cluster_heartbeat_timeout_secs: 60,
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            tenant_max_memory_bytes: 0,
+            tenant_max_threads: 0,
+            tenant_max_concurrent_queries: 0,
+            tenant_query_queue_timeout_secs: 60,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -296,6 +316,9 @@ impl Default for QueryConfig {
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
+            max_udf_versions: 10,
+            enable_local_import: false,
+            local_import_root: "".to_string(),
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
             data_retention_time_in_days_max: 90,
@@ -527,6 +550,9 @@ pub struct CacheConfig {
     /// Max number of cached prune partitions objects. Set it to 0 to disable it.
     pub table_prune_partitions_count: u64,
 
+    /// Max number of cached meta data of the files of parquet tables. Set it to 0 to disable it.
+    pub parquet_file_meta_count: u64,
+
     /// Max number of cached bloom index filters. Set it to 0 to disable it.
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     //
@@ -617,6 +643,7 @@ impl Default for CacheConfig {
             table_bloom_index_filter_count: 0,
             table_bloom_index_filter_size: 2147483648,
             table_prune_partitions_count: 256,
+            parquet_file_meta_count: 3000,
             data_cache_storage: Default::default(),
             table_data_cache_population_queue_size: 0,
             disk_cache_config: Default::default(),
@@ -19,6 +19,7 @@ use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONED_SNAPSHOT_LOCATIONS;
 use futures_util::TryStreamExt;
 use log::info;
 use opendal::EntryMode;
@@ -37,6 +38,15 @@ pub async fn do_vacuum_drop_table(
         return Ok(None);
     }
 
+    // the files of a table which has been cloned are shared with the clones, ignore
+    if table_info
+        .options()
+        .contains_key(OPT_KEY_CLONED_SNAPSHOT_LOCATIONS)
+    {
+        info!("ignore cloned table {}", table_info.name);
+        return Ok(None);
+    }
+
     let dir = format!("{}/", FuseTable::parse_storage_prefix(table_info)?);
     info!("vacuum drop table {:?} dir {:?}", table_info.name, dir);
     let start = Instant::now();
@@ -129,10 +129,18 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+
+    // The files shared with the tables cloned from this table are referenced by them.
+    let protected_locations = fuse_table.read_protected_locations(ctx).await?;
+    segments.extend(protected_locations.segments);
+    blocks.extend(protected_locations.blocks);
+    blocks_index.extend(protected_locations.blooms);
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
@@ -143,7 +151,13 @@ async fn get_orphan_files_to_be_purged(
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
+    // A cloned table refers to the files of the table it was cloned from, only the files of the
+    // table itself are listed.
+    let own_location = referenced_files
+        .iter()
+        .find(|location| fuse_table.is_own_location(location))
+        .cloned();
+    let files_to_be_purged = match own_location {
         Some(location) => {
             let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
             if let Some(prefix) = prefix {
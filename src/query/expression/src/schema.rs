@@ -164,6 +164,10 @@ pub struct TableField {
     #[serde(default = "uninit_column_id")]
     pub column_id: ColumnId,
     pub computed_expr: Option<ComputedExpr>,
+    /// The length declared for a string column, e.g. 255 for `VARCHAR(255)`, kept for
+    /// `SHOW CREATE TABLE`; the length of the values is not checked.
+    #[serde(default)]
+    pub declared_length: Option<u64>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -1004,6 +1008,7 @@ impl TableField {
             data_type,
             column_id: 0,
             computed_expr: None,
+            declared_length: None,
         }
     }
 
@@ -1014,6 +1019,7 @@ impl TableField {
             data_type,
             column_id,
             computed_expr: None,
+            declared_length: None,
         }
     }
 
@@ -1060,6 +1066,7 @@ impl TableField {
             data_type: self.data_type.clone(),
             column_id,
             computed_expr: self.computed_expr.clone(),
+            declared_length: self.declared_length,
         }
     }
 
@@ -1105,6 +1112,11 @@ impl TableField {
         self
     }
 
+    pub fn with_declared_length(mut self, declared_length: Option<u64>) -> Self {
+        self.declared_length = declared_length;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1121,6 +1133,10 @@ impl TableField {
         self.computed_expr.as_ref()
     }
 
+    pub fn declared_length(&self) -> Option<u64> {
+        self.declared_length
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
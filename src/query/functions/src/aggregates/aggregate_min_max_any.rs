@@ -21,6 +21,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
 use databend_common_expression::types::number::*;
+use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::*;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::Scalar;
@@ -110,6 +111,79 @@ where
     }
 }
 
+/// The state of `min`, `max` and `any` for strings, serialized as `MinMaxAnyState`.
+///
+/// A serialized state is merged by comparing its bytes in place, so the string is
+/// only copied when it changes the value of the state.
+#[derive(Default)]
+pub struct MinMaxStringState<C>
+where C: ChangeIf<StringType> + Default
+{
+    inner: MinMaxAnyState<StringType, C>,
+}
+
+impl<C> BorshSerialize for MinMaxStringState<C>
+where C: ChangeIf<StringType> + Default
+{
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.inner.serialize(writer)
+    }
+}
+
+impl<C> BorshDeserialize for MinMaxStringState<C>
+where C: ChangeIf<StringType> + Default
+{
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        Ok(Self {
+            inner: MinMaxAnyState::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl<C> UnaryState<StringType, StringType> for MinMaxStringState<C>
+where C: ChangeIf<StringType> + Default
+{
+    fn add(&mut self, other: &str) -> Result<()> {
+        self.inner.add(other)
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.inner.merge(&rhs.inner)
+    }
+
+    fn merge_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        // The layout of a borsh `Option<String>`: a tag byte, then the length
+        // of the string as a little endian u32 followed by its bytes.
+        let bad_bytes = || ErrorCode::BadBytes("Invalid state of min/max/any");
+        let (tag, rest) = reader.split_first().ok_or_else(bad_bytes)?;
+        if *tag == 0 {
+            *reader = rest;
+            return Ok(());
+        }
+        if rest.len() < 4 {
+            return Err(bad_bytes());
+        }
+        let (len, rest) = rest.split_at(4);
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        if rest.len() < len {
+            return Err(bad_bytes());
+        }
+        let (value, rest) = rest.split_at(len);
+        let value = std::str::from_utf8(value).map_err(|_| bad_bytes())?;
+        self.inner.add(value)?;
+        *reader = rest;
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut StringColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.inner.merge_result(builder, function_data)
+    }
+}
+
 pub fn try_create_aggregate_min_max_any_function<const CMP_TYPE: u8>(
     display_name: &str,
     params: Vec<Scalar>,
@@ -126,6 +200,17 @@ pub fn try_create_aggregate_min_max_any_function<const CMP_TYPE: u8>(
 
     with_compare_mapped_type!(|CMP| match CMP_TYPE {
         CMP => {
+            if data_type == DataType::String {
+                let return_type = data_type.clone();
+                let func = AggregateUnaryFunction::<
+                    MinMaxStringState<CMP>,
+                    StringType,
+                    StringType,
+                >::try_create(display_name, return_type, params, data_type)
+                .with_need_drop(need_drop);
+
+                return Ok(Arc::new(func));
+            }
             with_simple_no_number_mapped_type!(|T| match data_type {
                 DataType::T => {
                     let return_type = data_type.clone();
@@ -45,6 +45,13 @@ where
 
     fn merge(&mut self, rhs: &Self) -> Result<()>;
 
+    /// Merge a state serialized by `BorshSerialize`. States able to merge the raw bytes
+    /// can override it to skip the allocations of deserializing the state.
+    fn merge_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let rhs = Self::deserialize_reader(reader)?;
+        self.merge(&rhs)
+    }
+
     fn merge_result(
         &mut self,
         builder: &mut R::ColumnBuilder,
@@ -249,8 +256,7 @@ where
 
     fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
         let state: &mut S = place.get::<S>();
-        let rhs = S::deserialize_reader(reader)?;
-        state.merge(&rhs)
+        state.merge_binary(reader)
     }
 
     fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
@@ -14,17 +14,22 @@
 
 use std::io::Write;
 
+use bumpalo::Bump;
 use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::BitmapType;
 use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::DecimalSize;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::FromData;
+use databend_common_expression::StateAddr;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
 use goldenfile::Mint;
 use itertools::Itertools;
 use roaring::RoaringTreemap;
@@ -764,3 +769,47 @@ fn test_agg_group_array_moving_sum(file: &mut impl Write, simulator: impl Aggreg
         simulator,
     );
 }
+
+#[test]
+fn test_agg_min_max_string_merge() {
+    let factory = AggregateFunctionFactory::instance();
+    let arena = Bump::new();
+    for (name, expected) in [("min", "abc"), ("max", "xyz"), ("any", "opq")] {
+        let func = factory.get(name, vec![], vec![DataType::String]).unwrap();
+        let create_state = |values: &[&str]| {
+            let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+            func.init_state(place);
+            let column = StringType::from_data(values.to_vec());
+            func.accumulate(place, &[column], None, values.len())
+                .unwrap();
+            place
+        };
+
+        // The states are merged from their serialized form, including an empty state.
+        let place = create_state(&["opq", "def"]);
+        let mut states = vec![];
+        for values in [&["xyz"][..], &[], &["abc", "xyz"]] {
+            func.serialize(create_state(values), &mut states).unwrap();
+        }
+        let mut reader = states.as_slice();
+        for _ in 0..3 {
+            func.merge(place, &mut reader).unwrap();
+        }
+        assert!(reader.is_empty());
+
+        let mut builder = ColumnBuilder::with_capacity(&DataType::String, 1);
+        func.merge_result(place, &mut builder).unwrap();
+        assert_eq!(
+            builder.build(),
+            StringType::from_data(vec![expected]),
+            "{name}"
+        );
+
+        let mut reader = &states[..states.len() - 1];
+        let mut result = Ok(());
+        for _ in 0..3 {
+            result = result.and_then(|_| func.merge(place, &mut reader));
+        }
+        assert!(result.is_err(), "{name}");
+    }
+}
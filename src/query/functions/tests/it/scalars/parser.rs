@@ -533,7 +533,8 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
             DataType::Decimal(DecimalDataType::from_size(DecimalSize { precision, scale }).unwrap())
         }
         databend_common_ast::ast::TypeName::Binary => DataType::Binary,
-        databend_common_ast::ast::TypeName::String => DataType::String,
+        databend_common_ast::ast::TypeName::String
+        | databend_common_ast::ast::TypeName::Varchar(_) => DataType::String,
         databend_common_ast::ast::TypeName::Timestamp => DataType::Timestamp,
         databend_common_ast::ast::TypeName::Date => DataType::Date,
         databend_common_ast::ast::TypeName::Array(item_type) => {
@@ -12,10 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
 use databend_common_exception::Result;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::NodeInfo;
 
+/// A node heartbeats at a random interval between 1/3 and 2/3 of the lift time, so
+/// that it renews its lease well before the lease expires.
+pub fn heartbeat_interval(lift_time: Duration) -> RangeInclusive<Duration> {
+    lift_time / 3..=lift_time * 2 / 3
+}
+
+/// The node info with the liveness in the cluster registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeHeartbeat {
+    pub node: NodeInfo,
+    pub seq: u64,
+    /// The last heartbeat time of the node, in milliseconds since 1970.
+    pub last_heartbeat_ms: u64,
+    /// The longest interval between two heartbeats of the node, in milliseconds.
+    pub heartbeat_interval_ms: u64,
+}
+
+impl NodeHeartbeat {
+    /// A node is considered dead once a full heartbeat interval plus a margin passed
+    /// since its last heartbeat, even if its lease is not expired yet. The margin
+    /// covers the latency of the heartbeat and the clock skew between the nodes.
+    pub fn is_alive(&self, now_ms: u64) -> bool {
+        let margin_ms = self.heartbeat_interval_ms / 4;
+        now_ms.saturating_sub(self.last_heartbeat_ms) <= self.heartbeat_interval_ms + margin_ms
+    }
+}
+
 #[async_trait::async_trait]
 pub trait ClusterApi: Sync + Send {
     // Add a new node info to /tenant/cluster_id/node-name.
@@ -24,6 +54,9 @@ pub trait ClusterApi: Sync + Send {
     // Get the tenant's cluster all nodes.
     async fn get_nodes(&self) -> Result<Vec<NodeInfo>>;
 
+    // Get the tenant's cluster all nodes, with the last heartbeat of each node.
+    async fn get_nodes_heartbeat(&self) -> Result<Vec<NodeHeartbeat>>;
+
     // Drop the tenant's cluster one node by node.id.
     async fn drop_node(&self, node_id: String, seq: MatchSeq) -> Result<()>;
 
@@ -29,11 +29,29 @@ use databend_common_meta_types::MetaSpec;
 use databend_common_meta_types::NodeInfo;
 use databend_common_meta_types::Operation;
 use databend_common_meta_types::SeqV;
+use databend_common_meta_types::SeqValue;
+use serde::Deserialize;
+use serde::Serialize;
 
+use crate::cluster::heartbeat_interval;
 use crate::cluster::ClusterApi;
+use crate::cluster::NodeHeartbeat;
 
 pub static CLUSTER_API_KEY_PREFIX: &str = "__fd_clusters";
 
+/// The value of a node in the cluster registry. The heartbeat is recorded in it, since
+/// the lease is only in seconds and doesn't tell the heartbeat interval of the node.
+#[derive(Serialize, Deserialize)]
+struct NodeValue {
+    #[serde(flatten)]
+    node: NodeInfo,
+    /// Zero if the node is registered by a version without it.
+    #[serde(default)]
+    heartbeat_at_ms: u64,
+    #[serde(default)]
+    heartbeat_interval_ms: u64,
+}
+
 pub struct ClusterMgr {
     metastore: MetaStore,
     lift_time: Duration,
@@ -74,6 +92,14 @@ impl ClusterMgr {
 
         MetaSpec::new_expire(expire_at.as_secs())
     }
+
+    fn new_node_value(&self, node: &NodeInfo) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&NodeValue {
+            node: node.clone(),
+            heartbeat_at_ms: SeqV::<()>::now_ms(),
+            heartbeat_interval_ms: heartbeat_interval(self.lift_time).end().as_millis() as u64,
+        })?)
+    }
 }
 
 #[async_trait::async_trait]
@@ -84,7 +110,7 @@ impl ClusterApi for ClusterMgr {
         // Only when there are no record, i.e. seq=0
         let seq = MatchSeq::Exact(0);
         let meta = Some(self.new_lift_time());
-        let value = Operation::Update(serde_json::to_vec(&node)?);
+        let value = Operation::Update(self.new_node_value(&node)?);
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
         let upsert_node = self
             .metastore
@@ -116,6 +142,39 @@ impl ClusterApi for ClusterMgr {
         Ok(nodes_info)
     }
 
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_nodes_heartbeat(&self) -> Result<Vec<NodeHeartbeat>> {
+        let values = self.metastore.prefix_list_kv(&self.cluster_prefix).await?;
+        let lift_time_ms = self.lift_time.as_millis() as u64;
+
+        let mut nodes_heartbeat = Vec::with_capacity(values.len());
+        for (node_key, value) in values {
+            let mut node_value = serde_json::from_slice::<NodeValue>(&value.data)?;
+            node_value.node.id = unescape_for_key(&node_key[self.cluster_prefix.len() + 1..])?;
+
+            // The node registered by a version without the heartbeat in the value renews
+            // its lease with the lift time on every heartbeat.
+            if node_value.heartbeat_at_ms == 0 {
+                node_value.heartbeat_at_ms = value
+                    .get_expire_at_ms()
+                    .map(|expire_at_ms| expire_at_ms.saturating_sub(lift_time_ms))
+                    .unwrap_or_default();
+                node_value.heartbeat_interval_ms =
+                    heartbeat_interval(self.lift_time).end().as_millis() as u64;
+            }
+
+            nodes_heartbeat.push(NodeHeartbeat {
+                node: node_value.node,
+                seq: value.seq,
+                last_heartbeat_ms: node_value.heartbeat_at_ms,
+                heartbeat_interval_ms: node_value.heartbeat_interval_ms,
+            });
+        }
+
+        Ok(nodes_heartbeat)
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn drop_node(&self, node_id: String, seq: MatchSeq) -> Result<()> {
@@ -143,9 +202,10 @@ impl ClusterApi for ClusterMgr {
         let meta = Some(self.new_lift_time());
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
 
-        let upsert_meta =
-            self.metastore
-                .upsert_kv(UpsertKVReq::new(&node_key, seq, Operation::AsIs, meta));
+        let value = Operation::Update(self.new_node_value(node)?);
+        let upsert_meta = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&node_key, seq, value, meta));
 
         match upsert_meta.await? {
             UpsertKVReply {
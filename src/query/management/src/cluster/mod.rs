@@ -15,5 +15,7 @@
 mod cluster_api;
 mod cluster_mgr;
 
+pub use cluster_api::heartbeat_interval;
 pub use cluster_api::ClusterApi;
+pub use cluster_api::NodeHeartbeat;
 pub use cluster_mgr::ClusterMgr;
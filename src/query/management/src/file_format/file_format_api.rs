@@ -33,6 +33,13 @@ pub trait FileFormatApi: Sync + Send {
         seq: MatchSeq,
     ) -> Result<SeqV<UserDefinedFileFormat>>;
 
+    // Update the file_format with the same name, returns the new seq.
+    async fn update_file_format(
+        &self,
+        file_format: UserDefinedFileFormat,
+        seq: MatchSeq,
+    ) -> Result<u64>;
+
     // Get all the file_formats for a tenant.
     async fn get_file_formats(&self) -> Result<Vec<UserDefinedFileFormat>>;
 
@@ -121,6 +121,33 @@ impl FileFormatApi for FileFormatMgr {
         }
     }
 
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_file_format(&self, info: UserDefinedFileFormat, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serialize_struct(
+            &info,
+            ErrorCode::IllegalFileFormat,
+            || "",
+        )?);
+        let key = format!(
+            "{}/{}",
+            self.file_format_prefix,
+            escape_for_key(&info.name)?
+        );
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq: s, .. }) if res.prev.is_some() => Ok(s),
+            _ => Err(ErrorCode::UnknownFileFormat(format!(
+                "File format '{}' does not exist.",
+                info.name
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn get_file_formats(&self) -> Result<Vec<UserDefinedFileFormat>> {
@@ -17,26 +17,35 @@
 mod cluster;
 mod connection;
 mod file_format;
+mod migration;
 mod network_policy;
+mod notification;
 mod password_policy;
 mod quota;
 mod role;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 pub mod udf;
 mod user;
 
 pub mod errors;
 
+pub use cluster::heartbeat_interval;
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use cluster::NodeHeartbeat;
 pub use connection::ConnectionApi;
 pub use connection::ConnectionMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
+pub use migration::MigrationApi;
+pub use migration::MigrationMgr;
 pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
+pub use notification::NotificationApi;
+pub use notification::NotificationMgr;
 pub use password_policy::PasswordPolicyApi;
 pub use password_policy::PasswordPolicyMgr;
 pub use quota::QuotaApi;
@@ -50,5 +59,7 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskApi;
+pub use task::TaskMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Migration;
+
+#[async_trait::async_trait]
+pub trait MigrationApi: Sync + Send {
+    /// Records an applied migration, fails if a migration with the same name is recorded.
+    async fn add_migration(&self, migration: Migration) -> Result<()>;
+
+    async fn drop_migration(&self, name: &str) -> Result<()>;
+
+    async fn get_migration(&self, name: &str) -> Result<Option<Migration>>;
+
+    /// Lists the migrations in the order they were applied.
+    async fn list_migrations(&self) -> Result<Vec<Migration>>;
+}
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Migration;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::Operation;
+
+use crate::migration::migration_api::MigrationApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static MIGRATION_API_KEY_PREFIX: &str = "__fd_migrations";
+
+pub struct MigrationMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    migration_prefix: String,
+}
+
+impl MigrationMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create migration)",
+            ));
+        }
+
+        Ok(MigrationMgr {
+            kv_api,
+            migration_prefix: format!("{}/{}", MIGRATION_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_migration_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.migration_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationApi for MigrationMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_migration(&self, migration: Migration) -> Result<()> {
+        let key = self.make_migration_key(&migration.name)?;
+        let value = Operation::Update(serialize_struct(
+            &migration,
+            ErrorCode::IllegalMigration,
+            || "",
+        )?);
+
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(0), value, None))
+            .await?;
+        if res.prev.is_some() {
+            return Err(ErrorCode::IllegalMigration(format!(
+                "Migration '{}' is already applied.",
+                migration.name
+            )));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_migration(&self, name: &str) -> Result<()> {
+        let key = self.make_migration_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(1),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownMigration(format!(
+                "Migration '{}' is not applied.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_migration(&self, name: &str) -> Result<Option<Migration>> {
+        let key = self.make_migration_key(name)?;
+        match self.kv_api.get_kv(&key).await? {
+            Some(seq_value) => Ok(Some(deserialize_struct(
+                &seq_value.data,
+                ErrorCode::IllegalMigration,
+                || "",
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn list_migrations(&self) -> Result<Vec<Migration>> {
+        let values = self.kv_api.prefix_list_kv(&self.migration_prefix).await?;
+
+        let mut migrations = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let migration: Migration =
+                deserialize_struct(&value.data, ErrorCode::IllegalMigration, || "")?;
+            migrations.push(migration);
+        }
+        migrations.sort_by_key(|m| m.applied_at);
+        Ok(migrations)
+    }
+}
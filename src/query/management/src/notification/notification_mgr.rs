@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::notification::notification_api::NotificationApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static NOTIFICATION_API_KEY_PREFIX: &str = "__fd_notification_integrations";
+
+pub struct NotificationMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    notification_prefix: String,
+}
+
+impl NotificationMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create notification integration)",
+            ));
+        }
+
+        Ok(NotificationMgr {
+            kv_api,
+            notification_prefix: format!("{}/{}", NOTIFICATION_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_notification_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.notification_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationApi for NotificationMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_notification_integration(
+        &self,
+        notification_integration: NotificationIntegration,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let seq = MatchSeq::from(*create_option);
+        let key = self.make_notification_key(notification_integration.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &notification_integration,
+            ErrorCode::IllegalNotificationIntegration,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        if let CreateOption::CreateIfNotExists(false) = create_option {
+            if res.prev.is_some() {
+                return Err(ErrorCode::NotificationIntegrationAlreadyExists(format!(
+                    "Notification integration '{}' already exists.",
+                    notification_integration.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_notification_integration(
+        &self,
+        notification_integration: NotificationIntegration,
+        match_seq: MatchSeq,
+    ) -> Result<u64> {
+        let key = self.make_notification_key(notification_integration.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &notification_integration,
+            ErrorCode::IllegalNotificationIntegration,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownNotificationIntegration(format!(
+                "Notification integration '{}' cannot be updated as it may not exist or the request is invalid.",
+                notification_integration.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_notification_integration(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_notification_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownNotificationIntegration(format!(
+                "Notification integration '{}' does not exist.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_notification_integration(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<NotificationIntegration>> {
+        let key = self.make_notification_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownNotificationIntegration(format!(
+                "Notification integration '{}' does not exist.",
+                name
+            ))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(
+                    &seq_value.data,
+                    ErrorCode::IllegalNotificationIntegration,
+                    || "",
+                )?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownNotificationIntegration(format!(
+                "Notification integration '{}' does not exist.",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_notification_integrations(&self) -> Result<Vec<NotificationIntegration>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.notification_prefix)
+            .await?;
+
+        let mut notification_integrations = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let notification_integration = deserialize_struct(
+                &value.data,
+                ErrorCode::IllegalNotificationIntegration,
+                || "",
+            )?;
+            notification_integrations.push(notification_integration);
+        }
+        Ok(notification_integrations)
+    }
+}
@@ -30,6 +30,12 @@ pub trait StageApi: Sync + Send {
     // Drop the tenant's stage by name.
     async fn drop_stage(&self, name: &str) -> Result<()>;
 
+    // Replace the stage info of an existing stage, the number of files is kept as is.
+    async fn update_stage(&self, stage: StageInfo) -> Result<()>;
+
+    // Rename a stage, along with the files recorded for it.
+    async fn rename_stage(&self, name: &str, new_name: &str) -> Result<()>;
+
     async fn add_file(&self, name: &str, file: StageFile) -> Result<u64>;
 
     async fn list_files(&self, name: &str) -> Result<Vec<StageFile>>;
@@ -172,6 +172,129 @@ impl StageApi for StageMgr {
         ))
     }
 
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_stage(&self, mut info: StageInfo) -> Result<()> {
+        let stage_key = format!(
+            "{}/{}",
+            self.stage_prefix,
+            escape_for_key(&info.stage_name)?
+        );
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (stage_seq, old_stage): (_, StageInfo) =
+                if let Some(seq_v) = self.kv_api.get_kv(&stage_key).await? {
+                    (
+                        seq_v.seq,
+                        deserialize_struct(&seq_v.data, ErrorCode::IllegalUserStageFormat, || "")?,
+                    )
+                } else {
+                    return Err(ErrorCode::UnknownStage(format!(
+                        "Stage '{}' does not exist.",
+                        info.stage_name
+                    )));
+                };
+            // The files may be added while the stage is being altered.
+            info.number_of_files = old_stage.number_of_files;
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // stage is not changed
+                    txn_cond_seq(&stage_key, Eq, stage_seq),
+                ],
+                if_then: vec![txn_op_put(
+                    &stage_key,
+                    serialize_struct(&info, ErrorCode::IllegalUserStageFormat, || "")?,
+                )],
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("update_stage", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn rename_stage(&self, name: &str, new_name: &str) -> Result<()> {
+        let stage_key = format!("{}/{}", self.stage_prefix, escape_for_key(name)?);
+        let new_stage_key = format!("{}/{}", self.stage_prefix, escape_for_key(new_name)?);
+        let file_key_prefix = format!("{}/{}/", self.stage_file_prefix, escape_for_key(name)?);
+        let new_file_key_prefix =
+            format!("{}/{}/", self.stage_file_prefix, escape_for_key(new_name)?);
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (stage_seq, mut stage): (_, StageInfo) =
+                if let Some(seq_v) = self.kv_api.get_kv(&stage_key).await? {
+                    (
+                        seq_v.seq,
+                        deserialize_struct(&seq_v.data, ErrorCode::IllegalUserStageFormat, || "")?,
+                    )
+                } else {
+                    return Err(ErrorCode::UnknownStage(format!(
+                        "Stage '{}' does not exist.",
+                        name
+                    )));
+                };
+            if self.kv_api.get_kv(&new_stage_key).await?.is_some() {
+                return Err(ErrorCode::StageAlreadyExists(format!(
+                    "Stage '{}' already exists.",
+                    new_name
+                )));
+            }
+            stage.stage_name = new_name.to_string();
+
+            let mut if_then = vec![
+                txn_op_del(&stage_key),
+                txn_op_put(
+                    &new_stage_key,
+                    serialize_struct(&stage, ErrorCode::IllegalUserStageFormat, || "")?,
+                ),
+            ];
+            // move the files of the stage, they are not changed as long as the stage is not.
+            let file_keys = self.kv_api.prefix_list_kv(&file_key_prefix).await?;
+            for (key, value) in file_keys {
+                let new_key = format!("{}{}", new_file_key_prefix, &key[file_key_prefix.len()..]);
+                if_then.push(txn_op_del(&key));
+                if_then.push(txn_op_put(&new_key, value.data));
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // stage is not changed
+                    txn_cond_seq(&stage_key, Eq, stage_seq),
+                    // new stage does not exist
+                    txn_cond_seq(&new_stage_key, Eq, 0),
+                ],
+                if_then,
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("rename_stage", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn add_file(&self, name: &str, file: StageFile) -> Result<u64> {
@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    /// Adds a task with a new id, returns the id of the task.
+    async fn add_task(&self, task: Task, create_option: &CreateOption) -> Result<u64>;
+
+    async fn update_task(&self, task: Task, seq: MatchSeq) -> Result<u64>;
+
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>>;
+
+    async fn get_tasks(&self) -> Result<Vec<Task>>;
+
+    /// Adds a finished run to the history, which expires after `ttl`.
+    async fn add_task_run(&self, task_run: TaskRun, ttl: Duration) -> Result<()>;
+
+    /// Lists the runs in the history in the order of the tasks and the scheduled time.
+    async fn list_task_runs(&self) -> Result<Vec<TaskRun>>;
+
+    /// Claims the run of a task scheduled at `scheduled_at`, only one of the query nodes
+    /// trying to claim the same run succeeds until the claim expires after `ttl`.
+    async fn try_claim_task_run(
+        &self,
+        name: &str,
+        scheduled_at: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Result<bool>;
+}
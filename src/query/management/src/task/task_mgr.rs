@@ -0,0 +1,273 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::task::task_api::TaskApi;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+static TASK_RUN_CLAIM_KEY_PREFIX: &str = "__fd_task_run_claims";
+/// The seq of the key is used as the id of the tasks.
+static TASK_ID_GEN_KEY: &str = "__fd_task_id_gen";
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_prefix: String,
+    task_run_prefix: String,
+    task_run_claim_prefix: String,
+}
+
+impl TaskMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create task)",
+            ));
+        }
+
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+            task_run_claim_prefix: format!("{}/{}", TASK_RUN_CLAIM_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_task_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.task_prefix, escape_for_key(name)?))
+    }
+
+    /// The runs of a task are listed in the order of the scheduled time as the keys are
+    /// ordered, so the time is padded to a fixed width.
+    fn make_task_run_key(&self, task_run: &TaskRun) -> Result<String> {
+        Ok(format!(
+            "{}/{}/{:020}/{}",
+            self.task_run_prefix,
+            escape_for_key(&task_run.task_name)?,
+            task_run.scheduled_at.timestamp_micros(),
+            escape_for_key(&task_run.run_id)?
+        ))
+    }
+
+    fn make_task_run_claim_key(&self, name: &str, scheduled_at: DateTime<Utc>) -> Result<String> {
+        Ok(format!(
+            "{}/{}/{}",
+            self.task_run_claim_prefix,
+            escape_for_key(name)?,
+            scheduled_at.timestamp_micros()
+        ))
+    }
+
+    fn expire_after(ttl: Duration) -> MetaSpec {
+        let expire_at = (SystemTime::now() + ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        MetaSpec::new_expire(expire_at.as_secs())
+    }
+
+    #[async_backtrace::framed]
+    async fn generate_task_id(&self) -> Result<u64> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                TASK_ID_GEN_KEY,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+        match res.result {
+            Some(SeqV { seq, .. }) => Ok(seq),
+            None => Err(ErrorCode::Internal("Cannot generate the id of the task")),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task(&self, mut task: Task, create_option: &CreateOption) -> Result<u64> {
+        task.id = self.generate_task_id().await?;
+
+        let seq = MatchSeq::from(*create_option);
+        let key = self.make_task_key(task.name.as_str())?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        if let CreateOption::CreateIfNotExists(false) = create_option {
+            if res.prev.is_some() {
+                return Err(ErrorCode::TaskAlreadyExists(format!(
+                    "Task '{}' already exists.",
+                    task.name
+                )));
+            }
+        }
+
+        Ok(task.id)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_task(&self, task: Task, match_seq: MatchSeq) -> Result<u64> {
+        let key = self.make_task_key(task.name.as_str())?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        // The task is not updated if it has been dropped or updated since it was read.
+        match (upsert_kv.is_changed(), upsert_kv.result) {
+            (true, Some(SeqV { seq: s, .. })) => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' cannot be updated as it may not exist or the request is invalid.",
+                task.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_task_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>> {
+        let key = self.make_task_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Task '{}' does not exist.", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalTask, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist.",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_tasks(&self) -> Result<Vec<Task>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task_run(&self, task_run: TaskRun, ttl: Duration) -> Result<()> {
+        let key = self.make_task_run_key(&task_run)?;
+        let value = Operation::Update(serialize_struct(&task_run, ErrorCode::IllegalTask, || "")?);
+
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                value,
+                Some(Self::expire_after(ttl)),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn list_task_runs(&self) -> Result<Vec<TaskRun>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_run_prefix).await?;
+
+        let mut task_runs = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task_run = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            task_runs.push(task_run);
+        }
+        Ok(task_runs)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn try_claim_task_run(
+        &self,
+        name: &str,
+        scheduled_at: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let key = self.make_task_run_claim_key(name, scheduled_at)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(Self::expire_after(ttl)),
+            ))
+            .await?;
+        Ok(res.prev.is_none() && res.result.is_some())
+    }
+}
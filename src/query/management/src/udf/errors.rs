@@ -15,6 +15,7 @@
 use std::fmt::Display;
 
 use databend_common_exception::ErrorCode;
+use databend_common_meta_app::app_error::TxnRetryMaxTimes;
 use databend_common_meta_types::MetaError;
 
 use crate::errors::TenantError;
@@ -37,6 +38,14 @@ pub enum UdfError {
         name: String,
         reason: String,
     },
+
+    // NOTE: do not expose tenant in a for-user error message.
+    #[error("UDF has an invalid signature: '{name}'; {reason}")]
+    InvalidSignature {
+        tenant: String,
+        name: String,
+        reason: String,
+    },
 }
 
 impl From<UdfError> for ErrorCode {
@@ -45,6 +54,7 @@ impl From<UdfError> for ErrorCode {
         match value {
             UdfError::NotFound { .. } => ErrorCode::UnknownUDF(s),
             UdfError::Exists { .. } => ErrorCode::UdfAlreadyExists(s),
+            UdfError::InvalidSignature { .. } => ErrorCode::IllegalUDFFormat(s),
         }
     }
 }
@@ -60,6 +70,9 @@ pub enum UdfApiError {
         meta_err: MetaError,
         context: String,
     },
+
+    #[error(transparent)]
+    TxnRetryMaxTimes(#[from] TxnRetryMaxTimes),
 }
 
 impl From<MetaError> for UdfApiError {
@@ -78,6 +91,7 @@ impl From<UdfApiError> for ErrorCode {
             UdfApiError::MetaError { meta_err, context } => {
                 ErrorCode::from(meta_err).add_message_back(context)
             }
+            UdfApiError::TxnRetryMaxTimes(e) => ErrorCode::TxnRetryMaxTimes(e.to_string()),
         }
     }
 }
@@ -93,6 +107,7 @@ impl UdfApiError {
                 meta_err,
                 context: format!("{}; {}", old, context),
             },
+            UdfApiError::TxnRetryMaxTimes(e) => UdfApiError::TxnRetryMaxTimes(e),
         }
     }
 }
@@ -18,3 +18,4 @@ mod udf_mgr;
 pub use errors::UdfApiError;
 pub use errors::UdfError;
 pub use udf_mgr::UdfMgr;
+pub use udf_mgr::DEFAULT_MAX_UDF_VERSIONS;
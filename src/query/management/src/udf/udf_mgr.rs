@@ -12,30 +12,48 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_functions::is_builtin_function;
 use databend_common_meta_api::kv_pb_api::KVPbApi;
-use databend_common_meta_api::kv_pb_api::UpsertPB;
+use databend_common_meta_api::reply::txn_reply_to_api_result;
+use databend_common_meta_api::serialize_struct;
+use databend_common_meta_api::txn_cond_seq;
+use databend_common_meta_api::txn_op_del;
+use databend_common_meta_api::txn_op_put;
+use databend_common_meta_app::app_error::TxnRetryMaxTimes;
+use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UdfName;
+use databend_common_meta_app::principal::UdfVersionIdent;
 use databend_common_meta_app::principal::UserDefinedFunction;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_kvapi::kvapi;
 use databend_common_meta_kvapi::kvapi::DirName;
+use databend_common_meta_kvapi::kvapi::NonEmptyItem;
+use databend_common_meta_types::ConditionResult::Eq;
 use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::NonEmptyStr;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_meta_types::SeqV;
-use databend_common_meta_types::With;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
 use futures::stream::TryStreamExt;
 
 use crate::udf::UdfApiError;
 use crate::udf::UdfError;
 
+const TXN_MAX_RETRY_TIMES: u32 = 10;
+
+/// The number of previous versions of a UDF kept by default.
+pub const DEFAULT_MAX_UDF_VERSIONS: usize = 10;
+
 pub struct UdfMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
     tenant: NonEmptyString,
+    max_versions: usize,
 }
 
 impl UdfMgr {
@@ -43,10 +61,21 @@ impl UdfMgr {
         UdfMgr {
             kv_api,
             tenant: tenant.into(),
+            max_versions: DEFAULT_MAX_UDF_VERSIONS,
         }
     }
 
+    /// Set the number of previous versions kept for each UDF, the oldest ones are removed.
+    pub fn with_max_versions(mut self, max_versions: usize) -> Self {
+        self.max_versions = max_versions;
+        self
+    }
+
     /// Add a UDF to /tenant/udf-name.
+    ///
+    /// The previous versions left under the name, e.g. the ones of the UDF replaced by
+    /// `CreateOrReplace`, are removed in the same transaction: they do not belong to the
+    /// added UDF. The transaction is retried if the UDF is changed after it is read.
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn add_udf(
@@ -54,31 +83,62 @@ impl UdfMgr {
         info: UserDefinedFunction,
         create_option: &CreateOption,
     ) -> Result<Result<(), UdfError>, UdfApiError> {
-        if let Err(e) = self.ensure_non_builtin(info.name.as_str()) {
+        if let Err(e) = self.check_udf(&info) {
             return Ok(Err(e));
         }
 
-        let seq = MatchSeq::from(*create_option);
-
         let key = UdfName::new(self.tenant.as_str(), &info.name);
-        let req = UpsertPB::insert(key, info.clone()).with(seq);
-        let res = self.kv_api.upsert_pb(&req).await?;
+        for _ in 0..TXN_MAX_RETRY_TIMES {
+            let prev = self.kv_api.get_pb(&key).await?;
+            let prev_seq = match (prev, create_option) {
+                (Some(_), CreateOption::CreateIfNotExists(true)) => return Ok(Ok(())),
+                (Some(_), CreateOption::CreateIfNotExists(false)) => {
+                    return Ok(Err(UdfError::Exists {
+                        tenant: self.tenant.to_string(),
+                        name: info.name.to_string(),
+                        reason: "".to_string(),
+                    }));
+                }
+                (Some(prev), CreateOption::CreateOrReplace) => prev.seq,
+                (None, _) => 0,
+            };
 
-        if let CreateOption::CreateIfNotExists(false) = create_option {
-            if res.prev.is_some() {
-                let err = UdfError::Exists {
-                    tenant: self.tenant.to_string(),
-                    name: info.name.to_string(),
-                    reason: "".to_string(),
-                };
-                return Ok(Err(err));
+            let mut if_then = vec![txn_op_put(
+                &key,
+                serialize_struct(&info).map_err(MetaError::from)?,
+            )];
+            if_then.extend(self.del_versions_ops(&info.name).await?);
+            let txn_req = TxnRequest {
+                // the UDF to replace, if any, is not changed
+                condition: vec![txn_cond_seq(&key, Eq, prev_seq)],
+                if_then,
+                else_then: vec![],
+            };
+            let reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(reply).map_err(MetaError::from)?;
+
+            if succ {
+                return Ok(Ok(()));
             }
         }
 
-        Ok(Ok(()))
+        Err(UdfApiError::TxnRetryMaxTimes(TxnRetryMaxTimes::new(
+            "add_udf",
+            TXN_MAX_RETRY_TIMES,
+        )))
+    }
+
+    /// Check if a UDF can be added: its name is not a builtin function and its signature is valid.
+    pub fn check_udf(&self, info: &UserDefinedFunction) -> Result<(), UdfError> {
+        self.ensure_non_builtin(info.name.as_str())?;
+        self.ensure_valid_signature(info)
     }
 
-    /// Update a UDF to /tenant/udf-name.
+    /// Update a UDF to /tenant/udf-name, return the seq of the updated UDF.
+    ///
+    /// The replaced definition is kept as a previous version in the same transaction, see
+    /// [`Self::get_udf_at_version`]. The transaction is retried if the UDF is changed after
+    /// it is read.
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn update_udf(
@@ -90,20 +150,153 @@ impl UdfMgr {
             return Ok(Err(e));
         }
 
+        if let Err(e) = self.ensure_valid_signature(&info) {
+            return Ok(Err(e));
+        }
+
         let key = UdfName::new(self.tenant.as_str(), &info.name);
-        let req = UpsertPB::update(key, info.clone()).with(seq);
-        let res = self.kv_api.upsert_pb(&req).await?;
+        for _ in 0..TXN_MAX_RETRY_TIMES {
+            let prev = self.kv_api.get_pb(&key).await?;
+            let Some(prev) = prev.filter(|v| seq.match_seq(v).is_ok()) else {
+                return Ok(Err(UdfError::NotFound {
+                    tenant: self.tenant.to_string(),
+                    name: info.name.to_string(),
+                    context: "while update udf".to_string(),
+                }));
+            };
 
-        let res = if res.is_changed() {
-            Ok(res.result.unwrap().seq)
-        } else {
-            Err(UdfError::NotFound {
-                tenant: self.tenant.to_string(),
-                name: info.name.to_string(),
-                context: "while update udf".to_string(),
-            })
-        };
-        Ok(res)
+            let mut if_then = vec![txn_op_put(
+                &key,
+                serialize_struct(&info).map_err(MetaError::from)?,
+            )];
+            if_then.extend(self.add_version_ops(&prev).await?);
+            let txn_req = TxnRequest {
+                // the UDF is not changed, nor are its previous versions
+                condition: vec![txn_cond_seq(&key, Eq, prev.seq)],
+                if_then,
+                else_then: vec![],
+            };
+            let reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(reply).map_err(MetaError::from)?;
+
+            if succ {
+                // The reply of a transaction does not contain the seq of the records put.
+                let updated = self.kv_api.get_pb(&key).await?;
+                return Ok(Ok(updated.map_or(0, |v| v.seq)));
+            }
+        }
+
+        Err(UdfApiError::TxnRetryMaxTimes(TxnRetryMaxTimes::new(
+            "update_udf",
+            TXN_MAX_RETRY_TIMES,
+        )))
+    }
+
+    /// Rename a UDF from /tenant/old-name to /tenant/new-name, return the seq of the renamed UDF.
+    ///
+    /// The UDF is moved with its previous versions in a transaction, and the UDF as it was
+    /// before the rename is kept as a previous version under the new name. The transaction is
+    /// retried if the UDF, or the one named `new_name`, is changed after it is read.
+    ///
+    /// If there is already a UDF named `new_name`, it is replaced with its previous versions
+    /// removed if `or_replace`, otherwise an `Exists` error is returned.
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    pub async fn rename_udf(
+        &self,
+        old_name: &str,
+        new_name: &str,
+        or_replace: bool,
+        seq: MatchSeq,
+    ) -> Result<Result<u64, UdfError>, UdfApiError> {
+        if let Err(e) = self.ensure_non_builtin(new_name) {
+            return Ok(Err(e));
+        }
+
+        let old_key = UdfName::new(self.tenant.as_str(), old_name);
+        let new_key = UdfName::new(self.tenant.as_str(), new_name);
+        for _ in 0..TXN_MAX_RETRY_TIMES {
+            let old = self.kv_api.get_pb(&old_key).await?;
+            let Some(SeqV {
+                seq: old_seq,
+                data: mut udf,
+                ..
+            }) = old.filter(|v| seq.match_seq(v).is_ok())
+            else {
+                return Ok(Err(UdfError::NotFound {
+                    tenant: self.tenant.to_string(),
+                    name: old_name.to_string(),
+                    context: "while rename udf".to_string(),
+                }));
+            };
+            if old_name == new_name {
+                return Ok(Ok(old_seq));
+            }
+
+            let new_seq = match self.kv_api.get_pb(&new_key).await? {
+                Some(_) if !or_replace => {
+                    return Ok(Err(UdfError::Exists {
+                        tenant: self.tenant.to_string(),
+                        name: new_name.to_string(),
+                        reason: "".to_string(),
+                    }));
+                }
+                Some(existing) => existing.seq,
+                None => 0,
+            };
+
+            udf.name = new_name.to_string();
+            let mut if_then = vec![
+                txn_op_del(&old_key),
+                txn_op_put(&new_key, serialize_struct(&udf).map_err(MetaError::from)?),
+            ];
+            // The versions of the replaced UDF are removed, as `drop_udf` does.
+            if new_seq != 0 {
+                if_then.extend(self.del_versions_ops(new_name).await?);
+            }
+            // The UDF before the rename becomes the latest previous version, the oldest ones
+            // are removed if there are more than `max_versions`.
+            let versions = self.list_versions(old_name).await?;
+            if_then.extend(versions.iter().map(|v| txn_op_del(&v.key)));
+            let num_removed = (versions.len() + 1).saturating_sub(self.max_versions);
+            let moved = versions
+                .into_iter()
+                .map(|v| (v.key.seq, v.seqv.data))
+                .chain(std::iter::once((old_seq, udf.clone())))
+                .skip(num_removed);
+            for (seq, mut data) in moved {
+                data.name = new_name.to_string();
+                let key = UdfVersionIdent::new(self.tenant.as_str(), new_name, seq);
+                if_then.push(txn_op_put(
+                    &key,
+                    serialize_struct(&data).map_err(MetaError::from)?,
+                ));
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // the UDF is not changed, nor are its previous versions
+                    txn_cond_seq(&old_key, Eq, old_seq),
+                    // the UDF to replace, if any, is not changed
+                    txn_cond_seq(&new_key, Eq, new_seq),
+                ],
+                if_then,
+                else_then: vec![],
+            };
+            let reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(reply).map_err(MetaError::from)?;
+
+            if succ {
+                // The reply of a transaction does not contain the seq of the records put.
+                let renamed = self.kv_api.get_pb(&new_key).await?;
+                return Ok(Ok(renamed.map_or(0, |v| v.seq)));
+            }
+        }
+
+        Err(UdfApiError::TxnRetryMaxTimes(TxnRetryMaxTimes::new(
+            "rename_udf",
+            TXN_MAX_RETRY_TIMES,
+        )))
     }
 
     /// Get UDF by name.
@@ -118,6 +311,26 @@ impl UdfMgr {
         Ok(res)
     }
 
+    /// Get the UDF as it was when its seq was `seq`, it's either the current UDF or one of the
+    /// previous versions kept by `update_udf`.
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    pub async fn get_udf_at_version(
+        &self,
+        udf_name: &str,
+        seq: u64,
+    ) -> Result<Option<UserDefinedFunction>, MetaError> {
+        if let Some(current) = self.get_udf(udf_name).await? {
+            if current.seq == seq {
+                return Ok(Some(current.data));
+            }
+        }
+
+        let key = UdfVersionIdent::new(self.tenant.as_str(), udf_name, seq);
+        let res = self.kv_api.get_pb(&key).await?;
+        Ok(res.map(|v| v.data))
+    }
+
     /// Get all the UDFs for a tenant.
     #[async_backtrace::framed]
     #[minitrace::trace]
@@ -134,23 +347,94 @@ impl UdfMgr {
         Ok(udfs)
     }
 
-    /// Drop the tenant's UDF by name, return the dropped one or None if nothing is dropped.
+    /// Drop the tenant's UDF by name with its previous versions in a transaction,
+    /// return the dropped one or None if nothing is dropped.
+    ///
+    /// The transaction is retried if the UDF is changed after it is read.
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn drop_udf(
         &self,
         udf_name: &str,
         seq: MatchSeq,
-    ) -> Result<Option<SeqV<UserDefinedFunction>>, MetaError> {
+    ) -> Result<Option<SeqV<UserDefinedFunction>>, UdfApiError> {
         let key = UdfName::new(self.tenant.as_str(), udf_name);
-        let req = UpsertPB::delete(key).with(seq);
-        let res = self.kv_api.upsert_pb(&req).await?;
+        for _ in 0..TXN_MAX_RETRY_TIMES {
+            let prev = self.kv_api.get_pb(&key).await?;
+            let Some(prev) = prev.filter(|v| seq.match_seq(v).is_ok()) else {
+                return Ok(None);
+            };
 
-        if res.is_changed() {
-            Ok(res.prev)
-        } else {
-            Ok(None)
+            let mut if_then = vec![txn_op_del(&key)];
+            if_then.extend(self.del_versions_ops(udf_name).await?);
+            let txn_req = TxnRequest {
+                // the UDF is not changed, nor are its previous versions
+                condition: vec![txn_cond_seq(&key, Eq, prev.seq)],
+                if_then,
+                else_then: vec![],
+            };
+            let reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(reply).map_err(MetaError::from)?;
+
+            if succ {
+                return Ok(Some(prev));
+            }
         }
+
+        Err(UdfApiError::TxnRetryMaxTimes(TxnRetryMaxTimes::new(
+            "drop_udf",
+            TXN_MAX_RETRY_TIMES,
+        )))
+    }
+
+    /// The ops to keep a replaced UDF as a previous version keyed by its seq, and to remove the
+    /// oldest versions, keeping at most `max_versions` of them.
+    async fn add_version_ops(
+        &self,
+        prev: &SeqV<UserDefinedFunction>,
+    ) -> Result<Vec<TxnOp>, MetaError> {
+        if self.max_versions == 0 {
+            return Ok(vec![]);
+        }
+
+        let key = UdfVersionIdent::new(self.tenant.as_str(), &prev.data.name, prev.seq);
+        let mut ops = vec![txn_op_put(
+            &key,
+            serialize_struct(&prev.data).map_err(MetaError::from)?,
+        )];
+        let versions = self.list_versions(&prev.data.name).await?;
+        let num_removed = (versions.len() + 1).saturating_sub(self.max_versions);
+        ops.extend(
+            versions
+                .iter()
+                .take(num_removed)
+                .map(|v| txn_op_del(&v.key)),
+        );
+        Ok(ops)
+    }
+
+    /// The ops to remove all the previous versions of a UDF.
+    async fn del_versions_ops(&self, udf_name: &str) -> Result<Vec<TxnOp>, MetaError> {
+        let versions = self.list_versions(udf_name).await?;
+        Ok(versions.iter().map(|v| txn_op_del(&v.key)).collect())
+    }
+
+    /// List the previous versions of a UDF, the oldest first.
+    async fn list_versions(
+        &self,
+        udf_name: &str,
+    ) -> Result<Vec<NonEmptyItem<UdfVersionIdent>>, MetaError> {
+        let dir = DirName::new(UdfVersionIdent::new(self.tenant.as_str(), udf_name, 0));
+        let strm = self.kv_api.list_pb(&dir).await?;
+        let mut versions = strm
+            .try_filter(|item| {
+                // The dir also matches the versions of the UDFs prefixed by `udf_name`.
+                futures::future::ready(item.key.name == udf_name)
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+        versions.sort_by_key(|item| item.key.seq);
+        Ok(versions)
     }
 
     fn ensure_non_builtin(&self, name: &str) -> Result<(), UdfError> {
@@ -163,4 +447,66 @@ impl UdfMgr {
         }
         Ok(())
     }
+
+    /// Check the signature of a UDF before it is stored, instead of failing when it is called.
+    fn ensure_valid_signature(&self, info: &UserDefinedFunction) -> Result<(), UdfError> {
+        let reason = match &info.definition {
+            UDFDefinition::LambdaUDF(lambda) => {
+                let mut names = HashSet::with_capacity(lambda.parameters.len());
+                let max_position = max_positional_parameter(&lambda.definition);
+                if let Some(name) = lambda.parameters.iter().find(|p| !names.insert(*p)) {
+                    Some(format!("duplicate parameter '{}'", name))
+                } else if max_position > lambda.parameters.len() {
+                    Some(format!(
+                        "the definition references ${}, but only {} parameters are declared",
+                        max_position,
+                        lambda.parameters.len()
+                    ))
+                } else {
+                    None
+                }
+            }
+            UDFDefinition::UDFServer(server) => server
+                .arg_types
+                .iter()
+                .chain(std::iter::once(&server.return_type))
+                .find(|ty| ty.has_generic())
+                .map(|ty| format!("unresolved data type '{}'", ty)),
+        };
+
+        match reason {
+            Some(reason) => Err(UdfError::InvalidSignature {
+                tenant: self.tenant.to_string(),
+                name: info.name.to_string(),
+                reason,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The largest `n` of the positional parameters `$n` referenced in the definition of a lambda
+/// UDF, the string literals are skipped.
+fn max_positional_parameter(definition: &str) -> usize {
+    let mut max_position = 0;
+    let mut in_string = false;
+    let mut chars = definition.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '\'' => in_string = !in_string,
+            '$' if !in_string => {
+                let mut position = 0usize;
+                while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                    position = position.saturating_mul(10).saturating_add(digit as usize);
+                    chars.next();
+                }
+                max_position = max_position.max(position);
+            }
+            _ => {}
+        }
+    }
+    max_position
 }
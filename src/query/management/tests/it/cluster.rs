@@ -43,7 +43,7 @@ async fn test_successfully_add_node() -> Result<()> {
             data: value,
         }) => {
             assert!(meta.unwrap().get_expire_at_ms().unwrap() - now_ms >= 59_000);
-            assert_eq!(value, serde_json::to_vec(&node_info)?);
+            assert_eq!(serde_json::from_slice::<NodeInfo>(&value)?, node_info);
         }
         catch => panic!("GetKVActionReply{:?}", catch),
     }
@@ -81,6 +81,40 @@ async fn test_successfully_get_nodes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_get_nodes_heartbeat() -> Result<()> {
+    let (_, cluster_api) = new_cluster_api().await?;
+
+    let nodes = cluster_api.get_nodes_heartbeat().await?;
+    assert_eq!(nodes, vec![]);
+
+    let now_ms = SeqV::<()>::now_ms();
+    let node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+
+    let nodes = cluster_api.get_nodes_heartbeat().await?;
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].node, node_info);
+    assert_eq!(nodes[0].seq, 1);
+    assert!(nodes[0].last_heartbeat_ms >= now_ms);
+    assert!(nodes[0].last_heartbeat_ms <= SeqV::<()>::now_ms());
+    // The node heartbeats at most every 2/3 of the lift time.
+    assert_eq!(nodes[0].heartbeat_interval_ms, 40_000);
+
+    // A full missed heartbeat interval and the margin are allowed.
+    let last_heartbeat_ms = nodes[0].last_heartbeat_ms;
+    assert!(nodes[0].is_alive(last_heartbeat_ms + 50_000));
+    assert!(!nodes[0].is_alive(last_heartbeat_ms + 50_001));
+
+    // The heartbeat is recorded in the value of the node.
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    cluster_api.heartbeat(&node_info, MatchSeq::GE(1)).await?;
+    let heartbeat_nodes = cluster_api.get_nodes_heartbeat().await?;
+    assert!(heartbeat_nodes[0].last_heartbeat_ms > last_heartbeat_ms);
+    assert!(heartbeat_nodes[0].seq > nodes[0].seq);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_drop_node() -> Result<()> {
     let (_, cluster_api) = new_cluster_api().await?;
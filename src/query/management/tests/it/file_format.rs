@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NdJsonFileFormatParams;
+use databend_common_meta_app::principal::UserDefinedFileFormat;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_file_format() -> Result<()> {
+    let file_format_api = new_file_format_api().await?;
+
+    let file_format = create_test_file_format();
+    file_format_api
+        .add_file_format(file_format.clone(), &CreateOption::CreateIfNotExists(false))
+        .await?;
+
+    let mut new_file_format = file_format.clone();
+    new_file_format.file_format_params =
+        FileFormatParams::NdJson(NdJsonFileFormatParams::default());
+    file_format_api
+        .update_file_format(new_file_format.clone(), MatchSeq::GE(1))
+        .await?;
+
+    let got = file_format_api
+        .get_file_format("my_format", MatchSeq::GE(0))
+        .await?;
+    assert_eq!(got.data, new_file_format);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unknown_file_format_update() -> Result<()> {
+    let file_format_api = new_file_format_api().await?;
+
+    match file_format_api
+        .update_file_format(create_test_file_format(), MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown file format update must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2507),
+    }
+
+    Ok(())
+}
+
+fn create_test_file_format() -> UserDefinedFileFormat {
+    UserDefinedFileFormat::new(
+        "my_format",
+        FileFormatParams::default(),
+        UserIdentity::new("root", "%"),
+    )
+}
+
+async fn new_file_format_api() -> Result<FileFormatMgr> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    FileFormatMgr::create(test_api, "admin")
+}
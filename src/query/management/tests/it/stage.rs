@@ -218,3 +218,80 @@ async fn test_remove_files() -> Result<()> {
     assert_eq!(new_mystage.number_of_files, 1);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_stage() -> Result<()> {
+    let (_kv_api, stage_api) = new_stage_api().await?;
+    let stage_info = create_test_stage_info();
+    stage_api
+        .add_stage(stage_info.clone(), &CreateOption::CreateIfNotExists(false))
+        .await?;
+    stage_api
+        .add_file("mystage", StageFile {
+            path: "books.csv".to_string(),
+            size: 100,
+            ..Default::default()
+        })
+        .await?;
+
+    // The number of files is not overwritten by the stale stage info.
+    let mut new_stage_info = stage_info.clone();
+    new_stage_info.comment = "altered".to_string();
+    stage_api.update_stage(new_stage_info).await?;
+
+    let mystage = stage_api.get_stage("mystage").await?;
+    assert_eq!(mystage.comment, "altered".to_string());
+    assert_eq!(mystage.number_of_files, 1);
+
+    let mut unknown_stage = stage_info.clone();
+    unknown_stage.stage_name = "UNKNOWN_ID".to_string();
+    match stage_api.update_stage(unknown_stage).await {
+        Ok(_) => panic!("Unknown stage update stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rename_stage() -> Result<()> {
+    let (_kv_api, stage_api) = new_stage_api().await?;
+    let stage_info = create_test_stage_info();
+    stage_api
+        .add_stage(stage_info.clone(), &CreateOption::CreateIfNotExists(false))
+        .await?;
+    stage_api
+        .add_file("mystage", StageFile {
+            path: "books.csv".to_string(),
+            size: 100,
+            ..Default::default()
+        })
+        .await?;
+
+    stage_api.rename_stage("mystage", "newstage").await?;
+
+    let stages = stage_api.get_stages().await?;
+    assert_eq!(stages.len(), 1);
+    assert_eq!(stages[0].stage_name, "newstage".to_string());
+    assert_eq!(stages[0].stage_params, stage_info.stage_params);
+
+    let files = stage_api.list_files("newstage").await?;
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "books.csv".to_string());
+    assert!(stage_api.list_files("mystage").await?.is_empty());
+
+    match stage_api.rename_stage("mystage", "newstage2").await {
+        Ok(_) => panic!("Unknown stage rename stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2501),
+    }
+
+    stage_api
+        .add_stage(stage_info.clone(), &CreateOption::CreateIfNotExists(false))
+        .await?;
+    match stage_api.rename_stage("mystage", "newstage").await {
+        Ok(_) => panic!("Rename stage to an existing stage must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2502),
+    }
+
+    Ok(())
+}
@@ -19,6 +19,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_management::udf::UdfError;
 use databend_common_management::udf::UdfMgr;
 use databend_common_management::*;
 use databend_common_meta_app::principal::UserDefinedFunction;
@@ -118,6 +119,61 @@ async fn test_already_exists_add_udf() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_invalid_signature_add_udf() -> Result<()> {
+    let (_, udf_api) = new_udf_api().await?;
+
+    let udf = UserDefinedFunction::create_lambda_udf(
+        "add",
+        vec!["a".to_string(), "a".to_string()],
+        "a + a",
+        "",
+    );
+    let err = udf_api
+        .add_udf(udf, &CreateOption::CreateIfNotExists(false))
+        .await?
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "UDF has an invalid signature: 'add'; duplicate parameter 'a'"
+    );
+
+    // `$3` in the string literal is not a parameter.
+    let udf = UserDefinedFunction::create_lambda_udf(
+        "add",
+        vec!["a".to_string(), "b".to_string()],
+        "concat($1 + $3, '$3')",
+        "",
+    );
+    let res = udf_api
+        .add_udf(udf.clone(), &CreateOption::CreateIfNotExists(false))
+        .await?;
+    assert!(matches!(res, Err(UdfError::InvalidSignature { .. })));
+    let udf = UserDefinedFunction::create_lambda_udf(
+        "add",
+        vec!["a".to_string(), "b".to_string()],
+        "concat($1 + $2, '$3')",
+        "",
+    );
+    udf_api
+        .add_udf(udf.clone(), &CreateOption::CreateIfNotExists(false))
+        .await??;
+
+    let udf = UserDefinedFunction::create_udf_server(
+        "add",
+        "http://localhost:8888",
+        "add_py",
+        "python",
+        vec![DataType::Generic(0)],
+        DataType::Number(NumberDataType::Int64),
+        "",
+    );
+    let res = udf_api.update_udf(udf, MatchSeq::GE(1)).await?;
+    assert!(matches!(res, Err(UdfError::InvalidSignature { .. })));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_get_udfs() -> Result<()> {
     let (_, udf_api) = new_udf_api().await?;
@@ -171,8 +227,184 @@ async fn test_successfully_drop_udf() -> Result<()> {
 async fn test_unknown_udf_drop_udf() -> Result<()> {
     let (_, udf_api) = new_udf_api().await?;
 
-    let res = udf_api.drop_udf("UNKNOWN_NAME", MatchSeq::GE(1)).await;
-    assert_eq!(Ok(None), res);
+    let res = udf_api.drop_udf("UNKNOWN_NAME", MatchSeq::GE(1)).await?;
+    assert_eq!(None, res);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rename_udf() -> Result<()> {
+    let (_, udf_api) = new_udf_api().await?;
+
+    let lambda_udf = create_test_lambda_udf();
+    let udf_server = create_test_udf_server();
+    udf_api
+        .add_udf(lambda_udf.clone(), &CreateOption::CreateIfNotExists(false))
+        .await??;
+    udf_api
+        .add_udf(udf_server.clone(), &CreateOption::CreateIfNotExists(false))
+        .await??;
+
+    // rename to a new name.
+    let seq = udf_api
+        .rename_udf(&lambda_udf.name, "notempty", false, MatchSeq::GE(1))
+        .await??;
+    assert!(udf_api.get_udf(&lambda_udf.name).await?.is_none());
+    let renamed = udf_api.get_udf("notempty").await?.unwrap();
+    assert_eq!(renamed.seq, seq);
+    assert_eq!(renamed.data.name, "notempty");
+    assert_eq!(renamed.data.definition, lambda_udf.definition);
+
+    // rename to an existing name.
+    let res = udf_api
+        .rename_udf("notempty", &udf_server.name, false, MatchSeq::GE(1))
+        .await?;
+    assert!(matches!(res, Err(UdfError::Exists { .. })));
+    assert!(udf_api.get_udf("notempty").await?.is_some());
+
+    let seq = udf_api
+        .rename_udf("notempty", &udf_server.name, true, MatchSeq::GE(1))
+        .await??;
+    let replaced = udf_api.get_udf(&udf_server.name).await?.unwrap();
+    assert_eq!(replaced.seq, seq);
+    assert_eq!(replaced.data.definition, lambda_udf.definition);
+    assert_eq!(udf_api.list_udf().await?.len(), 1);
+
+    // rename an unknown UDF, or with a seq not matched.
+    let res = udf_api
+        .rename_udf("UNKNOWN_NAME", "notempty", false, MatchSeq::GE(1))
+        .await?;
+    assert!(matches!(res, Err(UdfError::NotFound { .. })));
+    let res = udf_api
+        .rename_udf(
+            &udf_server.name,
+            "notempty",
+            false,
+            MatchSeq::Exact(seq + 1),
+        )
+        .await?;
+    assert!(matches!(res, Err(UdfError::NotFound { .. })));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_udf_versions() -> Result<()> {
+    let (_, udf_api) = new_udf_api().await?;
+    let udf_api = udf_api.with_max_versions(2);
+
+    let udf = create_test_lambda_udf();
+    udf_api
+        .add_udf(udf.clone(), &CreateOption::CreateIfNotExists(false))
+        .await??;
+    let mut seqs = vec![udf_api.get_udf(&udf.name).await?.unwrap().seq];
+    let mut versions = vec![udf.clone()];
+    for definition in ["is_not_null(p)", "p is not null", "not(p is null)"] {
+        let new_udf = UserDefinedFunction::create_lambda_udf(
+            &udf.name,
+            vec!["p".to_string()],
+            definition,
+            "This is a description",
+        );
+        seqs.push(
+            udf_api
+                .update_udf(new_udf.clone(), MatchSeq::GE(1))
+                .await??,
+        );
+        versions.push(new_udf);
+    }
+
+    // The current version and the 2 previous ones are kept.
+    assert_eq!(udf_api.get_udf_at_version(&udf.name, seqs[0]).await?, None);
+    for (seq, version) in seqs.iter().zip(versions.iter()).skip(1) {
+        let got = udf_api.get_udf_at_version(&udf.name, *seq).await?;
+        assert_eq!(got.as_ref(), Some(version));
+    }
+
+    // The previous versions are moved with the UDF, and the UDF before the rename is kept
+    // as the latest one.
+    let new_name = format!("{}_renamed", udf.name);
+    udf_api
+        .rename_udf(&udf.name, &new_name, false, MatchSeq::GE(1))
+        .await??;
+    for (seq, version) in seqs.iter().zip(versions.iter()).skip(2) {
+        assert_eq!(udf_api.get_udf_at_version(&udf.name, *seq).await?, None);
+        let got = udf_api.get_udf_at_version(&new_name, *seq).await?;
+        let expected = UserDefinedFunction {
+            name: new_name.clone(),
+            ..version.clone()
+        };
+        assert_eq!(got, Some(expected));
+    }
+    assert_eq!(udf_api.get_udf_at_version(&new_name, seqs[1]).await?, None);
+
+    // The previous versions are removed with the UDF.
+    udf_api.drop_udf(&new_name, MatchSeq::GE(1)).await?;
+    for seq in seqs {
+        assert_eq!(udf_api.get_udf_at_version(&new_name, seq).await?, None);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_replace_udf_versions() -> Result<()> {
+    let (_, udf_api) = new_udf_api().await?;
+
+    let udf = create_test_lambda_udf();
+    udf_api
+        .add_udf(udf.clone(), &CreateOption::CreateIfNotExists(false))
+        .await??;
+    let first_seq = udf_api.get_udf(&udf.name).await?.unwrap().seq;
+    let updated = UserDefinedFunction::create_lambda_udf(
+        &udf.name,
+        vec!["p".to_string()],
+        "p is not null",
+        "This is a description",
+    );
+    let updated_seq = udf_api
+        .update_udf(updated.clone(), MatchSeq::GE(1))
+        .await??;
+    assert_eq!(
+        udf_api.get_udf_at_version(&udf.name, first_seq).await?,
+        Some(udf.clone())
+    );
+
+    // The versions of the replaced UDF are removed.
+    let replaced = UserDefinedFunction::create_lambda_udf(
+        &udf.name,
+        vec!["a".to_string(), "b".to_string()],
+        "a = b",
+        "This is another description",
+    );
+    udf_api
+        .add_udf(replaced.clone(), &CreateOption::CreateOrReplace)
+        .await??;
+    assert_eq!(
+        udf_api.get_udf_at_version(&udf.name, first_seq).await?,
+        None
+    );
+    assert_eq!(
+        udf_api.get_udf_at_version(&udf.name, updated_seq).await?,
+        None
+    );
+    let current = udf_api.get_udf(&udf.name).await?.unwrap();
+    assert_eq!(current.data, replaced);
+    assert_eq!(
+        udf_api.get_udf_at_version(&udf.name, current.seq).await?,
+        Some(replaced)
+    );
+
+    // Adding an existing UDF without OR REPLACE keeps it.
+    let res = udf_api
+        .add_udf(udf.clone(), &CreateOption::CreateIfNotExists(false))
+        .await?;
+    assert!(matches!(res, Err(UdfError::Exists { .. })));
+    udf_api
+        .add_udf(udf.clone(), &CreateOption::CreateIfNotExists(true))
+        .await??;
+    assert_eq!(udf_api.get_udf(&udf.name).await?.unwrap().seq, current.seq);
 
     Ok(())
 }
@@ -24,6 +24,7 @@ mod lock_guard;
 mod pipe;
 mod pipeline;
 mod pipeline_display;
+mod pipeline_renderer;
 mod unsafe_cell_wrap;
 
 pub use input_error::InputError;
@@ -36,6 +37,7 @@ pub use pipe::SourcePipeBuilder;
 pub use pipe::TransformPipeBuilder;
 pub use pipeline::query_spill_prefix;
 pub use pipeline::Pipeline;
+pub use pipeline_renderer::PipelineRenderer;
 pub use processors::get_statistics_desc;
 pub use processors::PlanScope;
 pub use processors::PlanScopeGuard;
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::pipe::Pipe;
+use crate::Pipeline;
+
+/// Renders the processors of pipelines for `DESCRIBE PIPELINE FOR <query>`.
+///
+/// Each pipe is rendered as the name of its processors and the number of processors
+/// running in parallel, from the source to the sink of the pipeline:
+///
+/// ```text
+/// [SyncReadParquetDataSource x4] --> [DeserializeDataTransform x4] --> [TransformFilter x4 ~100 rows]
+/// ```
+///
+/// The resize pipes are not rendered, the parallelism of the pipes around them shows how
+/// the data is merged or split.
+pub struct PipelineRenderer<'a> {
+    pipelines: Vec<&'a Pipeline>,
+    estimated_rows: HashMap<u32, f64>,
+}
+
+impl<'a> PipelineRenderer<'a> {
+    /// The first pipeline is the main pipeline, the others are the pipelines feeding it.
+    pub fn create(pipelines: Vec<&'a Pipeline>) -> PipelineRenderer<'a> {
+        PipelineRenderer {
+            pipelines,
+            estimated_rows: HashMap::new(),
+        }
+    }
+
+    /// Annotate the pipes with the number of rows their plan is estimated to output,
+    /// keyed by the id of the plan.
+    pub fn with_estimated_rows(mut self, estimated_rows: HashMap<u32, f64>) -> Self {
+        self.estimated_rows = estimated_rows;
+        self
+    }
+
+    /// Render each pipeline as a line of ASCII art.
+    pub fn render_text(&self) -> Vec<String> {
+        self.pipelines
+            .iter()
+            .map(|pipeline| {
+                self.nodes(pipeline)
+                    .iter()
+                    .map(|node| format!("[{node}]"))
+                    .collect::<Vec<_>>()
+                    .join(" --> ")
+            })
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    /// Render the pipelines as a Graphviz DOT graph, with a cluster for each pipeline.
+    pub fn render_dot(&self) -> String {
+        let mut dot = String::from("digraph pipeline {\n    rankdir = LR;\n");
+        for (pipeline_index, pipeline) in self.pipelines.iter().enumerate() {
+            let nodes = self.nodes(pipeline);
+            if nodes.is_empty() {
+                continue;
+            }
+
+            let _ = writeln!(dot, "    subgraph cluster_{pipeline_index} {{");
+            let label = if pipeline_index == 0 {
+                "main pipeline".to_string()
+            } else {
+                format!("source pipeline {pipeline_index}")
+            };
+            let _ = writeln!(dot, "        label = \"{label}\";");
+            for (node_index, node) in nodes.iter().enumerate() {
+                let _ = writeln!(
+                    dot,
+                    "        p{pipeline_index}_{node_index} [shape = box, label = \"{}\"];",
+                    node.replace('"', "\\\"")
+                );
+            }
+            for node_index in 1..nodes.len() {
+                let _ = writeln!(
+                    dot,
+                    "        p{pipeline_index}_{} -> p{pipeline_index}_{node_index};",
+                    node_index - 1
+                );
+            }
+            let _ = writeln!(dot, "    }}");
+        }
+        dot.push('}');
+        dot
+    }
+
+    fn nodes(&self, pipeline: &Pipeline) -> Vec<String> {
+        pipeline
+            .pipes
+            .iter()
+            .filter(|pipe| !Self::is_resize(pipe))
+            .map(|pipe| {
+                let mut node = format!(
+                    "{} x{}",
+                    unsafe { pipe.items[0].processor.name() },
+                    pipe.items.len()
+                );
+                let estimated_rows = pipe
+                    .scope
+                    .as_ref()
+                    .and_then(|scope| self.estimated_rows.get(&scope.id));
+                if let Some(estimated_rows) = estimated_rows {
+                    let _ = write!(node, " ~{estimated_rows:.0} rows");
+                }
+                node
+            })
+            .collect()
+    }
+
+    fn is_resize(pipe: &Pipe) -> bool {
+        pipe.input_length != pipe.output_length && pipe.input_length != 0 && pipe.output_length != 0
+    }
+}
@@ -16,6 +16,8 @@ use std::sync::atomic::AtomicPtr;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageRegistry;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 
@@ -34,6 +36,16 @@ const UNSET_FLAGS_MASK: usize = !FLAGS_MASK;
 #[repr(align(8))]
 pub struct SharedData(pub Result<DataBlock>);
 
+/// Reports the bytes of a data block pushed to or pulled from a port.
+#[inline(always)]
+fn record_buffered_data(data: &Result<DataBlock>, pushed: bool) {
+    if let Ok(data_block) = data {
+        let bytes = data_block.memory_size() as i64;
+        let bytes = if pushed { bytes } else { -bytes };
+        MemoryUsageRegistry::instance().record(MemoryComponent::PipelineBuffers, bytes, bytes);
+    }
+}
+
 pub struct SharedStatus {
     data: AtomicPtr<SharedData>,
 }
@@ -46,7 +58,8 @@ impl Drop for SharedStatus {
             let address = self.swap(std::ptr::null_mut(), 0, HAS_DATA);
 
             if !address.is_null() {
-                drop(Box::from_raw(address));
+                let data = Box::from_raw(address);
+                record_buffered_data(&data.0, false);
             }
         }
     }
@@ -187,7 +200,11 @@ impl InputPort {
             let unset_flags = HAS_DATA | NEED_DATA;
             match self.shared.swap(std::ptr::null_mut(), 0, unset_flags) {
                 address if address.is_null() => None,
-                address => Some((*Box::from_raw(address)).0),
+                address => {
+                    let data = (*Box::from_raw(address)).0;
+                    record_buffered_data(&data, false);
+                    Some(data)
+                }
             }
         }
     }
@@ -240,6 +257,7 @@ impl OutputPort {
                 }
             }
 
+            record_buffered_data(&data, true);
             let data = Box::into_raw(Box::new(SharedData(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
         }
@@ -213,6 +213,9 @@ impl InputFormatTextBase for InputFormatNDJson {
                 } else {
                     builder.num_rows += 1;
                     builder.file_status.num_rows_loaded += 1;
+                    builder
+                        .metadata_columns
+                        .append_row(&batch.split_info, batch.start_row_in_split + i);
                 }
             }
             start = *end;
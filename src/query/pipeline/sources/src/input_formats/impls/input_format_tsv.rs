@@ -244,6 +244,9 @@ impl InputFormatTextBase for InputFormatTSV {
             } else {
                 builder.num_rows += 1;
                 builder.file_status.num_rows_loaded += 1;
+                builder
+                    .metadata_columns
+                    .append_row(&batch.split_info, i + batch.start_row_in_split);
             }
             start = *end;
         }
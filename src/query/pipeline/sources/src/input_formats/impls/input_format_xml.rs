@@ -254,6 +254,10 @@ impl InputFormatTextBase for InputFormatXML {
                             } else {
                                 builder.num_rows += 1;
                                 builder.file_status.num_rows_loaded += 1;
+                                builder.metadata_columns.append_row(
+                                    &batch.split_info,
+                                    num_rows + batch.start_row_in_split,
+                                );
                             }
                             num_rows += 1;
                             cols.clear();
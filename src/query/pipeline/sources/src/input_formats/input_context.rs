@@ -24,6 +24,7 @@ use std::sync::Mutex;
 use dashmap::DashMap;
 use databend_common_base::base::tokio::sync::mpsc::Receiver;
 use databend_common_base::base::Progress;
+use databend_common_catalog::plan::StageMetadataColumn;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_compress::CompressAlgorithm;
 use databend_common_exception::ErrorCode;
@@ -134,6 +135,8 @@ pub struct InputContext {
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
     pub projection: Option<Vec<usize>>,
+    /// The metadata columns appended to the columns of `schema`.
+    pub metadata_columns: Vec<StageMetadataColumn>,
 }
 
 impl InputContext {}
@@ -178,6 +181,7 @@ impl InputContext {
         is_select: bool,
         projection: Option<Vec<usize>>,
         default_values: Option<Vec<Scalar>>,
+        metadata_columns: Vec<StageMetadataColumn>,
     ) -> Result<Self> {
         let mut file_format_options_ext =
             FileFormatOptionsExt::create_from_settings(&settings, is_select)?;
@@ -208,6 +212,7 @@ impl InputContext {
             on_error_map: Some(on_error_map),
             projection,
             default_values,
+            metadata_columns,
         })
     }
 
@@ -265,6 +270,7 @@ impl InputContext {
             on_error_map: None,
             projection: None,
             default_values: None,
+            metadata_columns: vec![],
         })
     }
 
@@ -308,6 +314,7 @@ impl InputContext {
             on_error_map: None,
             projection: None,
             default_values: None,
+            metadata_columns: vec![],
         })
     }
 
@@ -349,11 +356,8 @@ impl InputContext {
                     "compress type lzo is unimplemented",
                 ));
             }
-            StageFileCompression::Snappy => {
-                return Err(ErrorCode::Unimplemented(
-                    "compress type snappy is unimplemented",
-                ));
-            }
+            StageFileCompression::Snappy => Some(CompressAlgorithm::Snappy),
+            StageFileCompression::Lz4 => Some(CompressAlgorithm::Lz4),
             StageFileCompression::None => None,
         };
         Ok(compression_algo)
@@ -16,15 +16,18 @@ use std::marker::PhantomData;
 use std::mem;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::StageMetadataColumn;
 use databend_common_compress::DecompressDecoder;
 use databend_common_compress::DecompressState;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
 use databend_common_formats::FieldDecoder;
 use databend_common_formats::FileFormatOptionsExt;
 use databend_common_meta_app::principal::FileFormatParams;
@@ -388,6 +391,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
         for info in file_infos {
             let size = info.size as usize;
             let path = info.path.clone();
+            let content_key = info.etag.clone().or_else(|| info.md5.clone());
 
             let compress_alg = InputContext::get_compression_alg_copy(
                 stage_info.file_format_params.compression(),
@@ -410,6 +414,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
                     size,
                     num_splits: split_offsets.len(),
                     compress_alg,
+                    content_key,
                 });
                 for (i, (offset, size)) in split_offsets.into_iter().enumerate() {
                     infos.push(Arc::new(SplitInfo {
@@ -427,6 +432,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
                     size, // dummy
                     num_splits: 1,
                     compress_alg,
+                    content_key,
                 });
                 infos.push(Arc::new(SplitInfo {
                     file,
@@ -551,10 +557,64 @@ impl<T: InputFormatTextBase> AligningStateTrait for AligningStateMaybeCompressed
     }
 }
 
+/// Builds the metadata columns of the rows loaded from the files.
+pub struct MetadataColumnsBuilder {
+    columns: Vec<(StageMetadataColumn, ColumnBuilder)>,
+}
+
+impl MetadataColumnsBuilder {
+    fn create(metadata_columns: &[StageMetadataColumn]) -> Self {
+        let columns = metadata_columns
+            .iter()
+            .map(|c| {
+                let builder =
+                    ColumnBuilder::with_capacity_hint(&(&c.data_type()).into(), 1024, false);
+                (*c, builder)
+            })
+            .collect();
+        Self { columns }
+    }
+
+    /// Appends the metadata of a row loaded from the split, `row` is the row in the split
+    /// starting from 0.
+    pub fn append_row(&mut self, split_info: &SplitInfo, row: usize) {
+        for (column, builder) in self.columns.iter_mut() {
+            match column {
+                StageMetadataColumn::FileName => {
+                    builder.push(ScalarRef::String(&split_info.file.path));
+                }
+                StageMetadataColumn::FileRowNumber => {
+                    builder.push(ScalarRef::Number(NumberScalar::UInt64(row as u64 + 1)));
+                }
+                StageMetadataColumn::FileContentKey => match &split_info.file.content_key {
+                    Some(key) => builder.push(ScalarRef::String(key)),
+                    None => builder.push(ScalarRef::Null),
+                },
+            }
+        }
+    }
+
+    fn build(&mut self) -> Vec<Column> {
+        self.columns
+            .iter_mut()
+            .map(|(_, col)| {
+                let empty_builder =
+                    ColumnBuilder::with_capacity_hint(&col.data_type(), 1024, false);
+                std::mem::replace(col, empty_builder).build()
+            })
+            .collect()
+    }
+
+    fn memory_size(&self) -> usize {
+        self.columns.iter().map(|(_, x)| x.memory_size()).sum()
+    }
+}
+
 pub struct BlockBuilder<T> {
     pub field_decoder: Arc<dyn FieldDecoder>,
     pub ctx: Arc<InputContext>,
     pub mutable_columns: Vec<ColumnBuilder>,
+    pub metadata_columns: MetadataColumnsBuilder,
     pub num_rows: usize,
     pub projection: Option<Vec<usize>>,
     pub file_status: FileStatus,
@@ -580,10 +640,12 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         let field_decoder =
             T::create_field_decoder(&ctx.file_format_params, &ctx.file_format_options_ext);
         let projection = ctx.projection.clone();
+        let metadata_columns = MetadataColumnsBuilder::create(&ctx.metadata_columns);
 
         BlockBuilder {
             ident_case_sensitive: ctx.file_format_options_ext.ident_case_sensitive,
             mutable_columns: columns,
+            metadata_columns,
             num_rows: 0,
             field_decoder,
             phantom: PhantomData,
@@ -604,7 +666,7 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
             })
             .collect();
 
-        let columns = if let Some(projection) = &self.projection {
+        let mut columns = if let Some(projection) = &self.projection {
             columns
                 .into_iter()
                 .enumerate()
@@ -626,6 +688,7 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         } else {
             columns
         };
+        columns.extend(self.metadata_columns.build());
         self.num_rows = 0;
 
         if columns.is_empty() || columns[0].len() == 0 {
@@ -636,7 +699,11 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
     }
 
     fn memory_size(&self) -> usize {
-        self.mutable_columns.iter().map(|x| x.memory_size()).sum()
+        self.mutable_columns
+            .iter()
+            .map(|x| x.memory_size())
+            .sum::<usize>()
+            + self.metadata_columns.memory_size()
     }
 }
 
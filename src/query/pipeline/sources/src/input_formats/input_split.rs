@@ -35,6 +35,9 @@ pub struct FileInfo {
     pub size: usize,
     pub num_splits: usize,
     pub compress_alg: Option<CompressAlgorithm>,
+    /// The ETag of the file, or its MD5 if there is no ETag.
+    #[serde(default)]
+    pub content_key: Option<String>,
 }
 
 impl PartialEq for FileInfo {
@@ -137,6 +140,7 @@ impl SplitInfo {
                 size: 0,
                 num_splits: 1,
                 compress_alg,
+                content_key: None,
             }),
             seq_in_file: 0,
             offset: 0,
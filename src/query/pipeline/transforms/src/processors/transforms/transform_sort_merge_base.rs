@@ -15,6 +15,8 @@
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageGuard;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -117,6 +119,9 @@ pub struct TransformSortMergeBase<M, R, Converter> {
     /// The number of spilled blocks in each merge of the spill processor.
     spill_num_merge: usize,
 
+    /// Reports the buffered data size to the memory usage registry.
+    memory_usage: MemoryUsageGuard,
+
     _r: PhantomData<R>,
 }
 
@@ -150,6 +155,7 @@ where
             spill_batch_size: 0,
             spill_num_merge: 0,
             may_spill,
+            memory_usage: MemoryUsageGuard::create(MemoryComponent::SortBuffers),
             _r: PhantomData,
         })
     }
@@ -244,13 +250,18 @@ where
             vec![]
         };
 
+        let num_bytes = self.inner.num_bytes();
+        self.memory_usage.update(num_bytes, num_bytes);
+
         Ok(blocks)
     }
 
     fn on_finish(&mut self, _output: bool) -> Result<Vec<DataBlock>> {
         // If the processor has started to spill blocks,
         // gather the final few data in one block.
-        self.inner.on_finish(self.spill_num_merge > 0)
+        let blocks = self.inner.on_finish(self.spill_num_merge > 0)?;
+        self.memory_usage.update(0, 0);
+        Ok(blocks)
     }
 }
 
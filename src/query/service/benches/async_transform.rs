@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use criterion::Criterion;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::EmptySink;
+use databend_common_pipeline_sources::BlocksSource;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use databend_query::pipelines::PipelineBuildResult;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::execute_pipeline;
+use databend_query::test_kits::TestFixture;
+use parking_lot::Mutex;
+
+/// The number of transforms running in parallel.
+const WORKERS: usize = 64;
+const BLOCKS: usize = 1024;
+/// The latency of the I/O done by the transforms for each block.
+const IO_LATENCY: Duration = Duration::from_millis(1);
+
+/// Waits for the I/O by blocking the thread of the pipeline executor.
+struct BlockingIoTransform;
+
+impl Transform for BlockingIoTransform {
+    const NAME: &'static str = "BlockingIoTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        std::thread::sleep(IO_LATENCY);
+        Ok(data)
+    }
+}
+
+/// Waits for the I/O without blocking the thread of the pipeline executor.
+struct AsyncIoTransform;
+
+#[async_trait::async_trait]
+impl AsyncTransform for AsyncIoTransform {
+    const NAME: &'static str = "AsyncIoTransform";
+
+    async fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        tokio::time::sleep(IO_LATENCY).await;
+        Ok(data)
+    }
+}
+
+fn run_pipeline(ctx: &Arc<QueryContext>, is_async: bool) -> Result<()> {
+    let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1, 2, 3])]);
+    let blocks = Arc::new(Mutex::new(VecDeque::from(vec![block; BLOCKS])));
+
+    let mut build_res = PipelineBuildResult::create();
+    let source_ctx: Arc<dyn TableContext> = ctx.clone();
+    build_res.main_pipeline.add_source(
+        |output| BlocksSource::create(source_ctx.clone(), output, blocks.clone()),
+        WORKERS,
+    )?;
+    build_res.main_pipeline.add_transform(|input, output| {
+        Ok(ProcessorPtr::create(match is_async {
+            true => AsyncTransformer::create(input, output, AsyncIoTransform),
+            false => Transformer::create(input, output, BlockingIoTransform),
+        }))
+    })?;
+    build_res
+        .main_pipeline
+        .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))?;
+    execute_pipeline(ctx.clone(), build_res)
+}
+
+/// Compares the throughput of the blocking and the async transforms doing I/O, with
+/// fewer executor threads than transforms.
+fn bench_io_transform(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let fixture = runtime.block_on(TestFixture::setup()).unwrap();
+    let ctx = runtime.block_on(fixture.new_query_ctx()).unwrap();
+    ctx.get_settings().set_max_threads(8).unwrap();
+
+    let mut group = c.benchmark_group("io_transform");
+    group.throughput(criterion::Throughput::Elements(BLOCKS as u64));
+    group.sample_size(10);
+
+    group.bench_function("blocking", |b| {
+        b.iter(|| run_pipeline(&ctx, false).unwrap())
+    });
+    group.bench_function("async", |b| b.iter(|| run_pipeline(&ctx, true).unwrap()));
+
+    ctx.get_settings()
+        .set_max_concurrent_async_tasks(WORKERS as u64 / 4)
+        .unwrap();
+    group.bench_function("async_bounded", |b| {
+        b.iter(|| run_pipeline(&ctx, true).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_io_transform);
+criterion_main!(benches);
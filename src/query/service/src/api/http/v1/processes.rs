@@ -31,6 +31,7 @@ pub struct ProcessInfo {
     pub client_address: String,
     pub session_extra_info: Option<String>,
     pub memory_usage: i64,
+    pub aggregate_memory_usage: i64,
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
@@ -60,6 +61,7 @@ pub async fn processlist_handler() -> poem::Result<impl IntoResponse> {
                 .unwrap_or("".to_string()),
             session_extra_info: process.session_extra_info.clone(),
             memory_usage: process.memory_usage,
+            aggregate_memory_usage: process.aggregate_memory_usage,
             mysql_connection_id: process.mysql_connection_id,
             created_time: process.created_time,
             status_info: process.status_info.clone(),
@@ -27,6 +27,7 @@ use databend_common_base::runtime::Thread;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_base::GLOBAL_TASK;
 use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
@@ -278,6 +279,36 @@ impl DataExchangeManager {
         }
     }
 
+    /// Kill the queries with fragments running on the dead node, which would wait
+    /// for the exchanges with the node forever otherwise.
+    pub fn on_node_dead(&self, node_id: &str) {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        let mut queries_ctx = vec![];
+        for query_coordinator in queries_coordinator.values() {
+            if let Some(info) = &query_coordinator.info {
+                if info.executors.iter().any(|executor| executor == node_id) {
+                    queries_ctx.push(info.query_ctx.clone());
+                }
+            }
+        }
+
+        // Drop mutex guard to avoid deadlock during kill,
+        drop(queries_coordinator_guard);
+
+        for query_ctx in queries_ctx {
+            // The node is excluded if the query is retried.
+            query_ctx.add_unavailable_node(node_id);
+            query_ctx
+                .get_current_session()
+                .force_kill_query(ErrorCode::CannotConnectNode(format!(
+                    "The node \"{}\" of the cluster is dead",
+                    node_id
+                )));
+        }
+    }
+
     #[minitrace::trace]
     pub fn on_finished_query(&self, query_id: &str) {
         let queries_coordinator_guard = self.queries_coordinator.lock();
@@ -305,19 +336,16 @@ impl DataExchangeManager {
         let conf = GlobalConfig::instance();
 
         // Initialize channels between cluster nodes
-        actions
-            .get_init_nodes_channel_packets()?
-            .commit(conf.as_ref(), timeout)
-            .await?;
+        let packets = actions.get_init_nodes_channel_packets()?;
+        Self::commit_packets(&ctx, &packets, |p| &p.executor.id, &conf, timeout).await?;
 
         // Submit distributed tasks to all nodes.
         let (local_query_fragments_plan_packet, query_fragments_plan_packets) =
             actions.get_query_fragments_plan_packets()?;
 
         // Submit tasks to other nodes
-        query_fragments_plan_packets
-            .commit(conf.as_ref(), timeout)
-            .await?;
+        let packets = &query_fragments_plan_packets;
+        Self::commit_packets(&ctx, packets, |p| &p.executor, &conf, timeout).await?;
 
         // Submit tasks to localhost
         self.init_query_fragments_plan(&ctx, &local_query_fragments_plan_packet)?;
@@ -325,13 +353,32 @@ impl DataExchangeManager {
         // Get local pipeline of local task
         let build_res = self.get_root_pipeline(ctx, root_actions)?;
 
-        actions
-            .get_execute_partial_query_packets()?
-            .commit(conf.as_ref(), timeout)
-            .await?;
+        let packets = actions.get_execute_partial_query_packets()?;
+        Self::commit_packets(&ctx, &packets, |p| &p.executor, &conf, timeout).await?;
         Ok(build_res)
     }
 
+    // Commit the packets to their nodes, the node which can't be connected is recorded in the
+    // query context, so that it's excluded from the cluster when the query is retried.
+    #[async_backtrace::framed]
+    async fn commit_packets<T: Packet>(
+        ctx: &Arc<QueryContext>,
+        packets: &[T],
+        executor: impl Fn(&T) -> &str,
+        conf: &InnerConfig,
+        timeout: u64,
+    ) -> Result<()> {
+        for packet in packets {
+            if let Err(cause) = packet.commit(conf, timeout).await {
+                if cause.code() == ErrorCode::CANNOT_CONNECT_NODE {
+                    ctx.add_unavailable_node(executor(packet));
+                }
+                return Err(cause);
+            }
+        }
+        Ok(())
+    }
+
     fn get_root_pipeline(
         &self,
         ctx: Arc<QueryContext>,
@@ -430,6 +477,7 @@ impl DataExchangeManager {
 struct QueryInfo {
     query_id: String,
     current_executor: String,
+    executors: Vec<String>,
     query_ctx: Arc<QueryContext>,
     query_executor: Option<Arc<PipelineCompleteExecutor>>,
 }
@@ -590,6 +638,7 @@ impl QueryCoordinator {
             query_ctx: ctx.clone(),
             query_id: packet.query_id.clone(),
             current_executor: packet.executor.clone(),
+            executors: packet.executors_info.keys().cloned().collect(),
             query_executor: None,
         });
 
@@ -36,12 +36,15 @@ use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
+use databend_common_management::heartbeat_interval;
 use databend_common_management::ClusterApi;
 use databend_common_management::ClusterMgr;
+use databend_common_management::NodeHeartbeat;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_store::MetaStoreProvider;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::NodeInfo;
+use databend_common_meta_types::SeqV;
 use databend_common_metrics::cluster::*;
 use futures::future::select;
 use futures::future::Either;
@@ -52,6 +55,7 @@ use log::warn;
 use rand::thread_rng;
 use rand::Rng;
 
+use crate::api::DataExchangeManager;
 use crate::api::FlightClient;
 
 pub struct ClusterDiscovery {
@@ -189,7 +193,7 @@ impl ClusterDiscovery {
         // TODO: generate if tenant or cluster id is empty
         let tenant_id = &cfg.query.tenant_id;
         let cluster_id = &cfg.query.cluster_id;
-        let lift_time = Duration::from_secs(60);
+        let lift_time = Duration::from_secs(cfg.query.cluster_heartbeat_timeout_secs);
         let cluster_manager = ClusterMgr::create(metastore, tenant_id, cluster_id, lift_time)?;
 
         Ok((lift_time, Arc::new(cluster_manager)))
@@ -197,7 +201,7 @@ impl ClusterDiscovery {
 
     #[async_backtrace::framed]
     pub async fn discover(&self, config: &InnerConfig) -> Result<Arc<Cluster>> {
-        match self.api_provider.get_nodes().await {
+        match self.api_provider.get_nodes_heartbeat().await {
             Err(cause) => {
                 metric_incr_cluster_error_count(
                     &self.local_id,
@@ -209,8 +213,10 @@ impl ClusterDiscovery {
                 Err(cause.add_message_back("(while cluster api get_nodes)."))
             }
             Ok(cluster_nodes) => {
+                let now_ms = SeqV::<()>::now_ms();
                 let mut res = Vec::with_capacity(cluster_nodes.len());
-                for node in &cluster_nodes {
+                for node_heartbeat in &cluster_nodes {
+                    let node = &node_heartbeat.node;
                     if node.id != self.local_id {
                         let start_at = Instant::now();
                         if let Err(cause) = create_client(config, &node.flight_address).await {
@@ -221,6 +227,11 @@ impl ClusterDiscovery {
                                 cause
                             );
 
+                            if !node_heartbeat.is_alive(now_ms)
+                                && drop_dead_node(self.api_provider.as_ref(), node_heartbeat).await
+                            {
+                                DataExchangeManager::instance().on_node_dead(&node.id);
+                            }
                             continue;
                         }
                     }
@@ -415,6 +426,8 @@ impl ClusterHeartbeat {
                             );
                             error!("Cluster cluster api heartbeat failure: {:?}", failure);
                         }
+
+                        check_nodes_liveness(cluster_api.as_ref(), &node.id).await;
                     }
                 }
             }
@@ -422,7 +435,8 @@ impl ClusterHeartbeat {
     }
 
     fn heartbeat_interval(&self, duration: Duration) -> RangeInclusive<u128> {
-        (duration / 3).as_millis()..=((duration / 3) * 2).as_millis()
+        let interval = heartbeat_interval(duration);
+        interval.start().as_millis()..=interval.end().as_millis()
     }
 
     pub fn start(&mut self, node_info: NodeInfo) {
@@ -447,6 +461,58 @@ impl ClusterHeartbeat {
     }
 }
 
+/// Deregister the nodes which missed the heartbeat rather than waiting for their
+/// lease to expire, and kill the local queries running on them.
+#[async_backtrace::framed]
+async fn check_nodes_liveness(cluster_api: &dyn ClusterApi, local_id: &str) {
+    let nodes_heartbeat = match cluster_api.get_nodes_heartbeat().await {
+        Ok(nodes_heartbeat) => nodes_heartbeat,
+        Err(cause) => {
+            warn!(
+                "Cannot check the liveness of cluster nodes, cause {:?}",
+                cause
+            );
+            return;
+        }
+    };
+
+    let now_ms = SeqV::<()>::now_ms();
+    for node_heartbeat in &nodes_heartbeat {
+        if node_heartbeat.node.id != local_id
+            && !node_heartbeat.is_alive(now_ms)
+            && drop_dead_node(cluster_api, node_heartbeat).await
+        {
+            // The node is not dropped if it heartbeats in the meantime, its queries
+            // are killed only once it's dropped.
+            DataExchangeManager::instance().on_node_dead(&node_heartbeat.node.id);
+        }
+    }
+}
+
+/// Deregister the node which missed the heartbeat, rather than waiting for its
+/// lease to expire. Returns whether the node is dropped.
+#[async_backtrace::framed]
+async fn drop_dead_node(cluster_api: &dyn ClusterApi, node_heartbeat: &NodeHeartbeat) -> bool {
+    let node = &node_heartbeat.node;
+    // The seq protects the node which is re-registered in the meantime.
+    let drop_dead_node =
+        cluster_api.drop_node(node.id.clone(), MatchSeq::Exact(node_heartbeat.seq));
+
+    match drop_dead_node.await {
+        Ok(_) => {
+            warn!(
+                "Drop dead node [{:?}], the last heartbeat of which is at {}ms",
+                node.flight_address, node_heartbeat.last_heartbeat_ms
+            );
+            true
+        }
+        Err(cause) => {
+            warn!("Drop dead node failure: {:?}", cause);
+            false
+        }
+    }
+}
+
 #[async_backtrace::framed]
 pub async fn create_client(config: &InnerConfig, address: &str) -> Result<FlightClient> {
     let timeout = if config.query.rpc_client_timeout_secs > 0 {
@@ -18,11 +18,14 @@ use databend_common_meta_app::schema::DatabaseIdent;
 use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_storages_information_schema::CheckConstraintsTable;
 use databend_common_storages_information_schema::ColumnsTable;
 use databend_common_storages_information_schema::KeyColumnUsageTable;
 use databend_common_storages_information_schema::KeywordsTable;
+use databend_common_storages_information_schema::PartitionsTable;
 use databend_common_storages_information_schema::SchemataTable;
 use databend_common_storages_information_schema::StatisticsTable;
+use databend_common_storages_information_schema::TableConstraintsTable;
 use databend_common_storages_information_schema::TablesTable;
 use databend_common_storages_information_schema::ViewsTable;
 
@@ -45,6 +48,9 @@ impl InformationSchemaDatabase {
             SchemataTable::create(sys_db_meta.next_table_id()),
             StatisticsTable::create(sys_db_meta.next_table_id()),
             KeyColumnUsageTable::create(sys_db_meta.next_table_id()),
+            TableConstraintsTable::create(sys_db_meta.next_table_id()),
+            CheckConstraintsTable::create(sys_db_meta.next_table_id()),
+            PartitionsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let db = "information_schema";
@@ -26,6 +26,7 @@ use databend_common_storages_system::BacktraceTable;
 use databend_common_storages_system::BuildOptionsTable;
 use databend_common_storages_system::CachesTable;
 use databend_common_storages_system::CatalogsTable;
+use databend_common_storages_system::ClusterNodesTable;
 use databend_common_storages_system::ClusteringHistoryTable;
 use databend_common_storages_system::ClustersTable;
 use databend_common_storages_system::ColumnsTable;
@@ -36,11 +37,15 @@ use databend_common_storages_system::DatabasesTable;
 use databend_common_storages_system::EnginesTable;
 use databend_common_storages_system::FunctionsTable;
 use databend_common_storages_system::IndexesTable;
+use databend_common_storages_system::IoStatsTable;
 use databend_common_storages_system::LocksTable;
 use databend_common_storages_system::MallocStatsTable;
 use databend_common_storages_system::MallocStatsTotalsTable;
+use databend_common_storages_system::MemoryUsageTable;
 use databend_common_storages_system::MetricsTable;
+use databend_common_storages_system::MigrationsTable;
 use databend_common_storages_system::OneTable;
+use databend_common_storages_system::PartitionsTable;
 use databend_common_storages_system::PasswordPoliciesTable;
 use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::ProcessorProfileTable;
@@ -49,13 +54,16 @@ use databend_common_storages_system::QueryLogTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
 use databend_common_storages_system::StagesTable;
+use databend_common_storages_system::StatusTable;
 use databend_common_storages_system::StreamsTable;
 use databend_common_storages_system::TableFunctionsTable;
+use databend_common_storages_system::TableStatisticsTable;
 use databend_common_storages_system::TablesTableWithHistory;
 use databend_common_storages_system::TablesTableWithoutHistory;
 use databend_common_storages_system::TaskHistoryTable;
 use databend_common_storages_system::TasksTable;
 use databend_common_storages_system::TempFilesTable;
+use databend_common_storages_system::TenantResourceUsageTable;
 use databend_common_storages_system::TracingTable;
 use databend_common_storages_system::UserFunctionsTable;
 use databend_common_storages_system::UsersTable;
@@ -76,6 +84,7 @@ impl SystemDatabase {
         let mut map = HashMap::new();
         map.insert("configs".to_string(), true);
         map.insert("clusters".to_string(), true);
+        map.insert("cluster_nodes".to_string(), true);
         map
     }
 
@@ -89,14 +98,18 @@ impl SystemDatabase {
             TablesTableWithoutHistory::create(sys_db_meta.next_table_id()),
             TablesTableWithHistory::create(sys_db_meta.next_table_id()),
             ClustersTable::create(sys_db_meta.next_table_id()),
+            ClusterNodesTable::create(sys_db_meta.next_table_id()),
             DatabasesTable::create(sys_db_meta.next_table_id()),
             StreamsTable::create(sys_db_meta.next_table_id()),
             Arc::new(TracingTable::create(sys_db_meta.next_table_id())),
             ProcessesTable::create(sys_db_meta.next_table_id()),
             ConfigsTable::create(sys_db_meta.next_table_id()),
             MetricsTable::create(sys_db_meta.next_table_id()),
+            StatusTable::create(sys_db_meta.next_table_id()),
             MallocStatsTable::create(sys_db_meta.next_table_id()),
             MallocStatsTotalsTable::create(sys_db_meta.next_table_id()),
+            MemoryUsageTable::create(sys_db_meta.next_table_id()),
+            TenantResourceUsageTable::create(sys_db_meta.next_table_id()),
             ColumnsTable::create(sys_db_meta.next_table_id()),
             UsersTable::create(sys_db_meta.next_table_id()),
             Arc::new(QueryLogTable::create(
@@ -107,6 +120,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(IoStatsTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -126,7 +143,10 @@ impl SystemDatabase {
             LocksTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
+            PartitionsTable::create(sys_db_meta.next_table_id()),
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
+            TableStatisticsTable::create(sys_db_meta.next_table_id()),
+            MigrationsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
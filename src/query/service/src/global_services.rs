@@ -40,8 +40,11 @@ use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
+use crate::notifications::NotificationManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::sessions::TenantResourceManager;
+use crate::tasks::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -98,6 +101,7 @@ impl GlobalServices {
         HttpQueryManager::init(config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
+        TenantResourceManager::init(config)?;
         LockManager::init()?;
         AuthMgr::init(config)?;
         UserApiProvider::init(
@@ -105,9 +109,11 @@ impl GlobalServices {
             config.query.idm.clone(),
             config.query.tenant_id.as_str(),
             config.query.tenant_quota.clone(),
+            config.query.max_udf_versions,
         )
         .await?;
         RoleCacheManager::init()?;
+        NotificationManager::init()?;
         ShareEndpointManager::init()?;
 
         DataOperator::init(&config.storage).await?;
@@ -121,6 +127,7 @@ impl GlobalServices {
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr, config.query.cloud_control_grpc_timeout).await?;
         }
+        TaskScheduler::init(config)?;
 
         Ok(())
     }
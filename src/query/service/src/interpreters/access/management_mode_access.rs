@@ -49,6 +49,7 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::ShowColumns(_, _, _)
                             | RewriteKind::ShowEngines
                             | RewriteKind::ShowSettings
+                            | RewriteKind::ShowStatus
                             | RewriteKind::ShowFunctions
                             | RewriteKind::ShowUserFunctions
                             | RewriteKind::ShowTableFunctions
@@ -77,6 +78,8 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Table.
                 | Plan::CreateTable(_)
+                | Plan::CreateRollupTable(_)
+                | Plan::CloneTable(_)
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
@@ -100,11 +103,16 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::RevokeRole(_)
                 // Stage.
                 | Plan::CreateStage(_)
+                | Plan::AlterStage(_)
                 | Plan::DropStage(_)
                 // Network policy.
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Notification integration.
+                | Plan::CreateNotificationIntegration(_)
+                | Plan::AlterNotificationIntegration(_)
+                | Plan::DropNotificationIntegration(_)
                 // Password policy.
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -26,10 +28,18 @@ use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserGrantSet;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_sql::optimizer::get_udf_names;
+use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::PresignAction;
+use databend_common_sql::plans::RelOperator;
 use databend_common_sql::plans::RewriteKind;
+use databend_common_sql::ColumnEntry;
+use databend_common_sql::IndexType;
+use databend_common_sql::Metadata;
+use databend_common_sql::Planner;
+use databend_common_sql::TableEntry;
 use databend_common_users::RoleCacheManager;
 
 use crate::interpreters::access::AccessChecker;
@@ -123,7 +133,9 @@ impl PrivilegeAccess {
                 catalog_name: catalog_name.clone(),
                 db_id: *db_id,
             },
-            GrantObject::TableById(catalog_name, db_id, table_id) => OwnershipObject::Table {
+            // The owner of the table could access all the columns of it.
+            GrantObject::TableById(catalog_name, db_id, table_id)
+            | GrantObject::Column(catalog_name, db_id, table_id, _) => OwnershipObject::Table {
                 catalog_name: catalog_name.clone(),
                 db_id: *db_id,
                 table_id: *table_id,
@@ -267,6 +279,125 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    /// Validate the SELECT privilege on the columns of the table, which is checked if
+    /// the table is not granted as a whole.
+    async fn validate_column_access(
+        &self,
+        catalog_name: &str,
+        db_name: &str,
+        table_name: &str,
+        columns: &BTreeSet<String>,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let ObjectId::Table(db_id, table_id) = self
+            .convert_to_id(&tenant, catalog_name, db_name, Some(table_name))
+            .await?
+        else {
+            return Err(ErrorCode::Internal(format!(
+                "table {db_name}.{table_name} is not converted to id"
+            )));
+        };
+        for column in columns {
+            self.validate_access(
+                &GrantObject::Column(catalog_name.to_string(), db_id, table_id, column.clone()),
+                vec![UserPrivilegeType::Select],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// The creator of a view must have the access to all the objects referenced by the query of the view.
+    async fn validate_view_query_access(
+        &self,
+        ctx: &Arc<QueryContext>,
+        subquery: &str,
+    ) -> Result<()> {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(subquery).await?;
+        self.check(ctx, &plan).await
+    }
+
+    /// Validate the SELECT privilege of the owner of the view on the table referenced by it.
+    async fn validate_view_owner_access(
+        &self,
+        view: &TableEntry,
+        table: &TableEntry,
+    ) -> Result<()> {
+        let db_name = table.database().to_lowercase();
+        let table_name = table.name().to_lowercase();
+        if (db_name == "system" && SYSTEM_TABLES_ALLOW_LIST.iter().any(|x| x == &table_name))
+            || db_name == "information_schema"
+        {
+            return Ok(());
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let role_mgr = RoleCacheManager::instance();
+        let (view_db_id, view_id) = self.convert_to_table_id(view).await?;
+        let view_object = OwnershipObject::Table {
+            catalog_name: view.catalog().to_string(),
+            db_id: view_db_id,
+            table_id: view_id,
+        };
+        // The views created before the ownership is introduced are only accessible by the
+        // privileges of the current user.
+        let Some(owner) = role_mgr.find_object_owner(&tenant, &view_object).await? else {
+            return self
+                .validate_table_access(
+                    table.catalog(),
+                    table.database(),
+                    table.name(),
+                    vec![UserPrivilegeType::Select],
+                    false,
+                )
+                .await;
+        };
+
+        let (db_id, table_id) = self.convert_to_table_id(table).await?;
+        let table_object = OwnershipObject::Table {
+            catalog_name: table.catalog().to_string(),
+            db_id,
+            table_id,
+        };
+        let owner_roles = role_mgr
+            .find_related_roles(&tenant, &[owner.clone()])
+            .await?;
+        if let Some(table_owner) = role_mgr.find_object_owner(&tenant, &table_object).await? {
+            if owner_roles.iter().any(|r| r.name == table_owner) {
+                return Ok(());
+            }
+        }
+        let grant_object = GrantObject::TableById(table.catalog().to_string(), db_id, table_id);
+        if owner_roles.iter().any(|r| {
+            r.grants
+                .verify_privilege(&grant_object, vec![UserPrivilegeType::Select])
+        }) {
+            return Ok(());
+        }
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, privilege [Select] is required on '{}'.'{}'.'{}' for the owner {} of view '{}'.'{}'",
+            table.catalog(),
+            table.database(),
+            table.name(),
+            owner,
+            view.database(),
+            view.name(),
+        )))
+    }
+
+    async fn convert_to_table_id(&self, table: &TableEntry) -> Result<(u64, u64)> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(table.catalog()).await?;
+        let db_id = catalog
+            .get_database(&tenant, table.database())
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        Ok((db_id, table.table().get_id()))
+    }
+
     async fn has_ownership(
         &self,
         session: &Arc<Session>,
@@ -310,7 +441,8 @@ impl PrivilegeAccess {
                 let db_name = catalog.get_db_name_by_id(*db_id).await?;
                 (db_name.to_lowercase(), "".to_string())
             }
-            GrantObject::TableById(catalog_name, db_id, table_id) => {
+            GrantObject::TableById(catalog_name, db_id, table_id)
+            | GrantObject::Column(catalog_name, db_id, table_id, _) => {
                 let catalog = self.ctx.get_catalog(catalog_name).await?;
                 let db_name = catalog.get_db_name_by_id(*db_id).await?;
                 let table_name = catalog.get_table_name_by_id(*table_id).await?;
@@ -333,7 +465,8 @@ impl PrivilegeAccess {
             | GrantObject::DatabaseById(_, _)
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
-            | GrantObject::TableById(_, _, _) => true,
+            | GrantObject::TableById(_, _, _)
+            | GrantObject::Column(_, _, _, _) => true,
             GrantObject::Global => false,
         };
 
@@ -371,6 +504,18 @@ impl PrivilegeAccess {
                             roles_name,
                         )))
                     }
+                    GrantObject::Column(catalog_name, _, _, column) => {
+                        Err(ErrorCode::PermissionDenied(format!(
+                            "Permission denied, privilege {:?} is required on column {} of '{}'.'{}'.'{}' for user {} with roles [{}]",
+                            privileges.clone(),
+                            column,
+                            catalog_name,
+                            db_name,
+                            table_name,
+                            &current_user.identity(),
+                            roles_name,
+                        )))
+                    }
                     GrantObject::DatabaseById(catalog_name, _) => {
                         Err(ErrorCode::PermissionDenied(format!(
                             "Permission denied, privilege {:?} is required on '{}'.'{}'.* for user {} with roles [{}]",
@@ -569,6 +714,8 @@ impl AccessChecker for PrivilegeAccess {
                 }
 
                 let metadata = metadata.read().clone();
+                let mut scan_columns = HashMap::new();
+                collect_scan_columns(s_expr, &metadata, &mut scan_columns);
 
                 for table in metadata.tables() {
                     if enable_experimental_rbac_check && table.is_source_of_stage() {
@@ -585,6 +732,14 @@ impl AccessChecker for PrivilegeAccess {
                             DataSourceInfo::TableSource(_) | DataSourceInfo::ResultScanSource(_) => {}
                         }
                     }
+                    if let Some(view_index) = table.view_index() {
+                        // The tables referenced by a view are checked against the owner of the
+                        // view, so the view is not accessible once the owner loses the access.
+                        if !table.is_source_of_stage() {
+                            self.validate_view_owner_access(metadata.table(view_index), table).await?;
+                        }
+                        continue;
+                    }
                     if table.is_source_of_view() {
                         continue;
                     }
@@ -592,11 +747,19 @@ impl AccessChecker for PrivilegeAccess {
                     // like this sql: copy into t from (select * from @s3); will bind a mock table with name `system.read_parquet(s3)`
                     // this is no means to check table `system.read_parquet(s3)` privilege
                     if !table.is_source_of_stage() {
-                        self.validate_table_access(catalog_name, table.database(), table.name(), vec![UserPrivilegeType::Select], false).await?
+                        if let Err(err) = self.validate_table_access(catalog_name, table.database(), table.name(), vec![UserPrivilegeType::Select], false).await {
+                            // The query is allowed if all the columns it reads from the table are granted.
+                            match scan_columns.get(&table.index()) {
+                                Some(columns) if err.code() == ErrorCode::PERMISSION_DENIED && !columns.is_empty() => {
+                                    self.validate_column_access(catalog_name, table.database(), table.name(), columns).await?
+                                }
+                                _ => return Err(err),
+                            }
+                        }
                     }
                 }
             }
-            Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => {
+            Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } | Plan::RecommendIndex { plan } => {
                 self.check(ctx, plan).await?
             }
 
@@ -604,10 +767,13 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ShowCreateDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Select], false).await?
             }
-            Plan::CreateDatabase(_) => {
+            Plan::CreateDatabase(_) | Plan::RestoreDatabase(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::CreateDatabase])
                     .await?;
             }
+            Plan::BackupDatabase(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Select], false).await?
+            }
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop], plan.if_exists).await?;
             }
@@ -668,6 +834,18 @@ impl AccessChecker for PrivilegeAccess {
                     self.check(ctx, query).await?;
                 }
             }
+            Plan::CreateRollupTable(plan) => {
+                let create_table = &plan.create_table;
+                self.validate_db_access(&create_table.catalog, &create_table.database, vec![UserPrivilegeType::Create], false).await?;
+                // Creating a rollup registers it to the options of the source table.
+                self.validate_table_access(&create_table.catalog, &plan.source_database, &plan.source_table, vec![UserPrivilegeType::Select, UserPrivilegeType::Alter], false).await?;
+            }
+            Plan::CloneTable(plan) => {
+                let create_table = &plan.create_table;
+                self.validate_db_access(&create_table.catalog, &create_table.database, vec![UserPrivilegeType::Create], false).await?;
+                // Cloning registers the cloned snapshot to the options of the source table.
+                self.validate_table_access(&plan.source_catalog, &plan.source_database, &plan.source_table, vec![UserPrivilegeType::Select, UserPrivilegeType::Alter], false).await?;
+            }
             Plan::DropTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Drop], plan.if_exists).await?;
             }
@@ -847,10 +1025,12 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Update], false).await?;
             }
             Plan::CreateView(plan) => {
-                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?;
+                self.validate_view_query_access(ctx, &plan.subquery).await?
             }
             Plan::AlterView(plan) => {
-                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Alter], false).await?
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Alter], false).await?;
+                self.validate_view_query_access(ctx, &plan.subquery).await?
             }
             Plan::DropView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop], plan.if_exists).await?
@@ -901,7 +1081,10 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Grant])
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
+            Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::ResetMemoryPeak => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
             }
@@ -915,8 +1098,17 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             Plan::CopyIntoTable(plan) => {
+                // The files in the filesystem of the query node, e.g. loaded by IMPORT, are only
+                // accessible to the super users.
+                if matches!(plan.stage_table_info.stage_info.stage_params.storage, StorageParams::Fs(_)) {
+                    self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super]).await?;
+                }
                 self.validate_stage_access(&plan.stage_table_info.stage_info, UserPrivilegeType::Read).await?;
-                self.validate_table_access(plan.catalog_info.catalog_name(), &plan.database_name, &plan.table_name, vec![UserPrivilegeType::Insert], false).await?;
+                match &plan.create_table {
+                    // The table doesn't exist yet, it will be created with the inferred schema.
+                    Some(create_table) => self.validate_db_access(&create_table.catalog, &create_table.database, vec![UserPrivilegeType::Create], false).await?,
+                    None => self.validate_table_access(plan.catalog_info.catalog_name(), &plan.database_name, &plan.table_name, vec![UserPrivilegeType::Insert], false).await?,
+                }
                 if let Some(query) = &plan.query {
                     self.check(ctx, query).await?;
                 }
@@ -940,8 +1132,10 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::CreateCatalog(_)
             | Plan::DropCatalog(_)
             | Plan::CreateStage(_)
+            | Plan::AlterStage(_)
             | Plan::DropStage(_)
             | Plan::CreateFileFormat(_)
+            | Plan::AlterFileFormat(_)
             | Plan::DropFileFormat(_)
             | Plan::ShowFileFormats(_)
             | Plan::CreateNetworkPolicy(_)
@@ -949,12 +1143,18 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
             | Plan::ShowNetworkPolicies(_)
+            | Plan::CreateNotificationIntegration(_)
+            | Plan::AlterNotificationIntegration(_)
+            | Plan::DropNotificationIntegration(_)
+            | Plan::ShowNotificationIntegrations(_)
             | Plan::CreatePasswordPolicy(_)
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
+            | Plan::ShowBackups(_)
+            | Plan::RestoreMetasrv(_)
             | Plan::DescConnection(_)
             | Plan::DropConnection(_)
             | Plan::CreateUDF(_)
@@ -964,7 +1164,14 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
             | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
-            | Plan::AlterTask(_) => {
+            | Plan::AlterTask(_)
+            | Plan::RollbackMigration(_)
+            | Plan::PurgeQueryCache(_) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await?;
+            }
+            Plan::ApplyMigration(plan) => {
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Read).await?;
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
             }
@@ -979,6 +1186,9 @@ impl AccessChecker for PrivilegeAccess {
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
+            // BEGIN & COMMIT only change the transaction of the session
+            Plan::Begin => {}
+            Plan::Commit => {}
             Plan::ShowRoles(_) => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
@@ -998,6 +1208,30 @@ impl AccessChecker for PrivilegeAccess {
     }
 }
 
+/// Collect the names of the table columns read by the scans of the query, by table index.
+fn collect_scan_columns(
+    s_expr: &SExpr,
+    metadata: &Metadata,
+    columns: &mut HashMap<IndexType, BTreeSet<String>>,
+) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        let names = columns.entry(scan.table_index).or_default();
+        for index in scan.columns.iter() {
+            if let ColumnEntry::BaseTableColumn(column) = metadata.column(*index) {
+                // The inner columns of a tuple are named as `<column>:<field>`.
+                let name = match column.path_indices {
+                    Some(_) => column.column_name.split(':').next().unwrap_or_default(),
+                    None => column.column_name.as_str(),
+                };
+                names.insert(name.to_string());
+            }
+        }
+    }
+    for child in s_expr.children() {
+        collect_scan_columns(child, metadata, columns);
+    }
+}
+
 // TODO(liyz): replace it with verify_access
 async fn has_priv(
     tenant: &str,
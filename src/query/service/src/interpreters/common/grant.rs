@@ -79,6 +79,31 @@ pub async fn validate_grant_object_exists(
                 ));
             }
         }
+        GrantObject::Column(catalog_name, db_id, table_id, column) => {
+            let catalog = ctx.get_catalog(catalog_name).await?;
+            let (Ok(db_name), Ok(table_name)) = (
+                catalog.get_db_name_by_id(*db_id).await,
+                catalog.get_table_name_by_id(*table_id).await,
+            ) else {
+                return Err(databend_common_exception::ErrorCode::UnknownTableId(
+                    format!(
+                        "table id `{}`.`{}` not exists in catalog '{}'",
+                        db_id, table_id, catalog_name,
+                    ),
+                ));
+            };
+            let table = catalog
+                .get_table(tenant.as_str(), &db_name, &table_name)
+                .await?;
+            if table.schema().field_with_name(column).is_err() {
+                return Err(databend_common_exception::ErrorCode::UnknownColumn(
+                    format!(
+                        "column {} not exists in table `{}`.`{}`",
+                        column, db_name, table_name,
+                    ),
+                ));
+            }
+        }
         GrantObject::UDF(udf) => {
             if !UserApiProvider::instance()
                 .exists_udf(tenant.as_str(), udf)
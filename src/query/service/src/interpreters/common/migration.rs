@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::Planner;
+use futures_util::TryStreamExt;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The line of a migration script after which the statements rolling back the migration follow.
+const ROLLBACK_SEPARATOR: &str = "--/+";
+
+/// A migration script read from a stage.
+pub struct MigrationScript<'a> {
+    pub checksum: String,
+    pub apply: &'a str,
+    pub rollback: Option<&'a str>,
+}
+
+impl<'a> MigrationScript<'a> {
+    pub fn parse(script: &'a str) -> Self {
+        let checksum = format!("{:x}", Sha256::digest(script.as_bytes()));
+
+        let mut offset = 0;
+        for line in script.split_inclusive('\n') {
+            if line.trim() == ROLLBACK_SEPARATOR {
+                let rollback = &script[offset + line.len()..];
+                return MigrationScript {
+                    checksum,
+                    apply: &script[..offset],
+                    rollback: (!rollback.trim().is_empty()).then_some(rollback),
+                };
+            }
+            offset += line.len();
+        }
+        MigrationScript {
+            checksum,
+            apply: script,
+            rollback: None,
+        }
+    }
+}
+
+/// Splits a script into the statements separated by semicolons, the comments are skipped.
+pub fn split_statements(script: &str) -> Result<Vec<String>> {
+    let mut statements = vec![];
+    let mut range: Option<(usize, usize)> = None;
+    for token in tokenize_sql(script)? {
+        match token.kind {
+            TokenKind::SemiColon | TokenKind::EOI => {
+                if let Some((start, end)) = range.take() {
+                    statements.push(script[start..end].to_string());
+                }
+            }
+            _ => {
+                let start = range.map_or(token.span.start(), |(start, _)| start);
+                range = Some((start, token.span.end()));
+            }
+        }
+    }
+    Ok(statements)
+}
+
+/// Runs the statements of a migration one by one as the current user.
+///
+/// There are no transactions across statements, so the running stops at the first statement
+/// that fails, leaving the statements before it applied.
+pub async fn run_migration_statements(
+    ctx: &Arc<QueryContext>,
+    name: &str,
+    statements: &[String],
+) -> Result<()> {
+    // The statements are run in a session of their own, which doesn't take the query permit
+    // of the tenant held by the current query.
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    session.set_current_tenant(ctx.get_tenant());
+    session
+        .set_authed_user(ctx.get_current_user()?, None)
+        .await?;
+    if let Some(role) = ctx.get_current_role() {
+        session.set_current_role_checked(&role.name).await?;
+    }
+    session.set_current_database(ctx.get_current_database());
+
+    for (i, statement) in statements.iter().enumerate() {
+        run_statement(&session, statement).await.map_err(|e| {
+            e.add_message_back(format!(
+                " (while running statement {} of migration '{}', the statements before it are applied)",
+                i + 1,
+                name
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+async fn run_statement(session: &Arc<Session>, statement: &str) -> Result<()> {
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, plan_extras) = planner.plan_sql(statement).await?;
+    ctx.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
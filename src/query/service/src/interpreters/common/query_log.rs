@@ -20,6 +20,8 @@ use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storages_system::IoStatsLogElement;
+use databend_common_storages_system::IoStatsQueue;
 use databend_common_storages_system::LogType;
 use databend_common_storages_system::QueryLogElement;
 use databend_common_storages_system::QueryLogQueue;
@@ -197,6 +199,7 @@ impl InterpreterQueryLog {
             session_settings,
             extra: "".to_string(),
             has_profiles: false,
+            query_retries: 0,
         })
     }
 
@@ -345,6 +348,29 @@ impl InterpreterQueryLog {
             session_settings,
             extra: "".to_string(),
             has_profiles,
-        })
+            query_retries: ctx.get_query_retries(),
+        })?;
+
+        Self::write_io_stats(ctx)
+    }
+
+    fn write_io_stats(ctx: &QueryContext) -> Result<()> {
+        let query_id = ctx.get_id();
+        for (operator_name, metrics) in ctx.get_io_stats() {
+            if metrics.get_read_requests() == 0 && metrics.get_write_requests() == 0 {
+                continue;
+            }
+
+            IoStatsQueue::instance()?.append_data(IoStatsLogElement {
+                query_id: query_id.clone(),
+                operator_name,
+                bytes_read: metrics.get_read_bytes() as u64,
+                bytes_written: metrics.get_write_bytes() as u64,
+                read_requests: metrics.get_read_requests(),
+                write_requests: metrics.get_write_requests(),
+                read_latency_p99_ms: metrics.get_read_latency_p99_ms(),
+            })?;
+        }
+        Ok(())
     }
 }
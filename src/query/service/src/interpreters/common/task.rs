@@ -22,6 +22,7 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskSchedule;
 
 use crate::sessions::QueryContext;
 
@@ -47,6 +48,13 @@ pub fn make_schedule_options(
     }
 }
 
+pub fn make_task_schedule(opt: ScheduleOptions) -> TaskSchedule {
+    match opt {
+        ScheduleOptions::IntervalSecs(secs) => TaskSchedule::IntervalSecs(secs),
+        ScheduleOptions::CronExpression(expr, time_zone) => TaskSchedule::Cron { expr, time_zone },
+    }
+}
+
 pub fn make_warehouse_options(
     opt: WarehouseOptions,
 ) -> databend_common_cloud_control::pb::WarehouseOptions {
@@ -22,6 +22,8 @@ use crate::interpreters::hook::compact_hook::CompactHookTraceCtx;
 use crate::interpreters::hook::compact_hook::CompactTargetTableDescription;
 use crate::interpreters::hook::refresh_hook::hook_refresh;
 use crate::interpreters::hook::refresh_hook::RefreshDesc;
+use crate::interpreters::hook::rollup_hook::hook_rollup;
+use crate::interpreters::hook::rollup_hook::RollupDesc;
 use crate::sessions::QueryContext;
 
 /// Hook operator.
@@ -104,4 +106,20 @@ impl HookOperator {
 
         hook_refresh(self.ctx.clone(), pipeline, refresh_desc).await;
     }
+
+    /// Execute the rollup hook operator.
+    /// Refresh the rollup tables of the target table with the newly inserted rows,
+    /// or rebuild them if `rebuild`, it must be executed before the compact hook operator.
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    pub async fn execute_rollup(&self, pipeline: &mut Pipeline, rebuild: bool) {
+        let rollup_desc = RollupDesc {
+            catalog: self.catalog.to_owned(),
+            database: self.database.to_owned(),
+            table: self.table.to_owned(),
+            rebuild,
+        };
+
+        hook_rollup(self.ctx.clone(), pipeline, rollup_desc).await;
+    }
 }
@@ -0,0 +1,325 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::SEGMENT_NAME_COL_NAME;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::Planner;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_REFRESH_ERROR;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_SYNC_REBUILD;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_TABLES;
+use log::error;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+
+pub struct RollupDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Rebuild the rollup tables from all the rows of the source table, instead of aggregating
+    /// the newly inserted segments. It is required by the statements which overwrite, update
+    /// or delete the rows of the source table.
+    pub rebuild: bool,
+}
+
+/// Hook rollup action with a on-finished callback.
+///
+/// A rollup table failed to refresh is marked with [`OPT_KEY_ROLLUP_REFRESH_ERROR`],
+/// and rebuilt by its next refresh.
+///
+/// The rebuilds required by the statements other than INSERT recompute the rollup tables
+/// from the whole source table, they run in the background unless the source table sets
+/// [`OPT_KEY_ROLLUP_SYNC_REBUILD`].
+///
+/// The callback must be set before the compact hook, which clears the
+/// segment locations inserted by this query.
+pub async fn hook_rollup(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc: RollupDesc) {
+    if pipeline.is_empty() {
+        // The rows may have been removed without a pipeline, such as by TRUNCATE.
+        if desc.rebuild {
+            if let Err(e) = run_rollup_job(ctx, desc).await {
+                error!("execute rollup job failed. {:?}", e);
+            }
+        }
+        return;
+    }
+
+    pipeline.set_on_finished(move |err| {
+        if err.is_ok() {
+            info!("execute pipeline finished successfully, starting run rollup job.");
+            match GlobalIORuntime::instance().block_on(run_rollup_job(ctx, desc)) {
+                Ok(_) => {
+                    info!("execute rollup job successfully.");
+                }
+                Err(e) => {
+                    error!("execute rollup job failed. {:?}", e);
+                }
+            }
+        }
+        Ok(())
+    });
+}
+
+async fn run_rollup_job(ctx: Arc<QueryContext>, desc: RollupDesc) -> Result<()> {
+    if !desc.rebuild {
+        return refresh_rollups(ctx, desc).await;
+    }
+
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let tenant = ctx.get_tenant();
+    let table = catalog
+        .get_table(tenant.as_str(), &desc.database, &desc.table)
+        .await?;
+    let Some(rollup_tables) = table.options().get(OPT_KEY_ROLLUP_TABLES) else {
+        return Ok(());
+    };
+    let sync_rebuild = table
+        .options()
+        .get(OPT_KEY_ROLLUP_SYNC_REBUILD)
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+    if sync_rebuild {
+        return refresh_rollups(ctx, desc).await;
+    }
+
+    // The rollup tables are out of sync until they are rebuilt, they are marked stale first,
+    // so they are rebuilt by their next refresh if the background rebuild fails.
+    for rollup_table in rollup_tables.split(',') {
+        let Some((database, table)) = rollup_table.split_once('.') else {
+            continue;
+        };
+        if catalog
+            .get_table(tenant.as_str(), database, table)
+            .await
+            .is_ok()
+        {
+            let error = "the source table is modified, the rebuild is pending".to_string();
+            set_refresh_error(catalog.as_ref(), &tenant, database, table, Some(error)).await?;
+        }
+    }
+
+    let query_id = ctx.get_id();
+    GlobalIORuntime::instance().spawn(query_id, async move {
+        if let Err(e) = refresh_rollups(ctx, desc).await {
+            error!("rebuild rollup tables in the background failed. {:?}", e);
+        }
+    });
+    Ok(())
+}
+
+async fn refresh_rollups(ctx: Arc<QueryContext>, desc: RollupDesc) -> Result<()> {
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let tenant = ctx.get_tenant();
+    let table = catalog
+        .get_table(tenant.as_str(), &desc.database, &desc.table)
+        .await?;
+    let Some(rollup_tables) = table.options().get(OPT_KEY_ROLLUP_TABLES) else {
+        return Ok(());
+    };
+
+    let segment_locs = if desc.rebuild {
+        vec![]
+    } else {
+        ctx.get_segment_locations()?
+    };
+    for rollup_table in rollup_tables.split(',') {
+        let Some((database, table)) = rollup_table.split_once('.') else {
+            continue;
+        };
+        let segment_locs = (!desc.rebuild).then_some(segment_locs.as_slice());
+        refresh_rollup(ctx.clone(), catalog.as_ref(), database, table, segment_locs).await?;
+    }
+
+    Ok(())
+}
+
+/// Rebuild a rollup table from all the rows of its source table.
+#[async_backtrace::framed]
+pub async fn rebuild_rollup(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(catalog).await?;
+    refresh_rollup(ctx, catalog.as_ref(), database, table, None).await
+}
+
+/// Refresh a rollup table with the rows of the inserted segments of the source table,
+/// or rebuild it if `segment_locs` is `None` or its last refresh failed.
+///
+/// A failed refresh is recorded in the options of the rollup table instead of being returned,
+/// the rows of the source table have been committed anyway.
+async fn refresh_rollup(
+    ctx: Arc<QueryContext>,
+    catalog: &dyn Catalog,
+    database: &str,
+    table: &str,
+    segment_locs: Option<&[Location]>,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    // The rollup table may have been dropped.
+    let Ok(rollup_table) = catalog.get_table(tenant.as_str(), database, table).await else {
+        return Ok(());
+    };
+    let Some(rollup_query) = rollup_table.options().get(OPT_KEY_ROLLUP_QUERY) else {
+        return Ok(());
+    };
+
+    let failed = rollup_table
+        .options()
+        .contains_key(OPT_KEY_ROLLUP_REFRESH_ERROR);
+    let sql = match segment_locs {
+        Some([]) if !failed => return Ok(()),
+        Some(segment_locs) if !failed => Some(build_rollup_insert_sql(
+            ctx.clone(),
+            database,
+            table,
+            rollup_query,
+            segment_locs,
+        )),
+        _ => None,
+    };
+    let res = match sql {
+        Some(Ok(sql)) => insert_rollup(ctx.clone(), &sql).await,
+        Some(Err(e)) => Err(e),
+        // `INSERT OVERWRITE` replaces the rows of the rollup table in a single commit.
+        None => {
+            let sql = format!("INSERT OVERWRITE `{database}`.`{table}` {rollup_query}");
+            insert_rollup(ctx.clone(), &sql).await
+        }
+    };
+
+    match res {
+        Ok(_) if failed => set_refresh_error(catalog, &tenant, database, table, None).await,
+        Ok(_) => Ok(()),
+        Err(e) => {
+            error!(
+                "refresh rollup table {database}.{table} failed, it will be rebuilt by the next refresh. {:?}",
+                e
+            );
+            set_refresh_error(catalog, &tenant, database, table, Some(e.message())).await
+        }
+    }
+}
+
+/// Set or clear the error of the last refresh of a rollup table.
+async fn set_refresh_error(
+    catalog: &dyn Catalog,
+    tenant: &str,
+    database: &str,
+    table: &str,
+    error: Option<String>,
+) -> Result<()> {
+    let rollup_table = catalog.get_table(tenant, database, table).await?;
+    if error.is_none()
+        && !rollup_table
+            .options()
+            .contains_key(OPT_KEY_ROLLUP_REFRESH_ERROR)
+    {
+        return Ok(());
+    }
+
+    let req = UpsertTableOptionReq {
+        table_id: rollup_table.get_id(),
+        seq: MatchSeq::Exact(rollup_table.get_table_info().ident.seq),
+        options: HashMap::from([(OPT_KEY_ROLLUP_REFRESH_ERROR.to_string(), error)]),
+    };
+    catalog.upsert_table_option(tenant, database, req).await?;
+    Ok(())
+}
+
+/// Build `INSERT INTO <rollup_table> <rollup_query>` with the rollup query only
+/// aggregating the newly inserted segments of the source table.
+fn build_rollup_insert_sql(
+    ctx: Arc<QueryContext>,
+    database: &str,
+    table: &str,
+    rollup_query: &str,
+    segment_locs: &[Location],
+) -> Result<String> {
+    let sql_dialect = ctx.get_settings().get_sql_dialect()?;
+    let tokens = tokenize_sql(rollup_query)?;
+    let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+    let Statement::Query(mut query) = stmt else {
+        return Err(ErrorCode::Internal(format!(
+            "rollup query is not a query: {rollup_query}"
+        )));
+    };
+    let SetExpr::Select(select) = &mut query.body else {
+        return Err(ErrorCode::Internal(format!(
+            "rollup query is not a select: {rollup_query}"
+        )));
+    };
+
+    let segment_names = segment_locs
+        .iter()
+        .map(|(path, _)| format!("'{path}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let selection = format!("{SEGMENT_NAME_COL_NAME} IN ({segment_names})");
+    let tokens = tokenize_sql(&selection)?;
+    select.selection = Some(parse_expr(&tokens, sql_dialect)?);
+
+    Ok(format!("INSERT INTO `{database}`.`{table}` {query}"))
+}
+
+async fn insert_rollup(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+    // Run in a new context, so the segments inserted into the rollup table
+    // will not be mixed with those of the source table.
+    let ctx = QueryContext::create_from(ctx);
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let mut build_res = interpreter.execute2().await?;
+    if build_res.main_pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let settings = ctx.get_settings();
+    let query_id = ctx.get_id();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(&settings, query_id)?;
+
+    if build_res.main_pipeline.is_complete_pipeline()? {
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()?;
+    }
+    Ok(())
+}
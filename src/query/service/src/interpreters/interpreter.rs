@@ -20,6 +20,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::SendableDataBlockStream;
+use databend_common_meta_app::principal::NotificationEvent;
 use databend_common_pipeline_core::get_statistics_desc;
 use databend_common_pipeline_core::processors::profile::PlanProfile;
 use databend_common_pipeline_core::processors::ProfileDesc;
@@ -30,6 +31,8 @@ use log::info;
 
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
+use crate::notifications::NotificationManager;
+use crate::notifications::NotificationPayload;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -51,7 +54,7 @@ pub trait Interpreter: Sync + Send {
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
-        ctx.set_status_info("building pipeline");
+        ctx.set_status_info("waiting for the running queries of the tenant");
         InterpreterMetrics::record_query_start(&ctx);
         log_query_start(&ctx);
 
@@ -59,6 +62,14 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()), false);
             return Err(err);
         }
+
+        if let Err(err) = ctx.acquire_tenant_permit().await {
+            InterpreterMetrics::record_query_error(&ctx);
+            log_query_finished(&ctx, Some(err.clone()), false);
+            return Err(err);
+        }
+
+        ctx.set_status_info("building pipeline");
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
@@ -190,6 +201,14 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    if let Some(error) = &error {
+        NotificationManager::instance().notify(
+            &ctx.get_tenant(),
+            NotificationEvent::QueryError,
+            NotificationPayload::query_error(ctx.get_id(), error.message()),
+        );
+    }
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
@@ -24,6 +24,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
+use databend_common_meta_app::principal::NotificationEvent;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
@@ -39,9 +40,12 @@ use log::debug;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::notifications::NotificationManager;
+use crate::notifications::NotificationPayload;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -279,6 +283,27 @@ impl CopyIntoTableInterpreter {
                 main_pipeline,
             )?;
         }
+
+        // Notify the integrations after the files are committed.
+        {
+            let table = format!("{}.{}", plan.database_name, plan.table_name);
+            main_pipeline.set_on_finished(move |may_error| {
+                if may_error.is_ok() {
+                    let row_count = ctx
+                        .get_copy_status()
+                        .files
+                        .iter()
+                        .map(|entry| entry.value().num_rows_loaded as u64)
+                        .sum();
+                    NotificationManager::instance().notify(
+                        &ctx.get_tenant(),
+                        NotificationEvent::CopyIntoComplete,
+                        NotificationPayload::copy_into_complete(ctx.get_id(), table, row_count),
+                    );
+                }
+                Ok(())
+            });
+        }
         Ok(())
     }
 }
@@ -298,6 +323,13 @@ impl Interpreter for CopyIntoTableInterpreter {
             return Ok(PipelineBuildResult::create());
         }
 
+        // `SCHEMA = INFER` creates the table before copying if it doesn't exist.
+        if let Some(create_table) = &self.plan.create_table {
+            CreateTableInterpreter::try_create(self.ctx.clone(), *create_table.clone())?
+                .execute2()
+                .await?;
+        }
+
         if self.plan.no_file_to_copy {
             return Ok(PipelineBuildResult::create());
         }
@@ -328,6 +360,12 @@ impl Interpreter for CopyIntoTableInterpreter {
                 "copy_into_table".to_string(),
                 true,
             );
+            hook_operator
+                .execute_rollup(
+                    &mut build_res.main_pipeline,
+                    self.plan.write_mode.is_overwrite(),
+                )
+                .await;
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
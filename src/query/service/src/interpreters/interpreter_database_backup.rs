@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::plans::BackupDatabasePlan;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::operations::BackupManifest;
+use databend_common_storages_fuse::operations::TableBackup;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct BackupDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BackupDatabasePlan,
+}
+
+impl BackupDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BackupDatabasePlan) -> Result<Self> {
+        Ok(BackupDatabaseInterpreter { ctx, plan })
+    }
+}
+
+/// Remove the parts of the table meta that refer to the storage of the backed up table.
+fn backup_table_meta(meta: &TableMeta) -> TableMeta {
+    let mut meta = meta.clone();
+    meta.storage_params = None;
+    meta.part_prefix = "".to_string();
+    for key in [
+        OPT_KEY_DATABASE_ID,
+        OPT_KEY_STORAGE_PREFIX,
+        OPT_KEY_SNAPSHOT_LOCATION,
+        OPT_KEY_LEGACY_SNAPSHOT_LOC,
+        OPT_KEY_TABLE_ATTACHED_DATA_URI,
+        OPT_KEY_TABLE_ATTACHED_READ_ONLY,
+    ] {
+        meta.options.remove(key);
+    }
+    meta
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BackupDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "BackupDatabaseInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let operator = DataOperator::try_create(&self.plan.storage_params).await?;
+        let operator = operator.operator();
+
+        // The snapshots of the tables in the backup that this one is taken on.
+        let since_snapshots = match &self.plan.since {
+            Some(since) => {
+                let manifest = BackupManifest::read(&operator, since).await?;
+                if manifest.database != self.plan.database {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "backup {} is a backup of database {}, not {}",
+                        since, manifest.database, self.plan.database
+                    )));
+                }
+                manifest
+                    .tables
+                    .into_iter()
+                    .filter_map(|t| t.snapshot_location.map(|loc| (t.name, loc)))
+                    .collect::<HashMap<_, _>>()
+            }
+            None => HashMap::new(),
+        };
+
+        let mut tables = vec![];
+        let mut copied_files = 0;
+        for table in catalog
+            .list_tables(tenant.as_str(), &self.plan.database)
+            .await?
+        {
+            let table_info = table.get_table_info();
+            let (storage_prefix, snapshot_location) = match table.engine() {
+                "FUSE" => {
+                    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                    let since = since_snapshots.get(table.name()).map(|s| s.as_str());
+                    let backup = fuse_table
+                        .do_backup(self.ctx.clone(), &operator, since)
+                        .await?;
+                    let snapshot_location = backup.map(|(location, copied)| {
+                        copied_files += copied;
+                        location
+                    });
+                    (
+                        FuseTable::parse_storage_prefix(table_info)?,
+                        snapshot_location,
+                    )
+                }
+                // Views have no data, the definition in the meta is backed up.
+                "VIEW" => ("".to_string(), None),
+                engine => {
+                    self.ctx.push_warning(format!(
+                        "table {} with engine {} is not backed up",
+                        table.name(),
+                        engine
+                    ));
+                    continue;
+                }
+            };
+            tables.push(TableBackup {
+                name: table.name().to_string(),
+                meta: backup_table_meta(&table_info.meta),
+                storage_prefix,
+                snapshot_location,
+            });
+        }
+
+        let manifest = BackupManifest {
+            backup_id: BackupManifest::new_backup_id(),
+            database: self.plan.database.clone(),
+            since: self.plan.since.clone(),
+            created_on: Utc::now(),
+            tables,
+        };
+        manifest.write(&operator).await?;
+        info!(
+            "backup {} of database {} is taken, {} files copied",
+            manifest.backup_id, manifest.database, copied_files
+        );
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![manifest.backup_id.clone()]),
+            UInt64Type::from_data(vec![manifest.tables.len() as u64]),
+            UInt64Type::from_data(vec![copied_files]),
+        ])])
+    }
+}
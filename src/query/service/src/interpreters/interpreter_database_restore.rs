@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CreateDatabaseReq;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::plans::RestoreDatabasePlan;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::operations::BackupManifest;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RestoreDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreDatabasePlan,
+}
+
+impl RestoreDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreDatabasePlan) -> Result<Self> {
+        Ok(RestoreDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RestoreDatabaseInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.clone();
+        let operator = DataOperator::try_create(&self.plan.storage_params).await?;
+        let operator = operator.operator();
+
+        // The latest backup of the database is restored.
+        let manifest = BackupManifest::list(&operator)
+            .await?
+            .into_iter()
+            .rev()
+            .find(|m| m.database == self.plan.database)
+            .ok_or_else(|| {
+                ErrorCode::StorageNotFound(format!(
+                    "no backup of database {} found",
+                    self.plan.database
+                ))
+            })?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let now = Utc::now();
+        let reply = catalog
+            .create_database(CreateDatabaseReq {
+                create_option: CreateOption::CreateIfNotExists(false),
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: self.plan.new_database.clone(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    created_on: now,
+                    updated_on: now,
+                    ..Default::default()
+                },
+            })
+            .await?;
+        let db_id = reply.db_id;
+
+        let role_api = UserApiProvider::instance().get_role_api_client(&tenant)?;
+        let current_role = self.ctx.get_current_role();
+        if let Some(current_role) = &current_role {
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Database {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+        }
+
+        for table_backup in manifest.tables.iter() {
+            let mut table_meta = table_backup.meta.clone();
+            table_meta.created_on = now;
+            table_meta.updated_on = now;
+            if table_meta.engine == "FUSE" {
+                table_meta
+                    .options
+                    .insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+            }
+            let reply = catalog
+                .create_table(CreateTableReq {
+                    create_option: CreateOption::CreateIfNotExists(false),
+                    name_ident: TableNameIdent {
+                        tenant: tenant.clone(),
+                        db_name: self.plan.new_database.clone(),
+                        table_name: table_backup.name.clone(),
+                    },
+                    table_meta,
+                })
+                .await?;
+
+            if let Some(current_role) = &current_role {
+                role_api
+                    .grant_ownership(
+                        &OwnershipObject::Table {
+                            catalog_name: self.plan.catalog.clone(),
+                            db_id,
+                            table_id: reply.table_id,
+                        },
+                        &current_role.name,
+                    )
+                    .await?;
+            }
+
+            if table_backup.snapshot_location.is_some() {
+                let table = catalog
+                    .get_table(tenant.as_str(), &self.plan.new_database, &table_backup.name)
+                    .await?;
+                FuseTable::try_from_table(table.as_ref())?
+                    .do_restore(self.ctx.clone(), &operator, table_backup)
+                    .await?;
+            }
+        }
+        if current_role.is_some() {
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
+        info!(
+            "backup {} of database {} is restored as {}",
+            manifest.backup_id, self.plan.database, self.plan.new_database
+        );
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -58,6 +58,7 @@ use futures_util::TryStreamExt;
 use log::debug;
 
 use crate::interpreters::common::create_push_down_filters;
+use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::locks::LockManager;
@@ -233,6 +234,21 @@ impl Interpreter for DeleteInterpreter {
                 build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
         }
 
+        // The rows deleted can't be aggregated incrementally. If the rows are deleted without
+        // a pipeline, such as by a DELETE without filter, the rollup tables are rebuilt at once.
+        let hook_operator = HookOperator::create(
+            self.ctx.clone(),
+            catalog_name.to_string(),
+            db_name.to_string(),
+            tbl_name.to_string(),
+            "delete".to_string(),
+            // table lock has been added, no need to check.
+            false,
+        );
+        hook_operator
+            .execute_rollup(&mut build_res.main_pipeline, true)
+            .await;
+
         build_res.main_pipeline.add_lock_guard(lock_guard);
 
         Ok(build_res)
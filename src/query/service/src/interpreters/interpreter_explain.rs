@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::FormatTreeNode;
+use databend_common_base::base::convert_byte_size;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -24,7 +25,9 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_pipeline_core::processors::profile::PlanProfile;
+use databend_common_pipeline_core::PipelineRenderer;
 use databend_common_sql::optimizer::ColumnSet;
+use databend_common_sql::optimizer::DefaultCostModel;
 use databend_common_sql::plans::UpdatePlan;
 use databend_common_sql::BindContext;
 use databend_common_sql::InsertInputSource;
@@ -44,6 +47,7 @@ use crate::schedulers::build_query_pipeline;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
+use crate::sql::executor::MemoryEstimation;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::optimizer::SExpr;
@@ -157,6 +161,21 @@ impl Interpreter for ExplainInterpreter {
                 ))?,
             },
 
+            ExplainKind::Memory => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => {
+                    self.explain_memory(s_expr, metadata, bind_context.column_set())
+                        .await?
+                }
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN MEMORY statement",
+                ))?,
+            },
+
             ExplainKind::Pipeline => {
                 // todo:(JackTan25), we need to make all execute2() just do `build pipeline` work,
                 // don't take real actions. for now we fix #13657 like below.
@@ -192,6 +211,56 @@ impl Interpreter for ExplainInterpreter {
                 }
             },
 
+            ExplainKind::Distributed { json } => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => {
+                    self.explain_distributed(
+                        *s_expr.clone(),
+                        metadata.clone(),
+                        bind_context.column_set(),
+                        *json,
+                    )
+                    .await?
+                }
+                _ => {
+                    return Err(ErrorCode::Unimplemented(
+                        "Unsupported EXPLAIN DISTRIBUTED statement",
+                    ));
+                }
+            },
+
+            ExplainKind::Cost { json } => match &self.plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => self.explain_cost(s_expr, metadata, *json)?,
+                _ => {
+                    return Err(ErrorCode::Unimplemented(
+                        "Unsupported EXPLAIN COST statement",
+                    ));
+                }
+            },
+
+            ExplainKind::DescribePipeline { dot } => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => {
+                    self.describe_pipeline(s_expr, metadata, bind_context.column_set(), *dot)
+                        .await?
+                }
+                _ => {
+                    return Err(ErrorCode::Unimplemented(
+                        "Unsupported DESCRIBE PIPELINE statement",
+                    ));
+                }
+            },
+
             ExplainKind::Graph => {
                 return Err(ErrorCode::Unimplemented(
                     "ExplainKind graph is unimplemented",
@@ -321,6 +390,63 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    #[async_backtrace::framed]
+    async fn explain_distributed(
+        &self,
+        s_expr: SExpr,
+        metadata: MetadataRef,
+        required: ColumnSet,
+        json: bool,
+    ) -> Result<Vec<DataBlock>> {
+        let ctx = self.ctx.clone();
+        let plan = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true)
+            .build(&s_expr, required)
+            .await?;
+
+        let root_fragment = Fragmenter::try_create(ctx.clone())?.build_fragment(&plan)?;
+
+        let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
+        root_fragment.get_actions(ctx, &mut fragments_actions)?;
+
+        let summaries = fragments_actions.distributed_summaries(&metadata)?;
+        let formatted_plan = if json {
+            let json = serde_json::to_string_pretty(&serde_json::json!({
+                "fragments": summaries
+            }))?;
+            StringType::from_data(vec![json])
+        } else {
+            let display_string = summaries
+                .iter()
+                .map(|summary| summary.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            StringType::from_data(display_string.lines().collect::<Vec<_>>())
+        };
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
+    fn explain_cost(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        json: bool,
+    ) -> Result<Vec<DataBlock>> {
+        let cluster_peers = self.ctx.get_cluster().nodes.len();
+        let dop = self.ctx.get_settings().get_max_threads()? as usize;
+        let cost_model = DefaultCostModel::new(self.ctx.clone())?
+            .with_cluster_peers(cluster_peers)
+            .with_degree_of_parallelism(dop);
+        let breakdown = cost_model.explain_cost(s_expr, metadata)?;
+
+        let formatted_plan = if json {
+            StringType::from_data(vec![serde_json::to_string_pretty(&breakdown)?])
+        } else {
+            let display_string = breakdown.to_format_tree().format_pretty()?;
+            StringType::from_data(display_string.lines().collect::<Vec<_>>())
+        };
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
     #[async_backtrace::framed]
     async fn explain_update_fragments(&self, update: &UpdatePlan) -> Result<Vec<DataBlock>> {
         let interpreter = UpdateInterpreter::try_create(self.ctx.clone(), update.clone())?;
@@ -340,6 +466,71 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    #[async_backtrace::framed]
+    async fn explain_memory(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        required: ColumnSet,
+    ) -> Result<Vec<DataBlock>> {
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true);
+        let plan = builder.build(s_expr, required).await?;
+
+        let settings = self.ctx.get_settings();
+        let estimation =
+            MemoryEstimation::estimate(&plan, settings.get_max_block_size()? as usize)?;
+        let mut lines = estimation
+            .to_string()
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+
+        let max_memory_usage = settings.get_max_memory_usage()?;
+        if max_memory_usage > 0 && estimation.peak_bytes > max_memory_usage as f64 {
+            lines.push(format!(
+                "warning: the estimated peak memory exceeds max_memory_usage ({})",
+                convert_byte_size(max_memory_usage as f64)
+            ));
+        }
+        Ok(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(lines),
+        ])])
+    }
+
+    #[async_backtrace::framed]
+    async fn describe_pipeline(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        required: ColumnSet,
+        dot: bool,
+    ) -> Result<Vec<DataBlock>> {
+        // The parallelism of the table scans depends on the partitions, so read them
+        // like the query would instead of using the `dry_run` mode.
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+        let plan = builder.build(s_expr, required).await?;
+        let build_res = build_query_pipeline(&self.ctx, &[], &plan, false).await?;
+
+        let mut estimated_rows = HashMap::new();
+        collect_estimated_rows(&plan, &mut estimated_rows);
+        let mut pipelines = vec![&build_res.main_pipeline];
+        pipelines.extend(build_res.sources_pipelines.iter());
+        let renderer = PipelineRenderer::create(pipelines).with_estimated_rows(estimated_rows);
+
+        let lines = if dot {
+            renderer
+                .render_dot()
+                .lines()
+                .map(|line| line.to_string())
+                .collect()
+        } else {
+            renderer.render_text()
+        };
+        Ok(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(lines),
+        ])])
+    }
+
     #[async_backtrace::framed]
     async fn explain_analyze(
         &self,
@@ -432,3 +623,12 @@ impl ExplainInterpreter {
             .await
     }
 }
+
+fn collect_estimated_rows(plan: &PhysicalPlan, estimated_rows: &mut HashMap<u32, f64>) {
+    if let Some(rows) = plan.get_estimated_rows() {
+        estimated_rows.insert(plan.get_id(), rows);
+    }
+    for child in plan.children() {
+        collect_estimated_rows(child, estimated_rows);
+    }
+}
@@ -35,10 +35,14 @@ use crate::interpreters::interpreter_connection_drop::DropConnectionInterpreter;
 use crate::interpreters::interpreter_connection_show::ShowConnectionsInterpreter;
 use crate::interpreters::interpreter_copy_into_location::CopyIntoLocationInterpreter;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
+use crate::interpreters::interpreter_file_format_alter::AlterFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
+use crate::interpreters::interpreter_migration_apply::ApplyMigrationInterpreter;
+use crate::interpreters::interpreter_migration_rollback::RollbackMigrationInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
+use crate::interpreters::interpreter_query_cache_purge::PurgeQueryCacheInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
@@ -114,6 +118,9 @@ impl InterpreterFactory {
                 *plan.clone(),
                 ExplainKind::AnalyzePlan,
             )?)),
+            Plan::RecommendIndex { plan } => Ok(Arc::new(RecommendIndexInterpreter::try_create(
+                *plan.clone(),
+            )?)),
 
             Plan::CopyIntoTable(copy_plan) => Ok(Arc::new(CopyIntoTableInterpreter::try_create(
                 ctx,
@@ -153,6 +160,18 @@ impl InterpreterFactory {
             Plan::RenameDatabase(rename_database) => Ok(Arc::new(
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
+            Plan::BackupDatabase(backup_database) => Ok(Arc::new(
+                BackupDatabaseInterpreter::try_create(ctx, *backup_database.clone())?,
+            )),
+            Plan::RestoreDatabase(restore_database) => Ok(Arc::new(
+                RestoreDatabaseInterpreter::try_create(ctx, *restore_database.clone())?,
+            )),
+            Plan::ShowBackups(show_backups) => Ok(Arc::new(ShowBackupsInterpreter::try_create(
+                *show_backups.clone(),
+            )?)),
+            Plan::RestoreMetasrv(restore_metasrv) => Ok(Arc::new(
+                RestoreMetasrvInterpreter::try_create(*restore_metasrv.clone())?,
+            )),
 
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
@@ -165,6 +184,13 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CreateRollupTable(create_rollup_table) => Ok(Arc::new(
+                CreateRollupTableInterpreter::try_create(ctx, *create_rollup_table.clone())?,
+            )),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -331,6 +357,10 @@ impl InterpreterFactory {
             Plan::CreateStage(create_stage) => Ok(Arc::new(
                 CreateUserStageInterpreter::try_create(ctx, *create_stage.clone())?,
             )),
+            Plan::AlterStage(s) => Ok(Arc::new(AlterUserStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
             Plan::DropStage(s) => Ok(Arc::new(DropUserStageInterpreter::try_create(
                 ctx,
                 *s.clone(),
@@ -344,6 +374,9 @@ impl InterpreterFactory {
             Plan::CreateFileFormat(create_file_format) => Ok(Arc::new(
                 CreateFileFormatInterpreter::try_create(ctx, *create_file_format.clone())?,
             )),
+            Plan::AlterFileFormat(alter_file_format) => Ok(Arc::new(
+                AlterFileFormatInterpreter::try_create(ctx, *alter_file_format.clone())?,
+            )),
             Plan::DropFileFormat(drop_file_format) => Ok(Arc::new(
                 DropFileFormatInterpreter::try_create(ctx, *drop_file_format.clone())?,
             )),
@@ -401,6 +434,9 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ResetMemoryPeak => Ok(Arc::new(ResetMemoryPeakInterpreter::try_create()?)),
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
 
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
@@ -474,6 +510,18 @@ impl InterpreterFactory {
             Plan::ShowNetworkPolicies(_) => {
                 Ok(Arc::new(ShowNetworkPoliciesInterpreter::try_create(ctx)?))
             }
+            Plan::CreateNotificationIntegration(p) => Ok(Arc::new(
+                CreateNotificationIntegrationInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterNotificationIntegration(p) => Ok(Arc::new(
+                AlterNotificationIntegrationInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropNotificationIntegration(p) => Ok(Arc::new(
+                DropNotificationIntegrationInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::ShowNotificationIntegrations(_) => Ok(Arc::new(
+                ShowNotificationIntegrationsInterpreter::try_create(ctx)?,
+            )),
             Plan::CreatePasswordPolicy(p) => Ok(Arc::new(
                 CreatePasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
             )),
@@ -505,6 +553,20 @@ impl InterpreterFactory {
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::ApplyMigration(p) => Ok(Arc::new(ApplyMigrationInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::RollbackMigration(p) => Ok(Arc::new(RollbackMigrationInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            Plan::PurgeQueryCache(p) => Ok(Arc::new(PurgeQueryCacheInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
             Plan::CreateConnection(p) => Ok(Arc::new(CreateConnectionInterpreter::try_create(
                 ctx,
                 *p.clone(),
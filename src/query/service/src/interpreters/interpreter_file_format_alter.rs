@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::AlterFileFormatPlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Replaces the format options of a file format, the queries that are already planned
+/// keep the options they resolved.
+#[derive(Debug)]
+pub struct AlterFileFormatInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterFileFormatPlan,
+}
+
+impl AlterFileFormatInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterFileFormatPlan) -> Result<Self> {
+        Ok(AlterFileFormatInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterFileFormatInterpreter {
+    fn name(&self) -> &str {
+        "AlterFileFormatInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_file_format_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let mut file_format = match user_mgr.get_file_format(&tenant, &plan.name).await {
+            Ok(file_format) => file_format,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_FILE_FORMAT => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+        file_format.file_format_params = plan.file_format_params;
+        user_mgr.update_file_format(&tenant, file_format).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::IndexAdvisor;
+use databend_storages_common_index::BloomIndex;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+
+/// Recommends the indexes for the optimized plan of a query, without running it.
+pub struct RecommendIndexInterpreter {
+    plan: Plan,
+}
+
+impl RecommendIndexInterpreter {
+    pub fn try_create(plan: Plan) -> Result<Self> {
+        Ok(RecommendIndexInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RecommendIndexInterpreter {
+    fn name(&self) -> &str {
+        "RecommendIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = &self.plan
+        else {
+            return Err(ErrorCode::Unimplemented(
+                "Unsupported RECOMMEND INDEX statement",
+            ));
+        };
+
+        let recommendations =
+            IndexAdvisor::new(metadata.clone(), BloomIndex::supported_type).recommend(s_expr)?;
+        if recommendations.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let mut tables = Vec::with_capacity(recommendations.len());
+        let mut kinds = Vec::with_capacity(recommendations.len());
+        let mut statements = Vec::with_capacity(recommendations.len());
+        let mut benefits = Vec::with_capacity(recommendations.len());
+        let mut reasons = Vec::with_capacity(recommendations.len());
+        for recommendation in recommendations {
+            tables.push(recommendation.table);
+            kinds.push(recommendation.kind.to_string());
+            statements.push(recommendation.statement);
+            benefits.push(format!(
+                "{:.2}% rows skipped",
+                recommendation.estimated_benefit * 100.0
+            ));
+            reasons.push(recommendation.reason);
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(tables),
+            StringType::from_data(kinds),
+            StringType::from_data(statements),
+            StringType::from_data(benefits),
+            StringType::from_data(reasons),
+        ])])
+    }
+}
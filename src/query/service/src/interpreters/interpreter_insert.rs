@@ -254,6 +254,9 @@ impl Interpreter for InsertInterpreter {
                         "insert_into_table".to_owned(),
                         true,
                     );
+                    hook_operator
+                        .execute_rollup(&mut build_res.main_pipeline, self.plan.overwrite)
+                        .await;
                     hook_operator.execute(&mut build_res.main_pipeline).await;
                 }
 
@@ -289,6 +292,9 @@ impl Interpreter for InsertInterpreter {
                 "insert_into_table".to_owned(),
                 true,
             );
+            hook_operator
+                .execute_rollup(&mut build_res.main_pipeline, self.plan.overwrite)
+                .await;
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
@@ -110,6 +110,10 @@ impl Interpreter for MergeIntoInterpreter {
                 "merge_into".to_owned(),
                 true,
             );
+            // The rows updated or deleted can't be aggregated incrementally.
+            hook_operator
+                .execute_rollup(&mut build_res.main_pipeline, true)
+                .await;
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
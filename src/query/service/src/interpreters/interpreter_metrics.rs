@@ -113,6 +113,9 @@ impl InterpreterMetrics {
     pub fn record_query_start(ctx: &QueryContext) {
         let labels = Self::common_labels(ctx);
         QUERY_START.get_or_create(&labels).inc();
+        QUERY_RECEIVED_BYTES
+            .get_or_create(&labels)
+            .inc_by(ctx.get_query_str().len() as u64);
     }
 
     pub fn record_query_finished(ctx: &QueryContext, err: Option<ErrorCode>) {
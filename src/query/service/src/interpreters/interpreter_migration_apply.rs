@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Migration;
+use databend_common_sql::plans::ApplyMigrationPlan;
+use databend_common_storage::init_stage_read_operator;
+use databend_common_users::UserApiProvider;
+use log::info;
+
+use crate::interpreters::common::run_migration_statements;
+use crate::interpreters::common::split_statements;
+use crate::interpreters::common::MigrationScript;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct ApplyMigrationInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ApplyMigrationPlan,
+}
+
+impl ApplyMigrationInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ApplyMigrationPlan) -> Result<Self> {
+        Ok(ApplyMigrationInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ApplyMigrationInterpreter {
+    fn name(&self) -> &str {
+        "ApplyMigrationInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let name = &self.plan.name;
+
+        let operator = init_stage_read_operator(&self.plan.stage)?;
+        let script = String::from_utf8(operator.read(&self.plan.path).await?)?;
+        let script = MigrationScript::parse(&script);
+
+        let user_api = UserApiProvider::instance();
+        if let Some(migration) = user_api.get_migration(&tenant, name).await? {
+            if migration.checksum == script.checksum {
+                info!("migration '{}' is already applied, skip it", name);
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::IllegalMigration(format!(
+                "Migration '{}' is already applied with checksum {}, but the script has been changed to checksum {}.",
+                name, migration.checksum, script.checksum
+            )));
+        }
+
+        let statements = split_statements(script.apply)?;
+        // Check the rollback statements can be split before anything is applied.
+        if let Some(rollback) = script.rollback {
+            split_statements(rollback)?;
+        }
+        run_migration_statements(&self.ctx, name, &statements).await?;
+
+        let migration = Migration {
+            name: name.clone(),
+            checksum: script.checksum,
+            rollback_script: script.rollback.map(str::to_string),
+            applied_at: Utc::now(),
+        };
+        user_api.add_migration(&tenant, migration).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::RollbackMigrationPlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::common::run_migration_statements;
+use crate::interpreters::common::split_statements;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct RollbackMigrationInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RollbackMigrationPlan,
+}
+
+impl RollbackMigrationInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RollbackMigrationPlan) -> Result<Self> {
+        Ok(RollbackMigrationInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RollbackMigrationInterpreter {
+    fn name(&self) -> &str {
+        "RollbackMigrationInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let name = &self.plan.name;
+
+        let user_api = UserApiProvider::instance();
+        let migrations = user_api.get_migrations(&tenant).await?;
+        // The migrations are rolled back in the reverse order they were applied, as a migration
+        // may depend on the ones before it.
+        let migration = match migrations.last() {
+            Some(last) if &last.name == name => last,
+            Some(last) if migrations.iter().any(|m| &m.name == name) => {
+                return Err(ErrorCode::IllegalMigration(format!(
+                    "Migration '{}' can not be rolled back before the last applied migration '{}'.",
+                    name, last.name
+                )));
+            }
+            _ => {
+                return Err(ErrorCode::UnknownMigration(format!(
+                    "Migration '{}' is not applied.",
+                    name
+                )));
+            }
+        };
+        let Some(rollback) = &migration.rollback_script else {
+            return Err(ErrorCode::IllegalMigration(format!(
+                "Migration '{}' has no statements to roll back after the '--/+' line.",
+                name
+            )));
+        };
+
+        let statements = split_statements(rollback)?;
+        run_migration_statements(&self.ctx, name, &statements).await?;
+        user_api.drop_migration(&tenant, name).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_sql::plans::CreateNotificationIntegrationPlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateNotificationIntegrationInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateNotificationIntegrationPlan,
+}
+
+impl CreateNotificationIntegrationInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: CreateNotificationIntegrationPlan,
+    ) -> Result<Self> {
+        Ok(CreateNotificationIntegrationInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateNotificationIntegrationInterpreter {
+    fn name(&self) -> &str {
+        "CreateNotificationIntegrationInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_notification_integration_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let integration = NotificationIntegration {
+            name: plan.name,
+            notification_type: plan.notification_type,
+            url: plan.url,
+            events: plan.events,
+            enabled: plan.enabled,
+            comment: plan.comment,
+            create_on: Utc::now(),
+            update_on: None,
+        };
+        user_mgr
+            .add_notification_integration(&tenant, integration, &plan.create_option)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
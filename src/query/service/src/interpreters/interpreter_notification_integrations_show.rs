@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowNotificationIntegrationsInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowNotificationIntegrationsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowNotificationIntegrationsInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowNotificationIntegrationsInterpreter {
+    fn name(&self) -> &str {
+        "ShowNotificationIntegrationsInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let mut integrations = user_mgr.get_notification_integrations(&tenant).await?;
+        integrations.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut names = Vec::with_capacity(integrations.len());
+        let mut types = Vec::with_capacity(integrations.len());
+        let mut urls = Vec::with_capacity(integrations.len());
+        let mut events = Vec::with_capacity(integrations.len());
+        let mut enabled = Vec::with_capacity(integrations.len());
+        let mut comments = Vec::with_capacity(integrations.len());
+        for integration in integrations {
+            names.push(integration.name);
+            types.push(integration.notification_type.to_string());
+            urls.push(integration.url);
+            events.push(
+                integration
+                    .events
+                    .iter()
+                    .map(|event| event.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+            enabled.push(integration.enabled);
+            comments.push(integration.comment);
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_data(urls),
+            StringType::from_data(events),
+            BooleanType::from_data(enabled),
+            StringType::from_data(comments),
+        ])])
+    }
+}
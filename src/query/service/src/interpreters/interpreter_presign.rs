@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -62,6 +63,7 @@ impl Interpreter for PresignInterpreter {
             ));
         }
 
+        let expires_at = Utc::now().timestamp_micros() + self.plan.expire.as_micros() as i64;
         let presigned_req = match self.plan.action {
             PresignAction::Download => op.presign_read(&self.plan.path, self.plan.expire).await?,
             PresignAction::Upload => {
@@ -105,6 +107,10 @@ impl Interpreter for PresignInterpreter {
                     DataType::String,
                     Value::Scalar(Scalar::String(presigned_req.uri().to_string())),
                 ),
+                BlockEntry::new(
+                    DataType::Timestamp,
+                    Value::Scalar(Scalar::Timestamp(expires_at)),
+                ),
             ],
             1,
         );
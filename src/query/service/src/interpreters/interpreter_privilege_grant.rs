@@ -100,7 +100,7 @@ impl GrantPrivilegeInterpreter {
             GrantObject::UDF(name) => Ok(OwnershipObject::UDF {
                 name: name.to_string(),
             }),
-            GrantObject::Global => Err(ErrorCode::IllegalGrant(
+            GrantObject::Global | GrantObject::Column(_, _, _, _) => Err(ErrorCode::IllegalGrant(
                 "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
             )),
         }
@@ -174,8 +174,10 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         let plan = self.plan.clone();
 
-        validate_grant_privileges(&plan.on, plan.priv_types)?;
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in &plan.on {
+            validate_grant_privileges(object, plan.priv_types)?;
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
@@ -185,27 +187,32 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         match plan.principal {
             PrincipalIdentity::User(user) => {
-                user_mgr
-                    .grant_privileges_to_user(&tenant, user, plan.on, plan.priv_types)
-                    .await?;
+                for object in plan.on {
+                    user_mgr
+                        .grant_privileges_to_user(&tenant, user.clone(), object, plan.priv_types)
+                        .await?;
+                }
             }
             PrincipalIdentity::Role(role) => {
                 if plan.priv_types.has_privilege(Ownership) && plan.priv_types.len() == 1 {
-                    let owner_object = self
-                        .convert_to_ownerobject(&tenant, &plan.on, plan.on.catalog())
-                        .await?;
-                    if self.ctx.get_current_role().is_some() {
-                        self.grant_ownership(&self.ctx, &tenant, &owner_object, &role)
-                            .await?;
-                    } else {
+                    if self.ctx.get_current_role().is_none() {
                         return Err(databend_common_exception::ErrorCode::UnknownRole(
                             "No current role, cannot grant ownership",
                         ));
                     }
+                    for object in &plan.on {
+                        let owner_object = self
+                            .convert_to_ownerobject(&tenant, object, object.catalog())
+                            .await?;
+                        self.grant_ownership(&self.ctx, &tenant, &owner_object, &role)
+                            .await?;
+                    }
                 } else {
-                    user_mgr
-                        .grant_privileges_to_role(&tenant, &role, plan.on, plan.priv_types)
-                        .await?;
+                    for object in plan.on {
+                        user_mgr
+                            .grant_privileges_to_role(&tenant, &role, object, plan.priv_types)
+                            .await?;
+                    }
                     RoleCacheManager::instance().invalidate_cache(&tenant);
                 }
             }
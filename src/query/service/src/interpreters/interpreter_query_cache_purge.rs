@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::plans::PurgeQueryCachePlan;
+use databend_common_storages_result_cache::ResultCacheManager;
+use databend_common_users::UserApiProvider;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct PurgeQueryCacheInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: PurgeQueryCachePlan,
+}
+
+impl PurgeQueryCacheInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: PurgeQueryCachePlan) -> Result<Self> {
+        Ok(PurgeQueryCacheInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for PurgeQueryCacheInterpreter {
+    fn name(&self) -> &str {
+        "PurgeQueryCacheInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let kv_store = UserApiProvider::instance().get_meta_store_client();
+        let manager = ResultCacheManager::create(kv_store, &self.ctx.get_tenant());
+
+        let now = Utc::now().timestamp() as u64;
+        let purged = manager
+            .purge(|entry| {
+                let table_matched = match &self.plan.table {
+                    Some(table) => entry.value.tables.contains(table),
+                    None => true,
+                };
+                let older = match self.plan.older_than_secs {
+                    Some(secs) => entry.value.query_time + secs <= now,
+                    None => true,
+                };
+                table_matched && older
+            })
+            .await?;
+        info!("Purged {} query result caches", purged);
+        Ok(PipelineBuildResult::create())
+    }
+}
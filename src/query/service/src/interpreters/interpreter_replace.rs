@@ -109,6 +109,10 @@ impl Interpreter for ReplaceInterpreter {
                 "replace_into".to_owned(),
                 true,
             );
+            // The rows replaced can't be aggregated incrementally.
+            hook_operator
+                .execute_rollup(&mut pipeline.main_pipeline, true)
+                .await;
             hook_operator.execute(&mut pipeline.main_pipeline).await;
         }
 
@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::runtime::MemoryUsageRegistry;
+use databend_common_exception::Result;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+
+/// Resets the `peak_bytes` of `system.memory_usage` on the node that runs the statement.
+pub struct ResetMemoryPeakInterpreter;
+
+impl ResetMemoryPeakInterpreter {
+    pub fn try_create() -> Result<Self> {
+        Ok(ResetMemoryPeakInterpreter)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ResetMemoryPeakInterpreter {
+    fn name(&self) -> &str {
+        "ResetMemoryPeakInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        MemoryUsageRegistry::instance().reset_peak();
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::merge_changelog;
+use databend_common_meta_types::parse_changelog_file_name;
+use databend_common_meta_types::ChangelogEntry;
+use databend_common_meta_types::SeqV;
+use databend_common_sql::plans::RestoreMetasrvPlan;
+use databend_common_storage::DataOperator;
+use databend_common_users::UserApiProvider;
+use log::info;
+use opendal::Operator;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+
+pub struct RestoreMetasrvInterpreter {
+    plan: RestoreMetasrvPlan,
+}
+
+impl RestoreMetasrvInterpreter {
+    pub fn try_create(plan: RestoreMetasrvPlan) -> Result<Self> {
+        Ok(RestoreMetasrvInterpreter { plan })
+    }
+}
+
+/// Read the changelog entries exported by every node.
+///
+/// Every node of a metasrv cluster exports the logs it applies to `<node_id>/`. A node may miss
+/// the logs before the snapshot it installs, and may export a log twice if it is re-applied
+/// after a restart, the entries are merged by the log index with [`merge_changelog`].
+async fn read_changelog(operator: &Operator) -> Result<Vec<ChangelogEntry>> {
+    let mut entries = vec![];
+    for dir in operator.list("/").await? {
+        if !dir.metadata().is_dir() {
+            continue;
+        }
+
+        let mut paths = vec![];
+        for entry in operator.list(dir.path()).await? {
+            if let Some((first_index, _)) = parse_changelog_file_name(entry.name()) {
+                paths.push((entry.path().to_string(), first_index));
+            }
+        }
+        paths.sort_by_key(|(_, first_index)| *first_index);
+
+        for (path, _) in paths {
+            let data = operator.read(&path).await?;
+            for line in data.split(|b| *b == b'\n').filter(|l| !l.is_empty()) {
+                entries.push(serde_json::from_slice::<ChangelogEntry>(line)?);
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Returns true if the key is written by a query node when it starts with a new metasrv:
+/// the node info, the built-in role `public`, the database `default` and the id generators.
+///
+/// The keys with an expiration time, such as the node info and the locks, are not restored data.
+fn is_bootstrap_key(key: &str, seqv: &SeqV) -> bool {
+    if seqv
+        .meta
+        .as_ref()
+        .and_then(|m| m.get_expire_at_ms())
+        .is_some()
+    {
+        return true;
+    }
+
+    let mut parts = key.split('/');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some("__fd_clusters" | "__fd_id_gen"), ..) => true,
+        (Some("__fd_database_by_id" | "__fd_database_id_to_name"), ..) => true,
+        (Some("__fd_roles"), Some(_tenant), Some("public"), None) => true,
+        (Some("__fd_database" | "__fd_db_id_list"), Some(_tenant), Some("default"), None) => true,
+        _ => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreMetasrvInterpreter {
+    fn name(&self) -> &str {
+        "RestoreMetasrvInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let operator = DataOperator::try_create(&self.plan.storage_params).await?;
+        let operator = operator.operator();
+        let until_ms = self.plan.timestamp.timestamp_millis() as u64;
+
+        let meta = UserApiProvider::instance().get_meta_store_client();
+
+        // Replaying onto existing data mixes them up, and the result is neither of them.
+        let existing = meta.prefix_list_kv("").await?;
+        if let Some((key, _)) = existing
+            .iter()
+            .find(|(key, seqv)| !is_bootstrap_key(key, seqv))
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "the metasrv to restore into is not empty, it contains key: {}",
+                key
+            )));
+        }
+
+        let entries = read_changelog(&operator).await?;
+        if entries.is_empty() {
+            return Err(ErrorCode::StorageNotFound(
+                "no changelog of metasrv found in the location",
+            ));
+        }
+        let entries = merge_changelog(entries).map_err(|e| {
+            ErrorCode::StorageOther(format!("can not restore metasrv from the changelog: {}", e))
+        })?;
+
+        let mut restored_logs = 0;
+        let mut restored_changes = 0;
+        for entry in entries {
+            // The logs that have no time, such as the membership changes, are stamped with 0.
+            if entry.time_ms > until_ms {
+                break;
+            }
+            for change in entry.changes.iter() {
+                meta.upsert_kv(change.to_upsert_kv()).await?;
+                restored_changes += 1;
+            }
+            restored_logs += 1;
+        }
+
+        info!(
+            "restored {} changes of {} logs of metasrv until {}",
+            restored_changes, restored_logs, self.plan.timestamp
+        );
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateRollupTablePlan;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_TABLES;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::hook::rollup_hook::rebuild_rollup;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreateRollupTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateRollupTablePlan,
+}
+
+impl CreateRollupTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateRollupTablePlan) -> Result<Self> {
+        Ok(CreateRollupTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateRollupTableInterpreter {
+    fn name(&self) -> &str {
+        "CreateRollupTableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let create_table = &self.plan.create_table;
+
+        // Create the rollup table, the returned pipeline back-fills it from
+        // the snapshot of the source table which is pinned while binding.
+        let mut build_res =
+            CreateTableInterpreter::try_create(self.ctx.clone(), create_table.clone())?
+                .execute2()
+                .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&create_table.catalog).await?;
+        let rollup_table = catalog
+            .get_table(tenant.as_str(), &create_table.database, &create_table.table)
+            .await?;
+
+        // `IF NOT EXISTS` hits a table which is not this rollup, leave the source table untouched.
+        if rollup_table.options().get(OPT_KEY_ROLLUP_QUERY)
+            != create_table.options.get(OPT_KEY_ROLLUP_QUERY)
+        {
+            return Ok(build_res);
+        }
+
+        // Register the rollup table to the source table, so that the following
+        // insertions into the source table will refresh it in the hook.
+        let source_table = catalog
+            .get_table(
+                tenant.as_str(),
+                &self.plan.source_database,
+                &self.plan.source_table,
+            )
+            .await?;
+        let rollup_name = format!("{}.{}", create_table.database, create_table.table);
+        let mut rollup_tables = source_table
+            .options()
+            .get(OPT_KEY_ROLLUP_TABLES)
+            .map(|tables| tables.split(',').map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if !rollup_tables.contains(&rollup_name) {
+            rollup_tables.push(rollup_name);
+
+            let mut options = HashMap::new();
+            options.insert(
+                OPT_KEY_ROLLUP_TABLES.to_string(),
+                Some(rollup_tables.join(",")),
+            );
+            let req = UpsertTableOptionReq {
+                table_id: source_table.get_id(),
+                seq: MatchSeq::Exact(source_table.get_table_info().ident.seq),
+                options,
+            };
+            catalog
+                .upsert_table_option(tenant.as_str(), &self.plan.source_database, req)
+                .await?;
+        }
+
+        // The rows inserted into the source table after the back-filled snapshot was pinned
+        // and before the rollup table was registered refresh no rollup, so the rollup table is
+        // rebuilt after the back-fill if the source table has changed.
+        let pinned_table = self
+            .ctx
+            .get_table(
+                &create_table.catalog,
+                &self.plan.source_database,
+                &self.plan.source_table,
+            )
+            .await?;
+        let source_table = catalog
+            .get_table(
+                tenant.as_str(),
+                &self.plan.source_database,
+                &self.plan.source_table,
+            )
+            .await?;
+        if pinned_table.options().get(OPT_KEY_SNAPSHOT_LOCATION)
+            != source_table.options().get(OPT_KEY_SNAPSHOT_LOCATION)
+        {
+            let ctx = self.ctx.clone();
+            let catalog = create_table.catalog.clone();
+            let database = create_table.database.clone();
+            let table = create_table.table.clone();
+            if build_res.main_pipeline.is_empty() {
+                rebuild_rollup(ctx, &catalog, &database, &table).await?;
+            } else {
+                build_res.main_pipeline.set_on_finished(move |may_error| {
+                    if may_error.is_ok() {
+                        GlobalIORuntime::instance().block_on(async move {
+                            rebuild_rollup(ctx, &catalog, &database, &table).await
+                        })?;
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        Ok(build_res)
+    }
+}
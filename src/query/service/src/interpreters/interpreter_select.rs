@@ -35,6 +35,7 @@ use databend_common_storages_result_cache::gen_result_cache_key;
 use databend_common_storages_result_cache::ResultCacheReader;
 use databend_common_storages_result_cache::WriteResultCacheSink;
 use databend_common_users::UserApiProvider;
+use itertools::Itertools;
 use log::error;
 use log::info;
 
@@ -172,6 +173,7 @@ impl SelectInterpreter {
                 self.ctx.clone(),
                 key,
                 schema,
+                self.read_table_names(),
                 sink_inputs.clone(),
                 kv_store,
             )?,
@@ -201,6 +203,17 @@ impl SelectInterpreter {
         Ok(None)
     }
 
+    /// The tables read by the query, as `<database>.<table>`.
+    fn read_table_names(&self) -> Vec<String> {
+        let metadata = self.metadata.read();
+        metadata
+            .tables()
+            .iter()
+            .map(|table| format!("{}.{}", table.database(), table.name()))
+            .unique()
+            .collect()
+    }
+
     fn attach_tables_to_ctx(&self) {
         let metadata = self.metadata.read();
         for table in metadata.tables() {
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::ShowBackupsPlan;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::operations::BackupManifest;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+
+pub struct ShowBackupsInterpreter {
+    plan: ShowBackupsPlan,
+}
+
+impl ShowBackupsInterpreter {
+    pub fn try_create(plan: ShowBackupsPlan) -> Result<Self> {
+        Ok(ShowBackupsInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowBackupsInterpreter {
+    fn name(&self) -> &str {
+        "ShowBackupsInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let operator = DataOperator::try_create(&self.plan.storage_params).await?;
+        let manifests = BackupManifest::list(&operator.operator()).await?;
+
+        let mut backup_ids = Vec::with_capacity(manifests.len());
+        let mut databases = Vec::with_capacity(manifests.len());
+        let mut sinces = Vec::with_capacity(manifests.len());
+        let mut tables = Vec::with_capacity(manifests.len());
+        let mut created_ons = Vec::with_capacity(manifests.len());
+        for manifest in manifests {
+            backup_ids.push(manifest.backup_id);
+            databases.push(manifest.database);
+            sinces.push(manifest.since);
+            tables.push(manifest.tables.len() as u64);
+            created_ons.push(manifest.created_on.timestamp_micros());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(backup_ids),
+            StringType::from_data(databases),
+            StringType::from_opt_data(sinces),
+            UInt64Type::from_data(tables),
+            TimestampType::from_data(created_ons),
+        ])])
+    }
+}
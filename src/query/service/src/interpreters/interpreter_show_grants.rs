@@ -100,6 +100,17 @@ impl Interpreter for ShowGrantsInterpreter {
                         &privileges_str, catalog_name, db_name, table_name, identity
                     ));
                 }
+                // Column-level grants are listed in separate rows from the table-level grants.
+                GrantObject::Column(catalog_name, db_id, table_id, column) => {
+                    let privileges: UserPrivilegeSet = (*grant_entry.privileges()).into();
+                    let catalog = self.ctx.get_catalog(catalog_name).await?;
+                    let db_name = catalog.get_db_name_by_id(*db_id).await?;
+                    let table_name = catalog.get_table_name_by_id(*table_id).await?;
+                    grant_list.push(format!(
+                        "GRANT {} ({}) ON '{}'.'{}'.'{}' TO {}",
+                        privileges, column, catalog_name, db_name, table_name, identity
+                    ));
+                }
                 GrantObject::DatabaseById(catalog_name, db_id) => {
                     let privileges_str = if grant_entry.has_all_available_privileges() {
                         "ALL".to_string()
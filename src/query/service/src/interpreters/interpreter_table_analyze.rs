@@ -12,16 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnId;
+use databend_common_expression::DataBlock;
+use databend_common_sql::plans::AnalyzeColumnMethod;
 use databend_common_sql::plans::AnalyzeTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_index::Index;
+use databend_storages_common_index::RangeIndex;
+use futures::TryStreamExt;
 
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sql::Planner;
 
 pub struct AnalyzeTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -32,6 +44,58 @@ impl AnalyzeTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: AnalyzeTablePlan) -> Result<Self> {
         Ok(AnalyzeTableInterpreter { ctx, plan })
     }
+
+    /// Computes the NDV of a column, exactly or from a sample of the rows.
+    #[async_backtrace::framed]
+    async fn column_ndv(
+        &self,
+        column: &str,
+        method: AnalyzeColumnMethod,
+        total_rows: u64,
+    ) -> Result<u64> {
+        let table = format!(
+            "`{}`.`{}`.`{}`",
+            self.plan.catalog, self.plan.database, self.plan.table
+        );
+        match method {
+            AnalyzeColumnMethod::Full | AnalyzeColumnMethod::Auto => {
+                let sql = format!("SELECT count(DISTINCT `{column}`) FROM {table}");
+                let blocks = self.execute_query(&sql).await?;
+                Ok(get_u64(&blocks, 0))
+            }
+            AnalyzeColumnMethod::Sample { percent } => {
+                let sql = format!(
+                    "SELECT sum(cnt), count(), count_if(cnt = 1) FROM \
+                    (SELECT `{column}`, count() AS cnt FROM {table} \
+                    WHERE `{column}` IS NOT NULL AND rand() < {} GROUP BY `{column}`)",
+                    percent as f64 / 100.0
+                );
+                let blocks = self.execute_query(&sql).await?;
+                let sample_rows = get_u64(&blocks, 0);
+                let sample_ndv = get_u64(&blocks, 1);
+                let singletons = get_u64(&blocks, 2);
+                Ok(estimate_ndv(
+                    sample_rows,
+                    sample_ndv,
+                    singletons,
+                    total_rows,
+                ))
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_query(&self, sql: &str) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        interpreter
+            .execute(ctx)
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -51,7 +115,76 @@ impl Interpreter for AnalyzeTableInterpreter {
         // check mutability
         table.check_mutable()?;
 
-        table.analyze(self.ctx.clone()).await?;
-        return Ok(PipelineBuildResult::create());
+        let Some(columns) = &plan.columns else {
+            table.analyze(self.ctx.clone()).await?;
+            return Ok(PipelineBuildResult::create());
+        };
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+            return Ok(PipelineBuildResult::create());
+        };
+        let total_rows = snapshot.summary.row_count;
+        let threshold = self
+            .ctx
+            .get_settings()
+            .get_statistics_auto_sample_threshold()?;
+        let table_bytes = snapshot.summary.compressed_byte_size;
+
+        let schema = table.schema();
+        let mut tasks = Vec::with_capacity(columns.len());
+        for (column, method) in columns {
+            let field = schema.field_with_name(column)?;
+            // Only the columns that have the min/max statistics have the NDV.
+            if !RangeIndex::supported_type(&DataType::from(field.data_type())) {
+                continue;
+            }
+            let method = match method {
+                AnalyzeColumnMethod::Auto if threshold > 0 && table_bytes > threshold => {
+                    // Read about `threshold` bytes of the table.
+                    let percent = (threshold as f64 * 100.0 / table_bytes as f64).ceil() as u64;
+                    AnalyzeColumnMethod::Sample {
+                        percent: percent.clamp(1, 100),
+                    }
+                }
+                method => method.clone(),
+            };
+            let column_id: ColumnId = field.column_id();
+            tasks.push(async move {
+                let ndv = self.column_ndv(column, method, total_rows).await?;
+                Ok::<_, ErrorCode>((column_id, ndv))
+            });
+        }
+        let column_ndvs = futures::future::try_join_all(tasks)
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        fuse_table.do_analyze_with_ndvs(&ctx, column_ndvs).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+fn get_u64(blocks: &[DataBlock], offset: usize) -> u64 {
+    blocks
+        .iter()
+        .find(|block| block.num_rows() > 0)
+        .and_then(|block| block.get_by_offset(offset).value.index(0))
+        .and_then(|value| value.as_number().and_then(|n| n.as_u_int64().copied()))
+        .unwrap_or_default()
+}
+
+/// Estimates the NDV of the table from the NDV of a sample with the Duj1 estimator of
+/// Haas and Stokes: `n * d / (n - f1 + f1 * n / N)`, `f1` is the number of the values that
+/// appear only once in the sample of `n` rows.
+fn estimate_ndv(sample_rows: u64, sample_ndv: u64, singletons: u64, total_rows: u64) -> u64 {
+    if sample_rows == 0 || total_rows == 0 {
+        return 0;
     }
+    let n = sample_rows as f64;
+    let d = sample_ndv as f64;
+    let f1 = singletons as f64;
+    let estimated = n * d / (n - f1 + f1 * n / total_rows as f64);
+    (estimated as u64).min(total_rows).max(sample_ndv)
 }
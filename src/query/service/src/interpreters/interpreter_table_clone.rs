@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CloneTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONED_SNAPSHOT_LOCATIONS;
+use uuid::Uuid;
+
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let source_catalog = self.ctx.get_catalog(&self.plan.source_catalog).await?;
+        let mut source_table = source_catalog
+            .get_table(
+                tenant.as_str(),
+                &self.plan.source_database,
+                &self.plan.source_table,
+            )
+            .await?;
+        if let Some(snapshot_id) = &self.plan.snapshot_id {
+            source_table = source_table
+                .navigate_to(&NavigationPoint::SnapshotID(snapshot_id.clone()))
+                .await?;
+        }
+        let source_fuse_table = FuseTable::try_from_table(source_table.as_ref())?;
+        let snapshot_location = source_fuse_table.snapshot_loc().await?;
+        let snapshot = source_fuse_table.read_table_snapshot().await?;
+
+        // Register the snapshot to the source table before the clone refers to it, so that the
+        // files referenced by it are not purged by the source table.
+        if let Some(location) = &snapshot_location {
+            let mut locations = source_table
+                .options()
+                .get(OPT_KEY_CLONED_SNAPSHOT_LOCATIONS)
+                .map(|locations| locations.split(',').map(str::to_string).collect::<Vec<_>>())
+                .unwrap_or_default();
+            if !locations.contains(location) {
+                locations.push(location.clone());
+
+                let mut options = HashMap::new();
+                options.insert(
+                    OPT_KEY_CLONED_SNAPSHOT_LOCATIONS.to_string(),
+                    Some(locations.join(",")),
+                );
+                let req = UpsertTableOptionReq {
+                    table_id: source_table.get_id(),
+                    seq: MatchSeq::Exact(source_table.get_table_info().ident.seq),
+                    options,
+                };
+                source_catalog
+                    .upsert_table_option(tenant.as_str(), &self.plan.source_database, req)
+                    .await?;
+            }
+        }
+
+        let create_table = &self.plan.create_table;
+        let build_res = CreateTableInterpreter::try_create(self.ctx.clone(), create_table.clone())?
+            .execute2()
+            .await?;
+        let Some(snapshot) = snapshot else {
+            return Ok(build_res);
+        };
+
+        // The snapshot chain of the clone starts from a copy of the cloned snapshot, which is
+        // written under the prefix of the clone and refers to the segments of the source table.
+        let catalog = self.ctx.get_catalog(&create_table.catalog).await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &create_table.database, &create_table.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &snapshot.timestamp,
+            None,
+            fuse_table.schema().as_ref().clone(),
+            snapshot.summary.clone(),
+            snapshot.segments.clone(),
+            fuse_table.cluster_key_meta(),
+            snapshot.table_statistics_location.clone(),
+        );
+        FuseTable::commit_to_meta_server(
+            self.ctx.as_ref(),
+            fuse_table.get_table_info(),
+            fuse_table.meta_location_generator(),
+            new_snapshot,
+            None,
+            &None,
+            fuse_table.get_operator_ref(),
+        )
+        .await?;
+
+        Ok(build_res)
+    }
+}
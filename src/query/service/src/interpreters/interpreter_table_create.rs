@@ -58,6 +58,8 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_SYNC_REBUILD;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -346,6 +348,7 @@ impl CreateTableInterpreter {
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_rollup_sync_rebuild(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -456,6 +459,8 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_ROLLUP_QUERY);
+    r.insert(OPT_KEY_ROLLUP_SYNC_REBUILD);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -526,3 +531,10 @@ pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()
     }
     Ok(())
 }
+
+pub fn is_valid_rollup_sync_rebuild(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ROLLUP_SYNC_REBUILD) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
@@ -263,6 +263,7 @@ impl ModifyTableColumnInterpreter {
                         )));
                     }
                     new_schema.fields[i].data_type = data_type.clone();
+                    new_schema.fields[i].declared_length = field.declared_length;
                     table_info.meta.field_comments[i] = comment.to_string();
                 }
             } else {
@@ -323,6 +324,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: old_data_type,
                         column_id: old_column_id,
                         computed_expr: old_computed_expr,
+                        declared_length: _,
                     } = old_field;
                     let TableField {
                         name: new_name,
@@ -330,6 +332,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: new_data_type,
                         column_id: new_column_id,
                         computed_expr: new_computed_expr,
+                        declared_length: _,
                     } = new_field;
                     old_name == new_name
                         && old_default_expr == new_default_expr
@@ -23,6 +23,7 @@ use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use log::error;
 
@@ -30,6 +31,7 @@ use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_rollup_sync_rebuild;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -61,6 +63,7 @@ impl Interpreter for SetOptionsInterpreter {
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
         is_valid_change_tracking(&self.plan.set_options)?;
+        is_valid_rollup_sync_rebuild(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -77,6 +80,13 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_DATABASE_ID
             )));
         }
+        if self.plan.set_options.get(OPT_KEY_ROLLUP_QUERY).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_ROLLUP_QUERY
+            )));
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -22,6 +22,7 @@ use databend_common_expression::BlockEntry;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
 use databend_common_expression::Value;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
@@ -83,11 +84,25 @@ impl ShowCreateTableInterpreter {
         let schema = table.schema();
         let field_comments = table.field_comments();
         let n_fields = schema.fields().len();
+        let settings = self.ctx.get_settings();
 
-        let mut table_create_sql = format!("CREATE TABLE `{}` (\n", name);
-        if table.options().contains_key("TRANSIENT") {
-            table_create_sql = format!("CREATE TRANSIENT TABLE `{}` (\n", name)
-        }
+        let transient = if table.options().contains_key("TRANSIENT") {
+            "TRANSIENT "
+        } else {
+            ""
+        };
+        let if_not_exists = if settings.get_show_create_table_if_not_exists()? {
+            " IF NOT EXISTS"
+        } else {
+            ""
+        };
+        let table_name = if settings.get_show_create_table_qualified()? {
+            format!("`{}`.`{}`", self.plan.database, name)
+        } else {
+            format!("`{}`", name)
+        };
+        let mut table_create_sql =
+            format!("CREATE {transient}TABLE{if_not_exists} {table_name} (\n");
 
         // Append columns.
         {
@@ -120,15 +135,25 @@ impl ShowCreateTableInterpreter {
                     // can not use debug print, will add double quote
                     format!(
                         " COMMENT '{}'",
-                        &field_comments[idx].as_str().replace('\'', "\\'")
+                        &field_comments[idx]
+                            .as_str()
+                            .replace('\\', "\\\\")
+                            .replace('\'', "\\'")
                     )
                 } else {
                     "".to_string()
                 };
+                let data_type = field.data_type().remove_recursive_nullable();
+                let sql_type = match field.declared_length() {
+                    Some(length) if matches!(data_type, TableDataType::String) => {
+                        format!("{}({length})", data_type.sql_name())
+                    }
+                    _ => data_type.sql_name(),
+                };
                 let column = format!(
                     "  `{}` {}{}{}{}{}",
                     field.name(),
-                    field.data_type().remove_recursive_nullable().sql_name(),
+                    sql_type,
                     nullable,
                     default_expr,
                     computed_expr,
@@ -154,7 +179,6 @@ impl ShowCreateTableInterpreter {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
 
-        let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
             .get_hide_options_in_show_create_table()
             .unwrap_or(false);
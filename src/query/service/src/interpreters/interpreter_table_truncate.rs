@@ -21,6 +21,7 @@ use databend_common_sql::plans::TruncateTablePlan;
 
 use crate::api::Packet;
 use crate::api::TruncateTablePacket;
+use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -93,6 +94,22 @@ impl Interpreter for TruncateTableInterpreter {
         }
 
         table.truncate(self.ctx.clone()).await?;
-        Ok(PipelineBuildResult::create())
+
+        let mut build_res = PipelineBuildResult::create();
+        // The other nodes truncate the table only, the rollup tables are rebuilt once.
+        if self.proxy_to_cluster {
+            let hook_operator = HookOperator::create(
+                self.ctx.clone(),
+                self.catalog_name.clone(),
+                self.database_name.clone(),
+                self.table_name.clone(),
+                "truncate".to_string(),
+                false,
+            );
+            hook_operator
+                .execute_rollup(&mut build_res.main_pipeline, true)
+                .await;
+        }
+        Ok(build_res)
     }
 }
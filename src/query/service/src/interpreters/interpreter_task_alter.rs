@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
@@ -24,13 +25,18 @@ use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskState;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::tasks::TaskScheduler;
 
 #[derive(Debug)]
 pub struct AlterTaskInterpreter {
@@ -121,6 +127,78 @@ impl AlterTaskInterpreter {
         }
         req
     }
+
+    fn alter_local_task(alter_options: &AlterTaskOptions, task: &mut Task) -> Result<()> {
+        let now = Utc::now();
+        match alter_options.clone() {
+            AlterTaskOptions::Resume => {
+                task.state = TaskState::Started;
+                task.failures = 0;
+            }
+            AlterTaskOptions::Suspend => {
+                task.state = TaskState::Suspended;
+                task.last_suspended_at = Some(now);
+            }
+            AlterTaskOptions::Set {
+                schedule,
+                comments,
+                warehouse,
+                suspend_task_after_num_failures,
+                session_parameters,
+            } => {
+                if let Some(schedule) = schedule {
+                    task.schedule = Some(make_task_schedule(schedule));
+                }
+                if let Some(comments) = comments {
+                    task.comment = comments;
+                }
+                if let Some(warehouse) = warehouse {
+                    task.warehouse = Some(warehouse);
+                }
+                if let Some(num) = suspend_task_after_num_failures {
+                    task.suspend_task_after_num_failures = Some(num);
+                }
+                if let Some(session_parameters) = session_parameters {
+                    task.session_parameters = session_parameters;
+                }
+            }
+            AlterTaskOptions::Unset { warehouse } => {
+                if warehouse {
+                    task.warehouse = None;
+                }
+            }
+            AlterTaskOptions::ModifyAs(sql) => {
+                task.query_text = sql;
+            }
+            AlterTaskOptions::AddAfter(_)
+            | AlterTaskOptions::RemoveAfter(_)
+            | AlterTaskOptions::ModifyWhen(_) => {
+                return Err(ErrorCode::Unimplemented(
+                    "AFTER and WHEN of task require cloud control enabled",
+                ));
+            }
+        }
+        task.updated_on = now;
+        task.reschedule(now)
+    }
+
+    /// Alters the task in the meta service, the query nodes pick up the new schedule.
+    async fn alter_local_task_in_meta(&self) -> Result<()> {
+        let plan = &self.plan;
+        let res = UserApiProvider::instance()
+            .update_task_with(&plan.tenant, &plan.task_name, |task| {
+                Self::alter_local_task(&plan.alter_options, task)
+            })
+            .await;
+        match res {
+            Ok(_) => {
+                TaskScheduler::instance().wake_up();
+                Ok(())
+            }
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TASK => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -134,9 +212,8 @@ impl Interpreter for AlterTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.alter_local_task_in_meta().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::CreateTaskRequest;
@@ -21,10 +22,15 @@ use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskState;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -66,6 +72,49 @@ impl CreateTaskInterpreter {
             session_parameters: plan.session_parameters,
         }
     }
+
+    /// Creates the task in the meta service, it is scheduled by the query nodes after it is
+    /// resumed.
+    async fn create_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        if !plan.after.is_empty() || plan.when_condition.is_some() {
+            return Err(ErrorCode::Unimplemented(
+                "AFTER and WHEN of task require cloud control enabled",
+            ));
+        }
+        let owner = self
+            .ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string();
+        let now = Utc::now();
+        let task = Task {
+            id: 0,
+            name: plan.task_name,
+            owner,
+            query_text: plan.sql,
+            schedule: plan.schedule_opts.map(make_task_schedule),
+            warehouse: plan.warehouse_opts.warehouse,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            session_parameters: plan.session_parameters,
+            comment: plan.comment,
+            state: TaskState::Suspended,
+            failures: 0,
+            next_scheduled_at: None,
+            last_suspended_at: None,
+            created_on: now,
+            updated_on: now,
+        };
+        UserApiProvider::instance()
+            .add_task(
+                &plan.tenant,
+                task,
+                &CreateOption::CreateIfNotExists(plan.if_not_exists),
+            )
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -79,9 +128,8 @@ impl Interpreter for CreateTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.create_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
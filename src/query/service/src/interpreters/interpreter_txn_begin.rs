@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionTxn;
+
+/// Starts a transaction of the session, with the isolation level of the
+/// `transaction_isolation` setting.
+///
+/// The transaction only scopes what the statements read, each statement still commits its
+/// writes when it finishes, and they can not be rolled back.
+pub struct BeginInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl BeginInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(BeginInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BeginInterpreter {
+    fn name(&self) -> &str {
+        "BeginInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let session = self.ctx.get_current_session();
+        if session.get_txn().is_some() {
+            self.ctx
+                .push_warning("there is already a transaction in progress".to_string());
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let isolation = self.ctx.get_settings().get_transaction_isolation()?;
+        session.set_txn(Some(Arc::new(SessionTxn::create(isolation))));
+        self.ctx.push_warning(
+            "the statements of the transaction commit their writes when they finish, ROLLBACK is not supported"
+                .to_string(),
+        );
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -119,6 +119,10 @@ impl Interpreter for UpdateInterpreter {
                     // table lock has been added, no need to check.
                     false,
                 );
+                // The rows updated can't be aggregated incrementally.
+                hook_operator
+                    .execute_rollup(&mut build_res.main_pipeline, true)
+                    .await;
                 hook_operator
                     .execute_refresh(&mut build_res.main_pipeline)
                     .await;
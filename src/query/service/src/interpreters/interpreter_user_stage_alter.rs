@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::StageType;
+use databend_common_sql::plans::AlterStagePlan;
+use databend_common_sql::plans::AlterStagePlanAction;
+use databend_common_storages_stage::StageTable;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use futures::TryStreamExt;
+use log::debug;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Alters a stage in place.
+///
+/// The queries that are running on the stage keep the stage info and the operator they
+/// started with, so they are not affected by the change.
+#[derive(Debug)]
+pub struct AlterUserStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterStagePlan,
+}
+
+impl AlterUserStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterStagePlan) -> Result<Self> {
+        Ok(AlterUserStageInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterUserStageInterpreter {
+    fn name(&self) -> &str {
+        "AlterUserStageInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_user_stage_execute");
+
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+
+        let mut stage = match user_mgr.get_stage(&plan.tenant, &plan.stage_name).await {
+            Ok(stage) => stage,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_STAGE => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        match plan.action {
+            AlterStagePlanAction::Set {
+                storage,
+                file_format_params,
+                comment,
+            } => {
+                if let Some(storage) = storage {
+                    if stage.stage_type != StageType::External {
+                        return Err(ErrorCode::StagePermissionDenied(format!(
+                            "the url of internal stage {} can not be changed",
+                            stage.stage_name
+                        )));
+                    }
+                    stage.stage_params.storage = storage;
+                }
+                if let Some(file_format_params) = file_format_params {
+                    stage.file_format_params = file_format_params;
+                }
+                if let Some(comment) = comment {
+                    stage.comment = comment;
+                }
+                user_mgr.update_stage(&plan.tenant, stage).await?;
+            }
+            AlterStagePlanAction::RenameTo { new_stage_name } => {
+                // The files of an internal stage are stored under the path with the stage name.
+                if stage.stage_type != StageType::External {
+                    return Err(ErrorCode::StagePermissionDenied(format!(
+                        "internal stage {} can not be renamed",
+                        stage.stage_name
+                    )));
+                }
+                user_mgr
+                    .rename_stage(&plan.tenant, &plan.stage_name, &new_stage_name)
+                    .await?;
+
+                // Move the ownership to the new name.
+                let role_api = user_mgr.get_role_api_client(&plan.tenant)?;
+                let old_object = OwnershipObject::Stage {
+                    name: plan.stage_name.clone(),
+                };
+                if let Some(ownership) = role_api.get_ownership(&old_object).await? {
+                    role_api.revoke_ownership(&old_object).await?;
+                    role_api
+                        .grant_ownership(
+                            &OwnershipObject::Stage {
+                                name: new_stage_name.clone(),
+                            },
+                            &ownership.role,
+                        )
+                        .await?;
+                    RoleCacheManager::instance().invalidate_cache(&plan.tenant);
+                }
+                info!("stage {} renamed to {}", plan.stage_name, new_stage_name);
+            }
+            AlterStagePlanAction::RefreshCredentials => {
+                if stage.stage_type != StageType::External {
+                    return Err(ErrorCode::StagePermissionDenied(format!(
+                        "internal stage {} has no credentials to refresh",
+                        stage.stage_name
+                    )));
+                }
+                // The operators are built for each query, build a new one to load the
+                // credentials again and check that they have access to the stage.
+                let op = StageTable::get_op(&stage)?;
+                let mut lister = op.lister("/").await?;
+                lister.try_next().await?;
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -17,6 +17,8 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
@@ -25,6 +27,8 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -115,7 +119,30 @@ impl Interpreter for CreateViewInterpreter {
                 ..Default::default()
             },
         };
-        catalog.create_table(plan).await?;
+        let reply = catalog.create_table(plan).await?;
+
+        // grant the ownership of the view to the current role, the tables referenced by the view
+        // are accessed with the privileges of the owner.
+        let current_role = self.ctx.get_current_role().filter(|_| reply.new_table);
+        if let Some(current_role) = current_role {
+            let db = catalog
+                .get_database(tenant.as_str(), &self.plan.database)
+                .await?;
+            let db_id = db.get_db_info().ident.db_id;
+
+            let role_api = UserApiProvider::instance().get_role_api_client(&tenant)?;
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Table {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id,
+                        table_id: reply.table_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
 
         Ok(PipelineBuildResult::create())
     }
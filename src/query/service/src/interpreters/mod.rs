@@ -31,29 +31,39 @@ mod interpreter_copy_into_table;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_backup;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
+mod interpreter_database_restore;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
 mod interpreter_explain;
 mod interpreter_factory;
+mod interpreter_file_format_alter;
 mod interpreter_file_format_create;
 mod interpreter_file_format_drop;
 mod interpreter_file_format_show;
 mod interpreter_index_create;
 mod interpreter_index_drop;
+mod interpreter_index_recommend;
 mod interpreter_index_refresh;
 mod interpreter_insert;
 mod interpreter_kill;
 mod interpreter_merge_into;
 mod interpreter_metrics;
+mod interpreter_migration_apply;
+mod interpreter_migration_rollback;
 mod interpreter_network_policies_show;
 mod interpreter_network_policy_alter;
 mod interpreter_network_policy_create;
 mod interpreter_network_policy_desc;
 mod interpreter_network_policy_drop;
+mod interpreter_notification_integration_alter;
+mod interpreter_notification_integration_create;
+mod interpreter_notification_integration_drop;
+mod interpreter_notification_integrations_show;
 mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
@@ -61,7 +71,10 @@ mod interpreter_password_policy_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
+mod interpreter_query_cache_purge;
 mod interpreter_replace;
+mod interpreter_reset_memory_peak;
+mod interpreter_restore_metasrv;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -69,6 +82,7 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_rollup_table_create;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -82,12 +96,14 @@ mod interpreter_share_grant_object;
 mod interpreter_share_revoke_object;
 mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
+mod interpreter_show_backups;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
+mod interpreter_table_clone;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
@@ -110,12 +126,15 @@ mod interpreter_task_describe;
 mod interpreter_task_drop;
 mod interpreter_task_execute;
 mod interpreter_tasks_show;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
+mod interpreter_user_stage_alter;
 mod interpreter_user_stage_create;
 mod interpreter_user_stage_drop;
 mod interpreter_user_stage_remove;
@@ -143,14 +162,17 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_backup::BackupDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_restore::RestoreDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
+pub use interpreter_index_recommend::RecommendIndexInterpreter;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
@@ -160,6 +182,10 @@ pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
 pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
 pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
 pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_notification_integration_alter::AlterNotificationIntegrationInterpreter;
+pub use interpreter_notification_integration_create::CreateNotificationIntegrationInterpreter;
+pub use interpreter_notification_integration_drop::DropNotificationIntegrationInterpreter;
+pub use interpreter_notification_integrations_show::ShowNotificationIntegrationsInterpreter;
 pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
@@ -167,12 +193,15 @@ pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_reset_memory_peak::ResetMemoryPeakInterpreter;
+pub use interpreter_restore_metasrv::RestoreMetasrvInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_rollup_table_create::CreateRollupTableInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -185,12 +214,14 @@ pub use interpreter_share_grant_object::GrantShareObjectInterpreter;
 pub use interpreter_share_revoke_object::RevokeShareObjectInterpreter;
 pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
+pub use interpreter_show_backups::ShowBackupsInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_clone::CloneTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
@@ -205,12 +236,15 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
+pub use interpreter_user_stage_alter::AlterUserStageInterpreter;
 pub use interpreter_user_stage_create::CreateUserStageInterpreter;
 pub use interpreter_user_stage_drop::DropUserStageInterpreter;
 pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
@@ -51,6 +51,7 @@ pub mod interpreters;
 pub mod local;
 pub mod locks;
 pub mod metrics;
+pub mod notifications;
 pub mod pipelines;
 pub mod schedulers;
 pub mod servers;
@@ -58,6 +59,7 @@ pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod tasks;
 pub mod test_kits;
 
 mod global_services;
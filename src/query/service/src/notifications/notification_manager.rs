@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_users::UserApiProvider;
+use log::warn;
+
+/// The times a notification is sent to a webhook before it is given up.
+const MAX_DELIVERY_ATTEMPTS: usize = 3;
+/// The wait before the first retry, doubled for each of the following retries.
+const INITIAL_RETRY_BACKOFF: Duration = Duration::from_millis(200);
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The JSON body posted to the webhooks.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct NotificationPayload {
+    pub event: String,
+    pub query_id: String,
+    pub table: Option<String>,
+    pub row_count: Option<u64>,
+    pub error_message: Option<String>,
+}
+
+impl NotificationPayload {
+    pub fn copy_into_complete(query_id: String, table: String, row_count: u64) -> Self {
+        NotificationPayload {
+            event: NotificationEvent::CopyIntoComplete.to_string(),
+            query_id,
+            table: Some(table),
+            row_count: Some(row_count),
+            error_message: None,
+        }
+    }
+
+    pub fn query_error(query_id: String, error_message: String) -> Self {
+        NotificationPayload {
+            event: NotificationEvent::QueryError.to_string(),
+            query_id,
+            table: None,
+            row_count: None,
+            error_message: Some(error_message),
+        }
+    }
+}
+
+struct Notification {
+    tenant: String,
+    event: NotificationEvent,
+    payload: NotificationPayload,
+}
+
+/// Delivers the events to the notification integrations of the tenants.
+///
+/// The events are queued and delivered in the background, so the queries that raise them
+/// are not slowed down by the webhooks, and a failed delivery doesn't fail the query.
+pub struct NotificationManager {
+    tx: mpsc::UnboundedSender<Notification>,
+}
+
+impl NotificationManager {
+    pub fn init() -> Result<()> {
+        let (tx, mut rx) = mpsc::unbounded_channel::<Notification>();
+        let client = reqwest::Client::builder()
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .map_err(|e| {
+                ErrorCode::Internal(format!("Cannot build the notification client: {}", e))
+            })?;
+
+        let runtime = GlobalIORuntime::instance();
+        let user_api = UserApiProvider::instance();
+        runtime.clone().spawn(GLOBAL_TASK, async move {
+            while let Some(notification) = rx.recv().await {
+                let client = client.clone();
+                let user_api = user_api.clone();
+                runtime.spawn(GLOBAL_TASK, async move {
+                    Self::dispatch(&client, &user_api, notification).await;
+                });
+            }
+        });
+        GlobalInstance::set(Arc::new(NotificationManager { tx }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<NotificationManager> {
+        GlobalInstance::get()
+    }
+
+    /// Queues the event for the integrations of the tenant that subscribe to it.
+    pub fn notify(&self, tenant: &str, event: NotificationEvent, payload: NotificationPayload) {
+        let notification = Notification {
+            tenant: tenant.to_string(),
+            event,
+            payload,
+        };
+        if self.tx.send(notification).is_err() {
+            warn!(
+                "notification queue is closed, the {} event is dropped",
+                event
+            );
+        }
+    }
+
+    async fn dispatch(
+        client: &reqwest::Client,
+        user_api: &UserApiProvider,
+        notification: Notification,
+    ) {
+        let integrations = match user_api
+            .get_notification_integrations(&notification.tenant)
+            .await
+        {
+            Ok(integrations) => integrations,
+            Err(e) => {
+                warn!(
+                    "failed to get the notification integrations of tenant {}: {}",
+                    notification.tenant, e
+                );
+                return;
+            }
+        };
+
+        for integration in integrations {
+            if !integration.enabled || !integration.events.contains(&notification.event) {
+                continue;
+            }
+            if let Err(e) = Self::deliver(client, &integration, &notification.payload).await {
+                warn!(
+                    "failed to deliver the {} event of query {} to notification integration {}: {}",
+                    notification.event, notification.payload.query_id, integration.name, e
+                );
+            }
+        }
+    }
+
+    /// Posts the payload to the webhook of the integration, retrying with backoff until it
+    /// is accepted or `MAX_DELIVERY_ATTEMPTS` attempts have failed.
+    #[async_backtrace::framed]
+    pub async fn deliver(
+        client: &reqwest::Client,
+        integration: &NotificationIntegration,
+        payload: &NotificationPayload,
+    ) -> Result<()> {
+        let mut backoff = INITIAL_RETRY_BACKOFF;
+        let mut attempt = 1;
+        loop {
+            let res = client.post(&integration.url).json(payload).send().await;
+            let error = match res {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => format!("webhook responded {}", response.status()),
+                Err(e) => e.to_string(),
+            };
+
+            if attempt >= MAX_DELIVERY_ATTEMPTS {
+                return Err(ErrorCode::Internal(format!(
+                    "{} (after {} attempts)",
+                    error, attempt
+                )));
+            }
+            sleep(backoff).await;
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+}
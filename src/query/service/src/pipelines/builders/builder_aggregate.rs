@@ -36,6 +36,7 @@ use databend_common_storage::DataOperator;
 
 use crate::pipelines::processors::transforms::aggregator::build_partition_bucket;
 use crate::pipelines::processors::transforms::aggregator::AggregateInjector;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::aggregator::PartialSingleStateAggregator;
@@ -100,6 +101,7 @@ impl PipelineBuilder {
         self.build_pipeline(&aggregate.input)?;
 
         let max_block_size = self.settings.get_max_block_size()?;
+        let max_block_rows = self.settings.get_aggregate_max_block_rows()?;
         let max_threads = self.settings.get_max_threads()?;
 
         let enable_experimental_aggregate_hashtable = self
@@ -107,6 +109,10 @@ impl PipelineBuilder {
             .get_enable_experimental_aggregate_hashtable()?
             && self.ctx.get_cluster().is_empty();
 
+        // The NULL keys of the grouping sets are the subtotals, they are never dropped.
+        let drop_null_keys = self.settings.get_group_by_drop_null_keys()?
+            && !matches!(aggregate.input.as_ref(), PhysicalPlan::AggregateExpand(_));
+
         let params = Self::build_aggregator_params(
             aggregate.input.output_schema()?,
             &aggregate.group_by,
@@ -114,6 +120,9 @@ impl PipelineBuilder {
             enable_experimental_aggregate_hashtable,
             max_block_size as usize,
             None,
+            drop_null_keys,
+            max_block_rows as usize,
+            self.ctx.get_aggregate_memory_tracker(),
         )?;
 
         if params.group_columns.is_empty() {
@@ -211,6 +220,7 @@ impl PipelineBuilder {
 
     pub(crate) fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
         let max_block_size = self.settings.get_max_block_size()?;
+        let max_block_rows = self.settings.get_aggregate_max_block_rows()?;
         let enable_experimental_aggregate_hashtable = self
             .settings
             .get_enable_experimental_aggregate_hashtable()?
@@ -223,6 +233,10 @@ impl PipelineBuilder {
             enable_experimental_aggregate_hashtable,
             max_block_size as usize,
             aggregate.limit,
+            // The rows are dropped by the partial aggregation.
+            false,
+            max_block_rows as usize,
+            self.ctx.get_aggregate_memory_tracker(),
         )?;
 
         if params.group_columns.is_empty() {
@@ -288,6 +302,9 @@ impl PipelineBuilder {
         enable_experimental_aggregate_hashtable: bool,
         max_block_size: usize,
         limit: Option<usize>,
+        drop_null_keys: bool,
+        max_block_rows: usize,
+        memory_tracker: Arc<AggregateMemoryTracker>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
         let (group_by, group_data_types) = group_by
@@ -329,6 +346,9 @@ impl PipelineBuilder {
             enable_experimental_aggregate_hashtable,
             max_block_size,
             limit,
+            drop_null_keys,
+            max_block_rows,
+            memory_tracker,
         )?;
 
         Ok(params)
@@ -20,6 +20,8 @@ use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
@@ -62,7 +64,7 @@ impl PipelineBuilder {
                 let stage_table = StageTable::try_create(copy.stage_table_info.clone())?;
                 stage_table.set_block_thresholds(to_table.get_block_thresholds());
                 stage_table.read_data(self.ctx.clone(), source, &mut self.main_pipeline, false)?;
-                copy.required_source_schema.clone()
+                Self::stage_source_schema(copy)
             }
         };
         Self::build_append_data_pipeline(
@@ -75,6 +77,26 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// The metadata columns are read in their own types and cast to the types of the columns
+    /// they are loaded into, which are the last columns of the required source schema.
+    fn stage_source_schema(copy: &CopyIntoTable) -> Arc<DataSchema> {
+        let metadata_columns = &copy.stage_table_info.metadata_columns;
+        if metadata_columns.is_empty() {
+            return copy.required_source_schema.clone();
+        }
+
+        let fields = copy.required_source_schema.fields();
+        let num_file_fields = fields.len() - metadata_columns.len();
+        let mut source_fields = fields[..num_file_fields].to_vec();
+        for (field, column) in fields[num_file_fields..].iter().zip(metadata_columns) {
+            source_fields.push(DataField::new(
+                field.name(),
+                DataType::from(&column.data_type()),
+            ));
+        }
+        Arc::new(DataSchema::new(source_fields))
+    }
+
     fn build_append_data_pipeline(
         ctx: Arc<QueryContext>,
         main_pipeline: &mut Pipeline,
@@ -87,7 +109,7 @@ impl PipelineBuilder {
         let plan_required_values_schema = &plan.required_values_schema;
         let plan_write_mode = &plan.write_mode;
         if &source_schema != plan_required_source_schema {
-            // only parquet need cast
+            // only parquet and the metadata columns need cast
             let func_ctx = ctx.get_function_context()?;
             main_pipeline.add_transform(|transform_input_port, transform_output_port| {
                 TransformCastSchema::try_create(
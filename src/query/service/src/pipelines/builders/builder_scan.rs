@@ -12,23 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_pipeline_sources::OneBlockSource;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::ConstantTableScan;
 use databend_common_sql::executor::physical_plans::CteScan;
 use databend_common_sql::executor::physical_plans::TableScan;
+use databend_common_sql::executor::PhysicalPlan;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
+use crate::pipelines::processors::transforms::SharedChunkBuffer;
+use crate::pipelines::processors::transforms::SharedChunkSink;
+use crate::pipelines::processors::transforms::SharedChunkSource;
 use crate::pipelines::processors::transforms::TransformAddInternalColumns;
 use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub(crate) fn build_table_scan(&mut self, scan: &TableScan) -> Result<()> {
+        match self.shared_scans.get(&scan.plan_id).cloned() {
+            Some(buffer) => self.build_shared_table_scan(scan, buffer),
+            None => self.read_table_scan(scan),
+        }
+    }
+
+    fn read_table_scan(&mut self, scan: &TableScan) -> Result<()> {
         let table = self.ctx.build_table_from_source_plan(&scan.source)?;
         self.ctx.set_partitions(scan.source.parts.clone())?;
         table.read_data(
@@ -71,6 +88,136 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// The scan is read once by a pipeline of its own filling the buffer, and each consumer
+    /// plays the blocks of the buffer back.
+    fn build_shared_table_scan(
+        &mut self,
+        scan: &TableScan,
+        buffer: Arc<SharedChunkBuffer>,
+    ) -> Result<()> {
+        if buffer.try_build_producer() {
+            let producer_ctx = QueryContext::create_from(self.ctx.clone());
+            let mut producer_builder = PipelineBuilder::create(
+                self.func_ctx.clone(),
+                self.settings.clone(),
+                producer_ctx,
+                self.main_pipeline.get_scopes(),
+            );
+            producer_builder.read_table_scan(scan)?;
+            producer_builder.main_pipeline.add_sink(|input| {
+                Ok(ProcessorPtr::create(AsyncSinker::create(
+                    input,
+                    self.ctx.clone(),
+                    SharedChunkSink::create(buffer.clone()),
+                )))
+            })?;
+            self.pipelines
+                .push(producer_builder.main_pipeline.finalize());
+            self.pipelines.extend(producer_builder.pipelines);
+        }
+
+        let max_threads = self.settings.get_max_threads()?;
+        let consumer = buffer.create_consumer();
+        self.main_pipeline.add_source(
+            |output| SharedChunkSource::create(output, consumer.clone()),
+            max_threads as usize,
+        )
+    }
+
+    /// Find the table scans reading the same data, i.e. the same table with the same
+    /// projection and push downs, and give each group of them a buffer of `capacity` blocks
+    /// to share.
+    pub(crate) fn find_shared_table_scans(
+        plan: &PhysicalPlan,
+        capacity: usize,
+    ) -> Result<HashMap<u32, Arc<SharedChunkBuffer>>> {
+        let mut scans: Vec<&TableScan> = vec![];
+        // The runtime filters of a join are applied to the table scans of the probe side,
+        // so the scans can't serve other consumers.
+        let mut runtime_filter_tables = HashSet::new();
+        PhysicalPlan::traverse(
+            plan,
+            &mut |_| true,
+            &mut |plan| match plan {
+                PhysicalPlan::TableScan(scan) => scans.push(scan),
+                PhysicalPlan::HashJoin(join) => {
+                    runtime_filter_tables
+                        .extend(join.probe_keys_rt.iter().flatten().map(|(_, index)| *index));
+                }
+                _ => {}
+            },
+            &mut |_| {},
+        );
+
+        let mut keys: HashMap<u32, String> = HashMap::new();
+        for scan in scans {
+            let source = &scan.source;
+            // Keep the scans the rest of the plan depends on apart, e.g. the row ids of `RowFetch`.
+            if runtime_filter_tables.contains(&scan.table_index)
+                || source.query_internal_columns
+                || source.update_stream_columns
+                || source.base_block_ids.is_some()
+            {
+                continue;
+            }
+            let key = serde_json::to_string(&(
+                &source.source_info,
+                &source.output_schema,
+                &source.tbl_args,
+                &source.push_downs,
+                &source.data_mask_policy,
+                &scan.internal_column,
+                scan.name_mapping.keys().collect::<Vec<_>>(),
+            ))?;
+            keys.insert(scan.plan_id, key);
+        }
+
+        // The buffer is bounded, so the consumers must read it at the same time. The two sides
+        // of a join are not, e.g. the probe side waits for the build side to finish, so the
+        // scans under different sides of a join can't share a buffer.
+        let mut conflicts = HashSet::new();
+        Self::collect_shared_scan_keys(plan, &keys, &mut conflicts);
+
+        let mut groups: HashMap<String, Vec<u32>> = HashMap::new();
+        for (plan_id, key) in keys {
+            if !conflicts.contains(&key) {
+                groups.entry(key).or_default().push(plan_id);
+            }
+        }
+
+        let mut shared_scans = HashMap::new();
+        for plan_ids in groups.into_values().filter(|plan_ids| plan_ids.len() > 1) {
+            let buffer = SharedChunkBuffer::create(plan_ids.len(), capacity);
+            for plan_id in plan_ids {
+                shared_scans.insert(plan_id, buffer.clone());
+            }
+        }
+        Ok(shared_scans)
+    }
+
+    // Returns the keys of the scans under the plan, and collects the keys found under more
+    // than one input of a plan other than `UnionAll` into `conflicts`.
+    fn collect_shared_scan_keys(
+        plan: &PhysicalPlan,
+        keys: &HashMap<u32, String>,
+        conflicts: &mut HashSet<String>,
+    ) -> HashSet<String> {
+        if let PhysicalPlan::TableScan(scan) = plan {
+            return keys.get(&scan.plan_id).cloned().into_iter().collect();
+        }
+
+        let is_union = matches!(plan, PhysicalPlan::UnionAll(_));
+        let mut plan_keys = HashSet::new();
+        for child in plan.children() {
+            for key in Self::collect_shared_scan_keys(child, keys, conflicts) {
+                if !plan_keys.insert(key.clone()) && !is_union {
+                    conflicts.insert(key);
+                }
+            }
+        }
+        plan_keys
+    }
+
     pub(crate) fn build_cte_scan(&mut self, cte_scan: &CteScan) -> Result<()> {
         let max_threads = self.settings.get_max_threads()?;
         self.main_pipeline.add_source(
@@ -401,6 +401,17 @@ impl ScheduleQueue {
             let graph = proc.graph;
             let node_profile = executor.graph.get_node_profile(proc.processor.id()).clone();
             let process_future = proc.processor.async_process();
+            let permits = executor.async_tasks_permits.clone();
+            let process_future = async move {
+                // Wait for a permit without blocking the worker thread which scheduled the task.
+                let _permit = match permits {
+                    Some(permits) => Some(permits.acquire_owned().await.map_err(|_| {
+                        ErrorCode::Internal("The permits of the async tasks are closed")
+                    })?),
+                    None => None,
+                };
+                process_future.await
+            };
             executor.async_runtime.spawn(
                 query_id.as_ref().clone(),
                 TrackedFuture::create(ProcessorAsyncTask::create(
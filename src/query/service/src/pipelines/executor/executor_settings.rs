@@ -22,14 +22,19 @@ use databend_common_settings::Settings;
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub max_execute_time_in_seconds: Duration,
+    /// The maximum number of async tasks of the processors running at the same time,
+    /// 0 means no limit.
+    pub max_concurrent_async_tasks: usize,
 }
 
 impl ExecutorSettings {
     pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+        let max_concurrent_async_tasks = settings.get_max_concurrent_async_tasks()?;
         Ok(ExecutorSettings {
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            max_concurrent_async_tasks: max_concurrent_async_tasks as usize,
         })
     }
 }
@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::catch_unwind;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::Runtime;
@@ -57,6 +58,8 @@ pub struct PipelineExecutor {
     workers_condvar: Arc<WorkersCondvar>,
     pub async_runtime: Arc<Runtime>,
     pub global_tasks_queue: Arc<ExecutorTasksQueue>,
+    /// Bounds the async tasks of the processors running at the same time, if limited.
+    pub(crate) async_tasks_permits: Option<Arc<Semaphore>>,
     on_init_callback: Mutex<Option<InitCallback>>,
     on_finished_callback: Mutex<Option<FinishedCallback>>,
     settings: ExecutorSettings,
@@ -182,12 +185,17 @@ impl PipelineExecutor {
     ) -> Result<Arc<PipelineExecutor>> {
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = ExecutorTasksQueue::create(threads_num);
+        let async_tasks_permits = match settings.max_concurrent_async_tasks {
+            0 => None,
+            max_tasks => Some(Arc::new(Semaphore::new(max_tasks))),
+        };
 
         Ok(Arc::new(PipelineExecutor {
             graph,
             threads_num,
             workers_condvar,
             global_tasks_queue,
+            async_tasks_permits,
             on_init_callback,
             on_finished_callback,
             async_runtime: GlobalIORuntime::instance(),
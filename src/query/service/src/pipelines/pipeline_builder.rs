@@ -32,6 +32,7 @@ use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::HashJoinBuildState;
 use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::processors::transforms::SharedChunkBuffer;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
@@ -50,6 +51,9 @@ pub struct PipelineBuilder {
     // Cte -> state, each cte has it's own state
     pub cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
 
+    // TableScan plan id -> buffer, for the scans of the same data shared by several branches
+    pub shared_scans: HashMap<u32, Arc<SharedChunkBuffer>>,
+
     pub(crate) exchange_injector: Arc<dyn ExchangeInjector>,
 }
 
@@ -68,12 +72,20 @@ impl PipelineBuilder {
             main_pipeline: Pipeline::with_scopes(scopes),
             exchange_injector: DefaultExchangeInjector::create(),
             cte_state: HashMap::new(),
+            shared_scans: HashMap::new(),
             merge_into_probe_data_fields: None,
             join_state: None,
         }
     }
 
     pub fn finalize(mut self, plan: &PhysicalPlan) -> Result<PipelineBuildResult> {
+        // The sub builders inherit the shared scans of the whole plan.
+        if self.shared_scans.is_empty() && self.settings.get_enable_shared_table_scan()? {
+            // Let the fastest consumer go ahead of the slowest one by a few blocks per thread.
+            let capacity = self.settings.get_max_threads()? as usize * 2;
+            self.shared_scans = Self::find_shared_table_scans(plan, capacity)?;
+        }
+
         self.build_pipeline(plan)?;
 
         for source_pipeline in &self.pipelines {
@@ -15,6 +15,11 @@
 pub use databend_common_pipeline_core::processors::*;
 pub(crate) mod transforms;
 
+pub use transforms::aggregator::AggregateMemoryTracker;
+pub use transforms::aggregator::AggregatorParams;
+pub use transforms::aggregator::HashTableCell;
+pub use transforms::aggregator::PartialStateMerger;
+pub use transforms::group_by::HashMethodBounds;
 pub use transforms::DeduplicateRowNumber;
 pub use transforms::HashJoinDesc;
 pub use transforms::HashJoinState;
@@ -14,6 +14,8 @@
 
 use std::any::Any;
 use std::marker::PhantomData;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_functions::aggregates::StateAddr;
@@ -75,6 +77,56 @@ impl<T: HashMethodBounds, V: Send + Sync + 'static> HashTableCell<T, V> {
     }
 }
 
+/// The memory allocated by the hash tables and the states of the aggregations of a query,
+/// shown in the `aggregate_memory_usage` column of `system.processes`.
+///
+/// It is only for diagnosis, no limit is enforced on it: the same allocations are charged to
+/// the `MemStat` of the query by the tracking allocator, which enforces `max_memory_usage`.
+#[derive(Default)]
+pub struct AggregateMemoryTracker {
+    memory_usage: AtomicI64,
+}
+
+impl AggregateMemoryTracker {
+    pub fn create() -> Arc<AggregateMemoryTracker> {
+        Arc::new(AggregateMemoryTracker::default())
+    }
+
+    pub fn get_memory_usage(&self) -> i64 {
+        self.memory_usage.load(Ordering::Relaxed)
+    }
+}
+
+/// Accounts the memory allocated by the hash table of an aggregate processor in the tracker
+/// of the query, the memory is released from the tracker when the guard is dropped.
+pub struct AggregateMemoryGuard {
+    tracker: Arc<AggregateMemoryTracker>,
+    allocated_bytes: i64,
+}
+
+impl AggregateMemoryGuard {
+    pub fn create(tracker: Arc<AggregateMemoryTracker>) -> AggregateMemoryGuard {
+        AggregateMemoryGuard {
+            tracker,
+            allocated_bytes: 0,
+        }
+    }
+
+    pub fn update(&mut self, allocated_bytes: usize) {
+        let allocated_bytes = allocated_bytes as i64;
+        self.tracker
+            .memory_usage
+            .fetch_add(allocated_bytes - self.allocated_bytes, Ordering::Relaxed);
+        self.allocated_bytes = allocated_bytes;
+    }
+}
+
+impl Drop for AggregateMemoryGuard {
+    fn drop(&mut self) {
+        self.update(0);
+    }
+}
+
 pub trait HashTableDropper<T: HashMethodBounds, V: Send + Sync + 'static> {
     fn as_any(&self) -> &dyn Any;
     fn destroy(&self, hashtable: &mut T::HashTable<V>);
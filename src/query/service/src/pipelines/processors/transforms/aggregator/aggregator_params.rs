@@ -26,6 +26,7 @@ use databend_common_functions::aggregates::StateAddr;
 use databend_common_sql::IndexType;
 use itertools::Itertools;
 
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
 use crate::pipelines::processors::transforms::group_by::Area;
 
 pub struct AggregatorParams {
@@ -45,9 +46,18 @@ pub struct AggregatorParams {
     pub max_block_size: usize,
     // Limit is push down to AggregatorTransform
     pub limit: Option<usize>,
+    // If the rows whose group by keys contain NULL are dropped by the partial aggregation,
+    // otherwise the NULL keys are grouped like the other keys.
+    pub drop_null_keys: bool,
+    // The maximum rows of the blocks output by the final aggregation of a bucket,
+    // 0 outputs a single block for each bucket.
+    pub max_block_rows: usize,
+    // The memory of the hash tables is accounted in the tracker of the query.
+    pub memory_tracker: Arc<AggregateMemoryTracker>,
 }
 
 impl AggregatorParams {
+    #[allow(clippy::too_many_arguments)]
     pub fn try_create(
         input_schema: DataSchemaRef,
         group_data_types: Vec<DataType>,
@@ -57,6 +67,9 @@ impl AggregatorParams {
         enable_experimental_aggregate_hashtable: bool,
         max_block_size: usize,
         limit: Option<usize>,
+        drop_null_keys: bool,
+        max_block_rows: usize,
+        memory_tracker: Arc<AggregateMemoryTracker>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut states_offsets: Vec<usize> = Vec::with_capacity(agg_funcs.len());
         let mut states_layout = None;
@@ -76,6 +89,9 @@ impl AggregatorParams {
             enable_experimental_aggregate_hashtable,
             max_block_size,
             limit,
+            drop_null_keys,
+            max_block_rows,
+            memory_tracker,
         }))
     }
 
@@ -16,6 +16,7 @@ mod aggregate_cell;
 mod aggregate_exchange_injector;
 mod aggregate_meta;
 mod aggregator_params;
+mod partial_state_merger;
 mod serde;
 mod transform_aggregate_expand;
 mod transform_aggregate_final;
@@ -26,11 +27,14 @@ mod transform_partition_bucket;
 mod transform_single_key;
 mod utils;
 
+pub use aggregate_cell::AggregateMemoryGuard;
+pub use aggregate_cell::AggregateMemoryTracker;
 pub use aggregate_cell::HashTableCell;
 pub use aggregate_cell::PartitionedHashTableDropper;
 pub use aggregate_exchange_injector::AggregateInjector;
 pub use aggregate_meta::*;
 pub use aggregator_params::AggregatorParams;
+pub use partial_state_merger::PartialStateMerger;
 pub use transform_aggregate_expand::TransformExpandGroupingSets;
 pub use transform_aggregate_final::TransformFinalAggregate;
 pub use transform_aggregate_partial::TransformPartialAggregate;
@@ -0,0 +1,436 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_hashtable::HashtableEntryMutRefLike;
+use databend_common_hashtable::HashtableEntryRefLike;
+use databend_common_hashtable::HashtableLike;
+
+use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
+use crate::pipelines::processors::transforms::aggregator::estimated_key_size;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+use crate::pipelines::processors::transforms::aggregator::HashTableCell;
+use crate::pipelines::processors::transforms::group_by::GroupColumnsBuilder;
+use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
+use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
+
+/// Merges the partial aggregated states into the final aggregated results, without a
+/// pipeline or a query context. It is exported from `databend_query::pipelines::processors`
+/// for the operators built outside the aggregate transforms.
+///
+/// The states of the same group are merged in the order they are pushed.
+pub struct PartialStateMerger<Method: HashMethodBounds> {
+    method: Method,
+    params: Arc<AggregatorParams>,
+    hash_cell: HashTableCell<Method, usize>,
+    reach_limit: bool,
+    memory_guard: AggregateMemoryGuard,
+}
+
+impl<Method: HashMethodBounds> PartialStateMerger<Method> {
+    pub fn create(method: Method, params: Arc<AggregatorParams>) -> Result<Self> {
+        let arena = Arc::new(Bump::new());
+        let hashtable = method.create_hash_table::<usize>(arena)?;
+        let _dropper = AggregateHashTableDropper::create(params.clone());
+        let hash_cell = HashTableCell::<Method, usize>::create(hashtable, _dropper);
+        Ok(PartialStateMerger {
+            method,
+            memory_guard: AggregateMemoryGuard::create(params.memory_tracker.clone()),
+            params,
+            hash_cell,
+            reach_limit: false,
+        })
+    }
+
+    /// Merge a block serialized by the partial aggregation, the states of the aggregate
+    /// functions are the first columns and the group by keys are the last column.
+    pub fn push_chunk(&mut self, block: &DataBlock) -> Result<()> {
+        let aggregate_function_len = self.params.aggregate_functions.len();
+        if block.num_columns() != aggregate_function_len + 1 {
+            return Err(ErrorCode::Internal(format!(
+                "the partial aggregated block has {} columns, expected {}",
+                block.num_columns(),
+                aggregate_function_len + 1
+            )));
+        }
+
+        let Some(column) = block.columns().last().and_then(|c| c.value.as_column()) else {
+            return Err(ErrorCode::Internal(
+                "the group by keys of the partial aggregated block are not a column",
+            ));
+        };
+        let keys_iter = self.method.keys_iter_from_column(column)?;
+
+        // first state places of current block
+        let places = {
+            let keys_iter = keys_iter.iter();
+            let (len, _) = keys_iter.size_hint();
+            let mut places = Vec::with_capacity(len);
+
+            let mut current_len = self.hash_cell.hashtable.len();
+            unsafe {
+                for key in keys_iter {
+                    if self.reach_limit {
+                        let entry = self.hash_cell.hashtable.entry(key);
+                        if let Some(entry) = entry {
+                            let place = Into::<StateAddr>::into(*entry.get());
+                            places.push(place);
+                        }
+                        continue;
+                    }
+
+                    match self.hash_cell.hashtable.insert_and_entry(key) {
+                        Ok(mut entry) => {
+                            let place = self.params.alloc_layout(&mut self.hash_cell.arena);
+                            places.push(place);
+
+                            *entry.get_mut() = place.addr();
+
+                            if let Some(limit) = self.params.limit {
+                                current_len += 1;
+                                if current_len >= limit {
+                                    self.reach_limit = true;
+                                }
+                            }
+                        }
+                        Err(entry) => {
+                            let place = Into::<StateAddr>::into(*entry.get());
+                            places.push(place);
+                        }
+                    }
+                }
+            }
+
+            places
+        };
+
+        let states_columns = (0..aggregate_function_len)
+            .map(|i| block.get_by_offset(i))
+            .collect::<Vec<_>>();
+        let mut states_binary_columns = Vec::with_capacity(states_columns.len());
+
+        for agg in states_columns.iter().take(aggregate_function_len) {
+            let Some(col) = agg.value.as_column() else {
+                return Err(ErrorCode::Internal(
+                    "the aggregate states of the partial aggregated block are not a column",
+                ));
+            };
+            states_binary_columns.push(col.slice(0..places.len()));
+        }
+
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+
+        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+            aggregate_function.batch_merge(
+                &places,
+                offsets_aggregate_states[idx],
+                &states_binary_columns[idx],
+            )?;
+        }
+        self.memory_guard.update(self.hash_cell.allocated_bytes());
+        Ok(())
+    }
+
+    /// Merge the states of a hash table of the partial aggregation.
+    pub fn push_hash_table(&mut self, cell: &HashTableCell<Method, usize>) -> Result<()> {
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+
+        unsafe {
+            for entry in cell.hashtable.iter() {
+                let place = match self.hash_cell.hashtable.insert(entry.key()) {
+                    Err(place) => StateAddr::new(*place),
+                    Ok(entry) => {
+                        let place = self.params.alloc_layout(&mut self.hash_cell.arena);
+                        entry.write(place.addr());
+                        place
+                    }
+                };
+
+                let old_place = StateAddr::new(*entry.get());
+                for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                    let final_place = place.next(offsets_aggregate_states[idx]);
+                    let state_place = old_place.next(offsets_aggregate_states[idx]);
+                    aggregate_function.merge_states(final_place, state_place)?;
+                }
+            }
+        }
+        self.memory_guard.update(self.hash_cell.allocated_bytes());
+        Ok(())
+    }
+
+    /// Build the final aggregated results of the states merged, the results of the aggregate
+    /// functions are the first columns, followed by the group by columns.
+    ///
+    /// The results are split into blocks of at most `max_block_rows` groups, or output as a
+    /// single block if `max_block_rows` is 0.
+    pub fn finish(self) -> Result<Vec<DataBlock>> {
+        let keys_len = self.hash_cell.hashtable.len();
+        let value_size = estimated_key_size(&self.hash_cell.hashtable);
+        let block_rows = match self.params.max_block_rows {
+            0 => keys_len,
+            max_block_rows => max_block_rows,
+        };
+
+        let mut blocks = Vec::with_capacity(keys_len.div_ceil(block_rows.max(1)).max(1));
+        let mut entries = self.hash_cell.hashtable.iter();
+        let mut remain_rows = keys_len;
+        loop {
+            let rows = remain_rows.min(block_rows);
+            let mut group_columns_builder = self.method.group_columns_builder(
+                rows,
+                value_size * rows / keys_len.max(1),
+                &self.params,
+            );
+
+            let mut places = Vec::with_capacity(rows);
+            for group_entity in entries.by_ref().take(rows) {
+                places.push(StateAddr::new(*group_entity.get()));
+                group_columns_builder.append_value(group_entity.key());
+            }
+
+            blocks.push(self.build_block(places, group_columns_builder.finish()?)?);
+
+            remain_rows -= rows;
+            if remain_rows == 0 {
+                return Ok(blocks);
+            }
+        }
+    }
+
+    fn build_block(
+        &self,
+        mut places: Vec<StateAddr>,
+        group_columns: Vec<Column>,
+    ) -> Result<DataBlock> {
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+
+        let mut columns = Vec::with_capacity(aggregate_functions.len() + group_columns.len());
+        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+            if idx > 0 {
+                for place in places.iter_mut() {
+                    *place = place
+                        .next(offsets_aggregate_states[idx] - offsets_aggregate_states[idx - 1]);
+                }
+            }
+
+            let data_type = aggregate_function.return_type()?;
+            let mut builder = ColumnBuilder::with_capacity(&data_type, places.len());
+            aggregate_function.batch_merge_result(&places, 0, &mut builder)?;
+            columns.push(builder.build());
+        }
+
+        columns.extend(group_columns);
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::sync::Arc;
+
+    use bumpalo::Bump;
+    use databend_common_exception::ErrorCode;
+    use databend_common_exception::Result;
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::UInt64Type;
+    use databend_common_expression::types::ValueType;
+    use databend_common_expression::DataBlock;
+    use databend_common_expression::DataField;
+    use databend_common_expression::DataSchemaRefExt;
+    use databend_common_expression::FromData;
+    use databend_common_expression::HashMethodFixedKeys;
+    use databend_common_functions::aggregates::AggregateFunctionFactory;
+    use databend_common_functions::aggregates::StateAddr;
+    use databend_common_hashtable::HashtableLike;
+
+    use super::PartialStateMerger;
+    use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
+    use crate::pipelines::processors::transforms::aggregator::serialize_aggregate;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
+    use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+    use crate::pipelines::processors::transforms::aggregator::HashTableCell;
+    use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
+
+    pub(crate) type TestMethod = HashMethodFixedKeys<u64>;
+
+    /// The params of `SELECT sum(v) FROM t GROUP BY k`, `k` and `v` are `UInt64`.
+    pub(crate) fn create_test_params(
+        max_block_rows: usize,
+        memory_tracker: Arc<AggregateMemoryTracker>,
+    ) -> Result<Arc<AggregatorParams>> {
+        let uint64 = DataType::Number(NumberDataType::UInt64);
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("k", uint64.clone()),
+            DataField::new("v", uint64.clone()),
+        ]);
+        let sum = AggregateFunctionFactory::instance().get("sum", vec![], vec![uint64.clone()])?;
+        AggregatorParams::try_create(
+            schema,
+            vec![uint64],
+            &[0],
+            &[sum],
+            &[vec![1]],
+            false,
+            65536,
+            None,
+            false,
+            max_block_rows,
+            memory_tracker,
+        )
+    }
+
+    /// The hash table of the partial aggregation of the `(k, v)` rows.
+    pub(crate) fn create_partial_cell(
+        params: &Arc<AggregatorParams>,
+        rows: &[(u64, u64)],
+    ) -> Result<HashTableCell<TestMethod, usize>> {
+        let hashtable = TestMethod::default().create_hash_table::<usize>(Arc::new(Bump::new()))?;
+        let dropper = AggregateHashTableDropper::create(params.clone());
+        let mut cell = HashTableCell::create(hashtable, dropper);
+
+        let values = UInt64Type::from_data(rows.iter().map(|(_, v)| *v).collect::<Vec<_>>());
+        let offset = params.offsets_aggregate_states[0];
+        for (row, (key, _)) in rows.iter().enumerate() {
+            let place = unsafe {
+                match cell.hashtable.insert(key) {
+                    Ok(entry) => {
+                        let place = params.alloc_layout(&mut cell.arena);
+                        entry.write(place.addr());
+                        place
+                    }
+                    Err(place) => StateAddr::new(*place),
+                }
+            };
+            params.aggregate_functions[0].accumulate_row(
+                place.next(offset),
+                &[values.clone()],
+                row,
+            )?;
+        }
+        Ok(cell)
+    }
+
+    /// The block serialized by the partial aggregation of the `(k, v)` rows.
+    pub(crate) fn create_partial_block(
+        params: &Arc<AggregatorParams>,
+        rows: &[(u64, u64)],
+    ) -> Result<DataBlock> {
+        let cell = create_partial_cell(params, rows)?;
+        serialize_aggregate(&TestMethod::default(), params, &cell.hashtable)
+    }
+
+    /// The `(k, sum(v))` of the final aggregated blocks, ordered by `k`.
+    fn collect_results(blocks: &[DataBlock]) -> Result<Vec<(u64, u64)>> {
+        let mut results = vec![];
+        for block in blocks {
+            let column = |i: usize| {
+                block
+                    .get_by_offset(i)
+                    .value
+                    .as_column()
+                    .and_then(UInt64Type::try_downcast_column)
+                    .ok_or_else(|| ErrorCode::Internal(format!("column {i} is not UInt64")))
+            };
+            let (sums, keys) = (column(0)?, column(1)?);
+            results.extend(keys.iter().copied().zip(sums.iter().copied()));
+        }
+        results.sort();
+        Ok(results)
+    }
+
+    #[test]
+    fn test_merge_blocks_and_hash_tables() -> Result<()> {
+        let params = create_test_params(0, AggregateMemoryTracker::create())?;
+        let mut merger = PartialStateMerger::create(TestMethod::default(), params.clone())?;
+
+        merger.push_chunk(&create_partial_block(&params, &[(1, 1), (2, 2), (1, 3)])?)?;
+        merger.push_hash_table(&create_partial_cell(&params, &[(2, 10), (3, 20)])?)?;
+        merger.push_chunk(&create_partial_block(&params, &[(3, 100), (4, 200)])?)?;
+        merger.push_hash_table(&create_partial_cell(&params, &[(1, 1000)])?)?;
+
+        let blocks = merger.finish()?;
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(collect_results(&blocks)?, vec![
+            (1, 1004),
+            (2, 12),
+            (3, 120),
+            (4, 200)
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_malformed_chunk() -> Result<()> {
+        let params = create_test_params(0, AggregateMemoryTracker::create())?;
+        let mut merger = PartialStateMerger::create(TestMethod::default(), params.clone())?;
+
+        let block = create_partial_block(&params, &[(1, 1)])?;
+        let keys_only = DataBlock::new(vec![block.get_by_offset(1).clone()], block.num_rows());
+        let res = merger.push_chunk(&keys_only);
+        assert_eq!(res.unwrap_err().code(), ErrorCode::INTERNAL);
+        let res = merger.push_chunk(&DataBlock::empty());
+        assert_eq!(res.unwrap_err().code(), ErrorCode::INTERNAL);
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_split_by_max_block_rows() -> Result<()> {
+        let params = create_test_params(2, AggregateMemoryTracker::create())?;
+        let mut merger = PartialStateMerger::create(TestMethod::default(), params.clone())?;
+
+        let rows = (0..5).map(|k| (k, k * 10)).collect::<Vec<_>>();
+        merger.push_chunk(&create_partial_block(&params, &rows)?)?;
+
+        let blocks = merger.finish()?;
+        let block_rows = blocks.iter().map(DataBlock::num_rows).collect::<Vec<_>>();
+        assert_eq!(block_rows, vec![2, 2, 1]);
+        assert_eq!(collect_results(&blocks)?, rows);
+        Ok(())
+    }
+
+    #[test]
+    fn test_memory_released_on_drop() -> Result<()> {
+        let memory_tracker = AggregateMemoryTracker::create();
+        let params = create_test_params(0, memory_tracker.clone())?;
+        let mut merger = PartialStateMerger::create(TestMethod::default(), params.clone())?;
+
+        let rows = (0..1000).map(|k| (k, k)).collect::<Vec<_>>();
+        merger.push_chunk(&create_partial_block(&params, &rows)?)?;
+        assert!(memory_tracker.get_memory_usage() > 0);
+
+        drop(merger);
+        assert_eq!(memory_tracker.get_memory_usage(), 0);
+
+        let mut merger = PartialStateMerger::create(TestMethod::default(), params.clone())?;
+        merger.push_hash_table(&create_partial_cell(&params, &rows)?)?;
+        assert!(memory_tracker.get_memory_usage() > 0);
+
+        merger.finish()?;
+        assert_eq!(memory_tracker.get_memory_usage(), 0);
+        Ok(())
+    }
+}
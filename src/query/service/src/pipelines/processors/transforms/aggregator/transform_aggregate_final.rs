@@ -12,40 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::borrow::BorrowMut;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use bumpalo::Bump;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::AggregateHashTable;
-use databend_common_expression::ColumnBuilder;
+use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::HashTableConfig;
 use databend_common_expression::PayloadFlushState;
-use databend_common_functions::aggregates::StateAddr;
-use databend_common_hashtable::HashtableEntryMutRefLike;
-use databend_common_hashtable::HashtableEntryRefLike;
 use databend_common_hashtable::HashtableLike;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
-use databend_common_pipeline_transforms::processors::BlockMetaTransform;
-use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use log::info;
 
-use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
-use crate::pipelines::processors::transforms::aggregator::estimated_key_size;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
-use crate::pipelines::processors::transforms::aggregator::HashTableCell;
-use crate::pipelines::processors::transforms::group_by::GroupColumnsBuilder;
+use crate::pipelines::processors::transforms::aggregator::PartialStateMerger;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
-use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
 
 pub struct TransformFinalAggregate<Method: HashMethodBounds> {
     method: Method,
     params: Arc<AggregatorParams>,
     flush_state: PayloadFlushState,
+    // Bucket -> the number of the partial aggregated rows merged, `-1` is the unpartitioned bucket.
+    bucket_row_counts: HashMap<isize, usize>,
+    // The memory of the hash table of the bucket being merged with the experimental aggregate
+    // hash table, the `PartialStateMerger` tracks its own.
+    memory_guard: AggregateMemoryGuard,
 }
 
 impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
@@ -55,18 +54,77 @@ impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
         method: Method,
         params: Arc<AggregatorParams>,
     ) -> Result<Box<dyn Processor>> {
-        Ok(Box::new(BlockMetaTransformer::create(
+        Ok(AccumulatingTransformer::create(
             input,
             output,
             TransformFinalAggregate::<Method> {
                 method,
+                memory_guard: AggregateMemoryGuard::create(params.memory_tracker.clone()),
                 params,
                 flush_state: PayloadFlushState::default(),
+                bucket_row_counts: HashMap::new(),
             },
-        )))
+        ))
+    }
+
+    /// The number of the partial aggregated rows merged by the processor for each bucket,
+    /// which shows the skew of the buckets. `-1` is the unpartitioned bucket.
+    pub fn bucket_row_counts(&self) -> HashMap<isize, usize> {
+        self.bucket_row_counts.clone()
+    }
+
+    fn record_bucket_rows(&mut self, meta: &AggregateMeta<Method, usize>) {
+        if let AggregateMeta::Partitioned { bucket, data } = meta {
+            let rows = data
+                .iter()
+                .map(|bucket_data| match bucket_data {
+                    AggregateMeta::Serialized(payload) => payload.data_block.num_rows(),
+                    AggregateMeta::HashTable(payload) => payload.cell.hashtable.len(),
+                    AggregateMeta::AggregateHashTable(payload) => payload.len(),
+                    _ => 0,
+                })
+                .sum::<usize>();
+            *self.bucket_row_counts.entry(*bucket).or_default() += rows;
+        }
+    }
+
+    /// Merge the partial aggregated states of a bucket, the memory of the merged states is
+    /// tracked until the merger is finished or dropped.
+    fn merge_bucket(
+        &self,
+        meta: AggregateMeta<Method, usize>,
+    ) -> Result<PartialStateMerger<Method>> {
+        let AggregateMeta::Partitioned { bucket, data } = meta else {
+            return Err(ErrorCode::Internal(
+                "TransformFinalAggregate only recv AggregateMeta::Partitioned",
+            ));
+        };
+
+        let mut merger = PartialStateMerger::create(self.method.clone(), self.params.clone())?;
+        for bucket_data in data {
+            match bucket_data {
+                AggregateMeta::Spilled(_) => unreachable!(),
+                AggregateMeta::BucketSpilled(_) => unreachable!(),
+                AggregateMeta::Spilling(_) => unreachable!(),
+                AggregateMeta::Partitioned { .. } => unreachable!(),
+                AggregateMeta::Serialized(payload) => {
+                    debug_assert!(bucket == payload.bucket);
+                    merger.push_chunk(&payload.data_block)?;
+                }
+                AggregateMeta::HashTable(payload) => {
+                    debug_assert!(bucket == payload.bucket);
+                    merger.push_hash_table(&payload.cell)?;
+                }
+                AggregateMeta::AggregateHashTable(_) => unreachable!(),
+            }
+        }
+        Ok(merger)
     }
 
-    fn transform_agg_hashtable(&mut self, meta: AggregateMeta<Method, usize>) -> Result<DataBlock> {
+    fn transform_agg_hashtable(
+        &mut self,
+        meta: AggregateMeta<Method, usize>,
+    ) -> Result<Vec<DataBlock>> {
         let mut agg_hashtable: Option<AggregateHashTable> = None;
         if let AggregateMeta::Partitioned { bucket: _, data } = meta {
             for bucket_data in data {
@@ -91,6 +149,9 @@ impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
                     },
                     _ => unreachable!(),
                 }
+                if let Some(ht) = agg_hashtable.as_ref() {
+                    self.memory_guard.update(ht.payload.memory_size());
+                }
             }
         }
 
@@ -108,194 +169,156 @@ impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
                 }
             }
 
-            if blocks.is_empty() {
-                return Ok(self.params.empty_result_block());
+            if !blocks.is_empty() {
+                let block = DataBlock::concat(&blocks)?;
+                return match self.params.max_block_rows {
+                    0 => Ok(vec![block]),
+                    max_block_rows => Ok(block.split_by_rows_no_tail(max_block_rows)),
+                };
             }
-            return DataBlock::concat(&blocks);
         }
 
-        Ok(self.params.empty_result_block())
+        Ok(vec![self.params.empty_result_block()])
     }
 }
 
-impl<Method> BlockMetaTransform<AggregateMeta<Method, usize>> for TransformFinalAggregate<Method>
+impl<Method> AccumulatingTransform for TransformFinalAggregate<Method>
 where Method: HashMethodBounds
 {
     const NAME: &'static str = "TransformFinalAggregate";
 
-    fn transform(&mut self, meta: AggregateMeta<Method, usize>) -> Result<DataBlock> {
-        if self.params.enable_experimental_aggregate_hashtable {
-            return self.transform_agg_hashtable(meta);
-        }
-
-        if let AggregateMeta::Partitioned { bucket, data } = meta {
-            let mut reach_limit = false;
-            let arena = Arc::new(Bump::new());
-            let hashtable = self.method.create_hash_table::<usize>(arena)?;
-            let _dropper = AggregateHashTableDropper::create(self.params.clone());
-            let mut hash_cell = HashTableCell::<Method, usize>::create(hashtable, _dropper);
-
-            for bucket_data in data {
-                match bucket_data {
-                    AggregateMeta::Spilled(_) => unreachable!(),
-                    AggregateMeta::BucketSpilled(_) => unreachable!(),
-                    AggregateMeta::Spilling(_) => unreachable!(),
-                    AggregateMeta::Partitioned { .. } => unreachable!(),
-                    AggregateMeta::Serialized(payload) => {
-                        debug_assert!(bucket == payload.bucket);
-
-                        let aggregate_function_len = self.params.aggregate_functions.len();
-
-                        let column = payload.get_group_by_column();
-                        let keys_iter = self.method.keys_iter_from_column(column)?;
-
-                        // first state places of current block
-                        let places = {
-                            let keys_iter = keys_iter.iter();
-                            let (len, _) = keys_iter.size_hint();
-                            let mut places = Vec::with_capacity(len);
-
-                            let mut current_len = hash_cell.hashtable.len();
-                            unsafe {
-                                for key in keys_iter {
-                                    if reach_limit {
-                                        let entry = hash_cell.hashtable.entry(key);
-                                        if let Some(entry) = entry {
-                                            let place = Into::<StateAddr>::into(*entry.get());
-                                            places.push(place);
-                                        }
-                                        continue;
-                                    }
-
-                                    match hash_cell.hashtable.insert_and_entry(key) {
-                                        Ok(mut entry) => {
-                                            let place =
-                                                self.params.alloc_layout(&mut hash_cell.arena);
-                                            places.push(place);
-
-                                            *entry.get_mut() = place.addr();
-
-                                            if let Some(limit) = self.params.limit {
-                                                current_len += 1;
-                                                if current_len >= limit {
-                                                    reach_limit = true;
-                                                }
-                                            }
-                                        }
-                                        Err(entry) => {
-                                            let place = Into::<StateAddr>::into(*entry.get());
-                                            places.push(place);
-                                        }
-                                    }
-                                }
-                            }
-
-                            places
-                        };
-
-                        let states_columns = (0..aggregate_function_len)
-                            .map(|i| payload.data_block.get_by_offset(i))
-                            .collect::<Vec<_>>();
-                        let mut states_binary_columns = Vec::with_capacity(states_columns.len());
-
-                        for agg in states_columns.iter().take(aggregate_function_len) {
-                            let col = agg.value.as_column().unwrap();
-                            states_binary_columns.push(col.slice(0..places.len()));
-                        }
+    fn transform(&mut self, mut data: DataBlock) -> Result<Vec<DataBlock>> {
+        // The blocks without the meta of the aggregation are ignored.
+        let Some(meta) = data
+            .take_meta()
+            .and_then(AggregateMeta::<Method, usize>::downcast_from)
+        else {
+            return Ok(vec![]);
+        };
 
-                        let aggregate_functions = &self.params.aggregate_functions;
-                        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+        self.record_bucket_rows(&meta);
 
-                        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
-                            aggregate_function.batch_merge(
-                                &places,
-                                offsets_aggregate_states[idx],
-                                &states_binary_columns[idx],
-                            )?;
-                        }
-                    }
-                    AggregateMeta::HashTable(payload) => unsafe {
-                        debug_assert!(bucket == payload.bucket);
-
-                        let aggregate_functions = &self.params.aggregate_functions;
-                        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
-
-                        for entry in payload.cell.hashtable.iter() {
-                            let place = match hash_cell.hashtable.insert(entry.key()) {
-                                Err(place) => StateAddr::new(*place),
-                                Ok(entry) => {
-                                    let place = self.params.alloc_layout(&mut hash_cell.arena);
-                                    entry.write(place.addr());
-                                    place
-                                }
-                            };
-
-                            let old_place = StateAddr::new(*entry.get());
-                            for (idx, aggregate_function) in aggregate_functions.iter().enumerate()
-                            {
-                                let final_place = place.next(offsets_aggregate_states[idx]);
-                                let state_place = old_place.next(offsets_aggregate_states[idx]);
-                                aggregate_function.merge_states(final_place, state_place)?;
-                            }
-                        }
-                    },
-                    AggregateMeta::AggregateHashTable(_) => unreachable!(),
-                }
-            }
+        if self.params.enable_experimental_aggregate_hashtable {
+            let blocks = self.transform_agg_hashtable(meta);
+            // The hash table of the bucket is dropped once its results are built.
+            self.memory_guard.update(0);
+            return blocks;
+        }
 
-            let keys_len = hash_cell.hashtable.len();
-            let value_size = estimated_key_size(&hash_cell.hashtable);
+        self.merge_bucket(meta)?.finish()
+    }
 
-            let mut group_columns_builder =
-                self.method
-                    .group_columns_builder(keys_len, value_size, &self.params);
+    fn on_finish(&mut self, _output: bool) -> Result<Vec<DataBlock>> {
+        if let Some((bucket, rows)) = self.bucket_row_counts.iter().max_by_key(|(_, rows)| **rows) {
+            let total_rows = self.bucket_row_counts.values().sum::<usize>();
+            info!(
+                "Final aggregate merged {} rows of {} buckets, the largest bucket {} has {} rows",
+                total_rows,
+                self.bucket_row_counts.len(),
+                bucket,
+                rows
+            );
+        }
+        Ok(vec![])
+    }
+}
 
-            let aggregate_functions = &self.params.aggregate_functions;
-            let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use databend_common_exception::Result;
+    use databend_common_expression::DataBlock;
+    use databend_common_expression::PayloadFlushState;
+    use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+    use super::TransformFinalAggregate;
+    use crate::pipelines::processors::transforms::aggregator::partial_state_merger::tests::*;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMeta;
+    use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+    use crate::pipelines::processors::transforms::aggregator::HashTablePayload;
+    use crate::pipelines::processors::transforms::aggregator::SerializedPayload;
+
+    fn create_transform(params: &Arc<AggregatorParams>) -> TransformFinalAggregate<TestMethod> {
+        TransformFinalAggregate::<TestMethod> {
+            method: TestMethod::default(),
+            params: params.clone(),
+            flush_state: PayloadFlushState::default(),
+            bucket_row_counts: HashMap::new(),
+            memory_guard: AggregateMemoryGuard::create(params.memory_tracker.clone()),
+        }
+    }
 
-            let mut aggregates_column_builder = {
-                let mut values = vec![];
-                for aggregate_function in aggregate_functions {
-                    let data_type = aggregate_function.return_type()?;
-                    let builder = ColumnBuilder::with_capacity(&data_type, keys_len);
-                    values.push(builder)
-                }
-                values
+    #[test]
+    fn test_bucket_row_counts() -> Result<()> {
+        let params = create_test_params(0, AggregateMemoryTracker::create())?;
+        let mut transform = create_transform(&params);
+
+        let serialized =
+            |bucket: isize, rows: &[(u64, u64)]| -> Result<AggregateMeta<TestMethod, usize>> {
+                Ok(AggregateMeta::Serialized(SerializedPayload {
+                    bucket,
+                    data_block: create_partial_block(&params, rows)?,
+                }))
             };
+        let hash_table =
+            |bucket: isize, rows: &[(u64, u64)]| -> Result<AggregateMeta<TestMethod, usize>> {
+                Ok(AggregateMeta::HashTable(HashTablePayload {
+                    bucket,
+                    cell: create_partial_cell(&params, rows)?,
+                }))
+            };
+        let partitioned = |bucket: isize, data| {
+            DataBlock::empty_with_meta(AggregateMeta::<TestMethod, usize>::create_partitioned(
+                bucket, data,
+            ))
+        };
+
+        // The rows of the partial aggregated states, not the input rows, are counted.
+        transform.transform(partitioned(0, vec![
+            serialized(0, &[(0, 1), (2, 1), (0, 1)])?,
+            hash_table(0, &[(4, 1), (6, 1)])?,
+        ]))?;
+        transform.transform(partitioned(1, vec![serialized(1, &[
+            (1, 1),
+            (3, 1),
+            (5, 1),
+        ])?]))?;
+        transform.transform(partitioned(0, vec![serialized(0, &[(8, 1)])?]))?;
+        transform.transform(partitioned(-1, vec![hash_table(-1, &[(7, 1), (9, 1)])?]))?;
+
+        let expected = HashMap::from([(0, 5), (1, 3), (-1, 2)]);
+        assert_eq!(transform.bucket_row_counts(), expected);
+        Ok(())
+    }
 
-            let mut places = Vec::with_capacity(keys_len);
-            for group_entity in hash_cell.hashtable.iter() {
-                places.push(StateAddr::new(*group_entity.get()));
-                group_columns_builder.append_value(group_entity.key());
-            }
-
-            for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
-                let builder = aggregates_column_builder[idx].borrow_mut();
-
-                if idx > 0 {
-                    for place in places.iter_mut() {
-                        *place = place.next(
-                            offsets_aggregate_states[idx] - offsets_aggregate_states[idx - 1],
-                        );
-                    }
-                }
-                aggregate_function.batch_merge_result(&places, 0, builder)?;
-            }
-
-            // Build final state block.
-            let mut columns = aggregates_column_builder
-                .into_iter()
-                .map(|builder| builder.build())
-                .collect::<Vec<_>>();
-
-            let group_columns = group_columns_builder.finish()?;
-            columns.extend_from_slice(&group_columns);
-
-            return Ok(DataBlock::new_from_columns(columns));
-        }
-
-        Err(ErrorCode::Internal(
-            "TransformFinalAggregate only recv AggregateMeta::Partitioned",
-        ))
+    #[test]
+    fn test_memory_usage_during_merge() -> Result<()> {
+        let memory_tracker = AggregateMemoryTracker::create();
+        let params = create_test_params(0, memory_tracker.clone())?;
+        let transform = create_transform(&params);
+
+        let rows = (0..1000).map(|k| (k, k)).collect::<Vec<_>>();
+        let merger = transform.merge_bucket(AggregateMeta::<TestMethod, usize>::Partitioned {
+            bucket: 0,
+            data: vec![
+                AggregateMeta::Serialized(SerializedPayload {
+                    bucket: 0,
+                    data_block: create_partial_block(&params, &rows)?,
+                }),
+                AggregateMeta::HashTable(HashTablePayload {
+                    bucket: 0,
+                    cell: create_partial_cell(&params, &rows)?,
+                }),
+            ],
+        })?;
+        assert!(memory_tracker.get_memory_usage() > 0);
+
+        merger.finish()?;
+        assert_eq!(memory_tracker.get_memory_usage(), 0);
+        Ok(())
     }
 }
@@ -43,6 +43,8 @@ use log::info;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::filter_null_keys;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::HashTableCell;
 use crate::pipelines::processors::transforms::aggregator::PartitionedHashTableDropper;
@@ -65,6 +67,17 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
     }
 }
 
+impl<Method: HashMethodBounds> HashTable<Method> {
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            HashTable::MovedOut => 0,
+            HashTable::HashTable(cell) => cell.allocated_bytes(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.payload.memory_size(),
+            HashTable::PartitionedHashTable(cell) => cell.allocated_bytes(),
+        }
+    }
+}
+
 struct AggregateSettings {
     convert_threshold: usize,
     max_memory_usage: usize,
@@ -112,6 +125,7 @@ pub struct TransformPartialAggregate<Method: HashMethodBounds> {
     hash_table: HashTable<Method>,
     probe_state: ProbeState,
     params: Arc<AggregatorParams>,
+    memory_guard: AggregateMemoryGuard,
 }
 
 impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
@@ -148,6 +162,7 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
             output,
             TransformPartialAggregate::<Method> {
                 method,
+                memory_guard: AggregateMemoryGuard::create(params.memory_tracker.clone()),
                 params,
                 hash_table,
                 probe_state: ProbeState::default(),
@@ -236,7 +251,10 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
             .map(|index| index.is_agg)
             .unwrap_or_default();
 
-        let block = block.convert_to_full();
+        let mut block = block.convert_to_full();
+        if self.params.drop_null_keys {
+            block = filter_null_keys(block, &self.params.group_columns)?;
+        }
 
         let group_columns = self
             .params
@@ -378,6 +396,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                         new_hashtable,
                         _dropper.unwrap(),
                     ));
+                    self.memory_guard.update(self.hash_table.allocated_bytes());
                     return Ok(blocks);
                 }
 
@@ -385,10 +404,13 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
             }
         }
 
+        self.memory_guard.update(self.hash_table.allocated_bytes());
         Ok(vec![])
     }
 
     fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        // The hash tables are accounted by the final aggregation once they are merged.
+        self.memory_guard.update(0);
         Ok(match std::mem::take(&mut self.hash_table) {
             HashTable::MovedOut => match !output && std::thread::panicking() {
                 true => vec![],
@@ -31,6 +31,7 @@ use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::estimated_key_size;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::group_by::GroupColumnsBuilder;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
@@ -40,6 +41,7 @@ pub struct TransformFinalGroupBy<Method: HashMethodBounds> {
     method: Method,
     params: Arc<AggregatorParams>,
     flush_state: PayloadFlushState,
+    memory_guard: AggregateMemoryGuard,
 }
 
 impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
@@ -54,6 +56,7 @@ impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
             output,
             TransformFinalGroupBy::<Method> {
                 method,
+                memory_guard: AggregateMemoryGuard::create(params.memory_tracker.clone()),
                 params,
                 flush_state: PayloadFlushState::default(),
             },
@@ -84,6 +87,9 @@ impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
                     },
                     _ => unreachable!(),
                 }
+                if let Some(ht) = agg_hashtable.as_ref() {
+                    self.memory_guard.update(ht.payload.memory_size());
+                }
             }
         }
 
@@ -108,6 +114,72 @@ impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
         }
         Ok(self.params.empty_result_block())
     }
+
+    /// Merge the group by keys of a bucket, the memory of the merged keys is tracked until
+    /// the block is built.
+    fn merge_bucket(&mut self, meta: AggregateMeta<Method, ()>) -> Result<Method::HashTable<()>> {
+        let AggregateMeta::Partitioned { bucket, data } = meta else {
+            return Err(ErrorCode::Internal(
+                "TransformFinalGroupBy only recv AggregateMeta::Partitioned",
+            ));
+        };
+
+        let arena = Arc::new(Bump::new());
+        let mut hashtable = self.method.create_hash_table::<()>(arena)?;
+
+        'merge_hashtable: for bucket_data in data {
+            match bucket_data {
+                AggregateMeta::Spilled(_) => unreachable!(),
+                AggregateMeta::BucketSpilled(_) => unreachable!(),
+                AggregateMeta::Spilling(_) => unreachable!(),
+                AggregateMeta::Partitioned { .. } => unreachable!(),
+                AggregateMeta::Serialized(payload) => {
+                    debug_assert!(bucket == payload.bucket);
+                    let column = payload.get_group_by_column();
+                    let keys_iter = self.method.keys_iter_from_column(column)?;
+
+                    unsafe {
+                        for key in keys_iter.iter() {
+                            let _ = hashtable.insert_and_entry(key);
+                        }
+                    }
+                }
+                AggregateMeta::HashTable(payload) => unsafe {
+                    debug_assert!(bucket == payload.bucket);
+
+                    for key in payload.cell.hashtable.iter() {
+                        let _ = hashtable.insert_and_entry(key.key());
+                    }
+                },
+                AggregateMeta::AggregateHashTable(_) => unreachable!(),
+            }
+
+            self.memory_guard.update(hashtable.bytes_len(false));
+
+            if let Some(limit) = self.params.limit {
+                if hashtable.len() >= limit {
+                    break 'merge_hashtable;
+                }
+            }
+        }
+
+        Ok(hashtable)
+    }
+
+    fn build_block(&self, hashtable: &Method::HashTable<()>) -> Result<DataBlock> {
+        let value_size = estimated_key_size(hashtable);
+        let keys_len = hashtable.len();
+
+        let mut group_columns_builder =
+            self.method
+                .group_columns_builder(keys_len, value_size, &self.params);
+
+        for group_entity in hashtable.iter() {
+            group_columns_builder.append_value(group_entity.key());
+        }
+
+        Ok(DataBlock::new_from_columns(group_columns_builder.finish()?))
+    }
 }
 
 impl<Method> BlockMetaTransform<AggregateMeta<Method, ()>> for TransformFinalGroupBy<Method>
@@ -117,69 +189,89 @@ where Method: HashMethodBounds
 
     fn transform(&mut self, meta: AggregateMeta<Method, ()>) -> Result<DataBlock> {
         if self.params.enable_experimental_aggregate_hashtable {
-            return self.transform_agg_hashtable(meta);
+            let block = self.transform_agg_hashtable(meta);
+            self.memory_guard.update(0);
+            return block;
         }
 
-        if let AggregateMeta::Partitioned { bucket, data } = meta {
-            let arena = Arc::new(Bump::new());
-            let mut hashtable = self.method.create_hash_table::<()>(arena)?;
-
-            'merge_hashtable: for bucket_data in data {
-                match bucket_data {
-                    AggregateMeta::Spilled(_) => unreachable!(),
-                    AggregateMeta::BucketSpilled(_) => unreachable!(),
-                    AggregateMeta::Spilling(_) => unreachable!(),
-                    AggregateMeta::Partitioned { .. } => unreachable!(),
-                    AggregateMeta::Serialized(payload) => {
-                        debug_assert!(bucket == payload.bucket);
-                        let column = payload.get_group_by_column();
-                        let keys_iter = self.method.keys_iter_from_column(column)?;
-
-                        unsafe {
-                            for key in keys_iter.iter() {
-                                let _ = hashtable.insert_and_entry(key);
-                            }
-
-                            if let Some(limit) = self.params.limit {
-                                if hashtable.len() >= limit {
-                                    break 'merge_hashtable;
-                                }
-                            }
-                        }
-                    }
-                    AggregateMeta::HashTable(payload) => unsafe {
-                        debug_assert!(bucket == payload.bucket);
+        let hashtable = self.merge_bucket(meta)?;
+        let block = self.build_block(&hashtable);
+        // The keys of the bucket are released once the block is built.
+        drop(hashtable);
+        self.memory_guard.update(0);
+        block
+    }
+}
 
-                        for key in payload.cell.hashtable.iter() {
-                            let _ = hashtable.insert_and_entry(key.key());
-                        }
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
 
-                        if let Some(limit) = self.params.limit {
-                            if hashtable.len() >= limit {
-                                break 'merge_hashtable;
-                            }
-                        }
-                    },
-                    AggregateMeta::AggregateHashTable(_) => unreachable!(),
-                }
-            }
+    use databend_common_exception::Result;
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::UInt64Type;
+    use databend_common_expression::DataBlock;
+    use databend_common_expression::DataField;
+    use databend_common_expression::DataSchemaRefExt;
+    use databend_common_expression::FromData;
+    use databend_common_expression::PayloadFlushState;
 
-            let value_size = estimated_key_size(&hashtable);
-            let keys_len = hashtable.len();
+    use super::TransformFinalGroupBy;
+    use crate::pipelines::processors::transforms::aggregator::partial_state_merger::tests::TestMethod;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMeta;
+    use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+    use crate::pipelines::processors::transforms::aggregator::SerializedPayload;
 
-            let mut group_columns_builder =
-                self.method
-                    .group_columns_builder(keys_len, value_size, &self.params);
+    #[test]
+    fn test_memory_usage_during_merge() -> Result<()> {
+        let memory_tracker = AggregateMemoryTracker::create();
+        // The params of `SELECT k FROM t GROUP BY k`, `k` is `UInt64`.
+        let uint64 = DataType::Number(NumberDataType::UInt64);
+        let schema = DataSchemaRefExt::create(vec![DataField::new("k", uint64.clone())]);
+        let params = AggregatorParams::try_create(
+            schema,
+            vec![uint64],
+            &[0],
+            &[],
+            &[],
+            false,
+            65536,
+            None,
+            false,
+            0,
+            memory_tracker.clone(),
+        )?;
+        let mut transform = TransformFinalGroupBy::<TestMethod> {
+            method: TestMethod::default(),
+            memory_guard: AggregateMemoryGuard::create(memory_tracker.clone()),
+            params,
+            flush_state: PayloadFlushState::default(),
+        };
 
-            for group_entity in hashtable.iter() {
-                group_columns_builder.append_value(group_entity.key());
-            }
+        let serialized = |keys: Vec<u64>| {
+            AggregateMeta::<TestMethod, ()>::Serialized(SerializedPayload {
+                bucket: 0,
+                data_block: DataBlock::new_from_columns(vec![UInt64Type::from_data(keys)]),
+            })
+        };
+        let hashtable = transform.merge_bucket(AggregateMeta::Partitioned {
+            bucket: 0,
+            data: vec![
+                serialized((0..1000).collect()),
+                serialized((500..1500).collect()),
+            ],
+        })?;
+        assert!(memory_tracker.get_memory_usage() > 0);
 
-            return Ok(DataBlock::new_from_columns(group_columns_builder.finish()?));
-        }
+        let block = transform.build_block(&hashtable)?;
+        assert_eq!(block.num_rows(), 1500);
 
-        Err(ErrorCode::Internal(
-            "TransformFinalGroupBy only recv AggregateMeta::Partitioned",
-        ))
+        drop(hashtable);
+        transform.memory_guard.update(0);
+        assert_eq!(memory_tracker.get_memory_usage(), 0);
+        Ok(())
     }
 }
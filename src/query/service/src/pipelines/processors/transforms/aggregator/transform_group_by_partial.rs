@@ -39,6 +39,8 @@ use log::info;
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::GroupByHashTableDropper;
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::HashTableCell;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::filter_null_keys;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryGuard;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::PartitionedHashTableDropper;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
@@ -60,6 +62,17 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
     }
 }
 
+impl<Method: HashMethodBounds> HashTable<Method> {
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            HashTable::MovedOut => 0,
+            HashTable::HashTable(cell) => cell.allocated_bytes(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.payload.memory_size(),
+            HashTable::PartitionedHashTable(cell) => cell.allocated_bytes(),
+        }
+    }
+}
+
 struct GroupBySettings {
     convert_threshold: usize,
     max_memory_usage: usize,
@@ -105,8 +118,10 @@ pub struct TransformPartialGroupBy<Method: HashMethodBounds> {
     method: Method,
     hash_table: HashTable<Method>,
     group_columns: Vec<IndexType>,
+    drop_null_keys: bool,
     probe_state: ProbeState,
     settings: GroupBySettings,
+    memory_guard: AggregateMemoryGuard,
 }
 
 impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
@@ -139,7 +154,9 @@ impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
                 hash_table,
                 probe_state: ProbeState::default(),
                 group_columns: params.group_columns.clone(),
+                drop_null_keys: params.drop_null_keys,
                 settings: GroupBySettings::try_from(ctx)?,
+                memory_guard: AggregateMemoryGuard::create(params.memory_tracker.clone()),
             },
         ))
     }
@@ -149,7 +166,10 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
     const NAME: &'static str = "TransformPartialGroupBy";
 
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
-        let block = block.convert_to_full();
+        let mut block = block.convert_to_full();
+        if self.drop_null_keys {
+            block = filter_null_keys(block, &self.group_columns)?;
+        }
         let group_columns = self
             .group_columns
             .iter()
@@ -222,6 +242,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                             new_hashtable,
                             _dropper.unwrap(),
                         ));
+                        self.memory_guard.update(self.hash_table.allocated_bytes());
                         return Ok(blocks);
                     }
 
@@ -230,10 +251,13 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
             }
         }
 
+        self.memory_guard.update(self.hash_table.allocated_bytes());
         Ok(vec![])
     }
 
     fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        // The hash tables are passed to the final group by.
+        self.memory_guard.update(0);
         Ok(match std::mem::take(&mut self.hash_table) {
             HashTable::MovedOut => match !output && std::thread::panicking() {
                 true => vec![],
@@ -62,16 +62,12 @@ impl PartialSingleStateAggregator {
             .ok_or_else(|| ErrorCode::LayoutError("layout shouldn't be None"))?;
 
         let place: StateAddr = arena.alloc_layout(layout).into();
-        let temp_place: StateAddr = arena.alloc_layout(layout).into();
         let mut places = Vec::with_capacity(params.offsets_aggregate_states.len());
 
         for (idx, func) in params.aggregate_functions.iter().enumerate() {
             let arg_place = place.next(params.offsets_aggregate_states[idx]);
             func.init_state(arg_place);
             places.push(arg_place);
-
-            let state_place = temp_place.next(params.offsets_aggregate_states[idx]);
-            func.init_state(state_place);
         }
 
         Ok(AccumulatingTransformer::create(
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::Result;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
 use databend_common_functions::aggregates::AggregateFunctionRef;
 use databend_common_hashtable::HashtableLike;
 
@@ -25,3 +29,25 @@ pub fn create_state_serializer(func: &AggregateFunctionRef, row: usize) -> Binar
     let size = func.serialize_size_per_row().unwrap_or(4);
     BinaryColumnBuilder::with_capacity(row, row * size)
 }
+
+/// Remove the rows having NULL in any of the group by columns, the block should be
+/// `convert_to_full`.
+pub fn filter_null_keys(block: DataBlock, group_columns: &[usize]) -> Result<DataBlock> {
+    let mut validity: Option<Bitmap> = None;
+    for index in group_columns {
+        let column_validity = match block.get_by_offset(*index).value.as_column().unwrap() {
+            Column::Null { len } => Bitmap::new_zeroed(*len),
+            Column::Nullable(column) => column.validity.clone(),
+            _ => continue,
+        };
+        validity = Some(match validity {
+            Some(validity) => &validity & &column_validity,
+            None => column_validity,
+        });
+    }
+
+    match validity {
+        Some(validity) => block.filter_with_bitmap(&validity),
+        None => Ok(block),
+    }
+}
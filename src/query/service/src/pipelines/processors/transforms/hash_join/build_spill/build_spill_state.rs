@@ -23,7 +23,6 @@ use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
-use databend_common_hashtable::hash2bucket;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_storage::DataOperator;
 use log::info;
@@ -35,6 +34,10 @@ use crate::sessions::QueryContext;
 use crate::spillers::Spiller;
 use crate::spillers::SpillerConfig;
 use crate::spillers::SpillerType;
+use crate::spillers::DEFAULT_PARTITION_BITS;
+
+// The id of the partition to restore is sent to the build processors as an `i8`.
+const MAX_PARTITION_BITS: u32 = 7;
 
 /// Define some states for hash join build spilling
 /// Each processor owns its `BuildSpillState`
@@ -93,21 +96,55 @@ impl BuildSpillState {
         Ok(blocks)
     }
 
+    // Choose the number of partitions, so that the build data of each partition is estimated
+    // to fit in half of `join_spilling_threshold` when it's restored to build the hash table.
+    // The data of the whole build side is estimated by the buffered data and the estimated rows.
+    fn choose_partition_bits(&self, buffered_blocks: &[DataBlock]) -> Result<u32> {
+        let (buffered_rows, buffered_bytes) =
+            buffered_blocks.iter().fold((0, 0), |(rows, bytes), block| {
+                (rows + block.num_rows(), bytes + block.memory_size())
+            });
+        let mut total_bytes = buffered_bytes as f64;
+        if let Some(estimated_rows) = self
+            .build_state
+            .hash_join_state
+            .hash_join_desc
+            .build_estimated_rows
+            && buffered_rows != 0
+        {
+            total_bytes =
+                total_bytes.max(buffered_bytes as f64 / buffered_rows as f64 * estimated_rows);
+        }
+
+        let spill_threshold = self
+            .build_state
+            .ctx
+            .get_settings()
+            .get_join_spilling_threshold()?;
+        let partition_bytes = std::cmp::max(spill_threshold / 2, 1) as f64;
+        let partitions =
+            ((total_bytes / partition_bytes).ceil() as usize).min(1 << MAX_PARTITION_BITS);
+        let bits = partitions.next_power_of_two().trailing_zeros();
+        Ok(bits.clamp(DEFAULT_PARTITION_BITS, MAX_PARTITION_BITS))
+    }
+
     // Partition input blocks to different partitions.
     // Output is <partition_id, blocks>
     fn partition_input_blocks(
         &self,
         input_blocks: Vec<DataBlock>,
+        partition_bits: u32,
     ) -> Result<HashMap<u8, Vec<DataBlock>>> {
         let mut partition_blocks = HashMap::new();
+        let mask = (1 << partition_bits) - 1;
         for block in input_blocks {
             let mut hashes = Vec::with_capacity(block.num_rows());
             self.get_hashes(&block, &mut hashes)?;
             let mut indices = Vec::with_capacity(hashes.len());
             for hash in hashes {
-                indices.push(hash2bucket::<3, false>(hash as usize) as u8);
+                indices.push((hash & mask) as u8);
             }
-            let scatter_blocks = DataBlock::scatter(&block, &indices, 1 << 3)?;
+            let scatter_blocks = DataBlock::scatter(&block, &indices, 1 << partition_bits)?;
             for (p_id, p_block) in scatter_blocks.into_iter().enumerate() {
                 partition_blocks
                     .entry(p_id as u8)
@@ -128,6 +165,12 @@ impl BuildSpillState {
             let mut spill_tasks = self.spill_coordinator.spill_tasks.lock();
             spill_tasks.pop_back().unwrap()
         };
+        let partition_bits = self
+            .build_state
+            .hash_join_state
+            .spill_partition_bits
+            .load(Ordering::Acquire);
+        self.spiller.set_partition_bits(partition_bits);
         self.spiller.spill(spill_partitions, p_id).await
     }
 
@@ -234,7 +277,16 @@ impl BuildSpillState {
         spill_tasks: &mut VecDeque<Vec<(u8, DataBlock)>>,
     ) -> Result<()> {
         let blocks = self.collect_rows()?;
-        let partition_blocks = self.partition_input_blocks(blocks)?;
+        // The partitions are chosen once, the following spills use the same partitions.
+        let partition_bits = self
+            .build_state
+            .hash_join_state
+            .init_spill_partition_bits(self.choose_partition_bits(&blocks)?);
+        info!(
+            "hash join build spills to {} partitions",
+            1 << partition_bits
+        );
+        let partition_blocks = self.partition_input_blocks(blocks, partition_bits)?;
         // self.pick_partitions(&mut partition_blocks)?;
         let mut partition_tasks = HashMap::with_capacity(partition_blocks.len());
         // Stat how many rows in each partition, then split it equally.
@@ -49,6 +49,8 @@ pub struct HashJoinDesc {
     pub broadcast: bool,
     // If enable bloom runtime filter
     pub enable_bloom_runtime_filter: bool,
+    // The estimated number of rows of the build side, used to choose the number of spill partitions.
+    pub(crate) build_estimated_rows: Option<f64>,
 }
 
 impl HashJoinDesc {
@@ -90,6 +92,7 @@ impl HashJoinDesc {
             broadcast: join.broadcast,
             original_join_type: join.original_join_type.clone(),
             enable_bloom_runtime_filter: join.enable_bloom_runtime_filter,
+            build_estimated_rows: join.build.get_estimated_rows(),
         })
     }
 
@@ -16,6 +16,7 @@ use std::cell::SyncUnsafeCell;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicI8;
+use std::sync::atomic::AtomicU32;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -109,6 +110,9 @@ pub struct HashJoinState {
     /// Spill related states
     /// Spill partition set
     pub(crate) build_spilled_partitions: RwLock<HashSet<u8>>,
+    /// The number of bits of the hashes used to partition the spilled data, 0 if not chosen yet.
+    /// It's chosen by the first spill of the build side, and the probe side is partitioned alike.
+    pub(crate) spill_partition_bits: AtomicU32,
     /// Send message to notify all build processors to next round.
     /// Initial message is false, send true to wake up all build processors.
     pub(crate) continue_build_watcher: Sender<bool>,
@@ -162,6 +166,7 @@ impl HashJoinState {
             row_space: RowSpace::new(ctx, build_schema, build_projections)?,
             build_state: SyncUnsafeCell::new(BuildState::new()),
             build_spilled_partitions: Default::default(),
+            spill_partition_bits: AtomicU32::new(0),
             continue_build_watcher,
             _continue_build_dummy_receiver,
             partition_id: AtomicI8::new(-2),
@@ -226,6 +231,20 @@ impl HashJoinState {
         spill_partition.extend(partitions);
     }
 
+    /// Set the number of spill partition bits if they are not chosen yet,
+    /// and return the bits in use.
+    pub(crate) fn init_spill_partition_bits(&self, bits: u32) -> u32 {
+        match self.spill_partition_bits.compare_exchange(
+            0,
+            bits,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => bits,
+            Err(bits) => bits,
+        }
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn wait_probe_notify(&self) -> Result<()> {
         let mut rx = self.continue_build_watcher.subscribe();
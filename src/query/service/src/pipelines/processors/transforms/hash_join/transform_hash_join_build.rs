@@ -354,8 +354,8 @@ impl Processor for TransformHashJoinBuild {
             HashJoinBuildStep::WaitProbe => {
                 self.build_state.hash_join_state.wait_probe_notify().await?;
                 // Currently, each processor will read its own partition
-                // Note: we assume that the partition files will fit into memory
-                // later, will introduce multiple level spill or other way to handle this.
+                // Note: the number of partitions is chosen to fit a partition into memory by the estimated
+                // size of the build side, later, will introduce multiple level spill to handle skewed data.
                 let partition_id = self
                     .build_state
                     .hash_join_state
@@ -503,6 +503,13 @@ impl Processor for TransformHashJoinProbe {
             HashJoinProbeStep::Spill => {
                 if let Some(data) = self.input_data.pop_front() {
                     let spill_state = self.spill_state.as_mut().unwrap();
+                    // Partition the probe data like the build data.
+                    let partition_bits = self
+                        .join_probe_state
+                        .hash_join_state
+                        .spill_partition_bits
+                        .load(Ordering::Acquire);
+                    spill_state.spiller.set_partition_bits(partition_bits);
                     let mut hashes = Vec::with_capacity(data.num_rows());
                     spill_state.get_hashes(&data, &mut hashes)?;
                     // Pass build spilled partition set, we only need to spill data in build spilled partition set
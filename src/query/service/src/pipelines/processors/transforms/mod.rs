@@ -32,6 +32,7 @@ mod transform_merge_block;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
+mod transform_shared_scan;
 mod transform_sort_spill;
 mod transform_srf;
 mod transform_udf;
@@ -56,6 +57,10 @@ pub use transform_merge_block::TransformMergeBlock;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
+pub use transform_shared_scan::SharedChunkBuffer;
+pub use transform_shared_scan::SharedChunkConsumer;
+pub use transform_shared_scan::SharedChunkSink;
+pub use transform_shared_scan::SharedChunkSource;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
 pub use transform_udf::TransformUdf;
@@ -0,0 +1,321 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use parking_lot::Mutex;
+
+struct SharedChunkState {
+    chunks: VecDeque<DataBlock>,
+    // The index of the front block in the sequence of all the blocks pushed.
+    first_index: usize,
+    // The index of the next block to read for each consumer, `None` once it is detached.
+    cursors: Vec<Option<usize>>,
+    finished: bool,
+}
+
+/// The blocks of a table scan shared by the branches of a query, e.g. the two sides of a
+/// `UNION ALL` reading the same table.
+///
+/// The scan is executed once by the pipeline built for the first consumer, and fills the
+/// buffer with `SharedChunkSink`. Each consumer reads the blocks in order with its own
+/// cursor, see `SharedChunkSource`. A block is dropped once all the consumers have read it,
+/// and the producer waits while `capacity` blocks are buffered, so the fastest consumer is
+/// at most `capacity` blocks ahead of the slowest one. The producer stops once all the
+/// consumers are finished, e.g. by a `LIMIT`.
+pub struct SharedChunkBuffer {
+    state: Mutex<SharedChunkState>,
+    capacity: usize,
+    data_notify: Notify,
+    space_notify: Notify,
+    running_sinkers: AtomicUsize,
+    next_consumer: AtomicUsize,
+    producer_built: AtomicBool,
+}
+
+impl SharedChunkBuffer {
+    pub fn create(num_consumers: usize, capacity: usize) -> Arc<Self> {
+        Arc::new(SharedChunkBuffer {
+            state: Mutex::new(SharedChunkState {
+                chunks: VecDeque::new(),
+                first_index: 0,
+                cursors: vec![Some(0); num_consumers],
+                finished: false,
+            }),
+            capacity: capacity.max(1),
+            data_notify: Notify::new(),
+            space_notify: Notify::new(),
+            running_sinkers: AtomicUsize::new(0),
+            next_consumer: AtomicUsize::new(0),
+            producer_built: AtomicBool::new(false),
+        })
+    }
+
+    /// Returns true only the first time, the caller should build the pipeline filling the buffer.
+    pub fn try_build_producer(&self) -> bool {
+        !self.producer_built.swap(true, Ordering::SeqCst)
+    }
+
+    /// Register a consumer of the buffer, the sources of the consumer share it.
+    pub fn create_consumer(self: &Arc<Self>) -> Arc<SharedChunkConsumer> {
+        let id = self.next_consumer.fetch_add(1, Ordering::SeqCst);
+        debug_assert!(id < self.state.lock().cursors.len());
+        Arc::new(SharedChunkConsumer {
+            buffer: self.clone(),
+            id,
+            running_sources: AtomicUsize::new(0),
+        })
+    }
+
+    fn attach_sinker(&self) {
+        self.running_sinkers.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn detach_sinker(&self) {
+        if self.running_sinkers.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Hold the lock, so that a consumer can't miss the notification between
+            // checking `finished` and waiting.
+            let mut state = self.state.lock();
+            state.finished = true;
+            self.data_notify.notify_waiters();
+        }
+    }
+
+    /// Push a block, wait while the buffer is full. Returns false once all the consumers are
+    /// detached, the producer doesn't need to go on.
+    async fn push(&self, block: DataBlock) -> bool {
+        loop {
+            let notified = {
+                let mut state = self.state.lock();
+                if state.cursors.iter().all(Option::is_none) {
+                    return false;
+                }
+                if state.chunks.len() < self.capacity {
+                    state.chunks.push_back(block);
+                    self.data_notify.notify_waiters();
+                    return true;
+                }
+                self.space_notify.notified()
+            };
+            notified.await;
+        }
+    }
+
+    /// Read the next block of the consumer, wait for the producer if the consumer read all
+    /// the blocks so far. Returns `None` once all the blocks are read.
+    async fn read(&self, id: usize) -> Option<DataBlock> {
+        loop {
+            let notified = {
+                let mut state = self.state.lock();
+                let Some(cursor) = state.cursors[id] else {
+                    return None;
+                };
+                let offset = cursor - state.first_index;
+                if let Some(block) = state.chunks.get(offset).cloned() {
+                    state.cursors[id] = Some(cursor + 1);
+                    if offset == 0 {
+                        self.release(&mut state);
+                    }
+                    return Some(block);
+                }
+                if state.finished {
+                    return None;
+                }
+                self.data_notify.notified()
+            };
+            notified.await;
+        }
+    }
+
+    fn detach_consumer(&self, id: usize) {
+        let mut state = self.state.lock();
+        if state.cursors[id].take().is_some() {
+            self.release(&mut state);
+        }
+    }
+
+    // Drop the blocks read by all the consumers, and wake up the producer.
+    fn release(&self, state: &mut SharedChunkState) {
+        let min_cursor = state.cursors.iter().flatten().min().copied();
+        let min_cursor = min_cursor.unwrap_or(state.first_index + state.chunks.len());
+        while state.first_index < min_cursor {
+            state.chunks.pop_front();
+            state.first_index += 1;
+        }
+        self.space_notify.notify_waiters();
+    }
+}
+
+/// A consumer of a `SharedChunkBuffer`, shared by the parallel sources of the consumer so
+/// that each block is read once by the consumer. The consumer is detached from the buffer
+/// once all its sources are finished.
+pub struct SharedChunkConsumer {
+    buffer: Arc<SharedChunkBuffer>,
+    id: usize,
+    running_sources: AtomicUsize,
+}
+
+impl SharedChunkConsumer {
+    fn attach_source(&self) {
+        self.running_sources.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn detach_source(&self) {
+        if self.running_sources.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.buffer.detach_consumer(self.id);
+        }
+    }
+}
+
+pub struct SharedChunkSink {
+    buffer: Arc<SharedChunkBuffer>,
+    finished: bool,
+}
+
+impl SharedChunkSink {
+    pub fn create(buffer: Arc<SharedChunkBuffer>) -> Self {
+        buffer.attach_sinker();
+        SharedChunkSink {
+            buffer,
+            finished: false,
+        }
+    }
+
+    fn finish(&mut self) {
+        if !self.finished {
+            self.finished = true;
+            self.buffer.detach_sinker();
+        }
+    }
+}
+
+impl Drop for SharedChunkSink {
+    fn drop(&mut self) {
+        // Don't leave the consumers waiting if the producer is aborted.
+        self.finish();
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for SharedChunkSink {
+    const NAME: &'static str = "SharedChunkSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.finish();
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        // Finish the producer once no consumer needs the blocks.
+        Ok(!self.buffer.push(data_block).await)
+    }
+}
+
+/// Reads the blocks of a `SharedChunkBuffer` for a consumer of the shared scan.
+pub struct SharedChunkSource {
+    output: Arc<OutputPort>,
+    consumer: Arc<SharedChunkConsumer>,
+    data: Option<DataBlock>,
+    exhausted: bool,
+    detached: bool,
+}
+
+impl SharedChunkSource {
+    pub fn create(
+        output: Arc<OutputPort>,
+        consumer: Arc<SharedChunkConsumer>,
+    ) -> Result<ProcessorPtr> {
+        consumer.attach_source();
+        Ok(ProcessorPtr::create(Box::new(SharedChunkSource {
+            output,
+            consumer,
+            data: None,
+            exhausted: false,
+            detached: false,
+        })))
+    }
+
+    fn detach(&mut self) {
+        if !self.detached {
+            self.detached = true;
+            self.consumer.detach_source();
+        }
+    }
+}
+
+impl Drop for SharedChunkSource {
+    fn drop(&mut self) {
+        self.detach();
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for SharedChunkSource {
+    fn name(&self) -> String {
+        "SharedChunkSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.detach();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.data.take() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.exhausted {
+            self.output.finish();
+            self.detach();
+            return Ok(Event::Finished);
+        }
+
+        Ok(Event::Async)
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.consumer.buffer.read(self.consumer.id).await {
+            Some(data_block) => self.data = Some(data_block),
+            None => self.exhausted = true,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use log::info;
+
+use crate::sessions::QueryContext;
+use crate::sql::executor::PhysicalPlan;
+
+/// Reduce the parallelism of a query by the actual data volume it reads.
+///
+/// The partitions of the table scans are already pruned while building the physical plan,
+/// so the read bytes of them are much closer to the actual data than the estimation at the
+/// query start, it's a waste to spawn `max_threads` threads for a query reading little data.
+pub struct DataVolumeAdaptiveScheduler;
+
+impl DataVolumeAdaptiveScheduler {
+    /// Lower `max_threads` of the query to `max(1, ceil(read_bytes / min_bytes_per_thread))`.
+    ///
+    /// It only changes the settings of the query itself, not the ones of the session.
+    pub fn adjust_max_threads(ctx: &Arc<QueryContext>, plan: &PhysicalPlan) -> Result<()> {
+        let settings = ctx.get_settings();
+        let min_bytes_per_thread = settings.get_min_bytes_per_thread()?;
+        if min_bytes_per_thread == 0 {
+            return Ok(());
+        }
+
+        let mut has_table_scan = false;
+        let mut read_bytes = 0_u64;
+        PhysicalPlan::traverse(
+            plan,
+            &mut |_| true,
+            &mut |plan| {
+                if let PhysicalPlan::TableScan(scan) = plan {
+                    has_table_scan = true;
+                    read_bytes += scan.source.statistics.read_bytes as u64;
+                }
+            },
+            &mut |_| {},
+        );
+
+        // The data volume of the other sources, e.g. values or stages, is not known here.
+        if !has_table_scan {
+            return Ok(());
+        }
+
+        let max_threads = settings.get_max_threads()?;
+        let threads = std::cmp::max(1, read_bytes.div_ceil(min_bytes_per_thread));
+        if threads < max_threads {
+            info!(
+                "Adjust max_threads from {} to {}, read bytes: {}, min bytes per thread: {}",
+                max_threads, threads, read_bytes, min_bytes_per_thread
+            );
+            settings.set_max_threads(threads)?;
+        }
+        Ok(())
+    }
+}
@@ -15,7 +15,11 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::MetadataRef;
+use itertools::Itertools;
 
 use crate::api::DataExchange;
 use crate::schedulers::QueryFragmentActions;
@@ -88,3 +92,192 @@ impl<'a> Display for QueryFragmentActionsWrap<'a> {
         Ok(())
     }
 }
+
+impl QueryFragmentsActions {
+    /// Summaries of the fragments, used by `EXPLAIN DISTRIBUTED`.
+    pub fn distributed_summaries(
+        &self,
+        metadata: &MetadataRef,
+    ) -> Result<Vec<DistributedFragmentSummary>> {
+        self.fragments_actions
+            .iter()
+            .map(|fragment_actions| {
+                DistributedFragmentSummary::try_create(
+                    fragment_actions,
+                    &self.get_local_executor(),
+                    metadata,
+                )
+            })
+            .collect()
+    }
+}
+
+/// The distributed properties of a fragment: how its output is exchanged,
+/// which nodes it runs on and which fragments it reads from.
+#[derive(serde::Serialize)]
+pub struct DistributedFragmentSummary {
+    pub fragment_id: usize,
+    pub exchange: Option<String>,
+    pub shuffle_keys: Vec<String>,
+    pub destinations: Vec<String>,
+    pub executors: Vec<String>,
+    pub source_fragments: Vec<usize>,
+    pub estimated_rows: Option<f64>,
+    pub plan: String,
+    #[serde(skip)]
+    pub local_executor: String,
+}
+
+impl DistributedFragmentSummary {
+    fn try_create(
+        fragment_actions: &QueryFragmentActions,
+        local_executor: &str,
+        metadata: &MetadataRef,
+    ) -> Result<Self> {
+        let (exchange, shuffle_keys) = match &fragment_actions.data_exchange {
+            None => (None, vec![]),
+            Some(DataExchange::Merge(_)) => (Some("Merge".to_string()), vec![]),
+            Some(DataExchange::Broadcast(_)) => (Some("Broadcast".to_string()), vec![]),
+            Some(DataExchange::ShuffleDataExchange(exchange)) => (
+                Some("Shuffle".to_string()),
+                exchange
+                    .shuffle_keys
+                    .iter()
+                    .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                    .collect(),
+            ),
+        };
+        let destinations = fragment_actions
+            .data_exchange
+            .as_ref()
+            .map(|exchange| exchange.get_destinations())
+            .unwrap_or_default();
+        let executors = fragment_actions
+            .fragment_actions
+            .iter()
+            .map(|action| action.executor.clone())
+            .collect();
+
+        let (source_fragments, estimated_rows, plan) =
+            match fragment_actions.fragment_actions.first() {
+                None => (vec![], None, String::new()),
+                Some(action) => (
+                    source_fragments(&action.physical_plan),
+                    estimated_rows(&action.physical_plan),
+                    action
+                        .physical_plan
+                        .format(metadata.clone(), Default::default())?
+                        .format_pretty()?,
+                ),
+            };
+
+        Ok(DistributedFragmentSummary {
+            fragment_id: fragment_actions.fragment_id,
+            exchange,
+            shuffle_keys,
+            destinations,
+            executors,
+            source_fragments,
+            estimated_rows,
+            plan,
+            local_executor: local_executor.to_string(),
+        })
+    }
+
+    // Node ids are not stable, display the role of the nodes instead.
+    fn display_nodes(&self, nodes: &[String]) -> String {
+        match nodes {
+            [node] if node == &self.local_executor => "coordinator".to_string(),
+            [_] => "1 node".to_string(),
+            nodes => format!("{} nodes", nodes.len()),
+        }
+    }
+}
+
+impl Display for DistributedFragmentSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Fragment {}:", self.fragment_id)?;
+        if let Some(exchange) = &self.exchange {
+            writeln!(f, "  DataExchange: {exchange}")?;
+        }
+        if !self.shuffle_keys.is_empty() {
+            writeln!(f, "  ShuffleKeys: [{}]", self.shuffle_keys.join(", "))?;
+        }
+        if !self.destinations.is_empty() {
+            writeln!(
+                f,
+                "  Destinations: {}",
+                self.display_nodes(&self.destinations)
+            )?;
+        }
+        writeln!(f, "  Executors: {}", self.display_nodes(&self.executors))?;
+        if !self.source_fragments.is_empty() {
+            writeln!(
+                f,
+                "  SourceFragments: [{}]",
+                self.source_fragments.iter().join(", ")
+            )?;
+        }
+        if let Some(estimated_rows) = self.estimated_rows {
+            writeln!(f, "  EstimatedRows: {estimated_rows:.2}")?;
+        }
+        for line in self.plan.lines() {
+            writeln!(f, "    {line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Fragments whose outputs are read by the plan through exchange sources.
+fn source_fragments(plan: &PhysicalPlan) -> Vec<usize> {
+    let mut fragments = vec![];
+    PhysicalPlan::traverse(
+        plan,
+        &mut |_| true,
+        &mut |plan| {
+            if let PhysicalPlan::ExchangeSource(source) = plan {
+                fragments.push(source.source_fragment_id);
+            }
+        },
+        &mut |_| {},
+    );
+    fragments
+}
+
+/// Estimated rows of the topmost operator with statistics, which is the data volume
+/// sent through the exchange of the fragment.
+fn estimated_rows(plan: &PhysicalPlan) -> Option<f64> {
+    let mut estimated_rows = None;
+    PhysicalPlan::traverse(
+        plan,
+        &mut |_| true,
+        &mut |plan| {
+            if estimated_rows.is_some() {
+                return;
+            }
+            let stat_info = match plan {
+                PhysicalPlan::TableScan(plan) => &plan.stat_info,
+                PhysicalPlan::Filter(plan) => &plan.stat_info,
+                PhysicalPlan::Project(plan) => &plan.stat_info,
+                PhysicalPlan::EvalScalar(plan) => &plan.stat_info,
+                PhysicalPlan::AggregateExpand(plan) => &plan.stat_info,
+                PhysicalPlan::AggregatePartial(plan) => &plan.stat_info,
+                PhysicalPlan::AggregateFinal(plan) => &plan.stat_info,
+                PhysicalPlan::Sort(plan) => &plan.stat_info,
+                PhysicalPlan::Limit(plan) => &plan.stat_info,
+                PhysicalPlan::RowFetch(plan) => &plan.stat_info,
+                PhysicalPlan::HashJoin(plan) => &plan.stat_info,
+                PhysicalPlan::RangeJoin(plan) => &plan.stat_info,
+                PhysicalPlan::UnionAll(plan) => &plan.stat_info,
+                PhysicalPlan::ProjectSet(plan) => &plan.stat_info,
+                PhysicalPlan::Udf(plan) => &plan.stat_info,
+                _ => return,
+            };
+            if let Some(stat_info) = stat_info {
+                estimated_rows = Some(stat_info.estimated_rows);
+            }
+        },
+        &mut |_| {},
+    );
+    estimated_rows
+}
@@ -14,10 +14,17 @@
 
 use std::sync::Arc;
 
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use log::warn;
 
+use crate::clusters::Cluster;
+use crate::clusters::ClusterDiscovery;
+use crate::clusters::ClusterHelper;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
+use crate::schedulers::DataVolumeAdaptiveScheduler;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
@@ -67,6 +74,8 @@ pub async fn build_local_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
 ) -> Result<PipelineBuildResult> {
+    DataVolumeAdaptiveScheduler::adjust_max_threads(ctx, plan)?;
+
     let pipeline = PipelineBuilder::create(
         ctx.get_function_context()?,
         ctx.get_settings(),
@@ -81,10 +90,53 @@ pub async fn build_local_pipeline(
 }
 
 /// Build distributed pipeline via fragment and actions.
+///
+/// If a node cannot be connected while dispatching the fragments, no fragment has been
+/// executed yet, so the whole query is re-dispatched on the rediscovered cluster without the
+/// nodes which could not be connected, at most `max_query_retry_on_failure` times.
 #[async_backtrace::framed]
 pub async fn build_distributed_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
+) -> Result<PipelineBuildResult> {
+    let max_retries = ctx.get_settings().get_max_query_retry_on_failure()?;
+    loop {
+        match try_build_distributed_pipeline(ctx, plan).await {
+            Err(cause)
+                if cause.code() == ErrorCode::CANNOT_CONNECT_NODE
+                    && (ctx.get_query_retries() as u64) < max_retries =>
+            {
+                let query_id = ctx.get_id();
+                // Release the fragments already submitted to the local node.
+                ctx.get_exchange_manager().on_finished_query(&query_id);
+
+                // The failed node may still be alive in the rediscovered cluster.
+                let config = GlobalConfig::instance();
+                let cluster = ClusterDiscovery::instance().discover(&config).await?;
+                let unavailable_nodes = ctx.get_unavailable_nodes();
+                let nodes = cluster
+                    .nodes
+                    .iter()
+                    .filter(|node| !unavailable_nodes.contains(&node.id))
+                    .cloned()
+                    .collect();
+                ctx.set_cluster(Cluster::create(nodes, cluster.local_id.clone()));
+
+                let retries = ctx.incr_query_retries();
+                warn!(
+                    "Retry query {} on failure ({}/{}), cause: {:?}",
+                    query_id, retries, max_retries, cause
+                );
+            }
+            res => return res,
+        }
+    }
+}
+
+#[async_backtrace::framed]
+async fn try_build_distributed_pipeline(
+    ctx: &Arc<QueryContext>,
+    plan: &PhysicalPlan,
 ) -> Result<PipelineBuildResult> {
     let fragmenter = Fragmenter::try_create(ctx.clone())?;
 
@@ -146,7 +146,14 @@ impl FlightSqlServiceImpl {
                                 Err(err) => Err(status!("Could not convert batches", err)),
                             };
 
-                        let _ = s1.send(res).await;
+                        // The receiver is dropped with the response stream, which means the
+                        // client is gone, abort the pipeline instead of running it to the end.
+                        if s1.send(res).await.is_err() {
+                            session.force_kill_query(ErrorCode::AbortedQuery(
+                                "Aborted query, because the flight client closed the stream",
+                            ));
+                            break;
+                        }
                     }
                     Err(err) => {
                         let _ = s1
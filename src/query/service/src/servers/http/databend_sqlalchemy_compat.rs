@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ctor::ctor;
+use regex::Regex;
+
+/// The variables that are not settings of databend, but are asked for by the
+/// introspection of SQLAlchemy dialects.
+const COMPAT_VARIABLES: &[(&str, &str)] = &[
+    (
+        "sql_mode",
+        "ONLY_FULL_GROUP_BY STRICT_TRANS_TABLES NO_ZERO_IN_DATE NO_ZERO_DATE ERROR_FOR_DIVISION_BY_ZERO NO_ENGINE_SUBSTITUTION",
+    ),
+    ("lower_case_table_names", "0"),
+    ("transaction_isolation", "READ-COMMITTED"),
+    ("character_set_client", "utf8mb4"),
+    ("character_set_results", "utf8mb4"),
+];
+
+/// Rewrites the queries sent by SQLAlchemy (through the `databend-sqlalchemy` dialect)
+/// that databend has no statement for to the equivalent queries.
+///
+/// `SELECT 1` (ping), `SELECT version()`, `SELECT current_database()` and the
+/// `information_schema` queries used by `MetaData.reflect()` are answered by the
+/// planner as is, so only the rest is handled here.
+pub struct DatabendSqlalchemyCompat {}
+
+#[ctor]
+static SHOW_VARIABLES_REGEX: Regex = Regex::new(
+    r"(?i)^\s*SHOW\s+(?:SESSION\s+|GLOBAL\s+)?VARIABLES(?:\s+LIKE\s+('(?:[^']|'')*'))?\s*;?\s*$",
+)
+.unwrap();
+
+#[ctor]
+static SHOW_ISOLATION_LEVEL_REGEX: Regex =
+    Regex::new(r"(?i)^\s*SHOW\s+TRANSACTION\s+ISOLATION\s+LEVEL\s*;?\s*$").unwrap();
+
+impl DatabendSqlalchemyCompat {
+    pub fn rewrite(query: &str) -> Option<String> {
+        if let Some(captures) = SHOW_VARIABLES_REGEX.captures(query) {
+            return Some(Self::show_variables(captures.get(1).map(|m| m.as_str())));
+        }
+
+        if SHOW_ISOLATION_LEVEL_REGEX.is_match(query) {
+            return Some("SELECT 'read committed' AS `transaction_isolation`".to_string());
+        }
+
+        None
+    }
+
+    // Format is:
+    // |Variable_name| Value|
+    // | xx          | yy   |
+    fn show_variables(pattern: Option<&str>) -> String {
+        let mut query = String::from(
+            "SELECT name AS `Variable_name`, value AS `Value` FROM (SELECT name, value FROM system.settings",
+        );
+        for (name, value) in COMPAT_VARIABLES {
+            query.push_str(&format!(" UNION ALL SELECT '{name}', '{value}'"));
+        }
+        query.push(')');
+        if let Some(pattern) = pattern {
+            query.push_str(&format!(" WHERE name LIKE {pattern}"));
+        }
+        query.push_str(" ORDER BY name");
+        query
+    }
+}
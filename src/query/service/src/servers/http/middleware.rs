@@ -14,9 +14,12 @@
 
 use std::any::Any;
 use std::collections::HashMap;
+use std::io::Cursor;
 use std::sync::Arc;
 use std::time::Instant;
 
+use async_compression::tokio::bufread::BrotliEncoder;
+use async_compression::tokio::bufread::GzipEncoder;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_metrics::http::metrics_incr_http_request_count;
@@ -27,7 +30,12 @@ use databend_common_storages_fuse::TableContext;
 use headers::authorization::Basic;
 use headers::authorization::Bearer;
 use headers::authorization::Credentials;
+use http::header::ACCEPT_ENCODING;
 use http::header::AUTHORIZATION;
+use http::header::CONTENT_ENCODING;
+use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_TYPE;
+use http::header::VARY;
 use http::HeaderMap;
 use http::HeaderValue;
 use log::error;
@@ -334,6 +342,126 @@ impl<E: Endpoint> Endpoint for MetricsMiddlewareEndpoint<E> {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResponseEncoding {
+    Brotli,
+    Gzip,
+}
+
+impl ResponseEncoding {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ResponseEncoding::Brotli => "br",
+            ResponseEncoding::Gzip => "gzip",
+        }
+    }
+
+    /// Pick the encoding with the highest quality from the `Accept-Encoding` header,
+    /// brotli is preferred if the qualities are the same.
+    pub fn from_accept_encoding(accept_encoding: &str) -> Option<ResponseEncoding> {
+        let mut best: Option<(ResponseEncoding, f32)> = None;
+        for item in accept_encoding.split(',') {
+            let mut parts = item.split(';');
+            let encoding = match parts.next().map(|v| v.trim().to_lowercase()).as_deref() {
+                Some("br") => ResponseEncoding::Brotli,
+                Some("gzip") => ResponseEncoding::Gzip,
+                _ => continue,
+            };
+            let quality = parts
+                .find_map(|v| v.trim().strip_prefix("q="))
+                .map(|v| v.trim().parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+            if quality <= 0.0 {
+                continue;
+            }
+            match best {
+                Some((b, q)) if q > quality || (q == quality && b == ResponseEncoding::Brotli) => {}
+                _ => best = Some((encoding, quality)),
+            }
+        }
+        best.map(|(encoding, _)| encoding)
+    }
+}
+
+/// Compress the responses in the encoding accepted by the client.
+///
+/// The responses smaller than the setting `response_compression_threshold` are sent as is,
+/// and so are the event streams, whose events must not be held in the encoder.
+pub struct CompressionMiddleware;
+
+impl<E: Endpoint> Middleware<E> for CompressionMiddleware {
+    type Output = CompressionMiddlewareEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        CompressionMiddlewareEndpoint { ep }
+    }
+}
+
+pub struct CompressionMiddlewareEndpoint<E> {
+    ep: E,
+}
+
+#[poem::async_trait]
+impl<E: Endpoint> Endpoint for CompressionMiddlewareEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::error::Result<Self::Output> {
+        let encoding = req
+            .headers()
+            .get(ACCEPT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .and_then(ResponseEncoding::from_accept_encoding);
+        // The session-level settings of the request are applied to the session by the
+        // query handler, so the threshold is read after the call.
+        let settings = req
+            .extensions()
+            .get::<HttpQueryContext>()
+            .map(|ctx| ctx.get_settings());
+
+        let mut resp = self.ep.call(req).await?.into_response();
+        let Some(encoding) = encoding else {
+            return Ok(resp);
+        };
+        let is_event_stream = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.starts_with("text/event-stream"))
+            .unwrap_or(false);
+        if is_event_stream || resp.headers().contains_key(CONTENT_ENCODING) {
+            return Ok(resp);
+        }
+
+        let threshold = match settings {
+            Some(settings) => settings.get_response_compression_threshold().map_err(|e| {
+                PoemError::from_string(e.message(), StatusCode::INTERNAL_SERVER_ERROR)
+            })?,
+            None => 0,
+        };
+        let body = resp.take_body().into_bytes().await?;
+        if (body.len() as u64) < threshold {
+            resp.set_body(body);
+            return Ok(resp);
+        }
+
+        // The body is compressed while it is sent, instead of being compressed as a whole.
+        let reader = Cursor::new(body);
+        let body = match encoding {
+            ResponseEncoding::Brotli => Body::from_async_read(BrotliEncoder::new(reader)),
+            ResponseEncoding::Gzip => Body::from_async_read(GzipEncoder::new(reader)),
+        };
+        resp.set_body(body);
+        resp.headers_mut().remove(CONTENT_LENGTH);
+        resp.headers_mut().insert(
+            CONTENT_ENCODING,
+            HeaderValue::from_static(encoding.as_str()),
+        );
+        resp.headers_mut()
+            .insert(VARY, HeaderValue::from_static("accept-encoding"));
+        Ok(resp)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct PanicHandler {}
 
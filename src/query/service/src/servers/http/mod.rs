@@ -14,11 +14,13 @@
 
 mod clickhouse_federated;
 mod clickhouse_handler;
+mod databend_sqlalchemy_compat;
 mod http_services;
 pub mod middleware;
 pub mod v1;
 
 pub use clickhouse_federated::ClickHouseFederated;
+pub use databend_sqlalchemy_compat::DatabendSqlalchemyCompat;
 pub use http_services::HttpHandler;
 pub use http_services::HttpHandlerKind;
 
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use databend_common_base::base::mask_connection_info;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::DataSchemaRef;
@@ -27,6 +30,8 @@ use poem::error::Result as PoemResult;
 use poem::get;
 use poem::http::StatusCode;
 use poem::post;
+use poem::web::sse::Event;
+use poem::web::sse::SSE;
 use poem::web::Json;
 use poem::web::Path;
 use poem::EndpointExt;
@@ -35,11 +40,15 @@ use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 
 use super::query::ExecuteStateKind;
+use super::query::HttpQuery;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
 use super::query::RemoveReason;
+use crate::servers::http::middleware::CompressionMiddleware;
 use crate::servers::http::middleware::MetricsMiddleware;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
@@ -47,11 +56,18 @@ use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::QueryAffect;
+use crate::sessions::SessionType;
 
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
 
+/// The least time the session of a batch query is kept between the statements.
+const BATCH_SESSION_KEEP_SECS: u64 = 60;
+
+/// The interval of the keep-alive comments of a result stream.
+const STREAM_KEEP_ALIVE_SECS: u64 = 15;
+
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
 }
@@ -223,6 +239,84 @@ impl QueryResponse {
     }
 }
 
+/// What to do with the rest statements of a batch query when a statement fails.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOnError {
+    #[default]
+    Abort,
+    Continue,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HttpBatchQueryRequest {
+    pub session_id: Option<String>,
+    /// Applied to the session before the first statement.
+    pub session: Option<HttpSessionConf>,
+    pub sqls: Vec<String>,
+    #[serde(default)]
+    pub on_error: BatchOnError,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchStatementResponse {
+    pub index: usize,
+    pub id: String,
+    pub schema: Vec<QueryResponseField>,
+    pub data: Vec<Vec<JsonValue>>,
+    pub state: ExecuteStateKind,
+    pub error: Option<QueryError>,
+    pub stats: QueryStats,
+    pub affect: Option<QueryAffect>,
+    pub warnings: Vec<String>,
+}
+
+impl BatchStatementResponse {
+    fn fail_to_start_sql(index: usize, id: String, err: &ErrorCode) -> Self {
+        metrics_incr_http_response_errors_count(err.name(), err.code());
+        BatchStatementResponse {
+            index,
+            id,
+            schema: vec![],
+            data: vec![],
+            state: ExecuteStateKind::Failed,
+            error: Some(QueryError::from_error_code(err)),
+            stats: QueryStats::default(),
+            affect: None,
+            warnings: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchQueryResponse {
+    /// Returned only if the session is kept by `keep_server_session_secs`.
+    pub session_id: Option<String>,
+    pub session: Option<HttpSessionConf>,
+    /// The results of the executed statements, in the order of the statements.
+    pub results: Vec<BatchStatementResponse>,
+    /// The error of the first failed statement.
+    pub error: Option<QueryError>,
+    pub error_index: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamChunk {
+    pub data: Vec<Vec<JsonValue>>,
+    /// The number of the rows sent so far, includes this chunk.
+    pub total_rows: usize,
+}
+
+/// The last event of a result stream.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamEnd {
+    pub state: ExecuteStateKind,
+    pub error: Option<QueryError>,
+    pub stats: QueryStats,
+    pub affect: Option<QueryAffect>,
+    pub warnings: Vec<String>,
+}
+
 #[poem::handler]
 async fn query_final_handler(
     ctx: &HttpQueryContext,
@@ -366,6 +460,125 @@ async fn query_page_handler(
     .await
 }
 
+fn stream_event<T: Serialize>(event_type: &str, value: &T) -> Event {
+    // Serializing the plain structs does not fail.
+    Event::message(serde_json::to_string(value).unwrap()).event_type(event_type)
+}
+
+/// Push the pages of the query to `tx` until the end of the query, the events are:
+/// - `schema`: the fields of the result, sent before the first chunk.
+/// - `chunk`: a [`StreamChunk`] of the rows, sent as soon as some rows are produced.
+/// - `end`: the [`StreamEnd`] of the query.
+///
+/// The query is killed if the client closes the connection before the end.
+#[async_backtrace::framed]
+async fn stream_query(query: Arc<HttpQuery>, tx: mpsc::Sender<Event>) {
+    let http_query_manager = HttpQueryManager::instance();
+    let mut schema_sent = false;
+
+    query.update_expire_time(true).await;
+    let mut closed = false;
+    let mut page_error = None;
+    while let Some(page_no) = query.next_page_no().await {
+        let page = match query.get_response_page(page_no).await {
+            Ok(resp) => match resp.data {
+                Some(d) => d.page,
+                None => break,
+            },
+            Err(err) => {
+                page_error = Some(err);
+                break;
+            }
+        };
+        if !schema_sent {
+            let fields = QueryResponseField::from_schema(page.data.schema().clone());
+            closed = tx.send(stream_event("schema", &fields)).await.is_err();
+            schema_sent = true;
+        }
+        if !closed && !page.data.is_empty() {
+            let chunk = StreamChunk {
+                data: page.data.into(),
+                total_rows: page.total_rows,
+            };
+            closed = tx.send(stream_event("chunk", &chunk)).await.is_err();
+        }
+        if closed {
+            break;
+        }
+    }
+    query.update_expire_time(false).await;
+
+    if closed {
+        info!("{}: http query stream is closed by the client", &query.id);
+        query.kill("http query stream closed by client").await;
+        http_query_manager
+            .remove_query(&query.id, RemoveReason::Canceled)
+            .await
+            .ok();
+        return;
+    }
+
+    let resp = query.get_response_state_only().await;
+    let error = page_error.or(resp.state.error);
+    if let Some(err) = &error {
+        metrics_incr_http_response_errors_count(err.name(), err.code());
+    }
+    let end = StreamEnd {
+        state: match error {
+            Some(_) => ExecuteStateKind::Failed,
+            None => resp.state.state,
+        },
+        error: error.as_ref().map(QueryError::from_error_code),
+        stats: QueryStats {
+            progresses: resp.state.progresses,
+            running_time_ms: resp.state.running_time_ms,
+        },
+        affect: resp.state.affect,
+        warnings: resp.state.warnings,
+    };
+    let _ = tx.send(stream_event("end", &end)).await;
+    http_query_manager
+        .remove_query(&query.id, RemoveReason::Finished)
+        .await
+        .ok();
+}
+
+/// Push the rest pages of the query with server-sent events, instead of polling the pages.
+#[poem::handler]
+async fn query_stream_handler(
+    ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+) -> PoemResult<impl IntoResponse> {
+    let trace_id = query_id_to_trace_id(&query_id);
+    let root = Span::root(
+        full_name!(),
+        SpanContext::new(trace_id, SpanId(rand::random())),
+    )
+    .with_properties(|| ctx.to_minitrace_properties());
+
+    async {
+        let http_query_manager = HttpQueryManager::instance();
+        match http_query_manager.try_get_query(&query_id).await {
+            Ok(query) => {
+                info!("{}: http query start streaming", query_id);
+                let (tx, rx) = mpsc::channel(1);
+                databend_common_base::runtime::spawn(
+                    stream_query(query, tx).in_span(Span::enter_with_local_parent(full_name!())),
+                );
+                Ok(SSE::new(ReceiverStream::new(rx))
+                    .keep_alive(Duration::from_secs(STREAM_KEEP_ALIVE_SECS)))
+            }
+            Err(reason) => Err(query_id_not_found_or_removed(
+                &query_id,
+                &ctx.node_id,
+                reason,
+            )),
+        }
+    }
+    .in_span(root)
+    .await
+}
+
 #[poem::handler]
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
@@ -415,12 +628,181 @@ pub(crate) async fn query_handler(
     .await
 }
 
+/// Run the statement of a batch query to the end, and collect all the pages of the result.
+#[async_backtrace::framed]
+async fn execute_batch_statement(
+    ctx: &HttpQueryContext,
+    index: usize,
+    request: HttpQueryRequest,
+) -> (BatchStatementResponse, Option<HttpSessionConf>) {
+    let http_query_manager = HttpQueryManager::instance();
+    let sql = request.sql.clone();
+
+    let query = match http_query_manager.try_create_query(ctx, request).await {
+        Ok(query) => query,
+        Err(err) => {
+            let err = err.display_with_sql(&sql);
+            error!(
+                "{}: http batch query fail to start sql, error: {:?}",
+                &ctx.query_id, err
+            );
+            return (
+                BatchStatementResponse::fail_to_start_sql(index, ctx.query_id.clone(), &err),
+                None,
+            );
+        }
+    };
+
+    query.update_expire_time(true).await;
+    let mut pages = vec![];
+    let mut page_no = 0;
+    let mut page_error = None;
+    loop {
+        match query.get_response_page(page_no).await {
+            Ok(resp) => match resp.data {
+                Some(d) => {
+                    pages.push(d.page.data);
+                    match d.next_page_no {
+                        Some(n) => page_no = n,
+                        None => break,
+                    }
+                }
+                None => break,
+            },
+            Err(err) => {
+                page_error = Some(err.display_with_sql(&sql));
+                break;
+            }
+        }
+    }
+    query.update_expire_time(false).await;
+
+    let resp = query.get_response_state_only().await;
+    http_query_manager
+        .remove_query(&query.id, RemoveReason::Finished)
+        .await
+        .ok();
+
+    let error = page_error.or(resp.state.error);
+    if let Some(err) = &error {
+        metrics_incr_http_response_errors_count(err.name(), err.code());
+    }
+    let state = match error {
+        Some(_) => ExecuteStateKind::Failed,
+        None => resp.state.state,
+    };
+    let data = JsonBlock::concat(pages);
+    let response = BatchStatementResponse {
+        index,
+        id: query.id.clone(),
+        schema: QueryResponseField::from_schema(data.schema().clone()),
+        data: data.into(),
+        state,
+        error: error.as_ref().map(QueryError::from_error_code),
+        stats: QueryStats {
+            progresses: resp.state.progresses,
+            running_time_ms: resp.state.running_time_ms,
+        },
+        affect: resp.state.affect,
+        warnings: resp.state.warnings,
+    };
+    (response, resp.session)
+}
+
+/// Execute the statements one by one in the same session.
+///
+/// A session is created for the batch if `session_id` is not given, which is dropped
+/// after the batch unless `keep_server_session_secs` is set.
+#[poem::handler]
+#[async_backtrace::framed]
+async fn query_batch_handler(
+    ctx: &HttpQueryContext,
+    Json(req): Json<HttpBatchQueryRequest>,
+) -> PoemResult<impl IntoResponse> {
+    let trace_id = query_id_to_trace_id(&ctx.query_id);
+    let root = Span::root(full_name!(), SpanContext::new(trace_id, SpanId::default()))
+        .with_properties(|| ctx.to_minitrace_properties());
+    let _t = SlowRequestLogTracker::new(ctx);
+
+    async {
+        info!(
+            "{}: http batch query new request with {} statements, on_error={:?}",
+            &ctx.query_id,
+            req.sqls.len(),
+            req.on_error
+        );
+        let http_query_manager = HttpQueryManager::instance();
+        let keep_server_session_secs = req
+            .session
+            .as_ref()
+            .and_then(|s| s.keep_server_session_secs)
+            .unwrap_or(0);
+
+        let (session_id, drop_session) = match &req.session_id {
+            Some(id) => (id.clone(), false),
+            None => {
+                let session = ctx.upgrade_session(SessionType::HTTPQuery)?;
+                let secs = keep_server_session_secs.max(BATCH_SESSION_KEEP_SECS);
+                http_query_manager
+                    .add_session(session.clone(), Duration::from_secs(secs))
+                    .await;
+                (session.get_id(), keep_server_session_secs == 0)
+            }
+        };
+
+        let mut response = BatchQueryResponse {
+            session_id: None,
+            session: None,
+            results: Vec::with_capacity(req.sqls.len()),
+            error: None,
+            error_index: None,
+        };
+        let mut session_conf = req.session;
+        for (index, sql) in req.sqls.into_iter().enumerate() {
+            let request = HttpQueryRequest {
+                session_id: Some(session_id.clone()),
+                session: session_conf.take(),
+                sql,
+                pagination: Default::default(),
+                string_fields: true,
+                stage_attachment: None,
+            };
+            let statement_ctx = ctx.with_batch_index(index);
+            let (result, session) = execute_batch_statement(&statement_ctx, index, request).await;
+            if session.is_some() {
+                response.session = session;
+            }
+
+            let failed = result.error.is_some();
+            if failed && response.error.is_none() {
+                response.error = result.error.clone();
+                response.error_index = Some(index);
+            }
+            response.results.push(result);
+            if failed && req.on_error == BatchOnError::Abort {
+                break;
+            }
+        }
+
+        if drop_session {
+            http_query_manager.kill_session(&session_id);
+        } else {
+            response.session_id = Some(session_id);
+        }
+        Ok(Json(response))
+    }
+    .in_span(root)
+    .await
+}
+
 pub fn query_route() -> Route {
     // Note: endpoints except /v1/query may change without notice, use uris in response instead
     let rules = [
         ("/", post(query_handler)),
+        ("/batch", post(query_batch_handler)),
         ("/:id", get(query_state_handler)),
         ("/:id/page/:page_no", get(query_page_handler)),
+        ("/:id/stream", get(query_stream_handler)),
         (
             "/:id/kill",
             get(query_cancel_handler).post(query_cancel_handler),
@@ -433,7 +815,12 @@ pub fn query_route() -> Route {
 
     let mut route = Route::new();
     for (path, endpoint) in rules.into_iter() {
-        route = route.at(path, endpoint.with(MetricsMiddleware::new(path)));
+        route = route.at(
+            path,
+            endpoint
+                .with(CompressionMiddleware)
+                .with(MetricsMiddleware::new(path)),
+        );
     }
     route
 }
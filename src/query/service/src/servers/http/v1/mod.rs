@@ -23,9 +23,15 @@ pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
+pub use http_query_handlers::BatchOnError;
+pub use http_query_handlers::BatchQueryResponse;
+pub use http_query_handlers::BatchStatementResponse;
+pub use http_query_handlers::HttpBatchQueryRequest;
 pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
+pub use http_query_handlers::StreamChunk;
+pub use http_query_handlers::StreamEnd;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
@@ -36,6 +42,7 @@ pub use query::HttpQueryContext;
 pub use query::HttpQueryManager;
 pub use query::HttpSessionConf;
 pub use stage::upload_to_stage;
+pub use stage::StageUploader;
 pub use stage::UploadToStageResponse;
 pub use suggestions::list_suggestions;
 pub use suggestions::SuggestionsResponse;
@@ -50,6 +50,7 @@ use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::http::DatabendSqlalchemyCompat;
 use crate::sessions::short_sql;
 use crate::sessions::QueryAffect;
 use crate::sessions::SessionType;
@@ -336,7 +337,8 @@ impl HttpQuery {
         let block_sender_closer = block_sender.closer();
         let state_clone = state.clone();
         let ctx_clone = ctx.clone();
-        let sql = request.sql.clone();
+        let sql =
+            DatabendSqlalchemyCompat::rewrite(&request.sql).unwrap_or_else(|| request.sql.clone());
         let query_id_clone = query_id.clone();
 
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
@@ -424,6 +426,12 @@ impl HttpQuery {
         })
     }
 
+    /// The number of the next page to fetch, `None` if all the pages are fetched.
+    #[async_backtrace::framed]
+    pub async fn next_page_no(&self) -> Option<usize> {
+        self.page_manager.lock().await.next_page_no()
+    }
+
     #[async_backtrace::framed]
     pub async fn get_response_state_only(&self) -> HttpQueryResponseInternal {
         let state = self.get_state().await;
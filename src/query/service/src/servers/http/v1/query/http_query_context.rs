@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_settings::Settings;
 use http::StatusCode;
 use poem::FromRequest;
 use poem::Request;
@@ -64,6 +65,27 @@ impl HttpQueryContext {
         Ok(self.session.clone())
     }
 
+    /// The settings of the session, includes the session-level settings of the query request.
+    pub fn get_settings(&self) -> Arc<Settings> {
+        self.session.get_settings()
+    }
+
+    /// The context of the `index`th statement of a batch query, which has its own query id.
+    ///
+    /// The deduplicate label is not passed to the statements, it would skip all the DML
+    /// statements but the first one.
+    pub fn with_batch_index(&self, index: usize) -> Self {
+        HttpQueryContext {
+            session: self.session.clone(),
+            query_id: format!("{}-{}", self.query_id, index),
+            node_id: self.node_id.clone(),
+            deduplicate_label: None,
+            user_agent: self.user_agent.clone(),
+            http_method: self.http_method.clone(),
+            uri: self.uri.clone(),
+        }
+    }
+
     pub fn to_minitrace_properties(&self) -> Vec<(&'static str, String)> {
         let mut properties = self.session.to_minitrace_properties();
         properties.extend([
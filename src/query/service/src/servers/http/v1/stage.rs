@@ -12,9 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::runtime::spawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::UserApiProvider;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use log::warn;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
@@ -23,6 +35,8 @@ use poem::web::Multipart;
 use poem::Request;
 use serde::Deserialize;
 use serde::Serialize;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
 
 use super::HttpQueryContext;
 use crate::sessions::SessionType;
@@ -78,6 +92,112 @@ impl UploadToStageArgs {
     }
 }
 
+/// The max number of the retries of a file.
+const UPLOAD_MAX_RETRIES_PER_FILE: usize = 3;
+
+/// Writes the uploaded files to the stage, at most `concurrency` files at the same time.
+///
+/// A failed write is retried up to [`UPLOAD_MAX_RETRIES_PER_FILE`] times, and the
+/// retries are shared by the concurrent writes: a storage that keeps failing fails
+/// the upload after `UPLOAD_MAX_RETRIES_PER_FILE * concurrency` retries in total,
+/// instead of retrying every file.
+pub struct StageUploader<W> {
+    write: W,
+    concurrency: usize,
+    retries: Arc<AtomicUsize>,
+    uploads: FuturesUnordered<JoinHandle<Result<()>>>,
+}
+
+impl<W, F> StageUploader<W>
+where
+    W: Fn(String, Vec<u8>) -> F + Clone + Send + 'static,
+    F: Future<Output = Result<()>> + Send + 'static,
+{
+    pub fn new(write: W, concurrency: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        StageUploader {
+            write,
+            concurrency,
+            retries: Arc::new(AtomicUsize::new(UPLOAD_MAX_RETRIES_PER_FILE * concurrency)),
+            uploads: FuturesUnordered::new(),
+        }
+    }
+
+    /// Start to write a file, waits for a running write to finish if there are
+    /// `concurrency` of them already.
+    #[async_backtrace::framed]
+    pub async fn upload(&mut self, path: String, data: Vec<u8>) -> Result<()> {
+        if self.uploads.len() >= self.concurrency {
+            self.join_next().await?;
+        }
+
+        let write = self.write.clone();
+        let retries = self.retries.clone();
+        self.uploads.push(spawn(async move {
+            let mut attempt = 0;
+            loop {
+                match write(path.clone(), data.clone()).await {
+                    Ok(_) => return Ok(()),
+                    Err(e) => {
+                        attempt += 1;
+                        let retry = attempt <= UPLOAD_MAX_RETRIES_PER_FILE
+                            && retries
+                                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                                    n.checked_sub(1)
+                                })
+                                .is_ok();
+                        if !retry {
+                            return Err(e.add_message_back(format!(
+                                " (while uploading {path}, after {} retries)",
+                                attempt - 1
+                            )));
+                        }
+                        warn!("fail to upload {}, retry {}: {}", path, attempt, e);
+                        sleep(Duration::from_millis(100 * (1 << attempt))).await;
+                    }
+                }
+            }
+        }));
+        Ok(())
+    }
+
+    /// Wait for all the writes to finish.
+    #[async_backtrace::framed]
+    pub async fn finish(mut self) -> Result<()> {
+        while !self.uploads.is_empty() {
+            self.join_next().await?;
+        }
+        Ok(())
+    }
+
+    /// Wait for a write to finish, the rest writes are aborted if it fails.
+    async fn join_next(&mut self) -> Result<()> {
+        let res = match self.uploads.next().await {
+            Some(Ok(res)) => res,
+            Some(Err(e)) => Err(ErrorCode::Internal(format!("upload task failed: {e}"))),
+            None => Ok(()),
+        };
+        if res.is_err() {
+            self.abort_all();
+        }
+        res
+    }
+}
+
+impl<W> StageUploader<W> {
+    fn abort_all(&mut self) {
+        for upload in self.uploads.iter() {
+            upload.abort();
+        }
+    }
+}
+
+impl<W> Drop for StageUploader<W> {
+    fn drop(&mut self) {
+        self.abort_all();
+    }
+}
+
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn upload_to_stage(
@@ -108,6 +228,17 @@ pub async fn upload_to_stage(
     };
 
     let op = StageTable::get_op(&stage).map_err(InternalServerError)?;
+    let concurrency = context
+        .get_settings()
+        .get_copy_max_upload_concurrency()
+        .map_err(InternalServerError)? as usize;
+    let mut uploader = StageUploader::new(
+        move |path: String, data: Vec<u8>| {
+            let op = op.clone();
+            async move { Ok(op.write(&path, data).await?) }
+        },
+        concurrency,
+    );
 
     let mut files = vec![];
     while let Ok(Some(field)) = multipart.next_field().await {
@@ -119,13 +250,14 @@ pub async fn upload_to_stage(
         let file_path = format!("{}/{}", args.relative_path, name)
             .trim_start_matches('/')
             .to_string();
-        let _ = op
-            .write(&file_path, bytes)
+        uploader
+            .upload(file_path, bytes)
             .await
             .map_err(InternalServerError)?;
 
         files.push(name.clone());
     }
+    uploader.finish().await.map_err(InternalServerError)?;
 
     let mut id = uuid::Uuid::new_v4().to_string();
     Ok(Json(UploadToStageResponse {
@@ -174,7 +174,6 @@ impl MySQLFederated {
         static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
             // Txn.
             (Regex::new("(?i)^(ROLLBACK(.*))").unwrap(), None),
-            (Regex::new("(?i)^(COMMIT(.*))").unwrap(), None),
             (Regex::new("(?i)^(START(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET NAMES(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET character_set_results(.*))").unwrap(), None),
@@ -244,6 +243,15 @@ impl MySQLFederated {
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
+    // ROLLBACK is answered OK for the drivers outside of a transaction, but the statements of
+    // a transaction have committed their writes, which can not be rolled back.
+    pub fn is_rollback(query: &str) -> bool {
+        #[ctor]
+        static ROLLBACK_RULE: Regex = Regex::new("(?i)^(ROLLBACK(.*))").unwrap();
+
+        ROLLBACK_RULE.is_match(query)
+    }
+
     pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
         // First to check the select @@variables.
         let select_variable = self
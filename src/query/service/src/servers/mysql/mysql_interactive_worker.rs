@@ -332,6 +332,12 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn do_query(&mut self, query: &str) -> Result<(QueryResult, Option<FormatSettings>)> {
+        if self.session.get_txn().is_some() && MySQLFederated::is_rollback(query) {
+            return Err(ErrorCode::Unimplemented(
+                "ROLLBACK is not supported in a transaction, the statements of the transaction have committed their writes",
+            ));
+        }
+
         match self.federated_server_command_check(query) {
             Some((schema, data_block)) => {
                 info!("Federated query: {}", query);
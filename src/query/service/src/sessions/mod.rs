@@ -22,7 +22,9 @@ mod session_mgr;
 mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
+mod session_txn;
 mod session_type;
+mod tenant_resource_mgr;
 
 pub use databend_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -36,4 +38,8 @@ pub use session_info::ProcessInfo;
 pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
+pub use session_txn::SessionTxn;
 pub use session_type::SessionType;
+pub use tenant_resource_mgr::TenantQueryPermit;
+pub use tenant_resource_mgr::TenantResourceManager;
+pub use tenant_resource_mgr::TenantResources;
@@ -48,6 +48,7 @@ use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::TenantResourceUsage;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -100,12 +101,14 @@ use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::TenantResourceManager;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
@@ -260,6 +263,14 @@ impl QueryContext {
         self.shared.get_data_metrics()
     }
 
+    pub fn get_aggregate_memory_tracker(&self) -> Arc<AggregateMemoryTracker> {
+        self.shared.aggregate_memory_tracker.clone()
+    }
+
+    pub fn get_io_stats(&self) -> Vec<(String, StorageMetrics)> {
+        self.shared.get_io_stats()
+    }
+
     pub fn set_affect(self: &Arc<Self>, affect: QueryAffect) {
         self.shared.set_affect(affect)
     }
@@ -301,9 +312,41 @@ impl QueryContext {
         *self.shared.finish_time.write() = Some(time)
     }
 
+    /// Replace the cluster of the query, e.g. with the rediscovered one before retrying the query.
+    pub fn set_cluster(&self, cluster: Arc<Cluster>) {
+        self.shared.set_cluster(cluster)
+    }
+
+    pub fn get_query_retries(&self) -> u32 {
+        self.shared.query_retries.load(Ordering::Acquire)
+    }
+
+    pub fn incr_query_retries(&self) -> u32 {
+        self.shared.query_retries.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// Record a node which could not be connected, it's excluded when the query is retried.
+    pub fn add_unavailable_node(&self, node_id: &str) {
+        self.shared
+            .unavailable_nodes
+            .write()
+            .insert(node_id.to_string());
+    }
+
+    pub fn get_unavailable_nodes(&self) -> HashSet<String> {
+        self.shared.unavailable_nodes.read().clone()
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
+
+    /// Waits until the tenant runs less than `tenant_max_concurrent_queries` queries, the
+    /// query is counted in the running queries of the tenant until it finishes.
+    #[async_backtrace::framed]
+    pub async fn acquire_tenant_permit(&self) -> Result<()> {
+        self.shared.acquire_tenant_permit().await
+    }
 }
 
 #[async_trait::async_trait]
@@ -316,7 +359,7 @@ impl TableContext for QueryContext {
     /// A plan just contains raw information about a table or table function.
     /// This method builds a `dyn Table`, which provides table specific io methods the plan needs.
     fn build_table_from_source_plan(&self, plan: &DataSourcePlan) -> Result<Arc<dyn Table>> {
-        match &plan.source_info {
+        let table = match &plan.source_info {
             DataSourceInfo::TableSource(table_info) => self.build_table_by_table_info(
                 &plan.catalog_info,
                 table_info,
@@ -330,7 +373,9 @@ impl TableContext for QueryContext {
             DataSourceInfo::Parquet2Source(table_info) => Parquet2Table::from_info(table_info),
             DataSourceInfo::ParquetSource(table_info) => ParquetRSTable::from_info(table_info),
             DataSourceInfo::ResultScanSource(table_info) => ResultScan::from_info(table_info),
-        }
+        }?;
+        self.shared.attach_source_table(table.clone());
+        Ok(table)
     }
 
     fn incr_total_scan_value(&self, value: ProgressValues) {
@@ -559,6 +604,12 @@ impl TableContext for QueryContext {
         self.get_current_session().get_id()
     }
 
+    fn get_instance_started_at(&self) -> SystemTime {
+        SessionManager::instance()
+            .get_current_session_status()
+            .instance_started_at
+    }
+
     async fn get_visibility_checker(&self) -> Result<GrantObjectVisibilityChecker> {
         self.shared.session.get_visibility_checker().await
     }
@@ -650,6 +701,28 @@ impl TableContext for QueryContext {
         SessionManager::instance().processes_info()
     }
 
+    // Get the resources used by the queries of each tenant.
+    fn get_tenant_resource_usage(&self) -> Vec<TenantResourceUsage> {
+        let manager = TenantResourceManager::instance();
+        manager
+            .get_all_resources()
+            .into_iter()
+            .map(|resources| {
+                let mem_stat = resources.get_mem_stat();
+                TenantResourceUsage {
+                    tenant: resources.get_tenant().to_string(),
+                    running_queries: resources.get_running_queries(),
+                    queued_queries: resources.get_queued_queries(),
+                    memory_usage: mem_stat.get_memory_usage(),
+                    peak_memory_usage: mem_stat.get_peak_memory_usage(),
+                    max_memory_bytes: manager.get_max_memory_bytes(),
+                    max_threads: manager.get_max_threads(),
+                    max_concurrent_queries: manager.get_max_concurrent_queries(),
+                }
+            })
+            .collect()
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
@@ -766,6 +839,29 @@ impl TableContext for QueryContext {
         Ok(table)
     }
 
+    #[async_backtrace::framed]
+    async fn pin_table_snapshot(&self, table: Arc<dyn Table>) -> Result<Arc<dyn Table>> {
+        match self.shared.session.get_txn() {
+            Some(txn) => txn.pin_table_snapshot(table).await,
+            None => Ok(table),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn check_table_commit(&self, table_info: &TableInfo) -> Result<()> {
+        match self.shared.session.get_txn() {
+            Some(txn) => txn.check_table_commit(table_info).await,
+            None => Ok(()),
+        }
+    }
+
+    fn on_table_committed(&self, table_info: &TableInfo) -> Result<()> {
+        match self.shared.session.get_txn() {
+            Some(txn) => txn.on_table_committed(table_info),
+            None => Ok(()),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn filter_out_copied_files(
         &self,
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -22,6 +25,7 @@ use std::time::SystemTime;
 
 use dashmap::DashMap;
 use databend_common_base::base::Progress;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::Runtime;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
@@ -51,8 +55,12 @@ use uuid::Uuid;
 
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::aggregator::AggregateMemoryTracker;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::sessions::SessionType;
+use crate::sessions::TenantQueryPermit;
+use crate::sessions::TenantResourceManager;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -71,6 +79,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) agg_spill_progress: Arc<Progress>,
     /// Record how many bytes/rows have been spilled in group by
     pub(in crate::sessions) group_by_spill_progress: Arc<Progress>,
+    /// Record how many bytes are allocated by the hash tables of agg and group by.
+    pub(in crate::sessions) aggregate_memory_tracker: Arc<AggregateMemoryTracker>,
     /// result_progress for metrics of result datablocks (uncompressed)
     pub(in crate::sessions) result_progress: Arc<Progress>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
@@ -78,11 +88,18 @@ pub struct QueryContextShared {
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
-    pub(in crate::sessions) cluster_cache: Arc<Cluster>,
+    pub(in crate::sessions) cluster_cache: Arc<RwLock<Arc<Cluster>>>,
+    /// Record how many times the query has been retried on cluster node failures.
+    pub(in crate::sessions) query_retries: Arc<AtomicU32>,
+    /// The nodes which could not be connected while dispatching the query fragments.
+    pub(in crate::sessions) unavailable_nodes: Arc<RwLock<HashSet<String>>>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<QueryKind>>>,
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    /// The tables built from the source plans to read the data, which do not share the
+    /// storage metrics with the tables in `tables_refs`.
+    pub(in crate::sessions) source_tables: Arc<Mutex<Vec<Arc<dyn Table>>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
@@ -120,6 +137,10 @@ pub struct QueryContextShared {
 
     // Records query level data cache metrics
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
+
+    /// Counts the query in the running queries of the tenant until the query finishes,
+    /// acquired when the query starts to execute.
+    pub(in crate::sessions) tenant_permit: Mutex<Option<TenantQueryPermit>>,
 }
 
 impl QueryContextShared {
@@ -129,7 +150,9 @@ impl QueryContextShared {
     ) -> Result<Arc<QueryContextShared>> {
         Ok(Arc::new(QueryContextShared {
             session,
-            cluster_cache,
+            cluster_cache: Arc::new(RwLock::new(cluster_cache)),
+            query_retries: Arc::new(AtomicU32::new(0)),
+            unavailable_nodes: Arc::new(RwLock::new(HashSet::new())),
             catalog_manager: CatalogManager::instance(),
             data_operator: DataOperator::instance(),
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
@@ -144,6 +167,7 @@ impl QueryContextShared {
             running_query_kind: Arc::new(RwLock::new(None)),
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            source_tables: Arc::new(Mutex::new(vec![])),
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
@@ -163,10 +187,12 @@ impl QueryContextShared {
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
+            aggregate_memory_tracker: AggregateMemoryTracker::create(),
             query_cache_metrics: DataCacheMetrics::new(),
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
             merge_into_join: Default::default(),
+            tenant_permit: Mutex::new(None),
         }))
     }
 
@@ -222,7 +248,11 @@ impl QueryContextShared {
     }
 
     pub fn get_cluster(&self) -> Arc<Cluster> {
-        self.cluster_cache.clone()
+        self.cluster_cache.read().clone()
+    }
+
+    pub fn set_cluster(&self, cluster: Arc<Cluster>) {
+        *self.cluster_cache.write() = cluster;
     }
 
     pub fn get_current_catalog(&self) -> String {
@@ -271,13 +301,45 @@ impl QueryContextShared {
         tables.values().cloned().collect()
     }
 
+    pub fn get_aggregate_memory_usage(&self) -> i64 {
+        self.aggregate_memory_tracker.get_memory_usage()
+    }
+
     pub fn get_data_metrics(&self) -> StorageMetrics {
-        let tables = self.get_tables_refs();
+        let mut tables = self.get_tables_refs();
+        tables.extend(self.source_tables.lock().iter().cloned());
         let metrics: Vec<Arc<StorageMetrics>> =
             tables.iter().filter_map(|v| v.get_data_metrics()).collect();
         StorageMetrics::merge(&metrics)
     }
 
+    /// Get the storage metrics of each storage operator used by this query.
+    ///
+    /// The operators are named after the engines and the names of their tables.
+    pub fn get_io_stats(&self) -> Vec<(String, StorageMetrics)> {
+        let mut tables = self.get_tables_refs();
+        tables.extend(self.source_tables.lock().iter().cloned());
+
+        let mut operators: BTreeMap<String, Vec<Arc<StorageMetrics>>> = BTreeMap::new();
+        for table in tables {
+            if let Some(metrics) = table.get_data_metrics() {
+                let operator_name = format!("{}: {}", table.engine(), table.name());
+                operators.entry(operator_name).or_default().push(metrics);
+            }
+        }
+
+        operators
+            .into_iter()
+            .map(|(operator_name, metrics)| (operator_name, StorageMetrics::merge(&metrics)))
+            .collect()
+    }
+
+    pub fn attach_source_table(&self, table: Arc<dyn Table>) {
+        if table.get_data_metrics().is_some() {
+            self.source_tables.lock().push(table);
+        }
+    }
+
     pub fn get_tenant(&self) -> String {
         self.session.get_current_tenant()
     }
@@ -345,6 +407,26 @@ impl QueryContextShared {
         Ok(())
     }
 
+    /// Waits until the tenant admits the query, if the query is not admitted yet.
+    #[async_backtrace::framed]
+    pub async fn acquire_tenant_permit(&self) -> Result<()> {
+        // The fragments of the distributed queries are not limited by the concurrency of the
+        // tenant, their queries have been admitted by the coordinator node.
+        if matches!(
+            self.session.get_type(),
+            SessionType::Dummy | SessionType::FlightRPC
+        ) || self.tenant_permit.lock().is_some()
+        {
+            return Ok(());
+        }
+
+        let permit = TenantResourceManager::instance()
+            .acquire_query(&self.get_tenant())
+            .await?;
+        *self.tenant_permit.lock() = Some(permit);
+        Ok(())
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -352,11 +434,24 @@ impl QueryContextShared {
         match &*query_runtime {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
-                // To avoid possible deadlock, we should keep at least two threads.
-                let runtime = Arc::new(Runtime::with_worker_threads(
-                    2,
-                    Some("query-ctx".to_string()),
-                )?);
+                let resources =
+                    TenantResourceManager::instance().get_resources(&self.get_tenant())?;
+                // The queries of the tenant share the runtime of the tenant if there is one.
+                let runtime = match resources.get_runtime() {
+                    Some(runtime) => runtime,
+                    None => {
+                        let mem_stat = MemStat::create_child(
+                            String::from("query-ctxRuntime"),
+                            Some(resources.get_mem_stat()),
+                        );
+                        // To avoid possible deadlock, we should keep at least two threads.
+                        Arc::new(Runtime::with_worker_threads_and_mem_stat(
+                            2,
+                            Some("query-ctx".to_string()),
+                            mem_stat,
+                        )?)
+                    }
+                };
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
@@ -38,6 +38,7 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
+use crate::sessions::SessionTxn;
 use crate::sessions::SessionType;
 
 pub struct Session {
@@ -142,6 +143,7 @@ impl Session {
     /// Create a query context for query.
     /// For a query, execution environment(e.g cluster) should be immutable.
     /// We can bind the environment to the context in create_context method.
+    /// The cluster is only rediscovered when a distributed query is retried before dispatching.
     #[async_backtrace::framed]
     pub async fn create_query_context(self: &Arc<Self>) -> Result<Arc<QueryContext>> {
         let config = GlobalConfig::instance();
@@ -308,6 +310,14 @@ impl Session {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
+
+    pub fn get_txn(self: &Arc<Self>) -> Option<Arc<SessionTxn>> {
+        self.session_ctx.get_txn()
+    }
+
+    pub fn set_txn(self: &Arc<Self>, txn: Option<Arc<SessionTxn>>) {
+        self.session_ctx.set_txn(txn)
+    }
 }
 
 impl Drop for Session {
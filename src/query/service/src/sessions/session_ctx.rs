@@ -28,6 +28,7 @@ use parking_lot::RwLock;
 
 use super::SessionType;
 use crate::sessions::QueryContextShared;
+use crate::sessions::SessionTxn;
 
 pub struct SessionContext {
     abort: AtomicBool,
@@ -62,6 +63,8 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The transaction started by `BEGIN`, if any.
+    txn: RwLock<Option<Arc<SessionTxn>>>,
     typ: SessionType,
 }
 
@@ -81,6 +84,7 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            txn: Default::default(),
             typ,
         }))
     }
@@ -286,4 +290,12 @@ impl SessionContext {
         let lock = self.query_ids_results.read();
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
+
+    pub fn get_txn(&self) -> Option<Arc<SessionTxn>> {
+        self.txn.read().clone()
+    }
+
+    pub fn set_txn(&self, txn: Option<Arc<SessionTxn>>) {
+        *self.txn.write() = txn;
+    }
 }
@@ -32,6 +32,7 @@ impl Session {
 
     fn to_process_info(self: &Arc<Self>, session_ctx: &SessionContext) -> ProcessInfo {
         let mut memory_usage = 0;
+        let mut aggregate_memory_usage = 0;
 
         let shared_query_context = &session_ctx.get_query_context_shared();
         if let Some(shared) = shared_query_context {
@@ -39,6 +40,7 @@ impl Session {
                 let mem_stat = runtime.get_tracker();
                 memory_usage = mem_stat.get_memory_usage();
             }
+            aggregate_memory_usage = shared.get_aggregate_memory_usage();
         }
 
         ProcessInfo {
@@ -51,6 +53,7 @@ impl Session {
             client_address: session_ctx.get_client_host(),
             session_extra_info: self.process_extra_info(session_ctx),
             memory_usage,
+            aggregate_memory_usage,
             data_metrics: Self::query_data_metrics(session_ctx),
             scan_progress_value: Self::query_scan_progress_value(session_ctx),
             mysql_connection_id: self.mysql_connection_id,
@@ -58,6 +61,7 @@ impl Session {
             status_info: shared_query_context
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
+            current_query: Self::query_current_query(session_ctx),
         }
     }
 
@@ -89,6 +93,13 @@ impl Session {
             .map(|context_shared| context_shared.get_query_str())
     }
 
+    fn query_current_query(status: &SessionContext) -> Option<String> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.get_query_str())
+    }
+
     fn query_data_metrics(status: &SessionContext) -> Option<StorageMetrics> {
         status
             .get_query_context_shared()
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_settings::TransactionIsolation;
+use databend_common_storages_fuse::FuseTable;
+use parking_lot::Mutex;
+
+/// The transaction started by `BEGIN` and ended by `COMMIT`.
+///
+/// The transaction only scopes the reads: the statements still commit their writes one by one,
+/// and there is no rollback.
+///
+/// Each statement reads the tables once, so that it sees the data committed before it starts.
+/// Under `SNAPSHOT ISOLATION`, the snapshot of a Fuse table is pinned to the one read when the
+/// transaction accesses the table for the first time, so that the statements of the
+/// transaction see the same data. A statement can only write a table that is not changed by
+/// others since the pinned snapshot, and the snapshot it commits is pinned instead, so that the
+/// transaction sees its own writes but not the others'.
+pub struct SessionTxn {
+    isolation: TransactionIsolation,
+    pinned_tables: Mutex<HashMap<u64, Arc<dyn Table>>>,
+}
+
+impl SessionTxn {
+    pub fn create(isolation: TransactionIsolation) -> Self {
+        SessionTxn {
+            isolation,
+            pinned_tables: Default::default(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn pin_table_snapshot(&self, table: Arc<dyn Table>) -> Result<Arc<dyn Table>> {
+        if self.isolation != TransactionIsolation::SnapshotIsolation || table.engine() != "FUSE" {
+            return Ok(table);
+        }
+
+        let table_id = table.get_id();
+        // The table is read with its latest snapshot, which is pinned.
+        let mut pinned_tables = self.pinned_tables.lock();
+        Ok(pinned_tables.entry(table_id).or_insert(table).clone())
+    }
+
+    /// Fails if the table to be replaced by a commit is not the one pinned by the transaction,
+    /// the first one of the concurrent writes wins.
+    #[async_backtrace::framed]
+    pub async fn check_table_commit(&self, table_info: &TableInfo) -> Result<()> {
+        if self.isolation != TransactionIsolation::SnapshotIsolation
+            || table_info.meta.engine != "FUSE"
+        {
+            return Ok(());
+        }
+
+        let base: Arc<FuseTable> = FuseTable::do_create(table_info.clone())?.into();
+        let base_loc = base.snapshot_loc().await?;
+        let pinned = self.pin_table_snapshot(base).await?;
+        let pinned_loc = FuseTable::try_from_table(pinned.as_ref())?
+            .snapshot_loc()
+            .await?;
+        if base_loc != pinned_loc {
+            return Err(ErrorCode::UnresolvableConflict(format!(
+                "table {} has been changed by another transaction since the snapshot read by the current transaction",
+                table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn on_table_committed(&self, table_info: &TableInfo) -> Result<()> {
+        if self.isolation != TransactionIsolation::SnapshotIsolation
+            || table_info.meta.engine != "FUSE"
+        {
+            return Ok(());
+        }
+
+        let committed: Arc<FuseTable> = FuseTable::do_create(table_info.clone())?.into();
+        self.pinned_tables
+            .lock()
+            .insert(table_info.ident.table_id, committed);
+        Ok(())
+    }
+}
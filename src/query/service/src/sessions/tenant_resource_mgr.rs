@@ -0,0 +1,238 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
+use databend_common_base::base::tokio::sync::Semaphore;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::Runtime;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parking_lot::RwLock;
+
+/// The resources shared by the queries of a tenant.
+///
+/// The memory of the queries of a tenant is tracked by the `MemStat` of the tenant, which is
+/// limited to `tenant_max_memory_bytes`, so the queries of a tenant running out of memory
+/// don't fail the queries of the other tenants. If `tenant_max_threads` is set, the queries
+/// of a tenant share a runtime of that many worker threads instead of a runtime of their own.
+pub struct TenantResources {
+    tenant: String,
+    mem_stat: Arc<MemStat>,
+    runtime: Option<Arc<Runtime>>,
+    /// `None` if the concurrency of the queries is unlimited.
+    queries_semaphore: Option<Arc<Semaphore>>,
+    running_queries: AtomicU64,
+    queued_queries: AtomicU64,
+}
+
+impl TenantResources {
+    pub fn get_tenant(&self) -> &str {
+        &self.tenant
+    }
+
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
+    pub fn get_runtime(&self) -> Option<Arc<Runtime>> {
+        self.runtime.clone()
+    }
+
+    pub fn get_running_queries(&self) -> u64 {
+        self.running_queries.load(Ordering::Relaxed)
+    }
+
+    pub fn get_queued_queries(&self) -> u64 {
+        self.queued_queries.load(Ordering::Relaxed)
+    }
+}
+
+/// Holds a running query of a tenant, the query stops running when it is dropped.
+pub struct TenantQueryPermit {
+    resources: Arc<TenantResources>,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl TenantQueryPermit {
+    pub fn get_resources(&self) -> Arc<TenantResources> {
+        self.resources.clone()
+    }
+}
+
+impl Drop for TenantQueryPermit {
+    fn drop(&mut self) {
+        self.resources
+            .running_queries
+            .fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Counts a query waiting for the permit, the query may be cancelled while it is waiting.
+struct QueuedQueryGuard<'a> {
+    queued_queries: &'a AtomicU64,
+}
+
+impl<'a> QueuedQueryGuard<'a> {
+    fn create(queued_queries: &'a AtomicU64) -> Self {
+        queued_queries.fetch_add(1, Ordering::Relaxed);
+        QueuedQueryGuard { queued_queries }
+    }
+}
+
+impl Drop for QueuedQueryGuard<'_> {
+    fn drop(&mut self) {
+        self.queued_queries.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+pub struct TenantResourceManager {
+    max_memory_bytes: u64,
+    max_threads: u64,
+    max_concurrent_queries: u64,
+    /// `None` if the queries wait for the permit without a timeout.
+    queue_timeout: Option<Duration>,
+    tenants: RwLock<BTreeMap<String, Arc<TenantResources>>>,
+}
+
+impl TenantResourceManager {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Self::create(conf));
+
+        Ok(())
+    }
+
+    pub fn create(conf: &InnerConfig) -> Arc<TenantResourceManager> {
+        Arc::new(TenantResourceManager {
+            max_memory_bytes: conf.query.tenant_max_memory_bytes,
+            max_threads: conf.query.tenant_max_threads,
+            max_concurrent_queries: conf.query.tenant_max_concurrent_queries,
+            queue_timeout: match conf.query.tenant_query_queue_timeout_secs {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            },
+            tenants: RwLock::new(BTreeMap::new()),
+        })
+    }
+
+    pub fn instance() -> Arc<TenantResourceManager> {
+        GlobalInstance::get()
+    }
+
+    pub fn get_max_memory_bytes(&self) -> u64 {
+        self.max_memory_bytes
+    }
+
+    pub fn get_max_threads(&self) -> u64 {
+        self.max_threads
+    }
+
+    pub fn get_max_concurrent_queries(&self) -> u64 {
+        self.max_concurrent_queries
+    }
+
+    /// The resources of the tenants that have run queries, in the order of the tenants.
+    pub fn get_all_resources(&self) -> Vec<Arc<TenantResources>> {
+        self.tenants.read().values().cloned().collect()
+    }
+
+    pub fn get_resources(&self, tenant: &str) -> Result<Arc<TenantResources>> {
+        if let Some(resources) = self.tenants.read().get(tenant) {
+            return Ok(resources.clone());
+        }
+
+        let mut tenants = self.tenants.write();
+        if let Some(resources) = tenants.get(tenant) {
+            return Ok(resources.clone());
+        }
+
+        let resources = Arc::new(self.create_resources(tenant)?);
+        tenants.insert(tenant.to_string(), resources.clone());
+        Ok(resources)
+    }
+
+    /// Waits until the tenant runs less than `tenant_max_concurrent_queries` queries, for at
+    /// most `tenant_query_queue_timeout_secs`.
+    #[async_backtrace::framed]
+    pub async fn acquire_query(&self, tenant: &str) -> Result<TenantQueryPermit> {
+        let resources = self.get_resources(tenant)?;
+        let permit = match &resources.queries_semaphore {
+            None => None,
+            Some(semaphore) => {
+                let _queued = QueuedQueryGuard::create(&resources.queued_queries);
+                let acquire = semaphore.clone().acquire_owned();
+                let permit = match self.queue_timeout {
+                    None => acquire.await,
+                    Some(queue_timeout) => tokio::time::timeout(queue_timeout, acquire)
+                        .await
+                        .map_err(|_| {
+                            ErrorCode::QueryQueueTimeout(format!(
+                                "The query of tenant {} waited more than {:?} in the queue",
+                                tenant, queue_timeout
+                            ))
+                        })?,
+                };
+                Some(permit.map_err(|e| {
+                    ErrorCode::Internal(format!(
+                        "Cannot acquire the query permit of tenant {}: {}",
+                        tenant, e
+                    ))
+                })?)
+            }
+        };
+
+        resources.running_queries.fetch_add(1, Ordering::Relaxed);
+        Ok(TenantQueryPermit {
+            resources,
+            _permit: permit,
+        })
+    }
+
+    fn create_resources(&self, tenant: &str) -> Result<TenantResources> {
+        let mem_stat = MemStat::create_child(format!("Tenant-{}", tenant), None);
+        mem_stat.set_limit(self.max_memory_bytes as i64);
+
+        let runtime = match self.max_threads {
+            0 => None,
+            // To avoid possible deadlock, we should keep at least two threads.
+            max_threads => Some(Arc::new(Runtime::with_worker_threads_and_mem_stat(
+                max_threads.max(2) as usize,
+                Some(format!("tenant-{}", tenant)),
+                mem_stat.clone(),
+            )?)),
+        };
+
+        let queries_semaphore = match self.max_concurrent_queries {
+            0 => None,
+            max_queries => Some(Arc::new(Semaphore::new(max_queries as usize))),
+        };
+
+        Ok(TenantResources {
+            tenant: tenant.to_string(),
+            mem_stat,
+            runtime,
+            queries_semaphore,
+            running_queries: AtomicU64::new(0),
+            queued_queries: AtomicU64::new(0),
+        })
+    }
+}
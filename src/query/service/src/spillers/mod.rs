@@ -17,3 +17,4 @@ mod spiller;
 pub use spiller::Spiller;
 pub use spiller::SpillerConfig;
 pub use spiller::SpillerType;
+pub use spiller::DEFAULT_PARTITION_BITS;
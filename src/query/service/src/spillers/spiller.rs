@@ -25,12 +25,14 @@ use databend_common_exception::Result;
 use databend_common_expression::arrow::deserialize_column;
 use databend_common_expression::arrow::serialize_column;
 use databend_common_expression::DataBlock;
-use databend_common_hashtable::hash2bucket;
 use log::info;
 use opendal::Operator;
 
 use crate::sessions::QueryContext;
 
+/// The number of partition bits of a spiller if not set, i.e. 8 partitions.
+pub const DEFAULT_PARTITION_BITS: u32 = 3;
+
 /// Spiller type, currently only supports HashJoin
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SpillerType {
@@ -75,8 +77,10 @@ pub struct Spiller {
     config: SpillerConfig,
     spiller_type: SpillerType,
     /// Partition set, which records there are how many partitions.
-    /// Currently it's fixed, in the future we can make it configurable.
+    /// There are `1 << partition_bits` partitions, see `set_partition_bits`.
     pub partition_set: Vec<u8>,
+    /// The number of low bits of the hash used as the partition id.
+    pub partition_bits: u32,
     /// Spilled partition set, after one partition is spilled, it will be added to this set.
     pub spilled_partition_set: HashSet<u8>,
     /// Record the location of the spilled partitions
@@ -99,13 +103,27 @@ impl Spiller {
             operator,
             config,
             spiller_type,
-            partition_set: vec![0, 1, 2, 3, 4, 5, 6, 7],
+            partition_set: (0..1 << DEFAULT_PARTITION_BITS).collect(),
+            partition_bits: DEFAULT_PARTITION_BITS,
             spilled_partition_set: Default::default(),
             partition_location: Default::default(),
             columns_layout: Default::default(),
         }
     }
 
+    /// Set the number of partitions to `1 << bits`, the ids of the partitions should fit in `u8`.
+    pub fn set_partition_bits(&mut self, bits: u32) {
+        debug_assert!(bits <= 8);
+        self.partition_bits = bits;
+        self.partition_set = (0..1_u16 << bits).map(|id| id as u8).collect();
+    }
+
+    /// Get the partition id of a row by its hash.
+    #[inline(always)]
+    pub fn partition_id(&self, hash: u64) -> u8 {
+        (hash & ((1 << self.partition_bits) - 1)) as u8
+    }
+
     /// Read a certain file to a [`DataBlock`].
     /// We should guarantee that the file is managed by this spiller.
     pub async fn read_spilled(&self, file: &str) -> Result<(DataBlock, u64)> {
@@ -253,7 +271,7 @@ impl Spiller {
         let mut partition_rows = HashMap::new();
         // Classify rows to spill or not spill.
         for (row_idx, hash) in hashes.iter().enumerate() {
-            let partition_id = hash2bucket::<3, false>(*hash as usize) as u8;
+            let partition_id = self.partition_id(*hash);
             if spilled_partition_set.contains(&partition_id) {
                 // the row can be directly spilled to corresponding partition
                 partition_rows
@@ -32,7 +32,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
@@ -121,7 +121,7 @@ impl AsyncSource for ParquetInferSchemaSource {
             path: path.clone(),
             ..self.args_parsed.files_info.clone()
         };
-        let operator = init_stage_operator(&stage_info)?;
+        let operator = init_stage_read_operator(&stage_info)?;
 
         let first_file = files_info.first_file(&operator).await?;
         let file_format_params = match &self.args_parsed.file_format {
@@ -45,7 +45,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_stage_location;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::read_metadata_async;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storages_fuse::table_functions::string_literal;
@@ -225,7 +225,7 @@ impl AsyncSource for InspectParquetSource {
             }
         }
 
-        let operator = init_stage_operator(&stage_info)?;
+        let operator = init_stage_read_operator(&stage_info)?;
 
         let file_info = StageFilesInfo {
             path: path.clone(),
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
@@ -87,6 +88,7 @@ impl ListStageTable {
         TableSchemaRefExt::create(vec![
             TableField::new("name", TableDataType::String),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            // The etag of the file, kept for compatibility.
             TableField::new(
                 "md5",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
@@ -96,6 +98,19 @@ impl ListStageTable {
                 "creator",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new(
+                "content_md5",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "etag",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            // Not returned by the listing of opendal yet, always NULL.
+            TableField::new(
+                "storage_class",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ])
     }
 }
@@ -127,12 +142,13 @@ impl Table for ListStageTable {
     fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _plan: &DataSourcePlan,
+        plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
         _put_cache: bool,
     ) -> Result<()> {
+        let limit = plan.push_downs.as_ref().and_then(|extras| extras.limit);
         pipeline.add_source(
-            |output| ListStagesSource::create(ctx.clone(), output, self.args_parsed.clone()),
+            |output| ListStagesSource::create(ctx.clone(), output, self.args_parsed.clone(), limit),
             1,
         )?;
         Ok(())
@@ -154,6 +170,7 @@ struct ListStagesSource {
     is_finished: bool,
     ctx: Arc<dyn TableContext>,
     args_parsed: ListStageArgsParsed,
+    limit: Option<usize>,
 }
 
 impl ListStagesSource {
@@ -161,11 +178,13 @@ impl ListStagesSource {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         args_parsed: ListStageArgsParsed,
+        limit: Option<usize>,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(ctx.clone(), output, ListStagesSource {
             is_finished: false,
             ctx,
             args_parsed,
+            limit,
         })
     }
 }
@@ -201,7 +220,7 @@ impl AsyncSource for ListStagesSource {
                 )));
             }
         }
-        let op = StageTable::get_op(&stage_info)?;
+        let op = StageTable::get_read_op(&stage_info)?;
 
         let files_info = StageFilesInfo {
             path,
@@ -209,7 +228,23 @@ impl AsyncSource for ListStagesSource {
             pattern: self.args_parsed.files_info.pattern.clone(),
         };
 
-        let files = files_info.list(&op, false, None).await?;
+        // The files have to be filtered by `staged_since` before the limit is applied.
+        let mut files = match self.args_parsed.staged_since {
+            None => files_info.list(&op, false, self.limit).await?,
+            Some(staged_since) => {
+                let mut files = files_info.list(&op, false, None).await?;
+                let since = chrono::Duration::from_std(staged_since)
+                    .ok()
+                    .and_then(|d| Utc::now().checked_sub_signed(d));
+                if let Some(since) = since {
+                    files.retain(|file| file.last_modified >= since);
+                }
+                files
+            }
+        };
+        if let Some(limit) = self.limit {
+            files.truncate(limit);
+        }
 
         let names: Vec<String> = files.iter().map(|file| file.path.to_string()).collect();
 
@@ -230,13 +265,18 @@ impl AsyncSource for ListStagesSource {
             .iter()
             .map(|file| file.creator.as_ref().map(|c| c.to_string()))
             .collect();
+        let content_md5s: Vec<Option<String>> = files.iter().map(|file| file.md5.clone()).collect();
+        let storage_classes: Vec<Option<String>> = vec![None; files.len()];
 
         let block = DataBlock::new_from_columns(vec![
             StringType::from_data(names),
             UInt64Type::from_data(sizes),
-            StringType::from_opt_data(etags),
+            StringType::from_opt_data(etags.clone()),
             StringType::from_data(last_modifieds),
             StringType::from_opt_data(creators),
+            StringType::from_opt_data(content_md5s),
+            StringType::from_opt_data(etags),
+            StringType::from_opt_data(storage_classes),
         ]);
 
         Ok(Some(block))
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -22,6 +24,8 @@ use databend_common_storages_fuse::table_functions::string_value;
 pub(crate) struct ListStageArgsParsed {
     pub(crate) location: String,
     pub(crate) files_info: StageFilesInfo,
+    /// Only list the files modified within the duration.
+    pub(crate) staged_since: Option<Duration>,
 }
 
 impl ListStageArgsParsed {
@@ -29,6 +33,7 @@ impl ListStageArgsParsed {
         let args = table_args.expect_all_named("list_stage")?;
 
         let mut location = None;
+        let mut staged_since = None;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -51,6 +56,15 @@ impl ListStageArgsParsed {
                 "pattern" => {
                     files_info.pattern = Some(string_value(v)?);
                 }
+                "staged_since" => {
+                    let secs = v.get_i64().filter(|secs| *secs >= 0).ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "staged_since must be a non-negative number of seconds, but got {}",
+                            v
+                        ))
+                    })?;
+                    staged_since = Some(Duration::from_secs(secs as u64));
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for list_stage",
@@ -66,6 +80,7 @@ impl ListStageArgsParsed {
         Ok(Self {
             location,
             files_info,
+            staged_since,
         })
     }
 }
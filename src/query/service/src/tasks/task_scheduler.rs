@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::Runtime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskState;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The longest time the scheduler sleeps, so the tasks created or altered on the other query
+/// nodes are picked up in time.
+const TASK_RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+/// How long the claim of a run is held. The run is claimed again by the query nodes if the
+/// node that claimed it fails before the task is rescheduled.
+const TASK_RUN_CLAIM_TTL: Duration = Duration::from_secs(300);
+
+/// Runs the tasks stored in the meta service on their schedules, if cloud control is not
+/// enabled to schedule them.
+///
+/// Every query node of the tenant runs a scheduler. A run of a task is claimed in the meta
+/// service before it is executed, so it is executed by one of the nodes only.
+pub struct TaskScheduler {
+    tenant: String,
+    user_api: Arc<UserApiProvider>,
+    session_manager: Arc<SessionManager>,
+    wake_up: Notify,
+}
+
+impl TaskScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let scheduler = Arc::new(TaskScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            user_api: UserApiProvider::instance(),
+            session_manager: SessionManager::instance(),
+            wake_up: Notify::new(),
+        });
+
+        if conf.query.cloud_control_grpc_server_address.is_none() {
+            let runtime = GlobalIORuntime::instance();
+            runtime.clone().spawn(GLOBAL_TASK, {
+                let scheduler = scheduler.clone();
+                async move { scheduler.schedule(runtime).await }
+            });
+        }
+        GlobalInstance::set(scheduler);
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    /// Wakes up the scheduler to pick up the tasks created or altered on this node.
+    pub fn wake_up(&self) {
+        self.wake_up.notify_one();
+    }
+
+    async fn schedule(self: Arc<Self>, runtime: Arc<Runtime>) {
+        loop {
+            let wait = match self.run_due_tasks(&runtime).await {
+                Ok(Some(next_scheduled_at)) => (next_scheduled_at - Utc::now())
+                    .to_std()
+                    .unwrap_or_default()
+                    .min(TASK_RELOAD_INTERVAL),
+                Ok(None) => TASK_RELOAD_INTERVAL,
+                Err(e) => {
+                    warn!(
+                        "failed to schedule the tasks of tenant {}: {}",
+                        self.tenant, e
+                    );
+                    TASK_RELOAD_INTERVAL
+                }
+            };
+            let _ = timeout(wait, self.wake_up.notified()).await;
+        }
+    }
+
+    /// Spawns the runs of the tasks that are due, returns the earliest time the other tasks
+    /// are scheduled at.
+    async fn run_due_tasks(
+        self: &Arc<Self>,
+        runtime: &Arc<Runtime>,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let now = Utc::now();
+        let mut earliest: Option<DateTime<Utc>> = None;
+        for task in self.user_api.get_tasks(&self.tenant).await? {
+            let Some(scheduled_at) = task.next_scheduled_at else {
+                continue;
+            };
+            if task.state != TaskState::Started {
+                continue;
+            }
+            if scheduled_at > now {
+                earliest = Some(earliest.map_or(scheduled_at, |t| t.min(scheduled_at)));
+                continue;
+            }
+
+            let claimed = self
+                .user_api
+                .try_claim_task_run(&self.tenant, &task.name, scheduled_at, TASK_RUN_CLAIM_TTL)
+                .await?;
+            if !claimed {
+                continue;
+            }
+
+            // The task is rescheduled before it runs, so a long run doesn't delay the next one.
+            let task = match self
+                .user_api
+                .update_task_with(&self.tenant, &task.name, |t| t.reschedule(now))
+                .await
+            {
+                Ok(task) => task,
+                Err(e) => {
+                    warn!("failed to reschedule task {}: {}", task.name, e);
+                    continue;
+                }
+            };
+            if let Some(next_scheduled_at) = task.next_scheduled_at {
+                earliest = Some(earliest.map_or(next_scheduled_at, |t| t.min(next_scheduled_at)));
+            }
+
+            let scheduler = self.clone();
+            runtime.spawn(GLOBAL_TASK, async move {
+                if let Err(e) = scheduler.execute_task(&task, scheduled_at).await {
+                    warn!(
+                        "task {} scheduled at {} failed: {}",
+                        task.name, scheduled_at, e
+                    );
+                }
+            });
+        }
+        Ok(earliest)
+    }
+
+    /// Runs the SQL of the task in a new session with the role of the owner of the task, and
+    /// adds the run to the history. The task is suspended if it has failed
+    /// `suspend_task_after_num_failures` times in a row.
+    #[async_backtrace::framed]
+    pub async fn execute_task(&self, task: &Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let mut query_id = String::new();
+        let res = self.run_query(task, &mut query_id).await;
+        let completed_at = Utc::now();
+        info!(
+            "task {} scheduled at {} completed, query id: {}",
+            task.name, scheduled_at, query_id
+        );
+
+        let task_run = TaskRun {
+            task_id: task.id,
+            task_name: task.name.clone(),
+            owner: task.owner.clone(),
+            query_text: task.query_text.clone(),
+            schedule: task.schedule.clone(),
+            warehouse: task.warehouse.clone(),
+            comment: task.comment.clone(),
+            run_id: uuid::Uuid::new_v4().to_string(),
+            query_id,
+            state: match &res {
+                Ok(_) => TaskRunState::Succeeded,
+                Err(_) => TaskRunState::Failed,
+            },
+            error_code: res.as_ref().err().map_or(0, |e| e.code() as i64),
+            error_message: res.as_ref().err().map(|e| e.message()),
+            attempt_number: 1,
+            scheduled_at,
+            completed_at,
+            session_parameters: task.session_parameters.clone(),
+        };
+        self.user_api.add_task_run(&self.tenant, task_run).await?;
+
+        let failed = res.is_err();
+        let update = self
+            .user_api
+            .update_task_with(&self.tenant, &task.name, |t| {
+                // The task has been dropped and created again while it ran.
+                if t.id != task.id {
+                    return Ok(());
+                }
+                if !failed {
+                    t.failures = 0;
+                    return Ok(());
+                }
+                t.failures += 1;
+                if let Some(max_failures) = t.suspend_task_after_num_failures
+                    && max_failures > 0
+                    && t.failures >= max_failures
+                {
+                    t.state = TaskState::Suspended;
+                    t.last_suspended_at = Some(completed_at);
+                    t.next_scheduled_at = None;
+                }
+                Ok(())
+            })
+            .await;
+        match update {
+            Ok(_) => {}
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TASK => {}
+            Err(e) => warn!("failed to update task {} after it ran: {}", task.name, e),
+        }
+
+        res
+    }
+
+    async fn run_query(&self, task: &Task, query_id: &mut String) -> Result<()> {
+        let session = self
+            .session_manager
+            .create_session(SessionType::Dummy)
+            .await?;
+        let user = UserInfo::new_no_auth(&format!("{}-task-svc", self.tenant), "0.0.0.0");
+        let owner = (!task.owner.is_empty()).then(|| task.owner.clone());
+        session.set_authed_user(user, owner).await?;
+        let settings = session.get_settings();
+        for (key, value) in task.session_parameters.iter() {
+            settings.set_setting(key.clone(), value.clone())?;
+        }
+
+        let ctx = session.create_query_context().await?;
+        *query_id = ctx.get_id();
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, plan_extras) = planner.plan_sql(&task.query_text).await?;
+        ctx.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+}
@@ -83,6 +83,26 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn tenant_max_memory_bytes(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.tenant_max_memory_bytes = value;
+        self
+    }
+
+    pub fn tenant_max_threads(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.tenant_max_threads = value;
+        self
+    }
+
+    pub fn tenant_max_concurrent_queries(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.tenant_max_concurrent_queries = value;
+        self
+    }
+
+    pub fn tenant_query_queue_timeout_secs(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.tenant_query_queue_timeout_secs = value;
+        self
+    }
+
     pub fn jwt_key_file(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.jwt_key_file = value.into();
         self
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_management::ClusterApi;
+use databend_common_management::ClusterMgr;
+use databend_common_meta_types::NodeInfo;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::clusters::ClusterHelper;
 use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -65,6 +72,47 @@ async fn test_remove_invalid_nodes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_drop_killed_worker() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.cluster_heartbeat_timeout_secs = 6;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    // The worker is killed after registered, it never heartbeats.
+    let lift_time = Duration::from_secs(config.query.cluster_heartbeat_timeout_secs);
+    let metastore = ClusterDiscovery::create_meta_client(&config).await?;
+    let worker_api = ClusterMgr::create(
+        metastore,
+        &config.query.tenant_id,
+        &config.query.cluster_id,
+        lift_time,
+    )?;
+    let worker = NodeInfo::create(
+        "killed_worker".to_string(),
+        1,
+        "127.0.0.1:6073".to_string(),
+        "".to_string(),
+    );
+    worker_api.add_node(worker).await?;
+
+    let query = "select status from system.cluster_nodes where name = 'killed_worker'";
+    let res = fixture.execute_query(query).await?;
+    let blocks = res.try_collect::<Vec<DataBlock>>().await?;
+    let num_rows: usize = blocks.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(num_rows, 1);
+
+    // The worker is dead after a heartbeat interval of at most 2/3 of the lift time plus
+    // a quarter of it as the margin, and dropped by the check of the next heartbeat.
+    tokio::time::sleep(lift_time * 5 / 3).await;
+
+    let res = fixture.execute_query(query).await?;
+    let blocks = res.try_collect::<Vec<DataBlock>>().await?;
+    let num_rows: usize = blocks.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(num_rows, 0);
+
+    Ok(())
+}
+
 // TODO:(Winter) need kvapi::KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
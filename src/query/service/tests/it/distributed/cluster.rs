@@ -20,6 +20,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_query::api::RpcService;
+use databend_query::sessions::TableContext;
 use databend_query::test_kits::*;
 use futures_util::TryStreamExt;
 use tokio::runtime::Builder as TokioRuntimeBuilder;
@@ -112,6 +113,35 @@ fn test_simple_cluster() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_retry_excludes_unavailable_node() -> Result<()> {
+    let mut conf = ConfigBuilder::create().build();
+    conf.query.flight_api_address = "0.0.0.0:6071".to_string();
+    let fixture = TestFixture::setup_with_config(&conf).await?;
+    let mut srv = RpcService::create(conf.clone())?;
+    srv.start(conf.query.flight_api_address.parse()?).await?;
+
+    // The other node is down, the query is retried without it.
+    let local_id = conf.query.node_id.clone();
+    let cluster_desc = ClusterDescriptor::new()
+        .with_node(&local_id, &conf.query.flight_api_address)
+        .with_node("unavailable", "127.0.0.1:6072")
+        .with_local_id(&local_id);
+
+    let query = "select number % 3 as a, count(*) from numbers(100000) group by a";
+    let ctx = fixture.new_query_ctx_with_cluster(cluster_desc).await?;
+    ctx.get_settings()
+        .set_setting("max_query_retry_on_failure".to_string(), "1".to_string())?;
+    let res = execute_query(ctx.clone(), query).await?;
+    let blocks = res.try_collect::<Vec<DataBlock>>().await?;
+    let num_rows: usize = blocks.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(num_rows, 3);
+    assert_eq!(ctx.get_query_retries(), 1);
+    assert!(ctx.get_unavailable_nodes().contains("unavailable"));
+
+    Ok(())
+}
+
 /// Setup the configurations for the nodes in the cluster.
 fn setup_node_configs(addresses: Vec<&str>) -> Vec<InnerConfig> {
     addresses
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn describe_pipeline(fixture: &TestFixture, query: &str) -> Result<Vec<String>> {
+    let stream = fixture
+        .execute_query(&format!("describe pipeline for {query}"))
+        .await?;
+    let blocks = stream.try_collect::<Vec<DataBlock>>().await?;
+
+    let mut lines = vec![];
+    for block in blocks {
+        let column = block.get_by_offset(0).value.as_column().unwrap();
+        lines.extend(
+            column
+                .as_string()
+                .unwrap()
+                .iter()
+                .map(|line| line.to_string()),
+        );
+    }
+    Ok(lines)
+}
+
+/// Parse the operators of the rendered pipelines, e.g. `[TransformFilter x4 ~10 rows]`,
+/// into their name and parallelism.
+fn parse_operators(lines: &[String]) -> Vec<(String, usize)> {
+    lines
+        .iter()
+        .flat_map(|line| line.split(" --> "))
+        .map(|node| {
+            let node = node.strip_prefix('[').unwrap().strip_suffix(']').unwrap();
+            let node = match node.split_once(" ~") {
+                Some((node, rows)) => {
+                    assert!(rows.ends_with(" rows"), "{node}");
+                    node
+                }
+                None => node,
+            };
+            let (name, parallelism) = node.rsplit_once(" x").unwrap();
+            (name.to_string(), parallelism.parse().unwrap())
+        })
+        .collect()
+}
+
+fn assert_operators(lines: &[String], expected: &[&str]) {
+    let operators = parse_operators(lines);
+    for name in expected {
+        assert!(
+            operators.iter().any(|(operator, _)| operator == name),
+            "{name} not found in {lines:?}"
+        );
+    }
+    assert!(
+        operators.iter().all(|(_, parallelism)| *parallelism > 0),
+        "{lines:?}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_describe_pipeline() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t1(a int, b int)")
+        .await?;
+    fixture
+        .execute_command("create table t2(a int, c int)")
+        .await?;
+    fixture
+        .execute_command("insert into t1 values(1, 1), (2, 2), (3, 3)")
+        .await?;
+    fixture
+        .execute_command("insert into t2 values(1, 1), (2, 2)")
+        .await?;
+
+    let lines = describe_pipeline(&fixture, "select a from t1 where b > 1").await?;
+    assert_eq!(lines.len(), 1);
+    assert_operators(&lines, &[
+        "SyncReadParquetDataSource",
+        "DeserializeDataTransform",
+        "TransformFilter",
+    ]);
+    assert!(lines[0].contains(" rows]"), "{lines:?}");
+
+    let lines = describe_pipeline(&fixture, "select a, count(*) from t1 group by a").await?;
+    assert_operators(&lines, &[
+        "SyncReadParquetDataSource",
+        "TransformPartialAggregate",
+        "TransformFinalAggregate",
+    ]);
+
+    // The build side of the join is rendered as a pipeline of its own.
+    let lines =
+        describe_pipeline(&fixture, "select t1.b, t2.c from t1 join t2 on t1.a = t2.a").await?;
+    assert_eq!(lines.len(), 2);
+    assert_operators(&lines, &["HashJoinProbe", "HashJoinBuild"]);
+
+    let lines = describe_pipeline(&fixture, "select a from t1 format = dot").await?;
+    assert_eq!(
+        lines.first().map(String::as_str),
+        Some("digraph pipeline {")
+    );
+    assert_eq!(lines.last().map(String::as_str), Some("}"));
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains("label = \"SyncReadParquetDataSource x")),
+        "{lines:?}"
+    );
+    assert!(lines.iter().any(|line| line.contains(" -> ")), "{lines:?}");
+
+    Ok(())
+}
@@ -12,4 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod describe_pipeline;
+mod restore_metasrv;
+mod rollup_table;
+mod transaction_isolation;
 mod union;
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::changelog_file_name;
+use databend_common_meta_types::ChangelogChange;
+use databend_common_meta_types::ChangelogEntry;
+use databend_common_meta_types::SeqValue;
+use databend_common_users::UserApiProvider;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn query_names(fixture: &TestFixture, query: &str) -> Result<Vec<String>> {
+    let blocks = fixture
+        .execute_query(query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let mut names = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            let value = block.columns()[0].value.index(row).unwrap();
+            names.push(value.as_string().unwrap().to_string());
+        }
+    }
+    Ok(names)
+}
+
+fn write_changelog(dir: &std::path::Path, node_id: u64, entries: &[ChangelogEntry]) -> Result<()> {
+    let node_dir = dir.join(node_id.to_string());
+    std::fs::create_dir_all(&node_dir)?;
+
+    let mut data = vec![];
+    for entry in entries {
+        serde_json::to_writer(&mut data, entry)?;
+        data.push(b'\n');
+    }
+    let name = changelog_file_name(
+        entries.first().unwrap().log_index,
+        entries.last().unwrap().log_index,
+    );
+    std::fs::write(node_dir.join(name), data)?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_restore_metasrv() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("create database db1").await?;
+    fixture
+        .execute_command("create table db1.t(a int, b string)")
+        .await?;
+    fixture
+        .execute_command("create user u1 identified by 'p1'")
+        .await?;
+    fixture
+        .execute_command("create user u2 identified by 'p2'")
+        .await?;
+
+    // Export the data as the changelog of a metasrv, one change per log, and the user `u2` is
+    // created after the point in time to restore to.
+    let meta = UserApiProvider::instance().get_meta_store_client();
+    let kvs = meta.prefix_list_kv("").await?;
+    let (mut before, mut after) = (vec![], vec![]);
+    for (key, seqv) in kvs {
+        // The node info that expires is not restored data.
+        if seqv.get_expire_at_ms().is_some() {
+            continue;
+        }
+        let change = ChangelogChange {
+            key: key.clone(),
+            value: Some(seqv.data),
+            expire_at: None,
+        };
+        if key.starts_with("__fd_users/") && key.contains("u2") {
+            after.push(change);
+        } else {
+            before.push(change);
+        }
+    }
+    let entries = before
+        .into_iter()
+        .map(|c| (1_000, c))
+        .chain(after.into_iter().map(|c| (3_000, c)))
+        .enumerate()
+        .map(|(i, (time_ms, change))| ChangelogEntry {
+            log_index: i as u64,
+            time_ms,
+            changes: vec![change],
+        })
+        .collect::<Vec<_>>();
+
+    // Node 1 re-exports the logs after a restart, node 2 installs a snapshot of the first half.
+    let dir = tempfile::tempdir()?;
+    let half = entries.len() / 2;
+    write_changelog(dir.path(), 1, &entries[..half + 1])?;
+    write_changelog(dir.path(), 1, &entries[half..])?;
+    write_changelog(dir.path(), 2, &entries[half..])?;
+
+    let restore = format!(
+        "restore metasrv to timestamp '1970-01-01 00:00:02' from 'fs://{}/'",
+        dir.path().to_str().unwrap()
+    );
+
+    let res = fixture.execute_command(&restore).await;
+    assert!(
+        res.unwrap_err().message().contains("not empty"),
+        "restoring into a metasrv with data must fail"
+    );
+
+    // Lose the data.
+    for (key, seqv) in meta.prefix_list_kv("").await? {
+        if seqv.get_expire_at_ms().is_none() {
+            meta.upsert_kv(UpsertKVReq::delete(&key)).await?;
+        }
+    }
+    fixture.execute_command(&restore).await?;
+
+    assert_eq!(
+        query_names(
+            &fixture,
+            "select name from system.tables where database = 'db1'"
+        )
+        .await?,
+        vec!["t".to_string()]
+    );
+    assert_eq!(
+        query_names(
+            &fixture,
+            "select name from system.columns where database = 'db1' and table = 't' order by name"
+        )
+        .await?,
+        vec!["a".to_string(), "b".to_string()]
+    );
+    assert_eq!(
+        query_names(
+            &fixture,
+            "select name from system.users where name like 'u%' order by name"
+        )
+        .await?,
+        vec!["u1".to_string()]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::test_kits::TestFixture;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+
+/// The number of inserts into the source table.
+const NUM_INSERTS: usize = 10_000;
+/// The number of inserts running concurrently.
+const CONCURRENCY: usize = 16;
+
+async fn query_rows(fixture: &TestFixture, query: &str) -> Result<Vec<Vec<String>>> {
+    let blocks = fixture
+        .execute_query(query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let mut rows = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            rows.push(
+                block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.index(row).unwrap().to_string())
+                    .collect(),
+            );
+        }
+    }
+    Ok(rows)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rollup_table_with_concurrent_inserts() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t(a int not null, b int not null)")
+        .await?;
+    fixture
+        .execute_command("insert into t values(0, 1)")
+        .await?;
+    fixture
+        .execute_command(
+            "create rollup table r on t aggregate (sum(b) as s, count(*) as n, min(b) as mi, max(b) as ma) grouping by a",
+        )
+        .await?;
+
+    let fixture = &fixture;
+    futures_util::stream::iter(0..NUM_INSERTS)
+        .map(|i| async move {
+            fixture
+                .execute_command(&format!("insert into t values({}, {i})", i % 10))
+                .await
+        })
+        .buffer_unordered(CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let expected = query_rows(
+        fixture,
+        "select a, sum(b), count(*), min(b), max(b) from t group by a order by a",
+    )
+    .await?;
+    let actual = query_rows(
+        fixture,
+        "select a, sum(s), sum(n), min(mi), max(ma) from r group by a order by a",
+    )
+    .await?;
+    assert_eq!(expected.len(), 10);
+    assert_eq!(actual, expected);
+
+    // No refresh has failed, which is recorded in the options of the rollup table.
+    let rows = query_rows(fixture, "show create table r").await?;
+    assert!(!rows[0][1].contains("rollup_refresh_error"), "{rows:?}");
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::ScalarRef;
+use databend_query::sessions::Session;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+async fn execute(session: &Arc<Session>, query: &str) -> Result<Vec<DataBlock>> {
+    let ctx = session.create_query_context().await?;
+    execute_query(ctx, query).await?.try_collect().await
+}
+
+async fn count(session: &Arc<Session>, query: &str) -> Result<u64> {
+    let blocks = execute(session, query).await?;
+    let value = blocks[0].get_by_offset(0).value.index(0).unwrap();
+    let ScalarRef::Number(NumberScalar::UInt64(count)) = value else {
+        unreachable!("count(*) should be UInt64, but got {:?}", value);
+    };
+    Ok(count)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_committed_prevents_dirty_reads() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t(a int not null)")
+        .await?;
+    fixture
+        .execute_command("insert into t values(1), (2)")
+        .await?;
+
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    execute(&session, "set transaction isolation level read committed").await?;
+    execute(&session, "begin").await?;
+    assert_eq!(count(&session, "select count(*) from t").await?, 2);
+
+    // The blocks written by a statement are not visible before the statement commits.
+    let table = fixture
+        .new_query_ctx()
+        .await?
+        .get_table("default", "default", "t")
+        .await?;
+    let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![3, 4])]);
+    fixture
+        .append_commit_blocks(table.clone(), vec![block.clone()], false, false)
+        .await?;
+    assert_eq!(count(&session, "select count(*) from t").await?, 2);
+
+    // Each statement sees the data committed before it starts.
+    fixture
+        .append_commit_blocks(table, vec![block], false, true)
+        .await?;
+    assert_eq!(count(&session, "select count(*) from t").await?, 4);
+
+    execute(&session, "commit").await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_snapshot_isolation_prevents_phantom_reads() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t1(a int not null)")
+        .await?;
+    fixture
+        .execute_command("create table t2(a int not null)")
+        .await?;
+    fixture
+        .execute_command("create table t3(a int not null)")
+        .await?;
+    fixture
+        .execute_command("insert into t1 values(1), (2), (3)")
+        .await?;
+    fixture.execute_command("insert into t3 values(1)").await?;
+
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    execute(
+        &session,
+        "set transaction isolation level snapshot isolation",
+    )
+    .await?;
+    execute(&session, "begin transaction").await?;
+    assert_eq!(
+        count(&session, "select count(*) from t1 where a > 1").await?,
+        2
+    );
+
+    // The rows inserted by another session after the transaction reads a table are not
+    // visible, the snapshot of a table is pinned when the transaction reads it first.
+    fixture
+        .execute_command("insert into t1 values(4), (5)")
+        .await?;
+    fixture.execute_command("insert into t2 values(1)").await?;
+    assert_eq!(
+        count(&session, "select count(*) from t1 where a > 1").await?,
+        2
+    );
+    assert_eq!(count(&session, "select count(*) from t2").await?, 1);
+    fixture.execute_command("insert into t2 values(2)").await?;
+    assert_eq!(count(&session, "select count(*) from t2").await?, 1);
+
+    // The table changed by another session since the snapshot read by the transaction
+    // can not be written, the first one of the concurrent writes wins.
+    let res = execute(&session, "insert into t2 values(3)").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNRESOLVABLE_CONFLICT);
+    assert_eq!(count(&session, "select count(*) from t2").await?, 1);
+
+    // The transaction sees its own writes, but not the ones of another session after them.
+    execute(&session, "insert into t3 values(2)").await?;
+    fixture.execute_command("insert into t3 values(3)").await?;
+    assert_eq!(count(&session, "select count(*) from t3").await?, 2);
+
+    execute(&session, "commit").await?;
+    assert_eq!(
+        count(&session, "select count(*) from t1 where a > 1").await?,
+        4
+    );
+    assert_eq!(count(&session, "select count(*) from t3").await?, 3);
+
+    // Outside of a transaction, each statement sees the latest data.
+    fixture.execute_command("insert into t1 values(6)").await?;
+    assert_eq!(
+        count(&session, "select count(*) from t1 where a > 1").await?,
+        5
+    );
+    Ok(())
+}
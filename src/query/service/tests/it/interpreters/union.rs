@@ -19,6 +19,7 @@ use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::TableSchemaRef;
@@ -28,7 +29,9 @@ use databend_common_sql::Planner;
 use databend_query::interpreters::InterpreterFactory;
 use databend_query::interpreters::InterpreterPtr;
 use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
 use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
 
 async fn plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Plan> {
     let mut planner = Planner::new(ctx.clone());
@@ -205,3 +208,75 @@ async fn test_union_output_type() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_union_all_shared_table_scan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t(a int not null)")
+        .await?;
+    fixture
+        .execute_command("insert into t values(1), (2), (3)")
+        .await?;
+
+    let query = "select a from t union all select a from t";
+    let num_rows = |blocks: Vec<DataBlock>| blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+
+    // Both branches read the blocks of a single scan.
+    let ctx = fixture.new_query_ctx().await?;
+    let blocks: Vec<DataBlock> = execute_sql(ctx.clone(), query).await?.try_collect().await?;
+    assert_eq!(num_rows(blocks), 6);
+    assert_eq!(ctx.get_scan_progress_value().rows, 3);
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_shared_table_scan".to_string(), "0".to_string())?;
+    let blocks: Vec<DataBlock> = execute_sql(ctx.clone(), query).await?.try_collect().await?;
+    assert_eq!(num_rows(blocks), 6);
+    assert_eq!(ctx.get_scan_progress_value().rows, 6);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_union_all_shared_table_scan_bounded() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t(a int not null)")
+        .await?;
+    // Each insert writes a block, more than the buffer holds with a single thread.
+    for i in 0..10 {
+        fixture
+            .execute_command(&format!("insert into t values({i})"))
+            .await?;
+    }
+
+    let num_rows = |blocks: Vec<DataBlock>| blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+    async fn new_ctx(fixture: &TestFixture) -> Result<Arc<QueryContext>> {
+        let ctx = fixture.new_query_ctx().await?;
+        ctx.get_settings().set_max_threads(1)?;
+        Ok(ctx)
+    }
+
+    // The producer waits for the slowest consumer.
+    let ctx = new_ctx(&fixture).await?;
+    let query = "select a from t union all select a from t";
+    let blocks: Vec<DataBlock> = execute_sql(ctx.clone(), query).await?.try_collect().await?;
+    assert_eq!(num_rows(blocks), 20);
+    assert_eq!(ctx.get_scan_progress_value().rows, 10);
+
+    // The producer stops once the consumers have enough blocks.
+    let ctx = new_ctx(&fixture).await?;
+    let query = "select a from t union all select a from t limit 3";
+    let blocks: Vec<DataBlock> = execute_sql(ctx.clone(), query).await?.try_collect().await?;
+    assert_eq!(num_rows(blocks), 3);
+
+    // The two sides of a join don't share the scan.
+    let ctx = new_ctx(&fixture).await?;
+    let query = "select t1.a from t t1 join t t2 on t1.a = t2.a";
+    let blocks: Vec<DataBlock> = execute_sql(ctx.clone(), query).await?.try_collect().await?;
+    assert_eq!(num_rows(blocks), 10);
+    assert_eq!(ctx.get_scan_progress_value().rows, 20);
+
+    Ok(())
+}
@@ -30,6 +30,7 @@ mod distributed;
 mod frame;
 mod interpreters;
 mod metrics;
+mod notifications;
 mod parquet_rs;
 mod pipelines;
 mod servers;
@@ -38,4 +39,5 @@ mod spillers;
 mod sql;
 mod storages;
 mod table_functions;
+mod tasks;
 mod tests;
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_meta_app::principal::NotificationType;
+use databend_query::notifications::NotificationManager;
+use databend_query::notifications::NotificationPayload;
+use databend_query::test_kits::TestFixture;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::Request;
+use wiremock::ResponseTemplate;
+
+fn webhook_integration(url: String) -> NotificationIntegration {
+    NotificationIntegration {
+        name: "hook".to_string(),
+        notification_type: NotificationType::Webhook,
+        url,
+        events: vec![NotificationEvent::QueryError],
+        enabled: true,
+        comment: "".to_string(),
+        create_on: Utc::now(),
+        update_on: None,
+    }
+}
+
+async fn wait_for_requests(mock_server: &MockServer, n: usize) -> Vec<Request> {
+    for _ in 0..100 {
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        if requests.len() >= n {
+            return requests;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    mock_server.received_requests().await.unwrap_or_default()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_deliver_with_retry() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    // The first attempt fails, the retry is accepted.
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let integration = webhook_integration(format!("{}/hook", mock_server.uri()));
+    let payload = NotificationPayload::copy_into_complete("qid".to_string(), "db.t".to_string(), 3);
+    NotificationManager::deliver(&client, &integration, &payload).await?;
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    let body: serde_json::Value = requests[1].body_json().unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "event": "COPY_INTO_COMPLETE",
+            "query_id": "qid",
+            "table": "db.t",
+            "row_count": 3,
+            "error_message": null,
+        })
+    );
+
+    // Gives up after the attempts all fail.
+    let integration = webhook_integration(format!("{}/missing", mock_server.uri()));
+    let res = NotificationManager::deliver(&client, &integration, &payload).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_notify_query_error() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE NOTIFICATION INTEGRATION hook TYPE = WEBHOOK URL = '{}/hook' EVENTS = (QUERY_ERROR)",
+            mock_server.uri()
+        ))
+        .await?;
+    // The disabled integration is not notified.
+    fixture
+        .execute_command(&format!(
+            "CREATE NOTIFICATION INTEGRATION disabled_hook TYPE = WEBHOOK URL = '{}/disabled' EVENTS = (QUERY_ERROR) ENABLED = false",
+            mock_server.uri()
+        ))
+        .await?;
+
+    let res = fixture
+        .execute_command("DROP DATABASE not_exists_database")
+        .await;
+    assert!(res.is_err());
+
+    let requests = wait_for_requests(&mock_server, 1).await;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url.path(), "/hook");
+    let payload: serde_json::Value = requests[0].body_json().unwrap();
+    assert_eq!(payload["event"], "QUERY_ERROR");
+    assert!(
+        payload["error_message"]
+            .as_str()
+            .unwrap()
+            .contains("not_exists_database")
+    );
+
+    Ok(())
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod data;
+mod projection;
 mod prune_pages;
 mod prune_row_groups;
+mod split_row_groups;
 mod utils;
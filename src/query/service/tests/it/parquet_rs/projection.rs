@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::StructArray;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Fields;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::FileReader;
+use parquet::file::reader::SerializedFileReader;
+use tempfile::NamedTempFile;
+
+use crate::parquet_rs::utils::create_parquet2_test_fixture;
+
+const NUM_COLUMNS: usize = 8;
+
+/// Create a file of `NUM_COLUMNS` int64 columns `c0`, `c1`... and a struct column `s`
+/// of the fields `a` and `b`, in row groups of 1000 rows.
+fn make_wide_file() -> NamedTempFile {
+    let mut file = tempfile::Builder::new()
+        .prefix("parquet_projection")
+        .suffix(".parquet")
+        .tempfile()
+        .expect("tempfile creation");
+
+    let num_rows = 4000;
+    let mut columns: Vec<(String, ArrayRef)> = (0..NUM_COLUMNS)
+        .map(|i| {
+            let values = (0..num_rows).map(|v| (v * (i as i64 + 1)) ^ 0x5a5a);
+            let array: ArrayRef = Arc::new(Int64Array::from_iter_values(values));
+            (format!("c{i}"), array)
+        })
+        .collect();
+    let struct_array = StructArray::new(
+        Fields::from(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]),
+        vec![
+            Arc::new(Int64Array::from_iter_values(0..num_rows)),
+            Arc::new(StringArray::from_iter_values(
+                (0..num_rows).map(|v| format!("value {v}")),
+            )),
+        ],
+        None,
+    );
+    columns.push(("s".to_string(), Arc::new(struct_array)));
+    let batch = RecordBatch::try_from_iter(columns).unwrap();
+
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(1000)
+        .build();
+    let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    file
+}
+
+/// The bytes of the chunks of the leaf column in all the row groups of the file.
+fn leaf_bytes(file: &NamedTempFile, leaf: usize) -> usize {
+    let reader = SerializedFileReader::new(File::open(file.path()).unwrap()).unwrap();
+    reader
+        .metadata()
+        .row_groups()
+        .iter()
+        .map(|rg| rg.column(leaf).byte_range().1 as usize)
+        .sum()
+}
+
+/// Execute the query and return the bytes read from the files by the sources.
+async fn read_bytes(fixture: &TestFixture, sql: &str) -> Result<usize> {
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = executor.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<DataBlock>>().await?;
+    Ok(ctx.get_data_metrics().get_read_bytes())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_projected_columns() -> Result<()> {
+    let file = make_wide_file();
+    let file_path = file.path().to_string_lossy();
+    let fixture = create_parquet2_test_fixture().await;
+
+    // Only the chunks of the projected column are read.
+    let one_column = read_bytes(&fixture, &format!("select c3 from 'fs://{file_path}'")).await?;
+    assert_eq!(one_column, leaf_bytes(&file, 3));
+
+    let all_columns = read_bytes(&fixture, &format!("select * from 'fs://{file_path}'")).await?;
+    let expected: usize = (0..NUM_COLUMNS + 2)
+        .map(|leaf| leaf_bytes(&file, leaf))
+        .sum();
+    assert_eq!(all_columns, expected);
+    assert!(one_column * NUM_COLUMNS < all_columns);
+
+    // Only the chunks of the projected field of the struct are read.
+    let inner_column = read_bytes(&fixture, &format!("select s:a from 'fs://{file_path}'")).await?;
+    assert_eq!(inner_column, leaf_bytes(&file, NUM_COLUMNS));
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_storages_parquet::ParquetPart;
+use futures_util::TryStreamExt;
+
+use crate::parquet_rs::data::make_test_file_page;
+use crate::parquet_rs::data::Scenario;
+use crate::parquet_rs::utils::create_parquet2_test_fixture;
+use crate::parquet_rs::utils::get_data_source_plan;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_split_large_row_group() -> Result<()> {
+    // A file with a row group of 20 rows in 4 pages.
+    let (file, _) = make_test_file_page(Scenario::Int32).await;
+    let file_path = file.path().to_string_lossy();
+
+    let fixture = create_parquet2_test_fixture().await;
+    fixture
+        .default_session()
+        .get_settings()
+        .set_max_threads(8)?;
+
+    // The row group is split into a part for each page, as there are more threads than pages.
+    let sql = format!("select * from 'fs://{file_path}'");
+    let plan = get_data_source_plan(fixture.new_query_ctx().await?, &sql).await?;
+    let mut ranges = vec![];
+    for part in plan.parts.partitions.iter() {
+        let ParquetPart::ParquetRSRowGroup(part) = ParquetPart::from_part(part)? else {
+            unreachable!()
+        };
+        let selectors = part.selectors.as_ref().unwrap();
+        assert!(part.page_locations.is_some());
+        let begin = selectors
+            .iter()
+            .take_while(|s| s.skip)
+            .map(|s| s.row_count)
+            .sum::<usize>();
+        let selected = selectors.iter().find(|s| !s.skip).unwrap();
+        ranges.push((begin, begin + selected.row_count));
+    }
+    assert_eq!(ranges, vec![(0, 5), (5, 10), (10, 15), (15, 20)]);
+
+    // Each row is read once.
+    let stream = fixture
+        .execute_query(&format!("select count(i), sum(i) from 'fs://{file_path}'"))
+        .await?;
+    let blocks = stream.try_collect::<Vec<DataBlock>>().await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(
+        block.get_by_offset(0).value.index(0),
+        Some(ScalarRef::Number(NumberScalar::UInt64(20)))
+    );
+    assert_eq!(
+        block.get_by_offset(1).value.index(0),
+        Some(ScalarRef::Number(NumberScalar::Int64(20)))
+    );
+
+    Ok(())
+}
@@ -497,6 +497,7 @@ async fn create_executor_with_simple_pipeline(
     let settings = ExecutorSettings {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        max_concurrent_async_tasks: 0,
     };
     PipelineExecutor::create(pipeline, settings)
 }
@@ -43,6 +43,7 @@ async fn test_always_call_on_finished() -> Result<()> {
     let settings = ExecutorSettings {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        max_concurrent_async_tasks: 0,
     };
 
     {
@@ -16,6 +16,8 @@
 
 use std::fs;
 use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
 
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
@@ -31,6 +33,7 @@ use databend_common_meta_app::principal::PasswordHashMethod;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
@@ -148,3 +151,70 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_query_cancel_on_stream_dropped() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        // Another session to list the processes.
+        let mut observer = client_with_uds(path).await;
+        observer.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let sql = "select number from numbers(100000000000)";
+        let mut stmt = client.prepare(sql.to_string(), None).await.unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let mut flight_data = client.do_get(ticket).await.unwrap();
+        // Read the schema and the first batches, then close the stream.
+        for _ in 0..10 {
+            flight_data.next().await.unwrap().unwrap();
+        }
+
+        let check = "select count(*) = 0 as finished from system.processes \
+            where info like '%numbers(100000000000)%' and info not like '%system.processes%'";
+        let res = run_query(&mut observer, check).await.unwrap();
+        assert!(res.contains("false"), "query is not running: {res}");
+
+        drop(flight_data);
+        let deadline = Instant::now() + Duration::from_millis(500);
+        loop {
+            let res = run_query(&mut observer, check).await.unwrap();
+            if res.contains("true") {
+                break;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "query is still running after the stream is dropped: {res}"
+            );
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_query::servers::http::DatabendSqlalchemyCompat;
+
+#[test]
+fn test_databend_sqlalchemy_compat() {
+    // answered by the planner
+    for query in [
+        "SELECT 1",
+        "select version()",
+        "SELECT CAST('test plain returns' AS VARCHAR(60)) AS anon_1",
+        "select table_name from information_schema.tables where table_schema = 'default'",
+    ] {
+        assert!(DatabendSqlalchemyCompat::rewrite(query).is_none());
+    }
+
+    let query = DatabendSqlalchemyCompat::rewrite("SHOW VARIABLES LIKE 'sql_mode'").unwrap();
+    assert!(query.starts_with("SELECT name AS `Variable_name`, value AS `Value` FROM ("));
+    assert!(query.ends_with(" WHERE name LIKE 'sql_mode' ORDER BY name"));
+
+    let query = DatabendSqlalchemyCompat::rewrite("show session variables;").unwrap();
+    assert!(query.ends_with(") ORDER BY name"));
+
+    let query = DatabendSqlalchemyCompat::rewrite("SHOW TRANSACTION ISOLATION LEVEL").unwrap();
+    assert_eq!(query, "SELECT 'read committed' AS `transaction_isolation`");
+}
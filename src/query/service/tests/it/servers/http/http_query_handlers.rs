@@ -17,6 +17,8 @@ use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
 
+use async_compression::tokio::bufread::BrotliDecoder;
+use async_compression::tokio::bufread::GzipDecoder;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -30,20 +32,25 @@ use databend_common_users::EnsureUser;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::middleware::ResponseEncoding;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::BatchQueryResponse;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryError;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::StreamChunk;
+use databend_query::servers::http::v1::StreamEnd;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 use futures_util::future::try_join_all;
+use futures_util::StreamExt;
 use headers::Header;
 use headers::HeaderMapExt;
 use http::HeaderMap;
@@ -64,6 +71,7 @@ use poem::Route;
 use pretty_assertions::assert_eq;
 use serde_json::json;
 use serde_json::Value;
+use tokio::io::AsyncReadExt;
 use tokio::time::sleep;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
@@ -864,6 +872,361 @@ async fn test_query_log_killed() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_batch_query_ddl() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sqls": [
+        "create database batch_db",
+        "create table batch_db.t1(a int)",
+        "create table batch_db.t2(a int, b string)",
+        "alter table batch_db.t2 add column c int",
+        "show tables from batch_db",
+        "drop database batch_db",
+    ]});
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.error_index, None, "{:?}", result);
+    // the session is dropped after the batch
+    assert!(result.session_id.is_none(), "{:?}", result);
+    assert_eq!(result.results.len(), 6, "{:?}", result);
+    for (i, r) in result.results.iter().enumerate() {
+        assert_eq!(r.index, i, "{:?}", result);
+        assert_eq!(r.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    }
+    assert_eq!(result.results[4].data.len(), 2, "{:?}", result);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_batch_query_mixed() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    // the statements are executed in the same session
+    let json = serde_json::json!({"sqls": [
+        "create database batch_db",
+        "use batch_db",
+        "set max_threads = 3",
+        "create table t(a int)",
+        "insert into t values (1), (2), (3)",
+        "delete from t where a = 2",
+        "select a from t order by a",
+        "select database(), getsetting('max_threads')",
+    ], "session": {"keep_server_session_secs": 10}});
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.results.len(), 8, "{:?}", result);
+    assert_eq!(
+        result.results[1].affect,
+        Some(QueryAffect::UseDB {
+            name: "batch_db".to_string()
+        }),
+        "{:?}",
+        result
+    );
+    assert_eq!(result.results[6].data, vec![vec!["1"], vec!["3"]]);
+    assert_eq!(result.results[7].data, vec![vec!["batch_db", "3"]]);
+    assert_eq!(
+        result.session.as_ref().and_then(|s| s.database.clone()),
+        Some("batch_db".to_string()),
+        "{:?}",
+        result
+    );
+
+    // the session is kept, and can be used by the next queries
+    let session_id = result.session_id.unwrap();
+    let json = serde_json::json!({"sql": "select count(*) from t", "session_id": session_id});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data, vec![vec!["2"]]);
+
+    let json = serde_json::json!({"sqls": ["drop database batch_db"], "session_id": session_id});
+    let (_, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.session_id, Some(session_id));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_batch_query_on_error() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let sqls = serde_json::json!([
+        "create table batch_t(a int)",
+        "insert into batch_t values (1)",
+        "select * from batch_not_exists",
+        "insert into batch_t values (2)",
+        "select count(*) from batch_t",
+    ]);
+
+    // stop at the failed statement by default
+    let json = serde_json::json!({ "sqls": sqls });
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.error_index, Some(2), "{:?}", result);
+    assert_eq!(
+        result.error.as_ref().map(|e| e.code),
+        Some(ErrorCode::UNKNOWN_TABLE),
+        "{:?}",
+        result
+    );
+    assert_eq!(result.results.len(), 3, "{:?}", result);
+    assert_eq!(result.results[2].state, ExecuteStateKind::Failed);
+
+    let (_, result) = post_sql("select count(*) from batch_t", 3).await?;
+    assert_eq!(result.data, vec![vec!["1"]]);
+
+    // run all the statements regardless of the errors
+    let json = serde_json::json!({ "sqls": ["drop table batch_t"] });
+    let (_, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let json = serde_json::json!({ "sqls": sqls, "on_error": "continue" });
+    let (status, result) = post_batch_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.error_index, Some(2), "{:?}", result);
+    assert_eq!(result.results.len(), 5, "{:?}", result);
+    assert_eq!(result.results[2].state, ExecuteStateKind::Failed);
+    assert_eq!(result.results[3].state, ExecuteStateKind::Succeeded);
+    assert_eq!(result.results[4].data, vec![vec!["2"]]);
+
+    Ok(())
+}
+
+async fn post_batch_to_endpoint(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+) -> Result<(StatusCode, BatchQueryResponse)> {
+    let body = serde_json::to_vec(&json)?;
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/query/batch".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(basic)
+        .body(body);
+
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    let status = response.status();
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<BatchQueryResponse>(&body);
+    assert!(
+        result.is_ok(),
+        "body ='{}', result='{:?}'",
+        &body,
+        result.err()
+    );
+    Ok((status, result?))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_stream() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "select number from numbers(25000)", "pagination": {"wait_time_secs": 0, "max_rows_per_page": 10000}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let fetched = result.data.len();
+
+    let response = get_uri(&ep, &format!("/v1/query/{}/stream", result.id)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let events = parse_sse_events(&body);
+    assert!(events.len() >= 2, "{body}");
+
+    // the pages fetched before are not sent again
+    let mut rows = fetched;
+    let mut total_rows = fetched;
+    for (event, data) in events.iter() {
+        if event == "chunk" {
+            let chunk = serde_json::from_str::<StreamChunk>(data)?;
+            assert!(chunk.data.len() <= 10000, "{body}");
+            rows += chunk.data.len();
+            total_rows = chunk.total_rows;
+        }
+    }
+    assert_eq!(rows, 25000, "{body}");
+    assert_eq!(total_rows, 25000, "{body}");
+
+    let (event, data) = events.last().unwrap();
+    assert_eq!(event, "end", "{body}");
+    let end = serde_json::from_str::<StreamEnd>(data)?;
+    assert_eq!(end.state, ExecuteStateKind::Succeeded, "{body}");
+    assert!(end.error.is_none(), "{body}");
+
+    // the query is removed after the end
+    let response = get_uri(&ep, &make_state_uri(&result.id)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_stream_closed() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "select number from numbers(100000000000)", "pagination": {"wait_time_secs": 0}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let response = get_uri(&ep, &format!("/v1/query/{}/stream", result.id)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut stream = response.into_body().into_bytes_stream();
+    for _ in 0..3 {
+        stream.next().await.unwrap().unwrap();
+    }
+    drop(stream);
+
+    // the query is killed once the client closes the stream
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    loop {
+        let response = get_uri(&ep, &make_state_uri(&result.id)).await;
+        if response.status() == StatusCode::NOT_FOUND {
+            break;
+        }
+        assert!(
+            std::time::Instant::now() < deadline,
+            "query is still running after the stream is closed"
+        );
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_response_encoding_negotiation() {
+    let cases = [
+        ("gzip", Some(ResponseEncoding::Gzip)),
+        ("gzip, deflate, br", Some(ResponseEncoding::Brotli)),
+        ("br;q=0.5, gzip;q=0.8", Some(ResponseEncoding::Gzip)),
+        ("gzip;q=0, br;q=0", None),
+        ("deflate, identity", None),
+        ("", None),
+    ];
+    for (accept_encoding, expected) in cases {
+        assert_eq!(
+            ResponseEncoding::from_accept_encoding(accept_encoding),
+            expected,
+            "{accept_encoding}"
+        );
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_response_compression() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, to_string(number) from numbers(100000)";
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 6, "max_rows_per_page": 100000}});
+
+    let response = post_json_with_encoding(&ep, &json, "").await?;
+    assert_eq!(response.headers().get(header::CONTENT_ENCODING), None);
+    let raw = response.into_body().into_bytes().await.unwrap();
+    let expected = serde_json::from_slice::<QueryResponse>(&raw)?;
+    assert_eq!(expected.data.len(), 100000);
+
+    for encoding in ["gzip", "br"] {
+        let response = post_json_with_encoding(&ep, &json, encoding).await?;
+        assert_eq!(
+            response.headers().get(header::CONTENT_ENCODING),
+            Some(&HeaderValue::from_static(encoding))
+        );
+        let compressed = response.into_body().into_bytes().await.unwrap();
+        assert!(
+            compressed.len() * 2 <= raw.len(),
+            "{encoding}: {} bytes compressed to {} bytes",
+            raw.len(),
+            compressed.len()
+        );
+        let result =
+            serde_json::from_slice::<QueryResponse>(&decode_body(encoding, &compressed).await)?;
+        assert_eq!(result.data, expected.data);
+    }
+
+    // smaller than response_compression_threshold
+    let json = serde_json::json!({"sql": "select 1"});
+    let response = post_json_with_encoding(&ep, &json, "gzip").await?;
+    assert_eq!(response.headers().get(header::CONTENT_ENCODING), None);
+
+    let json = serde_json::json!({"sql": "select 1", "session": {"settings": {"response_compression_threshold": "0"}}});
+    let response = post_json_with_encoding(&ep, &json, "gzip").await?;
+    assert_eq!(
+        response.headers().get(header::CONTENT_ENCODING),
+        Some(&HeaderValue::from_static("gzip"))
+    );
+    let body = response.into_body().into_bytes().await.unwrap();
+    let result = serde_json::from_slice::<QueryResponse>(&decode_body("gzip", &body).await)?;
+    assert_eq!(result.data, vec![vec!["1"]]);
+
+    Ok(())
+}
+
+async fn decode_body(encoding: &str, data: &[u8]) -> Vec<u8> {
+    let mut decoded = vec![];
+    match encoding {
+        "gzip" => GzipDecoder::new(data).read_to_end(&mut decoded).await,
+        "br" => BrotliDecoder::new(data).read_to_end(&mut decoded).await,
+        _ => unreachable!(),
+    }
+    .unwrap();
+    decoded
+}
+
+async fn post_json_with_encoding(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+    accept_encoding: &str,
+) -> Result<Response> {
+    let body = serde_json::to_vec(&json)?;
+    let basic = headers::Authorization::basic("root", "");
+    let mut req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(basic);
+    if !accept_encoding.is_empty() {
+        req = req.header(header::ACCEPT_ENCODING, accept_encoding);
+    }
+
+    ep.call(req.body(body))
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))
+}
+
+fn parse_sse_events(body: &str) -> Vec<(String, String)> {
+    body.split("\n\n")
+        .filter_map(|event| {
+            let mut event_type = None;
+            let mut data = None;
+            for line in event.lines() {
+                if let Some(v) = line.strip_prefix("event: ") {
+                    event_type = Some(v.to_string());
+                } else if let Some(v) = line.strip_prefix("data: ") {
+                    data = Some(v.to_string());
+                }
+            }
+            Some((event_type?, data?))
+        })
+        .collect()
+}
+
 async fn check_response(response: Response) -> Result<(StatusCode, QueryResponse)> {
     let status = response.status();
     let body = response.into_body().into_string().await.unwrap();
@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod clickhouse_handler;
+mod databend_sqlalchemy_compat;
 mod http_query_handlers;
 mod json_block;
+mod stage_uploader;
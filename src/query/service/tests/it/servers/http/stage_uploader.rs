@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::servers::http::v1::StageUploader;
+use parking_lot::Mutex;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_stage_uploader_concurrency() -> Result<()> {
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let written = Arc::new(Mutex::new(vec![]));
+
+    let write = {
+        let running = running.clone();
+        let max_running = max_running.clone();
+        let written = written.clone();
+        move |path: String, _data: Vec<u8>| {
+            let running = running.clone();
+            let max_running = max_running.clone();
+            let written = written.clone();
+            async move {
+                let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(n, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                written.lock().push(path);
+                Ok(())
+            }
+        }
+    };
+
+    let mut uploader = StageUploader::new(write, 4);
+    for i in 0..20 {
+        uploader.upload(format!("file_{i}"), vec![0; 16]).await?;
+    }
+    uploader.finish().await?;
+
+    let mut written = written.lock().clone();
+    written.sort();
+    let mut expected = (0..20).map(|i| format!("file_{i}")).collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(written, expected);
+    let max_running = max_running.load(Ordering::SeqCst);
+    assert!(max_running > 1, "uploads are not concurrent");
+    assert!(max_running <= 4, "{max_running} uploads at the same time");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_stage_uploader_retry() -> Result<()> {
+    // the first two writes of file_3 fail
+    let attempts = Arc::new(Mutex::new(HashMap::<String, usize>::new()));
+    let write = {
+        let attempts = attempts.clone();
+        move |path: String, _data: Vec<u8>| {
+            let attempts = attempts.clone();
+            async move {
+                let attempt = {
+                    let mut attempts = attempts.lock();
+                    let n = attempts.entry(path.clone()).or_default();
+                    *n += 1;
+                    *n
+                };
+                if path == "file_3" && attempt <= 2 {
+                    return Err(ErrorCode::StorageOther("injected failure"));
+                }
+                Ok(())
+            }
+        }
+    };
+
+    let mut uploader = StageUploader::new(write, 4);
+    for i in 0..8 {
+        uploader.upload(format!("file_{i}"), vec![0; 16]).await?;
+    }
+    uploader.finish().await?;
+
+    let attempts = attempts.lock();
+    assert_eq!(attempts.len(), 8);
+    for (path, n) in attempts.iter() {
+        let expected = if path == "file_3" { 3 } else { 1 };
+        assert_eq!(*n, expected, "{path}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_stage_uploader_failure() -> Result<()> {
+    let write = |path: String, _data: Vec<u8>| async move {
+        if path == "file_1" {
+            return Err(ErrorCode::StorageOther("injected failure"));
+        }
+        Ok(())
+    };
+
+    let mut uploader = StageUploader::new(write, 2);
+    let mut res = Ok(());
+    for i in 0..8 {
+        res = uploader.upload(format!("file_{i}"), vec![0; 16]).await;
+        if res.is_err() {
+            break;
+        }
+    }
+    if res.is_ok() {
+        res = uploader.finish().await;
+    }
+
+    let err = res.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::STORAGE_OTHER);
+    assert!(err.message().contains("file_1"), "{}", err.message());
+    assert!(
+        err.message().contains("after 3 retries"),
+        "{}",
+        err.message()
+    );
+
+    Ok(())
+}
@@ -73,6 +73,37 @@ async fn test_connect_with_tls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_rollback_in_transaction() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    // The drivers may send ROLLBACK outside of a transaction.
+    assert!(connection.query_drop("ROLLBACK").await.is_ok());
+
+    // The statements of a transaction have committed their writes.
+    connection.query_drop("BEGIN").await.unwrap();
+    let error = connection.query_drop("ROLLBACK").await.unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("ROLLBACK is not supported in a transaction"),
+        "{}",
+        error
+    );
+
+    connection.query_drop("COMMIT").await.unwrap();
+    assert!(connection.query_drop("ROLLBACK").await.is_ok());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     // TestFixture will create a default session, so we should limit the max_active_sessions to 2.
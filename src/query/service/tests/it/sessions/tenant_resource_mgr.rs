@@ -0,0 +1,208 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::ScalarRef;
+use databend_query::sessions::Session;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::sessions::TenantResourceManager;
+use databend_query::test_kits::execute_query;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use tokio_stream::StreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tenant_resource_isolation() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .tenant_max_threads(2)
+        .tenant_max_concurrent_queries(1)
+        .build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    let session_a = fixture.new_session_with_type(SessionType::Local).await?;
+    session_a.set_current_tenant("tenant_a".to_string());
+    let session_b = fixture.new_session_with_type(SessionType::Local).await?;
+    session_b.set_current_tenant("tenant_b".to_string());
+
+    // The running query of tenant_a doesn't block the queries of tenant_b.
+    let ctx_a = session_a.create_query_context().await?;
+    let ctx_b = session_b.create_query_context().await?;
+    ctx_a.acquire_tenant_permit().await?;
+    ctx_b.acquire_tenant_permit().await?;
+    assert_eq!(ctx_a.get_tenant(), "tenant_a");
+    assert_eq!(ctx_b.get_tenant(), "tenant_b");
+
+    // But it blocks the other queries of tenant_a when they start to execute, not when their
+    // contexts are created, e.g. while logging in.
+    let waiting_ctx = session_a.create_query_context().await?;
+    let waiting = tokio::time::timeout(
+        Duration::from_millis(200),
+        waiting_ctx.acquire_tenant_permit(),
+    )
+    .await;
+    assert!(waiting.is_err());
+    drop(waiting_ctx);
+
+    // The tenants run their queries in the runtimes of their own.
+    let manager = TenantResourceManager::instance();
+    let resources_a = manager.get_resources("tenant_a")?;
+    let resources_b = manager.get_resources("tenant_b")?;
+    assert_eq!(resources_a.get_running_queries(), 1);
+    assert_eq!(resources_a.get_queued_queries(), 0);
+    assert_eq!(resources_b.get_running_queries(), 1);
+    assert!(!std::ptr::eq(
+        resources_a.get_runtime().unwrap().as_ref(),
+        resources_b.get_runtime().unwrap().as_ref()
+    ));
+
+    // Each tenant sees its own usage only.
+    let blocks = execute_query(
+        ctx_b.clone(),
+        "select tenant, running_queries, max_threads, max_concurrent_queries \
+         from system.tenant_resource_usage",
+    )
+    .await?
+    .collect::<Result<Vec<_>>>()
+    .await?;
+    assert_eq!(blocks.len(), 1);
+    let block = &blocks[0];
+    assert_eq!(block.num_rows(), 1);
+    assert_eq!(
+        block.get_by_offset(0).value.index(0),
+        Some(ScalarRef::String("tenant_b"))
+    );
+    for (offset, expected) in [(1, 1), (2, 2), (3, 1)] {
+        assert_eq!(
+            block.get_by_offset(offset).value.index(0),
+            Some(ScalarRef::Number(NumberScalar::UInt64(expected)))
+        );
+    }
+
+    // The next query of tenant_a runs after the running one finishes.
+    drop(ctx_a);
+    let ctx_a = session_a.create_query_context().await?;
+    tokio::time::timeout(Duration::from_secs(10), ctx_a.acquire_tenant_permit())
+        .await
+        .expect("the query of tenant_a is still blocked")?;
+    assert_eq!(ctx_a.get_tenant(), "tenant_a");
+    assert_eq!(resources_a.get_running_queries(), 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tenant_query_queue_timeout() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .tenant_max_concurrent_queries(1)
+        .tenant_query_queue_timeout_secs(1)
+        .build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    let session = fixture.new_session_with_type(SessionType::Local).await?;
+    session.set_current_tenant("tenant_a".to_string());
+    let running_ctx = session.create_query_context().await?;
+    running_ctx.acquire_tenant_permit().await?;
+
+    let queued_ctx = session.create_query_context().await?;
+    let res = queued_ctx.acquire_tenant_permit().await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::QUERY_QUEUE_TIMEOUT);
+
+    let resources = TenantResourceManager::instance().get_resources("tenant_a")?;
+    assert_eq!(resources.get_running_queries(), 1);
+    assert_eq!(resources.get_queued_queries(), 0);
+    Ok(())
+}
+
+/// The median latency of a query of the session.
+async fn median_latency(session: &Arc<Session>) -> Result<Duration> {
+    let mut latencies = Vec::with_capacity(11);
+    for _ in 0..11 {
+        let ctx = session.create_query_context().await?;
+        let start = Instant::now();
+        execute_query(ctx, "select sum(number) from numbers(10000000)")
+            .await?
+            .collect::<Result<Vec<_>>>()
+            .await?;
+        latencies.push(start.elapsed());
+    }
+    latencies.sort();
+    Ok(latencies[latencies.len() / 2])
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tenant_latency_isolation() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .tenant_max_threads(2)
+        .tenant_max_concurrent_queries(1)
+        .tenant_query_queue_timeout_secs(0)
+        .build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    let session_b = fixture.new_session_with_type(SessionType::Local).await?;
+    session_b.set_current_tenant("tenant_b".to_string());
+
+    // Warm up, then measure tenant_b alone.
+    median_latency(&session_b).await?;
+    let idle_latency = median_latency(&session_b).await?;
+
+    // tenant_a keeps a query running and more queued than its concurrency.
+    let stopped = Arc::new(AtomicBool::new(false));
+    let mut handles = Vec::with_capacity(4);
+    for _ in 0..4 {
+        let session_a = fixture.new_session_with_type(SessionType::Local).await?;
+        session_a.set_current_tenant("tenant_a".to_string());
+        session_a.get_settings().set_max_threads(1)?;
+        let stopped = stopped.clone();
+        handles.push(databend_common_base::runtime::spawn(async move {
+            while !stopped.load(Ordering::Relaxed) {
+                let ctx = session_a.create_query_context().await?;
+                execute_query(ctx, "select sum(number) from numbers(100000000)")
+                    .await?
+                    .collect::<Result<Vec<_>>>()
+                    .await?;
+            }
+            Result::Ok(())
+        }));
+    }
+
+    let resources_a = TenantResourceManager::instance().get_resources("tenant_a")?;
+    while resources_a.get_queued_queries() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let busy_latency = median_latency(&session_b).await?;
+
+    stopped.store(true, Ordering::Relaxed);
+    for handle in handles {
+        handle.await.unwrap()?;
+    }
+
+    // The queries of tenant_a don't slow down the queries of tenant_b by 20% or more.
+    assert!(
+        busy_latency.as_secs_f64() < idle_latency.as_secs_f64() * 1.2,
+        "the latency of tenant_b rises from {:?} to {:?}",
+        idle_latency,
+        busy_latency
+    );
+    Ok(())
+}
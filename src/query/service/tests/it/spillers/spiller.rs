@@ -75,3 +75,25 @@ async fn test_spill_with_partition() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_partition_bits() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let spiller_config = SpillerConfig::create(query_spill_prefix(&tenant));
+    let operator = DataOperator::instance().operator();
+
+    let mut spiller = Spiller::create(ctx, operator, spiller_config, SpillerType::HashJoinBuild);
+    assert_eq!(spiller.partition_set.len(), 8);
+    assert_eq!(spiller.partition_id(13), 5);
+
+    spiller.set_partition_bits(5);
+    assert_eq!(spiller.partition_set, (0..32).collect::<Vec<u8>>());
+    assert_eq!(spiller.partition_id(13), 13);
+    assert_eq!(spiller.partition_id(45), 13);
+    assert!(!spiller.is_all_spilled());
+
+    Ok(())
+}
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod agg_index_query_rewrite;
+mod rewrite_rule_applier;
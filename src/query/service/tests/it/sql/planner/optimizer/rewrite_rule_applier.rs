@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::optimizer::Matcher;
+use databend_common_sql::optimizer::RewriteRuleRegistry;
+use databend_common_sql::optimizer::Rule;
+use databend_common_sql::optimizer::RuleID;
+use databend_common_sql::optimizer::RulePtr;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::optimizer::TransformResult;
+use databend_common_sql::plans::Limit;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOp;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::MetadataRef;
+use databend_common_sql::Planner;
+use databend_query::test_kits::TestFixture;
+
+const MAGIC_LIMIT: usize = 240241;
+
+/// Rewrites `LIMIT 240241` to `LIMIT 1`.
+struct RuleRewriteMagicLimit {
+    matchers: Vec<Matcher>,
+}
+
+impl Rule for RuleRewriteMagicLimit {
+    fn id(&self) -> RuleID {
+        RuleID::UserDefined
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let limit: Limit = s_expr.plan().clone().try_into()?;
+        if limit.limit == Some(MAGIC_LIMIT) {
+            let limit = Limit {
+                limit: Some(1),
+                ..limit
+            };
+            state.add_result(SExpr::create_unary(
+                Arc::new(limit.into()),
+                Arc::new(s_expr.child(0)?.clone()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
+
+fn find_limit(s_expr: &SExpr) -> Option<&Limit> {
+    match s_expr.plan() {
+        RelOperator::Limit(limit) => Some(limit),
+        _ => s_expr.children().find_map(find_limit),
+    }
+}
+
+async fn plan_limit(planner: &mut Planner, sql: &str) -> Result<Option<usize>> {
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let Plan::Query { s_expr, .. } = plan else {
+        unreachable!()
+    };
+    Ok(find_limit(&s_expr).and_then(|limit| limit.limit))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_user_defined_rewrite_rule() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let sql = format!("select number from numbers(10) limit {MAGIC_LIMIT}");
+
+    let registry = RewriteRuleRegistry::instance();
+    registry.register(
+        "rewrite_magic_limit",
+        Arc::new(|_: MetadataRef| -> RulePtr {
+            Box::new(RuleRewriteMagicLimit {
+                matchers: vec![Matcher::MatchOp {
+                    op_type: RelOp::Limit,
+                    children: vec![Matcher::Leaf],
+                }],
+            })
+        }),
+    )?;
+    // The names of the rules are unique.
+    assert!(
+        registry
+            .register(
+                "rewrite_magic_limit",
+                Arc::new(|_: MetadataRef| -> RulePtr { unreachable!() })
+            )
+            .is_err()
+    );
+
+    assert_eq!(plan_limit(&mut planner, &sql).await?, Some(1));
+    assert_eq!(
+        plan_limit(&mut planner, "select number from numbers(10) limit 2").await?,
+        Some(2)
+    );
+
+    assert!(registry.unregister("rewrite_magic_limit"));
+    assert!(!registry.unregister("rewrite_magic_limit"));
+    assert_eq!(plan_limit(&mut planner, &sql).await?, Some(MAGIC_LIMIT));
+
+    Ok(())
+}
@@ -36,6 +36,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_context::TenantResourceUsage;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -570,6 +571,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_tenant_resource_usage(&self) -> Vec<TenantResourceUsage> {
+        todo!()
+    }
+
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         todo!()
     }
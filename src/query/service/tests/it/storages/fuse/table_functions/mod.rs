@@ -14,3 +14,4 @@
 
 mod clustering_information_table;
 mod fuse_block_table;
+mod show_segments;
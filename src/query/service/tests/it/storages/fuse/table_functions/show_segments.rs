@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::*;
+use tokio_stream::StreamExt;
+
+async fn query_blocks(ctx: Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    execute_query(ctx, sql)
+        .await?
+        .collect::<Result<Vec<DataBlock>>>()
+        .await
+}
+
+/// Returns the number of segments and the sum of their rows.
+async fn show_segments(ctx: Arc<QueryContext>, sql: &str) -> Result<(usize, u64)> {
+    let blocks = query_blocks(ctx, sql).await?;
+    let mut segment_count = 0;
+    let mut row_count = 0;
+    for block in blocks {
+        // The columns are segment_location, block_count, row_count, file_size_bytes,
+        // created_on and cluster_stats.
+        assert_eq!(block.num_columns(), 6);
+        for row in 0..block.num_rows() {
+            let value = block.get_by_offset(2).value.index(row).unwrap();
+            let ScalarRef::Number(NumberScalar::UInt64(rows)) = value else {
+                unreachable!("row_count should be UInt64, but got {:?}", value);
+            };
+            row_count += rows;
+            segment_count += 1;
+        }
+    }
+    Ok((segment_count, row_count))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_show_segments() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let show_sql = format!("show segments for table {}.{}", db, tbl);
+    assert_eq!(show_segments(ctx.clone(), &show_sql).await?, (0, 0));
+
+    // Each insertion writes a segment.
+    for i in 0..3 {
+        let qry = format!(
+            "insert into {}.{} values({}, ({}, {})), ({}, ({}, {}))",
+            db,
+            tbl,
+            i,
+            i,
+            i,
+            i + 10,
+            i,
+            i
+        );
+        execute_command(ctx.clone(), &qry).await?;
+    }
+    assert_eq!(show_segments(ctx.clone(), &show_sql).await?, (3, 6));
+
+    let qry = format!("optimize table {}.{} compact segment", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+    assert_eq!(show_segments(ctx.clone(), &show_sql).await?, (1, 6));
+
+    // The segments of the snapshot before the compaction are still readable.
+    let qry = format!(
+        "select snapshot_id from fuse_snapshot('{}', '{}') order by timestamp desc limit 1 offset 1",
+        db, tbl
+    );
+    let blocks = query_blocks(ctx.clone(), &qry).await?;
+    let snapshot_id = blocks[0]
+        .get_by_offset(0)
+        .value
+        .index(0)
+        .unwrap()
+        .into_string()
+        .unwrap()
+        .to_string();
+    let qry = format!("{} at snapshot '{}'", show_sql, snapshot_id);
+    assert_eq!(show_segments(ctx.clone(), &qry).await?, (3, 6));
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::test_kits::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_local_task() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t(a INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE TASK my_task SCHEDULE = 'USING CRON 0 * * * * Asia/Shanghai' AS INSERT INTO {db}.t VALUES (1)"
+        ))
+        .await?;
+
+    // A created task is suspended until it is resumed.
+    let query = "SELECT name, state, schedule FROM system.tasks";
+    expects_ok("created_task", fixture.execute_query(query).await, vec![
+        "+-----------+-------------+-----------------------------------------+",
+        "| Column 0  | Column 1    | Column 2                                |",
+        "+-----------+-------------+-----------------------------------------+",
+        "| 'my_task' | 'Suspended' | 'CRON 0 * * * * TIMEZONE Asia/Shanghai' |",
+        "+-----------+-------------+-----------------------------------------+",
+    ])
+    .await?;
+
+    fixture.execute_command("ALTER TASK my_task RESUME").await?;
+    expects_ok(
+        "resumed_task",
+        fixture
+            .execute_query("SELECT state, next_schedule_time IS NOT NULL FROM system.tasks")
+            .await,
+        vec![
+            "+-----------+----------+",
+            "| Column 0  | Column 1 |",
+            "+-----------+----------+",
+            "| 'Started' | true     |",
+            "+-----------+----------+",
+        ],
+    )
+    .await?;
+    fixture
+        .execute_command("ALTER TASK my_task SUSPEND")
+        .await?;
+
+    // A suspended task can still be executed.
+    fixture.execute_command("EXECUTE TASK my_task").await?;
+    expects_ok(
+        "executed_task",
+        fixture
+            .execute_query(&format!("SELECT count(*) FROM {db}.t"))
+            .await,
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 1        |",
+            "+----------+",
+        ],
+    )
+    .await?;
+
+    fixture
+        .execute_command("ALTER TASK my_task MODIFY AS INSERT INTO not_exists_table VALUES (1)")
+        .await?;
+    assert!(
+        fixture
+            .execute_command("EXECUTE TASK my_task")
+            .await
+            .is_err()
+    );
+
+    expects_ok(
+        "task_history",
+        fixture
+            .execute_query("SELECT name, state, exception_code = 0 FROM system.task_history")
+            .await,
+        vec![
+            "+-----------+-------------+----------+",
+            "| Column 0  | Column 1    | Column 2 |",
+            "+-----------+-------------+----------+",
+            "| 'my_task' | 'FAILED'    | false    |",
+            "| 'my_task' | 'SUCCEEDED' | true     |",
+            "+-----------+-------------+----------+",
+        ],
+    )
+    .await?;
+
+    fixture.execute_command("DROP TASK my_task").await?;
+    fixture
+        .execute_command("DROP TASK IF EXISTS my_task")
+        .await?;
+    assert!(fixture.execute_command("DROP TASK my_task").await.is_err());
+
+    Ok(())
+}
@@ -164,6 +164,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=1024)),
                 }),
+                ("max_concurrent_async_tasks", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of async processor tasks running at the same time in a query, such as reading data or calling UDF servers. 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_io_min_bytes_for_seek", DefaultSettingValue {
                     value: UserSettingValue::UInt64(48),
                     desc: "Sets the minimum byte size of data that must be read from storage in a single I/O operation \
@@ -193,6 +199,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("response_compression_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4096),
+                    desc: "Sets the minimum size in bytes of the http query responses to compress with the `Accept-Encoding` of the client.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
@@ -243,9 +255,9 @@ impl DefaultSettings {
                 }),
                 ("sql_dialect", DefaultSettingValue {
                     value: UserSettingValue::String("PostgreSQL".to_owned()),
-                    desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\",  \"Experimental\", and \"Hive\".",
+                    desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\",  \"Experimental\", \"Hive\", \"Snowflake\", and \"Trino\".",
                     mode: SettingMode::Both,
-                    range: Some(SettingRange::String(vec!["PostgreSQL", "MySQL", "Experimental", "Hive"])),
+                    range: Some(SettingRange::String(vec!["PostgreSQL", "MySQL", "Experimental", "Hive", "Snowflake", "Trino"])),
                 }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
@@ -277,6 +289,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_shared_table_scan", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables scanning a table once for the branches of a query reading it with the same projection and filters.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -307,6 +325,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("max_query_retry_on_failure", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of times a distributed query is retried when a cluster node cannot be connected while dispatching the query fragments. A node failing after the fragments are dispatched fails the query without a retry.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=10)),
+                }),
+                ("min_bytes_per_thread", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the minimum bytes of the table data to be read by a thread, the parallelism of a local query is reduced if it reads less data after partition pruning. Setting it to 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("s3_multipart_threshold_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the size threshold in bytes of a segment to be written to the object storage with multipart upload.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("s3_part_size_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(8 * 1024 * 1024),
+                    desc: "Sets the size in bytes of each part in a multipart upload, S3 requires it to be between 5MB and 5GB.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(5 * 1024 * 1024..=5 * 1024 * 1024 * 1024)),
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -325,6 +367,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("show_create_table_if_not_exists", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Renders CREATE TABLE IF NOT EXISTS in the result of SHOW CREATE TABLE.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("show_create_table_qualified", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Renders the table name qualified with its database in the result of SHOW CREATE TABLE.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("sandbox_tenant", DefaultSettingValue {
                     value: UserSettingValue::String("".to_string()),
                     desc: "Injects a custom 'sandbox_tenant' into this session. This is only for testing purposes and will take effect only when 'internal_enable_sandbox_tenant' is turned on.",
@@ -416,6 +470,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("group_by_drop_null_keys", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Drops the rows whose GROUP BY keys contain NULL instead of grouping them, the grouping sets are not affected.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("aggregate_max_block_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum rows of the blocks output by the final aggregation of a bucket, 0 outputs a single block for each bucket.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
@@ -467,6 +533,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("copy_max_upload_concurrency", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4),
+                    desc: "Sets the maximum number of files uploaded to a stage at the same time.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=1024)),
+                }),
                 ("enable_experimental_merge_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable experimental merge into.",
@@ -612,6 +684,60 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("parquet_case_sensitive", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Keeps the case of the column names of parquet files, instead of lowering them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("parquet_schema_merge", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Merges the schemas of the parquet files read together, filling the columns missing in a file with NULLs.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_parquet_statistics", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables reading the footers of all the parquet files read while planning, to provide their statistics to the optimizer.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_parquet_bloom_filter", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables pruning the row groups of parquet files by the bloom filters of the columns compared to constants.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("parquet_max_read_retries", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the max retries of the reads of parquet files failed with transient errors, 0 means never retry.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
+                ("parquet_read_retry_initial_delay_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the backoff in milliseconds of the first retry of a parquet read, it doubles for each following retry.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("parquet_snapshot_consistency", DefaultSettingValue {
+                    value: UserSettingValue::String("ignore".to_string()),
+                    desc: "Sets how the parquet files changed since the query was planned are handled, detected by their etags: ignore, error or skip.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["ignore", "error", "skip"])),
+                }),
+                ("parquet_int96_as_timestamp", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Reads the INT96 timestamps of parquet files as the wall clock times of the session timezone, like Hive and Impala write them, instead of UTC times.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("parquet_hive_partitioning", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Reads the Hive-style directories of parquet files, such as `year=2024/`, as partition columns, and prunes the files by the filters on them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_aggregate_hashtable", DefaultSettingValue {
                         value: UserSettingValue::UInt64(0),
                         desc: "Enables experimental aggregate hashtable",
@@ -678,13 +804,25 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("statistics_auto_sample_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Samples the columns in ANALYZE TABLE ... UPDATE STATISTICS if the table is larger than this number of bytes. Setting it to 0 means always reading all the data.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 // this setting will be removed when geometry type stable.
                 ("enable_geo_create_table", DefaultSettingValue{
                     value: UserSettingValue::UInt64(0),
                     desc: "Create and alter table with geometry type",
                     mode:SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1))
-                })
+                }),
+                ("transaction_isolation", DefaultSettingValue {
+                    value: UserSettingValue::String("READ COMMITTED".to_owned()),
+                    desc: "Sets the isolation level of the transactions started by BEGIN, which only scope the reads of the statements. Available values include \"READ COMMITTED\" and \"SNAPSHOT ISOLATION\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["READ COMMITTED", "SNAPSHOT ISOLATION"])),
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
@@ -30,6 +30,14 @@ pub enum FlightCompression {
     Zstd,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionIsolation {
+    /// Each statement sees the data committed before the statement starts.
+    ReadCommitted,
+    /// Each statement of a transaction sees the data committed before the transaction starts.
+    SnapshotIsolation,
+}
+
 impl Settings {
     // Get u64 value, we don't get from the metasrv.
     fn try_get_u64(&self, key: &str) -> Result<u64> {
@@ -277,6 +285,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_enable_shared_table_scan(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_shared_table_scan")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -285,11 +297,29 @@ impl Settings {
         Ok(self.try_get_u64("enforce_broadcast_join")? != 0)
     }
 
+    pub fn get_max_query_retry_on_failure(&self) -> Result<u64> {
+        self.try_get_u64("max_query_retry_on_failure")
+    }
+
+    pub fn get_min_bytes_per_thread(&self) -> Result<u64> {
+        self.try_get_u64("min_bytes_per_thread")
+    }
+
+    pub fn get_s3_multipart_threshold_bytes(&self) -> Result<u64> {
+        self.try_get_u64("s3_multipart_threshold_bytes")
+    }
+
+    pub fn get_s3_part_size_bytes(&self) -> Result<u64> {
+        self.try_get_u64("s3_part_size_bytes")
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
             "hive" => Ok(Dialect::Hive),
             "mysql" => Ok(Dialect::MySQL),
             "experimental" => Ok(Dialect::Experimental),
+            "snowflake" => Ok(Dialect::Snowflake),
+            "trino" => Ok(Dialect::Trino),
             _ => Ok(Dialect::PostgreSQL),
         }
     }
@@ -321,6 +351,14 @@ impl Settings {
         Ok(self.try_get_u64("hide_options_in_show_create_table")? != 0)
     }
 
+    pub fn get_show_create_table_if_not_exists(&self) -> Result<bool> {
+        Ok(self.try_get_u64("show_create_table_if_not_exists")? != 0)
+    }
+
+    pub fn get_show_create_table_qualified(&self) -> Result<bool> {
+        Ok(self.try_get_u64("show_create_table_qualified")? != 0)
+    }
+
     pub fn get_enable_query_result_cache(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
@@ -333,6 +371,10 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_response_compression_threshold(&self) -> Result<u64> {
+        self.try_get_u64("response_compression_threshold")
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -365,6 +407,14 @@ impl Settings {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
 
+    pub fn get_group_by_drop_null_keys(&self) -> Result<bool> {
+        Ok(self.try_get_u64("group_by_drop_null_keys")? != 0)
+    }
+
+    pub fn get_aggregate_max_block_rows(&self) -> Result<u64> {
+        self.try_get_u64("aggregate_max_block_rows")
+    }
+
     pub fn get_enable_experimental_aggregate_hashtable(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_aggregate_hashtable")? == 1)
     }
@@ -426,6 +476,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_copy_into")? != 0)
     }
 
+    pub fn get_copy_max_upload_concurrency(&self) -> Result<u64> {
+        self.try_get_u64("copy_max_upload_concurrency")
+    }
+
     pub fn get_enable_experimental_merge_into(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_merge_into")? != 0)
     }
@@ -535,6 +589,42 @@ impl Settings {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
 
+    pub fn get_parquet_case_sensitive(&self) -> Result<bool> {
+        Ok(self.try_get_u64("parquet_case_sensitive")? != 0)
+    }
+
+    pub fn get_parquet_schema_merge(&self) -> Result<bool> {
+        Ok(self.try_get_u64("parquet_schema_merge")? != 0)
+    }
+
+    pub fn get_enable_parquet_statistics(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_parquet_statistics")? != 0)
+    }
+
+    pub fn get_enable_parquet_bloom_filter(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_parquet_bloom_filter")? != 0)
+    }
+
+    pub fn get_parquet_max_read_retries(&self) -> Result<u64> {
+        self.try_get_u64("parquet_max_read_retries")
+    }
+
+    pub fn get_parquet_read_retry_initial_delay_ms(&self) -> Result<u64> {
+        self.try_get_u64("parquet_read_retry_initial_delay_ms")
+    }
+
+    pub fn get_parquet_snapshot_consistency(&self) -> Result<String> {
+        self.try_get_string("parquet_snapshot_consistency")
+    }
+
+    pub fn get_parquet_int96_as_timestamp(&self) -> Result<bool> {
+        Ok(self.try_get_u64("parquet_int96_as_timestamp")? != 0)
+    }
+
+    pub fn get_parquet_hive_partitioning(&self) -> Result<bool> {
+        Ok(self.try_get_u64("parquet_hive_partitioning")? != 0)
+    }
+
     pub fn get_numeric_cast_option(&self) -> Result<String> {
         self.try_get_string("numeric_cast_option")
     }
@@ -603,6 +693,10 @@ impl Settings {
         self.try_get_u64("cost_factor_network_per_row")
     }
 
+    pub fn get_statistics_auto_sample_threshold(&self) -> Result<u64> {
+        self.try_get_u64("statistics_auto_sample_threshold")
+    }
+
     pub fn get_enable_geo_create_table(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_geo_create_table")? != 0)
     }
@@ -610,4 +704,24 @@ impl Settings {
     pub fn set_enable_geo_create_table(&self, val: bool) -> Result<()> {
         self.try_set_u64("enable_geo_create_table", u64::from(val))
     }
+
+    pub fn get_max_concurrent_async_tasks(&self) -> Result<u64> {
+        self.try_get_u64("max_concurrent_async_tasks")
+    }
+
+    pub fn set_max_concurrent_async_tasks(&self, val: u64) -> Result<()> {
+        self.try_set_u64("max_concurrent_async_tasks", val)
+    }
+
+    pub fn get_transaction_isolation(&self) -> Result<TransactionIsolation> {
+        match self
+            .try_get_string("transaction_isolation")?
+            .to_uppercase()
+            .as_str()
+        {
+            "READ COMMITTED" => Ok(TransactionIsolation::ReadCommitted),
+            "SNAPSHOT ISOLATION" => Ok(TransactionIsolation::SnapshotIsolation),
+            _ => unreachable!("check possible_values in set variable"),
+        }
+    }
 }
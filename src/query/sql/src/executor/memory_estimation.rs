@@ -0,0 +1,234 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_base::base::convert_byte_size;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::DataSchema;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::executor::physical_plans::AggregateFunctionDesc;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+/// The size assumed for a value of a variable-length type, like `String` or `Variant`.
+const VARIABLE_VALUE_SIZE: usize = 32;
+/// The extra size of each entry of a hash table: the hash and the pointer to the row.
+const HASH_ENTRY_SIZE: usize = 16;
+
+/// The estimated memory usage of an operator of the physical plan.
+#[derive(Debug, Clone)]
+pub struct OperatorMemory {
+    pub plan_id: u32,
+    pub name: String,
+    pub estimated_rows: f64,
+    pub estimated_bytes: f64,
+    /// The depth of the operator in the plan tree, only used for display.
+    pub depth: usize,
+}
+
+/// The estimated memory usage of a physical plan.
+///
+/// The blocking operators hold their whole working set: the build side of a hash join,
+/// the input of a sort or a window and the groups of an aggregation. The other operators
+/// are streaming and hold a block at a time. All the operators of a query may run at the
+/// same time, so the peak is estimated as the sum of the operators.
+#[derive(Debug, Clone)]
+pub struct MemoryEstimation {
+    pub operators: Vec<OperatorMemory>,
+    pub peak_bytes: f64,
+}
+
+impl MemoryEstimation {
+    pub fn estimate(plan: &PhysicalPlan, max_block_size: usize) -> Result<Self> {
+        let mut operators = vec![];
+        estimate_operator(plan, max_block_size as f64, 0, &mut operators)?;
+        let peak_bytes = operators.iter().map(|op| op.estimated_bytes).sum();
+        Ok(MemoryEstimation {
+            operators,
+            peak_bytes,
+        })
+    }
+}
+
+impl Display for MemoryEstimation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for op in self.operators.iter() {
+            writeln!(
+                f,
+                "{}{} #{}: estimated rows: {:.2}, estimated memory: {}",
+                "    ".repeat(op.depth),
+                op.name,
+                op.plan_id,
+                op.estimated_rows,
+                convert_byte_size(op.estimated_bytes)
+            )?;
+        }
+        write!(
+            f,
+            "estimated peak memory: {}",
+            convert_byte_size(self.peak_bytes)
+        )
+    }
+}
+
+fn estimate_operator(
+    plan: &PhysicalPlan,
+    block_rows: f64,
+    depth: usize,
+    operators: &mut Vec<OperatorMemory>,
+) -> Result<()> {
+    let rows = estimated_rows(plan);
+    let streaming =
+        || -> Result<f64> { Ok(rows.min(block_rows) * row_width(&plan.output_schema()?) as f64) };
+    let estimated_bytes = match plan {
+        PhysicalPlan::HashJoin(join) => {
+            let build_rows = estimated_rows(&join.build);
+            let width = row_width(&join.build.output_schema()?) + HASH_ENTRY_SIZE;
+            build_rows * width as f64
+        }
+        PhysicalPlan::RangeJoin(join) => {
+            estimated_rows(&join.left) * row_width(&join.left.output_schema()?) as f64
+                + estimated_rows(&join.right) * row_width(&join.right.output_schema()?) as f64
+        }
+        PhysicalPlan::Sort(sort) => {
+            let mut input_rows = estimated_rows(&sort.input);
+            if let Some(limit) = sort.limit {
+                input_rows = input_rows.min(limit as f64);
+            }
+            input_rows * row_width(&sort.input.output_schema()?) as f64
+        }
+        PhysicalPlan::Window(window) => {
+            estimated_rows(&window.input) * row_width(&window.input.output_schema()?) as f64
+        }
+        PhysicalPlan::AggregatePartial(agg) => {
+            let input_schema = agg.input.output_schema()?;
+            aggregate_bytes(rows, &agg.group_by, &input_schema, &agg.agg_funcs)?
+        }
+        PhysicalPlan::AggregateFinal(agg) => aggregate_bytes(
+            rows,
+            &agg.group_by,
+            &agg.before_group_by_schema,
+            &agg.agg_funcs,
+        )?,
+        PhysicalPlan::MaterializedCte(cte) => {
+            estimated_rows(&cte.left) * row_width(&cte.left.output_schema()?) as f64
+        }
+        _ => streaming()?,
+    };
+
+    operators.push(OperatorMemory {
+        plan_id: plan.get_id(),
+        name: plan.name(),
+        estimated_rows: rows,
+        estimated_bytes,
+        depth,
+    });
+    for child in plan.children() {
+        estimate_operator(child, block_rows, depth + 1, operators)?;
+    }
+    Ok(())
+}
+
+/// The size of the hash table of an aggregation: the keys and the states of each group.
+fn aggregate_bytes(
+    groups: f64,
+    group_by: &[IndexType],
+    input_schema: &DataSchema,
+    agg_funcs: &[AggregateFunctionDesc],
+) -> Result<f64> {
+    let mut group_width = 0;
+    for index in group_by {
+        let field = input_schema.field_with_name(&index.to_string())?;
+        group_width += value_size(field.data_type());
+    }
+    if !group_by.is_empty() {
+        group_width += HASH_ENTRY_SIZE;
+    }
+    for agg in agg_funcs {
+        let func = AggregateFunctionFactory::instance().get(
+            &agg.sig.name,
+            agg.sig.params.clone(),
+            agg.sig.args.clone(),
+        )?;
+        group_width += func.state_layout().size();
+    }
+    let groups = if group_by.is_empty() {
+        1.0
+    } else {
+        groups.max(1.0)
+    };
+    Ok(groups * group_width as f64)
+}
+
+fn estimated_rows(plan: &PhysicalPlan) -> f64 {
+    let stat_info = match plan {
+        PhysicalPlan::TableScan(plan) => &plan.stat_info,
+        PhysicalPlan::Filter(plan) => &plan.stat_info,
+        PhysicalPlan::Project(plan) => &plan.stat_info,
+        PhysicalPlan::EvalScalar(plan) => &plan.stat_info,
+        PhysicalPlan::ProjectSet(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateExpand(plan) => &plan.stat_info,
+        PhysicalPlan::AggregatePartial(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateFinal(plan) => &plan.stat_info,
+        PhysicalPlan::Sort(plan) => &plan.stat_info,
+        PhysicalPlan::Limit(plan) => &plan.stat_info,
+        PhysicalPlan::RowFetch(plan) => &plan.stat_info,
+        PhysicalPlan::HashJoin(plan) => &plan.stat_info,
+        PhysicalPlan::RangeJoin(plan) => &plan.stat_info,
+        PhysicalPlan::UnionAll(plan) => &plan.stat_info,
+        PhysicalPlan::Udf(plan) => &plan.stat_info,
+        _ => &None,
+    };
+    match stat_info {
+        Some(info) => info.estimated_rows,
+        // The operators without statistics output as many rows as their inputs.
+        None => plan.children().map(estimated_rows).sum(),
+    }
+}
+
+fn row_width(schema: &DataSchema) -> usize {
+    schema
+        .fields()
+        .iter()
+        .map(|field| value_size(field.data_type()))
+        .sum()
+}
+
+/// The estimated size of a value of the type in a column.
+fn value_size(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Null | DataType::EmptyArray | DataType::EmptyMap => 0,
+        DataType::Boolean => 1,
+        DataType::Number(num) => num.bit_width() as usize / 8,
+        DataType::Decimal(DecimalDataType::Decimal128(_)) => 16,
+        DataType::Decimal(DecimalDataType::Decimal256(_)) => 32,
+        DataType::Timestamp => 8,
+        DataType::Date => 4,
+        DataType::Nullable(inner) => value_size(inner) + 1,
+        DataType::Tuple(fields) => fields.iter().map(value_size).sum(),
+        // The offsets and a few values of the inner type.
+        DataType::Array(inner) | DataType::Map(inner) => 8 + value_size(inner) * 4,
+        DataType::Binary
+        | DataType::String
+        | DataType::Bitmap
+        | DataType::Variant
+        | DataType::Geometry => 8 + VARIABLE_VALUE_SIZE,
+        DataType::Generic(_) => 8,
+    }
+}
@@ -14,6 +14,7 @@
 
 mod explain;
 mod format;
+mod memory_estimation;
 mod physical_plan;
 mod physical_plan_builder;
 mod physical_plan_display;
@@ -23,6 +24,8 @@ mod util;
 
 pub mod table_read_plan;
 
+pub use memory_estimation::MemoryEstimation;
+pub use memory_estimation::OperatorMemory;
 pub use physical_plan::PhysicalPlan;
 pub use physical_plan_builder::PhysicalPlanBuilder;
 pub use physical_plan_visitor::PhysicalPlanReplacer;
@@ -349,6 +349,30 @@ impl PhysicalPlan {
         }
     }
 
+    /// Get the number of rows the plan node is estimated to output, if the optimizer estimated it
+    pub fn get_estimated_rows(&self) -> Option<f64> {
+        let stat_info = match self {
+            PhysicalPlan::TableScan(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Filter(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Project(v) => v.stat_info.as_ref(),
+            PhysicalPlan::EvalScalar(v) => v.stat_info.as_ref(),
+            PhysicalPlan::ProjectSet(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AggregateExpand(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AggregatePartial(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AggregateFinal(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Sort(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Limit(v) => v.stat_info.as_ref(),
+            PhysicalPlan::RowFetch(v) => v.stat_info.as_ref(),
+            PhysicalPlan::HashJoin(v) => v.stat_info.as_ref(),
+            PhysicalPlan::RangeJoin(v) => v.stat_info.as_ref(),
+            PhysicalPlan::UnionAll(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Udf(v) => v.stat_info.as_ref(),
+            PhysicalPlan::CteScan(v) => Some(&v.stat),
+            _ => None,
+        };
+        stat_info.map(|stat_info| stat_info.estimated_rows)
+    }
+
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         match self {
             PhysicalPlan::TableScan(plan) => plan.output_schema(),
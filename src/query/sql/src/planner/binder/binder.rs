@@ -21,11 +21,13 @@ use databend_common_ast::ast::format_statement;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::OptimizerHint;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Dialect;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -43,6 +45,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::plans::AlterFileFormatPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
@@ -179,6 +182,88 @@ impl<'a> Binder {
         self.ctx.get_settings().set_batch_settings(&hint_settings)
     }
 
+    /// Apply the optimizer hints to the settings which drive the optimizer,
+    /// the hints which can't take effect are ignored with a warning.
+    pub(crate) fn opt_hints_optimizer(&mut self, hints: &Hint) -> Result<()> {
+        let mut hint_settings: HashMap<String, String> = HashMap::new();
+        for hint in &hints.optimizer_hints {
+            let ignored_reason = match hint {
+                // Hash join is the only join algorithm, nothing to override.
+                OptimizerHint::HashJoin(tables) => self.resolve_hint_tables(tables).err(),
+                OptimizerHint::SortMergeJoin(_) => {
+                    Some("sort merge join is not supported".to_string())
+                }
+                OptimizerHint::Broadcast(tables) => match self.resolve_hint_tables(tables) {
+                    Ok(_) => {
+                        hint_settings.insert("enforce_broadcast_join".to_string(), "1".to_string());
+                        None
+                    }
+                    Err(reason) => Some(reason),
+                },
+                OptimizerHint::NoIndex { table, columns } => {
+                    match self.resolve_hint_tables(std::slice::from_ref(table)) {
+                        Ok(_) if columns.is_empty() => {
+                            hint_settings.insert(
+                                "enable_aggregating_index_scan".to_string(),
+                                "0".to_string(),
+                            );
+                            None
+                        }
+                        Ok(_) => {
+                            Some("disabling the index of columns is not supported".to_string())
+                        }
+                        Err(reason) => Some(reason),
+                    }
+                }
+                // The cluster key is always used to prune the blocks if exists.
+                OptimizerHint::UseClusteringKey(table) => {
+                    match self.resolve_hint_tables(std::slice::from_ref(table)) {
+                        Ok(tables) if tables[0].cluster_keys(self.ctx.clone()).is_empty() => {
+                            Some(format!("table {table} has no cluster key"))
+                        }
+                        Ok(_) => None,
+                        Err(reason) => Some(reason),
+                    }
+                }
+                OptimizerHint::Parallel(0) => Some("parallel must be greater than 0".to_string()),
+                OptimizerHint::Parallel(num) => {
+                    hint_settings.insert("max_threads".to_string(), num.to_string());
+                    None
+                }
+            };
+            if let Some(reason) = ignored_reason {
+                let warning = format!("hint {hint} is ignored: {reason}");
+                warn!("{}", warning);
+                self.ctx.push_warning(warning);
+            }
+        }
+
+        self.ctx.get_settings().set_batch_settings(&hint_settings)
+    }
+
+    /// Find the tables referred by a hint in the bound tables, by alias or name.
+    fn resolve_hint_tables(
+        &self,
+        tables: &[Identifier],
+    ) -> std::result::Result<Vec<Arc<dyn Table>>, String> {
+        let metadata = self.metadata.read();
+        tables
+            .iter()
+            .map(|table| {
+                let name = normalize_identifier(table, &self.name_resolution_ctx).name;
+                metadata
+                    .tables()
+                    .iter()
+                    .find(|entry| match entry.alias_name() {
+                        Some(alias) => alias == &name,
+                        None => entry.name() == name,
+                    })
+                    .map(|entry| entry.table())
+                    .ok_or_else(|| format!("table {table} is not found in the query"))
+            })
+            .collect()
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub(crate) async fn bind_statement(
@@ -230,6 +315,11 @@ impl<'a> Binder {
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
 
+            Statement::RecommendIndex { query } => {
+                let plan = self.bind_statement(bind_context, query).await?;
+                Plan::RecommendIndex { plan: Box::new(plan) }
+            }
+
             Statement::ShowFunctions { show_options } => {
                 self.bind_show_functions(bind_context, show_options).await?
             }
@@ -251,6 +341,14 @@ impl<'a> Binder {
                 self.bind_copy_into_table(bind_context, stmt).await?
             }
 
+            Statement::Import(stmt) => self.bind_import(bind_context, stmt).await?,
+
+            Statement::ApplyMigration { location } => self.bind_apply_migration(bind_context, location).await?,
+            Statement::ShowMigrations => self.bind_show_migrations(bind_context).await?,
+            Statement::RollbackMigration { name } => self.bind_rollback_migration(bind_context, name).await?,
+            Statement::ShowQueryCache => self.bind_show_query_cache(bind_context).await?,
+            Statement::PurgeQueryCache { target } => self.bind_purge_query_cache(bind_context, target)?,
+
             Statement::CopyIntoLocation(stmt) => {
                 if let Some(hints) = &stmt.hints {
                     if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
@@ -261,6 +359,9 @@ impl<'a> Binder {
             }
 
             Statement::ShowMetrics { show_options } => self.bind_show_metrics(bind_context, show_options).await?,
+            Statement::ShowIoStats { query_id } => self.bind_show_io_stats(bind_context, query_id).await?,
+            Statement::ShowIncompatibilities { query } => self.bind_show_incompatibilities(bind_context, query).await?,
+            Statement::ShowStatus { global, show_options } => self.bind_show_status(bind_context, *global, show_options).await?,
             Statement::ShowProcessList { show_options } => self.bind_show_process_list(bind_context, show_options).await?,
             Statement::ShowEngines { show_options } => self.bind_show_engines(bind_context, show_options).await?,
             Statement::ShowSettings { show_options } => self.bind_show_settings(bind_context, show_options).await?,
@@ -279,6 +380,10 @@ impl<'a> Binder {
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
+            Statement::BackupDatabase(stmt) => self.bind_backup_database(stmt).await?,
+            Statement::RestoreDatabase(stmt) => self.bind_restore_database(stmt).await?,
+            Statement::ShowBackups(stmt) => self.bind_show_backups(stmt).await?,
+            Statement::RestoreMetasrv(stmt) => self.bind_restore_metasrv(stmt).await?,
             Statement::UseDatabase { database } => {
                 let database = normalize_identifier(database, &self.name_resolution_ctx).name;
                 Plan::UseDatabase(Box::new(UseDatabasePlan {
@@ -290,6 +395,7 @@ impl<'a> Binder {
             // Tables
             Statement::ShowTables(stmt) => self.bind_show_tables(bind_context, stmt).await?,
             Statement::ShowCreateTable(stmt) => self.bind_show_create_table(stmt).await?,
+            Statement::ShowSegments(stmt) => self.bind_show_segments(bind_context, stmt).await?,
             Statement::DescribeTable(stmt) => self.bind_describe_table(stmt).await?,
             Statement::ShowTablesStatus(stmt) => {
                 self.bind_show_tables_status(bind_context, stmt).await?
@@ -299,6 +405,8 @@ impl<'a> Binder {
             }
             Statement::AttachTable(stmt) => self.bind_attach_table(stmt).await?,
             Statement::CreateTable(stmt) => self.bind_create_table(stmt).await?,
+            Statement::CreateRollupTable(stmt) => self.bind_create_rollup_table(stmt).await?,
+            Statement::CloneTable(stmt) => self.bind_clone_table(stmt).await?,
             Statement::DropTable(stmt) => self.bind_drop_table(stmt).await?,
             Statement::UndropTable(stmt) => self.bind_undrop_table(stmt).await?,
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
@@ -314,6 +422,7 @@ impl<'a> Binder {
             // Views
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
+            Statement::AlterViewOwner(stmt) => self.bind_alter_view_owner(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
             // Indexes
@@ -356,16 +465,27 @@ impl<'a> Binder {
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
-            Statement::ListStage { location, pattern } => {
+            Statement::ListStage { location, pattern, staged_since, limit } => {
                 let pattern = if let Some(pattern) = pattern {
                     format!(", pattern => '{pattern}'")
                 } else {
                     "".to_string()
                 };
-                self.bind_rewrite_to_query(bind_context, format!("SELECT * FROM LIST_STAGE(location => '@{location}'{pattern})").as_str(), RewriteKind::ListStage).await?
+                let staged_since = if let Some(staged_since) = staged_since {
+                    format!(", staged_since => {}", staged_since.as_secs())
+                } else {
+                    "".to_string()
+                };
+                let limit = if let Some(limit) = limit {
+                    format!(" LIMIT {limit}")
+                } else {
+                    "".to_string()
+                };
+                self.bind_rewrite_to_query(bind_context, format!("SELECT name, size AS size_bytes, last_modified, content_md5, etag, storage_class FROM LIST_STAGE(location => '@{location}'{pattern}{staged_since}){limit}").as_str(), RewriteKind::ListStage).await?
             }
             Statement::DescribeStage { stage_name } => self.bind_rewrite_to_query(bind_context, format!("SELECT * FROM system.stages WHERE name = '{stage_name}'").as_str(), RewriteKind::DescribeStage).await?,
             Statement::CreateStage(stmt) => self.bind_create_stage(stmt).await?,
+            Statement::AlterStage(stmt) => self.bind_alter_stage(stmt).await?,
             Statement::DropStage {
                 stage_name,
                 if_exists,
@@ -438,6 +558,15 @@ impl<'a> Binder {
                     file_format_params: file_format_options.clone().try_into()?,
                 }))
             }
+            Statement::AlterFileFormat {
+                if_exists,
+                name,
+                file_format_options,
+            } => Plan::AlterFileFormat(Box::new(AlterFileFormatPlan {
+                if_exists: *if_exists,
+                name: name.clone(),
+                file_format_params: file_format_options.clone().try_into()?,
+            })),
             Statement::DropFileFormat {
                 if_exists,
                 name,
@@ -500,6 +629,13 @@ impl<'a> Binder {
                 self.bind_kill_stmt(bind_context, kill_target, object_id.as_str())
                     .await?
             }
+            Statement::ResetMemoryPeak => Plan::ResetMemoryPeak,
+
+            Statement::SetTransactionIsolation { level } => {
+                self.bind_set_transaction_isolation(level)?
+            }
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
 
             // share statements
             Statement::CreateShareEndpoint(stmt) => {
@@ -562,6 +698,18 @@ impl<'a> Binder {
             Statement::ShowNetworkPolicies => {
                 self.bind_show_network_policies().await?
             }
+            Statement::CreateNotificationIntegration(stmt) => {
+                self.bind_create_notification_integration(stmt).await?
+            }
+            Statement::AlterNotificationIntegration(stmt) => {
+                self.bind_alter_notification_integration(stmt).await?
+            }
+            Statement::DropNotificationIntegration(stmt) => {
+                self.bind_drop_notification_integration(stmt).await?
+            }
+            Statement::ShowNotificationIntegrations => {
+                self.bind_show_notification_integrations().await?
+            }
             Statement::CreatePasswordPolicy(stmt) => {
                 self.bind_create_password_policy(stmt).await?
             }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -19,11 +20,15 @@ use std::sync::Arc;
 use databend_common_ast::ast::ColumnID as AstColumnID;
 use databend_common_ast::ast::CopyIntoTableSource;
 use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::CopySourceColumn;
+use databend_common_ast::ast::CreateTableSource;
+use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FileLocation;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::HintItem;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::ImportStmt;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
@@ -31,9 +36,11 @@ use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UriLocation;
 use databend_common_ast::parser::parser_values_with_placeholder;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Visitor;
+use databend_common_catalog::plan::StageMetadataColumn;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
@@ -47,12 +54,20 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::NullAs;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::infer_stage_schema;
 use databend_common_users::UserApiProvider;
 use indexmap::IndexMap;
 use log::debug;
@@ -62,8 +77,10 @@ use parking_lot::RwLock;
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
+use crate::plans::CreateTablePlan;
 use crate::plans::Plan;
 use crate::plans::ValidationMode;
 use crate::BindContext;
@@ -87,6 +104,11 @@ impl<'a> Binder {
                     .await
             }
             CopyIntoTableSource::Query(query) => {
+                if stmt.src_columns.is_some() {
+                    return Err(ErrorCode::SyntaxException(
+                        "the columns of the files can't be specified for a query source, select them in the query instead",
+                    ));
+                }
                 let mut max_column_position = MaxColumnPosition::new();
                 max_column_position.visit_query(query.as_ref());
                 self.metadata
@@ -108,6 +130,18 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyIntoTableStmt,
         location: &FileLocation,
+    ) -> Result<CopyIntoTablePlan> {
+        let (stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
+        self.bind_copy_into_table_from_stage(bind_context, stmt, stage_info, path)
+            .await
+    }
+
+    async fn bind_copy_into_table_from_stage(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CopyIntoTableStmt,
+        mut stage_info: StageInfo,
+        path: String,
     ) -> Result<CopyIntoTablePlan> {
         let (catalog_name, database_name, table_name) = self.normalize_object_identifier_triple(
             &stmt.dst.catalog,
@@ -116,15 +150,10 @@ impl<'a> Binder {
         );
         let catalog = self.ctx.get_catalog(&catalog_name).await?;
         let catalog_info = catalog.info();
-        let table = self
-            .ctx
-            .get_table(&catalog_name, &database_name, &table_name)
-            .await?;
 
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
 
-        let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
         self.apply_copy_into_table_options(stmt, &mut stage_info)
             .await?;
         let files_info = StageFilesInfo {
@@ -132,17 +161,62 @@ impl<'a> Binder {
             files: stmt.files.clone(),
             pattern: stmt.pattern.clone(),
         };
-        let required_values_schema: DataSchemaRef = Arc::new(
-            match &stmt.dst_columns {
-                Some(cols) => self.schema_project(&table.schema(), cols)?,
-                None => self.schema_project(&table.schema(), &[])?,
+
+        let (table_schema, create_table) = match self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await
+        {
+            Ok(table) => {
+                if stmt.infer_schema {
+                    // The existing table is kept if it could load the files.
+                    let inferred_schema = infer_stage_schema(&stage_info, &files_info).await?;
+                    check_inferred_schema(
+                        &table.schema(),
+                        &inferred_schema,
+                        stmt.dst_columns.as_deref(),
+                        &stage_info.file_format_params,
+                    )?;
+                }
+                (table.schema(), None)
             }
-            .into(),
-        );
+            Err(e) if stmt.infer_schema && e.code() == ErrorCode::UNKNOWN_TABLE => {
+                let inferred_schema = Arc::new(infer_stage_schema(&stage_info, &files_info).await?);
+                let create_table = self
+                    .bind_create_table_with_inferred_schema(stmt, inferred_schema.clone())
+                    .await?;
+                (inferred_schema, Some(Box::new(create_table)))
+            }
+            Err(e) => return Err(e),
+        };
+
+        let dst_schema = match &stmt.dst_columns {
+            Some(cols) => self.schema_project(&table_schema, cols)?,
+            None => self.schema_project(&table_schema, &[])?,
+        };
+        let (required_values_schema, stage_schema, metadata_columns) = match &stmt.src_columns {
+            Some(src_columns) => {
+                if stmt.infer_schema {
+                    return Err(ErrorCode::SyntaxException(
+                        "the columns of the files can't be specified with SCHEMA = INFER",
+                    ));
+                }
+                self.bind_copy_source_columns(&dst_schema, src_columns, &mut stage_info)?
+            }
+            None => {
+                let required_values_schema: DataSchemaRef = Arc::new(dst_schema.into());
+                let stage_schema = infer_table_schema(&required_values_schema)?;
+                (required_values_schema, stage_schema, vec![])
+            }
+        };
 
-        let stage_schema = infer_table_schema(&required_values_schema)?;
+        // The default values are of the columns loaded from the fields of the files.
+        let num_file_fields = required_values_schema.num_fields() - metadata_columns.len();
+        let file_values_schema = Arc::new(DataSchema::new(
+            required_values_schema.fields()[..num_file_fields].to_vec(),
+        ));
         let default_values = self
-            .prepare_default_values(bind_context, &required_values_schema)
+            .prepare_default_values(bind_context, &file_values_schema)
             .await?;
 
         Ok(CopyIntoTablePlan {
@@ -160,17 +234,113 @@ impl<'a> Binder {
                 files_to_copy: None,
                 is_select: false,
                 default_values: Some(default_values),
+                metadata_columns,
             },
             values_consts: vec![],
             required_source_schema: required_values_schema.clone(),
             required_values_schema: required_values_schema.clone(),
             write_mode: CopyIntoTableMode::Copy,
             query: None,
+            create_table,
 
             enable_distributed: false,
         })
     }
 
+    /// Bind the columns of the files of `COPY INTO <table> (<columns>) FROM <location> (<columns>)`.
+    ///
+    /// The fields of the files are loaded into the destination columns of the same positions,
+    /// and the metadata columns are appended after them by the readers of the files, so the
+    /// returned values schema puts the destination columns of the metadata columns last.
+    fn bind_copy_source_columns(
+        &self,
+        dst_schema: &TableSchema,
+        src_columns: &[CopySourceColumn],
+        stage_info: &mut StageInfo,
+    ) -> Result<(DataSchemaRef, TableSchemaRef, Vec<StageMetadataColumn>)> {
+        let file_format_params = &stage_info.file_format_params;
+        if !matches!(
+            file_format_params,
+            FileFormatParams::Csv(_)
+                | FileFormatParams::Tsv(_)
+                | FileFormatParams::NdJson(_)
+                | FileFormatParams::Xml(_)
+        ) {
+            return Err(ErrorCode::SemanticError(format!(
+                "the columns of the files can't be specified for the file format {:?}",
+                file_format_params.get_type()
+            )));
+        }
+        if src_columns.len() != dst_schema.num_fields() {
+            return Err(ErrorCode::SemanticError(format!(
+                "the number of the columns of the files is {}, but the table expects {}",
+                src_columns.len(),
+                dst_schema.num_fields()
+            )));
+        }
+
+        let mut values_fields = Vec::with_capacity(src_columns.len());
+        let mut metadata_fields = vec![];
+        let mut stage_fields = vec![];
+        let mut metadata_columns = vec![];
+        for (src_column, dst_field) in src_columns.iter().zip(dst_schema.fields()) {
+            match src_column {
+                CopySourceColumn::Field(ident) => {
+                    // The fields are looked up by the names in the files of NDJSON and XML.
+                    let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                    stage_fields.push(TableField::new(&name, dst_field.data_type().clone()));
+                    values_fields.push(dst_field.clone());
+                }
+                CopySourceColumn::Metadata(ident) => {
+                    metadata_columns.push(StageMetadataColumn::from_name(&ident.name)?);
+                    metadata_fields.push(dst_field.clone());
+                }
+            }
+        }
+        values_fields.extend(metadata_fields);
+
+        if metadata_columns.contains(&StageMetadataColumn::FileRowNumber) {
+            // The splits other than the first one of a file don't know their first row number.
+            stage_info.copy_options.split_size = 0;
+        }
+
+        let values_schema = TableSchemaRefExt::create(values_fields);
+        Ok((
+            Arc::new(values_schema.into()),
+            TableSchemaRefExt::create(stage_fields),
+            metadata_columns,
+        ))
+    }
+
+    /// Bind `CREATE TABLE IF NOT EXISTS` of the destination table of `COPY INTO ... SCHEMA = INFER`.
+    async fn bind_create_table_with_inferred_schema(
+        &mut self,
+        stmt: &CopyIntoTableStmt,
+        schema: TableSchemaRef,
+    ) -> Result<CreateTablePlan> {
+        let create_table_stmt = CreateTableStmt {
+            create_option: CreateOption::CreateIfNotExists(true),
+            catalog: stmt.dst.catalog.clone(),
+            database: stmt.dst.database.clone(),
+            table: stmt.dst.table.clone(),
+            source: Some(CreateTableSource::Columns(vec![])),
+            engine: None,
+            uri_location: None,
+            cluster_by: vec![],
+            table_options: BTreeMap::new(),
+            as_query: None,
+            transient: false,
+        };
+        let Plan::CreateTable(mut create_table) =
+            self.bind_create_table(&create_table_stmt).await?
+        else {
+            return Err(ErrorCode::Internal("Invalid create table plan"));
+        };
+        create_table.field_comments = vec![String::new(); schema.num_fields()];
+        create_table.schema = schema;
+        Ok(*create_table)
+    }
+
     /// Bind COPY INFO <table> FROM <stage_location>
     #[async_backtrace::framed]
     async fn bind_copy_into_table_from_location(
@@ -289,9 +459,11 @@ impl<'a> Binder {
                 files_to_copy: None,
                 is_select: false,
                 default_values: Some(default_values),
+                metadata_columns: vec![],
             },
             write_mode,
             query: None,
+            create_table: None,
             validation_mode: ValidationMode::None,
 
             enable_distributed: false,
@@ -301,6 +473,65 @@ impl<'a> Binder {
             .await
     }
 
+    /// Bind `IMPORT INTO <table> FROM '<path>'`, which loads the files in the filesystem of the
+    /// query node like `COPY INTO <table> FROM 'fs://<path>'` without the stage.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_import(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ImportStmt,
+    ) -> Result<Plan> {
+        let config = GlobalConfig::instance();
+        if !config.query.enable_local_import {
+            return Err(ErrorCode::PermissionDenied(
+                "IMPORT is not allowed, you can enable it by setting 'enable_local_import = true' in query node config",
+            ));
+        }
+        if config.query.local_import_root.is_empty() {
+            return Err(ErrorCode::PermissionDenied(
+                "IMPORT is not allowed, 'local_import_root' is required to be set to the directory of the files to import in query node config",
+            ));
+        }
+
+        let (dir, files) = list_import_files(&config.query.local_import_root, &stmt.path).await?;
+        let stage_info = StageInfo::new_external_stage(
+            StorageParams::Fs(StorageFsConfig { root: dir.clone() }),
+            true,
+        );
+        let copy_stmt = CopyIntoTableStmt {
+            src: CopyIntoTableSource::Location(FileLocation::Uri(UriLocation::new(
+                "fs".to_string(),
+                "".to_string(),
+                dir,
+                "".to_string(),
+                BTreeMap::new(),
+            ))),
+            dst: stmt.dst.clone(),
+            dst_columns: None,
+            src_columns: None,
+            hints: None,
+            file_format: stmt.file_format.clone(),
+            files: Some(files),
+            pattern: None,
+            // The files are loaded each time they are imported.
+            force: true,
+            validation_mode: "".to_string(),
+            size_limit: 0,
+            max_files: 0,
+            split_size: 0,
+            purge: false,
+            disable_variant_check: false,
+            return_failed_only: false,
+            on_error: "abort".to_string(),
+            infer_schema: false,
+        };
+        let plan = self
+            .bind_copy_into_table_from_stage(bind_context, &copy_stmt, stage_info, "/".to_string())
+            .await?;
+        self.bind_copy_into_table_from_location(bind_context, plan)
+            .await
+    }
+
     /// Bind COPY INTO <table> FROM <query>
     #[async_backtrace::framed]
     async fn bind_copy_from_query_into_table(
@@ -379,6 +610,7 @@ impl<'a> Binder {
                         lit: Literal::UInt64(1),
                     },
                 }],
+                optimizer_hints: vec![],
             };
             if let Some(e) = self
                 .opt_hints_set_var(&mut output_context, &hints)
@@ -530,6 +762,44 @@ fn check_transform_query(
     ))
 }
 
+/// Check if the existing table of `COPY INTO ... SCHEMA = INFER` could load the files.
+///
+/// Parquet files are loaded by column names, so all the inferred columns must be found in the
+/// table. Text files are loaded by column positions, so the number of columns must match.
+fn check_inferred_schema(
+    table_schema: &TableSchema,
+    inferred_schema: &TableSchema,
+    dst_columns: Option<&[Identifier]>,
+    file_format_params: &FileFormatParams,
+) -> Result<()> {
+    match file_format_params {
+        FileFormatParams::Parquet(_) => {
+            for field in inferred_schema.fields() {
+                if table_schema.field_with_name(field.name()).is_err() {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "the inferred column {} of the files is not found in the table",
+                        field.name()
+                    )));
+                }
+            }
+        }
+        _ => {
+            let num_columns = match dst_columns {
+                Some(cols) => cols.len(),
+                None => table_schema.num_fields(),
+            };
+            if num_columns != inferred_schema.num_fields() {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "the number of inferred columns of the files is {}, but the table expects {}",
+                    inferred_schema.num_fields(),
+                    num_columns
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Named stage(start with `@`):
 ///
 /// ```sql
@@ -577,6 +847,72 @@ pub async fn resolve_stage_location(
     Ok((stage, path.to_string()))
 }
 
+/// List the files of `IMPORT` in the filesystem of the query node, returns the directory and the
+/// names of the files in it.
+///
+/// Only the absolute paths without `..` are allowed, and only the file name may be a glob
+/// pattern of `*` and `?`. The directory is canonicalized and must be in `root`, so that the
+/// files out of it can't be reached through the symbolic links either.
+async fn list_import_files(root: &str, path: &str) -> Result<(String, Vec<String>)> {
+    let invalid_path = |reason: &str| {
+        ErrorCode::BadArguments(format!("invalid path '{path}' to import: {reason}"))
+    };
+    if !path.starts_with('/') {
+        return Err(invalid_path("the path must be absolute"));
+    }
+    if path.split('/').any(|component| component == "..") {
+        return Err(invalid_path("'..' is not allowed"));
+    }
+    let (dir, file_name) = path.rsplit_once('/').unwrap_or(("", path));
+    if dir.contains(['*', '?']) {
+        return Err(invalid_path("only the file name can be a glob pattern"));
+    }
+    let dir = format!("{dir}/");
+
+    let canonical_root = tokio::fs::canonicalize(root).await.map_err(|e| {
+        ErrorCode::BadArguments(format!("cannot read the import root '{root}': {e}"))
+    })?;
+    let canonical_dir = tokio::fs::canonicalize(&dir)
+        .await
+        .map_err(|e| ErrorCode::BadArguments(format!("cannot read directory '{dir}': {e}")))?;
+    if !canonical_dir.starts_with(&canonical_root) {
+        return Err(invalid_path("the path is out of the import root"));
+    }
+    let mut dir = canonical_dir.to_string_lossy().to_string();
+    if !dir.ends_with('/') {
+        dir.push('/');
+    }
+
+    let file_name = if file_name.is_empty() { "*" } else { file_name };
+    let mut pattern = "^".to_string();
+    for c in file_name.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    let pattern = regex::Regex::new(&pattern).map_err(|e| invalid_path(&e.to_string()))?;
+
+    let read_dir_error =
+        |e: std::io::Error| ErrorCode::BadArguments(format!("cannot read directory '{dir}': {e}"));
+    let mut entries = tokio::fs::read_dir(&dir).await.map_err(read_dir_error)?;
+    let mut files = vec![];
+    while let Some(entry) = entries.next_entry().await.map_err(read_dir_error)? {
+        // The symbolic links are not followed, which may point out of the directory.
+        if !entry.file_type().await.map_err(read_dir_error)?.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if pattern.is_match(&name) {
+            files.push(name);
+        }
+    }
+    files.sort();
+    Ok((dir, files))
+}
+
 #[async_backtrace::framed]
 pub async fn resolve_file_location(
     ctx: &dyn TableContext,
@@ -56,7 +56,7 @@ impl Binder {
                 // ALL PRIVILEGES have different available privileges set on different grant objects
                 // Now in this case all is always true.
                 let grant_object = self.convert_to_grant_object(level).await?;
-                let priv_types = grant_object.available_privileges(false);
+                let priv_types = grant_object[0].available_privileges(false);
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
                     on: grant_object,
@@ -131,13 +131,13 @@ impl Binder {
     pub(in crate::planner::binder) async fn convert_to_grant_object(
         &self,
         source: &AccountMgrLevel,
-    ) -> Result<GrantObject> {
+    ) -> Result<Vec<GrantObject>> {
         // TODO fetch real catalog
         let catalog_name = self.ctx.get_current_catalog();
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&catalog_name).await?;
         match source {
-            AccountMgrLevel::Global => Ok(GrantObject::Global),
+            AccountMgrLevel::Global => Ok(vec![GrantObject::Global]),
             AccountMgrLevel::Table(database_name, table_name) => {
                 let database_name = database_name
                     .clone()
@@ -152,7 +152,28 @@ impl Binder {
                     .get_table(&tenant, &database_name, table_name)
                     .await?
                     .get_id();
-                Ok(GrantObject::TableById(catalog_name, db_id, table_id))
+                Ok(vec![GrantObject::TableById(catalog_name, db_id, table_id)])
+            }
+            AccountMgrLevel::Column(database_name, table_name, columns) => {
+                let database_name = database_name
+                    .clone()
+                    .unwrap_or_else(|| self.ctx.get_current_database());
+                let db_id = catalog
+                    .get_database(&tenant, &database_name)
+                    .await?
+                    .get_db_info()
+                    .ident
+                    .db_id;
+                let table_id = catalog
+                    .get_table(&tenant, &database_name, table_name)
+                    .await?
+                    .get_id();
+                Ok(columns
+                    .iter()
+                    .map(|column| {
+                        GrantObject::Column(catalog_name.clone(), db_id, table_id, column.clone())
+                    })
+                    .collect())
             }
             AccountMgrLevel::Database(database_name) => {
                 let database_name = database_name
@@ -164,10 +185,10 @@ impl Binder {
                     .get_db_info()
                     .ident
                     .db_id;
-                Ok(GrantObject::DatabaseById(catalog_name, db_id))
+                Ok(vec![GrantObject::DatabaseById(catalog_name, db_id)])
             }
-            AccountMgrLevel::UDF(udf) => Ok(GrantObject::UDF(udf.clone())),
-            AccountMgrLevel::Stage(stage) => Ok(GrantObject::Stage(stage.clone())),
+            AccountMgrLevel::UDF(udf) => Ok(vec![GrantObject::UDF(udf.clone())]),
+            AccountMgrLevel::Stage(stage) => Ok(vec![GrantObject::Stage(stage.clone())]),
         }
     }
 
@@ -219,6 +240,8 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(vec![GrantObject::UDF(udf.clone())]),
             AccountMgrLevel::Stage(stage) => Ok(vec![GrantObject::Stage(stage.clone())]),
+            // Column privileges are always granted by table id.
+            AccountMgrLevel::Column(_, _, _) => self.convert_to_grant_object(source).await,
         }
     }
 
@@ -69,17 +69,20 @@ impl Binder {
 
         let mut select_builder = SelectBuilder::from("information_schema.columns");
 
+        // The columns are in the same order as the MySQL `SHOW [FULL] COLUMNS`.
         select_builder
             .with_column("column_name AS `Field`")
-            .with_column("column_type AS `Type`")
+            .with_column("column_type AS `Type`");
+        if *full {
+            select_builder.with_column("collation_name AS `Collation`");
+        }
+        select_builder
             .with_column("is_nullable AS `Null`")
-            .with_column("default AS `Default`")
-            .with_column("extra AS `Extra`")
-            .with_column("column_key AS `Key`");
-
+            .with_column("column_key AS `Key`")
+            .with_column("column_default AS `Default`")
+            .with_column("extra AS `Extra`");
         if *full {
             select_builder
-                .with_column("collation_name AS `Collation`")
                 .with_column("privileges AS `Privileges`")
                 .with_column("column_comment AS `Comment`");
         }
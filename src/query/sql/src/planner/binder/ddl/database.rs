@@ -14,17 +14,25 @@
 
 use std::collections::BTreeMap;
 
+use chrono::Utc;
+use chrono_tz::Tz;
 use databend_common_ast::ast::AlterDatabaseAction;
 use databend_common_ast::ast::AlterDatabaseStmt;
+use databend_common_ast::ast::BackupDatabaseStmt;
 use databend_common_ast::ast::CreateDatabaseStmt;
 use databend_common_ast::ast::DatabaseEngine;
 use databend_common_ast::ast::DropDatabaseStmt;
+use databend_common_ast::ast::RestoreDatabaseStmt;
+use databend_common_ast::ast::RestoreMetasrvStmt;
 use databend_common_ast::ast::SQLProperty;
+use databend_common_ast::ast::ShowBackupsStmt;
 use databend_common_ast::ast::ShowCreateDatabaseStmt;
 use databend_common_ast::ast::ShowDatabasesStmt;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::UndropDatabaseStmt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::timestamp::string_to_timestamp;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRefExt;
@@ -32,14 +40,19 @@ use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::share::ShareNameIdent;
 use log::debug;
 
+use crate::binder::parse_storage_params_from_uri;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::BackupDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RestoreDatabasePlan;
+use crate::plans::RestoreMetasrvPlan;
 use crate::plans::RewriteKind;
+use crate::plans::ShowBackupsPlan;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
 use crate::BindContext;
@@ -239,6 +252,121 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_backup_database(
+        &self,
+        stmt: &BackupDatabaseStmt,
+    ) -> Result<Plan> {
+        let BackupDatabaseStmt {
+            catalog,
+            database,
+            uri_location,
+            since,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+
+        let mut uri = uri_location.clone();
+        let storage_params = parse_storage_params_from_uri(
+            &mut uri,
+            Some(self.ctx.as_ref()),
+            "when BACKUP DATABASE",
+        )
+        .await?;
+
+        Ok(Plan::BackupDatabase(Box::new(BackupDatabasePlan {
+            catalog,
+            database,
+            storage_params,
+            since: since.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_database(
+        &self,
+        stmt: &RestoreDatabaseStmt,
+    ) -> Result<Plan> {
+        let RestoreDatabaseStmt {
+            catalog,
+            database,
+            uri_location,
+            new_database,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let new_database = normalize_identifier(new_database, &self.name_resolution_ctx).name;
+
+        let mut uri = uri_location.clone();
+        let storage_params = parse_storage_params_from_uri(
+            &mut uri,
+            Some(self.ctx.as_ref()),
+            "when RESTORE DATABASE",
+        )
+        .await?;
+
+        Ok(Plan::RestoreDatabase(Box::new(RestoreDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            storage_params,
+            new_database,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_backups(
+        &self,
+        stmt: &ShowBackupsStmt,
+    ) -> Result<Plan> {
+        let mut uri = stmt.uri_location.clone();
+        let storage_params =
+            parse_storage_params_from_uri(&mut uri, Some(self.ctx.as_ref()), "when SHOW BACKUPS")
+                .await?;
+
+        Ok(Plan::ShowBackups(Box::new(ShowBackupsPlan {
+            storage_params,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_metasrv(
+        &self,
+        stmt: &RestoreMetasrvStmt,
+    ) -> Result<Plan> {
+        let tz = self.ctx.get_settings().get_timezone()?;
+        let tz = tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::InvalidTimezone(format!("Invalid Timezone: {tz}")))?;
+        let timestamp = string_to_timestamp(&stmt.timestamp, tz)
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!("invalid timestamp: {}", stmt.timestamp))
+            })?
+            .with_timezone(&Utc);
+
+        let mut uri = stmt.uri_location.clone();
+        let storage_params = parse_storage_params_from_uri(
+            &mut uri,
+            Some(self.ctx.as_ref()),
+            "when RESTORE METASRV",
+        )
+        .await?;
+
+        Ok(Plan::RestoreMetasrv(Box::new(RestoreMetasrvPlan {
+            timestamp,
+            storage_params,
+        })))
+    }
+
     fn database_meta(
         &self,
         engine: &Option<DatabaseEngine>,
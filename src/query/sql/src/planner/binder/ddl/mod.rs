@@ -20,8 +20,10 @@ mod data_mask;
 mod database;
 mod index;
 mod network_policy;
+mod notification;
 mod password_policy;
 mod role;
+mod rollup;
 mod share;
 mod stage;
 mod stream;
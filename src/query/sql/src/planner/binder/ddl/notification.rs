@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use databend_common_ast::ast::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationType;
+
+use crate::binder::Binder;
+use crate::plans::AlterNotificationIntegrationPlan;
+use crate::plans::CreateNotificationIntegrationPlan;
+use crate::plans::DropNotificationIntegrationPlan;
+use crate::plans::Plan;
+use crate::plans::ShowNotificationIntegrationsPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_notification_integration(
+        &mut self,
+        stmt: &CreateNotificationIntegrationStmt,
+    ) -> Result<Plan> {
+        let CreateNotificationIntegrationStmt {
+            create_option,
+            name,
+            notification_type,
+            url,
+            events,
+            enabled,
+            comment,
+        } = stmt;
+
+        let notification_type = NotificationType::from_str(notification_type)
+            .map_err(|e| ErrorCode::SemanticError(e.message()))?;
+        check_notification_url(url)?;
+        let events = bind_notification_events(events)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateNotificationIntegrationPlan {
+            create_option: *create_option,
+            tenant,
+            name: name.to_string(),
+            notification_type,
+            url: url.clone(),
+            events,
+            enabled: enabled.unwrap_or(true),
+            comment: comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreateNotificationIntegration(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_notification_integration(
+        &mut self,
+        stmt: &AlterNotificationIntegrationStmt,
+    ) -> Result<Plan> {
+        let AlterNotificationIntegrationStmt {
+            if_exists,
+            name,
+            url,
+            events,
+            enabled,
+            comment,
+        } = stmt;
+
+        if let Some(url) = url {
+            check_notification_url(url)?;
+        }
+        let events = match events {
+            Some(events) => Some(bind_notification_events(events)?),
+            None => None,
+        };
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterNotificationIntegrationPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            url: url.clone(),
+            events,
+            enabled: *enabled,
+            comment: comment.clone(),
+        };
+        Ok(Plan::AlterNotificationIntegration(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_notification_integration(
+        &mut self,
+        stmt: &DropNotificationIntegrationStmt,
+    ) -> Result<Plan> {
+        let DropNotificationIntegrationStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropNotificationIntegrationPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropNotificationIntegration(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_notification_integrations(
+        &mut self,
+    ) -> Result<Plan> {
+        let plan = ShowNotificationIntegrationsPlan {};
+        Ok(Plan::ShowNotificationIntegrations(Box::new(plan)))
+    }
+}
+
+fn check_notification_url(url: &str) -> Result<()> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(ErrorCode::SemanticError(format!(
+            "invalid webhook url {}, only http and https are supported",
+            url
+        )));
+    }
+    Ok(())
+}
+
+/// Parses the events and removes the duplicated ones, keeping the order they are listed in.
+fn bind_notification_events(events: &[String]) -> Result<Vec<NotificationEvent>> {
+    let mut bound_events = Vec::with_capacity(events.len());
+    for event in events {
+        let event = NotificationEvent::from_str(event)
+            .map_err(|e| ErrorCode::SemanticError(e.message()))?;
+        if !bound_events.contains(&event) {
+            bound_events.push(event);
+        }
+    }
+    Ok(bound_events)
+}
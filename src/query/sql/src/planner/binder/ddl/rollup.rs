@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_ast::ast::CreateRollupTableStmt;
+use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::RollupColumn;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_QUERY;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::CreateRollupTablePlan;
+use crate::plans::Plan;
+
+/// Aggregate functions whose partial results can be merged by re-aggregating the rollup table,
+/// so a rollup row computed from newly inserted rows only never needs to be recomputed.
+const ROLLUP_AGGREGATE_FUNCTIONS: [&str; 4] = ["sum", "count", "min", "max"];
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_rollup_table(
+        &mut self,
+        stmt: &CreateRollupTableStmt,
+    ) -> Result<Plan> {
+        let CreateRollupTableStmt {
+            if_not_exists,
+            catalog,
+            database,
+            table,
+            source_database,
+            source_table,
+            aggregates,
+            group_by,
+        } = stmt;
+
+        let (catalog_name, _, _) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let source_database = source_database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let source_table = normalize_identifier(source_table, &self.name_resolution_ctx).name;
+
+        // The rollup is refreshed by filtering the `_segment_name` of the newly inserted segments.
+        let table_info = self
+            .ctx
+            .get_table(&catalog_name, &source_database, &source_table)
+            .await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Rollup table only support FUSE engine",
+            ));
+        }
+
+        for column in aggregates {
+            match &column.expr {
+                Expr::CountAll { window: None, .. } => {}
+                Expr::FunctionCall {
+                    distinct: false,
+                    name,
+                    window: None,
+                    ..
+                } if ROLLUP_AGGREGATE_FUNCTIONS.contains(&name.name.to_lowercase().as_str()) => {}
+                expr => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Rollup table only support aggregate functions {:?}, but got: {expr}",
+                        ROLLUP_AGGREGATE_FUNCTIONS
+                    )));
+                }
+            }
+        }
+
+        let rollup_query = format!(
+            "SELECT {} FROM `{source_database}`.`{source_table}` GROUP BY {}",
+            display_rollup_columns(group_by.iter().chain(aggregates.iter()), true),
+            display_rollup_columns(group_by.iter(), false),
+        );
+
+        let tokens = tokenize_sql(&rollup_query)?;
+        let (query, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(query) = query else {
+            return Err(ErrorCode::Internal(format!(
+                "rollup query is not a query: {rollup_query}"
+            )));
+        };
+
+        // The rollup table is created and back-filled from the existing data of the source table
+        // by `CREATE TABLE ... AS SELECT`.
+        let create_table_stmt = CreateTableStmt {
+            create_option: CreateOption::CreateIfNotExists(*if_not_exists),
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table.clone(),
+            source: None,
+            engine: None,
+            uri_location: None,
+            cluster_by: vec![],
+            table_options: BTreeMap::new(),
+            as_query: Some(query),
+            transient: false,
+        };
+        let Plan::CreateTable(mut create_table) =
+            self.bind_create_table(&create_table_stmt).await?
+        else {
+            return Err(ErrorCode::Internal("Invalid create rollup table plan"));
+        };
+        create_table
+            .options
+            .insert(OPT_KEY_ROLLUP_QUERY.to_string(), rollup_query);
+
+        Ok(Plan::CreateRollupTable(Box::new(CreateRollupTablePlan {
+            create_table: *create_table,
+            source_database,
+            source_table,
+        })))
+    }
+}
+
+fn display_rollup_columns<'a>(
+    columns: impl Iterator<Item = &'a RollupColumn>,
+    with_alias: bool,
+) -> String {
+    columns
+        .map(|column| {
+            if with_alias {
+                column.to_string()
+            } else {
+                column.expr.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -15,6 +15,8 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use databend_common_ast::ast::AlterStageAction;
+use databend_common_ast::ast::AlterStageStmt;
 use databend_common_ast::ast::CreateStageStmt;
 use databend_common_ast::ast::UriLocation;
 use databend_common_exception::ErrorCode;
@@ -28,6 +30,8 @@ use databend_common_storage::init_operator;
 use super::super::copy_into_table::resolve_stage_location;
 use crate::binder::location::parse_storage_params_from_uri;
 use crate::binder::Binder;
+use crate::plans::AlterStagePlan;
+use crate::plans::AlterStagePlanAction;
 use crate::plans::CreateStagePlan;
 use crate::plans::Plan;
 use crate::plans::RemoveStagePlan;
@@ -122,6 +126,74 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_stage(
+        &mut self,
+        stmt: &AlterStageStmt,
+    ) -> Result<Plan> {
+        let AlterStageStmt {
+            if_exists,
+            stage_name,
+            action,
+        } = stmt;
+
+        if stage_name == "~" {
+            return Err(ErrorCode::StagePermissionDenied(
+                "user stage is not allowed to be altered",
+            ));
+        }
+
+        let action = match action {
+            AlterStageAction::Set {
+                location,
+                file_format_options,
+                comments,
+            } => {
+                let storage = match location {
+                    None => None,
+                    Some(uri) => {
+                        let mut uri = uri.clone();
+                        let storage = parse_storage_params_from_uri(
+                            &mut uri,
+                            Some(self.ctx.as_ref()),
+                            "when ALTER STAGE",
+                        )
+                        .await?;
+
+                        // Check the storage params via init operator.
+                        let _ = init_operator(&storage).map_err(|err| {
+                            ErrorCode::InvalidConfig(format!(
+                                "Input storage config for stage is invalid: {err:?}"
+                            ))
+                        })?;
+                        Some(storage)
+                    }
+                };
+                let file_format_params = if file_format_options.is_empty() {
+                    None
+                } else {
+                    Some(self.try_resolve_file_format(file_format_options).await?)
+                };
+                AlterStagePlanAction::Set {
+                    storage,
+                    file_format_params,
+                    comment: comments.clone(),
+                }
+            }
+            AlterStageAction::RenameTo { new_stage_name } => AlterStagePlanAction::RenameTo {
+                new_stage_name: new_stage_name.clone(),
+            },
+            AlterStageAction::RefreshCredentials => AlterStagePlanAction::RefreshCredentials,
+        };
+
+        Ok(Plan::AlterStage(Box::new(AlterStagePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            stage_name: stage_name.clone(),
+            action,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn try_resolve_file_format(
         &self,
@@ -21,6 +21,7 @@ use databend_common_ast::ast::AlterTableAction;
 use databend_common_ast::ast::AlterTableStmt;
 use databend_common_ast::ast::AnalyzeTableStmt;
 use databend_common_ast::ast::AttachTableStmt;
+use databend_common_ast::ast::CloneTableStmt;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CompactTarget;
@@ -39,13 +40,16 @@ use databend_common_ast::ast::RenameTableStmt;
 use databend_common_ast::ast::ShowCreateTableStmt;
 use databend_common_ast::ast::ShowDropTablesStmt;
 use databend_common_ast::ast::ShowLimit;
+use databend_common_ast::ast::ShowSegmentsStmt;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::StatisticsMethod;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
+use databend_common_ast::ast::UpdateStatisticsOption;
 use databend_common_ast::ast::UriLocation;
 use databend_common_ast::ast::VacuumDropTableStmt;
 use databend_common_ast::ast::VacuumTableStmt;
@@ -53,6 +57,7 @@ use databend_common_ast::ast::VacuumTemporaryFiles;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::walk_expr_mut;
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -74,9 +79,11 @@ use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -100,7 +107,9 @@ use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AnalyzeColumnMethod;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -242,6 +251,41 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_segments(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ShowSegmentsStmt,
+    ) -> Result<Plan> {
+        let ShowSegmentsStmt {
+            catalog,
+            database,
+            table,
+            snapshot_id,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        if catalog != CATALOG_DEFAULT {
+            return Err(ErrorCode::Unimplemented(format!(
+                "SHOW SEGMENTS only supports the tables in the catalog {CATALOG_DEFAULT}"
+            )));
+        }
+
+        let snapshot_arg = match snapshot_id {
+            Some(snapshot_id) => format!(", '{snapshot_id}'"),
+            None => "".to_string(),
+        };
+        let query = format!(
+            "SELECT file_location AS segment_location, block_count, row_count, \
+            bytes_compressed AS file_size_bytes, created_on, cluster_stats \
+            FROM fuse_segment('{database}', '{table}'{snapshot_arg})"
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowSegments)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_describe_table(
         &mut self,
@@ -745,6 +789,78 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_clone_table(
+        &mut self,
+        stmt: &CloneTableStmt,
+    ) -> Result<Plan> {
+        let CloneTableStmt {
+            catalog,
+            database,
+            table,
+            new_database,
+            new_table,
+            snapshot_id,
+        } = stmt;
+
+        let (source_catalog, source_database, source_table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, new_database, new_table);
+
+        let source = self
+            .ctx
+            .get_table(&source_catalog, &source_database, &source_table)
+            .await?;
+        if source.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Clone table only support FUSE engine",
+            ));
+        }
+        let source_info = source.get_table_info();
+        if source_info.options().contains_key(OPT_KEY_STORAGE_PREFIX) {
+            return Err(ErrorCode::SemanticError(
+                "Clone table does not support attached table",
+            ));
+        }
+
+        // The snapshot of the clone is pointed to by the interpreter, after the table is created.
+        let options = source_info
+            .options()
+            .iter()
+            .filter(|(k, _)| {
+                !is_reserved_opt_key(k)
+                    && !is_internal_opt_key(k)
+                    && *k != OPT_KEY_SNAPSHOT_LOCATION
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+            create_table: CreateTablePlan {
+                create_option: CreateOption::CreateIfNotExists(false),
+                tenant: self.ctx.get_tenant(),
+                catalog,
+                database,
+                table,
+                schema: source.schema(),
+                engine: Engine::Fuse,
+                engine_options: BTreeMap::new(),
+                storage_params: None,
+                read_only_attach: false,
+                part_prefix: "".to_string(),
+                options,
+                field_comments: source.field_comments().clone(),
+                cluster_key: source_info.meta.default_cluster_key.clone(),
+                as_select: None,
+            },
+            source_catalog,
+            source_database,
+            source_table,
+            snapshot_id: snapshot_id.clone(),
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_table(
         &mut self,
@@ -1200,15 +1316,53 @@ impl Binder {
             catalog,
             database,
             table,
+            update_statistics,
         } = stmt;
 
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        let columns = match update_statistics {
+            None => None,
+            Some(option) => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let all_columns = |method: AnalyzeColumnMethod| {
+                    schema
+                        .fields()
+                        .iter()
+                        .filter(|field| field.computed_expr().is_none())
+                        .map(|field| (field.name().clone(), method.clone()))
+                        .collect::<Vec<_>>()
+                };
+                let columns = match option {
+                    UpdateStatisticsOption::Auto => all_columns(AnalyzeColumnMethod::Auto),
+                    UpdateStatisticsOption::All(method) => {
+                        all_columns(Self::analyze_column_method(method)?)
+                    }
+                    UpdateStatisticsOption::Columns(columns) => {
+                        let mut analyze_columns = Vec::with_capacity(columns.len());
+                        for (column, method) in columns {
+                            let column =
+                                normalize_identifier(column, &self.name_resolution_ctx).name;
+                            schema.field_with_name(&column)?;
+                            analyze_columns.push((column, Self::analyze_column_method(method)?));
+                        }
+                        analyze_columns
+                    }
+                };
+                Some(columns)
+            }
+        };
+
         Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
             catalog,
             database,
             table,
+            columns,
         })))
     }
 
@@ -1278,7 +1432,8 @@ impl Binder {
         let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
         let not_null = self.is_column_not_null();
         let data_type = resolve_type_name(&column.data_type, not_null)?;
-        let mut field = TableField::new(&name, data_type);
+        let mut field = TableField::new(&name, data_type)
+            .with_declared_length(declared_length(&column.data_type));
         if let Some(expr) = &column.expr {
             match expr {
                 ColumnExpr::Default(default_expr) => {
@@ -1321,7 +1476,8 @@ impl Binder {
             let schema_data_type = resolve_type_name(&column.data_type, not_null)?;
             fields_comments.push(column.comment.clone().unwrap_or_default());
 
-            let mut field = TableField::new(&name, schema_data_type.clone());
+            let mut field = TableField::new(&name, schema_data_type.clone())
+                .with_declared_length(declared_length(&column.data_type));
             if let Some(expr) = &column.expr {
                 match expr {
                     ColumnExpr::Default(default_expr) => {
@@ -1528,7 +1684,7 @@ impl Binder {
         Ok(cluster_keys)
     }
 
-    fn valid_cluster_key_type(data_type: &DataType) -> bool {
+    pub(crate) fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
             inner_type,
@@ -1541,6 +1697,20 @@ impl Binder {
         )
     }
 
+    fn analyze_column_method(method: &StatisticsMethod) -> Result<AnalyzeColumnMethod> {
+        match method {
+            StatisticsMethod::Full => Ok(AnalyzeColumnMethod::Full),
+            StatisticsMethod::Sample { percent } => {
+                if *percent == 0 || *percent > 100 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "sample percent must be between 1 and 100, but got {percent}"
+                    )));
+                }
+                Ok(AnalyzeColumnMethod::Sample { percent: *percent })
+            }
+        }
+    }
+
     fn is_column_not_null(&self) -> bool {
         !self
             .ctx
@@ -1549,3 +1719,12 @@ impl Binder {
             .unwrap_or(true)
     }
 }
+
+/// The length declared for a string column, e.g. 255 for `VARCHAR(255) NOT NULL`.
+fn declared_length(type_name: &TypeName) -> Option<u64> {
+    match type_name {
+        TypeName::Varchar(length) => Some(*length),
+        TypeName::Nullable(inner) | TypeName::NotNull(inner) => declared_length(inner),
+        _ => None,
+    }
+}
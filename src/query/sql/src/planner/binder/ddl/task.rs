@@ -15,7 +15,6 @@
 use std::str::FromStr;
 
 use chrono_tz;
-use cron;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_ast::ast::AlterTaskStmt;
 use databend_common_ast::ast::CreateTaskStmt;
@@ -29,6 +28,7 @@ use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Dialect;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskSchedule;
 
 use crate::plans::AlterTaskPlan;
 use crate::plans::CreateTaskPlan;
@@ -61,7 +61,7 @@ fn verify_scheduler_option(schedule_opts: &Option<ScheduleOptions>) -> Result<()
     }
     let schedule_opts = schedule_opts.clone().unwrap();
     if let ScheduleOptions::CronExpression(cron_expr, time_zone) = schedule_opts {
-        if cron::Schedule::from_str(&cron_expr).is_err() {
+        if TaskSchedule::parse_cron(&cron_expr).is_err() {
             return Err(ErrorCode::SemanticError(format!(
                 "invalid cron expression {}",
                 cron_expr
@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::AlterViewOwnerStmt;
 use databend_common_ast::ast::AlterViewStmt;
 use databend_common_ast::ast::CreateViewStmt;
 use databend_common_ast::ast::DropViewStmt;
 use databend_common_ast::VisitorMut;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::PrincipalIdentity;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
+use crate::plans::GrantPrivilegePlan;
 use crate::plans::Plan;
 use crate::ViewRewriter;
 
@@ -104,6 +112,48 @@ impl Binder {
         Ok(Plan::AlterView(plan.into()))
     }
 
+    /// `ALTER VIEW <view> OWNER TO ROLE <role>` transfers the ownership of the view,
+    /// it's planned as `GRANT OWNERSHIP ON <view> TO ROLE <role>`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_view_owner(
+        &mut self,
+        stmt: &AlterViewOwnerStmt,
+    ) -> Result<Plan> {
+        let AlterViewOwnerStmt {
+            catalog,
+            database,
+            view,
+            role,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog_name, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let table = catalog.get_table(&tenant, &database, &view_name).await?;
+        if table.engine() != VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not VIEW",
+                &database, &view_name
+            )));
+        }
+        let db_id = catalog
+            .get_database(&tenant, &database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+
+        let mut priv_types = UserPrivilegeSet::empty();
+        priv_types.set_privilege(UserPrivilegeType::Ownership);
+        let plan = GrantPrivilegePlan {
+            principal: PrincipalIdentity::Role(role.clone()),
+            on: vec![GrantObject::TableById(catalog_name, db_id, table.get_id())],
+            priv_types,
+        };
+        Ok(Plan::GrantPriv(Box::new(plan)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_view(
         &mut self,
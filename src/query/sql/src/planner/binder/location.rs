@@ -164,9 +164,50 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
     }
     .to_string();
 
-    // If role_arn is empty and we don't allow allow insecure, we should disable credential loader.
-    let disable_credential_loader =
-        role_arn.is_empty() && !GlobalConfig::instance().storage.allow_insecure;
+    let preferred_region = l
+        .connection
+        .get("preferred_s3_region")
+        .cloned()
+        .unwrap_or_default();
+
+    let credentials_from = l
+        .connection
+        .get("credentials_from")
+        .map(|v| v.to_uppercase());
+
+    let disable_credential_loader = match credentials_from.as_deref() {
+        // If role_arn is empty and we don't allow allow insecure, we should disable credential loader.
+        None => role_arn.is_empty() && !GlobalConfig::instance().storage.allow_insecure,
+        // Load the credentials by the default credential chain of AWS: environment variables,
+        // web identity token, profile files, ECS task role and the instance metadata service.
+        Some("ENV") => {
+            if !GlobalConfig::instance().storage.allow_credential_chain {
+                return Err(Error::new(
+                    ErrorKind::PermissionDenied,
+                    anyhow!(
+                        "credentials_from = 'ENV' is not allowed, please enable storage.allow_credential_chain"
+                    ),
+                ));
+            }
+            false
+        }
+        // Assume the role with the credentials loaded by the default credential chain.
+        Some("AWS_ROLE") => {
+            if role_arn.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    anyhow!("role_arn is required when credentials_from is 'AWS_ROLE'"),
+                ));
+            }
+            false
+        }
+        Some(v) => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!("value for credentials_from is invalid: {v}, expected 'ENV' or 'AWS_ROLE'"),
+            ));
+        }
+    };
 
     let sp = StorageParams::S3(StorageS3Config {
         endpoint_url: secure_omission(endpoint),
@@ -181,6 +222,7 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
         enable_virtual_host_style,
         role_arn,
         external_id,
+        preferred_region,
     });
 
     l.connection.check()?;
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::resolve_stage_location;
+use crate::binder::Binder;
+use crate::plans::ApplyMigrationPlan;
+use crate::plans::Plan;
+use crate::plans::RewriteKind;
+use crate::plans::RollbackMigrationPlan;
+use crate::BindContext;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_apply_migration(
+        &mut self,
+        _: &BindContext,
+        location: &str,
+    ) -> Result<Plan> {
+        let Some(stage_location) = location.strip_prefix('@') else {
+            return Err(ErrorCode::BadArguments(format!(
+                "invalid migration script location '{location}', expect '@<stage_name>/<path>'"
+            )));
+        };
+        let (stage_info, path) = resolve_stage_location(self.ctx.as_ref(), stage_location).await?;
+        let name = path.rsplit('/').next().unwrap_or_default().to_string();
+        if name.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "invalid migration script location '{location}', expect a file"
+            )));
+        }
+
+        Ok(Plan::ApplyMigration(Box::new(ApplyMigrationPlan {
+            stage: Box::new(stage_info),
+            path,
+            name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_migrations(
+        &mut self,
+        bind_context: &mut BindContext,
+    ) -> Result<Plan> {
+        let query = "SELECT name, checksum, applied_at FROM system.databend_migrations \
+            ORDER BY applied_at";
+
+        self.bind_rewrite_to_query(bind_context, query, RewriteKind::ShowMigrations)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_rollback_migration(
+        &mut self,
+        _: &BindContext,
+        name: &str,
+    ) -> Result<Plan> {
+        Ok(Plan::RollbackMigration(Box::new(RollbackMigrationPlan {
+            name: name.to_string(),
+        })))
+    }
+}
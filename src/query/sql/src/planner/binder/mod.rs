@@ -33,10 +33,12 @@ mod kill;
 mod limit;
 mod location;
 mod merge_into;
+mod migration;
 mod presign;
 mod project;
 mod project_set;
 mod qualify;
+mod query_cache;
 mod replace;
 mod scalar;
 mod scalar_common;
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::PurgeQueryCacheTarget;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::Plan;
+use crate::plans::PurgeQueryCachePlan;
+use crate::plans::RewriteKind;
+use crate::BindContext;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_query_cache(
+        &mut self,
+        bind_context: &mut BindContext,
+    ) -> Result<Plan> {
+        let query = "SELECT query_id, sql_digest, num_rows AS result_rows, \
+            result_size AS result_bytes, hit_count, expires_at FROM system.query_cache \
+            ORDER BY expires_at";
+
+        self.bind_rewrite_to_query(bind_context, query, RewriteKind::ShowQueryCache)
+            .await
+    }
+
+    pub(in crate::planner::binder) fn bind_purge_query_cache(
+        &mut self,
+        _: &BindContext,
+        target: &PurgeQueryCacheTarget,
+    ) -> Result<Plan> {
+        let mut plan = PurgeQueryCachePlan::default();
+        match target {
+            PurgeQueryCacheTarget::All => {}
+            PurgeQueryCacheTarget::Table(table) => {
+                let table = if table.contains('.') {
+                    table.clone()
+                } else {
+                    format!("{}.{}", self.ctx.get_current_database(), table)
+                };
+                plan.table = Some(table);
+            }
+            PurgeQueryCacheTarget::OlderThan(interval) => {
+                plan.older_than_secs = Some(parse_interval_secs(interval)?);
+            }
+        }
+        Ok(Plan::PurgeQueryCache(Box::new(plan)))
+    }
+}
+
+/// Parse an interval like `'1 hour'` or `'30 minutes'` into seconds.
+fn parse_interval_secs(interval: &str) -> Result<u64> {
+    let invalid = || {
+        ErrorCode::BadArguments(format!(
+            "invalid interval '{interval}', expect '<number> {{ SECOND | MINUTE | HOUR | DAY | WEEK }}'"
+        ))
+    };
+
+    let mut parts = interval.split_whitespace();
+    let (Some(quantity), Some(unit), None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+    let quantity = quantity.parse::<u64>().map_err(|_| invalid())?;
+    let unit = unit.to_lowercase();
+    let unit_secs = match unit.strip_suffix('s').unwrap_or(&unit) {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 60 * 60,
+        "day" => 24 * 60 * 60,
+        "week" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    quantity.checked_mul(unit_secs).ok_or_else(invalid)
+}
@@ -134,6 +134,15 @@ impl Binder {
                 .await?
         };
 
+        if let Some(hints) = &stmt.hints {
+            if let Some(e) = self.opt_hints_optimizer(hints).err() {
+                warn!(
+                    "In SELECT resolve optimizer hints {:?} failed, err: {:?}",
+                    hints, e
+                );
+            }
+        }
+
         let mut rewriter = SelectRewriter::new(
             from_context.all_column_bindings(),
             self.name_resolution_ctx.unquoted_ident_case_sensitive,
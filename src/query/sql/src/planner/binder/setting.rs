@@ -14,6 +14,7 @@
 
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::TransactionIsolationLevel;
 use databend_common_ast::ast::UnSetSource;
 use databend_common_ast::ast::UnSetStmt;
 use databend_common_exception::ErrorCode;
@@ -91,4 +92,18 @@ impl Binder {
             }
         }
     }
+
+    /// `SET TRANSACTION ISOLATION LEVEL <level>` sets the `transaction_isolation` setting
+    /// of the session.
+    pub(in crate::planner::binder) fn bind_set_transaction_isolation(
+        &mut self,
+        level: &TransactionIsolationLevel,
+    ) -> Result<Plan> {
+        let vars = vec![VarValue {
+            is_global: false,
+            variable: "transaction_isolation".to_string(),
+            value: level.to_string(),
+        }];
+        Ok(Plan::SetVariable(Box::new(SettingPlan { vars })))
+    }
 }
@@ -15,7 +15,13 @@
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowLocksStmt;
 use databend_common_ast::ast::ShowOptions;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_statement_mut;
+use databend_common_ast::Dialect;
 use databend_common_exception::Result;
+use itertools::Itertools;
 use log::debug;
 
 use crate::plans::Plan;
@@ -23,6 +29,8 @@ use crate::plans::RewriteKind;
 use crate::BindContext;
 use crate::Binder;
 use crate::SelectBuilder;
+use crate::SnowflakeConstruct;
+use crate::SnowflakeRewriter;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -105,6 +113,92 @@ impl Binder {
             .await
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_io_stats(
+        &mut self,
+        bind_context: &mut BindContext,
+        query_id: &str,
+    ) -> Result<Plan> {
+        let query = format!(
+            "SELECT operator_name, bytes_read, bytes_written, read_requests, write_requests, read_latency_p99_ms \
+            FROM system.io_stats WHERE query_id = '{}' ORDER BY operator_name",
+            query_id.replace('\'', "''"),
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowIoStats)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_incompatibilities(
+        &mut self,
+        bind_context: &mut BindContext,
+        query: &str,
+    ) -> Result<Plan> {
+        let tokens = tokenize_sql(query)?;
+        let constructs = match parse_sql(&tokens, Dialect::Snowflake) {
+            Ok((mut stmt, _)) => {
+                let mut rewriter = SnowflakeRewriter::default();
+                walk_statement_mut(&mut rewriter, &mut stmt);
+                rewriter.constructs
+            }
+            // Table sampling can't be parsed, so it's reported instead of the syntax error.
+            Err(_)
+                if tokens.iter().any(|token| {
+                    token.kind == TokenKind::SAMPLE
+                        || token.text().eq_ignore_ascii_case("TABLESAMPLE")
+                }) =>
+            {
+                vec![SnowflakeConstruct {
+                    name: "SAMPLE".to_string(),
+                    difference: Some("table sampling is not supported".to_string()),
+                }]
+            }
+            Err(e) => return Err(e),
+        };
+
+        let query = if constructs.is_empty() {
+            "SELECT NULL AS construct, NULL AS difference LIMIT 0".to_string()
+        } else {
+            let rows = constructs
+                .iter()
+                .map(|c| {
+                    let difference = match &c.difference {
+                        Some(difference) => format!("'{}'", difference.replace('\'', "''")),
+                        None => "NULL".to_string(),
+                    };
+                    format!("('{}', {})", c.name.replace('\'', "''"), difference)
+                })
+                .join(", ");
+            format!("SELECT * FROM (VALUES {rows}) AS t(construct, difference)")
+        };
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowIncompatibilities)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_status(
+        &mut self,
+        bind_context: &mut BindContext,
+        global: bool,
+        show_options: &Option<ShowOptions>,
+    ) -> Result<Plan> {
+        let (show_limit, limit_str) = get_show_options(show_options, Some("name".to_string()));
+        let value = if global {
+            "global_value"
+        } else {
+            "session_value"
+        };
+        let query = format!(
+            "SELECT name AS \"Variable_name\", {} AS \"Value\" FROM system.status {} ORDER BY name {}",
+            value, show_limit, limit_str,
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowStatus)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_process_list(
         &mut self,
@@ -69,6 +69,7 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_types::MetaId;
+use databend_common_storage::file_format_type_of_extension;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
@@ -268,17 +269,18 @@ impl Binder {
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
                 new_bind_context.view_info = Some((database.clone(), table_name));
                 if let Statement::Query(query) = &stmt {
-                    self.metadata.write().add_table(
+                    let view_index = self.metadata.write().add_table(
                         catalog,
                         database.clone(),
                         table_meta,
                         table_alias_name,
-                        false,
+                        bind_context.view_info.is_some(),
                         false,
                         false,
                     );
                     let (s_expr, mut new_bind_context) =
                         self.bind_query(&mut new_bind_context, query).await?;
+                    self.metadata.write().set_view_sources(view_index);
                     if let Some(alias) = alias {
                         // view maybe has alias, e.g. select v1.col1 from v as v1;
                         new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -885,17 +887,34 @@ impl Binder {
             _ => location.clone(),
         };
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &location).await?;
+        let files_info = StageFilesInfo {
+            path,
+            pattern: options.pattern.clone(),
+            files: options.files.clone(),
+        };
         if let Some(f) = &options.file_format {
             stage_info.file_format_params = match StageFileFormatType::from_str(f) {
                 Ok(t) => FileFormatParams::default_by_type(t)?,
                 _ => self.ctx.get_file_format(f).await?,
             }
+        } else if let Some(extension) = files_info.file_extension() {
+            // Detect the format from the extension of the files, the format of the stage
+            // is kept if it's the same, with its options.
+            match file_format_type_of_extension(&extension) {
+                Some(format_type) if format_type != stage_info.file_format_params.get_type() => {
+                    stage_info.file_format_params = FileFormatParams::default_by_type(format_type)?;
+                }
+                Some(_) => {}
+                // A stage declares the format of its files.
+                None if matches!(location, FileLocation::Uri(_)) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Cannot detect the format of the files with the extension '.{extension}', specify it with FILE_FORMAT"
+                    )));
+                }
+                None => {}
+            }
         }
-        let files_info = StageFilesInfo {
-            path,
-            pattern: options.pattern.clone(),
-            files: options.files.clone(),
-        };
+        let files_info = files_info.resolve_glob();
         let table_ctx = self.ctx.clone();
         self.bind_stage_table(table_ctx, bind_context, stage_info, files_info, alias, None)
             .await
@@ -1009,6 +1028,40 @@ impl Binder {
                     read_options = read_options.with_do_prewhere(false);
                 }
 
+                if table_ctx.get_settings().get_parquet_case_sensitive()? {
+                    read_options = read_options.with_case_sensitive(true);
+                }
+
+                if table_ctx.get_settings().get_parquet_schema_merge()? {
+                    read_options = read_options.with_schema_merge(true);
+                }
+
+                if table_ctx.get_settings().get_enable_parquet_bloom_filter()? {
+                    read_options = read_options.with_use_bloom_filter(true);
+                }
+
+                read_options = read_options.with_read_retries(
+                    table_ctx.get_settings().get_parquet_max_read_retries()?,
+                    table_ctx
+                        .get_settings()
+                        .get_parquet_read_retry_initial_delay_ms()?,
+                );
+
+                read_options = read_options.with_snapshot_consistency(
+                    table_ctx
+                        .get_settings()
+                        .get_parquet_snapshot_consistency()?
+                        .parse()?,
+                );
+
+                if !table_ctx.get_settings().get_parquet_int96_as_timestamp()? {
+                    read_options = read_options.with_int96_as_timestamp(false);
+                }
+
+                if table_ctx.get_settings().get_parquet_hive_partitioning()? {
+                    read_options = read_options.with_hive_partitioning(true);
+                }
+
                 if use_parquet2 {
                     Parquet2Table::create(
                         table_ctx.clone(),
@@ -1041,6 +1094,7 @@ impl Binder {
                     files_to_copy: None,
                     is_select: true,
                     default_values: None,
+                    metadata_columns: vec![],
                 };
                 StageTable::try_create(info)?
             }
@@ -1075,6 +1129,7 @@ impl Binder {
                     files_to_copy: None,
                     is_select: true,
                     default_values: None,
+                    metadata_columns: vec![],
                 };
                 StageTable::try_create(info)?
             }
@@ -1447,6 +1502,8 @@ impl Binder {
 
         if let Some(tp) = travel_point {
             table_meta = table_meta.navigate_to(tp).await?;
+        } else {
+            table_meta = self.ctx.pin_table_snapshot(table_meta).await?;
         }
         Ok(table_meta)
     }
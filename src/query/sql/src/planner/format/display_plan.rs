@@ -55,6 +55,7 @@ impl Plan {
             Plan::ExplainAst { .. } => Ok("ExplainAst".to_string()),
             Plan::ExplainSyntax { .. } => Ok("ExplainSyntax".to_string()),
             Plan::ExplainAnalyze { .. } => Ok("ExplainAnalyze".to_string()),
+            Plan::RecommendIndex { .. } => Ok("RecommendIndex".to_string()),
 
             Plan::CopyIntoTable(_) => Ok("CopyIntoTable".to_string()),
             Plan::CopyIntoLocation(_) => Ok("CopyIntoLocation".to_string()),
@@ -70,9 +71,15 @@ impl Plan {
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
+            Plan::BackupDatabase(_) => Ok("BackupDatabase".to_string()),
+            Plan::RestoreDatabase(_) => Ok("RestoreDatabase".to_string()),
+            Plan::ShowBackups(_) => Ok("ShowBackups".to_string()),
+            Plan::RestoreMetasrv(_) => Ok("RestoreMetasrv".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
+            Plan::CreateRollupTable(_) => Ok("CreateRollupTable".to_string()),
+            Plan::CloneTable(_) => Ok("CloneTable".to_string()),
             Plan::ShowCreateTable(_) => Ok("ShowCreateTable".to_string()),
             Plan::DropTable(_) => Ok("DropTable".to_string()),
             Plan::UndropTable(_) => Ok("UndropTable".to_string()),
@@ -123,11 +130,13 @@ impl Plan {
 
             // Stages
             Plan::CreateStage(_) => Ok("CreateStage".to_string()),
+            Plan::AlterStage(_) => Ok("AlterStage".to_string()),
             Plan::DropStage(_) => Ok("DropStage".to_string()),
             Plan::RemoveStage(_) => Ok("RemoveStage".to_string()),
 
             // FileFormat
             Plan::CreateFileFormat(_) => Ok("CreateFileFormat".to_string()),
+            Plan::AlterFileFormat(_) => Ok("AlterFileFormat".to_string()),
             Plan::DropFileFormat(_) => Ok("DropFileFormat".to_string()),
             Plan::ShowFileFormats(_) => Ok("ShowFileFormats".to_string()),
 
@@ -153,6 +162,9 @@ impl Plan {
             Plan::SetSecondaryRoles(_) => Ok("SetSecondaryRoles".to_string()),
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
             Plan::Kill(_) => Ok("Kill".to_string()),
+            Plan::ResetMemoryPeak => Ok("ResetMemoryPeak".to_string()),
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
 
             Plan::CreateShareEndpoint(_) => Ok("CreateShareEndpoint".to_string()),
             Plan::ShowShareEndpoint(_) => Ok("ShowShareEndpoint".to_string()),
@@ -180,6 +192,12 @@ impl Plan {
             Plan::DropNetworkPolicy(_) => Ok("DropNetworkPolicy".to_string()),
             Plan::DescNetworkPolicy(_) => Ok("DescNetworkPolicy".to_string()),
             Plan::ShowNetworkPolicies(_) => Ok("ShowNetworkPolicies".to_string()),
+            Plan::CreateNotificationIntegration(_) => {
+                Ok("CreateNotificationIntegration".to_string())
+            }
+            Plan::AlterNotificationIntegration(_) => Ok("AlterNotificationIntegration".to_string()),
+            Plan::DropNotificationIntegration(_) => Ok("DropNotificationIntegration".to_string()),
+            Plan::ShowNotificationIntegrations(_) => Ok("ShowNotificationIntegrations".to_string()),
 
             // password policy
             Plan::CreatePasswordPolicy(_) => Ok("CreatePasswordPolicy".to_string()),
@@ -195,6 +213,11 @@ impl Plan {
             Plan::ExecuteTask(_) => Ok("ExecuteTask".to_string()),
             Plan::ShowTasks(_) => Ok("ShowTasks".to_string()),
 
+            // migration
+            Plan::ApplyMigration(_) => Ok("ApplyMigration".to_string()),
+            Plan::RollbackMigration(_) => Ok("RollbackMigration".to_string()),
+            Plan::PurgeQueryCache(_) => Ok("PurgeQueryCache".to_string()),
+
             // task
             Plan::CreateConnection(_) => Ok("CreateConnection".to_string()),
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
@@ -0,0 +1,386 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::TableDataType;
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+
+use crate::binder::Binder;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::optimizer::SelectivityEstimator;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::BloomIndexColumns;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// The least estimated benefit, the fraction of the rows that can be skipped, for a column
+/// to be recommended.
+const MIN_ESTIMATED_BENEFIT: f64 = 0.5;
+/// The most columns recommended for a cluster key, the later columns of a cluster key
+/// rarely help the pruning.
+const MAX_CLUSTER_KEY_COLUMNS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexKind {
+    Bloom,
+    ClusterKey,
+}
+
+impl Display for IndexKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndexKind::Bloom => write!(f, "BLOOM"),
+            IndexKind::ClusterKey => write!(f, "CLUSTER KEY"),
+        }
+    }
+}
+
+/// An index recommended for a table, with the statement that creates it.
+#[derive(Debug, Clone)]
+pub struct IndexRecommendation {
+    /// The qualified name of the table, `database.table`.
+    pub table: String,
+    pub kind: IndexKind,
+    pub columns: Vec<String>,
+    pub statement: String,
+    /// The estimated fraction of the rows of the table that the index can skip.
+    pub estimated_benefit: f64,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ColumnUsage {
+    /// `col = const`, `col IN (...)`.
+    Point,
+    /// `col < const`, `col >= const`, `col BETWEEN ...`.
+    Range,
+    /// The probe side of an inner hash join, pruned by the runtime filter.
+    JoinKey,
+    /// `ORDER BY col LIMIT n`, pruned by the top-n.
+    OrderBy,
+}
+
+impl Display for ColumnUsage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnUsage::Point => write!(f, "equality filter"),
+            ColumnUsage::Range => write!(f, "range filter"),
+            ColumnUsage::JoinKey => write!(f, "join key"),
+            ColumnUsage::OrderBy => write!(f, "order by"),
+        }
+    }
+}
+
+/// The best usage of a column of a base table in the query.
+#[derive(Debug, Clone)]
+struct ColumnCandidate {
+    name: String,
+    data_type: TableDataType,
+    usage: ColumnUsage,
+    benefit: f64,
+}
+
+/// Recommends the indexes for a query from its optimized plan.
+///
+/// The filters, the join keys and the sort keys of the plan are collected for each base
+/// table with the fraction of the rows they select, which is estimated from the statistics
+/// of the tables. The columns that skip most of the rows are recommended for a bloom index,
+/// if they are only compared with constants for equality, or for a cluster key, if the table
+/// has no cluster key. The indexes that the tables already have are not recommended.
+pub struct IndexAdvisor<F>
+where F: Fn(&TableDataType) -> bool
+{
+    metadata: MetadataRef,
+    /// Whether a type is supported by the bloom index.
+    bloom_supported: F,
+    /// The candidates of each table, by the column index.
+    candidates: BTreeMap<IndexType, BTreeMap<IndexType, ColumnCandidate>>,
+}
+
+impl<F> IndexAdvisor<F>
+where F: Fn(&TableDataType) -> bool
+{
+    pub fn new(metadata: MetadataRef, bloom_supported: F) -> Self {
+        IndexAdvisor {
+            metadata,
+            bloom_supported,
+            candidates: BTreeMap::new(),
+        }
+    }
+
+    pub fn recommend(mut self, s_expr: &SExpr) -> Result<Vec<IndexRecommendation>> {
+        self.collect(s_expr)?;
+
+        let mut recommendations = vec![];
+        for (table_index, columns) in std::mem::take(&mut self.candidates) {
+            let mut columns = columns
+                .into_values()
+                .filter(|column| column.benefit >= MIN_ESTIMATED_BENEFIT)
+                .collect::<Vec<_>>();
+            if columns.is_empty() {
+                continue;
+            }
+            columns.sort_by(|a, b| b.benefit.total_cmp(&a.benefit).then(a.usage.cmp(&b.usage)));
+            recommendations.extend(self.recommend_bloom_index(table_index, &columns)?);
+            recommendations.extend(self.recommend_cluster_key(table_index, &columns));
+        }
+        Ok(recommendations)
+    }
+
+    fn collect(&mut self, s_expr: &SExpr) -> Result<()> {
+        match s_expr.plan() {
+            RelOperator::Filter(filter) => {
+                let input = RelExpr::with_s_expr(s_expr.child(0)?).derive_cardinality()?;
+                for predicate in filter.predicates.iter() {
+                    let Some((index, usage)) = filter_column(predicate) else {
+                        continue;
+                    };
+                    let mut statistics = input.statistics.clone();
+                    let selectivity = SelectivityEstimator::new(&mut statistics, HashSet::new())
+                        .compute_selectivity(predicate, false)?;
+                    self.add_candidate(index, usage, 1.0 - selectivity);
+                }
+            }
+            RelOperator::Join(join) if join.join_type == JoinType::Inner => {
+                let probe = RelExpr::with_s_expr(s_expr.child(0)?).derive_cardinality()?;
+                let output = RelExpr::with_s_expr(s_expr).derive_cardinality()?;
+                if probe.cardinality > 0.0 {
+                    let benefit = 1.0 - (output.cardinality / probe.cardinality).min(1.0);
+                    for condition in join.left_conditions.iter() {
+                        if let Some(index) = column_index(condition) {
+                            self.add_candidate(index, ColumnUsage::JoinKey, benefit);
+                        }
+                    }
+                }
+            }
+            RelOperator::Sort(sort) => {
+                if let (Some(limit), Some(item)) = (sort.limit, sort.items.first()) {
+                    let input = RelExpr::with_s_expr(s_expr.child(0)?).derive_cardinality()?;
+                    if input.cardinality > 0.0 {
+                        let benefit = 1.0 - (limit as f64 / input.cardinality).min(1.0);
+                        self.add_candidate(item.index, ColumnUsage::OrderBy, benefit);
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        for child in s_expr.children() {
+            self.collect(child)?;
+        }
+        Ok(())
+    }
+
+    /// Keeps the usage of the column that skips the most rows.
+    fn add_candidate(&mut self, index: IndexType, usage: ColumnUsage, benefit: f64) {
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(column) = metadata.column(index) else {
+            return;
+        };
+        // The inner fields and the virtual computed columns can't be indexed.
+        if column.path_indices.is_some() || column.virtual_computed_expr.is_some() {
+            return;
+        }
+        if metadata.table(column.table_index).table().engine() != "FUSE" {
+            return;
+        }
+
+        let candidate = ColumnCandidate {
+            name: column.column_name.clone(),
+            data_type: column.data_type.clone(),
+            usage,
+            benefit,
+        };
+        let columns = self.candidates.entry(column.table_index).or_default();
+        match columns.get(&index) {
+            Some(old) if old.benefit >= benefit => (),
+            _ => {
+                columns.insert(index, candidate);
+            }
+        }
+    }
+
+    fn recommend_bloom_index(
+        &self,
+        table_index: IndexType,
+        columns: &[ColumnCandidate],
+    ) -> Result<Option<IndexRecommendation>> {
+        let metadata = self.metadata.read();
+        let entry = metadata.table(table_index);
+        let table = entry.table();
+
+        let bloom_index_columns = table
+            .options()
+            .get(OPT_KEY_BLOOM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::All);
+        let indexed = bloom_index_columns
+            .bloom_index_fields(table.schema(), &self.bloom_supported)?
+            .into_values()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+
+        let new_columns = columns
+            .iter()
+            .filter(|column| {
+                column.usage == ColumnUsage::Point
+                    && (self.bloom_supported)(&column.data_type)
+                    && !indexed.contains(&column.name)
+            })
+            .collect::<Vec<_>>();
+        let Some(best) = new_columns.first() else {
+            return Ok(None);
+        };
+
+        let table_name = format!("{}.{}", entry.database(), entry.name());
+        let names = new_columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect::<Vec<_>>();
+        let all_names = indexed.iter().chain(names.iter()).cloned();
+        let statement = format!(
+            "ALTER TABLE `{}`.`{}` SET OPTIONS({} = '{}')",
+            entry.database(),
+            entry.name(),
+            OPT_KEY_BLOOM_INDEX_COLUMNS,
+            all_names.collect::<Vec<_>>().join(", ")
+        );
+        Ok(Some(IndexRecommendation {
+            table: table_name,
+            kind: IndexKind::Bloom,
+            reason: reason(&new_columns),
+            columns: names,
+            statement,
+            estimated_benefit: best.benefit,
+        }))
+    }
+
+    fn recommend_cluster_key(
+        &self,
+        table_index: IndexType,
+        columns: &[ColumnCandidate],
+    ) -> Option<IndexRecommendation> {
+        let metadata = self.metadata.read();
+        let entry = metadata.table(table_index);
+        if entry
+            .table()
+            .get_table_info()
+            .meta
+            .default_cluster_key
+            .is_some()
+        {
+            return None;
+        }
+
+        let key_columns = columns
+            .iter()
+            .filter(|column| Binder::valid_cluster_key_type(&DataType::from(&column.data_type)))
+            .take(MAX_CLUSTER_KEY_COLUMNS)
+            .collect::<Vec<_>>();
+        let best = key_columns.first()?;
+
+        let names = key_columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect::<Vec<_>>();
+        let statement = format!(
+            "ALTER TABLE `{}`.`{}` CLUSTER BY ({})",
+            entry.database(),
+            entry.name(),
+            names
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        Some(IndexRecommendation {
+            table: format!("{}.{}", entry.database(), entry.name()),
+            kind: IndexKind::ClusterKey,
+            reason: reason(&key_columns),
+            columns: names,
+            statement,
+            estimated_benefit: best.benefit,
+        })
+    }
+}
+
+fn reason(columns: &[&ColumnCandidate]) -> String {
+    columns
+        .iter()
+        .map(|column| {
+            format!(
+                "{} on {} skips {:.2}% of rows",
+                column.usage,
+                column.name,
+                column.benefit * 100.0
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The column of a predicate that compares a column with constants.
+fn filter_column(predicate: &ScalarExpr) -> Option<(IndexType, ColumnUsage)> {
+    let ScalarExpr::FunctionCall(func) = predicate else {
+        return None;
+    };
+    match (func.func_name.as_str(), func.arguments.as_slice()) {
+        ("eq", [left, right]) => compared_column(left, right).map(|i| (i, ColumnUsage::Point)),
+        ("lt" | "lte" | "gt" | "gte", [left, right]) => {
+            compared_column(left, right).map(|i| (i, ColumnUsage::Range))
+        }
+        // `col IN (...)` with a long list.
+        ("contains", [ScalarExpr::ConstantExpr(_), column]) => {
+            column_index(column).map(|i| (i, ColumnUsage::Point))
+        }
+        // `col IN (...)` with a short list is rewritten to `col = ... OR col = ...`.
+        ("or", [left, right]) => {
+            let (left, ColumnUsage::Point) = filter_column(left)? else {
+                return None;
+            };
+            let (right, ColumnUsage::Point) = filter_column(right)? else {
+                return None;
+            };
+            (left == right).then_some((left, ColumnUsage::Point))
+        }
+        _ => None,
+    }
+}
+
+fn compared_column(left: &ScalarExpr, right: &ScalarExpr) -> Option<IndexType> {
+    match (left, right) {
+        (column, ScalarExpr::ConstantExpr(_)) | (ScalarExpr::ConstantExpr(_), column) => {
+            column_index(column)
+        }
+        _ => None,
+    }
+}
+
+fn column_index(scalar: &ScalarExpr) -> Option<IndexType> {
+    match scalar {
+        ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+        ScalarExpr::CastExpr(cast) => column_index(&cast.argument),
+        _ => None,
+    }
+}
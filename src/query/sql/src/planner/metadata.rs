@@ -274,6 +274,16 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    /// Mark the tables added after the view as referenced by it, the tables referenced by
+    /// the nested views are marked when binding the nested views.
+    pub fn set_view_sources(&mut self, view_index: IndexType) {
+        for table in self.tables.iter_mut().skip(view_index + 1) {
+            if table.view_index.is_none() {
+                table.view_index = Some(view_index);
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -299,6 +309,7 @@ impl Metadata {
             source_of_view,
             source_of_index,
             source_of_stage,
+            view_index: None,
         };
         self.tables.push(table_entry);
         let table_schema = table_meta.schema_with_stream();
@@ -410,6 +421,8 @@ pub struct TableEntry {
     source_of_index: bool,
 
     source_of_stage: bool,
+    /// The view which references this table directly, if it is source from view.
+    view_index: Option<IndexType>,
     table: Arc<dyn Table>,
 }
 
@@ -443,6 +456,7 @@ impl TableEntry {
             source_of_view: false,
             source_of_index: false,
             source_of_stage: false,
+            view_index: None,
         }
     }
 
@@ -481,6 +495,11 @@ impl TableEntry {
         self.source_of_view
     }
 
+    /// Get the index of the view which references this table directly.
+    pub fn view_index(&self) -> Option<IndexType> {
+        self.view_index
+    }
+
     /// Return true if it is source from stage.
     pub fn is_source_of_stage(&self) -> bool {
         self.source_of_stage
@@ -14,6 +14,7 @@
 
 mod bloom_index;
 mod format;
+mod index_advisor;
 mod metadata;
 #[allow(clippy::module_inception)]
 mod planner;
@@ -38,6 +39,9 @@ pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
+pub use index_advisor::IndexAdvisor;
+pub use index_advisor::IndexKind;
+pub use index_advisor::IndexRecommendation;
 pub use metadata::*;
 pub use planner::PlanExtras;
 pub use planner::Planner;
@@ -0,0 +1,212 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_ast::ast::FormatTreeNode;
+use databend_common_exception::Result;
+
+use super::DefaultCostModel;
+use crate::optimizer::format::display_rel_op;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Exchange;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::MetadataRef;
+
+/// Where the statistics used to estimate the row count of an operator come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatisticsSource {
+    /// Neither the row count nor the column statistics of the table are known.
+    DefaultHeuristic,
+    /// The row count of the table is known from the catalog.
+    Catalog,
+    /// The histograms built from the column statistics are used.
+    Histogram,
+}
+
+impl Display for StatisticsSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatisticsSource::DefaultHeuristic => write!(f, "default_heuristic"),
+            StatisticsSource::Catalog => write!(f, "catalog"),
+            StatisticsSource::Histogram => write!(f, "histogram"),
+        }
+    }
+}
+
+/// The values of the cost model for an operator of the plan, used by `EXPLAIN COST`.
+///
+/// The cost of the operator itself is split into `cpu_cost`, `io_cost`, `memory_cost`
+/// and `network_cost`, which add up to the cost computed by `DefaultCostModel`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CostBreakdown {
+    pub operator: String,
+    pub row_count_estimate: f64,
+    pub cpu_cost: f64,
+    pub io_cost: f64,
+    pub memory_cost: f64,
+    pub network_cost: f64,
+    /// The cost of the operator and all of its children.
+    pub total_cost: f64,
+    pub statistics_source: StatisticsSource,
+    pub children: Vec<CostBreakdown>,
+}
+
+impl CostBreakdown {
+    pub fn to_format_tree(&self) -> FormatTreeNode<String> {
+        let mut children = vec![
+            FormatTreeNode::new(format!(
+                "row_count_estimate: {:.3}",
+                self.row_count_estimate
+            )),
+            FormatTreeNode::new(format!("cpu_cost: {:.3}", self.cpu_cost)),
+            FormatTreeNode::new(format!("io_cost: {:.3}", self.io_cost)),
+            FormatTreeNode::new(format!("memory_cost: {:.3}", self.memory_cost)),
+            FormatTreeNode::new(format!("network_cost: {:.3}", self.network_cost)),
+            FormatTreeNode::new(format!("total_cost: {:.3}", self.total_cost)),
+            FormatTreeNode::new(format!("statistics_source: {}", self.statistics_source)),
+        ];
+        children.extend(self.children.iter().map(|child| child.to_format_tree()));
+        FormatTreeNode::with_children(self.operator.clone(), children)
+    }
+}
+
+impl DefaultCostModel {
+    /// Compute the cost of each operator of an optimized plan.
+    pub fn explain_cost(&self, s_expr: &SExpr, metadata: &MetadataRef) -> Result<CostBreakdown> {
+        let children = s_expr
+            .children()
+            .map(|child| self.explain_cost(child, metadata))
+            .collect::<Result<Vec<_>>>()?;
+        let child_card = |index: usize| -> f64 {
+            children
+                .get(index)
+                .map_or(0.0, |child: &CostBreakdown| child.row_count_estimate)
+        };
+
+        let card = RelExpr::with_s_expr(s_expr)
+            .derive_cardinality()?
+            .cardinality;
+        let (mut cpu_cost, mut io_cost, mut memory_cost, mut network_cost) = (0.0, 0.0, 0.0, 0.0);
+        match s_expr.plan() {
+            // Like `compute_cost_scan`, the I/O cost of a table scan is proportional to the rows read.
+            RelOperator::Scan(_) => io_cost = card * self.compute_per_row,
+            RelOperator::Join(join) => {
+                memory_cost = child_card(1) * self.hash_table_per_row;
+                cpu_cost = child_card(0) * self.compute_per_row;
+                if matches!(join.join_type, JoinType::RightAnti | JoinType::RightSemi) {
+                    memory_cost *= 3.0;
+                    cpu_cost *= 3.0;
+                }
+            }
+            RelOperator::UnionAll(_) => {
+                cpu_cost = (child_card(0) + child_card(1)) * self.compute_per_row
+            }
+            RelOperator::Aggregate(_) => cpu_cost = child_card(0) * self.aggregate_per_row,
+            RelOperator::MaterializedCte(_)
+            | RelOperator::EvalScalar(_)
+            | RelOperator::Filter(_)
+            | RelOperator::Window(_)
+            | RelOperator::Sort(_)
+            | RelOperator::ProjectSet(_)
+            | RelOperator::Udf(_)
+            | RelOperator::Limit(_) => cpu_cost = child_card(0) * self.compute_per_row,
+            RelOperator::Exchange(Exchange::Hash(_)) => {
+                network_cost = card * self.network_per_row;
+                cpu_cost = card * self.compute_per_row;
+            }
+            RelOperator::Exchange(Exchange::Merge | Exchange::MergeSort) => {
+                network_cost = card * self.network_per_row;
+                cpu_cost = card
+                    * self.compute_per_row
+                    * self.cluster_peers as f64
+                    * self.degree_of_parallelism as f64
+                    * 100.0;
+            }
+            RelOperator::Exchange(Exchange::Broadcast) => {
+                network_cost =
+                    card * self.network_per_row * self.cluster_peers.saturating_sub(1) as f64
+            }
+            _ => {}
+        }
+
+        let operator = match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                let metadata = metadata.read();
+                let table = metadata.table(scan.table_index);
+                format!(
+                    "Scan: {}.{}.{}",
+                    table.catalog(),
+                    table.database(),
+                    table.name()
+                )
+            }
+            plan => display_rel_op(plan),
+        };
+        let statistics_source = match s_expr.plan() {
+            RelOperator::Scan(scan) => scan_statistics_source(scan),
+            // The estimation of an operator is only as good as the statistics of the tables
+            // it reads, so take the least precise source of the children.
+            _ => children
+                .iter()
+                .map(|child| child.statistics_source)
+                .min()
+                .unwrap_or(StatisticsSource::DefaultHeuristic),
+        };
+        let total_cost = cpu_cost
+            + io_cost
+            + memory_cost
+            + network_cost
+            + children.iter().map(|child| child.total_cost).sum::<f64>();
+
+        Ok(CostBreakdown {
+            operator,
+            row_count_estimate: card,
+            cpu_cost,
+            io_cost,
+            memory_cost,
+            network_cost,
+            total_cost,
+            statistics_source,
+            children,
+        })
+    }
+}
+
+fn scan_statistics_source(scan: &Scan) -> StatisticsSource {
+    let used_columns = scan.used_columns();
+    if scan
+        .statistics
+        .col_stats
+        .iter()
+        .any(|(column, stat)| used_columns.contains(column) && stat.is_some())
+    {
+        StatisticsSource::Histogram
+    } else if scan
+        .statistics
+        .statistics
+        .as_ref()
+        .and_then(|stat| stat.num_rows)
+        .is_some()
+    {
+        StatisticsSource::Catalog
+    } else {
+        StatisticsSource::DefaultHeuristic
+    }
+}
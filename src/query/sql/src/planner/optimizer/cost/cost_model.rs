@@ -30,17 +30,17 @@ use crate::plans::Scan;
 
 #[derive(Default)]
 pub struct DefaultCostModel {
-    compute_per_row: f64,
-    hash_table_per_row: f64,
-    aggregate_per_row: f64,
-    network_per_row: f64,
+    pub(super) compute_per_row: f64,
+    pub(super) hash_table_per_row: f64,
+    pub(super) aggregate_per_row: f64,
+    pub(super) network_per_row: f64,
 
     /// The number of peers in the cluster to
     /// exchange data with.
-    cluster_peers: usize,
+    pub(super) cluster_peers: usize,
 
     /// Degree of parallelism on each node.
-    degree_of_parallelism: usize,
+    pub(super) degree_of_parallelism: usize,
 }
 
 impl CostModel for DefaultCostModel {
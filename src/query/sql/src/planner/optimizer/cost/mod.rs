@@ -14,9 +14,12 @@
 
 #[allow(clippy::module_inception)]
 mod cost;
+mod cost_breakdown;
 mod cost_model;
 
 pub use cost::Cost;
 pub use cost::CostContext;
 pub use cost::CostModel;
+pub use cost_breakdown::CostBreakdown;
+pub use cost_breakdown::StatisticsSource;
 pub use cost_model::DefaultCostModel;
@@ -31,8 +31,12 @@ pub mod s_expr;
 mod util;
 
 pub use cascades::CascadesOptimizer;
+pub use cost::CostBreakdown;
+pub use cost::DefaultCostModel;
+pub use cost::StatisticsSource;
 pub use decorrelate::FlattenInfo;
 pub use decorrelate::SubqueryRewriter;
+pub use extract::Matcher;
 pub use extract::PatternExtractor;
 pub use hyper_dp::DPhpy;
 pub use m_expr::MExpr;
@@ -44,9 +48,15 @@ pub use optimizer::RecursiveOptimizer;
 pub use property::*;
 pub use rule::agg_index;
 pub use rule::try_push_down_filter_join;
+pub use rule::RewriteRuleApplier;
+pub use rule::RewriteRuleCreator;
+pub use rule::RewriteRuleRegistry;
+pub use rule::Rule;
 pub use rule::RuleFactory;
 pub use rule::RuleID;
+pub use rule::RulePtr;
 pub use rule::RuleSet;
+pub use rule::TransformResult;
 pub use rule::DEFAULT_REWRITE_RULES;
 pub use s_expr::get_udf_names;
 pub use s_expr::SExpr;
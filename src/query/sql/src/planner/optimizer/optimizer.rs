@@ -37,6 +37,8 @@ use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::RewriteRuleApplier;
+use crate::optimizer::RewriteRuleRegistry;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -201,6 +203,9 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(optimize(opt_ctx, *plan)?),
         }),
+        Plan::RecommendIndex { plan } => Ok(Plan::RecommendIndex {
+            plan: Box::new(optimize(opt_ctx, *plan)?),
+        }),
         Plan::CopyIntoLocation(CopyIntoLocationPlan { stage, path, from }) => {
             Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
                 stage,
@@ -243,6 +248,12 @@ pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SE
     // Pull up and infer filter.
     s_expr = PullUpFilterOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
 
+    // Run the user-defined rewrite rules, their results are optimized by the default rules.
+    let rules = RewriteRuleRegistry::instance().create_rules(opt_ctx.metadata.clone());
+    if !rules.is_empty() {
+        s_expr = RewriteRuleApplier::new(rules).apply(&s_expr)?;
+    }
+
     // Run default rewrite rules
     s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
 
@@ -328,6 +339,12 @@ fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<Me
         )?;
     }
 
+    // Run the user-defined rewrite rules, their results are optimized by the default rules.
+    let rules = RewriteRuleRegistry::instance().create_rules(opt_ctx.metadata.clone());
+    if !rules.is_empty() {
+        s_expr = RewriteRuleApplier::new(rules).apply(&s_expr)?;
+    }
+
     // Run default rewrite rules
     s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
 
@@ -14,6 +14,7 @@
 
 mod factory;
 mod rewrite;
+mod rewrite_rule_applier;
 #[allow(clippy::module_inception)]
 mod rule;
 mod rule_set;
@@ -24,6 +25,9 @@ mod utils;
 pub use factory::RuleFactory;
 pub use rewrite::agg_index;
 pub use rewrite::try_push_down_filter_join;
+pub use rewrite_rule_applier::RewriteRuleApplier;
+pub use rewrite_rule_applier::RewriteRuleCreator;
+pub use rewrite_rule_applier::RewriteRuleRegistry;
 pub use rule::Rule;
 pub use rule::RuleID;
 pub use rule::RulePtr;
@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parking_lot::RwLock;
+
+use crate::optimizer::rule::RulePtr;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::SExpr;
+use crate::MetadataRef;
+
+/// The times the rules are applied to a node at most, in case the rules rewrite each
+/// other's results forever.
+const MAX_REWRITE_TIMES: usize = 16;
+
+/// Creates a rewrite rule for the query being optimized.
+pub type RewriteRuleCreator = Arc<dyn Fn(MetadataRef) -> RulePtr + Send + Sync>;
+
+static REWRITE_RULE_REGISTRY: LazyLock<RewriteRuleRegistry> =
+    LazyLock::new(|| RewriteRuleRegistry {
+        creators: RwLock::new(BTreeMap::new()),
+    });
+
+/// The registry of the rewrite rules that are not built in the optimizer.
+///
+/// The registered rules are applied by the [`RewriteRuleApplier`] to the logical plan of each
+/// query before the default rewrite rules, so their results are optimized as the other plans.
+/// Their `id` should be [`RuleID::UserDefined`](crate::optimizer::RuleID::UserDefined).
+pub struct RewriteRuleRegistry {
+    creators: RwLock<BTreeMap<String, RewriteRuleCreator>>,
+}
+
+impl RewriteRuleRegistry {
+    pub fn instance() -> &'static RewriteRuleRegistry {
+        &REWRITE_RULE_REGISTRY
+    }
+
+    pub fn register(&self, name: &str, creator: RewriteRuleCreator) -> Result<()> {
+        let mut creators = self.creators.write();
+        if creators.contains_key(name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Rewrite rule {name} is already registered"
+            )));
+        }
+        creators.insert(name.to_string(), creator);
+        Ok(())
+    }
+
+    /// Returns false if there is no rule with the name.
+    pub fn unregister(&self, name: &str) -> bool {
+        self.creators.write().remove(name).is_some()
+    }
+
+    /// Creates the registered rules in the order of their names.
+    pub fn create_rules(&self, metadata: MetadataRef) -> Vec<RulePtr> {
+        self.creators
+            .read()
+            .values()
+            .map(|creator| creator(metadata.clone()))
+            .collect()
+    }
+}
+
+/// Applies the rewrite rules to a plan from the bottom up.
+///
+/// The subtree of a node is replaced with the first result of the first rule that matches it,
+/// then the rules are applied again to the new node, until none of them rewrites it. The
+/// children of the new node are not visited again.
+pub struct RewriteRuleApplier {
+    rules: Vec<RulePtr>,
+}
+
+impl RewriteRuleApplier {
+    pub fn new(rules: Vec<RulePtr>) -> Self {
+        RewriteRuleApplier { rules }
+    }
+
+    pub fn apply(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.apply(child)?));
+        }
+        let mut s_expr = s_expr.replace_children(children);
+
+        for _ in 0..MAX_REWRITE_TIMES {
+            match self.rewrite(&s_expr)? {
+                Some(result) => s_expr = result,
+                None => break,
+            }
+        }
+        Ok(s_expr)
+    }
+
+    fn rewrite(&self, s_expr: &SExpr) -> Result<Option<SExpr>> {
+        for rule in self.rules.iter() {
+            if !rule
+                .matchers()
+                .iter()
+                .any(|matcher| matcher.matches(s_expr))
+            {
+                continue;
+            }
+            let mut state = TransformResult::new();
+            rule.apply(s_expr, &mut state)?;
+            if let Some(result) = state.results().first() {
+                return Ok(Some(result.clone()));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -109,6 +109,9 @@ pub enum RuleID {
     CommuteJoinBaseTable,
     LeftExchangeJoin,
     EagerAggregation,
+
+    // The rules registered in `RewriteRuleRegistry`
+    UserDefined,
 }
 
 impl Display for RuleID {
@@ -147,6 +150,7 @@ impl Display for RuleID {
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
+            RuleID::UserDefined => write!(f, "UserDefined"),
         }
     }
 }
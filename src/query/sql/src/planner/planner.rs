@@ -27,10 +27,14 @@ use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use itertools::Itertools;
+use log::info;
 use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use super::semantic::SnowflakeRewriter;
+use super::semantic::TrinoRewriter;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
@@ -188,6 +192,33 @@ impl Planner {
     fn replace_stmt(&self, stmt: &mut Statement, sql_dialect: Dialect) {
         walk_statement_mut(&mut DistinctToGroupBy::default(), stmt);
         walk_statement_mut(&mut AggregateRewriter { sql_dialect }, stmt);
+        match sql_dialect {
+            Dialect::Snowflake => {
+                let mut rewriter = SnowflakeRewriter::default();
+                walk_statement_mut(&mut rewriter, stmt);
+                for (construct, difference) in rewriter.incompatibilities() {
+                    self.ctx.push_warning(format!(
+                        "SQL_COMPATIBILITY_WARNING: {construct}: {difference}"
+                    ));
+                }
+            }
+            Dialect::Trino => {
+                let mut rewriter = TrinoRewriter::default();
+                walk_statement_mut(&mut rewriter, stmt);
+                if !rewriter.rewritten.is_empty() {
+                    info!(
+                        "{}: rewrote the Trino functions and syntax {}",
+                        self.ctx.get_id(),
+                        rewriter
+                            .rewritten
+                            .iter()
+                            .map(|(trino, databend)| format!("{trino} -> {databend}"))
+                            .join(", ")
+                    );
+                }
+            }
+            _ => {}
+        }
 
         self.add_max_rows_limit(stmt);
     }
@@ -33,10 +33,11 @@ use databend_common_meta_app::principal::COPY_MAX_FILES_COMMIT_MSG;
 use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_metrics::storage::*;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::StageFileInfo;
 use log::info;
 
+use crate::plans::CreateTablePlan;
 use crate::plans::Plan;
 
 #[derive(PartialEq, Eq, Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -104,6 +105,8 @@ pub struct CopyIntoTablePlan {
 
     pub stage_table_info: StageTableInfo,
     pub query: Option<Box<Plan>>,
+    /// The destination table to be created before copying, for `SCHEMA = INFER`.
+    pub create_table: Option<Box<CreateTablePlan>>,
 
     pub enable_distributed: bool,
 }
@@ -121,7 +124,7 @@ impl CopyIntoTablePlan {
             Some(max_files)
         };
 
-        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let operator = init_stage_read_operator(&stage_table_info.stage_info)?;
         let all_source_file_infos = if operator.info().native_capability().blocking {
             if self.force {
                 stage_table_info
@@ -117,7 +117,7 @@ impl ShowRolesPlan {
 pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
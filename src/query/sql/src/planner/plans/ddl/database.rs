@@ -12,13 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::schema::DatabaseNameIdent;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
+use databend_common_meta_app::storage::StorageParams;
 
 /// Create.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -142,3 +149,58 @@ impl ShowCreateDatabasePlan {
         self.schema.clone()
     }
 }
+
+/// Backup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackupDatabasePlan {
+    pub catalog: String,
+    pub database: String,
+    pub storage_params: StorageParams,
+    pub since: Option<String>,
+}
+
+impl BackupDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("backup_id", DataType::String),
+            DataField::new("tables", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("copied_files", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
+/// Restore.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoreDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub storage_params: StorageParams,
+    pub new_database: String,
+}
+
+/// Show backups.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowBackupsPlan {
+    pub storage_params: StorageParams,
+}
+
+impl ShowBackupsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("backup_id", DataType::String),
+            DataField::new("database", DataType::String),
+            DataField::new("since", DataType::String.wrap_nullable()),
+            DataField::new("tables", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("created_on", DataType::Timestamp),
+        ])
+    }
+}
+
+/// Restore metasrv.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoreMetasrvPlan {
+    /// The changes of the changelog applied until the time are restored.
+    pub timestamp: DateTime<Utc>,
+    pub storage_params: StorageParams,
+}
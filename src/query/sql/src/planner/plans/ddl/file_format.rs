@@ -28,6 +28,14 @@ pub struct CreateFileFormatPlan {
     pub file_format_params: FileFormatParams,
 }
 
+/// Alter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterFileFormatPlan {
+    pub if_exists: bool,
+    pub name: String,
+    pub file_format_params: FileFormatParams,
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropFileFormatPlan {
@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationType;
+use databend_common_meta_app::schema::CreateOption;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateNotificationIntegrationPlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub name: String,
+    pub notification_type: NotificationType,
+    pub url: String,
+    pub events: Vec<NotificationEvent>,
+    pub enabled: bool,
+    pub comment: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterNotificationIntegrationPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub url: Option<String>,
+    pub events: Option<Vec<NotificationEvent>>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropNotificationIntegrationPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowNotificationIntegrationsPlan {}
+
+impl ShowNotificationIntegrationsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("type", DataType::String),
+            DataField::new("url", DataType::String),
+            DataField::new("events", DataType::String),
+            DataField::new("enabled", DataType::Boolean),
+            DataField::new("comment", DataType::String),
+        ])
+    }
+}
@@ -14,8 +14,10 @@
 
 use std::fmt::Debug;
 
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::storage::StorageParams;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateStagePlan {
@@ -24,6 +26,28 @@ pub struct CreateStagePlan {
     pub stage_info: StageInfo,
 }
 
+/// Alter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterStagePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub stage_name: String,
+    pub action: AlterStagePlanAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterStagePlanAction {
+    Set {
+        storage: Option<StorageParams>,
+        file_format_params: Option<FileFormatParams>,
+        comment: Option<String>,
+    },
+    RenameTo {
+        new_stage_name: String,
+    },
+    RefreshCredentials,
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropStagePlan {
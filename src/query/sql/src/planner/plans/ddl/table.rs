@@ -62,6 +62,27 @@ impl CreateTablePlan {
     }
 }
 
+/// Create a rollup table, which is back-filled from the source table
+/// and then refreshed incrementally by every insertion into the source table.
+#[derive(Clone, Debug)]
+pub struct CreateRollupTablePlan {
+    pub create_table: CreateTablePlan,
+    pub source_database: String,
+    pub source_table: String,
+}
+
+/// Clone a fuse table, the new table refers to the segments of the snapshot of the source table
+/// instead of copying them.
+#[derive(Clone, Debug)]
+pub struct CloneTablePlan {
+    pub create_table: CreateTablePlan,
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+    /// The snapshot to be cloned, the current snapshot of the source table if not given.
+    pub snapshot_id: Option<String>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -196,6 +217,19 @@ pub struct AnalyzeTablePlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// The columns to compute the statistics of by reading the data, `None` if the
+    /// statistics are estimated from the block statistics.
+    pub columns: Option<Vec<(String, AnalyzeColumnMethod)>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnalyzeColumnMethod {
+    /// Samples the column if the table is larger than `statistics_auto_sample_threshold`.
+    Auto,
+    Full,
+    Sample {
+        percent: u64,
+    },
 }
 
 impl AnalyzeTablePlan {
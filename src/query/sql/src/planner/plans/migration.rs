@@ -0,0 +1,28 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::StageInfo;
+
+#[derive(Debug, Clone)]
+pub struct ApplyMigrationPlan {
+    pub stage: Box<StageInfo>,
+    pub path: String,
+    /// The file name of the script, which the migration is recorded by.
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RollbackMigrationPlan {
+    pub name: String,
+}
@@ -31,6 +31,7 @@ mod kill;
 mod limit;
 mod materialized_cte;
 mod merge_into;
+mod migration;
 mod udf;
 
 mod copy_into_location;
@@ -38,6 +39,7 @@ pub mod operator;
 mod plan;
 mod presign;
 mod project_set;
+mod query_cache;
 mod recluster_table;
 mod replace;
 mod revert_table;
@@ -77,10 +79,12 @@ pub use merge_into::UnmatchedEvaluator;
 pub use merge_into::DELETE_NAME;
 pub use merge_into::INSERT_NAME;
 pub use merge_into::UPDATE_NAME;
+pub use migration::*;
 pub use operator::*;
 pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
+pub use query_cache::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
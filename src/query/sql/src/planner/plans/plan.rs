@@ -28,9 +28,12 @@ use super::SetSecondaryRolesPlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AlterFileFormatPlan;
 use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterNotificationIntegrationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
 use crate::plans::AlterShareTenantsPlan;
+use crate::plans::AlterStagePlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
@@ -38,6 +41,9 @@ use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::ApplyMigrationPlan;
+use crate::plans::BackupDatabasePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CreateCatalogPlan;
@@ -47,8 +53,10 @@ use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreateNotificationIntegrationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRollupTablePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
@@ -74,6 +82,7 @@ use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropNotificationIntegrationPlan;
 use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropShareEndpointPlan;
@@ -99,6 +108,7 @@ use crate::plans::MergeInto;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
+use crate::plans::PurgeQueryCachePlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
@@ -107,13 +117,17 @@ use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
+use crate::plans::RestoreDatabasePlan;
+use crate::plans::RestoreMetasrvPlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
 use crate::plans::RevokeShareObjectPlan;
+use crate::plans::RollbackMigrationPlan;
 use crate::plans::SetOptionsPlan;
 use crate::plans::SetRolePlan;
 use crate::plans::SettingPlan;
+use crate::plans::ShowBackupsPlan;
 use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowCreateCatalogPlan;
 use crate::plans::ShowCreateDatabasePlan;
@@ -122,6 +136,7 @@ use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantTenantsOfSharePlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
+use crate::plans::ShowNotificationIntegrationsPlan;
 use crate::plans::ShowObjectGrantPrivilegesPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
@@ -165,6 +180,9 @@ pub enum Plan {
     ExplainAnalyze {
         plan: Box<Plan>,
     },
+    RecommendIndex {
+        plan: Box<Plan>,
+    },
 
     CopyIntoTable(Box<CopyIntoTablePlan>),
     CopyIntoLocation(CopyIntoLocationPlan),
@@ -184,11 +202,17 @@ pub enum Plan {
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
     UseDatabase(Box<UseDatabasePlan>),
+    BackupDatabase(Box<BackupDatabasePlan>),
+    RestoreDatabase(Box<RestoreDatabasePlan>),
+    ShowBackups(Box<ShowBackupsPlan>),
+    RestoreMetasrv(Box<RestoreMetasrvPlan>),
 
     // Tables
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlan>),
+    CreateRollupTable(Box<CreateRollupTablePlan>),
+    CloneTable(Box<CloneTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
@@ -259,11 +283,13 @@ pub enum Plan {
 
     // FileFormat
     CreateFileFormat(Box<CreateFileFormatPlan>),
+    AlterFileFormat(Box<AlterFileFormatPlan>),
     DropFileFormat(Box<DropFileFormatPlan>),
     ShowFileFormats(Box<ShowFileFormatsPlan>),
 
     // Stages
     CreateStage(Box<CreateStagePlan>),
+    AlterStage(Box<AlterStagePlan>),
     DropStage(Box<DropStagePlan>),
     RemoveStage(Box<RemoveStagePlan>),
 
@@ -280,6 +306,11 @@ pub enum Plan {
     SetVariable(Box<SettingPlan>),
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
+    ResetMemoryPeak,
+
+    // Transaction
+    Begin,
+    Commit,
 
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
@@ -307,6 +338,12 @@ pub enum Plan {
     DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
     ShowNetworkPolicies(Box<ShowNetworkPoliciesPlan>),
 
+    // Notification integration
+    CreateNotificationIntegration(Box<CreateNotificationIntegrationPlan>),
+    AlterNotificationIntegration(Box<AlterNotificationIntegrationPlan>),
+    DropNotificationIntegration(Box<DropNotificationIntegrationPlan>),
+    ShowNotificationIntegrations(Box<ShowNotificationIntegrationsPlan>),
+
     // Password policy
     CreatePasswordPolicy(Box<CreatePasswordPolicyPlan>),
     AlterPasswordPolicy(Box<AlterPasswordPolicyPlan>),
@@ -320,12 +357,22 @@ pub enum Plan {
     DescribeTask(Box<DescribeTaskPlan>),
     ShowTasks(Box<ShowTasksPlan>),
     ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Migration
+    ApplyMigration(Box<ApplyMigrationPlan>),
+    RollbackMigration(Box<RollbackMigrationPlan>),
+
+    // Query result cache
+    PurgeQueryCache(Box<PurgeQueryCachePlan>),
 }
 
 #[derive(Clone, Debug)]
 pub enum RewriteKind {
     ShowSettings,
     ShowMetrics,
+    ShowIoStats,
+    ShowIncompatibilities,
+    ShowStatus,
     ShowProcessList,
     ShowEngines,
     ShowIndexes,
@@ -337,6 +384,7 @@ pub enum RewriteKind {
     ShowTables(String, String),
     ShowColumns(String, String, String),
     ShowTablesStatus,
+    ShowSegments,
     ShowVirtualColumns,
 
     ShowStreams(String),
@@ -352,6 +400,9 @@ pub enum RewriteKind {
     ShowRoles,
     ShowPasswordPolicies,
 
+    ShowMigrations,
+    ShowQueryCache,
+
     Call,
 }
 
@@ -366,7 +417,8 @@ impl Plan {
             Plan::Explain { .. }
             | Plan::ExplainAnalyze { .. }
             | Plan::ExplainAst { .. }
-            | Plan::ExplainSyntax { .. } => QueryKind::Explain,
+            | Plan::ExplainSyntax { .. }
+            | Plan::RecommendIndex { .. } => QueryKind::Explain,
             Plan::Insert(_) => QueryKind::Insert,
             Plan::Replace(_)
             | Plan::Delete(_)
@@ -399,8 +451,17 @@ impl Plan {
             | Plan::ExplainAnalyze { .. } => {
                 DataSchemaRefExt::create(vec![DataField::new("explain", DataType::String)])
             }
+            Plan::RecommendIndex { .. } => DataSchemaRefExt::create(vec![
+                DataField::new("table", DataType::String),
+                DataField::new("index_type", DataType::String),
+                DataField::new("recommendation", DataType::String),
+                DataField::new("estimated_benefit", DataType::String),
+                DataField::new("reason", DataType::String),
+            ]),
             Plan::ShowCreateCatalog(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
+            Plan::BackupDatabase(plan) => plan.schema(),
+            Plan::ShowBackups(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
@@ -424,6 +485,7 @@ impl Plan {
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
+            Plan::ShowNotificationIntegrations(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
             Plan::CopyIntoTable(plan) => plan.schema(),
             Plan::CopyIntoLocation(plan) => plan.schema(),
@@ -451,7 +513,10 @@ impl Plan {
                 | Plan::ExplainAst { .. }
                 | Plan::ExplainSyntax { .. }
                 | Plan::ExplainAnalyze { .. }
+                | Plan::RecommendIndex { .. }
                 | Plan::ShowCreateDatabase(_)
+                | Plan::BackupDatabase(_)
+                | Plan::ShowBackups(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowCreateCatalog(_)
                 | Plan::ShowFileFormats(_)
@@ -469,6 +534,7 @@ impl Plan {
                 | Plan::DescDatamaskPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
+                | Plan::ShowNotificationIntegrations(_)
                 | Plan::DescPasswordPolicy(_)
                 | Plan::CopyIntoTable(_)
                 | Plan::CopyIntoLocation(_)
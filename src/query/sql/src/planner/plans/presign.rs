@@ -41,6 +41,7 @@ impl PresignPlan {
             DataField::new("method", DataType::String),
             DataField::new("headers", DataType::Variant),
             DataField::new("url", DataType::String),
+            DataField::new("expires_at", DataType::Timestamp),
         ])
     }
 }
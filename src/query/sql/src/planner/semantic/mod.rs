@@ -18,6 +18,8 @@ mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod snowflake_rewriter;
+mod trino_rewriter;
 mod type_check;
 mod udf_rewriter;
 mod view_rewriter;
@@ -35,6 +37,9 @@ pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use snowflake_rewriter::SnowflakeConstruct;
+pub use snowflake_rewriter::SnowflakeRewriter;
+pub use trino_rewriter::TrinoRewriter;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
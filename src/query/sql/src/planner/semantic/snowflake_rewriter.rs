@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperation;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::walk_set_expr_mut;
+use databend_common_ast::walk_table_reference_mut;
+use databend_common_ast::VisitorMut;
+
+/// A construct of the Snowflake dialect used by a statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnowflakeConstruct {
+    pub name: String,
+    /// How the behavior of Databend differs from Snowflake, `None` if it doesn't.
+    pub difference: Option<String>,
+}
+
+/// Rewrites the Snowflake functions to their Databend equivalents, and collects the
+/// Snowflake constructs used by the statement.
+#[derive(Debug, Clone, Default)]
+pub struct SnowflakeRewriter {
+    pub constructs: Vec<SnowflakeConstruct>,
+}
+
+impl SnowflakeRewriter {
+    fn record(&mut self, name: &str, difference: Option<&str>) {
+        if self.constructs.iter().all(|c| c.name != name) {
+            self.constructs.push(SnowflakeConstruct {
+                name: name.to_string(),
+                difference: difference.map(str::to_string),
+            });
+        }
+    }
+
+    /// The constructs whose behavior differs in Databend.
+    pub fn incompatibilities(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.constructs
+            .iter()
+            .filter_map(|c| Some((c.name.as_str(), c.difference.as_deref()?)))
+    }
+}
+
+impl VisitorMut for SnowflakeRewriter {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        // rewrite children
+        walk_expr_mut(self, expr);
+
+        match expr {
+            Expr::ColumnRef {
+                column: ColumnID::Position(_),
+                ..
+            } => self.record("$<n> positional column", None),
+            Expr::BinaryOp {
+                op: BinaryOperator::ILike | BinaryOperator::NotILike,
+                ..
+            } => self.record("ILIKE", None),
+            Expr::FunctionCall {
+                span,
+                name,
+                args,
+                params,
+                window: None,
+                lambda: None,
+                ..
+            } => match name.name.to_lowercase().as_str() {
+                "array_construct" => {
+                    self.record(
+                        "ARRAY_CONSTRUCT",
+                        Some(
+                            "builds an ARRAY of the common type of the elements instead of VARIANT",
+                        ),
+                    );
+                    *expr = Expr::Array {
+                        span: *span,
+                        exprs: std::mem::take(args),
+                    };
+                }
+                "object_construct" => {
+                    self.record("OBJECT_CONSTRUCT", None);
+                    *name = Identifier::from_name("json_object");
+                }
+                func @ ("try_to_number" | "try_to_numeric" | "try_to_decimal")
+                    if params.is_empty() =>
+                {
+                    let construct = func.to_uppercase();
+                    match args.as_slice() {
+                        [_] => {
+                            self.record(&construct, None);
+                            *params = vec![uint64_literal(38), uint64_literal(0)];
+                        }
+                        [
+                            _,
+                            precision @ Expr::Literal {
+                                lit: Literal::UInt64(_),
+                                ..
+                            },
+                            scale @ Expr::Literal {
+                                lit: Literal::UInt64(_),
+                                ..
+                            },
+                        ] => {
+                            self.record(&construct, None);
+                            *params = vec![precision.clone(), scale.clone()];
+                            args.truncate(1);
+                        }
+                        _ => {
+                            self.record(&construct, Some("the format argument is not supported"));
+                            return;
+                        }
+                    }
+                    *name = Identifier::from_name("try_to_decimal");
+                }
+                "try_to_double" => {
+                    self.record("TRY_TO_DOUBLE", None);
+                    *name = Identifier::from_name("try_to_float64");
+                }
+                func @ ("try_to_date" | "try_to_timestamp" | "try_to_boolean"
+                | "try_parse_json") => self.record(&func.to_uppercase(), None),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn visit_set_expr(&mut self, expr: &mut SetExpr) {
+        if let SetExpr::Select(select) = expr {
+            if select.qualify.is_some() {
+                self.record("QUALIFY", None);
+            }
+        }
+        walk_set_expr_mut(self, expr);
+    }
+
+    fn visit_set_operation(&mut self, op: &mut SetOperation) {
+        let SetOperation { left, right, .. } = op;
+
+        self.visit_set_expr(left);
+        self.visit_set_expr(right);
+    }
+
+    fn visit_table_reference(&mut self, table: &mut TableReference) {
+        if let TableReference::TableFunction { name, .. } = table {
+            if name.name.eq_ignore_ascii_case("flatten") {
+                self.record("FLATTEN", None);
+            }
+        }
+        walk_table_reference_mut(self, table);
+    }
+}
+
+fn uint64_literal(value: u64) -> Expr {
+    Expr::Literal {
+        span: None,
+        lit: Literal::UInt64(value),
+    }
+}
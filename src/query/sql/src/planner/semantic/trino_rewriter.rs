@@ -0,0 +1,242 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::IntervalKind;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::VisitorMut;
+
+/// The Trino functions that are named differently in Databend, and take the same arguments.
+const RENAMED_FUNCTIONS: &[(&str, &str)] = &[
+    ("approx_distinct", "approx_count_distinct"),
+    ("arbitrary", "any"),
+    ("array_join", "array_to_string"),
+    ("array_position", "array_indexof"),
+    ("bitwise_and", "bit_and"),
+    ("bitwise_not", "bit_not"),
+    ("bitwise_or", "bit_or"),
+    ("bitwise_xor", "bit_xor"),
+    ("cardinality", "array_length"),
+    ("chr", "char"),
+    ("codepoint", "ord"),
+    ("date_parse", "to_timestamp"),
+    ("day", "to_day_of_month"),
+    ("day_of_month", "to_day_of_month"),
+    ("day_of_week", "to_day_of_week"),
+    ("day_of_year", "to_day_of_year"),
+    ("dow", "to_day_of_week"),
+    ("doy", "to_day_of_year"),
+    ("element_at", "get"),
+    ("from_unixtime", "to_timestamp"),
+    ("hour", "to_hour"),
+    ("json_extract", "json_path_query_first"),
+    ("json_format", "json_to_string"),
+    ("json_parse", "parse_json"),
+    ("max_by", "arg_max"),
+    ("min_by", "arg_min"),
+    ("minute", "to_minute"),
+    ("month", "to_month"),
+    ("quarter", "to_quarter"),
+    ("random", "rand"),
+    ("regexp_extract", "regexp_substr"),
+    ("second", "to_second"),
+    ("strpos", "instr"),
+    ("to_unixtime", "to_unix_timestamp"),
+    ("week", "to_week_of_year"),
+    ("week_of_year", "to_week_of_year"),
+    ("year", "to_year"),
+];
+
+/// Rewrites the Trino functions and syntax variants to their Databend equivalents:
+///
+/// - The functions named differently in Databend are renamed, see `RENAMED_FUNCTIONS`.
+/// - `date_add('<unit>', n, d)` and `date_trunc('<unit>', d)` take the unit as a string.
+/// - `approx_percentile(x, p)` takes the percentage as an argument.
+/// - `INTERVAL '<n>' <unit>` takes the quantity as a string.
+/// - `d + INTERVAL ...` and `d - INTERVAL ...` add to and subtract from a date.
+/// - `current_date` is a function called without the parentheses.
+#[derive(Debug, Clone, Default)]
+pub struct TrinoRewriter {
+    /// The Trino functions and syntax rewritten, with their Databend equivalents.
+    pub rewritten: Vec<(String, String)>,
+}
+
+impl TrinoRewriter {
+    fn record(&mut self, trino: &str, databend: &str) {
+        if self.rewritten.iter().all(|(t, _)| t != trino) {
+            self.rewritten
+                .push((trino.to_string(), databend.to_string()));
+        }
+    }
+
+    fn rewrite_function_call(&mut self, expr: &mut Expr) -> Option<Expr> {
+        let Expr::FunctionCall {
+            span,
+            distinct,
+            name,
+            args,
+            params,
+            window,
+            lambda: None,
+        } = expr
+        else {
+            return None;
+        };
+        let func = name.name.to_lowercase();
+        match (func.as_str(), args.as_slice()) {
+            ("date_add", [unit, interval, date]) if !*distinct && window.is_none() => {
+                let unit = string_interval_kind(unit)?;
+                self.record("date_add", "DATE_ADD");
+                Some(Expr::DateAdd {
+                    span: *span,
+                    unit,
+                    interval: Box::new(interval.clone()),
+                    date: Box::new(date.clone()),
+                })
+            }
+            ("date_trunc", [unit, date]) if !*distinct && window.is_none() => {
+                let unit = string_interval_kind(unit)?;
+                self.record("date_trunc", "DATE_TRUNC");
+                Some(Expr::DateTrunc {
+                    span: *span,
+                    unit,
+                    date: Box::new(date.clone()),
+                })
+            }
+            ("approx_percentile", [_, _]) if params.is_empty() => {
+                self.record("approx_percentile", "quantile_tdigest");
+                *params = vec![args.pop()?];
+                *name = Identifier::from_name("quantile_tdigest");
+                None
+            }
+            (func, _) => {
+                let (trino, databend) = RENAMED_FUNCTIONS.iter().find(|(t, _)| *t == func)?;
+                self.record(trino, databend);
+                *name = Identifier::from_name(*databend);
+                None
+            }
+        }
+    }
+}
+
+impl VisitorMut for TrinoRewriter {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        // rewrite children
+        walk_expr_mut(self, expr);
+
+        let new_expr = match expr {
+            Expr::FunctionCall { .. } => self.rewrite_function_call(expr),
+            Expr::Interval { expr, .. } => {
+                if let Expr::Literal {
+                    span,
+                    lit: Literal::String(quantity),
+                } = expr.as_mut()
+                {
+                    if let Ok(quantity) = quantity.trim().parse::<u64>() {
+                        self.record("INTERVAL '<n>'", "INTERVAL <n>");
+                        **expr = Expr::Literal {
+                            span: *span,
+                            lit: Literal::UInt64(quantity),
+                        };
+                    }
+                }
+                None
+            }
+            Expr::BinaryOp {
+                span,
+                op: op @ (BinaryOperator::Plus | BinaryOperator::Minus),
+                left,
+                right,
+            } => match (left.as_ref(), right.as_ref()) {
+                (date, Expr::Interval { expr, unit, .. }) => {
+                    if *op == BinaryOperator::Plus {
+                        self.record("+ INTERVAL", "DATE_ADD");
+                        Some(Expr::DateAdd {
+                            span: *span,
+                            unit: *unit,
+                            interval: expr.clone(),
+                            date: Box::new(date.clone()),
+                        })
+                    } else {
+                        self.record("- INTERVAL", "DATE_SUB");
+                        Some(Expr::DateSub {
+                            span: *span,
+                            unit: *unit,
+                            interval: expr.clone(),
+                            date: Box::new(date.clone()),
+                        })
+                    }
+                }
+                (Expr::Interval { expr, unit, .. }, date) if *op == BinaryOperator::Plus => {
+                    self.record("+ INTERVAL", "DATE_ADD");
+                    Some(Expr::DateAdd {
+                        span: *span,
+                        unit: *unit,
+                        interval: expr.clone(),
+                        date: Box::new(date.clone()),
+                    })
+                }
+                _ => None,
+            },
+            Expr::ColumnRef {
+                span,
+                database: None,
+                table: None,
+                column: ColumnID::Name(column),
+            } if column.name.eq_ignore_ascii_case("current_date") && column.quote.is_none() => {
+                self.record("current_date", "today()");
+                Some(Expr::FunctionCall {
+                    span: *span,
+                    distinct: false,
+                    name: Identifier::from_name("today"),
+                    args: vec![],
+                    params: vec![],
+                    window: None,
+                    lambda: None,
+                })
+            }
+            _ => None,
+        };
+
+        if let Some(new_expr) = new_expr {
+            *expr = new_expr;
+        }
+    }
+}
+
+/// Parses the unit passed to a Trino date function as a string, e.g. `'day'`.
+fn string_interval_kind(unit: &Expr) -> Option<IntervalKind> {
+    let Expr::Literal {
+        lit: Literal::String(unit),
+        ..
+    } = unit
+    else {
+        return None;
+    };
+    match unit.to_lowercase().as_str() {
+        "year" => Some(IntervalKind::Year),
+        "quarter" => Some(IntervalKind::Quarter),
+        "month" => Some(IntervalKind::Month),
+        "week" => Some(IntervalKind::Week),
+        "day" => Some(IntervalKind::Day),
+        "hour" => Some(IntervalKind::Hour),
+        "minute" => Some(IntervalKind::Minute),
+        "second" => Some(IntervalKind::Second),
+        _ => None,
+    }
+}
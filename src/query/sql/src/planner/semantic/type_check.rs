@@ -2095,9 +2095,13 @@ impl<'a> TypeChecker<'a> {
         right: &Expr,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
         match op {
-            BinaryOperator::NotLike | BinaryOperator::NotRegexp | BinaryOperator::NotRLike => {
+            BinaryOperator::NotLike
+            | BinaryOperator::NotILike
+            | BinaryOperator::NotRegexp
+            | BinaryOperator::NotRLike => {
                 let positive_op = match op {
                     BinaryOperator::NotLike => BinaryOperator::Like,
+                    BinaryOperator::NotILike => BinaryOperator::ILike,
                     BinaryOperator::NotRegexp => BinaryOperator::Regexp,
                     BinaryOperator::NotRLike => BinaryOperator::RLike,
                     _ => unreachable!(),
@@ -2107,6 +2111,23 @@ impl<'a> TypeChecker<'a> {
                     .await?;
                 self.resolve_scalar_function_call(span, "not", vec![], vec![positive])
             }
+            BinaryOperator::ILike => {
+                // rewrite "expr1 ILIKE expr2" to "LOWER(expr1) LIKE LOWER(expr2)"
+                let box (left, _) = self.resolve(left).await?;
+                let box (right, _) = self.resolve(right).await?;
+
+                let (left, _) =
+                    *self.resolve_scalar_function_call(span, "lower", vec![], vec![left])?;
+                let (right, _) =
+                    *self.resolve_scalar_function_call(span, "lower", vec![], vec![right])?;
+
+                self.resolve_scalar_function_call(
+                    span,
+                    &BinaryOperator::Like.to_func_name(),
+                    vec![],
+                    vec![left, right],
+                )
+            }
             BinaryOperator::SoundsLike => {
                 // rewrite "expr1 SOUNDS LIKE expr2" to "SOUNDEX(expr1) = SOUNDEX(expr2)"
                 let box (left, _) = self.resolve(left).await?;
@@ -3827,7 +3848,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
             })?)
         }
         TypeName::Binary => TableDataType::Binary,
-        TypeName::String => TableDataType::String,
+        TypeName::String | TypeName::Varchar(_) => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
         TypeName::Array(item_type) => {
@@ -188,6 +188,44 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    preferred_region: "".to_string(),
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "s3_with_preferred_region",
+            UriLocation::new(
+                "s3".to_string(),
+                "test".to_string(),
+                "/tmp/".to_string(),
+                "".to_string(),
+                [
+                    ("access_key_id", "access_key_id"),
+                    ("secret_access_key", "secret_access_key"),
+                    ("session_token", "session_token"),
+                    ("region", "us-east-2"),
+                    ("preferred_s3_region", "us-west-2"),
+                ]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
+            ),
+            (
+                StorageParams::S3(StorageS3Config {
+                    endpoint_url: STORAGE_S3_DEFAULT_ENDPOINT.to_string(),
+                    region: "us-east-2".to_string(),
+                    bucket: "test".to_string(),
+                    access_key_id: "access_key_id".to_string(),
+                    secret_access_key: "secret_access_key".to_string(),
+                    security_token: "session_token".to_string(),
+                    master_key: "".to_string(),
+                    root: "/tmp/".to_string(),
+                    disable_credential_loader: true,
+                    enable_virtual_host_style: false,
+                    role_arn: "".to_string(),
+                    external_id: "".to_string(),
+                    preferred_region: "us-west-2".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -223,6 +261,7 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    preferred_region: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -258,6 +297,7 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    preferred_region: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -288,6 +328,7 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "aws::iam::xxxx".to_string(),
                     external_id: "".to_string(),
+                    preferred_region: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -423,3 +464,118 @@ async fn test_parse_uri_location() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_parse_uri_location_credentials_from() -> Result<()> {
+    let thread_name = std::thread::current()
+        .name()
+        .map(ToString::to_string)
+        .expect("thread should has a name");
+
+    GlobalInstance::init_testing(&thread_name);
+    let mut conf = InnerConfig::default();
+    conf.storage.allow_credential_chain = true;
+    GlobalConfig::init(&conf)?;
+
+    let s3_location = |conns: &[(&str, &str)]| {
+        UriLocation::new(
+            "s3".to_string(),
+            "test".to_string(),
+            "/tmp/".to_string(),
+            "".to_string(),
+            conns
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
+        )
+    };
+    let s3_params = |role_arn: &str| {
+        StorageParams::S3(StorageS3Config {
+            endpoint_url: STORAGE_S3_DEFAULT_ENDPOINT.to_string(),
+            region: "".to_string(),
+            bucket: "test".to_string(),
+            access_key_id: "".to_string(),
+            secret_access_key: "".to_string(),
+            security_token: "".to_string(),
+            master_key: "".to_string(),
+            root: "/tmp/".to_string(),
+            disable_credential_loader: false,
+            enable_virtual_host_style: false,
+            role_arn: role_arn.to_string(),
+            external_id: "".to_string(),
+            preferred_region: "".to_string(),
+        })
+    };
+
+    let cases = vec![
+        (
+            "credentials from env",
+            s3_location(&[("credentials_from", "env")]),
+            (s3_params(""), "/".to_string()),
+        ),
+        (
+            "credentials from aws role",
+            s3_location(&[
+                ("credentials_from", "AWS_ROLE"),
+                ("role_arn", "arn:aws:iam::123456789012:role/databend"),
+            ]),
+            (
+                s3_params("arn:aws:iam::123456789012:role/databend"),
+                "/".to_string(),
+            ),
+        ),
+    ];
+
+    for (name, mut input, expected) in cases {
+        let actual = parse_uri_location(&mut input, None).await?;
+        assert_eq!(expected, actual, "{}", name);
+    }
+
+    let cases = vec![
+        (
+            "aws role without role_arn",
+            s3_location(&[("credentials_from", "AWS_ROLE")]),
+            "role_arn is required when credentials_from is 'AWS_ROLE'",
+        ),
+        (
+            "unknown credentials_from",
+            s3_location(&[("credentials_from", "FILE")]),
+            "value for credentials_from is invalid: FILE, expected 'ENV' or 'AWS_ROLE'",
+        ),
+    ];
+
+    for (name, mut input, expected) in cases {
+        let err = parse_uri_location(&mut input, None).await.unwrap_err();
+        assert!(err.to_string().contains(expected), "{}: {}", name, err);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parse_uri_location_credential_chain_not_allowed() -> Result<()> {
+    let thread_name = std::thread::current()
+        .name()
+        .map(ToString::to_string)
+        .expect("thread should has a name");
+
+    GlobalInstance::init_testing(&thread_name);
+    GlobalConfig::init(&InnerConfig::default())?;
+
+    let mut input = UriLocation::new(
+        "s3".to_string(),
+        "test".to_string(),
+        "/tmp/".to_string(),
+        "".to_string(),
+        BTreeMap::from([("credentials_from".to_string(), "ENV".to_string())]),
+    );
+    let err = parse_uri_location(&mut input, None).await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("please enable storage.allow_credential_chain"),
+        "{}",
+        err
+    );
+
+    Ok(())
+}
@@ -16,6 +16,8 @@ use std::hash::BuildHasher;
 use std::hash::Hash;
 use std::sync::Arc;
 
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageGuard;
 use databend_common_cache::Count;
 use databend_common_cache::CountableMeter;
 use databend_common_cache::DefaultHashBuilder;
@@ -47,6 +49,7 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            memory_usage: None,
         }
     }
 }
@@ -58,6 +61,7 @@ impl<T> Named for T where T: Sized + Clone {}
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    memory_usage: Option<Arc<MemoryUsageGuard>>,
 }
 
 impl<C> NamedCache<C> {
@@ -65,6 +69,26 @@ impl<C> NamedCache<C> {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Reports the size of the cache as the memory usage of the component, the size of the
+    /// cache must be metered by bytes.
+    pub fn with_memory_component(mut self, component: MemoryComponent) -> Self {
+        self.memory_usage = Some(Arc::new(MemoryUsageGuard::create(component)));
+        self
+    }
+
+    fn report_memory_usage<K, V, S, M>(&self)
+    where
+        C: CacheAccessor<K, V, S, M>,
+        K: Eq + Hash,
+        S: BuildHasher,
+        M: CountableMeter<K, Arc<V>>,
+    {
+        if let Some(memory_usage) = &self.memory_usage {
+            let size = self.cache.size() as usize;
+            memory_usage.update(size, size);
+        }
+    }
 }
 
 impl<K, V, S, M, C> CacheAccessor<K, V, S, M> for NamedCache<C>
@@ -89,11 +113,14 @@ where
     }
 
     fn put(&self, key: K, value: Arc<V>) {
-        self.cache.put(key, value)
+        self.cache.put(key, value);
+        self.report_memory_usage::<K, V, S, M>();
     }
 
     fn evict(&self, k: &str) -> bool {
-        self.cache.evict(k)
+        let evicted = self.cache.evict(k);
+        self.report_memory_usage::<K, V, S, M>();
+        evicted
     }
 
     fn size(&self) -> u64 {
@@ -16,6 +16,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemoryComponent;
 use databend_common_cache::CountableMeter;
 use databend_common_cache::DefaultHashBuilder;
 use databend_common_config::CacheConfig;
@@ -34,6 +35,7 @@ use crate::caches::BloomIndexMetaCache;
 use crate::caches::ColumnArrayCache;
 use crate::caches::CompactSegmentInfoCache;
 use crate::caches::FileMetaDataCache;
+use crate::caches::ParquetMetaDataCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 use crate::BloomIndexFilterMeter;
@@ -52,6 +54,7 @@ pub struct CacheManager {
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
     prune_partitions_cache: Option<PrunePartitionsCache>,
     file_meta_data_cache: Option<FileMetaDataCache>,
+    parquet_meta_data_cache: Option<ParquetMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
     table_column_array_cache: Option<ColumnArrayCache>,
 }
@@ -109,6 +112,7 @@ impl CacheManager {
                 bloom_index_meta_cache: None,
                 prune_partitions_cache: None,
                 file_meta_data_cache: None,
+                parquet_meta_data_cache: None,
                 table_statistic_cache: None,
                 table_data_cache,
                 table_column_array_cache,
@@ -122,12 +126,14 @@ impl CacheManager {
                 config.table_meta_segment_bytes,
                 CompactSegmentInfoMeter {},
                 "segment_info",
-            );
+            )
+            .map(|cache| cache.with_memory_component(MemoryComponent::SegmentCache));
             let bloom_index_filter_cache = Self::new_in_memory_cache(
                 config.table_bloom_index_filter_size,
                 BloomIndexFilterMeter {},
                 "bloom_index_filter",
-            );
+            )
+            .map(|cache| cache.with_memory_component(MemoryComponent::MetaCache));
             let bloom_index_meta_cache = Self::new_item_cache(
                 config.table_bloom_index_meta_count,
                 "bloom_index_file_meta_data",
@@ -137,6 +143,8 @@ impl CacheManager {
 
             let file_meta_data_cache =
                 Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS, "parquet_file_meta");
+            let parquet_meta_data_cache =
+                Self::new_item_cache(config.parquet_file_meta_count, "parquet_meta_data");
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
                 segment_info_cache,
@@ -144,6 +152,7 @@ impl CacheManager {
                 bloom_index_meta_cache,
                 prune_partitions_cache,
                 file_meta_data_cache,
+                parquet_meta_data_cache,
                 table_statistic_cache,
                 table_data_cache,
                 table_column_array_cache,
@@ -185,6 +194,10 @@ impl CacheManager {
         self.file_meta_data_cache.clone()
     }
 
+    pub fn get_parquet_meta_data_cache(&self) -> Option<ParquetMetaDataCache> {
+        self.parquet_meta_data_cache.clone()
+    }
+
     pub fn get_table_data_cache(&self) -> Option<TableDataCache> {
         self.table_data_cache.clone()
     }
@@ -32,6 +32,7 @@ use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
+use parquet::file::metadata::ParquetMetaData;
 
 use crate::cache_manager::CacheManager;
 
@@ -52,6 +53,9 @@ pub type BloomIndexFilterCache =
 pub type BloomIndexMetaCache = NamedCache<InMemoryItemCacheHolder<BloomIndexMeta>>;
 /// In memory object cache of parquet FileMetaData of external parquet files
 pub type FileMetaDataCache = NamedCache<InMemoryItemCacheHolder<FileMetaData>>;
+/// In memory object cache of the meta data of the files of parquet tables, with the size of
+/// the file the meta data is read from.
+pub type ParquetMetaDataCache = NamedCache<InMemoryItemCacheHolder<(u64, Arc<ParquetMetaData>)>>;
 
 pub type PrunePartitionsCache = NamedCache<InMemoryItemCacheHolder<(PartStatistics, Partitions)>>;
 
@@ -136,6 +140,13 @@ impl CachedObject<FileMetaData> for FileMetaData {
     }
 }
 
+impl CachedObject<(u64, Arc<ParquetMetaData>)> for (u64, Arc<ParquetMetaData>) {
+    type Cache = ParquetMetaDataCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_parquet_meta_data_cache()
+    }
+}
+
 pub struct ColumnArrayMeter;
 
 impl<K, V> Meter<K, Arc<(V, usize)>> for ColumnArrayMeter {
@@ -24,6 +24,22 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 
+// Rollup table options.
+// The aggregation query of a rollup table, which is re-run over the newly inserted segments of the source table.
+pub const OPT_KEY_ROLLUP_QUERY: &str = "rollup_query";
+// The comma separated `<database>.<table>` list of rollup tables maintained from the source table.
+pub const OPT_KEY_ROLLUP_TABLES: &str = "rollup_tables";
+// The error of the last refresh of a rollup table, which is out of sync with its source table until it is rebuilt.
+pub const OPT_KEY_ROLLUP_REFRESH_ERROR: &str = "rollup_refresh_error";
+// If the rollup tables are rebuilt before the statements modifying the source table return,
+// instead of in the background. It is set on the source table.
+pub const OPT_KEY_ROLLUP_SYNC_REBUILD: &str = "rollup_sync_rebuild";
+
+// Cloned table options.
+// The comma separated snapshot locations of the table which have been cloned by `CLONE TABLE`,
+// the files referenced by them are not purged since the clones share them.
+pub const OPT_KEY_CLONED_SNAPSHOT_LOCATIONS: &str = "cloned_snapshot_locations";
+
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
@@ -53,6 +69,10 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_ROLLUP_QUERY);
+    r.insert(OPT_KEY_ROLLUP_TABLES);
+    r.insert(OPT_KEY_ROLLUP_REFRESH_ERROR);
+    r.insert(OPT_KEY_CLONED_SNAPSHOT_LOCATIONS);
     r
 });
 
@@ -62,6 +82,9 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
+    r.insert(OPT_KEY_ROLLUP_QUERY);
+    r.insert(OPT_KEY_ROLLUP_TABLES);
+    r.insert(OPT_KEY_CLONED_SNAPSHOT_LOCATIONS);
     r
 });
 
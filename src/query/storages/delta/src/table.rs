@@ -303,6 +303,7 @@ impl DeltaTable {
                             ParquetFilesPart {
                             files: vec![(add.path.clone(), add.size as u64)],
                             estimated_uncompressed_size: add.size as u64, // This field is not used here.
+                            etags: vec![],
                         },
                     )}) as Box<dyn PartInfo>
                 ))
@@ -41,6 +41,7 @@ pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
 pub use write::serialize_block;
 pub use write::write_data;
+pub use write::write_data_in_parts;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use chrono::Utc;
 use databend_common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use databend_common_arrow::native::write::NativeWriter;
@@ -111,6 +112,35 @@ pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str)
     Ok(())
 }
 
+/// Write the data with a multipart upload if it is larger than `multipart_threshold`.
+/// The data is uploaded part by part of `part_size`, so that the object is not limited
+/// by the max size of a single PUT of S3.
+#[async_backtrace::framed]
+pub async fn write_data_in_parts(
+    data: Vec<u8>,
+    data_accessor: &Operator,
+    location: &str,
+    multipart_threshold: usize,
+    part_size: usize,
+) -> Result<()> {
+    if data.len() <= multipart_threshold || part_size == 0 {
+        return write_data(data, data_accessor, location).await;
+    }
+
+    let data = Bytes::from(data);
+    let mut writer = data_accessor
+        .writer_with(location)
+        .buffer(part_size)
+        .await?;
+    for offset in (0..data.len()).step_by(part_size) {
+        let end = std::cmp::min(offset + part_size, data.len());
+        writer.write(data.slice(offset..end)).await?;
+    }
+    writer.close().await?;
+
+    Ok(())
+}
+
 pub struct BloomIndexState {
     pub(crate) data: Vec<u8>,
     pub(crate) size: u64,
@@ -19,6 +19,7 @@ mod write_settings;
 
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
+pub use block_writer::write_data_in_parts;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
 pub use meta_writer::CachedMetaWriter;
@@ -20,6 +20,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -33,6 +34,17 @@ use crate::FuseTable;
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
+        self.do_analyze_with_ndvs(ctx, HashMap::new()).await
+    }
+
+    /// Analyzes the table, the NDVs in `column_ndvs` are computed by reading the data
+    /// and replace the ones estimated from the block statistics.
+    #[async_backtrace::framed]
+    pub async fn do_analyze_with_ndvs(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        column_ndvs: HashMap<ColumnId, u64>,
+    ) -> Result<()> {
         // 1. Read table snapshot.
         let r = self.read_table_snapshot().await;
         let snapshot_opt = match r {
@@ -126,6 +138,7 @@ impl FuseTable {
                 let density_avg = *sum / block_count_sum as f64;
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
+            ndv_map.extend(column_ndvs);
 
             // 3. Generate new table statistics
             let table_statistics = TableSnapshotStatistics::new(ndv_map);
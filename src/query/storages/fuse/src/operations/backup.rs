@@ -0,0 +1,341 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableMeta;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::meta::Versioned;
+use log::info;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::MetaWriter;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// The directory of a backup location where the files of the tables are copied to.
+///
+/// The files keep their paths relative to the root of the table storage, so the
+/// files of the backups of the same table taken to one location are shared.
+pub const BACKUP_DATA_DIR: &str = "data";
+
+/// The directory of a backup location where the manifests of the backups are kept.
+pub const BACKUP_MANIFEST_DIR: &str = "_backups";
+
+/// BackupManifest describes a backup of a database.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupManifest {
+    pub backup_id: String,
+    pub database: String,
+    /// The backup that this incremental backup is taken on.
+    pub since: Option<String>,
+    pub created_on: DateTime<Utc>,
+    pub tables: Vec<TableBackup>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableBackup {
+    pub name: String,
+    pub meta: TableMeta,
+    /// The storage prefix of the table, which all the copied files start with.
+    pub storage_prefix: String,
+    /// The snapshot of the table, `None` if the table has no data.
+    pub snapshot_location: Option<String>,
+}
+
+impl BackupManifest {
+    pub fn new_backup_id() -> String {
+        Uuid::new_v4().simple().to_string()
+    }
+
+    fn location(backup_id: &str) -> String {
+        format!("{BACKUP_MANIFEST_DIR}/{backup_id}.json")
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(&self, operator: &Operator) -> Result<()> {
+        let data = serde_json::to_vec(self)?;
+        operator
+            .write(&Self::location(&self.backup_id), data)
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(operator: &Operator, backup_id: &str) -> Result<BackupManifest> {
+        let location = Self::location(backup_id);
+        if !operator.is_exist(&location).await? {
+            return Err(ErrorCode::StorageNotFound(format!(
+                "Backup {backup_id} not found"
+            )));
+        }
+        let data = operator.read(&location).await?;
+        Ok(serde_json::from_slice(&data)?)
+    }
+
+    /// List the backups of the location, ordered by the creation time.
+    #[async_backtrace::framed]
+    pub async fn list(operator: &Operator) -> Result<Vec<BackupManifest>> {
+        let mut manifests = vec![];
+        for entry in operator.list(&format!("{BACKUP_MANIFEST_DIR}/")).await? {
+            if let Some(backup_id) = entry.name().strip_suffix(".json") {
+                manifests.push(Self::read(operator, backup_id).await?);
+            }
+        }
+        manifests.sort_by_key(|m| m.created_on);
+        Ok(manifests)
+    }
+}
+
+fn backup_path(path: &str) -> String {
+    format!("{BACKUP_DATA_DIR}/{path}")
+}
+
+fn rebase(path: &str, from: &str, to: &str) -> Result<String> {
+    match path.strip_prefix(&format!("{from}/")) {
+        Some(rest) => Ok(format!("{to}/{rest}")),
+        None => Err(ErrorCode::StorageOther(format!(
+            "file {path} of the backup is not under the storage prefix {from}"
+        ))),
+    }
+}
+
+#[async_backtrace::framed]
+async fn copy_file(from: Operator, from_path: String, to: Operator, to_path: String) -> Result<()> {
+    let data = from.read(&from_path).await?;
+    to.write(&to_path, data).await?;
+    Ok(())
+}
+
+impl FuseTable {
+    /// Copy the files of the current snapshot of the table to `backup_operator`.
+    ///
+    /// The segments of the `since` snapshot, which is a snapshot of a previous backup of the
+    /// table, and the files kept in the backup location already are not copied again.
+    ///
+    /// Returns the location of the snapshot and the number of the copied files, or `None`
+    /// if the table has no snapshot.
+    #[async_backtrace::framed]
+    pub async fn do_backup(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        backup_operator: &Operator,
+        since: Option<&str>,
+    ) -> Result<Option<(String, u64)>> {
+        let Some(snapshot_location) = self.snapshot_loc().await? else {
+            return Ok(None);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+
+        let copied_segments = match since {
+            Some(since) => {
+                let since = read_backup_snapshot(backup_operator, since).await?;
+                since
+                    .segments
+                    .iter()
+                    .map(|(path, _)| path.clone())
+                    .collect::<HashSet<_>>()
+            }
+            None => HashSet::new(),
+        };
+        let new_segments = snapshot
+            .segments
+            .iter()
+            .filter(|(path, _)| !copied_segments.contains(path))
+            .cloned()
+            .collect::<Vec<Location>>();
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let mut files = vec![snapshot_location.clone()];
+        for (segment, (path, _)) in segments_io
+            .read_segments::<SegmentInfo>(&new_segments, false)
+            .await?
+            .into_iter()
+            .zip(new_segments.iter())
+        {
+            for block in segment?.blocks.iter() {
+                files.push(block.location.0.clone());
+                if let Some((index, _)) = &block.bloom_filter_index_location {
+                    files.push(index.clone());
+                }
+            }
+            files.push(path.clone());
+        }
+
+        // The files are immutable, so the one that exists in the backup is the same.
+        let mut tasks = Vec::with_capacity(files.len());
+        for path in files {
+            if backup_operator.is_exist(&backup_path(&path)).await? {
+                continue;
+            }
+            tasks.push(copy_file(
+                self.operator.clone(),
+                path.clone(),
+                backup_operator.clone(),
+                backup_path(&path),
+            ));
+        }
+        let copied = tasks.len() as u64;
+        let threads_nums = ctx.get_settings().get_max_threads()? as usize;
+        execute_futures_in_parallel(
+            tasks,
+            threads_nums,
+            threads_nums * 2,
+            "fuse-backup-worker".to_owned(),
+        )
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+
+        info!(
+            "backup table {} at snapshot {}, {} files copied",
+            self.table_info.desc, snapshot_location, copied
+        );
+        Ok(Some((snapshot_location, copied)))
+    }
+
+    /// Restore the data of a backup to the table, which is supposed to be empty.
+    ///
+    /// The files are copied to the storage of the table, the segments and the snapshot are
+    /// rewritten to refer to the copied files.
+    #[async_backtrace::framed]
+    pub async fn do_restore(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        backup_operator: &Operator,
+        table_backup: &TableBackup,
+    ) -> Result<()> {
+        let Some(snapshot_location) = &table_backup.snapshot_location else {
+            return Ok(());
+        };
+        let snapshot = read_backup_snapshot(backup_operator, snapshot_location).await?;
+        let from_prefix = &table_backup.storage_prefix;
+        let to_prefix = self.meta_location_generator.prefix();
+
+        let segment_locations = snapshot
+            .segments
+            .iter()
+            .map(|(path, ver)| (backup_path(path), *ver))
+            .collect::<Vec<_>>();
+        let segments_io = SegmentsIO::create(
+            ctx.clone(),
+            backup_operator.clone(),
+            Arc::new(snapshot.schema.clone()),
+        );
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&segment_locations, false)
+            .await?;
+
+        let mut tasks = vec![];
+        let mut new_segments = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let segment = segment?;
+            let mut blocks = Vec::with_capacity(segment.blocks.len());
+            for block in segment.blocks.iter() {
+                let mut block = BlockMeta::clone(block);
+                let location = rebase(&block.location.0, from_prefix, to_prefix)?;
+                tasks.push(copy_file(
+                    backup_operator.clone(),
+                    backup_path(&block.location.0),
+                    self.operator.clone(),
+                    location.clone(),
+                ));
+                block.location.0 = location;
+                if let Some((index, ver)) = &block.bloom_filter_index_location {
+                    let location = rebase(index, from_prefix, to_prefix)?;
+                    tasks.push(copy_file(
+                        backup_operator.clone(),
+                        backup_path(index),
+                        self.operator.clone(),
+                        location.clone(),
+                    ));
+                    block.bloom_filter_index_location = Some((location, *ver));
+                }
+                blocks.push(Arc::new(block));
+            }
+
+            let location = self.meta_location_generator.gen_segment_info_location();
+            SegmentInfo::new(blocks, segment.summary.clone())
+                .write_meta(&self.operator, &location)
+                .await?;
+            new_segments.push((location, SegmentInfo::VERSION));
+        }
+
+        let threads_nums = ctx.get_settings().get_max_threads()? as usize;
+        execute_futures_in_parallel(
+            tasks,
+            threads_nums,
+            threads_nums * 2,
+            "fuse-restore-worker".to_owned(),
+        )
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            snapshot.schema.clone(),
+            snapshot.summary.clone(),
+            new_segments,
+            snapshot.cluster_key_meta.clone(),
+            None,
+        );
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_backup_snapshot(
+    backup_operator: &Operator,
+    snapshot_location: &str,
+) -> Result<Arc<TableSnapshot>> {
+    let reader = MetaReaders::table_snapshot_reader(backup_operator.clone());
+    let params = LoadParams {
+        location: backup_path(snapshot_location),
+        len_hint: None,
+        ver: TableMetaLocationGenerator::snapshot_version(snapshot_location),
+        put_cache: false,
+    };
+    reader.read(&params).await
+}
@@ -214,6 +214,10 @@ impl FuseTable {
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
 
+        let committed_table_info = TableInfo {
+            meta: new_table_meta.clone(),
+            ..table_info.clone()
+        };
         let req = UpdateTableMetaReq {
             table_id,
             seq: MatchSeq::Exact(table_version),
@@ -224,6 +228,7 @@ impl FuseTable {
         };
 
         // 3. let's roll
+        ctx.check_table_commit(table_info).await?;
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -231,7 +236,7 @@ impl FuseTable {
                 // try keep a hit file of last snapshot
                 Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
                     .await;
-                Ok(())
+                ctx.on_table_committed(&committed_table_info)
             }
             Err(e) => {
                 // commit snapshot to meta server failed.
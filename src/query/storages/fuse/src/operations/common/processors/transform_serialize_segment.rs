@@ -36,6 +36,7 @@ use databend_storages_common_table_meta::meta::Versioned;
 use log::info;
 use opendal::Operator;
 
+use crate::io::write_data_in_parts;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::common::MutationLogEntry;
@@ -239,7 +240,15 @@ impl Processor for TransformSerializeSegment {
                 location,
                 segment,
             } => {
-                self.data_accessor.write(&location, data).await?;
+                let settings = self.ctx.get_settings();
+                write_data_in_parts(
+                    data,
+                    &self.data_accessor,
+                    &location,
+                    settings.get_s3_multipart_threshold_bytes()? as usize,
+                    settings.get_s3_part_size_bytes()? as usize,
+                )
+                .await?;
                 info!("fuse append wrote down segment {} ", location);
 
                 self.state = State::PreCommitSegment { location, segment };
@@ -31,6 +31,7 @@ use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONED_SNAPSHOT_LOCATIONS;
 use log::error;
 use log::warn;
 
@@ -166,6 +167,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &root_snapshot_info.protected_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -181,6 +183,7 @@ impl FuseTable {
                         ctx,
                         &mut counter,
                         &root_snapshot_info.referenced_locations,
+                        &root_snapshot_info.protected_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -221,6 +224,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &root_snapshot_info.protected_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -232,6 +236,7 @@ impl FuseTable {
                     ctx,
                     &mut counter,
                     &root_snapshot_info.referenced_locations,
+                    &root_snapshot_info.protected_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -252,6 +257,7 @@ impl FuseTable {
                 &mut counter,
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
+                &root_snapshot_info.protected_locations,
                 root_snapshot_info.snapshot_location,
                 &table_agg_index_ids,
             )
@@ -303,9 +309,11 @@ impl FuseTable {
             segments: HashSet::from_iter(root_snapshot.segments.clone()),
             table_statistics_location: root_snapshot.table_statistics_location.clone(),
         });
+        let protected_locations = self.read_protected_locations(ctx).await?;
         Ok(Some(RootSnapshotInfo {
             snapshot_location,
             referenced_locations,
+            protected_locations,
             snapshot_lite,
         }))
     }
@@ -316,6 +324,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        protected_locations: &ProtectedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -331,7 +340,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !self.is_purgeable(loc, &protected_locations.blocks)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -345,16 +356,33 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !self.is_purgeable(loc, &protected_locations.blooms)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| self.is_purgeable(&loc.0, &protected_locations.segments))
+                    .map(|loc| loc.0.clone()),
+            );
         }
-        purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
-        purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.extend(
+            ts_to_be_purged
+                .iter()
+                .filter(|loc| self.is_purgeable(loc, &protected_locations.table_statistics))
+                .map(|loc| loc.to_string()),
+        );
+        purge_files.extend(
+            snapshots_to_be_purged
+                .iter()
+                .filter(|loc| self.is_purgeable(loc, &protected_locations.snapshots))
+                .map(|loc| loc.to_string()),
+        );
 
         Ok(())
     }
@@ -365,6 +393,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        protected_locations: &ProtectedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -426,6 +455,7 @@ impl FuseTable {
             self.purge_block_segments(
                 ctx,
                 counter,
+                protected_locations,
                 blocks_to_be_purged,
                 agg_indexes_to_be_purged,
                 blooms_to_be_purged,
@@ -434,8 +464,14 @@ impl FuseTable {
             .await?;
         }
 
-        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
-            .await
+        self.purge_ts_snapshots(
+            ctx,
+            counter,
+            protected_locations,
+            ts_to_be_purged,
+            snapshots_to_be_purged,
+        )
+        .await
     }
 
     async fn purge_root_snapshot(
@@ -444,6 +480,7 @@ impl FuseTable {
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
+        protected_locations: &ProtectedLocations,
         root_snapshot_location: String,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
@@ -467,6 +504,7 @@ impl FuseTable {
         self.purge_block_segments(
             ctx,
             counter,
+            protected_locations,
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
             root_location_tuple.bloom_location,
@@ -481,6 +519,7 @@ impl FuseTable {
         self.purge_ts_snapshots(
             ctx,
             counter,
+            protected_locations,
             ts_to_be_purged,
             HashSet::from([root_snapshot_location]),
         )
@@ -491,11 +530,17 @@ impl FuseTable {
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
-        blocks_to_be_purged: HashSet<String>,
-        agg_indexes_to_be_purged: HashSet<String>,
-        blooms_to_be_purged: HashSet<String>,
-        segments_to_be_purged: HashSet<String>,
+        protected_locations: &ProtectedLocations,
+        mut blocks_to_be_purged: HashSet<String>,
+        mut agg_indexes_to_be_purged: HashSet<String>,
+        mut blooms_to_be_purged: HashSet<String>,
+        mut segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        blocks_to_be_purged.retain(|loc| self.is_purgeable(loc, &protected_locations.blocks));
+        agg_indexes_to_be_purged.retain(|loc| self.is_own_location(loc));
+        blooms_to_be_purged.retain(|loc| self.is_purgeable(loc, &protected_locations.blooms));
+        segments_to_be_purged.retain(|loc| self.is_purgeable(loc, &protected_locations.segments));
+
         // 1. Try to purge block file chunks.
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
@@ -539,9 +584,13 @@ impl FuseTable {
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
-        ts_to_be_purged: HashSet<String>,
-        snapshots_to_be_purged: HashSet<String>,
+        protected_locations: &ProtectedLocations,
+        mut ts_to_be_purged: HashSet<String>,
+        mut snapshots_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        ts_to_be_purged.retain(|loc| self.is_purgeable(loc, &protected_locations.table_statistics));
+        snapshots_to_be_purged.retain(|loc| self.is_purgeable(loc, &protected_locations.snapshots));
+
         // 3. Purge table statistic files
         let ts_count = ts_to_be_purged.len();
         if ts_count > 0 {
@@ -663,14 +712,88 @@ impl FuseTable {
         );
         SnapshotsIO::list_files(self.get_operator(), &prefix, None).await
     }
+
+    /// Whether the file is stored under the prefix of this table. A cloned table refers to the
+    /// files of the table it was cloned from, which must never be purged by the clone.
+    pub fn is_own_location(&self, location: &str) -> bool {
+        location.starts_with(&format!("{}/", self.meta_location_generator().prefix()))
+    }
+
+    fn is_purgeable(&self, location: &str, protected: &HashSet<String>) -> bool {
+        self.is_own_location(location) && !protected.contains(location)
+    }
+
+    /// Reads the files referenced by the snapshots of this table which have been cloned by
+    /// `CLONE TABLE`, they are shared with the clones and are not purged.
+    #[async_backtrace::framed]
+    pub async fn read_protected_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<ProtectedLocations> {
+        let mut protected = ProtectedLocations::default();
+        let Some(cloned) = self
+            .table_info
+            .options()
+            .get(OPT_KEY_CLONED_SNAPSHOT_LOCATIONS)
+        else {
+            return Ok(protected);
+        };
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut segments = HashSet::new();
+        for location in cloned.split(',').filter(|l| !l.is_empty()) {
+            let params = LoadParams {
+                location: location.to_string(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(location),
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "gc: cloned snapshot {} not found. table: {}, ident {}",
+                        location, self.table_info.desc, self.table_info.ident,
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            protected.snapshots.insert(location.to_string());
+            if let Some(ts) = &snapshot.table_statistics_location {
+                protected.table_statistics.insert(ts.clone());
+            }
+            segments.extend(snapshot.segments.iter().cloned());
+        }
+
+        let segments = Vec::from_iter(segments);
+        let locations = self
+            .get_block_locations(ctx.clone(), &segments, false, false)
+            .await?;
+        protected.segments = segments.into_iter().map(|loc| loc.0).collect();
+        protected.blocks = locations.block_location;
+        protected.blooms = locations.bloom_location;
+        Ok(protected)
+    }
 }
 
 struct RootSnapshotInfo {
     snapshot_location: String,
     referenced_locations: LocationTuple,
+    protected_locations: ProtectedLocations,
     snapshot_lite: Arc<SnapshotLiteExtended>,
 }
 
+/// The files of the table which are shared with the tables cloned from it.
+#[derive(Default)]
+pub struct ProtectedLocations {
+    pub snapshots: HashSet<String>,
+    pub table_statistics: HashSet<String>,
+    pub segments: HashSet<String>,
+    pub blocks: HashSet<String>,
+    pub blooms: HashSet<String>,
+}
+
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
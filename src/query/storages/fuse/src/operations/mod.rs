@@ -15,6 +15,7 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod backup;
 mod commit;
 pub mod common;
 mod compact;
@@ -35,6 +36,7 @@ mod truncate;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
+pub use backup::*;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
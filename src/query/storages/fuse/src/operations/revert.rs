@@ -17,6 +17,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 
@@ -47,6 +48,10 @@ impl FuseTable {
         let base_version = self.table_info.ident.seq;
         let catalog = ctx.get_catalog(&table_info.meta.catalog).await?;
         let table_id = table_info.ident.table_id;
+        let committed_table_info = TableInfo {
+            meta: table_meta_to_be_committed.clone(),
+            ..self.table_info.clone()
+        };
         let req = UpdateTableMetaReq {
             table_id,
             seq: MatchSeq::Exact(base_version),
@@ -57,6 +62,7 @@ impl FuseTable {
         };
 
         // 4. let's roll
+        ctx.check_table_commit(&self.table_info).await?;
         let reply = catalog.update_table_meta(&self.table_info, req).await;
         if reply.is_ok() {
             // try keep the snapshot hit
@@ -71,6 +77,7 @@ impl FuseTable {
             .await;
         };
 
-        reply.map(|_| ())
+        reply?;
+        ctx.on_table_committed(&committed_table_info)
     }
 }
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
@@ -69,9 +70,15 @@ impl FuseTable {
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
 
+            let committed_table_info = TableInfo {
+                meta: new_table_meta.clone(),
+                ..self.table_info.clone()
+            };
+
             // commit table meta to meta server.
             // `truncate_table` is not supposed to be retry-able, thus we use
             // `update_data_table_meta` directly.
+            ctx.check_table_commit(&self.table_info).await?;
             catalog
                 .update_table_meta(&self.table_info, UpdateTableMetaReq {
                     table_id,
@@ -82,6 +89,7 @@ impl FuseTable {
                     update_stream_meta: vec![],
                 })
                 .await?;
+            ctx.on_table_committed(&committed_table_info)?;
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq {
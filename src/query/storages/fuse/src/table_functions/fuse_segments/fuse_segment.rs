@@ -18,15 +18,19 @@ use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
+use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
+use futures_util::future::try_join_all;
 use futures_util::TryStreamExt;
 
 use crate::io::MetaReaders;
@@ -102,6 +106,8 @@ impl<'a> FuseSegment<'a> {
         let mut compressed: Vec<u64> = Vec::with_capacity(len);
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
         let mut file_location: Vec<String> = Vec::with_capacity(len);
+        let mut created_on: Vec<Option<i64>> = Vec::with_capacity(len);
+        let mut cluster_stats: Vec<Option<String>> = Vec::with_capacity(len);
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -117,15 +123,31 @@ impl<'a> FuseSegment<'a> {
             let segments = segments_io
                 .read_segments::<SegmentInfo>(chunk, true)
                 .await?;
-
-            for (idx, segment) in segments.into_iter().enumerate() {
+            // The segments do not record their creation time, take the time the files are
+            // last modified instead.
+            let stats = try_join_all(
+                chunk
+                    .iter()
+                    .map(|(location, _)| self.table.operator.stat(location)),
+            )
+            .await?;
+
+            for ((segment, location), stat) in segments.into_iter().zip(chunk).zip(stats) {
                 let segment = segment?;
-                format_versions.push(segment_locations[idx].1);
+                format_versions.push(location.1);
                 block_count.push(segment.summary.block_count);
                 row_count.push(segment.summary.row_count);
                 compressed.push(segment.summary.compressed_byte_size);
                 uncompressed.push(segment.summary.uncompressed_byte_size);
-                file_location.push(segment_locations[idx].0.clone());
+                file_location.push(location.0.clone());
+                created_on.push(stat.last_modified().map(|t| t.timestamp_micros()));
+                cluster_stats.push(
+                    segment
+                        .summary
+                        .cluster_stats
+                        .as_ref()
+                        .map(format_cluster_stats),
+                );
 
                 row_num += 1;
                 if row_num >= limit {
@@ -146,6 +168,8 @@ impl<'a> FuseSegment<'a> {
             UInt64Type::from_data(row_count),
             UInt64Type::from_data(uncompressed),
             UInt64Type::from_data(compressed),
+            TimestampType::from_opt_data(created_on),
+            StringType::from_opt_data(cluster_stats),
         ]))
     }
 
@@ -166,6 +190,31 @@ impl<'a> FuseSegment<'a> {
                 "bytes_compressed",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new(
+                "created_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "cluster_stats",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ])
     }
 }
+
+fn format_cluster_stats(stats: &ClusterStatistics) -> String {
+    let format_scalars = |scalars: &[Scalar]| {
+        scalars
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "{{cluster_key_id: {}, min: [{}], max: [{}], level: {}}}",
+        stats.cluster_key_id,
+        format_scalars(&stats.min),
+        format_scalars(&stats.max),
+        stats.level
+    )
+}
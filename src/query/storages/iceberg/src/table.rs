@@ -300,6 +300,7 @@ impl IcebergTable {
                                 ParquetFilesPart {
                                     files: vec![(location, v.file_size_in_bytes as u64)],
                                     estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
+                                    etags: vec![],
                                 },
                             ))) as Box<dyn PartInfo>,
                         ))
@@ -32,9 +32,10 @@ impl ColumnsTable {
             table AS table_name,
             name AS column_name,
             1 AS ordinal_position,
-            NULL AS column_default,
+            case when default_kind='DEFAULT' then default_expression
+            end AS column_default,
             comment AS column_comment,
-            NULL AS column_key,
+            '' AS column_key,
             case when is_nullable='NO' then 0
             when is_nullable='YES' then 1
             end as nullable,
@@ -58,7 +59,10 @@ impl ColumnsTable {
             NULL AS domain_name,
             NULL AS privileges,
             default_expression as default,
-            NULL AS extra
+            case when default_kind='VIRTUAL' then 'VIRTUAL GENERATED'
+            when default_kind='STORED' then 'STORED GENERATED'
+            else ''
+            end AS extra
         FROM system.columns;";
 
         let mut options = BTreeMap::new();
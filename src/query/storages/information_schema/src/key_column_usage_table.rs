@@ -26,6 +26,7 @@ pub struct KeyColumnUsageTable {}
 
 impl KeyColumnUsageTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
+        // PRIMARY KEY and FOREIGN KEY constraints are not supported yet, the view is always empty.
         let query = "SELECT \
         NULL as constraint_catalog, \
         NULL as constraint_schema, \
@@ -38,7 +39,8 @@ impl KeyColumnUsageTable {
         NULL as position_in_unique_constraint, \
         NULL as referenced_table_schema, \
         NULL as referenced_table_name, \
-        NULL as referenced_column_name"
+        NULL as referenced_column_name \
+        FROM system.one WHERE false"
             .to_string();
 
         let mut options = BTreeMap::new();
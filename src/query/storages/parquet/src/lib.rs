@@ -33,6 +33,7 @@ pub use parquet2::Parquet2Table;
 pub use parquet_part::ParquetFilesPart;
 pub use parquet_part::ParquetPart;
 pub use parquet_rs::InMemoryRowGroup;
+pub use parquet_rs::ParquetBloomFilterPruner;
 pub use parquet_rs::ParquetRSFullReader;
 pub use parquet_rs::ParquetRSPruner;
 pub use parquet_rs::ParquetRSReaderBuilder;
@@ -28,7 +28,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_storage::infer_schema_with_extension;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::read_parquet_metas_in_parallel;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
@@ -47,7 +47,7 @@ impl Parquet2Table {
         read_options: ParquetReadOptions,
         files_to_read: Option<Vec<StageFileInfo>>,
     ) -> Result<Arc<dyn Table>> {
-        let operator = init_stage_operator(&stage_info)?;
+        let operator = init_stage_read_operator(&stage_info)?;
         let first_file = match &files_to_read {
             Some(files) => files[0].path.clone(),
             None => files_info.first_file(&operator).await?.path.clone(),
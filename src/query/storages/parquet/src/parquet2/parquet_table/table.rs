@@ -44,7 +44,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::Pipeline;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::ColumnNodes;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
@@ -72,7 +72,7 @@ pub struct Parquet2Table {
 
 impl Parquet2Table {
     pub fn from_info(info: &Parquet2TableInfo) -> Result<Arc<dyn Table>> {
-        let operator = init_stage_operator(&info.stage_info)?;
+        let operator = init_stage_read_operator(&info.stage_info)?;
         Ok(Arc::new(Parquet2Table {
             table_info: info.table_info.clone(),
             arrow_schema: info.arrow_schema.clone(),
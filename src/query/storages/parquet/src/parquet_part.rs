@@ -58,6 +58,9 @@ impl ParquetPart {
 pub struct ParquetFilesPart {
     pub files: Vec<(String, u64)>,
     pub estimated_uncompressed_size: u64,
+    /// The etags of `files` captured when planning, empty if the snapshot of the files
+    /// is not pinned by `snapshot_consistency`.
+    pub etags: Vec<Option<String>>,
 }
 
 impl ParquetFilesPart {
@@ -134,6 +137,7 @@ pub(crate) fn collect_small_file_parts(
             Box::new(ParquetPart::ParquetFiles(ParquetFilesPart {
                 files,
                 estimated_uncompressed_size,
+                etags: vec![],
             })) as Box<dyn PartInfo>,
         ));
         stats.partitions_scanned += 1;
@@ -0,0 +1,216 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+use std::io::Cursor;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableField;
+use opendal::Operator;
+use parquet::basic::Type as PhysicalType;
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::format::BloomFilterHeader;
+use thrift::protocol::TCompactInputProtocol;
+use thrift::protocol::TSerializable;
+use twox_hash::XxHash64;
+
+/// The bytes read at the offset of a bloom filter to decode its header, the header is
+/// usually less than 20 bytes.
+const BLOOM_FILTER_HEADER_READ_SIZE: u64 = 32;
+
+/// The salts of the split block bloom filters, defined by the parquet format.
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// Prunes the row groups of parquet files by the bloom filters of the columns compared to
+/// constants with `=` in the filter.
+///
+/// A row group is pruned if the bloom filter of such a column shows the constant is absent,
+/// the columns without bloom filters don't prune any row group.
+#[derive(Clone, Debug)]
+pub struct ParquetBloomFilterPruner {
+    /// The leaf ids of the columns, and the constants they are equal to.
+    predicates: Vec<(usize, Scalar)>,
+}
+
+impl ParquetBloomFilterPruner {
+    /// Return [None] if no column is compared to a constant with `=` in the conjunctions of
+    /// the filter.
+    pub fn try_create(filter: &Expr<String>, leaf_fields: &[TableField]) -> Option<Self> {
+        let mut predicates = vec![];
+        collect_equal_predicates(filter, leaf_fields, &mut predicates);
+        if predicates.is_empty() {
+            None
+        } else {
+            Some(ParquetBloomFilterPruner { predicates })
+        }
+    }
+
+    /// Check if the row group may contain the rows matching the filter.
+    #[async_backtrace::framed]
+    pub async fn should_keep(
+        &self,
+        op: &Operator,
+        location: &str,
+        row_group: &RowGroupMetaData,
+    ) -> Result<bool> {
+        for (leaf_id, scalar) in self.predicates.iter() {
+            let column = row_group.column(*leaf_id);
+            let offset = match column.bloom_filter_offset() {
+                Some(offset) => offset as u64,
+                None => continue,
+            };
+            let hash = match hash_plain_value(scalar, column.column_type()) {
+                Some(hash) => hash,
+                None => continue,
+            };
+            let bloom_filter = read_bloom_filter(op, location, offset).await?;
+            if !bloom_filter.check_hash(hash) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+fn collect_equal_predicates(
+    expr: &Expr<String>,
+    leaf_fields: &[TableField],
+    predicates: &mut Vec<(usize, Scalar)>,
+) {
+    if let Expr::FunctionCall { function, args, .. } = expr {
+        match function.signature.name.as_str() {
+            "and" | "and_filters" => {
+                for arg in args {
+                    collect_equal_predicates(arg, leaf_fields, predicates);
+                }
+            }
+            "eq" => match args.as_slice() {
+                [Expr::ColumnRef { id, .. }, Expr::Constant { scalar, .. }]
+                | [Expr::Constant { scalar, .. }, Expr::ColumnRef { id, .. }] => {
+                    // Only the top level columns are leaves with the same name.
+                    if let Some(leaf_id) = leaf_fields
+                        .iter()
+                        .position(|f| f.name.eq_ignore_ascii_case(id))
+                    {
+                        predicates.push((leaf_id, scalar.clone()));
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Hash the value by its plain encoding of the physical type, as the bloom filters are
+/// populated by the parquet writers.
+///
+/// Return [None] if the value can't be encoded in the physical type, the floats are not
+/// hashed because `-0.0` and `0.0` are equal but encoded differently.
+fn hash_plain_value(scalar: &Scalar, physical_type: PhysicalType) -> Option<u64> {
+    let mut hasher = XxHash64::with_seed(0);
+    match (scalar, physical_type) {
+        (Scalar::Number(number), PhysicalType::INT32) => {
+            let value = match number {
+                NumberScalar::Int8(v) => *v as i32,
+                NumberScalar::Int16(v) => *v as i32,
+                NumberScalar::Int32(v) => *v,
+                NumberScalar::UInt8(v) => *v as i32,
+                NumberScalar::UInt16(v) => *v as i32,
+                NumberScalar::UInt32(v) => *v as i32,
+                _ => return None,
+            };
+            hasher.write(&value.to_le_bytes());
+        }
+        (Scalar::Number(number), PhysicalType::INT64) => {
+            let value = match number {
+                NumberScalar::Int64(v) => *v,
+                NumberScalar::UInt64(v) => *v as i64,
+                _ => return None,
+            };
+            hasher.write(&value.to_le_bytes());
+        }
+        (Scalar::String(s), PhysicalType::BYTE_ARRAY) => hasher.write(s.as_bytes()),
+        _ => return None,
+    }
+    Some(hasher.finish())
+}
+
+/// A split block bloom filter of a column chunk.
+struct SplitBlockBloomFilter {
+    blocks: Vec<[u32; 8]>,
+}
+
+impl SplitBlockBloomFilter {
+    fn from_bitset(bitset: &[u8]) -> Self {
+        let blocks = bitset
+            .chunks_exact(32)
+            .map(|chunk| {
+                let mut block = [0u32; 8];
+                for (i, word) in chunk.chunks_exact(4).enumerate() {
+                    block[i] = u32::from_le_bytes(word.try_into().unwrap());
+                }
+                block
+            })
+            .collect();
+        SplitBlockBloomFilter { blocks }
+    }
+
+    /// Return `false` if the value of the hash is definitely absent.
+    fn check_hash(&self, hash: u64) -> bool {
+        if self.blocks.is_empty() {
+            return true;
+        }
+        let index = (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize;
+        let block = &self.blocks[index];
+        let key = hash as u32;
+        SALT.iter()
+            .zip(block.iter())
+            .all(|(salt, word)| word & (1 << (key.wrapping_mul(*salt) >> 27)) != 0)
+    }
+}
+
+/// Read the bloom filter at `offset`, which is a thrift encoded header followed by the bitset.
+async fn read_bloom_filter(
+    op: &Operator,
+    location: &str,
+    offset: u64,
+) -> Result<SplitBlockBloomFilter> {
+    let buffer = op
+        .read_with(location)
+        .range(offset..offset + BLOOM_FILTER_HEADER_READ_SIZE)
+        .await?;
+    let mut cursor = Cursor::new(buffer.as_slice());
+    let header = {
+        let mut protocol = TCompactInputProtocol::new(&mut cursor);
+        BloomFilterHeader::read_from_in_protocol(&mut protocol).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "invalid bloom filter header in parquet file '{}': {}",
+                location, e
+            ))
+        })?
+    };
+    let bitset_offset = offset + cursor.position();
+    let bitset = op
+        .read_with(location)
+        .range(bitset_offset..bitset_offset + header.num_bytes as u64)
+        .await?;
+    Ok(SplitBlockBloomFilter::from_bitset(&bitset))
+}
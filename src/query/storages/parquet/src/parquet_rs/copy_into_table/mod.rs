@@ -16,4 +16,6 @@ mod reader;
 mod source;
 mod table;
 
+pub(crate) use reader::RowGroupReaderForCopy;
+pub(crate) use source::ParquetCopySource;
 pub use table::ParquetTableForCopy;
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_arrow::arrow::compute::cast::can_cast_types;
 use databend_common_arrow::arrow::datatypes::Field as ArrowField;
+use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_context::TableContext;
@@ -77,10 +78,11 @@ impl RowGroupReaderForCopy {
         file_metadata: &FileMetaData,
         output_schema: TableSchemaRef,
         default_values: Vec<Scalar>,
+        case_sensitive: bool,
     ) -> Result<RowGroupReaderForCopy> {
         let arrow_schema = infer_schema_with_extension(file_metadata)?;
         let schema_descr = file_metadata.schema_descr_ptr();
-        let parquet_table_schema = arrow_to_table_schema(&arrow_schema)?;
+        let parquet_table_schema = arrow_to_table_schema(&arrow_schema, case_sensitive)?;
         let mut pushdown_columns = vec![];
         let mut output_projection = vec![];
 
@@ -162,13 +164,16 @@ impl RowGroupReaderForCopy {
             projection: Some(Projection::Columns(pushdown_columns)),
             ..Default::default()
         };
+        let options = ParquetReadOptions::default()
+            .with_int96_as_timestamp(ctx.get_settings().get_parquet_int96_as_timestamp()?);
         let mut reader_builder = ParquetRSReaderBuilder::create_with_parquet_schema(
             ctx,
             op,
             Arc::new(parquet_table_schema),
             schema_descr,
         )
-        .with_push_downs(Some(&pushdowns));
+        .with_push_downs(Some(&pushdowns))
+        .with_options(options);
         reader_builder.build_output()?;
 
         let row_group_reader_builder = reader_builder.create_no_prefetch_policy_builder()?;
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_catalog::plan::SnapshotConsistency;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockEntry;
@@ -36,6 +37,7 @@ use opendal::Operator;
 
 use crate::parquet_rs::copy_into_table::reader::RowGroupReaderForCopy;
 use crate::parquet_rs::parquet_reader::policy::ReadPolicyImpl;
+use crate::utils::check_snapshot;
 use crate::ParquetPart;
 use crate::ReadSettings;
 
@@ -65,6 +67,7 @@ pub struct ParquetCopySource {
 
     state: State,
     batch_size: usize,
+    snapshot_consistency: SnapshotConsistency,
 }
 
 impl ParquetCopySource {
@@ -74,6 +77,7 @@ impl ParquetCopySource {
         row_group_readers: Arc<HashMap<usize, RowGroupReaderForCopy>>,
         operator: Operator,
         schema: DataSchemaRef,
+        snapshot_consistency: SnapshotConsistency,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         let batch_size = ctx.get_settings().get_parquet_max_block_size()? as usize;
@@ -91,6 +95,7 @@ impl ParquetCopySource {
             is_finished: false,
             state: State::Init,
             schema,
+            snapshot_consistency,
         })))
     }
 }
@@ -174,6 +179,17 @@ impl Processor for ParquetCopySource {
                 if let Some(part) = self.ctx.get_partition() {
                     match ParquetPart::from_part(&part)? {
                         ParquetPart::ParquetRSRowGroup(part) => {
+                            let readable = check_snapshot(
+                                &self.operator,
+                                &part.location,
+                                part.etag.as_deref(),
+                                self.snapshot_consistency,
+                            )
+                            .await?;
+                            if !readable {
+                                // The file has been changed, keep in init state.
+                                return Ok(());
+                            }
                             let schema_index = part.schema_index;
                             let builder = self
                                 .row_group_readers
@@ -22,13 +22,14 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::SnapshotConsistency;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_pipeline_core::Pipeline;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::FileStatus;
 use parquet::file::metadata::FileMetaData;
 
@@ -51,7 +52,7 @@ impl ParquetTableForCopy {
         let max_threads = settings.get_max_threads()? as usize;
         let max_memory_usage = settings.get_max_memory_usage()?;
 
-        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let operator = init_stage_read_operator(&stage_table_info.stage_info)?;
         // User set the files.
         let files = stage_table_info.files_to_copy.as_ref().expect(
             "ParquetTableForCopy::do_read_partitions must be called with files_to_copy set",
@@ -94,6 +95,7 @@ impl ParquetTableForCopy {
                     omit_filter: false,
                     page_locations: None,
                     selectors: None,
+                    etag: None,
                 };
                 parts.push(part);
             }
@@ -130,7 +132,7 @@ impl ParquetTableForCopy {
                 ));
             };
 
-        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let operator = init_stage_read_operator(&stage_table_info.stage_info)?;
 
         let mut readers = HashMap::new();
 
@@ -155,6 +157,8 @@ impl ParquetTableForCopy {
                                     .as_ref()
                                     .expect("default_values must be set for ParquetTableForCopy")
                                     .clone(),
+                                // The columns are matched with the columns of the table by the lowered names.
+                                false,
                             )?,
                         );
                     }
@@ -174,6 +178,7 @@ impl ParquetTableForCopy {
                     readers.clone(),
                     operator.clone(),
                     data_schema.clone(),
+                    SnapshotConsistency::Ignore,
                 )
             },
             max_threads,
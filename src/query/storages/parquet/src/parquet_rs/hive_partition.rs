@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// The partition value written by Hive for NULL.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The Hive-style directories of a file, e.g. `year=2024` and `month=1` for
+/// `data/year=2024/month=1/a.parquet`.
+fn parse_partitions(path: &str) -> impl Iterator<Item = (&str, &str)> {
+    let dirs = path.rsplit_once('/').map_or("", |(dirs, _)| dirs);
+    dirs.split('/')
+        .filter_map(|dir| dir.split_once('='))
+        .filter(|(name, _)| !name.is_empty())
+}
+
+fn column_name(name: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        name.to_string()
+    } else {
+        name.to_lowercase()
+    }
+}
+
+/// The partition columns of the files, in the order they first appear in the paths.
+pub(crate) fn hive_partition_columns<'a>(
+    paths: impl IntoIterator<Item = &'a str>,
+    case_sensitive: bool,
+) -> Vec<String> {
+    let mut columns = vec![];
+    for path in paths {
+        for (name, _) in parse_partitions(path) {
+            let name = column_name(name, case_sensitive);
+            if !columns.contains(&name) {
+                columns.push(name);
+            }
+        }
+    }
+    columns
+}
+
+/// The partition columns are read as nullable strings.
+pub(crate) fn hive_partition_fields(columns: &[String]) -> Vec<TableField> {
+    columns
+        .iter()
+        .map(|name| {
+            TableField::new(
+                name,
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            )
+        })
+        .collect()
+}
+
+/// The values of the partition columns of a file, NULL for the columns not in its path.
+pub(crate) fn hive_partition_values(
+    path: &str,
+    columns: &[String],
+    case_sensitive: bool,
+) -> Vec<Scalar> {
+    let mut values = vec![Scalar::Null; columns.len()];
+    for (name, value) in parse_partitions(path) {
+        let name = column_name(name, case_sensitive);
+        if let Some(index) = columns.iter().position(|column| *column == name) {
+            if value != HIVE_DEFAULT_PARTITION {
+                values[index] = Scalar::String(value.to_string());
+            }
+        }
+    }
+    values
+}
+
+/// The push downs to the files, without the partition columns, which are the fields of the
+/// table from `num_file_fields`.
+///
+/// The top-k by a partition column is not pushed down, and if only partition columns are
+/// projected, the first column of the files is read for the number of rows.
+pub(crate) fn push_downs_of_files(
+    push_downs: &PushDownInfo,
+    num_file_fields: usize,
+    columns: &[String],
+) -> PushDownInfo {
+    let projection_of_files = |projection: Projection| {
+        let projection = match projection {
+            Projection::Columns(indices) => Projection::Columns(
+                indices
+                    .into_iter()
+                    .filter(|index| *index < num_file_fields)
+                    .collect(),
+            ),
+            Projection::InnerColumns(paths) => Projection::InnerColumns(
+                paths
+                    .into_iter()
+                    .filter(|(_, path)| path[0] < num_file_fields)
+                    .collect(),
+            ),
+        };
+        match projection.len() {
+            0 => Projection::Columns(vec![0]),
+            _ => projection,
+        }
+    };
+
+    let mut push_downs = push_downs.clone();
+    push_downs.projection = push_downs.projection.map(projection_of_files);
+    push_downs.output_columns = push_downs.output_columns.map(projection_of_files);
+    let order_by_partition = push_downs.order_by.first().is_some_and(
+        |(expr, _, _)| matches!(expr, RemoteExpr::ColumnRef { id, .. } if columns.contains(id)),
+    );
+    if order_by_partition {
+        push_downs.order_by.clear();
+        push_downs.limit = None;
+    }
+    push_downs
+}
+
+/// Prunes the files by the filters on the partition columns.
+pub(crate) struct HivePartitionPruner {
+    columns: Vec<String>,
+    case_sensitive: bool,
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+}
+
+impl HivePartitionPruner {
+    /// `None` if there is no filter to prune the files.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        push_downs: Option<&PushDownInfo>,
+        columns: Vec<String>,
+        case_sensitive: bool,
+    ) -> Result<Option<HivePartitionPruner>> {
+        let Some(filters) = push_downs.and_then(|p| p.filters.as_ref()) else {
+            return Ok(None);
+        };
+        let filter = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+        if !filter
+            .column_refs()
+            .keys()
+            .any(|name| columns.contains(name))
+        {
+            return Ok(None);
+        }
+
+        let range_pruner = RangePrunerCreator::try_create(func_ctx, schema, Some(&filter))?;
+        Ok(Some(HivePartitionPruner {
+            columns,
+            case_sensitive,
+            range_pruner,
+        }))
+    }
+
+    pub fn should_keep(&self, path: &str) -> bool {
+        let values = hive_partition_values(path, &self.columns, self.case_sensitive);
+        let partition_values = self
+            .columns
+            .iter()
+            .cloned()
+            .zip(values)
+            .collect::<HashMap<_, _>>();
+        // The columns of the files have no statistics, so their domains are unknown.
+        self.range_pruner.should_keep_with_partition_columns(
+            &StatisticsOfColumns::new(),
+            Some(&partition_values),
+        )
+    }
+}
+
+/// Fills the partition columns into the blocks read from the files.
+pub(crate) struct HivePartitionFiller {
+    columns: Vec<String>,
+    case_sensitive: bool,
+    /// The positions of the partition columns in the output blocks, with their indices
+    /// in `columns`.
+    output_columns: Vec<(usize, usize)>,
+    /// If the first column of the files is only read for the number of rows.
+    only_partitions: bool,
+}
+
+impl HivePartitionFiller {
+    pub fn create(
+        columns: Vec<String>,
+        case_sensitive: bool,
+        output_schema: &TableSchema,
+    ) -> HivePartitionFiller {
+        let output_columns = output_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter_map(|(position, field)| {
+                columns
+                    .iter()
+                    .position(|column| column == field.name())
+                    .map(|index| (position, index))
+            })
+            .collect::<Vec<_>>();
+        let only_partitions = output_columns.len() == output_schema.num_fields();
+        HivePartitionFiller {
+            columns,
+            case_sensitive,
+            output_columns,
+            only_partitions,
+        }
+    }
+
+    pub fn fill(&self, location: &str, block: DataBlock) -> DataBlock {
+        if self.output_columns.is_empty() {
+            return block;
+        }
+
+        let values = hive_partition_values(location, &self.columns, self.case_sensitive);
+        let mut columns = if self.only_partitions {
+            vec![]
+        } else {
+            block.columns().to_vec()
+        };
+        for (position, index) in self.output_columns.iter() {
+            columns.insert(
+                *position,
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Scalar(values[*index].clone()),
+                ),
+            );
+        }
+        DataBlock::new(columns, block.num_rows())
+    }
+}
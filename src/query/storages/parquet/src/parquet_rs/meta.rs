@@ -21,6 +21,8 @@ use databend_common_catalog::plan::FullParquetMeta;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableField;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache_manager::CacheManager;
 use opendal::Operator;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::schema::types::SchemaDescPtr;
@@ -153,15 +155,54 @@ async fn load_and_check_parquet_meta(
     expect: &SchemaDescriptor,
     schema_from: &str,
 ) -> Result<Arc<ParquetMetaData>> {
-    let metadata =
-        databend_common_storage::parquet_rs::read_metadata_async(file, &op, Some(size)).await?;
+    let metadata = read_metadata_with_cache(file, size, &op)
+        .await
+        .map_err(|e| {
+            if e.code() == ErrorCode::STORAGE_NOT_FOUND {
+                ErrorCode::StorageNotFound(format!(
+                    "parquet file '{file}' was removed after the files were listed: {}",
+                    e.message()
+                ))
+            } else {
+                e
+            }
+        })?;
     check_parquet_schema(
         expect,
         metadata.file_metadata().schema_descr(),
         file,
         schema_from,
     )?;
-    Ok(Arc::new(metadata))
+    Ok(metadata)
+}
+
+/// Read the meta data of a parquet file, or get it from the cache if the size of the file
+/// is the same as when the meta data was cached.
+///
+/// The entry of a file whose size changed is evicted, a file overwritten with the same size
+/// is not detected.
+#[async_backtrace::framed]
+async fn read_metadata_with_cache(
+    file: &str,
+    size: u64,
+    op: &Operator,
+) -> Result<Arc<ParquetMetaData>> {
+    let cache = CacheManager::instance().get_parquet_meta_data_cache();
+    // The same path may be found in different stages or buckets.
+    let info = op.info();
+    let cache_key = format!("{}://{}{}{}", info.scheme(), info.name(), info.root(), file);
+    if let Some((cached_size, metadata)) = cache.get(&cache_key).as_deref() {
+        if *cached_size == size {
+            return Ok(metadata.clone());
+        }
+        cache.evict(&cache_key);
+    }
+
+    let metadata = Arc::new(
+        databend_common_storage::parquet_rs::read_metadata_async(file, op, Some(size)).await?,
+    );
+    cache.put(cache_key, Arc::new((size, metadata.clone())));
+    Ok(metadata)
 }
 
 pub async fn read_parquet_metas_batch(
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bloom_filter;
 mod copy_into_table;
+mod hive_partition;
 mod parquet_reader;
 mod parquet_table;
 mod partition;
@@ -23,6 +25,7 @@ mod statistics;
 mod meta;
 mod schema;
 
+pub use bloom_filter::ParquetBloomFilterPruner;
 pub use copy_into_table::ParquetTableForCopy;
 pub use meta::read_metas_in_parallel_for_copy;
 pub use meta::read_parquet_metas_batch;
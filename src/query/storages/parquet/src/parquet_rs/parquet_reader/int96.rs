@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::Array;
+use arrow_array::RecordBatch;
+use arrow_array::TimestampNanosecondArray;
+use chrono::LocalResult;
+use chrono::NaiveDateTime;
+use chrono::Offset;
+use chrono::TimeZone;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::utils::date_helper::TzLUT;
+use parquet::basic::Type as PhysicalType;
+use parquet::schema::types::SchemaDescriptor;
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// Reads the legacy INT96 timestamps written by Hive and Impala as the wall clock times
+/// of the session timezone.
+///
+/// The INT96 timestamps have no timezone, they are decoded by arrow as nanoseconds since
+/// the epoch in UTC. They are shifted by the offset of the session timezone, so they are
+/// displayed as they were written in any timezone.
+///
+/// Only the top level INT96 columns are converted.
+#[derive(Clone, Debug)]
+pub struct Int96Timestamps {
+    columns: Vec<String>,
+    tz: TzLUT,
+}
+
+impl Int96Timestamps {
+    /// Returns `None` if there is nothing to convert, that is the schema has no INT96
+    /// columns or the session timezone is UTC.
+    pub fn create(schema_desc: &SchemaDescriptor, tz: TzLUT) -> Option<Int96Timestamps> {
+        if tz.tz.name() == "UTC" {
+            return None;
+        }
+        let columns = schema_desc
+            .root_schema()
+            .get_fields()
+            .iter()
+            .filter(|f| f.is_primitive() && f.get_physical_type() == PhysicalType::INT96)
+            .map(|f| f.name().to_string())
+            .collect::<Vec<_>>();
+        (!columns.is_empty()).then_some(Int96Timestamps { columns, tz })
+    }
+
+    pub fn convert(&self, batch: RecordBatch) -> Result<RecordBatch> {
+        let schema = batch.schema();
+        let mut columns = batch.columns().to_vec();
+        let mut converted = false;
+        for (field, column) in schema.fields().iter().zip(columns.iter_mut()) {
+            if !self.columns.contains(field.name()) {
+                continue;
+            }
+            let Some(array) = column.as_any().downcast_ref::<TimestampNanosecondArray>() else {
+                continue;
+            };
+            let array = array
+                .iter()
+                .map(|v| v.map(|v| self.to_utc(v, field.name())).transpose())
+                .collect::<Result<TimestampNanosecondArray>>()?;
+            *column = Arc::new(array);
+            converted = true;
+        }
+
+        if !converted {
+            return Ok(batch);
+        }
+        Ok(RecordBatch::try_new(schema, columns)?)
+    }
+
+    fn to_utc(&self, nanos: i64, column: &str) -> Result<i64> {
+        let local = NaiveDateTime::from_timestamp_opt(
+            nanos.div_euclid(NANOS_PER_SEC),
+            nanos.rem_euclid(NANOS_PER_SEC) as u32,
+        );
+        local
+            .and_then(|local| {
+                let offset = match self.tz.tz.offset_from_local_datetime(&local) {
+                    LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset,
+                    // The wall clock time is skipped by the daylight saving time.
+                    LocalResult::None => self.tz.tz.offset_from_utc_datetime(&local),
+                };
+                let offset = offset.fix().local_minus_utc() as i64;
+                nanos.checked_sub(offset * NANOS_PER_SEC)
+            })
+            .ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "Invalid INT96 timestamp {nanos} of column '{column}' in parquet file"
+                ))
+            })
+    }
+}
+
+/// Convert the INT96 timestamps of `batch` if `int96_timestamps` is `Some`.
+pub fn convert_int96_timestamps(
+    int96_timestamps: &Option<Int96Timestamps>,
+    batch: RecordBatch,
+) -> Result<RecordBatch> {
+    match int96_timestamps {
+        Some(int96_timestamps) => int96_timestamps.convert(batch),
+        None => Ok(batch),
+    }
+}
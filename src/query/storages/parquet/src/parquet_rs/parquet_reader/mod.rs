@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod int96;
 mod predicate;
 mod read_policy;
 mod reader;
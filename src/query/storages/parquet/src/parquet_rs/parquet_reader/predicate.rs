@@ -34,6 +34,8 @@ use parquet::arrow::FieldLevels;
 use parquet::arrow::ProjectionMask;
 use parquet::schema::types::SchemaDescriptor;
 
+use super::int96::convert_int96_timestamps;
+use super::int96::Int96Timestamps;
 use super::utils::bitmap_to_boolean_array;
 use super::utils::transform_record_batch;
 use super::utils::FieldPaths;
@@ -49,6 +51,7 @@ pub struct ParquetPredicate {
     /// Predicate filter expression.
     filter: Expr,
     field_paths: Option<FieldPaths>,
+    int96_timestamps: Option<Int96Timestamps>,
 
     schema: TableSchema,
 }
@@ -83,7 +86,8 @@ impl ParquetPredicate {
         partition_block_entries: Option<Vec<BlockEntry>>,
     ) -> Result<BooleanArray> {
         let data_schema = DataSchema::from(&self.schema);
-        let block = transform_record_batch(&data_schema, batch, &self.field_paths)?;
+        let batch = convert_int96_timestamps(&self.int96_timestamps, batch.clone())?;
+        let block = transform_record_batch(&data_schema, &batch, &self.field_paths)?;
         let block = if let Some(partition_block_entries) = partition_block_entries {
             let mut columns = block.columns().to_vec();
             columns.extend_from_slice(&partition_block_entries);
@@ -107,6 +111,7 @@ pub fn build_predicate(
     table_schema: &TableSchema,
     schema_desc: &SchemaDescriptor,
     partition_columns: &[String],
+    int96_timestamps: Option<Int96Timestamps>,
 ) -> Result<(Arc<ParquetPredicate>, Vec<usize>)> {
     let inner_projection = matches!(prewhere.output_columns, Projection::InnerColumns(_));
     let schema = prewhere.prewhere_columns.project_schema(table_schema);
@@ -131,6 +136,7 @@ pub fn build_predicate(
             filter,
             field_levels,
             field_paths,
+            int96_timestamps,
             schema,
         }),
         leaves,
@@ -28,6 +28,8 @@ use parquet::schema::types::SchemaDescriptor;
 use super::policy::ReadPolicy;
 use super::policy::ReadPolicyBuilder;
 use super::policy::ReadPolicyImpl;
+use crate::parquet_rs::parquet_reader::int96::convert_int96_timestamps;
+use crate::parquet_rs::parquet_reader::int96::Int96Timestamps;
 use crate::parquet_rs::parquet_reader::row_group::InMemoryRowGroup;
 use crate::parquet_rs::parquet_reader::utils::transform_record_batch;
 use crate::parquet_rs::parquet_reader::utils::FieldPaths;
@@ -37,6 +39,7 @@ pub struct NoPretchPolicyBuilder {
     data_schema: DataSchema,
     field_levels: FieldLevels,
     field_paths: Arc<Option<FieldPaths>>,
+    int96_timestamps: Option<Int96Timestamps>,
 }
 
 #[async_trait::async_trait]
@@ -58,6 +61,7 @@ impl ReadPolicyBuilder for NoPretchPolicyBuilder {
         Ok(Some(Box::new(NoPrefetchPolicy {
             field_paths: self.field_paths.clone(),
             data_schema: self.data_schema.clone(),
+            int96_timestamps: self.int96_timestamps.clone(),
             reader,
         })))
     }
@@ -69,6 +73,7 @@ impl NoPretchPolicyBuilder {
         data_schema: DataSchema,
         projection: ProjectionMask,
         field_paths: Arc<Option<FieldPaths>>,
+        int96_timestamps: Option<Int96Timestamps>,
     ) -> Result<Box<dyn ReadPolicyBuilder>> {
         let field_levels = parquet_to_arrow_field_levels(schema, projection.clone(), None)?;
         Ok(Box::new(NoPretchPolicyBuilder {
@@ -76,6 +81,7 @@ impl NoPretchPolicyBuilder {
             data_schema,
             projection,
             field_paths,
+            int96_timestamps,
         }))
     }
 }
@@ -93,6 +99,7 @@ pub struct NoPrefetchPolicy {
     /// if `field_paths` is [None], we can skip the traversing.
     field_paths: Arc<Option<FieldPaths>>,
     data_schema: DataSchema,
+    int96_timestamps: Option<Int96Timestamps>,
 
     reader: ParquetRecordBatchReader,
 }
@@ -101,6 +108,7 @@ impl ReadPolicy for NoPrefetchPolicy {
     fn read_block(&mut self) -> Result<Option<DataBlock>> {
         let batch = self.reader.next().transpose()?;
         if let Some(batch) = batch {
+            let batch = convert_int96_timestamps(&self.int96_timestamps, batch)?;
             let block = transform_record_batch(&self.data_schema, &batch, &self.field_paths)?;
             Ok(Some(block))
         } else {
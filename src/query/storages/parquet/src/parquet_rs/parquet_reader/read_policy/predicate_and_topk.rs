@@ -34,6 +34,8 @@ use super::policy::ReadPolicyBuilder;
 use super::policy::ReadPolicyImpl;
 use super::utils::evaluate_topk;
 use super::utils::read_all;
+use crate::parquet_rs::parquet_reader::int96::convert_int96_timestamps;
+use crate::parquet_rs::parquet_reader::int96::Int96Timestamps;
 use crate::parquet_rs::parquet_reader::predicate::ParquetPredicate;
 use crate::parquet_rs::parquet_reader::row_group::InMemoryRowGroup;
 use crate::parquet_rs::parquet_reader::topk::BuiltTopK;
@@ -58,6 +60,7 @@ pub struct PredicateAndTopkPolicyBuilder {
     /// Record which prefetched columns are needed to be output.
     /// Other prefetched columns can be released immediately.
     output_prefetched_field_indices: Vec<usize>,
+    int96_timestamps: Option<Int96Timestamps>,
 }
 
 impl PredicateAndTopkPolicyBuilder {
@@ -68,6 +71,7 @@ impl PredicateAndTopkPolicyBuilder {
         output_leaves: &[usize],
         remain_schema: &TableSchema,
         output_schema: &TableSchema,
+        int96_timestamps: Option<Int96Timestamps>,
     ) -> Result<Box<dyn ReadPolicyBuilder>> {
         let (predicate, predicate_leaves) = predicate;
         let inner_projection = predicate.field_paths().is_some();
@@ -143,6 +147,7 @@ impl PredicateAndTopkPolicyBuilder {
             src_schema,
             dst_schema,
             output_prefetched_field_indices,
+            int96_timestamps,
         }))
     }
 }
@@ -175,6 +180,7 @@ impl ReadPolicyBuilder for PredicateAndTopkPolicyBuilder {
                 topk.field_levels(),
                 selection.clone(),
                 topk.field_paths(),
+                &self.int96_timestamps,
                 num_rows,
             )?;
             debug_assert_eq!(block.num_columns(), 1);
@@ -197,6 +203,7 @@ impl ReadPolicyBuilder for PredicateAndTopkPolicyBuilder {
                 self.predicate.field_levels(),
                 selection.clone(),
                 self.predicate.field_paths(),
+                &self.int96_timestamps,
                 num_rows,
             )?;
             let filter = self.predicate.evaluate_block(&block)?;
@@ -265,6 +272,7 @@ impl ReadPolicyBuilder for PredicateAndTopkPolicyBuilder {
             remain_field_paths: self.remain_field_paths.clone(),
             src_schema: self.src_schema.clone(),
             dst_schema: self.dst_schema.clone(),
+            int96_timestamps: self.int96_timestamps.clone(),
         })))
     }
 }
@@ -283,6 +291,7 @@ pub struct PredicateAndTopkPolicy {
     src_schema: DataSchemaRef,
     /// The final output schema.
     dst_schema: DataSchemaRef,
+    int96_timestamps: Option<Int96Timestamps>,
 }
 
 impl ReadPolicy for PredicateAndTopkPolicy {
@@ -291,6 +300,7 @@ impl ReadPolicy for PredicateAndTopkPolicy {
         if let Some(batch) = batch {
             debug_assert!(!self.prefetched.is_empty());
             let prefetched = self.prefetched.pop_front().unwrap();
+            let batch = convert_int96_timestamps(&self.int96_timestamps, batch)?;
             let mut block =
                 transform_record_batch(self.src_schema.as_ref(), &batch, &self.remain_field_paths)?;
             block.merge_block(prefetched);
@@ -35,6 +35,8 @@ use super::policy::ReadPolicyBuilder;
 use super::policy::ReadPolicyImpl;
 use super::utils::evaluate_topk;
 use super::utils::read_all;
+use crate::parquet_rs::parquet_reader::int96::convert_int96_timestamps;
+use crate::parquet_rs::parquet_reader::int96::Int96Timestamps;
 use crate::parquet_rs::parquet_reader::row_group::InMemoryRowGroup;
 use crate::parquet_rs::parquet_reader::topk::BuiltTopK;
 use crate::parquet_rs::parquet_reader::topk::ParquetTopK;
@@ -56,6 +58,7 @@ pub struct TopkOnlyPolicyBuilder {
 
     /// If the topk column is in the output columns.
     topk_in_output: bool,
+    int96_timestamps: Option<Int96Timestamps>,
 }
 
 impl TopkOnlyPolicyBuilder {
@@ -65,6 +68,7 @@ impl TopkOnlyPolicyBuilder {
         output_schema: &TableSchema,
         output_leaves: &[usize],
         inner_projection: bool,
+        int96_timestamps: Option<Int96Timestamps>,
     ) -> Result<Box<dyn ReadPolicyBuilder>> {
         let BuiltTopK {
             topk,
@@ -118,6 +122,7 @@ impl TopkOnlyPolicyBuilder {
             src_schema,
             dst_schema,
             topk_in_output,
+            int96_timestamps,
         }))
     }
 }
@@ -148,6 +153,7 @@ impl ReadPolicyBuilder for TopkOnlyPolicyBuilder {
             self.topk.field_levels(),
             selection.clone(),
             self.topk.field_paths(),
+            &self.int96_timestamps,
             num_rows,
         )?;
         let prefetched =
@@ -194,6 +200,7 @@ impl ReadPolicyBuilder for TopkOnlyPolicyBuilder {
             remain_schema: self.remain_schema.clone(),
             src_schema: self.src_schema.clone(),
             dst_schema: self.dst_schema.clone(),
+            int96_timestamps: self.int96_timestamps.clone(),
         })))
     }
 }
@@ -213,6 +220,7 @@ pub struct TopkOnlyPolicy {
     src_schema: DataSchemaRef,
     /// The final output schema.
     dst_schema: DataSchemaRef,
+    int96_timestamps: Option<Int96Timestamps>,
 }
 
 impl ReadPolicy for TopkOnlyPolicy {
@@ -222,6 +230,7 @@ impl ReadPolicy for TopkOnlyPolicy {
             debug_assert!(
                 self.prefetched.is_none() || !self.prefetched.as_ref().unwrap().is_empty()
             );
+            let batch = convert_int96_timestamps(&self.int96_timestamps, batch)?;
             let mut block =
                 transform_record_batch(&self.remain_schema, &batch, &self.remain_field_paths)?;
             if let Some(q) = self.prefetched.as_mut() {
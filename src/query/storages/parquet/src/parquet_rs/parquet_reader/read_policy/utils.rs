@@ -20,6 +20,8 @@ use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
 use parquet::arrow::arrow_reader::RowSelection;
 use parquet::arrow::FieldLevels;
 
+use crate::parquet_rs::parquet_reader::int96::convert_int96_timestamps;
+use crate::parquet_rs::parquet_reader::int96::Int96Timestamps;
 use crate::parquet_rs::parquet_reader::row_group::InMemoryRowGroup;
 use crate::parquet_rs::parquet_reader::topk::ParquetTopK;
 use crate::parquet_rs::parquet_reader::utils::bitmap_to_boolean_array;
@@ -32,12 +34,14 @@ pub fn read_all(
     field_levels: &FieldLevels,
     selection: Option<RowSelection>,
     field_paths: &Option<FieldPaths>,
+    int96_timestamps: &Option<Int96Timestamps>,
     num_rows: usize,
 ) -> Result<DataBlock> {
     let mut reader =
         ParquetRecordBatchReader::try_new_with_row_groups(field_levels, rg, num_rows, selection)?;
     let batch = reader.next().transpose()?.unwrap();
     debug_assert!(reader.next().is_none());
+    let batch = convert_int96_timestamps(int96_timestamps, batch)?;
     transform_record_batch(data_schema, &batch, field_paths)
 }
 
@@ -28,6 +28,7 @@ use parquet::arrow::ProjectionMask;
 use parquet::schema::types::SchemaDescPtr;
 
 use super::ParquetRSRowGroupReader;
+use crate::parquet_rs::parquet_reader::int96::Int96Timestamps;
 use crate::parquet_rs::parquet_reader::policy::default_policy_builders;
 use crate::parquet_rs::parquet_reader::policy::ReadPolicyBuilder;
 use crate::parquet_rs::parquet_reader::policy::POLICY_NO_PREFETCH;
@@ -133,6 +134,14 @@ impl<'a> ParquetRSReaderBuilder<'a> {
         self
     }
 
+    fn build_int96_timestamps(&self) -> Result<Option<Int96Timestamps>> {
+        if !self.options.int96_as_timestamp() {
+            return Ok(None);
+        }
+        let tz = self.ctx.get_function_context()?.tz;
+        Ok(Int96Timestamps::create(&self.schema_desc, tz))
+    }
+
     fn build_predicate(&mut self) -> Result<()> {
         if self.built_predicate.is_some() {
             return Ok(());
@@ -145,6 +154,7 @@ impl<'a> ParquetRSReaderBuilder<'a> {
                     &self.table_schema,
                     &self.schema_desc,
                     &self.partition_columns,
+                    self.build_int96_timestamps()?,
                 )
             })
             .transpose()?;
@@ -220,6 +230,7 @@ impl<'a> ParquetRSReaderBuilder<'a> {
             predicate,
             projection,
             field_paths,
+            int96_timestamps: self.build_int96_timestamps()?,
             pruner: self.pruner.clone(),
             need_page_index: self.options.prune_pages(),
             batch_size,
@@ -279,6 +290,7 @@ impl<'a> ParquetRSReaderBuilder<'a> {
             data_schema,
             projection.clone(),
             output_field_paths.clone(),
+            self.build_int96_timestamps()?,
         )
     }
 
@@ -290,6 +302,7 @@ impl<'a> ParquetRSReaderBuilder<'a> {
             output_schema,
             output_leaves,
             paths.is_some(),
+            self.build_int96_timestamps()?,
         )
     }
 
@@ -313,6 +326,7 @@ impl<'a> ParquetRSReaderBuilder<'a> {
             output_leaves,
             &remain_schema,
             output_schema,
+            self.build_int96_timestamps()?,
         )
     }
 }
@@ -36,6 +36,8 @@ use parquet::arrow::async_reader::ParquetRecordBatchStream;
 use parquet::arrow::ParquetRecordBatchStreamBuilder;
 use parquet::arrow::ProjectionMask;
 
+use crate::parquet_rs::parquet_reader::int96::convert_int96_timestamps;
+use crate::parquet_rs::parquet_reader::int96::Int96Timestamps;
 use crate::parquet_rs::parquet_reader::predicate::ParquetPredicate;
 use crate::parquet_rs::parquet_reader::utils::transform_record_batch;
 use crate::parquet_rs::parquet_reader::utils::transform_record_batch_by_field_paths;
@@ -59,6 +61,7 @@ pub struct ParquetRSFullReader {
     /// we should extract inner columns from the struct manually by traversing the nested column;
     /// if `field_paths` is [None], we can skip the traversing.
     pub(super) field_paths: Arc<Option<FieldPaths>>,
+    pub(super) int96_timestamps: Option<Int96Timestamps>,
 
     pub(super) pruner: Option<ParquetRSPruner>,
 
@@ -150,6 +153,7 @@ impl ParquetRSFullReader {
         let record_batch = stream.next().await.transpose()?;
 
         if let Some(batch) = record_batch {
+            let batch = convert_int96_timestamps(&self.int96_timestamps, batch)?;
             let blocks = transform_record_batch(
                 &self.output_schema.as_ref().into(),
                 &batch,
@@ -213,7 +217,7 @@ impl ParquetRSFullReader {
             reader
                 .into_iter()
                 .map(|batch| {
-                    let batch = batch?;
+                    let batch = convert_int96_timestamps(&self.int96_timestamps, batch?)?;
                     transform_record_batch_by_field_paths(&batch, field_paths)
                 })
                 .collect()
@@ -221,7 +225,7 @@ impl ParquetRSFullReader {
             reader
                 .into_iter()
                 .map(|batch| {
-                    let batch = batch?;
+                    let batch = convert_int96_timestamps(&self.int96_timestamps, batch?)?;
                     Ok(
                         DataBlock::from_record_batch(&self.output_schema.as_ref().into(), &batch)?
                             .0,
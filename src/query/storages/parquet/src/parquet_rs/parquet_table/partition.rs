@@ -12,29 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
+use std::io::Cursor;
 use std::sync::Arc;
 
 use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::plan::FullParquetMeta;
 use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::SnapshotConsistency;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::FileStatus;
+use opendal::Operator;
 use parquet::arrow::arrow_reader::RowSelector;
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::format::OffsetIndex;
+use thrift::protocol::TCompactInputProtocol;
+use thrift::protocol::TSerializable;
 
 use super::table::ParquetRSTable;
 use crate::parquet_part::collect_small_file_parts;
+use crate::parquet_rs::hive_partition::HivePartitionPruner;
 use crate::parquet_rs::partition::SerdePageLocation;
 use crate::parquet_rs::partition::SerdeRowSelector;
+use crate::parquet_rs::read_metas_in_parallel_for_copy;
 use crate::parquet_rs::read_parquet_metas_batch;
 use crate::parquet_rs::ParquetRSRowGroupPart;
 use crate::ParquetPart;
@@ -75,11 +87,23 @@ impl ParquetRSTable {
         // If a file size is less than `parquet_fast_read_bytes`,
         // we treat it as a small file and it will be totally loaded into memory.
         let fast_read_bytes = ctx.get_settings().get_parquet_fast_read_bytes()?;
+        let hive_partition_pruner = HivePartitionPruner::try_create(
+            ctx.get_function_context()?,
+            &self.schema(),
+            push_down.as_ref(),
+            self.hive_partition_columns.clone(),
+            self.read_options.case_sensitive(),
+        )?;
         let mut large_files = vec![];
         let mut large_file_indices = vec![];
         let mut small_file_indices = vec![];
         let mut small_files = vec![];
         for (index, (location, size)) in file_locations.into_iter().enumerate() {
+            if let Some(pruner) = &hive_partition_pruner {
+                if !pruner.should_keep(&location) {
+                    continue;
+                }
+            }
             if size > fast_read_bytes {
                 large_files.push((location, size));
                 large_file_indices.push(index);
@@ -95,8 +119,10 @@ impl ParquetRSTable {
             self.leaf_fields.clone(),
             &push_down,
             self.read_options,
-            vec![],
+            self.hive_partition_columns.clone(),
         )?);
+        // The columns to read and the top-k are about the columns in the files.
+        let file_push_down = self.file_push_downs(push_down.as_ref());
 
         let copy_status = if matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable) {
             Some(ctx.get_copy_status())
@@ -107,7 +133,7 @@ impl ParquetRSTable {
         // Get columns needed to be read into memory.
         // It will be used to calculate the memory will be used in reading.
         let columns_to_read = if let Some(prewhere) =
-            PushDownInfo::prewhere_of_push_downs(push_down.as_ref())
+            PushDownInfo::prewhere_of_push_downs(file_push_down.as_ref())
         {
             let (_, prewhere_columns) = prewhere
                 .prewhere_columns
@@ -122,14 +148,19 @@ impl ParquetRSTable {
             columns.sort();
             columns
         } else {
-            let output_projection =
-                PushDownInfo::projection_of_push_downs(&self.schema(), push_down.as_ref());
+            let output_projection = PushDownInfo::projection_of_push_downs(
+                &self.file_schema(),
+                file_push_down.as_ref(),
+            );
             let (_, columns) = output_projection.to_arrow_projection(&self.schema_descr);
             columns
         };
 
         let num_columns_to_read = columns_to_read.len();
-        let topk = push_down.as_ref().and_then(|p| p.top_k(&self.schema()));
+        let topk = file_push_down
+            .as_ref()
+            .and_then(|p| p.top_k(&self.file_schema()));
+        let num_threads = ctx.get_settings().get_max_threads()? as usize;
 
         let (mut stats, mut partitions) = if parquet_metas.is_empty() {
             self.read_and_prune_metas_in_parallel(
@@ -144,6 +175,7 @@ impl ParquetRSTable {
         } else {
             prune_metas_in_parallel(
                 ctx,
+                self.operator.clone(),
                 &parquet_metas,
                 large_file_indices,
                 pruner,
@@ -199,6 +231,21 @@ impl ParquetRSTable {
             }
         }
 
+        let etags = self.capture_etags().await?;
+        if !etags.is_empty() {
+            partitions = pin_etags(partitions, &etags);
+        }
+
+        let limit = push_down
+            .as_ref()
+            .filter(|p| p.order_by.is_empty() && p.filters.is_none())
+            .and_then(|p| p.limit);
+        if let Some(limit) = limit {
+            limit_partitions(&mut partitions, &mut stats, limit);
+        } else {
+            partitions = split_row_group_parts(&self.operator, partitions, num_threads).await?;
+        }
+
         Ok((stats, partitions))
     }
 
@@ -246,7 +293,15 @@ impl ParquetRSTable {
                     max_memory_usage,
                 )
                 .await?;
-                prune_and_generate_partitions(&pruner, metas, columns_to_read, &topk, copy_status)
+                prune_and_generate_partitions(
+                    &pruner,
+                    &op,
+                    metas,
+                    columns_to_read,
+                    &topk,
+                    copy_status,
+                )
+                .await
             });
         }
 
@@ -269,12 +324,139 @@ impl ParquetRSTable {
 
         Ok((stats, create_partitions(parts, &topk)))
     }
+
+    /// Generate a part for each row group of the files read with a merged schema.
+    ///
+    /// The files don't share the same parquet schema, so each part records the index of the
+    /// schema of its file, and the row groups are read by a reader built for that schema.
+    #[async_backtrace::framed]
+    pub(super) async fn do_read_partitions_with_merged_schema(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let file_infos = match &self.files_to_read {
+            Some(files) => files
+                .iter()
+                .map(|f| (f.path.clone(), f.size))
+                .collect::<Vec<_>>(),
+            None => self
+                .files_info
+                .list(&self.operator, false, None)
+                .await?
+                .into_iter()
+                .map(|f| (f.path, f.size))
+                .collect::<Vec<_>>(),
+        };
+        let metas = read_metas_in_parallel_for_copy(
+            &self.operator,
+            &file_infos,
+            self.max_threads,
+            self.max_memory_usage,
+        )
+        .await?;
+
+        let copy_status = if matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable) {
+            Some(ctx.get_copy_status())
+        } else {
+            None
+        };
+        let mut stats = PartStatistics::default_exact();
+        let mut schemas = vec![];
+        let mut parts = vec![];
+        for meta in metas.iter() {
+            let schema = meta.meta.file_metadata().schema_descr_ptr();
+            let schema_index = match schemas.iter().position(|s| s == &schema) {
+                Some(i) => i,
+                None => {
+                    schemas.push(schema);
+                    schemas.len() - 1
+                }
+            };
+            let num_rows = meta.meta.file_metadata().num_rows() as usize;
+            stats.read_rows += num_rows;
+            stats.read_bytes += meta.size as usize;
+            if let Some(copy_status) = &copy_status {
+                copy_status.add_chunk(meta.location.as_str(), FileStatus {
+                    num_rows_loaded: num_rows,
+                    error: None,
+                });
+            }
+            for rg in meta.meta.row_groups() {
+                parts.push(ParquetRSRowGroupPart {
+                    location: meta.location.clone(),
+                    meta: rg.clone(),
+                    schema_index,
+                    uncompressed_size: rg.total_byte_size() as u64,
+                    compressed_size: rg.compressed_size() as u64,
+                    sort_min_max: None,
+                    omit_filter: false,
+                    page_locations: None,
+                    selectors: None,
+                    etag: None,
+                });
+            }
+        }
+        stats.partitions_total = parts.len();
+        stats.partitions_scanned = parts.len();
+
+        let mut partitions = create_partitions(parts, &None);
+        let etags = self.capture_etags().await?;
+        if !etags.is_empty() {
+            partitions = pin_etags(partitions, &etags);
+        }
+        Ok((stats, partitions))
+    }
+
+    /// Capture the etags of the files to read if the snapshot of the files is pinned by
+    /// `snapshot_consistency`, the files are checked against them before they are read.
+    ///
+    /// The files whose storage doesn't return etags are not checked.
+    #[async_backtrace::framed]
+    async fn capture_etags(&self) -> Result<HashMap<String, String>> {
+        if self.read_options.snapshot_consistency() == SnapshotConsistency::Ignore {
+            return Ok(HashMap::new());
+        }
+
+        let files = match &self.files_to_read {
+            Some(files) => files.clone(),
+            None => self.files_info.list(&self.operator, false, None).await?,
+        };
+        Ok(files
+            .into_iter()
+            .filter_map(|f| Some((f.path, f.etag?)))
+            .collect())
+    }
+}
+
+/// Record the etags of the files captured when planning in their parts.
+fn pin_etags(partitions: Partitions, etags: &HashMap<String, String>) -> Partitions {
+    let parts = partitions
+        .partitions
+        .iter()
+        .map(|part| {
+            let mut part = part.as_any().downcast_ref::<ParquetPart>().unwrap().clone();
+            match &mut part {
+                ParquetPart::ParquetRSRowGroup(p) => p.etag = etags.get(&p.location).cloned(),
+                ParquetPart::ParquetFiles(p) => {
+                    p.etags = p
+                        .files
+                        .iter()
+                        .map(|(path, _)| etags.get(path).cloned())
+                        .collect()
+                }
+                ParquetPart::Parquet2RowGroup(_) => {}
+            }
+            Arc::new(Box::new(part) as Box<dyn PartInfo>)
+        })
+        .collect();
+    Partitions::create_nolazy(partitions.kind, parts)
 }
 
 /// Call this method only if already collected parquet metas and stats.
 #[async_backtrace::framed]
 async fn prune_metas_in_parallel(
     ctx: Arc<dyn TableContext>,
+    op: Operator,
     parquet_metas: &[Arc<FullParquetMeta>],
     files: Vec<usize>,
     pruner: Arc<ParquetRSPruner>,
@@ -309,12 +491,14 @@ async fn prune_metas_in_parallel(
             .map(|i| parquet_metas[*i].clone())
             .collect::<Vec<_>>();
         let pruner = pruner.clone();
+        let op = op.clone();
         let columns_to_read = columns_to_read.clone();
         let copy_status = copy_status.clone();
         let topk = topk.clone();
 
         tasks.push(async move {
-            prune_and_generate_partitions(&pruner, metas, columns_to_read, &topk, copy_status)
+            prune_and_generate_partitions(&pruner, &op, metas, columns_to_read, &topk, copy_status)
+                .await
         });
     }
 
@@ -338,8 +522,9 @@ async fn prune_metas_in_parallel(
     Ok((stats, create_partitions(parts, &topk)))
 }
 
-fn prune_and_generate_partitions(
+async fn prune_and_generate_partitions(
     pruner: &ParquetRSPruner,
+    op: &Operator,
     parquet_metas: Vec<Arc<FullParquetMeta>>,
     columns_to_read: Vec<usize>,
     topk: &Option<TopK>,
@@ -356,6 +541,9 @@ fn prune_and_generate_partitions(
         } = meta.as_ref();
         part_stats.partitions_total += meta.num_row_groups();
         let (rgs, omits) = pruner.prune_row_groups(meta, row_group_level_stats.as_deref(), None)?;
+        let (rgs, omits) = pruner
+            .prune_row_groups_by_bloom_filters(op, location, meta, rgs, omits)
+            .await?;
         let mut row_selections = if omits.iter().all(|x| *x) {
             None
         } else {
@@ -400,13 +588,11 @@ fn prune_and_generate_partitions(
                 uncompressed_size += rg_meta.column(*col).uncompressed_size() as u64;
             }
 
-            let sort_min_max =
-                topk.as_ref()
-                    .zip(row_group_level_stats.as_ref())
-                    .map(|(t, stats)| {
-                        let stat = &stats[rg][&(t.leaf_id as u32)];
-                        (stat.min.clone(), stat.max.clone())
-                    });
+            let sort_min_max = topk
+                .as_ref()
+                .zip(row_group_level_stats.as_ref())
+                .and_then(|(t, stats)| stats[rg].get(&(t.leaf_id as u32)))
+                .map(|stat| (stat.min.clone(), stat.max.clone()));
 
             parts.push(ParquetRSRowGroupPart {
                 location: location.clone(),
@@ -418,6 +604,7 @@ fn prune_and_generate_partitions(
                 sort_min_max,
                 omit_filter: omit,
                 schema_index: 0,
+                etag: None,
             });
         }
 
@@ -433,7 +620,174 @@ fn prune_and_generate_partitions(
     Ok((part_stats, parts))
 }
 
+/// Keep the partitions until the rows of the partitions kept reach `limit`.
+///
+/// Only the row counts of the row group parts are known, the small files parts are kept as
+/// long as the limit is not reached.
+fn limit_partitions(partitions: &mut Partitions, stats: &mut PartStatistics, limit: usize) {
+    let mut rows = 0;
+    let num_parts = partitions
+        .partitions
+        .iter()
+        .position(|part| {
+            if rows >= limit {
+                return true;
+            }
+            if let ParquetPart::ParquetRSRowGroup(p) =
+                part.as_any().downcast_ref::<ParquetPart>().unwrap()
+            {
+                rows += match &p.selectors {
+                    Some(selectors) => selectors
+                        .iter()
+                        .filter(|s| !s.skip)
+                        .map(|s| s.row_count)
+                        .sum(),
+                    None => p.meta.num_rows() as usize,
+                };
+            }
+            false
+        })
+        .unwrap_or(partitions.partitions.len());
+
+    for part in partitions.partitions.drain(num_parts..) {
+        stats.partitions_scanned = stats.partitions_scanned.saturating_sub(1);
+        if let ParquetPart::ParquetRSRowGroup(p) =
+            part.as_any().downcast_ref::<ParquetPart>().unwrap()
+        {
+            stats.read_rows = stats.read_rows.saturating_sub(p.meta.num_rows() as usize);
+            stats.read_bytes = stats
+                .read_bytes
+                .saturating_sub(p.meta.compressed_size() as usize);
+        }
+    }
+}
+
+/// Split the row group parts into ranges of rows if there are fewer parts than threads, so that
+/// the few large row groups of a large file are read by all the threads.
+///
+/// The pipeline sources take the parts one by one, so an idle source takes the next range.
+/// The ranges don't overlap, and each one only reads the pages containing its rows, located by
+/// the offset index of the row group. The row groups without offset index, or already pruned
+/// by pages, are not split.
+async fn split_row_group_parts(
+    op: &Operator,
+    partitions: Partitions,
+    num_threads: usize,
+) -> Result<Partitions> {
+    let num_parts = partitions.partitions.len();
+    if num_parts == 0 || num_parts >= num_threads {
+        return Ok(partitions);
+    }
+
+    let max_ranges = num_threads.div_ceil(num_parts);
+    let mut split_parts: Vec<PartInfoPtr> = Vec::with_capacity(num_threads);
+    for part_info in partitions.partitions {
+        let mut part = match part_info.as_any().downcast_ref::<ParquetPart>().unwrap() {
+            ParquetPart::ParquetRSRowGroup(part) if part.selectors.is_none() => part.clone(),
+            _ => {
+                split_parts.push(part_info);
+                continue;
+            }
+        };
+        if part.page_locations.is_none() {
+            part.page_locations = read_page_locations(op, &part.location, &part.meta).await?;
+        }
+        let num_pages = part
+            .page_locations
+            .as_ref()
+            .and_then(|columns| columns.iter().map(Vec::len).max())
+            .unwrap_or(0);
+        let num_ranges = max_ranges.min(num_pages);
+        if num_ranges <= 1 {
+            split_parts.push(part_info);
+            continue;
+        }
+
+        let num_rows = part.meta.num_rows() as usize;
+        for i in 0..num_ranges {
+            let begin = num_rows * i / num_ranges;
+            let end = num_rows * (i + 1) / num_ranges;
+            let mut selectors = Vec::with_capacity(3);
+            if begin > 0 {
+                selectors.push(SerdeRowSelector {
+                    row_count: begin,
+                    skip: true,
+                });
+            }
+            selectors.push(SerdeRowSelector {
+                row_count: end - begin,
+                skip: false,
+            });
+            if end < num_rows {
+                selectors.push(SerdeRowSelector {
+                    row_count: num_rows - end,
+                    skip: true,
+                });
+            }
+            let range = ParquetRSRowGroupPart {
+                selectors: Some(selectors),
+                compressed_size: part.compressed_size / num_ranges as u64,
+                uncompressed_size: part.uncompressed_size / num_ranges as u64,
+                ..part.clone()
+            };
+            split_parts.push(Arc::new(Box::new(ParquetPart::ParquetRSRowGroup(range))));
+        }
+    }
+    Ok(Partitions::create_nolazy(partitions.kind, split_parts))
+}
+
+/// Read the page locations of the columns of a row group from its offset index,
+/// return [None] if any column has no offset index.
+async fn read_page_locations(
+    op: &Operator,
+    location: &str,
+    meta: &RowGroupMetaData,
+) -> Result<Option<Vec<Vec<SerdePageLocation>>>> {
+    let ranges = meta
+        .columns()
+        .iter()
+        .map(|c| {
+            let offset = c.offset_index_offset()? as u64;
+            let length = c.offset_index_length()? as u64;
+            Some((offset, offset + length))
+        })
+        .collect::<Option<Vec<_>>>();
+    let ranges = match ranges {
+        Some(ranges) if !ranges.is_empty() => ranges,
+        _ => return Ok(None),
+    };
+
+    // The offset indexes of the columns of a row group are written together.
+    let begin = ranges.iter().map(|(begin, _)| *begin).min().unwrap();
+    let end = ranges.iter().map(|(_, end)| *end).max().unwrap();
+    let buffer = op.read_with(location).range(begin..end).await?;
+
+    let mut page_locations = Vec::with_capacity(ranges.len());
+    for (offset, offset_end) in ranges {
+        let bytes = &buffer[(offset - begin) as usize..(offset_end - begin) as usize];
+        let mut prot = TCompactInputProtocol::new(Cursor::new(bytes));
+        let index = OffsetIndex::read_from_in_protocol(&mut prot).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "Invalid offset index in parquet file '{}': {}",
+                location, e
+            ))
+        })?;
+        page_locations.push(
+            index
+                .page_locations
+                .iter()
+                .map(SerdePageLocation::from)
+                .collect(),
+        );
+    }
+    Ok(Some(page_locations))
+}
+
 fn create_partitions(mut parts: Vec<ParquetRSRowGroupPart>, topk: &Option<TopK>) -> Partitions {
+    // The row groups can only be sorted if all of them have the statistics of the sort column.
+    let topk = topk
+        .as_ref()
+        .filter(|_| parts.iter().all(|p| p.sort_min_max.is_some()));
     if let Some(topk) = topk {
         if topk.asc {
             parts.sort_by(|a, b| {
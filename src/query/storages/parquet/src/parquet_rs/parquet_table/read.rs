@@ -12,16 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::S3RetryConfig;
+use databend_common_storage::S3RetryLayer;
+use opendal::Operator;
+use parquet::file::metadata::FileMetaData;
 
 use super::ParquetRSTable;
+use crate::parquet_rs::copy_into_table::ParquetCopySource;
+use crate::parquet_rs::copy_into_table::RowGroupReaderForCopy;
+use crate::parquet_rs::hive_partition::HivePartitionFiller;
 use crate::parquet_rs::source::ParquetSource;
 use crate::utils::calc_parallelism;
 use crate::ParquetPart;
@@ -29,6 +40,23 @@ use crate::ParquetRSPruner;
 use crate::ParquetRSReaderBuilder;
 
 impl ParquetRSTable {
+    /// The operator reading the data of the files, which retries the reads failed with
+    /// transient errors (such as 503) as configured by the read options.
+    ///
+    /// The other errors, such as not found or permission denied, are returned at once.
+    fn read_operator(&self) -> Operator {
+        if self.read_options.max_read_retries() == 0 {
+            return self.operator.clone();
+        }
+
+        let config = S3RetryConfig {
+            max_retries: self.read_options.max_read_retries(),
+            initial_delay: Duration::from_millis(self.read_options.read_retry_initial_delay_ms()),
+            ..Default::default()
+        };
+        self.operator.clone().layer(S3RetryLayer::new(config))
+    }
+
     #[inline]
     pub(super) fn do_read_data(
         &self,
@@ -53,7 +81,7 @@ impl ParquetRSTable {
                 self.leaf_fields.clone(),
                 &plan.push_downs,
                 self.read_options,
-                vec![],
+                self.hive_partition_columns.clone(),
             )?)
         } else {
             None
@@ -61,19 +89,30 @@ impl ParquetRSTable {
 
         let num_threads = calc_parallelism(&ctx, plan)?;
 
-        let topk = plan
-            .push_downs
-            .as_ref()
-            .and_then(|p| p.top_k(&self.schema()));
+        // The files are read without the partition columns, which are filled into the
+        // blocks read from them.
+        let file_schema = self.file_schema();
+        let file_push_downs = self.file_push_downs(plan.push_downs.as_ref());
+        let hive_partition_filler = if self.hive_partition_columns.is_empty() {
+            None
+        } else {
+            Some(Arc::new(HivePartitionFiller::create(
+                self.hive_partition_columns.clone(),
+                self.read_options.case_sensitive(),
+                &plan.schema(),
+            )))
+        };
+
+        let topk = file_push_downs.as_ref().and_then(|p| p.top_k(&file_schema));
 
         let mut builder = ParquetRSReaderBuilder::create_with_parquet_schema(
             ctx.clone(),
-            self.operator.clone(),
-            table_schema,
+            self.read_operator(),
+            file_schema,
             self.schema_descr.clone(),
         )
         .with_options(self.read_options)
-        .with_push_downs(plan.push_downs.as_ref())
+        .with_push_downs(file_push_downs.as_ref())
         .with_pruner(pruner)
         .with_topk(topk.as_ref());
 
@@ -93,6 +132,67 @@ impl ParquetRSTable {
                     row_group_reader.clone(),
                     full_file_reader.clone(),
                     topk.clone(),
+                    self.read_options.snapshot_consistency(),
+                    hive_partition_filler.clone(),
+                )
+            },
+            num_threads,
+        )
+    }
+
+    /// Read the files with a merged schema.
+    ///
+    /// The columns of each file are matched with the columns of the table by name and cast
+    /// to the merged types, the columns missing in a file are filled with NULLs.
+    pub(super) fn do_read_data_with_merged_schema(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output_schema = plan.schema();
+        let default_values = vec![Scalar::Null; output_schema.num_fields()];
+
+        let operator = self.read_operator();
+        let mut readers = HashMap::new();
+        for part in &plan.parts.partitions {
+            let part = part.as_any().downcast_ref::<ParquetPart>().unwrap();
+            match part {
+                ParquetPart::ParquetRSRowGroup(part) => {
+                    if readers.contains_key(&part.schema_index) {
+                        continue;
+                    }
+                    let file_meta_data =
+                        FileMetaData::new(0, 0, None, None, part.meta.schema_descr_ptr(), None);
+                    readers.insert(
+                        part.schema_index,
+                        RowGroupReaderForCopy::try_create(
+                            &part.location,
+                            ctx.clone(),
+                            operator.clone(),
+                            &file_meta_data,
+                            output_schema.clone(),
+                            default_values.clone(),
+                            self.read_options.case_sensitive(),
+                        )?,
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+        let readers = Arc::new(readers);
+
+        let num_threads = calc_parallelism(&ctx, plan)?;
+        let data_schema = Arc::new(DataSchema::from(&output_schema));
+        pipeline.add_source(
+            |output| {
+                ParquetCopySource::try_create(
+                    ctx.clone(),
+                    output,
+                    readers.clone(),
+                    operator.clone(),
+                    data_schema.clone(),
+                    self.read_options.snapshot_consistency(),
                 )
             },
             num_threads,
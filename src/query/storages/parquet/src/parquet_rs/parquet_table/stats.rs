@@ -24,7 +24,10 @@ pub fn create_stats_provider(
     num_columns: usize,
 ) -> ParquetTableColumnStatisticsProvider {
     let mut num_rows = 0;
+    let mut num_row_groups = 0;
     let mut basic_column_stats = vec![BasicColumnStatistics::new_null(); num_columns];
+    // The number of row groups having the statistics of each column.
+    let mut num_row_groups_with_stats = vec![0; num_columns];
 
     for meta in metas {
         num_rows += meta
@@ -33,12 +36,14 @@ pub fn create_stats_provider(
             .iter()
             .map(|r| r.num_rows() as u64)
             .sum::<u64>();
+        num_row_groups += meta.meta.num_row_groups();
         if let Some(stats) = &meta.row_group_level_stats {
             for rg_stat in stats {
                 for (column_id, col_stat) in rg_stat {
                     let column_id = *column_id as usize;
                     let col_stat = col_stat.clone().into();
                     basic_column_stats[column_id].merge(col_stat);
+                    num_row_groups_with_stats[column_id] += 1;
                 }
             }
         }
@@ -46,7 +51,13 @@ pub fn create_stats_provider(
 
     let mut column_stats = HashMap::with_capacity(basic_column_stats.len());
     for (column_id, col_stat) in basic_column_stats.into_iter().enumerate() {
-        column_stats.insert(column_id as u32, col_stat.get_useful_stat(num_rows));
+        // The statistics of a column are unknown if any row group doesn't have them.
+        let col_stat = if num_row_groups_with_stats[column_id] == num_row_groups {
+            col_stat.get_useful_stat(num_rows)
+        } else {
+            None
+        };
+        column_stats.insert(column_id as u32, col_stat);
     }
 
     ParquetTableColumnStatisticsProvider::new(column_stats, num_rows)
@@ -37,23 +37,34 @@ use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::Pipeline;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::parquet_rs::infer_schema_with_extension;
 use databend_common_storage::parquet_rs::read_metadata_async;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storage::StorageMetrics;
+use databend_common_storage::StorageMetricsLayer;
 use opendal::Operator;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::schema::types::SchemaDescPtr;
 
 use super::stats::create_stats_provider;
+use crate::parquet_rs::hive_partition::hive_partition_columns;
+use crate::parquet_rs::hive_partition::hive_partition_fields;
+use crate::parquet_rs::hive_partition::push_downs_of_files;
 use crate::parquet_rs::meta::read_metas_in_parallel;
 use crate::parquet_rs::schema::arrow_to_table_schema;
+use crate::parquet_rs::schema::unify_schemas;
+
+/// The number of files, from the first one, whose schemas are merged with `schema_merge`.
+const SCHEMA_MERGE_SAMPLE_FILES: usize = 16;
 
 pub struct ParquetRSTable {
     pub(super) read_options: ParquetReadOptions,
@@ -61,6 +72,7 @@ pub struct ParquetRSTable {
     pub(super) files_info: StageFilesInfo,
 
     pub(super) operator: Operator,
+    pub(super) data_metrics: Arc<StorageMetrics>,
 
     pub(super) table_info: TableInfo,
     pub(super) arrow_schema: ArrowSchema,
@@ -72,6 +84,9 @@ pub struct ParquetRSTable {
     /// It's should be parallel with the parquet schema descriptor.
     /// Computing leaf fields could be expensive, so we store it here.
     pub(super) leaf_fields: Arc<Vec<TableField>>,
+    /// The columns parsed from the Hive-style directories of the files,
+    /// which are the last fields of the table schema.
+    pub(super) hive_partition_columns: Vec<String>,
 
     /// Lazy read parquet file metas.
     ///
@@ -92,12 +107,15 @@ pub struct ParquetRSTable {
 
 impl ParquetRSTable {
     pub fn from_info(info: &ParquetTableInfo) -> Result<Arc<dyn Table>> {
-        let operator = init_stage_operator(&info.stage_info)?;
+        let data_metrics = Arc::new(StorageMetrics::default());
+        let operator = init_stage_read_operator(&info.stage_info)?
+            .layer(StorageMetricsLayer::new(data_metrics.clone()));
 
         Ok(Arc::new(ParquetRSTable {
             table_info: info.table_info.clone(),
             arrow_schema: info.arrow_schema.clone(),
             operator,
+            data_metrics,
             read_options: info.read_options,
             stage_info: info.stage_info.clone(),
             files_info: info.files_info.clone(),
@@ -105,6 +123,7 @@ impl ParquetRSTable {
             schema_descr: info.schema_descr.clone(),
             schema_from: info.schema_from.clone(),
             leaf_fields: info.leaf_fields.clone(),
+            hive_partition_columns: info.hive_partition_columns.clone(),
             compression_ratio: info.compression_ratio,
             parquet_metas: info.parquet_metas.clone(),
             need_stats_provider: info.need_stats_provider,
@@ -121,7 +140,9 @@ impl ParquetRSTable {
         read_options: ParquetReadOptions,
         files_to_read: Option<Vec<StageFileInfo>>,
     ) -> Result<Arc<dyn Table>> {
-        let operator = init_stage_operator(&stage_info)?;
+        let data_metrics = Arc::new(StorageMetrics::default());
+        let operator = init_stage_read_operator(&stage_info)?
+            .layer(StorageMetricsLayer::new(data_metrics.clone()));
         let first_file = match &files_to_read {
             Some(files) => files[0].path.clone(),
             None => files_info.first_file(&operator).await?.path.clone(),
@@ -129,14 +150,51 @@ impl ParquetRSTable {
 
         let (arrow_schema, schema_descr, compression_ratio) =
             Self::prepare_metas(&first_file, operator.clone()).await?;
+        let arrow_schema = if read_options.schema_merge() {
+            Self::merge_schemas(
+                &operator,
+                &files_info,
+                files_to_read.as_deref(),
+                read_options.case_sensitive(),
+            )
+            .await?
+        } else {
+            arrow_schema
+        };
 
-        let table_info = create_parquet_table_info(&arrow_schema, &stage_info)?;
-        let leaf_fields = Arc::new(table_info.schema().leaf_fields());
+        let file_schema = arrow_to_table_schema(&arrow_schema, read_options.case_sensitive())?;
+        let hive_partition_columns =
+            if read_options.hive_partitioning() && !read_options.schema_merge() {
+                let files = match &files_to_read {
+                    Some(files) => files.clone(),
+                    None => files_info.list(&operator, false, None).await?,
+                };
+                let columns = hive_partition_columns(
+                    files.iter().map(|file| file.path.as_str()),
+                    read_options.case_sensitive(),
+                );
+                // The columns of the files take precedence over the directories.
+                columns
+                    .into_iter()
+                    .filter(|column| !file_schema.has_field(column))
+                    .collect()
+            } else {
+                vec![]
+            };
+
+        // The partition columns are not in the files, so they are not leaf fields.
+        let leaf_fields = Arc::new(file_schema.leaf_fields());
+        let mut schema = file_schema;
+        schema.add_columns(&hive_partition_fields(&hive_partition_columns))?;
+        let table_info = create_parquet_table_info(schema, &stage_info);
 
         // If the query is `COPY`, we don't need to collect column statistics.
         // It's because the only transform could be contained in `COPY` command is projection.
-        let need_stats_provider = !matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable);
+        // Collecting them reads the footers of all the files while planning, which can also be
+        // disabled for the queries sensitive to the latency.
         let settings = ctx.get_settings();
+        let need_stats_provider = !matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable)
+            && settings.get_enable_parquet_statistics()?;
         let max_threads = settings.get_max_threads()? as usize;
         let max_memory_usage = settings.get_max_memory_usage()?;
 
@@ -144,9 +202,11 @@ impl ParquetRSTable {
             table_info,
             arrow_schema,
             operator,
+            data_metrics,
             read_options,
             schema_descr,
             leaf_fields,
+            hive_partition_columns,
             stage_info,
             files_info,
             files_to_read,
@@ -174,6 +234,65 @@ impl ParquetRSTable {
         let schema_descr = first_meta.file_metadata().schema_descr_ptr();
         Ok((arrow_schema, schema_descr, compression_ratio))
     }
+
+    /// Merge the schemas of the first files to read, the columns missing in the other
+    /// files are filled with NULLs, and the columns not in the merged schema are ignored.
+    #[async_backtrace::framed]
+    async fn merge_schemas(
+        operator: &Operator,
+        files_info: &StageFilesInfo,
+        files_to_read: Option<&[StageFileInfo]>,
+        case_sensitive: bool,
+    ) -> Result<ArrowSchema> {
+        let files = match files_to_read {
+            Some(files) => files
+                .iter()
+                .take(SCHEMA_MERGE_SAMPLE_FILES)
+                .cloned()
+                .collect(),
+            None => {
+                files_info
+                    .list(operator, false, Some(SCHEMA_MERGE_SAMPLE_FILES))
+                    .await?
+            }
+        };
+        let mut schemas = Vec::with_capacity(files.len());
+        for file in files {
+            let meta = read_metadata_async(&file.path, operator, Some(file.size)).await?;
+            let schema = infer_schema_with_extension(meta.file_metadata())?;
+            schemas.push((file.path, schema));
+        }
+        unify_schemas(&schemas, case_sensitive)
+    }
+
+    /// The schema of the columns in the files, without the partition columns.
+    pub(super) fn file_schema(&self) -> TableSchemaRef {
+        let schema = self.schema();
+        if self.hive_partition_columns.is_empty() {
+            return schema;
+        }
+        let num_file_fields = schema.num_fields() - self.hive_partition_columns.len();
+        Arc::new(TableSchema::new(
+            schema.fields()[..num_file_fields].to_vec(),
+        ))
+    }
+
+    /// The push downs to the files, see [`push_downs_of_files`].
+    pub(super) fn file_push_downs(
+        &self,
+        push_downs: Option<&PushDownInfo>,
+    ) -> Option<PushDownInfo> {
+        let push_downs = push_downs?;
+        if self.hive_partition_columns.is_empty() {
+            return Some(push_downs.clone());
+        }
+        let num_file_fields = self.schema().num_fields() - self.hive_partition_columns.len();
+        Some(push_downs_of_files(
+            push_downs,
+            num_file_fields,
+            &self.hive_partition_columns,
+        ))
+    }
 }
 
 #[async_trait::async_trait]
@@ -191,17 +310,26 @@ impl Table for ParquetRSTable {
     }
 
     fn support_column_projection(&self) -> bool {
-        true
+        // The files with merged schemas are read by the names of the top level columns,
+        // while the projection may contain inner columns of tuples.
+        !self.read_options.schema_merge()
     }
 
     fn support_prewhere(&self) -> bool {
+        // The partition columns are not in the files to be read by prewhere.
         self.read_options.do_prewhere()
+            && !self.read_options.schema_merge()
+            && self.hive_partition_columns.is_empty()
     }
 
     fn has_exact_total_row_count(&self) -> bool {
         true
     }
 
+    fn get_data_metrics(&self) -> Option<Arc<StorageMetrics>> {
+        Some(self.data_metrics.clone())
+    }
+
     fn get_data_source_info(&self) -> DataSourceInfo {
         DataSourceInfo::ParquetSource(ParquetTableInfo {
             table_info: self.table_info.clone(),
@@ -210,6 +338,7 @@ impl Table for ParquetRSTable {
             stage_info: self.stage_info.clone(),
             schema_descr: self.schema_descr.clone(),
             leaf_fields: self.leaf_fields.clone(),
+            hive_partition_columns: self.hive_partition_columns.clone(),
             files_info: self.files_info.clone(),
             files_to_read: self.files_to_read.clone(),
             schema_from: self.schema_from.clone(),
@@ -230,6 +359,9 @@ impl Table for ParquetRSTable {
         push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
+        if self.read_options.schema_merge() {
+            return self.do_read_partitions_with_merged_schema(ctx).await;
+        }
         self.do_read_partitions(ctx, push_downs).await
     }
 
@@ -240,6 +372,9 @@ impl Table for ParquetRSTable {
         pipeline: &mut Pipeline,
         _put_cache: bool,
     ) -> Result<()> {
+        if self.read_options.schema_merge() {
+            return self.do_read_data_with_merged_schema(ctx, plan, pipeline);
+        }
         self.do_read_data(ctx, plan, pipeline)
     }
 
@@ -251,7 +386,9 @@ impl Table for ParquetRSTable {
         &self,
         _ctx: Arc<dyn TableContext>,
     ) -> Result<Box<dyn ColumnStatisticsProvider>> {
-        if !self.need_stats_provider {
+        // The files with merged schemas don't share the same parquet schema,
+        // so their statistics are not collected.
+        if !self.need_stats_provider || self.read_options.schema_merge() {
             return Ok(Box::new(DummyColumnStatisticsProvider));
         }
 
@@ -316,29 +453,42 @@ impl Table for ParquetRSTable {
             .iter()
             .map(|m| m.meta.file_metadata().num_rows() as u64)
             .sum();
+        let row_groups = parquet_metas.iter().flat_map(|m| m.meta.row_groups());
+        let data_size = row_groups
+            .clone()
+            .map(|rg| rg.total_byte_size() as u64)
+            .sum();
+        let data_size_compressed = row_groups
+            .clone()
+            .map(|rg| rg.compressed_size() as u64)
+            .sum();
+        let number_of_blocks = row_groups.count() as u64;
 
         // Other fields are not needed yet.
         Ok(Some(TableStatistics {
             num_rows: Some(num_rows),
+            data_size: Some(data_size),
+            data_size_compressed: Some(data_size_compressed),
+            number_of_blocks: Some(number_of_blocks),
             ..Default::default()
         }))
     }
 }
 
-fn create_parquet_table_info(schema: &ArrowSchema, stage_info: &StageInfo) -> Result<TableInfo> {
-    Ok(TableInfo {
+fn create_parquet_table_info(schema: TableSchema, stage_info: &StageInfo) -> TableInfo {
+    TableInfo {
         ident: TableIdent::new(0, 0),
         desc: "''.'read_parquet'".to_string(),
         name: format!("read_parquet({})", stage_info.stage_name),
         meta: TableMeta {
-            schema: arrow_to_table_schema(schema)?.into(),
+            schema: Arc::new(schema),
             engine: "SystemReadParquet".to_string(),
             created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
             updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
             ..Default::default()
         },
         ..Default::default()
-    })
+    }
 }
 
 fn get_compression_ratio(filemeta: &ParquetMetaData) -> f64 {
@@ -103,6 +103,9 @@ pub struct ParquetRSRowGroupPart {
     pub omit_filter: bool,
 
     pub schema_index: usize,
+    /// The etag of the file captured when planning, checked before reading the row group
+    /// if the snapshot of the files is pinned by `snapshot_consistency`.
+    pub etag: Option<String>,
 }
 
 impl Eq for ParquetRSRowGroupPart {}
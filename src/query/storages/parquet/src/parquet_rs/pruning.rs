@@ -26,12 +26,14 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_storages_common_pruner::RangePruner;
 use databend_storages_common_pruner::RangePrunerCreator;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use opendal::Operator;
 use parquet::arrow::arrow_reader::RowSelection;
 use parquet::arrow::arrow_reader::RowSelector;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::format::PageLocation;
 
 use super::statistics::collect_row_group_stats;
+use crate::parquet_rs::bloom_filter::ParquetBloomFilterPruner;
 use crate::parquet_rs::statistics::convert_index_to_column_statistics;
 
 /// A pruner to prune row groups and pages of a parquet files.
@@ -46,6 +48,7 @@ pub struct ParquetRSPruner {
     )>,
     prune_row_groups: bool,
     prune_pages: bool,
+    bloom_filter_pruner: Option<ParquetBloomFilterPruner>,
 
     /// Leaf ids of columns contained in filter predicates.
     predicate_columns: Vec<usize>,
@@ -100,11 +103,21 @@ impl ParquetRSPruner {
                 None
             };
 
+        let bloom_filter_pruner = filter
+            .filter(|_| options.prune_row_groups() && options.use_bloom_filter())
+            .and_then(|f| {
+                ParquetBloomFilterPruner::try_create(
+                    &f.filter.as_expr(&BUILTIN_FUNCTIONS),
+                    &leaf_fields,
+                )
+            });
+
         Ok(ParquetRSPruner {
             leaf_fields,
             range_pruner,
             prune_row_groups: options.prune_row_groups(),
             prune_pages: options.prune_pages(),
+            bloom_filter_pruner,
             predicate_columns,
         })
     }
@@ -167,6 +180,36 @@ impl ParquetRSPruner {
         }
     }
 
+    /// Prune the row groups selected by [`Self::prune_row_groups`] with the bloom filters of
+    /// the columns, which are read from the file.
+    #[async_backtrace::framed]
+    pub async fn prune_row_groups_by_bloom_filters(
+        &self,
+        op: &Operator,
+        location: &str,
+        meta: &ParquetMetaData,
+        row_groups: Vec<usize>,
+        omits: Vec<bool>,
+    ) -> Result<(Vec<usize>, Vec<bool>)> {
+        let bloom_filter_pruner = match &self.bloom_filter_pruner {
+            Some(pruner) => pruner,
+            None => return Ok((row_groups, omits)),
+        };
+
+        let mut selection = Vec::with_capacity(row_groups.len());
+        let mut selected_omits = Vec::with_capacity(omits.len());
+        for (rg, omit) in row_groups.into_iter().zip(omits.into_iter()) {
+            if bloom_filter_pruner
+                .should_keep(op, location, meta.row_group(rg))
+                .await?
+            {
+                selection.push(rg);
+                selected_omits.push(omit);
+            }
+        }
+        Ok((selection, selected_omits))
+    }
+
     /// Prune pages of a parquet file.
     ///
     /// Return a vector of [`RowSelection`] to represent rows to read.
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_schema::DataType as ArrowDataType;
@@ -43,12 +44,195 @@ pub(crate) fn lower_field_name(field: &ArrowField) -> ArrowField {
     }
 }
 
-pub(crate) fn arrow_to_table_schema(schema: &ArrowSchema) -> Result<TableSchema> {
-    let fields = schema
-        .fields
-        .iter()
-        .map(|f| Arc::new(lower_field_name(f)))
-        .collect::<Vec<_>>();
+/// Convert the arrow schema of a parquet file to a table schema.
+///
+/// The field names are lowered, including the nested ones, unless `case_sensitive`,
+/// in which case the fields must have distinct names.
+pub(crate) fn arrow_to_table_schema(
+    schema: &ArrowSchema,
+    case_sensitive: bool,
+) -> Result<TableSchema> {
+    let fields = if case_sensitive {
+        let mut names = HashSet::with_capacity(schema.fields.len());
+        for f in schema.fields.iter() {
+            if !names.insert(f.name()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "duplicate column name '{}' in parquet schema",
+                    f.name()
+                )));
+            }
+        }
+        schema.fields.iter().cloned().collect::<Vec<_>>()
+    } else {
+        schema
+            .fields
+            .iter()
+            .map(|f| Arc::new(lower_field_name(f)))
+            .collect::<Vec<_>>()
+    };
     let schema = ArrowSchema::new_with_metadata(fields, schema.metadata().clone());
     TableSchema::try_from(&schema).map_err(ErrorCode::from_std_error)
 }
+
+/// Merge the arrow schemas of parquet files, with their locations, into a schema having
+/// the columns of all of them, in the order they first appear.
+///
+/// The columns are matched by name, lowered unless `case_sensitive`. The types of a column
+/// must be the same in all the files, or integers or floats that can be widened to the
+/// widest of them. All the columns are nullable, to be filled with NULLs for the files
+/// missing them.
+pub(crate) fn unify_schemas(
+    schemas: &[(String, ArrowSchema)],
+    case_sensitive: bool,
+) -> Result<ArrowSchema> {
+    let mut fields: Vec<ArrowField> = vec![];
+    let mut first_locations: Vec<&str> = vec![];
+    for (location, schema) in schemas {
+        for field in schema.fields.iter() {
+            let field = if case_sensitive {
+                field.as_ref().clone()
+            } else {
+                lower_field_name(field)
+            };
+            match fields.iter().position(|f| f.name() == field.name()) {
+                Some(pos) => {
+                    let merged = &fields[pos];
+                    let data_type = promote_type(merged.data_type(), field.data_type())
+                        .ok_or_else(|| {
+                            ErrorCode::TableSchemaMismatch(format!(
+                                "column '{}' is {} in parquet file '{}', but {} in '{}'",
+                                field.name(),
+                                field.data_type(),
+                                location,
+                                merged.data_type(),
+                                first_locations[pos],
+                            ))
+                        })?;
+                    fields[pos] = merged.clone().with_data_type(data_type);
+                }
+                None => {
+                    fields.push(field.with_nullable(true));
+                    first_locations.push(location);
+                }
+            }
+        }
+    }
+    let metadata = schemas
+        .first()
+        .map(|(_, s)| s.metadata().clone())
+        .unwrap_or_default();
+    Ok(ArrowSchema::new_with_metadata(fields, metadata))
+}
+
+fn promote_type(l: &ArrowDataType, r: &ArrowDataType) -> Option<ArrowDataType> {
+    use ArrowDataType::*;
+    if l == r {
+        return Some(l.clone());
+    }
+    // Each type can be widened to the types after it.
+    let widenings = [
+        vec![Int8, Int16, Int32, Int64],
+        vec![UInt8, UInt16, UInt32, UInt64],
+        vec![Float32, Float64],
+    ];
+    widenings.into_iter().find_map(|types| {
+        let l = types.iter().position(|t| t == l)?;
+        let r = types.iter().position(|t| t == r)?;
+        Some(types[l.max(r)].clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::DataType as ArrowDataType;
+    use arrow_schema::Field as ArrowField;
+    use arrow_schema::Schema as ArrowSchema;
+
+    use super::arrow_to_table_schema;
+    use super::unify_schemas;
+
+    fn field_names(schema: &ArrowSchema, case_sensitive: bool) -> Vec<String> {
+        let schema = arrow_to_table_schema(schema, case_sensitive).unwrap();
+        schema
+            .leaf_fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_arrow_to_table_schema_case_sensitive() {
+        let inner = ArrowField::new("innerId", ArrowDataType::Int32, true);
+        let schema = ArrowSchema::new(vec![
+            ArrowField::new("userId", ArrowDataType::Int32, true),
+            ArrowField::new(
+                "Info",
+                ArrowDataType::Struct(vec![Arc::new(inner)].into()),
+                true,
+            ),
+        ]);
+        assert_eq!(field_names(&schema, false), vec!["userid", "info:innerid"]);
+        assert_eq!(field_names(&schema, true), vec!["userId", "Info:innerId"]);
+
+        // The names only differing in case are only distinct if case sensitive.
+        let schema = ArrowSchema::new(vec![
+            ArrowField::new("a", ArrowDataType::Int32, true),
+            ArrowField::new("A", ArrowDataType::Int32, true),
+        ]);
+        assert_eq!(field_names(&schema, true), vec!["a", "A"]);
+        let schema = ArrowSchema::new(vec![
+            ArrowField::new("a", ArrowDataType::Int32, true),
+            ArrowField::new("a", ArrowDataType::Int32, true),
+        ]);
+        assert!(arrow_to_table_schema(&schema, true).is_err());
+    }
+
+    #[test]
+    fn test_unify_schemas() {
+        let schemas = vec![
+            (
+                "1.parquet".to_string(),
+                ArrowSchema::new(vec![
+                    ArrowField::new("a", ArrowDataType::Int32, false),
+                    ArrowField::new("b", ArrowDataType::Utf8, false),
+                ]),
+            ),
+            (
+                "2.parquet".to_string(),
+                ArrowSchema::new(vec![
+                    ArrowField::new("A", ArrowDataType::Int64, false),
+                    ArrowField::new("c", ArrowDataType::Float32, false),
+                ]),
+            ),
+        ];
+        let merged = unify_schemas(&schemas, false).unwrap();
+        assert_eq!(
+            merged,
+            ArrowSchema::new(vec![
+                ArrowField::new("a", ArrowDataType::Int64, true),
+                ArrowField::new("b", ArrowDataType::Utf8, true),
+                ArrowField::new("c", ArrowDataType::Float32, true),
+            ])
+        );
+
+        // `a` and `A` are different columns if case sensitive.
+        let merged = unify_schemas(&schemas, true).unwrap();
+        assert_eq!(merged.fields().len(), 4);
+
+        let schemas = vec![
+            (
+                "1.parquet".to_string(),
+                ArrowSchema::new(vec![ArrowField::new("a", ArrowDataType::Int32, true)]),
+            ),
+            (
+                "2.parquet".to_string(),
+                ArrowSchema::new(vec![ArrowField::new("a", ArrowDataType::Utf8, true)]),
+            ),
+        ];
+        let err = unify_schemas(&schemas, false).unwrap_err();
+        assert!(err.message().contains("1.parquet"), "{}", err.message());
+        assert!(err.message().contains("2.parquet"), "{}", err.message());
+    }
+}
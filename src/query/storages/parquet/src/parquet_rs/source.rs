@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_catalog::plan::SnapshotConsistency;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
@@ -33,7 +34,9 @@ use databend_common_pipeline_core::processors::ProfileStatisticsName;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::FileStatus;
 
+use super::hive_partition::HivePartitionFiller;
 use super::parquet_reader::policy::ReadPolicyImpl;
+use crate::utils::check_snapshot;
 use crate::ParquetPart;
 use crate::ParquetRSFullReader;
 use crate::ParquetRSRowGroupReader;
@@ -41,7 +44,8 @@ use crate::ReadSettings;
 
 enum State {
     Init,
-    ReadRowGroup(ReadPolicyImpl),
+    /// The reader of a row group and the location of its file.
+    ReadRowGroup(ReadPolicyImpl, String),
     ReadFiles(Vec<(String, Vec<u8>)>),
 }
 
@@ -67,6 +71,8 @@ pub struct ParquetSource {
     copy_status: Arc<CopyStatus>,
     /// Pushed-down topk sorter.
     topk_sorter: Option<TopKSorter>,
+    snapshot_consistency: SnapshotConsistency,
+    hive_partition_filler: Option<Arc<HivePartitionFiller>>,
 }
 
 impl ParquetSource {
@@ -76,6 +82,8 @@ impl ParquetSource {
         row_group_reader: Arc<ParquetRSRowGroupReader>,
         full_file_reader: Option<Arc<ParquetRSFullReader>>,
         topk: Arc<Option<TopK>>,
+        snapshot_consistency: SnapshotConsistency,
+        hive_partition_filler: Option<Arc<HivePartitionFiller>>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         let is_copy = matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable);
@@ -98,8 +106,17 @@ impl ParquetSource {
             copy_status,
             topk_sorter,
             full_file_reader,
+            snapshot_consistency,
+            hive_partition_filler,
         })))
     }
+
+    fn fill_partitions(&self, location: &str, block: DataBlock) -> DataBlock {
+        match &self.hive_partition_filler {
+            Some(filler) => filler.fill(location, block),
+            None => block,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -130,7 +147,7 @@ impl Processor for ParquetSource {
             None => match &self.state {
                 State::Init => Ok(Event::Async),
                 State::ReadFiles(_) => Ok(Event::Sync),
-                State::ReadRowGroup(_) => Ok(Event::Sync),
+                State::ReadRowGroup(..) => Ok(Event::Sync),
             },
             Some(data_block) => {
                 let progress_values = ProgressValues {
@@ -150,10 +167,10 @@ impl Processor for ParquetSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Init) {
-            State::ReadRowGroup(mut reader) => {
+            State::ReadRowGroup(mut reader, location) => {
                 if let Some(block) = reader.as_mut().read_block()? {
-                    self.generated_data = Some(block);
-                    self.state = State::ReadRowGroup(reader);
+                    self.generated_data = Some(self.fill_partitions(&location, block));
+                    self.state = State::ReadRowGroup(reader, location);
                 }
                 // Else: The reader is finished. We should try to build another reader.
             }
@@ -175,13 +192,13 @@ impl Processor for ParquetSource {
                         blocks.extend(bs);
                     }
                 } else {
-                    for (_, buffer) in buffers {
-                        blocks.extend(
-                            self.full_file_reader
-                                .as_ref()
-                                .unwrap()
-                                .read_blocks_from_binary(buffer)?,
-                        );
+                    for (path, buffer) in buffers {
+                        let bs = self
+                            .full_file_reader
+                            .as_ref()
+                            .unwrap()
+                            .read_blocks_from_binary(buffer)?;
+                        blocks.extend(bs.into_iter().map(|b| self.fill_partitions(&path, b)));
                     }
                 }
 
@@ -202,6 +219,18 @@ impl Processor for ParquetSource {
                 if let Some(part) = self.ctx.get_partition() {
                     match ParquetPart::from_part(&part)? {
                         ParquetPart::ParquetRSRowGroup(part) => {
+                            let readable = check_snapshot(
+                                &self.row_group_reader.operator(),
+                                &part.location,
+                                part.etag.as_deref(),
+                                self.snapshot_consistency,
+                            )
+                            .await?;
+                            if !readable {
+                                // The file has been changed, keep in init state.
+                                return Ok(());
+                            }
+                            let location = part.location.clone();
                             if let Some(reader) = self
                                 .row_group_reader
                                 .create_read_policy(
@@ -211,22 +240,27 @@ impl Processor for ParquetSource {
                                 )
                                 .await?
                             {
-                                self.state = State::ReadRowGroup(reader);
+                                self.state = State::ReadRowGroup(reader, location);
                             }
                             // Else: keep in init state.
                         }
                         ParquetPart::ParquetFiles(parts) => {
                             let mut handlers = Vec::with_capacity(parts.files.len());
-                            for (path, _) in parts.files.iter() {
+                            for (index, (path, _)) in parts.files.iter().enumerate() {
                                 let op = self.row_group_reader.operator();
                                 let path = path.clone();
+                                let etag = parts.etags.get(index).cloned().flatten();
+                                let mode = self.snapshot_consistency;
                                 handlers.push(async move {
+                                    if !check_snapshot(&op, &path, etag.as_deref(), mode).await? {
+                                        return Ok(None);
+                                    }
                                     let data = op.read(&path).await?;
-                                    Ok::<_, ErrorCode>((path, data))
+                                    Ok::<_, ErrorCode>(Some((path, data)))
                                 });
                             }
                             let buffers = futures::future::try_join_all(handlers).await?;
-                            self.state = State::ReadFiles(buffers);
+                            self.state = State::ReadFiles(buffers.into_iter().flatten().collect());
                         }
                         _ => unreachable!(),
                     }
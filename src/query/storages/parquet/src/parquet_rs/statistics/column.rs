@@ -95,18 +95,9 @@ pub fn convert_column_statistics(s: &Statistics, typ: &TableDataType) -> Option<
                     _ => (Scalar::Null, Scalar::Null),
                 }
             }
-            Statistics::Int96(s) => {
-                let (max, min) = (s.max().to_i64(), s.min().to_i64());
-                let multi = match max.checked_ilog10().unwrap_or_default() + 1 {
-                    0..=10 => 1_000_000,
-                    11..=13 => 1_000,
-                    _ => 1,
-                };
-                (
-                    Scalar::Timestamp(max * multi),
-                    Scalar::Timestamp(min * multi),
-                )
-            }
+            // The sort order of INT96 is undefined, and the timestamps may be read as the
+            // wall clock times of the session timezone, so the min and max are not used.
+            Statistics::Int96(_) => (Scalar::Null, Scalar::Null),
             Statistics::Float(s) => (Scalar::from(*s.max()), Scalar::from(*s.min())),
             Statistics::Double(s) => (Scalar::from(*s.max()), Scalar::from(*s.min())),
             Statistics::ByteArray(s) => (
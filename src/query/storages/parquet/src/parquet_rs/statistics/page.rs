@@ -23,7 +23,6 @@ use ethnum::I256;
 use parquet::data_type::AsBytes;
 use parquet::data_type::ByteArray;
 use parquet::data_type::FixedLenByteArray;
-use parquet::data_type::Int96;
 use parquet::file::page_index::index::Index;
 use parquet::file::page_index::index::PageIndex;
 
@@ -61,14 +60,8 @@ pub fn convert_index_to_column_statistics(
                 .map(|index| convert_page_index_int64(index, typ))
                 .collect()
         }
-        Index::INT96(index) => {
-            assert_eq!(num_pagas, index.indexes.len());
-            index
-                .indexes
-                .iter()
-                .map(|index| convert_page_index_int96(index, typ))
-                .collect()
-        }
+        // Not used, like the INT96 statistics of the column chunks.
+        Index::INT96(_) => vec![None; num_pagas],
         Index::FLOAT(index) => {
             assert_eq!(num_pagas, index.indexes.len());
             index
@@ -176,22 +169,6 @@ fn convert_page_index_int64(
     }
 }
 
-fn convert_page_index_int96(
-    index: &PageIndex<Int96>,
-    _typ: &TableDataType,
-) -> Option<ColumnStatistics> {
-    match (&index.min, &index.max, index.null_count) {
-        (Some(min), Some(max), Some(null_count)) => Some(ColumnStatistics::new(
-            Scalar::Timestamp(min.to_i64()),
-            Scalar::Timestamp(max.to_i64()),
-            null_count as u64,
-            0,
-            None,
-        )),
-        _ => None,
-    }
-}
-
 fn convert_page_index_float(
     index: &PageIndex<f32>,
     _typ: &TableDataType,
@@ -25,51 +25,95 @@ use crate::parquet_rs::statistics::column::convert_column_statistics;
 /// The returned vector's length is the same as `rgs`.
 ///
 /// If columns is not [None], we can only collect statistics of the specified columns.
+///
+/// The columns without statistics, or with statistics of a type that can't be converted,
+/// are left out of the statistics of their row group, so they are not used to prune it.
+/// Return [None] if no column of the row groups has statistics.
 pub fn collect_row_group_stats(
     rgs: &[RowGroupMetaData],
     leaf_fields: &[TableField],
     columns: Option<&[usize]>,
 ) -> Option<Vec<StatisticsOfColumns>> {
-    // Only if the file has row groups level statistics, we can use them to prune.
-    if rgs
-        .iter()
-        .any(|rg| rg.columns().iter().any(|c| c.statistics().is_none()))
-    {
-        return None;
-    }
+    let all_columns = (0..leaf_fields.len()).collect::<Vec<_>>();
+    let columns = columns.unwrap_or(&all_columns);
 
     let mut stats = Vec::with_capacity(rgs.len());
     for rg in rgs {
         assert_eq!(rg.num_columns(), leaf_fields.len());
-        let mut stats_of_columns = HashMap::with_capacity(rg.columns().len());
+        let mut stats_of_columns = HashMap::with_capacity(columns.len());
 
         // Each row_group_stat is a `HashMap` holding key-value pairs.
         // The first element of the pair is the offset in the schema,
         // and the second element is the statistics of the column (according to the offset)
-        if let Some(columns) = columns {
-            for col_idx in columns.iter() {
-                let column = rg.column(*col_idx);
-                let field = &leaf_fields[*col_idx];
-                let column_stats = column.statistics().unwrap();
-                stats_of_columns.insert(
-                    *col_idx as u32,
-                    convert_column_statistics(column_stats, &field.data_type().remove_nullable())?,
-                );
-            }
-        } else {
-            for (col_idx, (column, field)) in
-                rg.columns().iter().zip(leaf_fields.iter()).enumerate()
-            {
-                let column_stats = column.statistics().unwrap();
-                stats_of_columns.insert(
-                    col_idx as u32,
-                    convert_column_statistics(column_stats, &field.data_type().remove_nullable())?,
-                );
+        for col_idx in columns.iter() {
+            let field = &leaf_fields[*col_idx];
+            let column_stats = rg
+                .column(*col_idx)
+                .statistics()
+                .and_then(|s| convert_column_statistics(s, &field.data_type().remove_nullable()));
+            if let Some(column_stats) = column_stats {
+                stats_of_columns.insert(*col_idx as u32, column_stats);
             }
         }
 
         stats.push(stats_of_columns);
     }
 
+    if stats.iter().all(|s| s.is_empty()) {
+        return None;
+    }
     Some(stats)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::ArrayRef;
+    use arrow_array::Int32Array;
+    use arrow_array::RecordBatch;
+    use bytes::Bytes;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::Scalar;
+    use databend_common_expression::TableDataType;
+    use databend_common_expression::TableField;
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::EnabledStatistics;
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::reader::FileReader;
+    use parquet::file::reader::SerializedFileReader;
+    use parquet::schema::types::ColumnPath;
+
+    use super::collect_row_group_stats;
+
+    #[test]
+    fn test_collect_row_group_stats_without_column_stats() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![5, 6, 7, 8]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+        // The statistics of `b` are not written, and each row group has 2 rows.
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .set_column_statistics_enabled(ColumnPath::from("b"), EnabledStatistics::None)
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+        let row_groups = reader.metadata().row_groups();
+        assert_eq!(row_groups.len(), 2);
+
+        let leaf_fields = ["a", "b"]
+            .map(|name| TableField::new(name, TableDataType::Number(NumberDataType::Int32)));
+        let stats = collect_row_group_stats(row_groups, &leaf_fields, None).unwrap();
+        assert_eq!(stats.len(), 2);
+        for (stats, min) in stats.iter().zip([1, 3]) {
+            assert_eq!(stats.len(), 1);
+            assert_eq!(stats[&0].min, Scalar::from(min));
+        }
+
+        assert!(collect_row_group_stats(row_groups, &leaf_fields, Some(&[1])).is_none());
+    }
+}
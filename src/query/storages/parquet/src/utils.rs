@@ -16,9 +16,11 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::SnapshotConsistency;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use opendal::Operator;
 
 use crate::ParquetPart;
 
@@ -83,3 +85,34 @@ pub(crate) fn calc_parallelism(
     );
     Ok(parall_limit)
 }
+
+/// Check if a file is still the one captured when planning by its etag, returns `false`
+/// if the file has been changed and should be skipped.
+pub(crate) async fn check_snapshot(
+    op: &Operator,
+    location: &str,
+    etag: Option<&str>,
+    mode: SnapshotConsistency,
+) -> Result<bool> {
+    let Some(etag) = etag else {
+        return Ok(true);
+    };
+    if mode == SnapshotConsistency::Ignore {
+        return Ok(true);
+    }
+
+    let meta = op.stat(location).await?;
+    if meta.etag() == Some(etag) {
+        return Ok(true);
+    }
+    match mode {
+        SnapshotConsistency::Error => Err(ErrorCode::StorageOther(format!(
+            "file {location} has been changed since the query was planned, etag: {etag} -> {:?}",
+            meta.etag()
+        ))),
+        _ => {
+            log::warn!("skip file {location} changed since the query was planned");
+            Ok(false)
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use bytes::Bytes;
+use databend_common_base::base::tokio;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_storages_parquet::ParquetBloomFilterPruner;
+use opendal::services::Memory;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::FileReader;
+use parquet::file::serialized_reader::SerializedFileReader;
+use parquet::schema::types::ColumnPath;
+
+fn compare(func: &str, name: &str, value: NumberScalar) -> Expr<String> {
+    let data_type = DataType::Number(value.data_type());
+    let column = Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: data_type.clone(),
+        display_name: name.to_string(),
+    };
+    let constant = Expr::Constant {
+        span: None,
+        scalar: Scalar::Number(value),
+        data_type,
+    };
+    check_function(None, func, &[], &[column, constant], &BUILTIN_FUNCTIONS).unwrap()
+}
+
+#[tokio::test]
+async fn test_prune_row_groups_by_bloom_filter() {
+    // Column `a` has a bloom filter, column `b` doesn't.
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", ArrowDataType::Int64, false),
+        Field::new("b", ArrowDataType::Int32, false),
+    ]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![
+        Arc::new(Int64Array::from_iter_values(0..100)) as ArrayRef,
+        Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+    ])
+    .unwrap();
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(50)
+        .set_column_bloom_filter_enabled(ColumnPath::from("a"), true)
+        .build();
+    let mut buf = vec![];
+    let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let op = Operator::new(Memory::default()).unwrap().finish();
+    op.write("t.parquet", buf.clone()).await.unwrap();
+    let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+    let meta = reader.metadata();
+    assert_eq!(meta.num_row_groups(), 2);
+
+    let leaf_fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int64)),
+        TableField::new("b", TableDataType::Number(NumberDataType::Int32)),
+    ];
+
+    // The first row group contains `a = 10`.
+    let pruner = ParquetBloomFilterPruner::try_create(
+        &compare("eq", "a", NumberScalar::Int64(10)),
+        &leaf_fields,
+    )
+    .unwrap();
+    let keep = pruner
+        .should_keep(&op, "t.parquet", meta.row_group(0))
+        .await
+        .unwrap();
+    assert!(keep);
+
+    // No row group contains `a = 1000`.
+    let pruner = ParquetBloomFilterPruner::try_create(
+        &compare("eq", "a", NumberScalar::Int64(1000)),
+        &leaf_fields,
+    )
+    .unwrap();
+    for rg in meta.row_groups() {
+        let keep = pruner.should_keep(&op, "t.parquet", rg).await.unwrap();
+        assert!(!keep);
+    }
+
+    // The row groups are kept if the column has no bloom filter.
+    let pruner = ParquetBloomFilterPruner::try_create(
+        &compare("eq", "b", NumberScalar::Int32(1000)),
+        &leaf_fields,
+    )
+    .unwrap();
+    for rg in meta.row_groups() {
+        let keep = pruner.should_keep(&op, "t.parquet", rg).await.unwrap();
+        assert!(keep);
+    }
+
+    // Only the columns compared to constants with `=` are checked.
+    let filter = compare("gt", "a", NumberScalar::Int64(1000));
+    assert!(ParquetBloomFilterPruner::try_create(&filter, &leaf_fields).is_none());
+}
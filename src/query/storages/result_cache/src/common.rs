@@ -55,4 +55,10 @@ pub struct ResultCacheValue {
     pub partitions_shas: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
+    /// The tables read by the query, as `<database>.<table>`.
+    #[serde(default)]
+    pub tables: Vec<String>,
+    /// The number of times the result cache was read.
+    #[serde(default)]
+    pub hit_count: u64,
 }
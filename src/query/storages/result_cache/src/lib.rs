@@ -16,6 +16,7 @@
 #![feature(impl_trait_in_assoc_type)]
 
 mod common;
+mod manager;
 mod meta_manager;
 mod read;
 mod table_function;
@@ -24,6 +25,9 @@ mod write;
 pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use common::ResultCacheValue;
+pub use manager::ResultCacheEntry;
+pub use manager::ResultCacheManager;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
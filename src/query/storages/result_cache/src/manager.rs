@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_store::MetaStore;
+use databend_common_storage::DataOperator;
+use opendal::Operator;
+
+use crate::common::gen_result_cache_meta_key;
+use crate::common::gen_result_cache_prefix;
+use crate::common::ResultCacheValue;
+use crate::meta_manager::ResultCacheMetaManager;
+
+/// A result cache of a tenant.
+pub struct ResultCacheEntry {
+    /// The key of the result cache, the sha256 of the formatted query.
+    pub sql_digest: String,
+    pub value: ResultCacheValue,
+}
+
+impl ResultCacheEntry {
+    /// The time in seconds since 1970 when the result cache expires.
+    pub fn expire_at(&self) -> u64 {
+        self.value.query_time + self.value.ttl
+    }
+}
+
+/// Manages the result caches of a tenant, for `SHOW QUERY CACHE` and `PURGE QUERY CACHE`.
+pub struct ResultCacheManager {
+    tenant: String,
+    meta_mgr: ResultCacheMetaManager,
+    operator: Operator,
+}
+
+impl ResultCacheManager {
+    pub fn create(kv_store: Arc<MetaStore>, tenant: &str) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+            meta_mgr: ResultCacheMetaManager::create(kv_store, 0),
+            operator: DataOperator::instance().operator(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list(&self) -> Result<Vec<ResultCacheEntry>> {
+        let prefix = gen_result_cache_prefix(&self.tenant);
+        let values = self.meta_mgr.list(&prefix).await?;
+        Ok(values
+            .into_iter()
+            .map(|(key, value)| ResultCacheEntry {
+                sql_digest: key.strip_prefix(&prefix).unwrap_or(&key).to_string(),
+                value,
+            })
+            .collect())
+    }
+
+    /// Remove the result caches matching `predicate` with their cached results,
+    /// and return the number of result caches removed.
+    #[async_backtrace::framed]
+    pub async fn purge(&self, predicate: impl Fn(&ResultCacheEntry) -> bool) -> Result<usize> {
+        let mut purged = 0;
+        for entry in self.list().await? {
+            if !predicate(&entry) {
+                continue;
+            }
+            let meta_key = gen_result_cache_meta_key(&self.tenant, &entry.sql_digest);
+            self.meta_mgr.delete(meta_key).await?;
+            self.operator.delete(&entry.value.location).await?;
+            purged += 1;
+        }
+        Ok(purged)
+    }
+}
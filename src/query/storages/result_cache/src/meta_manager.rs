@@ -69,19 +69,39 @@ impl ResultCacheMetaManager {
     }
 
     #[async_backtrace::framed]
-    pub async fn list(&self, prefix: &str) -> Result<Vec<ResultCacheValue>> {
+    pub async fn list(&self, prefix: &str) -> Result<Vec<(String, ResultCacheValue)>> {
         let result = self.inner.prefix_list_kv(prefix).await?;
 
         let mut r = vec![];
-        for (_key, val) in result {
+        for (key, val) in result {
             let u = serde_json::from_slice::<ResultCacheValue>(&val.data)?;
 
-            r.push(u);
+            r.push((key, u));
         }
 
         Ok(r)
     }
 
+    #[async_backtrace::framed]
+    pub async fn delete(&self, key: String) -> Result<()> {
+        let _ = self.inner.upsert_kv(UpsertKV::delete(key)).await?;
+        Ok(())
+    }
+
+    /// Increase the hit count of the result cache.
+    ///
+    /// The hit count is not updated if the value is changed concurrently.
+    #[async_backtrace::framed]
+    pub async fn increase_hit_count(&self, key: String) -> Result<()> {
+        let Some(SeqV { seq, data, .. }) = self.inner.get_kv(&key).await? else {
+            return Ok(());
+        };
+        let mut value = serde_json::from_slice::<ResultCacheValue>(&data)?;
+        value.hit_count += 1;
+        let expire_at = value.query_time + value.ttl;
+        self.set(key, value, MatchSeq::Exact(seq), expire_at).await
+    }
+
     pub fn get_ttl(&self) -> u64 {
         self.ttl
     }
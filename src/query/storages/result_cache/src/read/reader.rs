@@ -89,9 +89,10 @@ impl ResultCacheReader {
         &self,
         meta_key: String,
     ) -> Result<Option<Vec<DataBlock>>> {
-        match self.meta_mgr.get(meta_key).await? {
+        match self.meta_mgr.get(meta_key.clone()).await? {
             Some(value) => {
                 if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
+                    self.meta_mgr.increase_hit_count(meta_key).await?;
                     if value.num_rows == 0 {
                         Ok(Some(vec![DataBlock::empty()]))
                     } else {
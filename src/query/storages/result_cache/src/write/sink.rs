@@ -37,6 +37,7 @@ pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
     partitions_shas: Vec<String>,
+    tables: Vec<String>,
 
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
@@ -82,6 +83,8 @@ impl AsyncMpscSink for WriteResultCacheSink {
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
+            tables: self.tables.clone(),
+            hit_count: 0,
         };
         self.meta_mgr
             .set(self.meta_key.clone(), value, MatchSeq::GE(0), expire_at)
@@ -97,6 +100,7 @@ impl WriteResultCacheSink {
         ctx: Arc<dyn TableContext>,
         key: &str,
         schema: TableSchemaRef,
+        tables: Vec<String>,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
     ) -> Result<ProcessorPtr> {
@@ -120,6 +124,7 @@ impl WriteResultCacheSink {
                 ctx,
                 sql,
                 partitions_shas,
+                tables,
                 meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
                 meta_key,
                 cache_writer,
@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageGuard;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -33,6 +35,7 @@ pub(super) struct ResultCacheWriter {
 
     schema: TableSchemaRef,
     blocks: Vec<DataBlock>,
+    memory_usage: MemoryUsageGuard,
     _ctx: Arc<dyn TableContext>,
 }
 
@@ -52,6 +55,7 @@ impl ResultCacheWriter {
             num_rows: 0,
             schema,
             blocks: vec![],
+            memory_usage: MemoryUsageGuard::create(MemoryComponent::ResultCache),
             _ctx: ctx,
         }
     }
@@ -60,6 +64,8 @@ impl ResultCacheWriter {
         self.current_bytes += block.memory_size();
         self.num_rows += block.num_rows();
         self.blocks.push(block);
+        self.memory_usage
+            .update(self.current_bytes, self.current_bytes);
     }
 
     pub fn over_limit(&self) -> bool {
@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::DecompressDecoder;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_storage::init_stage_read_operator;
+use databend_common_storage::read_parquet_schema_async_rs;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use opendal::Operator;
+
+/// Max bytes read from the head of a text file to infer the schema.
+const MAX_INFER_BYTES: u64 = 1024 * 1024;
+/// Max rows of a text file to infer the schema.
+const MAX_INFER_ROWS: usize = 1000;
+const MAX_INFER_COLUMNS: usize = 1024;
+
+/// Infer the schema of the files to be loaded from the first one.
+///
+/// The schema of Parquet files is read from the metadata. The schema of CSV and TSV
+/// files is inferred from the head of the file: the column names are taken from the
+/// header if there is one, otherwise `c1, c2, ...`. The column types are the narrowest
+/// one of `BIGINT`, `DOUBLE`, `BOOLEAN` and `STRING` that all the sampled values could
+/// be parsed as, and all the columns are nullable.
+#[async_backtrace::framed]
+pub async fn infer_stage_schema(
+    stage_info: &StageInfo,
+    files_info: &StageFilesInfo,
+) -> Result<TableSchema> {
+    let operator = init_stage_read_operator(stage_info)?;
+    let first_file = files_info.first_file(&operator).await?;
+
+    match &stage_info.file_format_params {
+        FileFormatParams::Parquet(_) => {
+            let arrow_schema =
+                read_parquet_schema_async_rs(&operator, &first_file.path, Some(first_file.size))
+                    .await?;
+            TableSchema::try_from(&arrow_schema)
+        }
+        FileFormatParams::Csv(params) => {
+            let options = TextOptions {
+                field_delimiter: params.field_delimiter.as_bytes()[0],
+                record_delimiter: params.record_delimiter.clone(),
+                quote: params.quote.as_bytes().first().copied(),
+                headers: params.headers as usize,
+                null_display: params.null_display.clone(),
+            };
+            let data = read_head(&operator, &first_file, params.compression).await?;
+            infer_text_schema(&data, &options)
+        }
+        FileFormatParams::Tsv(params) => {
+            let options = TextOptions {
+                field_delimiter: params.field_delimiter.as_bytes()[0],
+                record_delimiter: params.record_delimiter.clone(),
+                quote: None,
+                headers: params.headers as usize,
+                null_display: "\\N".to_string(),
+            };
+            let data = read_head(&operator, &first_file, params.compression).await?;
+            infer_text_schema(&data, &options)
+        }
+        other => Err(ErrorCode::BadArguments(format!(
+            "SCHEMA = INFER is currently limited to format Parquet, CSV and TSV, but got {}",
+            other.get_type().to_string()
+        ))),
+    }
+}
+
+struct TextOptions {
+    field_delimiter: u8,
+    record_delimiter: String,
+    quote: Option<u8>,
+    headers: usize,
+    null_display: String,
+}
+
+/// Read the head of the file, decompressed if needed.
+///
+/// The last record is dropped if the file is not read to the end, since it may be incomplete.
+async fn read_head(
+    operator: &Operator,
+    file: &StageFileInfo,
+    compression: StageFileCompression,
+) -> Result<Vec<u8>> {
+    let algo = match compression {
+        StageFileCompression::None => None,
+        StageFileCompression::Auto => CompressAlgorithm::from_path(&file.path),
+        _ => {
+            return Err(ErrorCode::BadArguments(
+                "SCHEMA = INFER only supports COMPRESSION = AUTO or NONE for text files",
+            ));
+        }
+    };
+
+    let complete = file.size <= MAX_INFER_BYTES;
+    let data = if complete {
+        operator.read(&file.path).await?
+    } else {
+        operator
+            .read_with(&file.path)
+            .range(0..MAX_INFER_BYTES)
+            .await?
+    };
+    let mut data = match algo {
+        Some(algo) if complete => DecompressDecoder::new(algo).decompress_all(&data)?,
+        // The head of the file is decompressed without finishing the stream.
+        Some(algo) => DecompressDecoder::new(algo).decompress_batch(&data)?,
+        None => data,
+    };
+
+    if !complete {
+        let end = data.iter().rposition(|b| *b == b'\n').unwrap_or(0);
+        data.truncate(end);
+    }
+    Ok(data)
+}
+
+fn infer_text_schema(data: &[u8], options: &TextOptions) -> Result<TableSchema> {
+    let records = read_records(data, options)?;
+    let mut records = records.into_iter();
+
+    let names = match records.next() {
+        None => {
+            return Err(ErrorCode::BadArguments(
+                "can not infer schema from an empty file",
+            ));
+        }
+        Some(first) if options.headers > 0 => {
+            // skip the rest of the header rows
+            for _ in 1..options.headers {
+                records.next();
+            }
+            first
+        }
+        Some(first) => {
+            let names = (1..=first.len()).map(|i| format!("c{i}")).collect();
+            records = std::iter::once(first)
+                .chain(records)
+                .collect::<Vec<_>>()
+                .into_iter();
+            names
+        }
+    };
+
+    let mut types = vec![InferredType::Unknown; names.len()];
+    for record in records {
+        for (ty, value) in types.iter_mut().zip(record.iter()) {
+            if value.is_empty() || value == &options.null_display {
+                continue;
+            }
+            *ty = ty.merge(value);
+        }
+    }
+
+    let fields = names
+        .iter()
+        .zip(types)
+        .map(|(name, ty)| TableField::new(name, TableDataType::Nullable(Box::new(ty.into()))))
+        .collect();
+    Ok(TableSchema::new(fields))
+}
+
+fn read_records(data: &[u8], options: &TextOptions) -> Result<Vec<Vec<String>>> {
+    let terminator = match options.record_delimiter.as_str() {
+        "\n" | "\r\n" => csv_core::Terminator::CRLF,
+        other => csv_core::Terminator::Any(other.as_bytes()[0]),
+    };
+    let mut reader = csv_core::ReaderBuilder::new()
+        .delimiter(options.field_delimiter)
+        .quote(options.quote.unwrap_or(b'"'))
+        .quoting(options.quote.is_some())
+        .terminator(terminator)
+        .build();
+
+    // Each record is read in one call, since the whole input is provided.
+    let mut input = data.to_vec();
+    if !input.ends_with(options.record_delimiter.as_bytes()) {
+        input.extend_from_slice(options.record_delimiter.as_bytes());
+    }
+    let mut input = input.as_slice();
+    let mut output = vec![0; input.len()];
+    let mut ends = vec![0; MAX_INFER_COLUMNS];
+    let mut records = vec![];
+    while records.len() < MAX_INFER_ROWS + options.headers {
+        let (result, n_in, _, n_ends) = reader.read_record(input, &mut output, &mut ends);
+        input = &input[n_in..];
+        match result {
+            csv_core::ReadRecordResult::Record => {
+                let mut start = 0;
+                let mut record = Vec::with_capacity(n_ends);
+                for end in &ends[..n_ends] {
+                    record.push(String::from_utf8_lossy(&output[start..*end]).to_string());
+                    start = *end;
+                }
+                records.push(record);
+            }
+            csv_core::ReadRecordResult::OutputEndsFull => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "can not infer schema of a file with more than {MAX_INFER_COLUMNS} columns"
+                )));
+            }
+            csv_core::ReadRecordResult::InputEmpty
+            | csv_core::ReadRecordResult::OutputFull
+            | csv_core::ReadRecordResult::End => break,
+        }
+    }
+    Ok(records)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InferredType {
+    Unknown,
+    Int64,
+    Float64,
+    Boolean,
+    String,
+}
+
+impl InferredType {
+    fn of(value: &str) -> Self {
+        if value.parse::<i64>().is_ok() {
+            InferredType::Int64
+        } else if value.parse::<f64>().is_ok() {
+            InferredType::Float64
+        } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            InferredType::Boolean
+        } else {
+            InferredType::String
+        }
+    }
+
+    fn merge(self, value: &str) -> Self {
+        match (self, InferredType::of(value)) {
+            (InferredType::Unknown, ty) => ty,
+            (a, b) if a == b => a,
+            (InferredType::Int64, InferredType::Float64)
+            | (InferredType::Float64, InferredType::Int64) => InferredType::Float64,
+            _ => InferredType::String,
+        }
+    }
+}
+
+impl From<InferredType> for TableDataType {
+    fn from(ty: InferredType) -> Self {
+        match ty {
+            InferredType::Int64 => TableDataType::Number(NumberDataType::Int64),
+            InferredType::Float64 => TableDataType::Number(NumberDataType::Float64),
+            InferredType::Boolean => TableDataType::Boolean,
+            InferredType::Unknown | InferredType::String => TableDataType::String,
+        }
+    }
+}
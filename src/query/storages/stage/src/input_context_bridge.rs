@@ -49,7 +49,7 @@ impl StageTable {
             StageTable::list_files(stage_info, None).await?
         };
         let format = InputContext::get_input_format(&stage_info.stage_info.file_format_params)?;
-        let operator = StageTable::get_op(&stage_info.stage_info)?;
+        let operator = StageTable::get_read_op(&stage_info.stage_info)?;
         let splits = format
             .get_splits(
                 files,
@@ -107,7 +107,7 @@ impl StageTable {
             .collect::<Vec<_>>();
         let schema = TableSchemaRefExt::create(fields);
         let stage_info = stage_table_info.stage_info.clone();
-        let operator = StageTable::get_op(&stage_table_info.stage_info)?;
+        let operator = StageTable::get_read_op(&stage_table_info.stage_info)?;
         let compact_threshold = self.get_block_compact_thresholds_with_default();
         let on_error_map = ctx.get_on_error_map().unwrap_or_else(|| {
             let m = Arc::new(DashMap::new());
@@ -137,6 +137,7 @@ impl StageTable {
             self.table_info.is_select,
             projection,
             self.table_info.default_values.clone(),
+            self.table_info.metadata_columns.clone(),
         )?);
         debug!("start copy splits feeder in {}", ctx.get_cluster().local_id);
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
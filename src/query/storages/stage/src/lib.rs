@@ -16,9 +16,11 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod append;
+mod infer_schema;
 mod input_context_bridge;
 mod one_file_partition;
 mod read;
 mod stage_table;
 
+pub use infer_schema::infer_stage_schema;
 pub use stage_table::StageTable;
@@ -80,16 +80,23 @@ impl AccumulatingTransform for Decompressor {
         }
 
         if let Some((de, offset)) = &mut self.decompressor {
-            let mut data = de.decompress_batch(&batch.data)?;
+            let corrupted = |e: ErrorCode| {
+                ErrorCode::CorruptedCompressedFile(format!(
+                    "fail to decompress file {}: {}",
+                    batch.path,
+                    e.message()
+                ))
+            };
+            let mut data = de.decompress_batch(&batch.data).map_err(corrupted)?;
             if batch.is_eof {
-                let mut end = de.decompress_batch(&[])?;
+                let mut end = de.decompress_batch(&[]).map_err(corrupted)?;
                 data.append(&mut end);
                 let state = de.state();
                 if !matches!(state, DecompressState::Done) {
-                    return Err(ErrorCode::BadBytes(format!(
+                    return Err(corrupted(ErrorCode::BadBytes(format!(
                         "decompressor state is {:?} after decompressing all data",
                         state
-                    )));
+                    ))));
                 }
             }
             let new_batch = Box::new(BytesBatch {
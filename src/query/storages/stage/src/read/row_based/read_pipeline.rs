@@ -29,7 +29,7 @@ use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 use databend_common_settings::Settings;
-use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 
 use crate::read::load_context::LoadContext;
 use crate::read::row_based::format::create_row_based_file_format;
@@ -51,7 +51,7 @@ impl RowBasedReadPipelineBuilder<'_> {
         settings: &Settings,
         num_threads: usize,
     ) -> Result<()> {
-        let operator = init_stage_operator(&self.stage_table_info.stage_info)?;
+        let operator = init_stage_read_operator(&self.stage_table_info.stage_info)?;
         let batch_size = settings.get_input_read_buffer_size()? as usize;
         pipeline.add_source(
             |output| {
@@ -35,6 +35,7 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::init_stage_read_operator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use opendal::Operator;
@@ -78,6 +79,12 @@ impl StageTable {
         init_stage_operator(stage)
     }
 
+    /// Get operator with correctly prefix to read the files of the stage, see
+    /// `init_stage_read_operator`.
+    pub fn get_read_op(stage: &StageInfo) -> Result<Operator> {
+        init_stage_read_operator(stage)
+    }
+
     #[async_backtrace::framed]
     pub async fn list_files(
         stage_info: &StageTableInfo,
@@ -160,7 +167,11 @@ impl Table for StageTable {
             FileFormatParams::Parquet(_) => {
                 ParquetTableForCopy::do_read_partitions(stage_table_info, ctx, _push_downs).await
             }
-            FileFormatParams::Csv(_) if settings.get_enable_new_copy_for_text_formats()? == 1 => {
+            // The metadata columns are only filled by the input formats.
+            FileFormatParams::Csv(_)
+                if settings.get_enable_new_copy_for_text_formats()? == 1
+                    && stage_table_info.metadata_columns.is_empty() =>
+            {
                 self.read_partitions_simple(stage_table_info).await
             }
             _ => self.read_partition_old(&ctx).await,
@@ -185,7 +196,10 @@ impl Table for StageTable {
             FileFormatParams::Parquet(_) => {
                 ParquetTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache)
             }
-            FileFormatParams::Csv(_) if settings.get_enable_new_copy_for_text_formats()? == 1 => {
+            FileFormatParams::Csv(_)
+                if settings.get_enable_new_copy_for_text_formats()? == 1
+                    && stage_table_info.metadata_columns.is_empty() =>
+            {
                 let compact_threshold = self.get_block_compact_thresholds_with_default();
                 RowBasedReadPipelineBuilder {
                     stage_table_info,
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::UInt16Type;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_management::ClusterApi;
+use databend_common_management::ClusterMgr;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_types::SeqV;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The nodes registered in the cluster registry with their heartbeat,
+/// including the nodes which are not alive but whose lease is not expired yet.
+pub struct ClusterNodesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ClusterNodesTable {
+    const NAME: &'static str = "system.cluster_nodes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let config = GlobalConfig::instance();
+        let lift_time = Duration::from_secs(config.query.cluster_heartbeat_timeout_secs);
+        let metastore = UserApiProvider::instance().get_meta_store_client();
+        let cluster_api = ClusterMgr::create(
+            metastore.as_ref().clone(),
+            &config.query.tenant_id,
+            &config.query.cluster_id,
+            lift_time,
+        )?;
+        let nodes_heartbeat = cluster_api.get_nodes_heartbeat().await?;
+
+        let local_id = ctx.get_cluster().local_id.clone();
+        let now_ms = SeqV::<()>::now_ms();
+
+        let mut names = Vec::with_capacity(nodes_heartbeat.len());
+        let mut hosts = Vec::with_capacity(nodes_heartbeat.len());
+        let mut ports = Vec::with_capacity(nodes_heartbeat.len());
+        let mut versions = Vec::with_capacity(nodes_heartbeat.len());
+        let mut is_locals = Vec::with_capacity(nodes_heartbeat.len());
+        let mut last_heartbeats = Vec::with_capacity(nodes_heartbeat.len());
+        let mut statuses = Vec::with_capacity(nodes_heartbeat.len());
+        for node_heartbeat in &nodes_heartbeat {
+            let node = &node_heartbeat.node;
+            let (host, port) = node.ip_port()?;
+
+            names.push(node.id.clone());
+            hosts.push(host);
+            ports.push(port);
+            versions.push(node.binary_version.clone());
+            is_locals.push(node.id == local_id);
+            last_heartbeats.push(node_heartbeat.last_heartbeat_ms as i64 * 1000);
+            statuses.push(match node_heartbeat.is_alive(now_ms) {
+                true => "healthy",
+                false => "unhealthy",
+            });
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(hosts),
+            UInt16Type::from_data(ports),
+            StringType::from_data(versions),
+            BooleanType::from_data(is_locals),
+            TimestampType::from_data(last_heartbeats),
+            StringType::from_data(statuses),
+        ]))
+    }
+}
+
+impl ClusterNodesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("host", TableDataType::String),
+            TableField::new("port", TableDataType::Number(NumberDataType::UInt16)),
+            TableField::new("version", TableDataType::String),
+            TableField::new("is_local", TableDataType::Boolean),
+            TableField::new("last_heartbeat", TableDataType::Timestamp),
+            TableField::new("status", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'cluster_nodes'".to_string(),
+            name: "cluster_nodes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemClusterNodes".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
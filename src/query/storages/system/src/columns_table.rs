@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
 use databend_common_expression::utils::FromData;
+use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
@@ -80,6 +81,12 @@ impl AsyncSystemTable for ColumnsTable {
             if let Some(expr) = field.default_expr() {
                 default_kind = "DEFAULT".to_string();
                 default_expr = expr.to_string();
+            } else if let Some(computed_expr) = field.computed_expr() {
+                default_kind = match computed_expr {
+                    ComputedExpr::Virtual(_) => "VIRTUAL".to_string(),
+                    ComputedExpr::Stored(_) => "STORED".to_string(),
+                };
+                default_expr = computed_expr.expr().clone();
             }
             default_kinds.push(default_kind);
             default_exprs.push(default_expr);
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// The I/O of a storage operator in a finished query.
+#[derive(Clone)]
+pub struct IoStatsLogElement {
+    pub query_id: String,
+    pub operator_name: String,
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    pub read_requests: u64,
+    pub write_requests: u64,
+    pub read_latency_p99_ms: u64,
+}
+
+impl SystemLogElement for IoStatsLogElement {
+    const TABLE_NAME: &'static str = "io_stats";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("operator_name", TableDataType::String),
+            TableField::new("bytes_read", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "bytes_written",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "read_requests",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "write_requests",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "read_latency_p99_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.operator_name.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.bytes_read)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.bytes_written)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.read_requests)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.write_requests)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.read_latency_p99_ms)).as_ref());
+        Ok(())
+    }
+}
+
+pub type IoStatsQueue = SystemLogQueue<IoStatsLogElement>;
+pub type IoStatsTable = SystemLogTable<IoStatsLogElement>;
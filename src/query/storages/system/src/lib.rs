@@ -26,6 +26,7 @@ mod backtrace_table;
 mod build_options_table;
 mod caches_table;
 mod catalogs_table;
+mod cluster_nodes_table;
 mod clustering_history_table;
 mod clusters_table;
 mod columns_table;
@@ -36,12 +37,16 @@ mod databases_table;
 mod engines_table;
 mod functions_table;
 mod indexes_table;
+mod io_stats_table;
 mod locks_table;
 mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
+mod memory_usage_table;
 mod metrics_table;
+mod migrations_table;
 mod one_table;
+mod partitions_table;
 mod password_policies_table;
 mod processes_table;
 mod processor_profile_table;
@@ -50,13 +55,16 @@ mod query_log_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
+mod status_table;
 mod streams_table;
 mod table;
 mod table_functions_table;
+mod table_statistics_table;
 mod tables_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
+mod tenant_resource_usage_table;
 mod tracing_table;
 mod user_functions_table;
 mod users_table;
@@ -69,6 +77,7 @@ pub use backtrace_table::BacktraceTable;
 pub use build_options_table::BuildOptionsTable;
 pub use caches_table::CachesTable;
 pub use catalogs_table::CatalogsTable;
+pub use cluster_nodes_table::ClusterNodesTable;
 pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
@@ -81,14 +90,20 @@ pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
+pub use io_stats_table::IoStatsLogElement;
+pub use io_stats_table::IoStatsQueue;
+pub use io_stats_table::IoStatsTable;
 pub use locks_table::LocksTable;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
+pub use memory_usage_table::MemoryUsageTable;
 pub use metrics_table::MetricsTable;
+pub use migrations_table::MigrationsTable;
 pub use one_table::OneTable;
+pub use partitions_table::PartitionsTable;
 pub use password_policies_table::PasswordPoliciesTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
@@ -100,18 +115,23 @@ pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
+pub use status_table::StatusTable;
 pub use streams_table::StreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
 pub use table_functions_table::TableFunctionsTable;
+pub use table_statistics_table::TableStatisticsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::parse_local_task_runs_to_datablock;
 pub use task_history_table::parse_task_runs_to_datablock;
 pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::parse_local_tasks_to_datablock;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
+pub use tenant_resource_usage_table::TenantResourceUsageTable;
 pub use tracing_table::TracingTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::MemoryComponent;
+use databend_common_base::runtime::MemoryUsageRegistry;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::TableContext;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+pub struct MemoryUsageTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for MemoryUsageTable {
+    const NAME: &'static str = "system.memory_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let registry = MemoryUsageRegistry::instance();
+
+        let mut components = Vec::with_capacity(MemoryComponent::ALL.len());
+        let mut allocated_bytes = Vec::with_capacity(MemoryComponent::ALL.len());
+        let mut used_bytes = Vec::with_capacity(MemoryComponent::ALL.len());
+        let mut peak_bytes = Vec::with_capacity(MemoryComponent::ALL.len());
+        for component in MemoryComponent::ALL {
+            let stat = registry.get(component);
+            components.push(component.to_string());
+            // The releases may be reported before the allocations by other threads.
+            allocated_bytes.push(stat.get_allocated_bytes().max(0) as u64);
+            used_bytes.push(stat.get_used_bytes().max(0) as u64);
+            peak_bytes.push(stat.get_peak_bytes().max(0) as u64);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(components),
+            UInt64Type::from_data(allocated_bytes),
+            UInt64Type::from_data(used_bytes),
+            UInt64Type::from_data(peak_bytes),
+        ]))
+    }
+}
+
+impl MemoryUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("component", TableDataType::String),
+            TableField::new(
+                "allocated_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("used_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("peak_bytes", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'memory_usage'".to_string(),
+            name: "memory_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemMemoryUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::io::SegmentsIO;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// PartitionsTable lists the partitions of the Fuse tables.
+///
+/// A segment of the current snapshot is a partition of a table with cluster key, which is
+/// described by the range of the cluster key in it. The tables without cluster key are listed
+/// as a single row without partition.
+pub struct PartitionsTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct PartitionsColumns {
+    databases: Vec<String>,
+    tables: Vec<String>,
+    partition_names: Vec<Option<String>>,
+    ordinal_positions: Vec<Option<u64>>,
+    descriptions: Vec<Option<String>>,
+    rows: Vec<u64>,
+    data_sizes: Vec<u64>,
+    created_on: Vec<i64>,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PartitionsTable {
+    const NAME: &'static str = "system.partitions";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut columns = PartitionsColumns::default();
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, tables) in database_and_tables {
+            for table in tables {
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                Self::dump_partitions(&ctx, &database, fuse_table, &mut columns).await?;
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(columns.databases),
+            StringType::from_data(columns.tables),
+            StringType::from_opt_data(columns.partition_names),
+            UInt64Type::from_opt_data(columns.ordinal_positions),
+            StringType::from_opt_data(columns.descriptions),
+            UInt64Type::from_data(columns.rows),
+            UInt64Type::from_data(columns.data_sizes),
+            TimestampType::from_data(columns.created_on),
+        ]))
+    }
+}
+
+impl PartitionsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new(
+                "partition_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "partition_ordinal_position",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "partition_description",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("data_size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'partitions'".to_string(),
+            name: "partitions".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPartitions".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+
+    async fn dump_partitions(
+        ctx: &Arc<dyn TableContext>,
+        database: &str,
+        table: &FuseTable,
+        columns: &mut PartitionsColumns,
+    ) -> Result<()> {
+        let created_on = table.get_table_info().meta.created_on.timestamp_micros();
+        let snapshot = table.read_table_snapshot().await?;
+        let cluster_key_id = table.cluster_key_id();
+
+        let (Some(snapshot), Some(cluster_key_id)) = (snapshot.clone(), cluster_key_id) else {
+            let (rows, data_size) = snapshot.map_or((0, 0), |s| {
+                (s.summary.row_count, s.summary.compressed_byte_size)
+            });
+            columns.push(database, table.name(), None, rows, data_size, created_on);
+            return Ok(());
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), table.get_operator(), table.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut position = 0;
+        for chunk in snapshot.segments.chunks(chunk_size.max(1)) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (location, segment) in chunk.iter().zip(segments) {
+                let segment = segment?;
+                position += 1;
+                let name = location
+                    .0
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                // The segments written before the cluster key is altered are not described.
+                let description = segment
+                    .summary
+                    .cluster_stats
+                    .as_ref()
+                    .filter(|stats| stats.cluster_key_id == cluster_key_id)
+                    .map(describe_cluster_stats);
+                columns.push(
+                    database,
+                    table.name(),
+                    Some((name, position, description)),
+                    segment.summary.row_count,
+                    segment.summary.compressed_byte_size,
+                    created_on,
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PartitionsColumns {
+    fn push(
+        &mut self,
+        database: &str,
+        table: &str,
+        partition: Option<(String, u64, Option<String>)>,
+        rows: u64,
+        data_size: u64,
+        created_on: i64,
+    ) {
+        self.databases.push(database.to_string());
+        self.tables.push(table.to_string());
+        match partition {
+            Some((name, position, description)) => {
+                self.partition_names.push(Some(name));
+                self.ordinal_positions.push(Some(position));
+                self.descriptions.push(description);
+            }
+            None => {
+                self.partition_names.push(None);
+                self.ordinal_positions.push(None);
+                self.descriptions.push(None);
+            }
+        }
+        self.rows.push(rows);
+        self.data_sizes.push(data_size);
+        self.created_on.push(created_on);
+    }
+}
+
+/// Describe the range of the cluster key as `[min, max]`, the values of a multi-column
+/// cluster key are enclosed in parentheses.
+fn describe_cluster_stats(stats: &ClusterStatistics) -> String {
+    let format_values = |values: &[Scalar]| {
+        let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        if values.len() == 1 {
+            values[0].clone()
+        } else {
+            format!("({})", values.join(", "))
+        }
+    };
+    format!(
+        "[{}, {}]",
+        format_values(&stats.min),
+        format_values(&stats.max)
+    )
+}
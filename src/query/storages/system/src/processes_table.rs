@@ -64,6 +64,7 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_database = Vec::with_capacity(processes_info.len());
         let mut processes_extra_info = Vec::with_capacity(processes_info.len());
         let mut processes_memory_usage = Vec::with_capacity(processes_info.len());
+        let mut processes_aggregate_memory_usage = Vec::with_capacity(processes_info.len());
         let mut processes_data_read_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_data_write_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
@@ -71,6 +72,7 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_info_sql = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -93,6 +95,7 @@ impl SyncSystemTable for ProcessesTable {
                 process_info.session_extra_info.clone(),
             ));
             processes_memory_usage.push(process_info.memory_usage);
+            processes_aggregate_memory_usage.push(process_info.aggregate_memory_usage);
             processes_scan_progress_read_rows.push(scan_progress.rows as u64);
             processes_scan_progress_read_bytes.push(scan_progress.bytes as u64);
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
@@ -108,6 +111,7 @@ impl SyncSystemTable for ProcessesTable {
 
             // Status info.
             processes_status.push(process_info.status_info.clone().unwrap_or("".to_owned()));
+            processes_info_sql.push(process_info.current_query.clone());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -120,6 +124,7 @@ impl SyncSystemTable for ProcessesTable {
             StringType::from_data(processes_database),
             StringType::from_data(processes_extra_info),
             Int64Type::from_data(processes_memory_usage),
+            Int64Type::from_data(processes_aggregate_memory_usage),
             UInt64Type::from_data(processes_data_read_bytes),
             UInt64Type::from_data(processes_data_write_bytes),
             UInt64Type::from_data(processes_scan_progress_read_rows),
@@ -127,6 +132,7 @@ impl SyncSystemTable for ProcessesTable {
             UInt32Type::from_opt_data(processes_mysql_connection_id),
             UInt64Type::from_data(processes_time),
             StringType::from_data(processes_status),
+            StringType::from_opt_data(processes_info_sql),
         ]))
     }
 }
@@ -146,6 +152,10 @@ impl ProcessesTable {
             TableField::new("database", TableDataType::String),
             TableField::new("extra_info", TableDataType::String),
             TableField::new("memory_usage", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new(
+                "aggregate_memory_usage",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
             TableField::new(
                 "data_read_bytes",
                 TableDataType::Number(NumberDataType::UInt64),
@@ -168,6 +178,10 @@ impl ProcessesTable {
             ),
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("status", TableDataType::String),
+            TableField::new(
+                "info",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -21,6 +21,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
@@ -30,8 +31,7 @@ use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
-use databend_common_storages_result_cache::gen_result_cache_prefix;
-use databend_common_storages_result_cache::ResultCacheMetaManager;
+use databend_common_storages_result_cache::ResultCacheManager;
 use databend_common_users::UserApiProvider;
 use itertools::Itertools;
 
@@ -57,27 +57,32 @@ impl AsyncSystemTable for QueryCacheTable {
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let meta_client = UserApiProvider::instance().get_meta_store_client();
-        let result_cache_mgr = ResultCacheMetaManager::create(meta_client, 0);
-        let tenant = ctx.get_tenant();
-        let prefix = gen_result_cache_prefix(&tenant);
-
-        let cached_values = result_cache_mgr.list(prefix.as_str()).await?;
-
-        let mut sql_vec: Vec<&str> = Vec::with_capacity(cached_values.len());
-        let mut query_id_vec: Vec<&str> = Vec::with_capacity(cached_values.len());
-        let mut result_size_vec = Vec::with_capacity(cached_values.len());
-        let mut num_rows_vec = Vec::with_capacity(cached_values.len());
-        let mut partitions_sha_vec = Vec::with_capacity(cached_values.len());
-        let mut location_vec = Vec::with_capacity(cached_values.len());
-        let mut active_result_scan: Vec<bool> = Vec::with_capacity(cached_values.len());
-
-        cached_values.iter().for_each(|x| {
+        let result_cache_mgr = ResultCacheManager::create(meta_client, &ctx.get_tenant());
+
+        let cached_entries = result_cache_mgr.list().await?;
+
+        let mut sql_vec: Vec<&str> = Vec::with_capacity(cached_entries.len());
+        let mut query_id_vec: Vec<&str> = Vec::with_capacity(cached_entries.len());
+        let mut result_size_vec = Vec::with_capacity(cached_entries.len());
+        let mut num_rows_vec = Vec::with_capacity(cached_entries.len());
+        let mut partitions_sha_vec = Vec::with_capacity(cached_entries.len());
+        let mut location_vec = Vec::with_capacity(cached_entries.len());
+        let mut active_result_scan: Vec<bool> = Vec::with_capacity(cached_entries.len());
+        let mut sql_digest_vec: Vec<&str> = Vec::with_capacity(cached_entries.len());
+        let mut hit_count_vec = Vec::with_capacity(cached_entries.len());
+        let mut expires_at_vec = Vec::with_capacity(cached_entries.len());
+
+        cached_entries.iter().for_each(|entry| {
+            let x = &entry.value;
             sql_vec.push(x.sql.as_str());
             query_id_vec.push(x.query_id.as_str());
             result_size_vec.push(x.result_size as u64);
             num_rows_vec.push(x.num_rows as u64);
             partitions_sha_vec.push(x.partitions_shas.clone());
             location_vec.push(x.location.as_str());
+            sql_digest_vec.push(entry.sql_digest.as_str());
+            hit_count_vec.push(x.hit_count);
+            expires_at_vec.push(entry.expire_at() as i64 * 1_000_000);
         });
 
         let active_query_ids = ctx.get_query_id_history();
@@ -108,6 +113,9 @@ impl AsyncSystemTable for QueryCacheTable {
             ),
             StringType::from_data(location_vec),
             BooleanType::from_data(active_result_scan),
+            StringType::from_data(sql_digest_vec),
+            UInt64Type::from_data(hit_count_vec),
+            TimestampType::from_data(expires_at_vec),
         ]))
     }
 }
@@ -122,6 +130,9 @@ impl QueryCacheTable {
             TableField::new("partitions_sha", TableDataType::String),
             TableField::new("location", TableDataType::String),
             TableField::new("active_result_scan", TableDataType::Boolean),
+            TableField::new("sql_digest", TableDataType::String),
+            TableField::new("hit_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("expires_at", TableDataType::Timestamp),
         ]);
 
         let table_info = TableInfo {
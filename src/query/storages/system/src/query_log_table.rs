@@ -157,6 +157,9 @@ pub struct QueryLogElement {
     pub extra: String,
 
     pub has_profiles: bool,
+
+    // Retries of the query on cluster node failures.
+    pub query_retries: u32,
 }
 
 impl SystemLogElement for QueryLogElement {
@@ -291,6 +294,10 @@ impl SystemLogElement for QueryLogElement {
             // Extra.
             TableField::new("extra", TableDataType::String),
             TableField::new("has_profile", TableDataType::Boolean),
+            TableField::new(
+                "query_retries",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
         ])
     }
 
@@ -525,6 +532,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::Boolean(self.has_profiles).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt32(self.query_retries)).as_ref());
         Ok(())
     }
 }
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::ProcessInfoState;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_metrics::MetricSample;
+use databend_common_metrics::MetricValue;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// The status variables of the query node, in the way of the `SHOW STATUS` of MySQL.
+///
+/// The variables that are not tracked per session have the global value as the session value.
+pub struct StatusTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for StatusTable {
+    const NAME: &'static str = "system.status";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let samples = {
+            let registry = databend_common_metrics::load_global_prometheus_registry();
+            databend_common_metrics::dump_metric_samples(&registry)?
+        };
+        let sum = |name: &str| -> f64 {
+            samples
+                .iter()
+                .filter(|sample| sample_name_is(sample, name))
+                .map(|sample| match sample.value {
+                    MetricValue::Counter(v) | MetricValue::Gauge(v) | MetricValue::Untyped(v) => v,
+                    _ => 0.0,
+                })
+                .sum()
+        };
+
+        let processes = ctx.get_processes_info();
+        let session_id = ctx.get_current_session_id();
+        let threads_running = processes
+            .iter()
+            .filter(|process| process.state == ProcessInfoState::Query)
+            .count();
+        let session_memory_usage = processes
+            .iter()
+            .find(|process| process.id == session_id)
+            .map(|process| process.memory_usage)
+            .unwrap_or_default();
+
+        let uptime = SystemTime::now()
+            .duration_since(ctx.get_instance_started_at())
+            .unwrap_or_default()
+            .as_secs();
+
+        let cache_access = sum("cache_access_count");
+        let cache_hit_rate = if cache_access > 0.0 {
+            sum("cache_hit_count") / cache_access
+        } else {
+            0.0
+        };
+
+        let global_queries = sum("query_start") as u64;
+        let variables: Vec<(&str, String, Option<String>)> = vec![
+            (
+                "Bytes_received",
+                (sum("query_received_bytes") as u64).to_string(),
+                None,
+            ),
+            (
+                "Bytes_sent",
+                (sum("query_result_bytes") as u64).to_string(),
+                None,
+            ),
+            (
+                "Queries",
+                global_queries.to_string(),
+                Some(ctx.get_query_id_history().len().to_string()),
+            ),
+            ("Threads_connected", processes.len().to_string(), None),
+            ("Threads_running", threads_running.to_string(), None),
+            ("Uptime", uptime.to_string(), None),
+            (
+                "active_pipelines",
+                ctx.get_queries_profile().len().to_string(),
+                None,
+            ),
+            ("cache_hit_rate", format!("{:.4}", cache_hit_rate), None),
+            (
+                "memory_usage",
+                GLOBAL_MEM_STAT.get_memory_usage().to_string(),
+                Some(session_memory_usage.to_string()),
+            ),
+        ];
+
+        let mut names = Vec::with_capacity(variables.len());
+        let mut global_values = Vec::with_capacity(variables.len());
+        let mut session_values = Vec::with_capacity(variables.len());
+        for (name, global_value, session_value) in variables {
+            names.push(name.to_string());
+            session_values.push(session_value.unwrap_or_else(|| global_value.clone()));
+            global_values.push(global_value);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(global_values),
+            StringType::from_data(session_values),
+        ]))
+    }
+}
+
+impl StatusTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("global_value", TableDataType::String),
+            TableField::new("session_value", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'status'".to_string(),
+            name: "status".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemStatus".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(StatusTable { table_info })
+    }
+}
+
+/// The counters may be dumped with the `_total` suffix of OpenMetrics.
+fn sample_name_is(sample: &MetricSample, name: &str) -> bool {
+    sample.name == name
+        || sample
+            .name
+            .strip_suffix("_total")
+            .is_some_and(|prefix| prefix == name)
+}
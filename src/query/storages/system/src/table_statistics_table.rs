@@ -0,0 +1,297 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Float64Type;
+use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::F64;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::io::MetaReaders;
+use databend_common_storages_fuse::io::SegmentsIO;
+use databend_common_storages_fuse::io::SnapshotHistoryReader;
+use databend_common_storages_fuse::io::TableMetaLocationGenerator;
+use databend_common_storages_fuse::table_functions::ClusteringInformation;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use futures::TryStreamExt;
+use log::warn;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The offset of `average_depth` in the result of `clustering_information`.
+const AVERAGE_DEPTH_OFFSET: usize = 5;
+
+pub struct TableStatisticsTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct SnapshotMetrics {
+    clustering_depth: Option<f64>,
+    last_modified: Option<i64>,
+    last_analyzed: Option<i64>,
+    write_amplification_factor: Option<f64>,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableStatisticsTable {
+    const NAME: &'static str = "system.table_statistics";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut table_ids = vec![];
+        let mut total_rows = vec![];
+        let mut total_bytes = vec![];
+        let mut compressed_bytes = vec![];
+        let mut num_segments = vec![];
+        let mut num_blocks = vec![];
+        let mut clustering_depths = vec![];
+        let mut last_modified = vec![];
+        let mut last_analyzed = vec![];
+        let mut write_amplification_factors = vec![];
+
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, tables) in database_and_tables {
+            for table in tables {
+                if table.engine() != "FUSE" {
+                    continue;
+                }
+                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                let metrics = match snapshot_metrics(&ctx, fuse_table).await {
+                    Ok(metrics) => metrics,
+                    Err(e) => {
+                        warn!(
+                            "failed to read the snapshots of table {}.{}: {}",
+                            database,
+                            table.name(),
+                            e
+                        );
+                        SnapshotMetrics::default()
+                    }
+                };
+
+                let stats = &table.get_table_info().meta.statistics;
+                databases.push(database.clone());
+                names.push(table.name().to_string());
+                table_ids.push(table.get_id());
+                total_rows.push(stats.number_of_rows);
+                total_bytes.push(stats.data_bytes);
+                compressed_bytes.push(stats.compressed_data_bytes);
+                num_segments.push(stats.number_of_segments);
+                num_blocks.push(stats.number_of_blocks);
+                clustering_depths.push(metrics.clustering_depth.map(F64::from));
+                last_modified.push(metrics.last_modified);
+                last_analyzed.push(metrics.last_analyzed);
+                write_amplification_factors.push(metrics.write_amplification_factor.map(F64::from));
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            UInt64Type::from_data(table_ids),
+            UInt64Type::from_data(total_rows),
+            UInt64Type::from_data(total_bytes),
+            UInt64Type::from_data(compressed_bytes),
+            UInt64Type::from_opt_data(num_segments),
+            UInt64Type::from_opt_data(num_blocks),
+            Float64Type::from_opt_data(clustering_depths),
+            TimestampType::from_opt_data(last_modified),
+            TimestampType::from_opt_data(last_analyzed),
+            Float64Type::from_opt_data(write_amplification_factors),
+        ]))
+    }
+}
+
+/// Reads the metrics of a table that are not kept in the table meta from its snapshots.
+async fn snapshot_metrics(
+    ctx: &Arc<dyn TableContext>,
+    table: &FuseTable,
+) -> Result<SnapshotMetrics> {
+    let Some(location) = table.snapshot_loc().await? else {
+        return Ok(SnapshotMetrics::default());
+    };
+
+    let reader = MetaReaders::table_snapshot_reader(table.get_operator());
+    let version = TableMetaLocationGenerator::snapshot_version(&location);
+    // The history is terminated by the first snapshot that has been purged.
+    let history = reader
+        .snapshot_history(location, version, table.meta_location_generator().clone())
+        .map_ok(|(snapshot, _)| snapshot)
+        .try_collect::<Vec<_>>()
+        .await?;
+    let Some(snapshot) = history.first() else {
+        return Ok(SnapshotMetrics::default());
+    };
+
+    let clustering_depth = if table.cluster_key_meta().is_some() {
+        let info = ClusteringInformation::new(ctx.clone(), table)
+            .get_clustering_info()
+            .await?;
+        info.get_by_offset(AVERAGE_DEPTH_OFFSET)
+            .value
+            .index(0)
+            .and_then(|depth| depth.as_number().and_then(|n| n.as_float64().copied()))
+            .map(|depth| depth.0)
+    } else {
+        None
+    };
+
+    let last_analyzed = match &snapshot.table_statistics_location {
+        Some(location) => table
+            .get_operator()
+            .stat(location)
+            .await?
+            .last_modified()
+            .map(|t| t.timestamp_micros()),
+        None => None,
+    };
+
+    Ok(SnapshotMetrics {
+        clustering_depth,
+        last_modified: snapshot.timestamp.map(|t| t.timestamp_micros()),
+        last_analyzed,
+        write_amplification_factor: write_amplification_factor(ctx, table, &history).await?,
+    })
+}
+
+/// Divides the bytes of all the blocks that have been written by the snapshots in the
+/// history by the bytes of the blocks of the current snapshot.
+///
+/// The blocks rewritten by the compactions and the mutations are referenced by the earlier
+/// snapshots only, so the factor is 1.0 if the table has only been appended. Returns `None`
+/// if there is no history before the current snapshot.
+async fn write_amplification_factor(
+    ctx: &Arc<dyn TableContext>,
+    table: &FuseTable,
+    history: &[Arc<TableSnapshot>],
+) -> Result<Option<f64>> {
+    let logical_bytes = history[0].summary.compressed_byte_size;
+    if history.len() < 2 || logical_bytes == 0 {
+        return Ok(None);
+    }
+
+    let mut segment_locations = Vec::new();
+    let mut visited_segments = HashSet::new();
+    for snapshot in history {
+        for location in &snapshot.segments {
+            if visited_segments.insert(location.clone()) {
+                segment_locations.push(location.clone());
+            }
+        }
+    }
+
+    let segments_io = SegmentsIO::create(ctx.clone(), table.get_operator(), table.schema());
+    let mut visited_blocks = HashSet::new();
+    let mut written_bytes = 0;
+    let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+    for chunk in segment_locations.chunks(chunk_size) {
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(chunk, false)
+            .await?;
+        // The segments that have been purged are skipped.
+        for segment in segments.into_iter().flatten() {
+            for block in &segment.blocks {
+                if visited_blocks.insert(block.location.0.clone()) {
+                    written_bytes += block.file_size;
+                }
+            }
+        }
+    }
+
+    Ok(Some(written_bytes as f64 / logical_bytes as f64))
+}
+
+impl TableStatisticsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("total_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("total_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "compressed_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "num_segments",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "num_blocks",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "clustering_depth",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Float64))),
+            ),
+            TableField::new(
+                "last_modified",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "last_analyzed",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "write_amplification_factor",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Float64))),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_statistics'".to_string(),
+            name: "table_statistics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableStatistics".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -21,9 +22,10 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
+use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_cloud_control::task_client::make_request;
+use databend_common_cloud_control::task_utils;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::Int32Type;
@@ -34,15 +36,61 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal as mt;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
 pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock> {
+    let task_runs = task_runs
+        .into_iter()
+        .map(|task_run| task_run.try_into())
+        .collect::<Result<Vec<_>>>()?;
+    task_runs_to_datablock(task_runs)
+}
+
+/// Converts the runs of the tasks scheduled by the query nodes, which are kept in the
+/// meta service if cloud control is not enabled.
+pub fn parse_local_task_runs_to_datablock(task_runs: Vec<mt::TaskRun>) -> Result<DataBlock> {
+    task_runs_to_datablock(task_runs.into_iter().map(from_local_task_run).collect())
+}
+
+fn from_local_task_run(task_run: mt::TaskRun) -> task_utils::TaskRun {
+    task_utils::TaskRun {
+        task_id: task_run.task_id,
+        task_name: task_run.task_name,
+        query_text: task_run.query_text,
+        condition_text: "".to_string(),
+        comment: (!task_run.comment.is_empty()).then_some(task_run.comment),
+        owner: task_run.owner,
+        run_id: task_run.run_id,
+        query_id: task_run.query_id,
+        schedule_options: task_run.schedule.map(|s| s.to_string()),
+        warehouse_options: task_run.warehouse.map(|w| WarehouseOptions {
+            warehouse: Some(w),
+            using_warehouse_size: None,
+        }),
+        attempt_number: task_run.attempt_number,
+        state: match task_run.state {
+            mt::TaskRunState::Succeeded => task_utils::State::SUCCEEDED,
+            mt::TaskRunState::Failed => task_utils::State::FAILED,
+        },
+        scheduled_at: task_run.scheduled_at,
+        completed_at: Some(task_run.completed_at),
+        error_code: task_run.error_code,
+        error_message: task_run.error_message,
+        // The tasks scheduled by the query nodes have no predecessors.
+        root_task_id: task_run.task_id.to_string(),
+        session_params: task_run.session_parameters,
+    }
+}
+
+fn task_runs_to_datablock(task_runs: Vec<task_utils::TaskRun>) -> Result<DataBlock> {
     let mut name: Vec<String> = Vec::with_capacity(task_runs.len());
     let mut id: Vec<u64> = Vec::with_capacity(task_runs.len());
     let mut owner: Vec<String> = Vec::with_capacity(task_runs.len());
@@ -62,8 +110,7 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
     let mut root_task_id: Vec<String> = Vec::with_capacity(task_runs.len());
     let mut session_params: Vec<Option<Vec<u8>>> = Vec::with_capacity(task_runs.len());
 
-    for task_run in task_runs {
-        let tr: databend_common_cloud_control::task_utils::TaskRun = task_run.try_into()?;
+    for tr in task_runs {
         name.push(tr.task_name);
         id.push(tr.task_id);
         owner.push(tr.owner);
@@ -124,17 +171,25 @@ impl AsyncSystemTable for TaskHistoryTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let owners = available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect::<HashSet<_>>();
+            let task_runs = UserApiProvider::instance()
+                .get_task_runs(&tenant)
+                .await?
+                .into_iter()
+                .filter(|task_run| owners.contains(&task_run.owner))
+                .collect();
+            return parse_local_task_runs_to_datablock(task_runs);
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.clone(),
             scheduled_time_start: "".to_string(),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -21,9 +22,10 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::pb::Task;
+use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_cloud_control::task_client::make_request;
+use databend_common_cloud_control::task_utils;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -32,15 +34,58 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal as mt;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
 pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
+    let tasks = tasks
+        .into_iter()
+        .map(|task| task.try_into())
+        .collect::<Result<Vec<_>>>()?;
+    tasks_to_datablock(tasks)
+}
+
+/// Converts the tasks stored in the meta service, which are scheduled by the query nodes
+/// if cloud control is not enabled.
+pub fn parse_local_tasks_to_datablock(tasks: Vec<mt::Task>) -> Result<DataBlock> {
+    tasks_to_datablock(tasks.into_iter().map(from_local_task).collect())
+}
+
+fn from_local_task(task: mt::Task) -> task_utils::Task {
+    task_utils::Task {
+        task_id: task.id,
+        task_name: task.name,
+        query_text: task.query_text,
+        condition_text: "".to_string(),
+        after: vec![],
+        comment: (!task.comment.is_empty()).then_some(task.comment),
+        owner: task.owner,
+        schedule_options: task.schedule.map(|s| s.to_string()),
+        warehouse_options: task.warehouse.map(|w| WarehouseOptions {
+            warehouse: Some(w),
+            using_warehouse_size: None,
+        }),
+        next_scheduled_at: task.next_scheduled_at,
+        suspend_task_after_num_failures: task.suspend_task_after_num_failures.map(|n| n as i32),
+        status: match task.state {
+            mt::TaskState::Suspended => task_utils::Status::Suspended,
+            mt::TaskState::Started => task_utils::Status::Started,
+        },
+        created_at: task.created_on,
+        updated_at: task.updated_on,
+        last_suspended_at: task.last_suspended_at,
+        session_params: task.session_parameters,
+    }
+}
+
+fn tasks_to_datablock(tasks: Vec<task_utils::Task>) -> Result<DataBlock> {
     let mut created_on: Vec<i64> = Vec::with_capacity(tasks.len());
     let mut name: Vec<String> = Vec::with_capacity(tasks.len());
     let mut id: Vec<u64> = Vec::with_capacity(tasks.len());
@@ -57,8 +102,7 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     let mut next_schedule_time: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
     let mut last_suspended_on: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
     let mut session_params: Vec<Option<Vec<u8>>> = Vec::with_capacity(tasks.len());
-    for task in tasks {
-        let tsk: databend_common_cloud_control::task_utils::Task = task.try_into()?;
+    for tsk in tasks {
         created_on.push(tsk.created_at.timestamp_micros());
         name.push(tsk.task_name);
         id.push(tsk.task_id);
@@ -117,17 +161,25 @@ impl AsyncSystemTable for TasksTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let owners = available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect::<HashSet<_>>();
+            let tasks = UserApiProvider::instance()
+                .get_tasks(&tenant)
+                .await?
+                .into_iter()
+                .filter(|task| owners.contains(&task.owner))
+                .collect();
+            return parse_local_tasks_to_datablock(tasks);
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTasksRequest {
             tenant_id: tenant.clone(),
             name_like: "".to_string(),
@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::TableContext;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// The resources used by the queries of the current tenant on this node, the tenants can't see
+/// the usage of each other.
+pub struct TenantResourceUsageTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for TenantResourceUsageTable {
+    const NAME: &'static str = "system.tenant_resource_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let usages = ctx
+            .get_tenant_resource_usage()
+            .into_iter()
+            .filter(|usage| usage.tenant == tenant)
+            .collect::<Vec<_>>();
+
+        let mut tenants = Vec::with_capacity(usages.len());
+        let mut running_queries = Vec::with_capacity(usages.len());
+        let mut queued_queries = Vec::with_capacity(usages.len());
+        let mut memory_usage = Vec::with_capacity(usages.len());
+        let mut peak_memory_usage = Vec::with_capacity(usages.len());
+        let mut max_memory_bytes = Vec::with_capacity(usages.len());
+        let mut max_threads = Vec::with_capacity(usages.len());
+        let mut max_concurrent_queries = Vec::with_capacity(usages.len());
+        for usage in usages {
+            tenants.push(usage.tenant);
+            running_queries.push(usage.running_queries);
+            queued_queries.push(usage.queued_queries);
+            // The memory may be released by the threads of other tenants.
+            memory_usage.push(usage.memory_usage.max(0) as u64);
+            peak_memory_usage.push(usage.peak_memory_usage.max(0) as u64);
+            max_memory_bytes.push(usage.max_memory_bytes);
+            max_threads.push(usage.max_threads);
+            max_concurrent_queries.push(usage.max_concurrent_queries);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(tenants),
+            UInt64Type::from_data(running_queries),
+            UInt64Type::from_data(queued_queries),
+            UInt64Type::from_data(memory_usage),
+            UInt64Type::from_data(peak_memory_usage),
+            UInt64Type::from_data(max_memory_bytes),
+            UInt64Type::from_data(max_threads),
+            UInt64Type::from_data(max_concurrent_queries),
+        ]))
+    }
+}
+
+impl TenantResourceUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("tenant", TableDataType::String),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "queued_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "peak_memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_memory_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("max_threads", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "max_concurrent_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tenant_resource_usage'".to_string(),
+            name: "tenant_resource_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTenantResourceUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -49,6 +49,20 @@ impl UserApiProvider {
         Ok(get_file_format.await?.data)
     }
 
+    // Update a file_format, the file_format must exist.
+    #[async_backtrace::framed]
+    pub async fn update_file_format(
+        &self,
+        tenant: &str,
+        file_format: UserDefinedFileFormat,
+    ) -> Result<u64> {
+        let file_format_api_provider = self.get_file_format_api_client(tenant)?;
+        file_format_api_provider
+            .update_file_format(file_format, MatchSeq::GE(1))
+            .await
+            .map_err(|e| e.add_message_back(" (while update file_format)"))
+    }
+
     // Get the tenant all file_format list.
     #[async_backtrace::framed]
     pub async fn get_file_formats(&self, tenant: &str) -> Result<Vec<UserDefinedFileFormat>> {
@@ -18,9 +18,12 @@
 extern crate core;
 
 mod jwt;
+mod migration;
 mod network_policy;
+mod notification_integration;
 mod password_policy;
 mod role_mgr;
+mod task;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_management::MigrationApi;
+use databend_common_meta_app::principal::Migration;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Record an applied migration.
+    #[async_backtrace::framed]
+    pub async fn add_migration(&self, tenant: &str, migration: Migration) -> Result<()> {
+        let client = self.get_migration_api_client(tenant)?;
+        client
+            .add_migration(migration)
+            .await
+            .map_err(|e| e.add_message_back(" (while apply migration)."))
+    }
+
+    // Remove the record of a rolled back migration.
+    #[async_backtrace::framed]
+    pub async fn drop_migration(&self, tenant: &str, name: &str) -> Result<()> {
+        let client = self.get_migration_api_client(tenant)?;
+        client
+            .drop_migration(name)
+            .await
+            .map_err(|e| e.add_message_back(" (while rollback migration)."))
+    }
+
+    // Get an applied migration by name.
+    #[async_backtrace::framed]
+    pub async fn get_migration(&self, tenant: &str, name: &str) -> Result<Option<Migration>> {
+        let client = self.get_migration_api_client(tenant)?;
+        client.get_migration(name).await
+    }
+
+    // Get the applied migrations by tenant, in the order they were applied.
+    #[async_backtrace::framed]
+    pub async fn get_migrations(&self, tenant: &str) -> Result<Vec<Migration>> {
+        let client = self.get_migration_api_client(tenant)?;
+        client
+            .list_migrations()
+            .await
+            .map_err(|e| e.add_message_back(" (while get migrations)."))
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::NotificationApi;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new notification integration.
+    #[async_backtrace::framed]
+    pub async fn add_notification_integration(
+        &self,
+        tenant: &str,
+        notification_integration: NotificationIntegration,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.get_notification_api_client(tenant)?;
+        client
+            .add_notification_integration(notification_integration, create_option)
+            .await
+    }
+
+    // Update notification integration.
+    #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_notification_integration(
+        &self,
+        tenant: &str,
+        name: &str,
+        url: Option<String>,
+        events: Option<Vec<NotificationEvent>>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+        if_exists: bool,
+    ) -> Result<Option<u64>> {
+        let client = self.get_notification_api_client(tenant)?;
+        let seq_integration = match client
+            .get_notification_integration(name, MatchSeq::GE(0))
+            .await
+        {
+            Ok(seq_integration) => seq_integration,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_NOTIFICATION_INTEGRATION {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter notification integration)"));
+                }
+            }
+        };
+
+        let seq = seq_integration.seq;
+        let mut integration = seq_integration.data;
+        if let Some(url) = url {
+            integration.url = url;
+        }
+        if let Some(events) = events {
+            integration.events = events;
+        }
+        if let Some(enabled) = enabled {
+            integration.enabled = enabled;
+        }
+        if let Some(comment) = comment {
+            integration.comment = comment;
+        }
+        integration.update_on = Some(Utc::now());
+
+        match client
+            .update_notification_integration(integration, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter notification integration).")),
+        }
+    }
+
+    // Drop a notification integration by name.
+    #[async_backtrace::framed]
+    pub async fn drop_notification_integration(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_notification_api_client(tenant)?;
+        match client
+            .drop_notification_integration(name, MatchSeq::GE(1))
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_NOTIFICATION_INTEGRATION {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop notification integration)"))
+                }
+            }
+        }
+    }
+
+    // Get a notification integration by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_notification_integration(
+        &self,
+        tenant: &str,
+        name: &str,
+    ) -> Result<NotificationIntegration> {
+        let client = self.get_notification_api_client(tenant)?;
+        let integration = client
+            .get_notification_integration(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(integration)
+    }
+
+    // Get all notification integrations by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_notification_integrations(
+        &self,
+        tenant: &str,
+    ) -> Result<Vec<NotificationIntegration>> {
+        let client = self.get_notification_api_client(tenant)?;
+        let integrations = client
+            .get_notification_integrations()
+            .await
+            .map_err(|e| e.add_message_back(" (while get notification integrations)."))?;
+        Ok(integrations)
+    }
+}
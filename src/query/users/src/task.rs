@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::TaskApi;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// The runs of the tasks are kept in the history for 7 days.
+const TASK_RUN_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// The times a task is read and updated again if it is changed by others meanwhile.
+const MAX_UPDATE_ATTEMPTS: usize = 3;
+
+impl UserApiProvider {
+    // Add a new task, returns the id of the task.
+    #[async_backtrace::framed]
+    pub async fn add_task(
+        &self,
+        tenant: &str,
+        task: Task,
+        create_option: &CreateOption,
+    ) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client.add_task(task, create_option).await
+    }
+
+    // Update the task if it has not been changed since it was read with `seq`.
+    #[async_backtrace::framed]
+    pub async fn update_task(&self, tenant: &str, task: Task, seq: u64) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .update_task(task, MatchSeq::Exact(seq))
+            .await
+            .map_err(|e| e.add_message_back(" (while alter task)."))
+    }
+
+    // Update a task with `f`, which is retried if the task is changed meanwhile.
+    #[async_backtrace::framed]
+    pub async fn update_task_with<F>(&self, tenant: &str, name: &str, f: F) -> Result<Task>
+    where F: Fn(&mut Task) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        let mut attempt = 1;
+        loop {
+            let seq_task = client.get_task(name, MatchSeq::GE(0)).await?;
+            let mut task = seq_task.data;
+            f(&mut task)?;
+
+            match client
+                .update_task(task.clone(), MatchSeq::Exact(seq_task.seq))
+                .await
+            {
+                Ok(_) => return Ok(task),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TASK && attempt < MAX_UPDATE_ATTEMPTS => {
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.add_message_back(" (while alter task).")),
+            }
+        }
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.drop_task(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Get a task by name, with the seq to update it.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &str, name: &str) -> Result<SeqV<Task>> {
+        let client = self.get_task_api_client(tenant)?;
+        client.get_task(name, MatchSeq::GE(0)).await
+    }
+
+    // Get all tasks by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<Task>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .get_tasks()
+            .await
+            .map_err(|e| e.add_message_back(" (while get tasks)."))
+    }
+
+    // Add a finished run of a task to the history.
+    #[async_backtrace::framed]
+    pub async fn add_task_run(&self, tenant: &str, task_run: TaskRun) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        client.add_task_run(task_run, TASK_RUN_TTL).await
+    }
+
+    // Get the runs of the tasks in the history by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_task_runs(&self, tenant: &str) -> Result<Vec<TaskRun>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .list_task_runs()
+            .await
+            .map_err(|e| e.add_message_back(" (while get task runs)."))
+    }
+
+    // Claim the run of a task scheduled at the time, returns false if another query node has
+    // claimed it.
+    #[async_backtrace::framed]
+    pub async fn try_claim_task_run(
+        &self,
+        tenant: &str,
+        name: &str,
+        scheduled_at: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let client = self.get_task_api_client(tenant)?;
+        client.try_claim_task_run(name, scheduled_at, ttl).await
+    }
+}
@@ -20,12 +20,17 @@ use databend_common_exception::Result;
 use databend_common_grpc::RpcClientConf;
 use databend_common_management::errors::TenantError;
 use databend_common_management::udf::UdfMgr;
+use databend_common_management::udf::DEFAULT_MAX_UDF_VERSIONS;
 use databend_common_management::ConnectionApi;
 use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatApi;
 use databend_common_management::FileFormatMgr;
+use databend_common_management::MigrationApi;
+use databend_common_management::MigrationMgr;
 use databend_common_management::NetworkPolicyApi;
 use databend_common_management::NetworkPolicyMgr;
+use databend_common_management::NotificationApi;
+use databend_common_management::NotificationMgr;
 use databend_common_management::PasswordPolicyApi;
 use databend_common_management::PasswordPolicyMgr;
 use databend_common_management::QuotaApi;
@@ -36,6 +41,8 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskApi;
+use databend_common_management::TaskMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
 use databend_common_meta_app::principal::AuthInfo;
@@ -55,6 +62,7 @@ pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     idm_config: IDMConfig,
+    max_udf_versions: usize,
 }
 
 impl UserApiProvider {
@@ -64,8 +72,9 @@ impl UserApiProvider {
         idm_config: IDMConfig,
         tenant: &str,
         quota: Option<TenantQuota>,
+        max_udf_versions: usize,
     ) -> Result<()> {
-        GlobalInstance::set(Self::try_create(conf, idm_config, tenant).await?);
+        GlobalInstance::set(Self::try_create(conf, idm_config, tenant, max_udf_versions).await?);
         let user_mgr = UserApiProvider::instance();
         if let Some(q) = quota {
             let i = user_mgr.get_tenant_quota_api_client(tenant)?;
@@ -80,12 +89,14 @@ impl UserApiProvider {
         conf: RpcClientConf,
         idm_config: IDMConfig,
         tenant: &str,
+        max_udf_versions: usize,
     ) -> Result<Arc<UserApiProvider>> {
         let client = MetaStoreProvider::new(conf).create_meta_store().await?;
         let user_mgr = UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            max_udf_versions,
         };
 
         // init built-in role
@@ -111,7 +122,7 @@ impl UserApiProvider {
         conf: RpcClientConf,
         tenant: &str,
     ) -> Result<Arc<UserApiProvider>> {
-        Self::try_create(conf, IDMConfig::default(), tenant).await
+        Self::try_create(conf, IDMConfig::default(), tenant, DEFAULT_MAX_UDF_VERSIONS).await
     }
 
     pub fn instance() -> Arc<UserApiProvider> {
@@ -162,6 +173,21 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_notification_api_client(&self, tenant: &str) -> Result<Arc<impl NotificationApi>> {
+        Ok(Arc::new(NotificationMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<impl TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_migration_api_client(&self, tenant: &str) -> Result<Arc<impl MigrationApi>> {
+        Ok(Arc::new(MigrationMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_password_policy_api_client(
         &self,
         tenant: &str,
@@ -208,5 +234,6 @@ pub struct ForTenant<'a> {
 impl<'a> ForTenant<'a> {
     pub fn udf_api(&self) -> UdfMgr {
         UdfMgr::create(self.user_api.client.clone(), self.tenant)
+            .with_max_versions(self.user_api.max_udf_versions)
     }
 }
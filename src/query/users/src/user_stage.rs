@@ -82,4 +82,24 @@ impl UserApiProvider {
             }
         }
     }
+
+    // Update an existing stage.
+    #[async_backtrace::framed]
+    pub async fn update_stage(&self, tenant: &str, info: StageInfo) -> Result<()> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        stage_api_provider
+            .update_stage(info)
+            .await
+            .map_err(|e| e.add_message_back(" (while update stage)"))
+    }
+
+    // Rename a stage.
+    #[async_backtrace::framed]
+    pub async fn rename_stage(&self, tenant: &str, name: &str, new_name: &str) -> Result<()> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        stage_api_provider
+            .rename_stage(name, new_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while rename stage)"))
+    }
 }
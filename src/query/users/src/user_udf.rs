@@ -12,15 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::udf::UdfApiError;
 use databend_common_management::udf::UdfError;
 use databend_common_meta_app::principal::UserDefinedFunction;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_types::MatchSeq;
+use futures::stream;
+use futures::StreamExt;
 
 use crate::UserApiProvider;
 
+/// The number of UDFs persisted concurrently by `add_udfs`.
+const ADD_UDFS_CONCURRENCY: usize = 16;
+
 /// UDF operations.
 impl UserApiProvider {
     // Add a new UDF.
@@ -36,6 +45,50 @@ impl UserApiProvider {
         Ok(())
     }
 
+    // Add UDFs in a batch, return the result of adding each of them, in the order of `infos`.
+    // A failed entry, such as an invalid definition, a name given again in the batch or an
+    // existing UDF with `CreateIfNotExists(false)`, does not stop the others from being added.
+    #[async_backtrace::framed]
+    pub async fn add_udfs(
+        &self,
+        tenant: &str,
+        infos: Vec<UserDefinedFunction>,
+        create_option: &CreateOption,
+    ) -> Result<Vec<Result<()>>> {
+        let udf_api = self.for_tenant(tenant)?.udf_api();
+
+        let mut names = HashSet::with_capacity(infos.len());
+        let checked = infos
+            .into_iter()
+            .map(|info| {
+                udf_api.check_udf(&info)?;
+                if !names.insert(info.name.clone()) {
+                    return Err(UdfError::Exists {
+                        tenant: tenant.to_string(),
+                        name: info.name.clone(),
+                        reason: "given more than once in the batch".to_string(),
+                    });
+                }
+                Ok(info)
+            })
+            .collect::<Vec<_>>();
+
+        let udf_api = &udf_api;
+        let results = stream::iter(checked)
+            .map(|checked| async move {
+                let info = checked?;
+                udf_api
+                    .add_udf(info, create_option)
+                    .await
+                    .map_err(ErrorCode::from)?
+                    .map_err(ErrorCode::from)
+            })
+            .buffered(ADD_UDFS_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(results)
+    }
+
     // Update a UDF.
     #[async_backtrace::framed]
     pub async fn update_udf(&self, tenant: &str, info: UserDefinedFunction) -> Result<u64> {
@@ -49,6 +102,30 @@ impl UserApiProvider {
         Ok(seq)
     }
 
+    // Rename a UDF, return the seq of the renamed UDF, or None if it does not exist and `if_exists`.
+    // The UDF named `new_name`, if any, is replaced if `or_replace`.
+    #[async_backtrace::framed]
+    pub async fn rename_udf(
+        &self,
+        tenant: &str,
+        old_name: &str,
+        new_name: &str,
+        if_exists: bool,
+        or_replace: bool,
+    ) -> Result<Option<u64>> {
+        let res = self
+            .for_tenant(tenant)?
+            .udf_api()
+            .rename_udf(old_name, new_name, or_replace, MatchSeq::GE(1))
+            .await?;
+
+        match res {
+            Ok(seq) => Ok(Some(seq)),
+            Err(UdfError::NotFound { .. }) if if_exists => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     // Get a UDF by name.
     #[async_backtrace::framed]
     pub async fn get_udf(
@@ -60,12 +137,47 @@ impl UserApiProvider {
         Ok(seqv.map(|x| x.data))
     }
 
+    // Get a UDF as it was when its seq was `seq`, see `UdfMgr::get_udf_at_version`.
+    #[async_backtrace::framed]
+    pub async fn get_udf_at_version(
+        &self,
+        tenant: &str,
+        udf_name: &str,
+        seq: u64,
+    ) -> Result<Option<UserDefinedFunction>, UdfApiError> {
+        let udf = self
+            .for_tenant(tenant)?
+            .udf_api()
+            .get_udf_at_version(udf_name, seq)
+            .await?;
+        Ok(udf)
+    }
+
     #[async_backtrace::framed]
     pub async fn exists_udf(&self, tenant: &str, udf_name: &str) -> Result<bool> {
         let res = self.get_udf(tenant, udf_name).await?;
         Ok(res.is_some())
     }
 
+    // Check if the UDFs exist by names, with a single listing instead of a get for each name.
+    // The returned map contains an entry for each of the names.
+    #[async_backtrace::framed]
+    pub async fn exists_udfs(
+        &self,
+        tenant: &str,
+        names: &[String],
+    ) -> Result<HashMap<String, bool>> {
+        let udfs = self.get_udfs(tenant).await?;
+        let existing = udfs
+            .iter()
+            .map(|udf| udf.name.as_str())
+            .collect::<HashSet<_>>();
+        Ok(names
+            .iter()
+            .map(|name| (name.clone(), existing.contains(name.as_str())))
+            .collect())
+    }
+
     // Get all UDFs for the tenant.
     #[async_backtrace::framed]
     pub async fn get_udfs(&self, tenant: &str) -> Result<Vec<UserDefinedFunction>> {
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 mod jwt;
+mod migration;
 mod network_policy;
+mod notification_integration;
 mod password_policy;
 mod role_cache_mgr;
 mod role_mgr;
 mod role_util;
+mod task;
 mod user_mgr;
 mod user_udf;
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::Migration;
+use databend_common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_migration() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, tenant).await?;
+
+    let v2 = Migration {
+        name: "v1_to_v2.sql".to_string(),
+        checksum: "checksum_v2".to_string(),
+        rollback_script: Some("DROP TABLE t2;".to_string()),
+        applied_at: Utc.with_ymd_and_hms(2024, 2, 14, 8, 0, 0).unwrap(),
+    };
+    let v3 = Migration {
+        name: "v2_to_v3.sql".to_string(),
+        checksum: "checksum_v3".to_string(),
+        rollback_script: None,
+        applied_at: Utc.with_ymd_and_hms(2024, 2, 14, 9, 0, 0).unwrap(),
+    };
+    // the migrations are listed in the order they were applied, not by name
+    user_mgr.add_migration(tenant, v3.clone()).await?;
+    user_mgr.add_migration(tenant, v2.clone()).await?;
+    assert_eq!(user_mgr.get_migrations(tenant).await?, vec![
+        v2.clone(),
+        v3.clone()
+    ]);
+
+    // add again
+    let res = user_mgr.add_migration(tenant, v2.clone()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::ILLEGAL_MIGRATION);
+
+    assert_eq!(
+        user_mgr.get_migration(tenant, "v1_to_v2.sql").await?,
+        Some(v2.clone())
+    );
+    assert_eq!(user_mgr.get_migration(tenant, "unknown.sql").await?, None);
+
+    user_mgr.drop_migration(tenant, "v2_to_v3.sql").await?;
+    assert_eq!(user_mgr.get_migrations(tenant).await?, vec![v2]);
+
+    // drop again
+    let res = user_mgr.drop_migration(tenant, "v2_to_v3.sql").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_MIGRATION);
+
+    Ok(())
+}
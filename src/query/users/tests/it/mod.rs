@@ -13,9 +13,12 @@
 // limitations under the License.
 
 mod jwt;
+mod migration;
 mod network_policy;
+mod notification_integration;
 mod password_policy;
 mod role_cache_mgr;
 mod role_mgr;
+mod task;
 mod user_mgr;
 mod user_udf;
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::NotificationEvent;
+use databend_common_meta_app::principal::NotificationIntegration;
+use databend_common_meta_app::principal::NotificationType;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_notification_integration() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, tenant).await?;
+
+    let integration = NotificationIntegration {
+        name: "test_hook".to_string(),
+        notification_type: NotificationType::Webhook,
+        url: "https://example.com/hook".to_string(),
+        events: vec![NotificationEvent::CopyIntoComplete],
+        enabled: true,
+        comment: "".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 2, 9, 7, 8, 9).unwrap(),
+        update_on: None,
+    };
+    user_mgr
+        .add_notification_integration(
+            tenant,
+            integration.clone(),
+            &CreateOption::CreateIfNotExists(false),
+        )
+        .await?;
+
+    // add again
+    let res = user_mgr
+        .add_notification_integration(
+            tenant,
+            integration.clone(),
+            &CreateOption::CreateIfNotExists(false),
+        )
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::NOTIFICATION_INTEGRATION_ALREADY_EXISTS
+    );
+
+    // update notification integration
+    user_mgr
+        .update_notification_integration(
+            tenant,
+            "test_hook",
+            None,
+            Some(vec![
+                NotificationEvent::QueryError,
+                NotificationEvent::PipeError,
+            ]),
+            Some(false),
+            None,
+            false,
+        )
+        .await?;
+
+    let got = user_mgr
+        .get_notification_integration(tenant, "test_hook")
+        .await?;
+    assert_eq!(got.url, integration.url);
+    assert_eq!(got.events, vec![
+        NotificationEvent::QueryError,
+        NotificationEvent::PipeError
+    ]);
+    assert!(!got.enabled);
+    assert!(got.update_on.is_some());
+
+    let res = user_mgr
+        .update_notification_integration(tenant, "not_exists", None, None, None, None, true)
+        .await?;
+    assert!(res.is_none());
+
+    // drop notification integration
+    user_mgr
+        .drop_notification_integration(tenant, "test_hook", false)
+        .await?;
+    let integrations = user_mgr.get_notification_integrations(tenant).await?;
+    assert!(integrations.is_empty());
+
+    let res = user_mgr
+        .drop_notification_integration(tenant, "test_hook", false)
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskSchedule;
+use databend_common_meta_app::principal::TaskState;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, tenant).await?;
+
+    let task = Task {
+        name: "test_task".to_string(),
+        owner: "account_admin".to_string(),
+        query_text: "INSERT INTO t SELECT 1".to_string(),
+        schedule: Some(TaskSchedule::Cron {
+            expr: "0 * * * *".to_string(),
+            time_zone: None,
+        }),
+        state: TaskState::Started,
+        created_on: Utc.with_ymd_and_hms(2024, 2, 12, 7, 8, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2024, 2, 12, 7, 8, 9).unwrap(),
+        ..Default::default()
+    };
+    let id = user_mgr
+        .add_task(
+            tenant,
+            task.clone(),
+            &CreateOption::CreateIfNotExists(false),
+        )
+        .await?;
+
+    // add again
+    let res = user_mgr
+        .add_task(
+            tenant,
+            task.clone(),
+            &CreateOption::CreateIfNotExists(false),
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::TASK_ALREADY_EXISTS);
+
+    let got = user_mgr.get_task(tenant, "test_task").await?;
+    assert_eq!(got.data.id, id);
+    assert_eq!(got.data.query_text, task.query_text);
+
+    // update with the seq it was read with
+    let mut suspended = got.data.clone();
+    suspended.state = TaskState::Suspended;
+    user_mgr
+        .update_task(tenant, suspended.clone(), got.seq)
+        .await?;
+
+    // the task has been updated since it was read
+    let res = user_mgr.update_task(tenant, suspended, got.seq).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TASK);
+
+    let tasks = user_mgr.get_tasks(tenant).await?;
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].state, TaskState::Suspended);
+
+    // only one claim of a run succeeds
+    let scheduled_at = Utc.with_ymd_and_hms(2024, 2, 12, 8, 0, 0).unwrap();
+    let ttl = Duration::from_secs(60);
+    assert!(
+        user_mgr
+            .try_claim_task_run(tenant, "test_task", scheduled_at, ttl)
+            .await?
+    );
+    assert!(
+        !user_mgr
+            .try_claim_task_run(tenant, "test_task", scheduled_at, ttl)
+            .await?
+    );
+
+    // the runs are listed in the order of the scheduled time
+    for (run_id, hour) in [("r2", 9), ("r1", 8)] {
+        let run = TaskRun {
+            task_id: id,
+            task_name: "test_task".to_string(),
+            run_id: run_id.to_string(),
+            state: TaskRunState::Succeeded,
+            scheduled_at: Utc.with_ymd_and_hms(2024, 2, 12, hour, 0, 0).unwrap(),
+            completed_at: Utc.with_ymd_and_hms(2024, 2, 12, hour, 0, 1).unwrap(),
+            ..Default::default()
+        };
+        user_mgr.add_task_run(tenant, run).await?;
+    }
+    let runs = user_mgr.get_task_runs(tenant).await?;
+    let run_ids = runs.iter().map(|r| r.run_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(run_ids, vec!["r1", "r2"]);
+
+    // drop task
+    user_mgr.drop_task(tenant, "test_task", false).await?;
+    assert!(user_mgr.get_tasks(tenant).await?.is_empty());
+
+    let res = user_mgr.drop_task(tenant, "test_task", false).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TASK);
+    user_mgr.drop_task(tenant, "test_task", true).await?;
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_grpc::RpcClientConf;
@@ -73,6 +74,33 @@ async fn test_user_lambda_udf() -> Result<()> {
         assert_eq!(udf, Some(isempty_udf.clone()));
     }
 
+    // exists.
+    {
+        let names = vec![isempty.to_string(), "unknown".to_string()];
+        let exists = user_mgr.exists_udfs(tenant, &names).await?;
+        assert_eq!(exists.len(), 2);
+        assert_eq!(exists.get(isempty), Some(&true));
+        assert_eq!(exists.get("unknown"), Some(&false));
+    }
+
+    // get at version.
+    {
+        let updated_udf = UserDefinedFunction::create_lambda_udf(
+            isempty,
+            vec!["p".to_string()],
+            "p is null",
+            description,
+        );
+        let updated_seq = user_mgr.update_udf(tenant, updated_udf.clone()).await?;
+        let seq = user_mgr.update_udf(tenant, isempty_udf.clone()).await?;
+        let udf = user_mgr
+            .get_udf_at_version(tenant, isempty, updated_seq)
+            .await?;
+        assert_eq!(udf, Some(updated_udf));
+        let udf = user_mgr.get_udf_at_version(tenant, isempty, seq).await?;
+        assert_eq!(udf, Some(isempty_udf.clone()));
+    }
+
     // drop.
     {
         user_mgr.drop_udf(tenant, isnotempty, false).await??;
@@ -177,3 +205,78 @@ async fn test_user_udf_server() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_add_udfs() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, tenant).await?;
+    let create_udf = |name: &str| {
+        UserDefinedFunction::create_lambda_udf(name, vec!["p".to_string()], "is_null(p)", "")
+    };
+
+    let udfs = (0..40)
+        .map(|i| create_udf(&format!("udf_{i}")))
+        .collect::<Vec<_>>();
+    let results = user_mgr
+        .add_udfs(
+            tenant,
+            udfs.clone(),
+            &CreateOption::CreateIfNotExists(false),
+        )
+        .await?;
+    assert_eq!(results.len(), 40);
+    assert!(results.iter().all(|res| res.is_ok()));
+    assert_eq!(user_mgr.get_udfs(tenant).await?.len(), 40);
+
+    // An existing UDF only fails its own entry.
+    let results = user_mgr
+        .add_udfs(
+            tenant,
+            vec![create_udf("udf_1"), create_udf("udf_new")],
+            &CreateOption::CreateIfNotExists(false),
+        )
+        .await?;
+    assert_eq!(
+        results[0].as_ref().unwrap_err().code(),
+        ErrorCode::UDF_ALREADY_EXISTS
+    );
+    assert!(results[1].is_ok());
+
+    let results = user_mgr
+        .add_udfs(
+            tenant,
+            vec![create_udf("udf_1")],
+            &CreateOption::CreateIfNotExists(true),
+        )
+        .await?;
+    assert!(results[0].is_ok());
+
+    // An invalid definition or a name given again only fails its own entry.
+    let results = user_mgr
+        .add_udfs(
+            tenant,
+            vec![
+                create_udf("udf_other"),
+                create_udf("is_null"),
+                create_udf("udf_other"),
+            ],
+            &CreateOption::CreateOrReplace,
+        )
+        .await?;
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert_eq!(
+        results[1].as_ref().unwrap_err().code(),
+        ErrorCode::UDF_ALREADY_EXISTS
+    );
+    assert_eq!(
+        results[2].as_ref().unwrap_err().code(),
+        ErrorCode::UDF_ALREADY_EXISTS
+    );
+    assert!(user_mgr.exists_udf(tenant, "udf_other").await?);
+    assert!(!user_mgr.exists_udf(tenant, "is_null").await?);
+
+    Ok(())
+}
@@ -238,7 +238,7 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             lit: Literal::String("".to_string()),
         },
-        TypeName::String => Expr::Literal {
+        TypeName::String | TypeName::Varchar(_) => Expr::Literal {
             span: None,
             lit: Literal::String("".to_string()),
         },
@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+
+import os
+
+import sqlalchemy
+from sqlalchemy import Column, Integer, MetaData, String, Table, text
+from sqlalchemy.orm import Session, declarative_base
+
+http_port = os.getenv("QUERY_HTTP_HANDLER_PORT")
+if http_port is None:
+    port = "8000"
+else:
+    port = http_port
+
+
+uri = "databend://root:@localhost:" + port + "/default?sslmode=disable"
+engine = sqlalchemy.create_engine(uri, future=True)
+
+with engine.connect() as conn:
+    print(conn.execute(text("SELECT 1")).scalar())
+    print(conn.execute(text("SHOW VARIABLES LIKE 'lower_case_table_names'")).fetchall())
+    conn.execute(text("drop database if exists sqlalchemy_db"))
+    conn.execute(text("create database sqlalchemy_db"))
+    conn.execute(
+        text(
+            "create table sqlalchemy_db.books(id int not null, title varchar not null, author varchar null)"
+        )
+    )
+
+# reflect
+metadata = MetaData()
+metadata.reflect(bind=engine, schema="sqlalchemy_db")
+books = metadata.tables["sqlalchemy_db.books"]
+for column in books.columns:
+    print(column.name, column.type.__class__.__name__, column.nullable)
+
+# ORM mapping
+Base = declarative_base()
+
+
+class Book(Base):
+    __tablename__ = "books"
+    __table_args__ = {"schema": "sqlalchemy_db"}
+
+    id = Column(Integer, primary_key=True)
+    title = Column(String)
+    author = Column(String)
+
+
+# CRUD
+with Session(engine) as session:
+    session.add_all(
+        [
+            Book(id=1, title="mybook", author="author"),
+            Book(id=2, title="another", author=None),
+        ]
+    )
+    session.commit()
+
+    for book in session.query(Book).order_by(Book.id):
+        print(book.id, book.title, book.author)
+
+    session.query(Book).filter(Book.id == 2).update({"author": "someone"})
+    session.commit()
+    print(session.query(Book).filter(Book.id == 2).one().author)
+
+    session.query(Book).filter(Book.id == 1).delete()
+    session.commit()
+    print(session.query(Book).count())
+
+with engine.connect() as conn:
+    conn.execute(text("drop database sqlalchemy_db"))
@@ -0,0 +1,40 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop role if exists role_col" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_col" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_col" | $BENDSQL_CLIENT_CONNECT
+echo "create database db_col" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_col.t1(a int, b int, c string)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db_col.t1 values(1, 2, 'x')" | $BENDSQL_CLIENT_CONNECT
+echo "create role role_col" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_col identified by '123' with DEFAULT_ROLE='role_col'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role role_col to u_col" | $BENDSQL_CLIENT_CONNECT
+export TEST_USER_CONNECT="bendsql --user=u_col --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== grant column privileges ==="
+echo "grant select (a, b) on db_col.t1 to role role_col" | $BENDSQL_CLIENT_CONNECT
+echo "grant select (d) on db_col.t1 to role role_col" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role role_col" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== select with column privileges ==="
+echo "select a from db_col.t1" | $TEST_USER_CONNECT
+echo "select a, b from db_col.t1 where a > 0" | $TEST_USER_CONNECT
+echo "select * from db_col.t1" | $TEST_USER_CONNECT
+echo "select a from db_col.t1 where c = 'x'" | $TEST_USER_CONNECT
+
+echo "=== revoke column privileges ==="
+echo "revoke select (b) on db_col.t1 from role role_col" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role role_col" | $BENDSQL_CLIENT_CONNECT
+echo "select a from db_col.t1" | $TEST_USER_CONNECT
+echo "select b from db_col.t1" | $TEST_USER_CONNECT
+
+echo "=== table privileges cover all the columns ==="
+echo "grant select on db_col.t1 to role role_col" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_col.t1" | $TEST_USER_CONNECT
+
+echo "drop role if exists role_col" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_col" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_col" | $BENDSQL_CLIENT_CONNECT
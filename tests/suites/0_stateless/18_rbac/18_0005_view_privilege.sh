@@ -0,0 +1,50 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop role if exists role_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_view" | $BENDSQL_CLIENT_CONNECT
+echo "create database db_view" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_view.t1(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db_view.t1 values(1)" | $BENDSQL_CLIENT_CONNECT
+echo "create role role_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "create role role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "grant create on db_view.* to role role_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "grant create on db_view.* to role role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "grant select on db_view.t1 to role role_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_view_a identified by '123' with DEFAULT_ROLE='role_view_a'" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_view_b identified by '123' with DEFAULT_ROLE='role_view_b'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role role_view_a to u_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "grant role role_view_b to u_view_b" | $BENDSQL_CLIENT_CONNECT
+export TEST_USER_A_CONNECT="bendsql --user=u_view_a --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+export TEST_USER_B_CONNECT="bendsql --user=u_view_b --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== create view requires select on the referenced tables ==="
+echo "create view db_view.v2 as select * from db_view.t1" | $TEST_USER_B_CONNECT
+echo "create view db_view.v1 as select * from db_view.t1" | $TEST_USER_A_CONNECT
+
+echo "=== the view is accessed with the privileges of the owner ==="
+echo "grant select on db_view.v1 to role role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_view.v1" | $TEST_USER_B_CONNECT
+echo "select * from db_view.t1" | $TEST_USER_B_CONNECT
+
+echo "=== revoke the access of the owner ==="
+echo "revoke select on db_view.t1 from role role_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_view.v1" | $TEST_USER_B_CONNECT
+
+echo "=== alter view owner ==="
+echo "alter view db_view.v1 owner to role role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_view.v1" | $TEST_USER_B_CONNECT
+echo "grant select on db_view.t1 to role role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_view.v1" | $TEST_USER_B_CONNECT
+echo "alter view db_view.t1 owner to role role_view_b" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop role if exists role_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists role_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_view_a" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_view_b" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_view" | $BENDSQL_CLIENT_CONNECT
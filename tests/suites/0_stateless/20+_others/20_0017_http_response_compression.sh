@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+QUERY='{"sql": "select number, to_string(number) from numbers(100000)", "pagination": {"wait_time_secs": 6, "max_rows_per_page": 100000}}'
+
+for encoding in gzip br; do
+	echo "== ${encoding} =="
+	curl -s -u root: -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+		--header 'Content-Type: application/json' \
+		--header "Accept-Encoding: ${encoding}" \
+		-D /tmp/20_0017_headers.txt -o /tmp/20_0017_body.bin \
+		-d "${QUERY}"
+	grep -i '^content-encoding' /tmp/20_0017_headers.txt | tr -d '\r'
+	curl -s -u root: -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+		--header 'Content-Type: application/json' \
+		--compressed -H "Accept-Encoding: ${encoding}" \
+		-d "${QUERY}" | jq -r '.data | length, .[99999][1]'
+done
+
+echo "== below threshold =="
+curl -s -u root: -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+	--header 'Content-Type: application/json' \
+	--header 'Accept-Encoding: gzip' \
+	-D /tmp/20_0017_headers.txt -o /dev/null \
+	-d '{"sql": "select 1"}'
+grep -ci '^content-encoding' /tmp/20_0017_headers.txt
+
+rm -f /tmp/20_0017_headers.txt /tmp/20_0017_body.bin
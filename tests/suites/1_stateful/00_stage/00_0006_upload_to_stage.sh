@@ -12,7 +12,7 @@ echo "list @s2" | $BENDSQL_CLIENT_CONNECT
 curl -u root: -XPUT -H "stage_name:s2" -F "upload=@${TESTS_DATA_DIR}/csv/books.csv" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
 curl -u root: -XPUT -H "x-databend-stage-name:s2" -H "relative_path:test" -F "upload=@${TESTS_DATA_DIR}/csv/books.csv" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
 
-echo "list @s2" | $BENDSQL_CLIENT_CONNECT | awk '{print $1,$2,$3}'
+echo "list @s2" | $BENDSQL_CLIENT_CONNECT | awk -F'\t' '{print $1,$2,$5}'
 echo "drop stage s2;" | $BENDSQL_CLIENT_CONNECT
 
 # test drop stage
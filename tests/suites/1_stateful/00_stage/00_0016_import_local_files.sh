@@ -0,0 +1,44 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+IMPORT_DIR=/tmp/00_0016_import
+
+rm -rf "$IMPORT_DIR"
+mkdir -p "$IMPORT_DIR"
+printf '1,a\n2,b\n' > "$IMPORT_DIR"/data_1.csv
+printf '3,c\n' > "$IMPORT_DIR"/data_2.csv
+printf '4|d\n' > "$IMPORT_DIR"/data.txt
+
+stmt "DROP TABLE IF EXISTS t_import"
+stmt "CREATE TABLE t_import(a int, b string)"
+
+query "IMPORT INTO t_import FROM '$IMPORT_DIR/*.csv' FILE_FORMAT = (TYPE = CSV FIELD_DELIMITER = ',')"
+query "SELECT * FROM t_import ORDER BY a"
+
+query "IMPORT INTO t_import FROM '$IMPORT_DIR/data.txt' FILE_FORMAT = (TYPE = CSV FIELD_DELIMITER = '|')"
+query "SELECT * FROM t_import ORDER BY a"
+
+comment "the relative paths and the paths with .. are rejected"
+query "IMPORT INTO t_import FROM '$IMPORT_DIR/../00_0016_import/data_1.csv' FILE_FORMAT = (TYPE = CSV)"
+query "IMPORT INTO t_import FROM 'data_1.csv' FILE_FORMAT = (TYPE = CSV)"
+query "SELECT count(*) FROM t_import"
+
+comment "the paths out of the import root are rejected, even through the symbolic links"
+ln -s /etc "$IMPORT_DIR"/link
+query "IMPORT INTO t_import FROM '$IMPORT_DIR/link/hosts' FILE_FORMAT = (TYPE = CSV)"
+query "SELECT count(*) FROM t_import"
+
+comment "the users without the super privilege are denied"
+export TEST_USER_CONNECT="bendsql --user=test-user --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+echo "set global enable_experimental_rbac_check=1" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "create user 'test-user' IDENTIFIED BY 'password'" | $BENDSQL_CLIENT_CONNECT
+echo "grant insert on default.t_import to 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "IMPORT INTO t_import FROM '$IMPORT_DIR/data_1.csv' FILE_FORMAT = (TYPE = CSV)" | $TEST_USER_CONNECT
+query "SELECT count(*) FROM t_import"
+echo "drop user if exists 'test-user'" | $BENDSQL_CLIENT_CONNECT
+
+stmt "DROP TABLE IF EXISTS t_import"
+rm -rf "$IMPORT_DIR"
@@ -0,0 +1,62 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+MIGRATION_DIR=/tmp/00_0017_migration
+
+upload() {
+	curl -s -u root: -XPUT -H "x-databend-stage-name:s_migration" -F "upload=@$1" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
+}
+
+rm -rf "$MIGRATION_DIR"
+mkdir -p "$MIGRATION_DIR"
+cat > "$MIGRATION_DIR"/v1_to_v2.sql <<'EOF'
+-- the tables of v2
+CREATE TABLE mig_t1(a int);
+CREATE TABLE mig_t2(a int, b string);
+INSERT INTO mig_t1 VALUES (1), (2);
+--/+
+DROP TABLE mig_t2;
+DROP TABLE mig_t1;
+EOF
+
+stmt "DROP TABLE IF EXISTS mig_t1"
+stmt "DROP TABLE IF EXISTS mig_t2"
+stmt "DROP TABLE IF EXISTS mig_t3"
+stmt "DROP STAGE IF EXISTS s_migration"
+stmt "CREATE STAGE s_migration"
+upload "$MIGRATION_DIR"/v1_to_v2.sql
+
+query "APPLY MIGRATION '@s_migration/v1_to_v2.sql'"
+query "SELECT a FROM mig_t1 ORDER BY a"
+query "SELECT name, checksum FROM system.databend_migrations"
+
+comment "a migration is applied only once"
+query "APPLY MIGRATION '@s_migration/v1_to_v2.sql'"
+query "SELECT a FROM mig_t1 ORDER BY a"
+
+comment "an applied migration can not be changed"
+sed -i 's/(1), (2)/(1), (2), (3)/' "$MIGRATION_DIR"/v1_to_v2.sql
+upload "$MIGRATION_DIR"/v1_to_v2.sql
+query "APPLY MIGRATION '@s_migration/v1_to_v2.sql'"
+
+comment "a failed migration is not recorded, the statements before the failed one are applied"
+cat > "$MIGRATION_DIR"/v2_to_v3.sql <<'EOF'
+CREATE TABLE mig_t3(a int);
+DROP TABLE mig_unknown;
+EOF
+upload "$MIGRATION_DIR"/v2_to_v3.sql
+echo "APPLY MIGRATION '@s_migration/v2_to_v3.sql'" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "ResponseError with [0-9]*"
+query "SHOW MIGRATIONS" | cut -f1
+query "SELECT name FROM system.tables WHERE database = 'default' AND name LIKE 'mig_%' ORDER BY name"
+stmt "DROP TABLE mig_t3"
+
+comment "a migration not applied can not be rolled back"
+query "ROLLBACK MIGRATION 'v2_to_v3.sql'"
+query "ROLLBACK MIGRATION 'v1_to_v2.sql'"
+query "SELECT name FROM system.tables WHERE database = 'default' AND name LIKE 'mig_%' ORDER BY name"
+query "SHOW MIGRATIONS"
+
+stmt "DROP STAGE IF EXISTS s_migration"
+rm -rf "$MIGRATION_DIR"
@@ -32,17 +32,23 @@ with NativeClient(name="client1>") as client1:
 
     mycursor = mydb.cursor()
     mycursor.execute(
-        "SELECT mysql_connection_id FROM system.processes WHERE extra_info LIKE '%SELECT max(number)%' AND extra_info NOT LIKE '%system.processes%';"
+        "SELECT mysql_connection_id FROM system.processes WHERE info LIKE '%SELECT max(number)%' AND info NOT LIKE '%system.processes%';"
     )
     res = mycursor.fetchone()
+    assert res is not None
     kill_query = "kill query " + str(res[0]) + ";"
     mycursor.execute(kill_query)
-    time.sleep(0.1)
-    mycursor.execute(
-        "SELECT * FROM system.processes WHERE extra_info LIKE '%SELECT max(number)%' AND extra_info NOT LIKE '%system.processes%';"
-    )
-    res = mycursor.fetchone()
 
-    ## TODO NEW EXPRESSION
-    ## assert res is None
+    # The killed query should be gone within 1 second.
+    deadline = time.time() + 1
+    while True:
+        mycursor.execute(
+            "SELECT * FROM system.processes WHERE info LIKE '%SELECT max(number)%' AND info NOT LIKE '%system.processes%';"
+        )
+        res = mycursor.fetchone()
+        if res is None or time.time() > deadline:
+            break
+        time.sleep(0.1)
+
+    assert res is None
     client1.expect(prompt)
@@ -0,0 +1,40 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+BACKUP_LOCATION="'s3://testbucket/admin/backup_database/' connection=(access_key_id ='minioadmin' secret_access_key ='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}')"
+
+echo "drop database if exists db_backup;" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_restored;" | $BENDSQL_CLIENT_CONNECT
+
+echo "create database db_backup;" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_backup.t1(a int, b string);" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_backup.t2(a int);" | $BENDSQL_CLIENT_CONNECT
+echo "create view db_backup.v1 as select a from db_backup.t1 where a > 1;" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db_backup.t1 values(1, 'a'),(2, 'b');" | $BENDSQL_CLIENT_CONNECT
+
+## full backup
+backup_id=$(echo "backup database db_backup to ${BACKUP_LOCATION};" | $BENDSQL_CLIENT_CONNECT | cut -f 1)
+
+echo "insert into db_backup.t1 values(3, 'c');" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db_backup.t2 values(10);" | $BENDSQL_CLIENT_CONNECT
+
+## incremental backup, all the tables including the view are in the backup
+echo "backup database db_backup to ${BACKUP_LOCATION} incremental since '${backup_id}';" | $BENDSQL_CLIENT_CONNECT | cut -f 2
+
+echo "show backups from ${BACKUP_LOCATION};" | $BENDSQL_CLIENT_CONNECT | wc -l | tr -d ' '
+
+## the latest backup is restored
+echo "restore database db_backup from ${BACKUP_LOCATION} as db_restored;" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_restored.t1 order by a;" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_restored.t2;" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db_restored.v1 order by a;" | $BENDSQL_CLIENT_CONNECT
+
+## the restored table is writable
+echo "insert into db_restored.t1 values(4, 'd');" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from db_restored.t1;" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from db_backup.t1;" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop database if exists db_backup;" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_restored;" | $BENDSQL_CLIENT_CONNECT
@@ -13,7 +13,7 @@ curl -s -w "%{http_code}\n" -X PUT -o /dev/null -H Content-Type:application/octe
 
 # LIST will output file's updated time, so we only take first three of output:
 # file_name, file_size, file_md5
-echo "LIST @presign_stage/" | $BENDSQL_CLIENT_CONNECT | awk '{print $1,$2,$3}';
+echo "LIST @presign_stage/" | $BENDSQL_CLIENT_CONNECT | awk -F'\t' '{print $1,$2,$5}';
 
 ## Drop table.
 echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT
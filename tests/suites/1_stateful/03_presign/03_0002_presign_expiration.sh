@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE STAGE presign_stage;" | $BENDSQL_CLIENT_CONNECT
+
+# The headers to send with the upload request are returned in the second column.
+echo "PRESIGN UPLOAD @presign_stage/newfile.csv EXPIRATION = 600 CONTENT_TYPE = 'text/csv'" | $BENDSQL_CLIENT_CONNECT | cut -f 2
+
+url=$(echo "PRESIGN UPLOAD @presign_stage/newfile.csv EXPIRATION = 600 CONTENT_TYPE = 'text/csv'" | $BENDSQL_CLIENT_CONNECT | cut -f 3)
+curl -s -w "%{http_code}\n" -X PUT -o /dev/null -H Content-Type:text/csv "$url" -d "1,2,3"
+
+# The url is accessible before the expiration time in the fourth column.
+echo "PRESIGN DOWNLOAD @presign_stage/newfile.csv EXPIRATION = 3" | $BENDSQL_CLIENT_CONNECT >/tmp/03_0002_presign.txt
+url=$(cut -f 3 /tmp/03_0002_presign.txt)
+expires_at=$(cut -f 4 /tmp/03_0002_presign.txt)
+echo "select '${expires_at}'::timestamp > now()" | $BENDSQL_CLIENT_CONNECT
+curl -s -w "%{http_code}\n" -o /dev/null "$url"
+
+# And is rejected after it.
+sleep 5
+curl -s -w "%{http_code}\n" -o /dev/null "$url"
+rm -f /tmp/03_0002_presign.txt
+
+## Drop table.
+echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT